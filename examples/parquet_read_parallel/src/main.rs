@@ -39,7 +39,7 @@ fn parallel_read(path: &str, row_group: usize) -> Result<()> {
 
     // read (IO-bounded) all columns into memory (use a subset of the fields to project)
     let mut columns =
-        read::read_columns_many(&mut file, row_group, schema.fields, Some(chunk_size))?;
+        read::read_columns_many(&mut file, row_group, schema.fields, Some(chunk_size), None)?;
 
     // deserialize (CPU-bounded) to arrow
     let mut num_rows = row_group.num_rows();
@@ -67,7 +67,8 @@ async fn main() -> Result<()> {
 
     // this is IO-bounded (and issues a join, thus the reader_factory)
     let column_chunks =
-        read::read_columns_many_async(reader_factory, group, schema.fields, chunk_size).await?;
+        read::read_columns_many_async(reader_factory, group, schema.fields, chunk_size, None)
+            .await?;
 
     // this is CPU-bounded and should be sent to a separate thread-pool.
     // We do it here for simplicity
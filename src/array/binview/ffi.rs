@@ -0,0 +1,81 @@
+use crate::{
+    array::{FromFfi, ToFfi},
+    bitmap::align,
+    error::ArrowError,
+    ffi,
+};
+
+use crate::error::Result;
+
+use super::{BinaryViewArrayGeneric, ViewType};
+
+unsafe impl<T: ViewType + ?Sized> ToFfi for BinaryViewArrayGeneric<T> {
+    // the buffer with the size of each data buffer is not stored in this array and is
+    // thus appended when the array is exported (see `ArrowArray::new`)
+    fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
+        let mut buffers = Vec::with_capacity(self.buffers.len() + 2);
+        buffers.push(self.validity.as_ref().map(|x| x.as_ptr()));
+        buffers.push(Some(self.views.as_ptr().cast::<u8>()));
+        buffers.extend(
+            self.buffers
+                .iter()
+                .map(|buffer| Some(buffer.as_ptr().cast::<u8>())),
+        );
+        buffers
+    }
+
+    fn offset(&self) -> Option<usize> {
+        let offset = self.views.offset();
+        if let Some(bitmap) = self.validity.as_ref() {
+            if bitmap.offset() == offset {
+                Some(offset)
+            } else {
+                None
+            }
+        } else {
+            Some(offset)
+        }
+    }
+
+    fn to_ffi_aligned(&self) -> Self {
+        let offset = self.views.offset();
+
+        let validity = self.validity.as_ref().map(|bitmap| {
+            if bitmap.offset() == offset {
+                bitmap.clone()
+            } else {
+                align(bitmap, offset)
+            }
+        });
+
+        Self {
+            data_type: self.data_type.clone(),
+            validity,
+            views: self.views.clone(),
+            buffers: self.buffers.clone(),
+            phantom: self.phantom,
+        }
+    }
+}
+
+impl<T: ViewType + ?Sized, A: ffi::ArrowArrayRef> FromFfi<A> for BinaryViewArrayGeneric<T> {
+    unsafe fn try_from_ffi(array: A) -> Result<Self> {
+        let data_type = array.data_type().clone();
+
+        // validity, views, data buffers..., buffer sizes
+        let n_buffers = array.n_buffers();
+        if n_buffers < 3 {
+            return Err(ArrowError::oos(
+                "A view array must have at least 3 buffers (validity, views and buffer sizes)",
+            ));
+        }
+
+        let validity = unsafe { array.validity() }?;
+        let views = unsafe { array.buffer::<i128>(1) }?;
+        let buffers = (2..n_buffers - 1)
+            .map(|index| unsafe { array.buffer::<u8>(index) })
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::try_new(data_type, views, buffers.into(), validity)
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt::{Debug, Formatter, Result, Write};
+
+use super::super::fmt::write_vec;
+use super::{BinaryViewArrayGeneric, ViewType};
+
+pub fn write_value<T: ViewType + ?Sized, W: Write>(
+    array: &BinaryViewArrayGeneric<T>,
+    index: usize,
+    f: &mut W,
+) -> Result {
+    let bytes = array.value(index).to_bytes();
+    if T::IS_UTF8 {
+        // soundness: the invariant of the struct
+        write!(f, "{}", unsafe { std::str::from_utf8_unchecked(bytes) })
+    } else {
+        let writer = |f: &mut W, index| write!(f, "{}", bytes[index]);
+
+        write_vec(f, writer, None, bytes.len(), "None", false)
+    }
+}
+
+impl<T: ViewType + ?Sized> Debug for BinaryViewArrayGeneric<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let writer = |f: &mut Formatter, index| write_value(self, index, f);
+
+        let head = if T::IS_UTF8 {
+            "Utf8ViewArray"
+        } else {
+            "BinaryViewArray"
+        };
+        write!(f, "{}", head)?;
+        write_vec(f, writer, self.validity(), self.len(), "None", false)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::bitmap::utils::{zip_validity, ZipValidity};
+use crate::trusted_len::TrustedLen;
+
+use super::{BinaryViewArrayGeneric, ViewType};
+
+/// Iterator of values of a [`BinaryViewArrayGeneric`].
+#[derive(Debug, Clone)]
+pub struct BinaryViewValueIter<'a, T: ViewType + ?Sized> {
+    array: &'a BinaryViewArrayGeneric<T>,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, T: ViewType + ?Sized> BinaryViewValueIter<'a, T> {
+    /// Creates a new [`BinaryViewValueIter`]
+    pub fn new(array: &'a BinaryViewArrayGeneric<T>) -> Self {
+        Self {
+            array,
+            index: 0,
+            end: array.len(),
+        }
+    }
+}
+
+impl<'a, T: ViewType + ?Sized> Iterator for BinaryViewValueIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            return None;
+        }
+        let old = self.index;
+        self.index += 1;
+        Some(unsafe { self.array.value_unchecked(old) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.index, Some(self.end - self.index))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let new_index = self.index + n;
+        if new_index > self.end {
+            self.index = self.end;
+            None
+        } else {
+            self.index = new_index;
+            self.next()
+        }
+    }
+}
+
+impl<'a, T: ViewType + ?Sized> DoubleEndedIterator for BinaryViewValueIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(unsafe { self.array.value_unchecked(self.end) })
+        }
+    }
+}
+
+impl<'a, T: ViewType + ?Sized> IntoIterator for &'a BinaryViewArrayGeneric<T> {
+    type Item = Option<&'a T>;
+    type IntoIter = ZipValidity<'a, &'a T, BinaryViewValueIter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: ViewType + ?Sized> BinaryViewArrayGeneric<T> {
+    /// Returns an iterator of `Option<&T>`
+    pub fn iter(&'a self) -> ZipValidity<'a, &'a T, BinaryViewValueIter<'a, T>> {
+        zip_validity(
+            BinaryViewValueIter::new(self),
+            self.validity.as_ref().map(|x| x.iter()),
+        )
+    }
+
+    /// Returns an iterator of `&T`
+    pub fn values_iter(&'a self) -> BinaryViewValueIter<'a, T> {
+        BinaryViewValueIter::new(self)
+    }
+}
+
+unsafe impl<T: ViewType + ?Sized> TrustedLen for BinaryViewValueIter<'_, T> {}
@@ -0,0 +1,416 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use crate::{
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::Array;
+
+mod ffi;
+pub(super) mod fmt;
+mod iterator;
+mod mutable;
+mod view;
+pub use iterator::*;
+pub use mutable::*;
+pub use view::{View, MAX_INLINE_SIZE};
+
+mod private {
+    pub trait Sealed: Send + Sync {}
+
+    impl Sealed for str {}
+    impl Sealed for [u8] {}
+}
+
+/// Trait denoting the types of values that can be stored in a [`BinaryViewArrayGeneric`]:
+/// `str` ([`Utf8ViewArray`]) and `[u8]` ([`BinaryViewArray`]).
+pub trait ViewType: private::Sealed + PartialEq + std::fmt::Debug + 'static {
+    /// Whether the values are utf8
+    const IS_UTF8: bool;
+    /// The default logical type of arrays of this type
+    const DATA_TYPE: DataType;
+    /// The owned version of this type
+    type Owned: std::fmt::Debug + Clone + PartialEq + Send + Sync;
+
+    /// Interprets `bytes` as `&Self`.
+    /// # Safety
+    /// The caller must ensure that `bytes` are valid utf8 when `Self::IS_UTF8`.
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self;
+
+    /// Returns the bytes of `self`.
+    fn to_bytes(&self) -> &[u8];
+
+    /// Returns an owned version of `self`.
+    fn to_owned_value(&self) -> Self::Owned;
+}
+
+impl ViewType for str {
+    const IS_UTF8: bool = true;
+    const DATA_TYPE: DataType = DataType::Utf8View;
+    type Owned = String;
+
+    #[inline]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        std::str::from_utf8_unchecked(bytes)
+    }
+
+    #[inline]
+    fn to_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    #[inline]
+    fn to_owned_value(&self) -> Self::Owned {
+        self.to_string()
+    }
+}
+
+impl ViewType for [u8] {
+    const IS_UTF8: bool = false;
+    const DATA_TYPE: DataType = DataType::BinaryView;
+    type Owned = Vec<u8>;
+
+    #[inline]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        bytes
+    }
+
+    #[inline]
+    fn to_bytes(&self) -> &[u8] {
+        self
+    }
+
+    #[inline]
+    fn to_owned_value(&self) -> Self::Owned {
+        self.to_vec()
+    }
+}
+
+/// An [`Array`] of variable-length values represented by views, arrow's equivalent of
+/// an immutable `Vec<Option<T::Owned>>`.
+///
+/// Every slot is a 16-byte [`View`] that either inlines its value (when it is at most
+/// [`MAX_INLINE_SIZE`] bytes long) or points to a range of one of multiple data buffers.
+/// Contrarily to [`Utf8Array`](super::Utf8Array), values can be shared between slots
+/// and data buffers can be re-used across arrays.
+///
+/// Cloning and slicing this struct is `O(1)`.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8ViewArray;
+/// # fn main() {
+/// let array = Utf8ViewArray::from([Some("hi"), None, Some("a string longer than 12 bytes")]);
+/// assert_eq!(array.value(0), "hi");
+/// assert_eq!(array.value(2), "a string longer than 12 bytes");
+/// assert_eq!(array.data_buffers().len(), 1);
+/// # }
+/// ```
+/// # Safety
+/// The following invariants hold:
+/// * Every non-inlined view points to a valid range of one of the `buffers`.
+/// * When `T` is `str`, the values are valid `utf8`.
+/// * `len` is equal to `validity.len()`, when defined.
+pub struct BinaryViewArrayGeneric<T: ViewType + ?Sized> {
+    data_type: DataType,
+    views: Buffer<i128>,
+    buffers: Arc<[Buffer<u8>]>,
+    validity: Option<Bitmap>,
+    phantom: PhantomData<T>,
+}
+
+/// A [`BinaryViewArrayGeneric`] of utf8 values.
+pub type Utf8ViewArray = BinaryViewArrayGeneric<str>;
+/// A [`BinaryViewArrayGeneric`] of opaque binary values.
+pub type BinaryViewArray = BinaryViewArrayGeneric<[u8]>;
+
+impl<T: ViewType + ?Sized> Clone for BinaryViewArrayGeneric<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data_type: self.data_type.clone(),
+            views: self.views.clone(),
+            buffers: self.buffers.clone(),
+            validity: self.validity.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+// constructors
+impl<T: ViewType + ?Sized> BinaryViewArrayGeneric<T> {
+    /// Returns a new [`BinaryViewArrayGeneric`].
+    /// # Errors
+    /// This function returns an error iff:
+    /// * The `data_type`'s [`crate::datatypes::PhysicalType`] is not equal to `T`'s.
+    /// * A non-inlined view does not point to a valid range of `buffers`, or its prefix
+    ///   does not match the data.
+    /// * `T` is `str` and a value is not valid utf8.
+    /// * the validity's length is not equal to `views.len()`.
+    /// # Implementation
+    /// This function is `O(N)` - checking views and utf8 is `O(N)`
+    pub fn try_new(
+        data_type: DataType,
+        views: Buffer<i128>,
+        buffers: Arc<[Buffer<u8>]>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self> {
+        view::validate_views(&views, &buffers, T::IS_UTF8)?;
+        // Safety: views were validated above
+        unsafe { Self::try_new_unchecked(data_type, views, buffers, validity) }
+    }
+
+    /// Creates a new [`BinaryViewArrayGeneric`].
+    /// # Panics
+    /// This function panics iff [`BinaryViewArrayGeneric::try_new`] errors.
+    pub fn new(
+        data_type: DataType,
+        views: Buffer<i128>,
+        buffers: Arc<[Buffer<u8>]>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        Self::try_new(data_type, views, buffers, validity).unwrap()
+    }
+
+    /// Returns a new empty [`BinaryViewArrayGeneric`].
+    #[inline]
+    pub fn new_empty(data_type: DataType) -> Self {
+        unsafe { Self::new_unchecked(data_type, Buffer::new(), Arc::new([]), None) }
+    }
+
+    /// Returns a new [`BinaryViewArrayGeneric`] whose all slots are null / `None`.
+    #[inline]
+    pub fn new_null(data_type: DataType, length: usize) -> Self {
+        unsafe {
+            Self::new_unchecked(
+                data_type,
+                Buffer::new_zeroed(length),
+                Arc::new([]),
+                Some(Bitmap::new_zeroed(length)),
+            )
+        }
+    }
+
+    /// Returns the default [`DataType`], `DataType::Utf8View` or `DataType::BinaryView`
+    pub fn default_data_type() -> DataType {
+        T::DATA_TYPE
+    }
+}
+
+// unsafe constructors
+impl<T: ViewType + ?Sized> BinaryViewArrayGeneric<T> {
+    /// Creates a new [`BinaryViewArrayGeneric`] without checking its views.
+    /// # Errors
+    /// This function returns an error iff:
+    /// * The `data_type`'s [`crate::datatypes::PhysicalType`] is not equal to `T`'s.
+    /// * the validity's length is not equal to `views.len()`.
+    /// # Safety
+    /// This function is unsound iff:
+    /// * A non-inlined view does not point to a valid range of `buffers`
+    /// * `T` is `str` and a value is not valid utf8
+    /// # Implementation
+    /// This function is `O(1)`
+    pub unsafe fn try_new_unchecked(
+        data_type: DataType,
+        views: Buffer<i128>,
+        buffers: Arc<[Buffer<u8>]>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self> {
+        if matches!(&validity, Some(validity) if validity.len() != views.len()) {
+            return Err(ArrowError::oos(
+                "validity mask length must match the number of values",
+            ));
+        }
+
+        if data_type.to_physical_type() != T::DATA_TYPE.to_physical_type() {
+            return Err(ArrowError::oos(
+                "BinaryViewArray can only be initialized with DataType::Utf8View or DataType::BinaryView",
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            views,
+            buffers,
+            validity,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Creates a new [`BinaryViewArrayGeneric`] without checking its views.
+    /// # Panics
+    /// This function panics iff [`BinaryViewArrayGeneric::try_new_unchecked`] errors.
+    /// # Safety
+    /// This function is unsound iff:
+    /// * A non-inlined view does not point to a valid range of `buffers`
+    /// * `T` is `str` and a value is not valid utf8
+    pub unsafe fn new_unchecked(
+        data_type: DataType,
+        views: Buffer<i128>,
+        buffers: Arc<[Buffer<u8>]>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        Self::try_new_unchecked(data_type, views, buffers, validity).unwrap()
+    }
+}
+
+// must use
+impl<T: ViewType + ?Sized> BinaryViewArrayGeneric<T> {
+    /// Returns a slice of this [`BinaryViewArrayGeneric`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to essentially increase ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new Buffer cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Returns a slice of this [`BinaryViewArrayGeneric`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to essentially increase ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        let validity = self
+            .validity
+            .clone()
+            .map(|x| x.slice_unchecked(offset, length));
+        Self {
+            data_type: self.data_type.clone(),
+            views: self.views.clone().slice_unchecked(offset, length),
+            buffers: self.buffers.clone(),
+            validity,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Sets the validity bitmap on this [`BinaryViewArrayGeneric`].
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
+            panic!("validity should be as least as large as the array")
+        }
+        let mut arr = self.clone();
+        arr.validity = validity;
+        arr
+    }
+}
+
+// Accessors
+impl<T: ViewType + ?Sized> BinaryViewArrayGeneric<T> {
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Returns whether this array is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the element at index `i`
+    /// # Panics
+    /// iff `i >= self.len()`
+    #[inline]
+    pub fn value(&self, i: usize) -> &T {
+        assert!(i < self.len());
+        unsafe { self.value_unchecked(i) }
+    }
+
+    /// Returns the element at index `i`
+    /// # Safety
+    /// This function is safe iff `i < self.len`.
+    #[inline]
+    pub unsafe fn value_unchecked(&self, i: usize) -> &T {
+        // soundness: the invariant of the struct
+        let bytes = view::get_bytes(self.views.get_unchecked(i), &self.buffers);
+        T::from_bytes_unchecked(bytes)
+    }
+
+    /// Returns the [`View`] of the element at index `i`
+    /// # Panics
+    /// iff `i >= self.len()`
+    #[inline]
+    pub fn view(&self, i: usize) -> View {
+        self.views[i].into()
+    }
+
+    /// The optional validity.
+    #[inline]
+    pub fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    /// Returns the views of this array, in their physical representation.
+    /// Use [`View::from`] to decode them.
+    #[inline]
+    pub fn views(&self) -> &Buffer<i128> {
+        &self.views
+    }
+
+    /// Returns the data buffers that the non-inlined views of this array point to.
+    #[inline]
+    pub fn data_buffers(&self) -> &Arc<[Buffer<u8>]> {
+        &self.buffers
+    }
+}
+
+impl<T: ViewType + ?Sized> BinaryViewArrayGeneric<T> {
+    /// Creates a new [`BinaryViewArrayGeneric`] from a slice of values.
+    pub fn from_slice<V: AsRef<T>, P: AsRef<[V]>>(slice: P) -> Self {
+        MutableBinaryViewArray::<T>::from_values_iter(slice.as_ref().iter()).into()
+    }
+
+    /// Creates a new [`BinaryViewArrayGeneric`] from a slice of optional values.
+    // Note: this can't be `impl From` because Rust does not allow double `AsRef` on it.
+    pub fn from<V: AsRef<T>, P: AsRef<[Option<V>]>>(slice: P) -> Self {
+        MutableBinaryViewArray::<T>::from_iter(slice.as_ref().iter().map(|x| x.as_ref())).into()
+    }
+}
+
+impl<T: ViewType + ?Sized> Array for BinaryViewArrayGeneric<T> {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice(offset, length))
+    }
+
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+
+    fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
+        Box::new(self.with_validity(validity))
+    }
+
+    fn to_boxed(&self) -> Box<dyn Array> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,283 @@
+use std::{iter::FromIterator, marker::PhantomData, sync::Arc};
+
+use crate::{
+    array::{Array, MutableArray, TryExtend, TryPush},
+    bitmap::MutableBitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::{BinaryViewArrayGeneric, View, ViewType, MAX_INLINE_SIZE};
+
+const DEFAULT_BLOCK_SIZE: usize = 8 * 1024;
+const MAX_BLOCK_SIZE: usize = 16 * 1024 * 1024;
+
+/// The mutable version of [`BinaryViewArrayGeneric`]. See [`MutableArray`] for more details.
+///
+/// Values longer than [`MAX_INLINE_SIZE`] are appended to an in-progress data buffer whose
+/// capacity doubles (up to 16MiB) every time it is full.
+#[derive(Debug)]
+pub struct MutableBinaryViewArray<T: ViewType + ?Sized> {
+    data_type: DataType,
+    views: Vec<i128>,
+    completed_buffers: Vec<Buffer<u8>>,
+    in_progress_buffer: Vec<u8>,
+    validity: Option<MutableBitmap>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ViewType + ?Sized> From<MutableBinaryViewArray<T>> for BinaryViewArrayGeneric<T> {
+    fn from(mut other: MutableBinaryViewArray<T>) -> Self {
+        other.finish_in_progress();
+        // Safety:
+        // `MutableBinaryViewArray` has the same invariants as `BinaryViewArrayGeneric` and thus
+        // `BinaryViewArrayGeneric` can be safely created from `MutableBinaryViewArray` without checks.
+        unsafe {
+            BinaryViewArrayGeneric::<T>::new_unchecked(
+                other.data_type,
+                other.views.into(),
+                other.completed_buffers.into(),
+                other.validity.map(|x| x.into()),
+            )
+        }
+    }
+}
+
+impl<T: ViewType + ?Sized> Default for MutableBinaryViewArray<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ViewType + ?Sized> MutableBinaryViewArray<T> {
+    /// Initializes a new empty [`MutableBinaryViewArray`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Initializes a new [`MutableBinaryViewArray`] with a pre-allocated capacity of slots.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            data_type: T::DATA_TYPE,
+            views: Vec::with_capacity(capacity),
+            completed_buffers: vec![],
+            in_progress_buffer: vec![],
+            validity: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.views.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Returns whether this array is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+
+    /// Returns its views.
+    pub fn views(&self) -> &Vec<i128> {
+        &self.views
+    }
+
+    fn init_validity(&mut self) {
+        let mut validity = MutableBitmap::with_capacity(self.views.capacity());
+        validity.extend_constant(self.len(), true);
+        validity.set(self.len() - 1, false);
+        self.validity = Some(validity);
+    }
+
+    fn finish_in_progress(&mut self) {
+        if !self.in_progress_buffer.is_empty() {
+            let buffer = std::mem::take(&mut self.in_progress_buffer);
+            self.completed_buffers.push(buffer.into());
+        }
+    }
+
+    /// Pushes a new value to the array.
+    /// # Errors
+    /// iff the value is longer than `u32::MAX` bytes.
+    pub fn try_push_value<V: AsRef<T>>(&mut self, value: V) -> Result<()> {
+        let bytes = value.as_ref().to_bytes();
+        let length = u32::try_from(bytes.len()).map_err(|_| ArrowError::Overflow)?;
+
+        let view = if length <= MAX_INLINE_SIZE {
+            View::new(bytes, 0, 0)
+        } else {
+            let required = self.in_progress_buffer.len() + bytes.len();
+            if self.in_progress_buffer.capacity() < required || required > u32::MAX as usize {
+                let capacity = (self.in_progress_buffer.capacity() * 2)
+                    .clamp(DEFAULT_BLOCK_SIZE, MAX_BLOCK_SIZE)
+                    .max(bytes.len());
+                let in_progress = Vec::with_capacity(capacity);
+                let buffer = std::mem::replace(&mut self.in_progress_buffer, in_progress);
+                if !buffer.is_empty() {
+                    self.completed_buffers.push(buffer.into());
+                }
+            }
+            let offset = self.in_progress_buffer.len() as u32;
+            self.in_progress_buffer.extend_from_slice(bytes);
+            let buffer_idx =
+                u32::try_from(self.completed_buffers.len()).map_err(|_| ArrowError::Overflow)?;
+            View::new(bytes, buffer_idx, offset)
+        };
+        self.views.push(view.as_i128());
+
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    /// Pushes a new value to the array.
+    /// # Panics
+    /// iff the value is longer than `u32::MAX` bytes.
+    #[inline]
+    pub fn push_value<V: AsRef<T>>(&mut self, value: V) {
+        self.try_push_value(value).unwrap()
+    }
+
+    /// Pushes a new optional value to the array.
+    /// # Panics
+    /// iff the value is longer than `u32::MAX` bytes.
+    #[inline]
+    pub fn push<V: AsRef<T>>(&mut self, value: Option<V>) {
+        self.try_push(value).unwrap()
+    }
+
+    /// Pushes a null value to the array.
+    #[inline]
+    pub fn push_null(&mut self) {
+        self.views.push(0);
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    /// Converts itself into an [`Array`].
+    pub fn into_arc(self) -> Arc<dyn Array> {
+        let a: BinaryViewArrayGeneric<T> = self.into();
+        Arc::new(a)
+    }
+
+    /// Shrinks the capacity of the [`MutableBinaryViewArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.views.shrink_to_fit();
+        self.in_progress_buffer.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+
+    /// Creates a new [`MutableBinaryViewArray`] from an iterator of values.
+    pub fn from_values_iter<V: AsRef<T>, I: Iterator<Item = V>>(iterator: I) -> Self {
+        let mut array = Self::with_capacity(iterator.size_hint().0);
+        iterator.for_each(|x| array.push_value(x));
+        array
+    }
+
+    fn take(&mut self) -> BinaryViewArrayGeneric<T> {
+        let data_type = self.data_type.clone();
+        let other = std::mem::take(self);
+        self.data_type = data_type;
+        other.into()
+    }
+}
+
+impl<T: ViewType + ?Sized> MutableBinaryViewArray<T> {
+    // truncates this array to `len` slots. The bytes of the removed views are kept in the buffers.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.views.truncate(len);
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+    }
+}
+
+impl<T: ViewType + ?Sized> MutableArray for MutableBinaryViewArray<T> {
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.take())
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.take())
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
+
+impl<T: ViewType + ?Sized, V: AsRef<T>> FromIterator<Option<V>> for MutableBinaryViewArray<T> {
+    fn from_iter<I: IntoIterator<Item = Option<V>>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<T: ViewType + ?Sized, V: AsRef<T>> Extend<Option<V>> for MutableBinaryViewArray<T> {
+    fn extend<I: IntoIterator<Item = Option<V>>>(&mut self, iter: I) {
+        self.try_extend(iter).unwrap();
+    }
+}
+
+impl<T: ViewType + ?Sized, V: AsRef<T>> TryExtend<Option<V>> for MutableBinaryViewArray<T> {
+    fn try_extend<I: IntoIterator<Item = Option<V>>>(&mut self, iter: I) -> Result<()> {
+        let mut iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.try_for_each(|x| self.try_push(x))
+    }
+}
+
+impl<T: ViewType + ?Sized, V: AsRef<T>> TryPush<Option<V>> for MutableBinaryViewArray<T> {
+    #[inline]
+    fn try_push(&mut self, value: Option<V>) -> Result<()> {
+        match value {
+            Some(value) => self.try_push_value(value),
+            None => {
+                self.push_null();
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::buffer::Buffer;
+use crate::error::{ArrowError, Result};
+
+/// The maximum number of bytes that a [`View`] stores inline.
+pub const MAX_INLINE_SIZE: u32 = 12;
+
+/// The in-memory representation of a single slot of a
+/// [`BinaryViewArrayGeneric`](super::BinaryViewArrayGeneric).
+///
+/// A view is 16 bytes long. Its first 4 bytes are the `length` of the value. When
+/// `length <= 12`, the value is stored inline in the remaining 12 bytes. Otherwise, the value
+/// is stored in the data buffer `buffer_idx` at `offset`, and `prefix` contains its first 4 bytes.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Zeroable, Pod)]
+#[repr(C)]
+pub struct View {
+    /// The length of the value, in bytes
+    pub length: u32,
+    /// The first 4 bytes of the value
+    pub prefix: u32,
+    /// The index of the data buffer containing the value (only meaningful when not inlined)
+    pub buffer_idx: u32,
+    /// The offset of the value in its data buffer (only meaningful when not inlined)
+    pub offset: u32,
+}
+
+impl View {
+    /// Creates a new [`View`] of `bytes`, inlining it if it is short enough and
+    /// pointing to `offset` of the data buffer `buffer_idx` otherwise.
+    /// # Panics
+    /// iff `bytes.len() > u32::MAX`.
+    #[inline]
+    pub fn new(bytes: &[u8], buffer_idx: u32, offset: u32) -> Self {
+        let length = u32::try_from(bytes.len()).unwrap();
+        if length <= MAX_INLINE_SIZE {
+            let mut data = [0u8; 16];
+            data[..4].copy_from_slice(&length.to_ne_bytes());
+            data[4..4 + bytes.len()].copy_from_slice(bytes);
+            bytemuck::cast(data)
+        } else {
+            let mut prefix = [0u8; 4];
+            prefix.copy_from_slice(&bytes[..4]);
+            Self {
+                length,
+                prefix: u32::from_ne_bytes(prefix),
+                buffer_idx,
+                offset,
+            }
+        }
+    }
+
+    /// Whether the value of this [`View`] is stored inline.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.length <= MAX_INLINE_SIZE
+    }
+
+    /// Returns this view as its physical representation.
+    #[inline]
+    pub fn as_i128(self) -> i128 {
+        bytemuck::cast(self)
+    }
+}
+
+impl From<i128> for View {
+    #[inline]
+    fn from(value: i128) -> Self {
+        bytemuck::cast(value)
+    }
+}
+
+impl From<View> for i128 {
+    #[inline]
+    fn from(value: View) -> Self {
+        value.as_i128()
+    }
+}
+
+/// Returns the bytes that `view` refers to.
+/// # Safety
+/// The caller must ensure that `view` is in bounds of `buffers`.
+#[inline]
+pub(super) unsafe fn get_bytes<'a>(view: &'a i128, buffers: &'a [Buffer<u8>]) -> &'a [u8] {
+    let decoded = View::from(*view);
+    if decoded.is_inline() {
+        let bytes: &[u8] = bytemuck::bytes_of(view);
+        bytes.get_unchecked(4..4 + decoded.length as usize)
+    } else {
+        let start = decoded.offset as usize;
+        buffers
+            .get_unchecked(decoded.buffer_idx as usize)
+            .get_unchecked(start..start + decoded.length as usize)
+    }
+}
+
+/// Checks that every non-inlined view of `views` is in bounds of `buffers` and that its prefix
+/// matches the data it points to. When `is_utf8`, also checks that every value is valid utf8.
+pub(super) fn validate_views(views: &[i128], buffers: &[Buffer<u8>], is_utf8: bool) -> Result<()> {
+    for view in views {
+        let decoded = View::from(*view);
+        if !decoded.is_inline() {
+            let buffer = buffers
+                .get(decoded.buffer_idx as usize)
+                .ok_or_else(|| ArrowError::oos("a view must point to an existing data buffer"))?;
+            let start = decoded.offset as usize;
+            let end = start + decoded.length as usize;
+            let data = buffer.get(start..end).ok_or_else(|| {
+                ArrowError::oos("a view must point to a range within its data buffer")
+            })?;
+            if data[..4] != decoded.prefix.to_ne_bytes() {
+                return Err(ArrowError::oos(
+                    "the prefix of a view must match the first 4 bytes of its data",
+                ));
+            }
+        }
+        if is_utf8 {
+            // Safety: bounds were checked above
+            let bytes = unsafe { get_bytes(view, buffers) };
+            simdutf8::basic::from_utf8(bytes)?;
+        }
+    }
+    Ok(())
+}
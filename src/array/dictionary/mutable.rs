@@ -4,10 +4,14 @@ use std::{collections::hash_map::DefaultHasher, sync::Arc};
 use hash_hasher::HashedMap;
 
 use crate::{
-    array::{primitive::MutablePrimitiveArray, Array, MutableArray, TryExtend, TryPush},
+    array::{
+        mutable::hash_scalar, primitive::MutablePrimitiveArray, Array, MutableArray, TryExtend,
+        TryPush, TryPushScalar,
+    },
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
+    scalar::Scalar,
 };
 
 use super::{DictionaryArray, DictionaryKey};
@@ -143,6 +147,48 @@ impl<K: DictionaryKey, M: MutableArray> MutableDictionaryArray<K, M> {
     }
 }
 
+impl<K: DictionaryKey> MutableDictionaryArray<K, Box<dyn MutableArray>> {
+    // an empty array of `data_type` whose (empty) values are `values`
+    pub(crate) fn new_from(
+        values: Box<dyn MutableArray>,
+        data_type: DataType,
+        capacity: usize,
+    ) -> Self {
+        Self {
+            data_type,
+            keys: MutablePrimitiveArray::<K>::with_capacity(capacity),
+            map: HashedMap::default(),
+            values,
+        }
+    }
+
+    // pushes the valid `scalar`, a scalar of the values, pushing it to the values when it is new
+    pub(crate) fn try_push_scalar(&mut self, scalar: &dyn Scalar) -> Result<()> {
+        let mut hasher = DefaultHasher::new();
+        hash_scalar(scalar, &mut hasher);
+        let hash = hasher.finish();
+        let key = match self.map.get(&hash) {
+            Some(key) => *key,
+            None => {
+                let key = K::from_usize(self.map.len()).ok_or(ArrowError::Overflow)?;
+                self.values.as_mut().try_push_scalar(scalar)?;
+                self.map.insert(hash, key);
+                key
+            }
+        };
+        self.keys.push(Some(key));
+        Ok(())
+    }
+
+    // truncates this array to `len` slots. The values of the removed keys are kept.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        while self.keys.len() > len {
+            self.keys.pop();
+        }
+        crate::array::mutable::truncate(self.values.as_mut(), self.map.len());
+    }
+}
+
 impl<K: DictionaryKey, M: 'static + MutableArray> MutableArray for MutableDictionaryArray<K, M> {
     fn len(&self) -> usize {
         self.keys.len()
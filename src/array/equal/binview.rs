@@ -0,0 +1,8 @@
+use crate::array::{Array, BinaryViewArrayGeneric, ViewType};
+
+pub(super) fn equal<T: ViewType + ?Sized>(
+    lhs: &BinaryViewArrayGeneric<T>,
+    rhs: &BinaryViewArrayGeneric<T>,
+) -> bool {
+    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+}
@@ -3,6 +3,7 @@ use crate::types::NativeType;
 use super::*;
 
 mod binary;
+mod binview;
 mod boolean;
 mod dictionary;
 mod fixed_size_binary;
@@ -11,6 +12,7 @@ mod list;
 mod map;
 mod null;
 mod primitive;
+mod run_end_encoded;
 mod struct_;
 mod union;
 mod utf8;
@@ -93,6 +95,18 @@ impl<O: Offset> PartialEq<&dyn Array> for BinaryArray<O> {
     }
 }
 
+impl<T: ViewType + ?Sized> PartialEq<BinaryViewArrayGeneric<T>> for BinaryViewArrayGeneric<T> {
+    fn eq(&self, other: &Self) -> bool {
+        binview::equal(self, other)
+    }
+}
+
+impl<T: ViewType + ?Sized> PartialEq<&dyn Array> for BinaryViewArrayGeneric<T> {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 impl PartialEq<FixedSizeBinaryArray> for FixedSizeBinaryArray {
     fn eq(&self, other: &Self) -> bool {
         fixed_size_binary::equal(self, other)
@@ -153,6 +167,18 @@ impl<K: DictionaryKey> PartialEq<&dyn Array> for DictionaryArray<K> {
     }
 }
 
+impl<R: RunEndIndex> PartialEq<RunEndEncodedArray<R>> for RunEndEncodedArray<R> {
+    fn eq(&self, other: &Self) -> bool {
+        run_end_encoded::equal(self, other)
+    }
+}
+
+impl<R: RunEndIndex> PartialEq<&dyn Array> for RunEndEncodedArray<R> {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 impl PartialEq<UnionArray> for UnionArray {
     fn eq(&self, other: &Self) -> bool {
         union::equal(self, other)
@@ -165,6 +191,18 @@ impl PartialEq<&dyn Array> for UnionArray {
     }
 }
 
+impl PartialEq<MapArray> for MapArray {
+    fn eq(&self, other: &Self) -> bool {
+        map::equal(self, other)
+    }
+}
+
+impl PartialEq<&dyn Array> for MapArray {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 /// Logically compares two [`Array`]s.
 /// Two arrays are logically equal if and only if:
 /// * their data types are equal
@@ -211,6 +249,16 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             binary::equal::<i64>(lhs, rhs)
         }
+        BinaryView => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            binview::equal::<[u8]>(lhs, rhs)
+        }
+        Utf8View => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            binview::equal::<str>(lhs, rhs)
+        }
         List => {
             let lhs = lhs.as_any().downcast_ref().unwrap();
             let rhs = rhs.as_any().downcast_ref().unwrap();
@@ -253,5 +301,12 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             map::equal(lhs, rhs)
         }
+        RunEndEncoded(run_end_type) => {
+            match_run_end_type!(run_end_type, |$T| {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                run_end_encoded::equal::<$T>(lhs, rhs)
+            })
+        }
     }
 }
@@ -0,0 +1,8 @@
+use crate::array::{Array, RunEndEncodedArray, RunEndIndex};
+
+pub(super) fn equal<R: RunEndIndex>(
+    lhs: &RunEndEncodedArray<R>,
+    rhs: &RunEndEncodedArray<R>,
+) -> bool {
+    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+}
@@ -67,6 +67,8 @@ pub fn offset_buffers_children_dictionary(array: &dyn Array) -> BuffersChildren
         FixedSizeBinary => ffi_dyn!(array, FixedSizeBinaryArray),
         Utf8 => ffi_dyn!(array, Utf8Array::<i32>),
         LargeUtf8 => ffi_dyn!(array, Utf8Array::<i64>),
+        BinaryView => ffi_dyn!(array, BinaryViewArray),
+        Utf8View => ffi_dyn!(array, Utf8ViewArray),
         List => ffi_dyn!(array, ListArray::<i32>),
         LargeList => ffi_dyn!(array, ListArray::<i64>),
         FixedSizeList => ffi_dyn!(array, FixedSizeListArray),
@@ -84,5 +86,11 @@ pub fn offset_buffers_children_dictionary(array: &dyn Array) -> BuffersChildren
                 )
             })
         }
+        RunEndEncoded(run_end_type) => {
+            match_run_end_type!(run_end_type, |$T| {
+                let array = array.as_any().downcast_ref::<RunEndEncodedArray<$T>>().unwrap();
+                (array.offset(), array.buffers(), array.children(), None)
+            })
+        }
     }
 }
@@ -105,6 +105,16 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
     }
 }
 
+impl MutableFixedSizeListArray<Box<dyn MutableArray>> {
+    // truncates this array to `len` slots and its values to the values of these slots
+    pub(crate) fn truncate(&mut self, len: usize) {
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+        crate::array::mutable::truncate(self.values.as_mut(), len * self.size);
+    }
+}
+
 impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
     fn len(&self) -> usize {
         self.values.len() / self.size
@@ -58,6 +58,20 @@ pub fn get_value_display<'a, F: Write + 'a>(
                 f,
             )
         }),
+        BinaryView => Box::new(|f, index| {
+            super::binview::fmt::write_value::<[u8], _>(
+                array.as_any().downcast_ref().unwrap(),
+                index,
+                f,
+            )
+        }),
+        Utf8View => Box::new(|f, index| {
+            super::binview::fmt::write_value::<str, _>(
+                array.as_any().downcast_ref().unwrap(),
+                index,
+                f,
+            )
+        }),
         List => Box::new(move |f, index| {
             super::list::fmt::write_value::<i32, _>(
                 array.as_any().downcast_ref().unwrap(),
@@ -94,6 +108,11 @@ pub fn get_value_display<'a, F: Write + 'a>(
                 super::dictionary::fmt::write_value::<$T,_>(array.as_any().downcast_ref().unwrap(), index, null, f)
             })
         }),
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            Box::new(move |f, index| {
+                super::run_end_encoded::fmt::write_value::<$T,_>(array.as_any().downcast_ref().unwrap(), index, null, f)
+            })
+        }),
     }
 }
 
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, BinaryViewArrayGeneric, View, ViewType},
+    bitmap::MutableBitmap,
+    buffer::Buffer,
+};
+
+use super::{
+    utils::{build_extend_null_bits, ExtendNullBits},
+    Growable,
+};
+
+/// Concrete [`Growable`] for the [`BinaryViewArrayGeneric`].
+/// # Implementation
+/// The data buffers of all arrays are shared by the resulting array: only the views are copied.
+pub struct GrowableBinaryViewArray<'a, T: ViewType + ?Sized> {
+    arrays: Vec<&'a BinaryViewArrayGeneric<T>>,
+    validity: MutableBitmap,
+    views: Vec<i128>,
+    buffers: Vec<Buffer<u8>>,
+    // the index in `buffers` of the first data buffer of each array
+    buffers_offsets: Vec<u32>,
+    extend_null_bits: Vec<ExtendNullBits<'a>>,
+}
+
+impl<'a, T: ViewType + ?Sized> GrowableBinaryViewArray<'a, T> {
+    /// Creates a new [`GrowableBinaryViewArray`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(
+        arrays: Vec<&'a BinaryViewArrayGeneric<T>>,
+        mut use_validity: bool,
+        capacity: usize,
+    ) -> Self {
+        // if any of the arrays has nulls, insertions from any array requires setting bits
+        // as there is at least one array with nulls.
+        if arrays.iter().any(|array| array.null_count() > 0) {
+            use_validity = true;
+        };
+
+        let extend_null_bits = arrays
+            .iter()
+            .map(|array| build_extend_null_bits(*array, use_validity))
+            .collect();
+
+        let mut buffers = vec![];
+        let buffers_offsets = arrays
+            .iter()
+            .map(|array| {
+                let offset = buffers.len() as u32;
+                buffers.extend(array.data_buffers().iter().cloned());
+                offset
+            })
+            .collect();
+
+        Self {
+            arrays,
+            validity: MutableBitmap::with_capacity(capacity),
+            views: Vec::with_capacity(capacity),
+            buffers,
+            buffers_offsets,
+            extend_null_bits,
+        }
+    }
+
+    fn to(&mut self) -> BinaryViewArrayGeneric<T> {
+        let validity = std::mem::take(&mut self.validity);
+        let views = std::mem::take(&mut self.views);
+        let buffers = std::mem::take(&mut self.buffers);
+
+        // Safety: every view points to the same data as in its original array
+        unsafe {
+            BinaryViewArrayGeneric::<T>::new_unchecked(
+                self.arrays[0].data_type().clone(),
+                views.into(),
+                buffers.into(),
+                validity.into(),
+            )
+        }
+    }
+}
+
+impl<'a, T: ViewType + ?Sized> Growable<'a> for GrowableBinaryViewArray<'a, T> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        (self.extend_null_bits[index])(&mut self.validity, start, len);
+
+        let array = self.arrays[index];
+        let buffers_offset = self.buffers_offsets[index];
+
+        let views = &array.views()[start..start + len];
+        self.views.extend(views.iter().map(|view| {
+            let mut view = View::from(*view);
+            if !view.is_inline() {
+                view.buffer_idx += buffers_offset;
+            }
+            view.as_i128()
+        }));
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        self.views.resize(self.views.len() + additional, 0);
+        self.validity.extend_constant(additional, false);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a, T: ViewType + ?Sized> From<GrowableBinaryViewArray<'a, T>> for BinaryViewArrayGeneric<T> {
+    fn from(mut val: GrowableBinaryViewArray<'a, T>) -> Self {
+        val.to()
+    }
+}
@@ -6,6 +6,8 @@ use crate::datatypes::*;
 
 mod binary;
 pub use binary::GrowableBinary;
+mod binview;
+pub use binview::GrowableBinaryViewArray;
 mod union;
 pub use union::GrowableUnion;
 mod boolean;
@@ -18,6 +20,8 @@ mod primitive;
 pub use primitive::GrowablePrimitive;
 mod list;
 pub use list::GrowableList;
+mod map;
+pub use map::GrowableMap;
 mod structure;
 pub use structure::GrowableStruct;
 mod fixed_size_list;
@@ -26,6 +30,8 @@ mod utf8;
 pub use utf8::GrowableUtf8;
 mod dictionary;
 pub use dictionary::GrowableDictionary;
+mod run_end_encoded;
+pub use run_end_encoded::GrowableRunEndEncoded;
 
 mod utils;
 
@@ -97,6 +103,18 @@ pub fn make_growable<'a>(
             use_validity,
             capacity
         ),
+        BinaryView => dyn_growable!(
+            binview::GrowableBinaryViewArray::<[u8]>,
+            arrays,
+            use_validity,
+            capacity
+        ),
+        Utf8View => dyn_growable!(
+            binview::GrowableBinaryViewArray::<str>,
+            arrays,
+            use_validity,
+            capacity
+        ),
         FixedSizeBinary => dyn_growable!(
             fixed_binary::GrowableFixedSizeBinary,
             arrays,
@@ -119,7 +137,7 @@ pub fn make_growable<'a>(
                 .collect::<Vec<_>>();
             Box::new(union::GrowableUnion::new(arrays, capacity))
         }
-        Map => todo!(),
+        Map => dyn_growable!(map::GrowableMap, arrays, use_validity, capacity),
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 let arrays = arrays
@@ -138,5 +156,22 @@ pub fn make_growable<'a>(
                 ))
             })
         }
+        RunEndEncoded(run_end_type) => {
+            match_run_end_type!(run_end_type, |$T| {
+                let arrays = arrays
+                    .iter()
+                    .map(|array| {
+                        array
+                            .as_any()
+                            .downcast_ref::<RunEndEncodedArray<$T>>()
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>();
+                Box::new(run_end_encoded::GrowableRunEndEncoded::<$T>::new(
+                    arrays,
+                    capacity,
+                ))
+            })
+        }
     }
 }
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, RunEndEncodedArray, RunEndIndex},
+    datatypes::DataType,
+};
+
+use super::{make_growable, Growable};
+
+/// Concrete [`Growable`] for the [`RunEndEncodedArray`].
+/// # Implementation
+/// This growable does not merge equal runs coming from different slices; every extension
+/// appends the runs it overlaps to the new array.
+pub struct GrowableRunEndEncoded<'a, R: RunEndIndex> {
+    arrays: Vec<&'a RunEndEncodedArray<R>>,
+    data_type: DataType,
+    run_ends: Vec<R>,
+    length: usize,
+    values: Box<dyn Growable<'a> + 'a>,
+}
+
+impl<'a, R: RunEndIndex> GrowableRunEndEncoded<'a, R> {
+    /// Creates a new [`GrowableRunEndEncoded`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(arrays: Vec<&'a RunEndEncodedArray<R>>, capacity: usize) -> Self {
+        let data_type = arrays[0].data_type().clone();
+
+        // the values must always track validity, since `extend_validity` pushes null runs
+        let values = make_growable(
+            &arrays
+                .iter()
+                .map(|array| array.values().as_ref())
+                .collect::<Vec<_>>(),
+            true,
+            capacity,
+        );
+
+        Self {
+            arrays,
+            data_type,
+            run_ends: Vec::with_capacity(capacity),
+            length: 0,
+            values,
+        }
+    }
+
+    fn push_run(&mut self, length: usize) {
+        self.length += length;
+        self.run_ends
+            .push(R::from_usize(self.length).expect("length to fit the run end type"));
+    }
+
+    fn to(&mut self) -> RunEndEncodedArray<R> {
+        let run_ends = std::mem::take(&mut self.run_ends);
+        self.length = 0;
+        RunEndEncodedArray::<R>::new(
+            self.data_type.clone(),
+            run_ends.into(),
+            self.values.as_arc(),
+        )
+    }
+}
+
+impl<'a, R: RunEndIndex> Growable<'a> for GrowableRunEndEncoded<'a, R> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let array = self.arrays[index];
+        let offset = array.offset();
+        let end = start + len;
+
+        let mut current = start;
+        let mut run = array.physical_index(start);
+        while current < end {
+            let run_end = array.run_ends()[run].to_usize().unwrap() - offset;
+            let run_length = run_end.min(end) - current;
+            self.values.extend(index, run, 1);
+            self.push_run(run_length);
+            current += run_length;
+            run += 1;
+        }
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        if additional == 0 {
+            return;
+        }
+        self.values.extend_validity(1);
+        self.push_run(additional);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a, R: RunEndIndex> From<GrowableRunEndEncoded<'a, R>> for RunEndEncodedArray<R> {
+    fn from(mut val: GrowableRunEndEncoded<'a, R>) -> Self {
+        val.to()
+    }
+}
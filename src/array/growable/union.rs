@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use crate::array::{Array, UnionArray};
+use crate::{
+    array::{Array, UnionArray},
+    datatypes::DataType,
+};
 
 use super::{make_growable, Growable};
 
@@ -10,6 +13,8 @@ pub struct GrowableUnion<'a> {
     types: Vec<i8>,
     offsets: Option<Vec<i32>>,
     fields: Vec<Box<dyn Growable<'a> + 'a>>,
+    // the length of each field, used to compute the offsets of dense unions
+    lengths: Vec<usize>,
 }
 
 impl<'a> GrowableUnion<'a> {
@@ -31,7 +36,8 @@ impl<'a> GrowableUnion<'a> {
                         .iter()
                         .map(|x| x.fields()[i].as_ref())
                         .collect::<Vec<_>>(),
-                    false,
+                    // nulls of the union are null slots of its fields (see `extend_validity`)
+                    true,
                     capacity,
                 )
             })
@@ -39,6 +45,7 @@ impl<'a> GrowableUnion<'a> {
 
         Self {
             arrays,
+            lengths: vec![0; fields.len()],
             fields,
             offsets: if has_offsets {
                 Some(Vec::with_capacity(capacity))
@@ -70,23 +77,41 @@ impl<'a> Growable<'a> for GrowableUnion<'a> {
 
         let types = &array.types()[start..start + len];
         self.types.extend(types);
-        if let Some(x) = self.offsets.as_mut() {
-            let offsets = &array.offsets().unwrap()[start..start + len];
-
-            x.extend(offsets);
+        if let Some(offsets) = self.offsets.as_mut() {
             // in a dense union, each slot has its own offset. We extend the fields accordingly.
-            for (&type_, &offset) in types.iter().zip(offsets.iter()) {
-                self.fields[type_ as usize].extend(index, offset as usize, 1);
+            for i in start..start + len {
+                let (field, slot) = array.index(i);
+                self.fields[field].extend(index, slot, 1);
+                offsets.push(self.lengths[field] as i32);
+                self.lengths[field] += 1;
             }
-        } else {
+        } else if len > 0 {
             // in a sparse union, every field has the same length => extend all fields equally
+            let (_, start) = array.index(start);
             self.fields
                 .iter_mut()
                 .for_each(|field| field.extend(index, start, len))
         }
     }
 
-    fn extend_validity(&mut self, _additional: usize) {}
+    fn extend_validity(&mut self, additional: usize) {
+        // a union has no validity: nulls are represented by null slots of its first field
+        let type_ = match self.arrays[0].data_type().to_logical_type() {
+            DataType::Union(_, Some(ids), _) => ids[0] as i8,
+            _ => 0,
+        };
+        self.types.resize(self.types.len() + additional, type_);
+        if let Some(offsets) = self.offsets.as_mut() {
+            let length = self.lengths[0];
+            offsets.extend((length..length + additional).map(|x| x as i32));
+            self.lengths[0] += additional;
+            self.fields[0].extend_validity(additional);
+        } else {
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend_validity(additional))
+        }
+    }
 
     fn as_arc(&mut self) -> Arc<dyn Array> {
         Arc::new(self.to())
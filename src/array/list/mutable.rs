@@ -199,7 +199,19 @@ impl<O: Offset, M: MutableArray> MutableListArray<O, M> {
     }
 }
 
-impl<O: Offset, M: MutableArray + Default + 'static> MutableArray for MutableListArray<O, M> {
+impl<O: Offset> MutableListArray<O, Box<dyn MutableArray>> {
+    // truncates this array to `len` slots and its values to the values of these slots
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.offsets.truncate(len + 1);
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+        let values_len = self.offsets.last().unwrap().to_usize();
+        crate::array::mutable::truncate(self.values.as_mut(), values_len);
+    }
+}
+
+impl<O: Offset, M: MutableArray + 'static> MutableArray for MutableListArray<O, M> {
     fn len(&self) -> usize {
         self.offsets.len() - 1
     }
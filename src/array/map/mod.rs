@@ -12,6 +12,8 @@ use super::{new_empty_array, specification::try_check_offsets, Array};
 mod ffi;
 mod iterator;
 pub use iterator::*;
+mod mutable;
+pub use mutable::*;
 
 /// An array representing a (key, value), both of arbitrary logical types.
 #[derive(Debug, Clone)]
@@ -148,6 +150,16 @@ impl MapArray {
         }
     }
 
+    /// Returns the default [`DataType`] of a [`MapArray`] whose keys and values are of
+    /// type `keys` and `values`. The values are nullable; the keys are not.
+    pub fn default_datatype(keys: DataType, values: DataType) -> DataType {
+        let entries = DataType::Struct(vec![
+            Field::new("keys", keys, false),
+            Field::new("values", values, true),
+        ]);
+        DataType::Map(Box::new(Field::new("entries", entries, false)), false)
+    }
+
     pub(crate) fn try_get_field(data_type: &DataType) -> Result<&Field, ArrowError> {
         if let DataType::Map(field, _) = data_type.to_logical_type() {
             Ok(field.as_ref())
@@ -0,0 +1,320 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MutableArray, StructArray, TryExtend, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::MapArray;
+
+/// The mutable version of [`MapArray`], whose keys and values are built by
+/// the [`MutableArray`]s `K` and `V`.
+/// # Example
+/// ```
+/// # use arrow2::array::{MapArray, MutableMapArray, MutablePrimitiveArray, MutableUtf8Array, TryPush};
+/// # fn main() -> arrow2::error::Result<()> {
+/// let mut array = MutableMapArray::new(
+///     MutableUtf8Array::<i32>::new(),
+///     MutablePrimitiveArray::<i32>::new(),
+/// );
+/// array.try_push(Some(vec![(Some("a"), Some(1)), (Some("b"), None)]))?;
+/// array.try_push(None::<Vec<(Option<&str>, Option<i32>)>>)?;
+/// array.try_push(Some(vec![(Some("c"), Some(3))]))?;
+///
+/// let array: MapArray = array.into();
+/// assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 3]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MutableMapArray<K: MutableArray, V: MutableArray> {
+    data_type: DataType,
+    offsets: Vec<i32>,
+    // invariant: keys.len() == values.len()
+    keys: K,
+    values: V,
+    validity: Option<MutableBitmap>,
+}
+
+impl<K: MutableArray, V: MutableArray> From<MutableMapArray<K, V>> for MapArray {
+    fn from(mut other: MutableMapArray<K, V>) -> Self {
+        let field = other.field();
+        MapArray::new(
+            other.data_type,
+            other.offsets.into(),
+            field,
+            other.validity.map(|x| x.into()),
+        )
+    }
+}
+
+impl<K: MutableArray + Default, V: MutableArray + Default> Default for MutableMapArray<K, V> {
+    fn default() -> Self {
+        Self::new(K::default(), V::default())
+    }
+}
+
+impl<K: MutableArray, V: MutableArray> MutableMapArray<K, V> {
+    /// Creates a new empty [`MutableMapArray`] from empty `keys` and `values`, with the
+    /// default [`DataType`] (see [`MapArray::default_datatype`]).
+    /// # Panics
+    /// Panics iff `keys` or `values` are not empty.
+    pub fn new(keys: K, values: V) -> Self {
+        let data_type =
+            MapArray::default_datatype(keys.data_type().clone(), values.data_type().clone());
+        Self::try_new(data_type, keys, values).unwrap()
+    }
+
+    /// Creates a new empty [`MutableMapArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s logical type is not [`DataType::Map`] with an inner
+    ///   [`DataType::Struct`] of two fields
+    /// * The data types of `keys` and `values` differ from the data types of those fields
+    /// * `keys` or `values` are not empty
+    pub fn try_new(data_type: DataType, keys: K, values: V) -> Result<Self> {
+        let inner = MapArray::try_get_field(&data_type)?;
+        if let DataType::Struct(fields) = inner.data_type() {
+            if fields.len() != 2 {
+                return Err(ArrowError::InvalidArgumentError(
+                    "MapArray's inner `Struct` must have 2 fields (keys and maps)".to_string(),
+                ));
+            }
+            if fields[0].data_type() != keys.data_type()
+                || fields[1].data_type() != values.data_type()
+            {
+                return Err(ArrowError::InvalidArgumentError(
+                    "MutableMapArray expects `keys` and `values` to match its inner DataType"
+                        .to_string(),
+                ));
+            }
+        } else {
+            return Err(ArrowError::InvalidArgumentError(
+                "MapArray expects `DataType::Struct` as its inner logical type".to_string(),
+            ));
+        }
+        if !keys.is_empty() || !values.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "MutableMapArray must be initialized with empty keys and values".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            offsets: vec![0],
+            keys,
+            values,
+            validity: None,
+        })
+    }
+
+    /// The keys
+    pub fn keys(&self) -> &K {
+        &self.keys
+    }
+
+    /// The keys as a mutable reference
+    pub fn mut_keys(&mut self) -> &mut K {
+        &mut self.keys
+    }
+
+    /// The values
+    pub fn values(&self) -> &V {
+        &self.values
+    }
+
+    /// The values as a mutable reference
+    pub fn mut_values(&mut self) -> &mut V {
+        &mut self.values
+    }
+
+    /// The offsets
+    pub fn offsets(&self) -> &Vec<i32> {
+        &self.offsets
+    }
+
+    /// The length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Whether this array is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Needs to be called when the entries of a valid slot were pushed to the keys and values.
+    /// This is a relatively low level function, prefer `try_push` when you can.
+    /// # Errors
+    /// Errors iff the keys and values have a different length or the number of entries
+    /// overflows `i32`.
+    #[inline]
+    pub fn try_push_valid(&mut self) -> Result<()> {
+        let size = self.keys.len();
+        if size != self.values.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "MutableMapArray must have the same number of keys and values".to_string(),
+            ));
+        }
+        let size = i32::try_from(size).map_err(|_| ArrowError::Overflow)?;
+        assert!(size >= *self.offsets.last().unwrap());
+
+        self.offsets.push(size);
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    /// Pushes a null slot.
+    #[inline]
+    pub fn push_null(&mut self) {
+        self.offsets.push(*self.offsets.last().unwrap());
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.len();
+
+        let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    fn field(&mut self) -> Arc<dyn Array> {
+        let data_type = MapArray::get_field(&self.data_type).data_type().clone();
+        Arc::new(StructArray::new(
+            data_type,
+            vec![self.keys.as_arc(), self.values.as_arc()],
+            None,
+        ))
+    }
+
+    /// Converts itself into an [`Array`].
+    pub fn into_arc(self) -> Arc<dyn Array> {
+        let a: MapArray = self.into();
+        Arc::new(a)
+    }
+
+    /// converts itself into [`Box<dyn Array>`]
+    pub fn into_box(self) -> Box<dyn Array> {
+        let a: MapArray = self.into();
+        Box::new(a)
+    }
+
+    /// Shrinks the capacity of the [`MutableMapArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+
+    fn take_array(&mut self) -> MapArray {
+        let field = self.field();
+        let offsets = std::mem::replace(&mut self.offsets, vec![0]);
+        MapArray::new(
+            self.data_type.clone(),
+            offsets.into(),
+            field,
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableMapArray<Box<dyn MutableArray>, Box<dyn MutableArray>> {
+    // truncates this array to `len` slots and its keys and values to the entries of these slots
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.offsets.truncate(len + 1);
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+        let entries = *self.offsets.last().unwrap() as usize;
+        crate::array::mutable::truncate(self.keys.as_mut(), entries);
+        crate::array::mutable::truncate(self.values.as_mut(), entries);
+    }
+}
+
+impl<K: MutableArray + 'static, V: MutableArray + 'static> MutableArray for MutableMapArray<K, V> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.take_array())
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.take_array())
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
+
+impl<K, V, I, KT, VT> TryPush<Option<I>> for MutableMapArray<K, V>
+where
+    K: MutableArray + TryPush<KT>,
+    V: MutableArray + TryPush<VT>,
+    I: IntoIterator<Item = (KT, VT)>,
+{
+    #[inline]
+    fn try_push(&mut self, item: Option<I>) -> Result<()> {
+        if let Some(entries) = item {
+            for (key, value) in entries {
+                self.keys.try_push(key)?;
+                self.values.try_push(value)?;
+            }
+            self.try_push_valid()
+        } else {
+            self.push_null();
+            Ok(())
+        }
+    }
+}
+
+impl<K, V, I, KT, VT> TryExtend<Option<I>> for MutableMapArray<K, V>
+where
+    K: MutableArray + TryPush<KT>,
+    V: MutableArray + TryPush<VT>,
+    I: IntoIterator<Item = (KT, VT)>,
+{
+    fn try_extend<II: IntoIterator<Item = Option<I>>>(&mut self, iter: II) -> Result<()> {
+        for items in iter {
+            self.try_push(items)?;
+        }
+        Ok(())
+    }
+}
@@ -8,14 +8,16 @@
 //! * [`BooleanArray`] and [`MutableBooleanArray`], an array of boolean values (stored as a bitmap)
 //! * [`Utf8Array`] and [`MutableUtf8Array`], an array of variable length utf8 values
 //! * [`BinaryArray`] and [`MutableBinaryArray`], an array of opaque variable length values
+//! * [`Utf8ViewArray`], [`BinaryViewArray`] and [`MutableBinaryViewArray`], arrays of variable length values represented by views
 //! * [`ListArray`] and [`MutableListArray`], an array of arrays (e.g. `[[1, 2], None, [], [None]]`)
-//! * [`StructArray`], an array of arrays identified by a string (e.g. `{"a": [1, 2], "b": [true, false]}`)
+//! * [`StructArray`] and [`MutableStructArray`], an array of arrays identified by a string (e.g. `{"a": [1, 2], "b": [true, false]}`)
 //! All immutable arrays implement the trait object [`Array`] and that can be downcasted
 //! to a concrete struct based on [`PhysicalType`](crate::datatypes::PhysicalType) available from [`Array::data_type`].
 //! All immutable arrays are backed by [`Buffer`](crate::buffer::Buffer) and thus cloning and slicing them is `O(1)`.
 //!
 //! Most arrays contain a [`MutableArray`] counterpart that is neither clonable nor slicable, but
-//! can be operated in-place.
+//! can be operated in-place. [`make_mutable_array`] creates a [`MutableArray`] from a [`DataType`]
+//! known only at runtime.
 use std::any::Any;
 
 use crate::error::Result;
@@ -162,6 +164,45 @@ pub trait MutableArray: std::fmt::Debug + Send + Sync {
     fn shrink_to_fit(&mut self);
 }
 
+impl MutableArray for Box<dyn MutableArray> {
+    fn data_type(&self) -> &DataType {
+        (**self).data_type()
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        (**self).validity()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        (**self).as_box()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        (**self).as_arc()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        (**self).as_any()
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn Any {
+        (**self).as_mut_any()
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        (**self).push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        (**self).shrink_to_fit()
+    }
+}
+
 macro_rules! general_dyn {
     ($array:expr, $ty:ty, $f:expr) => {{
         let array = $array.as_any().downcast_ref::<$ty>().unwrap();
@@ -193,29 +234,123 @@ macro_rules! match_integer_type {(
     }
 })}
 
+// the run end type of an existing run-end encoded array, which `RunEndEncodedArray::try_new`
+// validates to be `i16`, `i32` or `i64`
+macro_rules! match_run_end_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::IntegerType::*;
+    match $key_type {
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        other => unreachable!("RunEndEncodedArray::try_new rejects run ends of type {:?}", other),
+    }
+})}
+
+// the run end type of a run-end encoded `DataType`; returns an out-of-spec error from the
+// enclosing function when it is not `i16`, `i32` or `i64`
+macro_rules! try_match_run_end_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::IntegerType::*;
+    match $key_type {
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        other => return Err(crate::error::ArrowError::oos(format!(
+            "Run ends of a run-end encoded array cannot be {:?}",
+            other
+        ))),
+    }
+})}
+
 macro_rules! with_match_primitive_type {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::{days_ms, months_days_ns};
+    use crate::types::{days_ms, f16, i256, months_days_ns};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
 })}
 
+// all primitive types except intervals; errors with `$error` on other types
+#[cfg(feature = "compute_aggregate")]
+macro_rules! with_match_numeric_type {(
+    $data_type:expr, $error:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::{PhysicalType, PrimitiveType::*};
+    use crate::types::f16;
+    match $data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => match primitive {
+            Int8 => __with_ty__! { i8 },
+            Int16 => __with_ty__! { i16 },
+            Int32 => __with_ty__! { i32 },
+            Int64 => __with_ty__! { i64 },
+            Int128 => __with_ty__! { i128 },
+            UInt8 => __with_ty__! { u8 },
+            UInt16 => __with_ty__! { u16 },
+            UInt32 => __with_ty__! { u32 },
+            UInt64 => __with_ty__! { u64 },
+            Float16 => __with_ty__! { f16 },
+            Float32 => __with_ty__! { f32 },
+            Float64 => __with_ty__! { f64 },
+            Int256 | DaysMs | MonthDayNano => return Err($error),
+        },
+        _ => return Err($error),
+    }
+})}
+
+// all primitive types except intervals and `f16`, which has no arithmetic; errors with
+// `$error` on other types
+#[cfg(any(
+    feature = "compute_cumulative",
+    feature = "compute_groupby",
+    feature = "compute_window"
+))]
+macro_rules! with_match_arithmetic_type {(
+    $data_type:expr, $error:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::{PhysicalType, PrimitiveType::*};
+    match $data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => match primitive {
+            Int8 => __with_ty__! { i8 },
+            Int16 => __with_ty__! { i16 },
+            Int32 => __with_ty__! { i32 },
+            Int64 => __with_ty__! { i64 },
+            Int128 => __with_ty__! { i128 },
+            UInt8 => __with_ty__! { u8 },
+            UInt16 => __with_ty__! { u16 },
+            UInt32 => __with_ty__! { u32 },
+            UInt64 => __with_ty__! { u64 },
+            Float32 => __with_ty__! { f32 },
+            Float64 => __with_ty__! { f64 },
+            Int256 | Float16 | DaysMs | MonthDayNano => return Err($error),
+        },
+        _ => return Err($error),
+    }
+})}
+
 impl std::fmt::Debug for dyn Array + '_ {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use crate::datatypes::PhysicalType::*;
@@ -230,6 +365,8 @@ impl std::fmt::Debug for dyn Array + '_ {
             FixedSizeBinary => fmt_dyn!(self, FixedSizeBinaryArray, f),
             Utf8 => fmt_dyn!(self, Utf8Array::<i32>, f),
             LargeUtf8 => fmt_dyn!(self, Utf8Array::<i64>, f),
+            BinaryView => fmt_dyn!(self, BinaryViewArray, f),
+            Utf8View => fmt_dyn!(self, Utf8ViewArray, f),
             List => fmt_dyn!(self, ListArray::<i32>, f),
             LargeList => fmt_dyn!(self, ListArray::<i64>, f),
             FixedSizeList => fmt_dyn!(self, FixedSizeListArray, f),
@@ -241,11 +378,19 @@ impl std::fmt::Debug for dyn Array + '_ {
                 })
             }
             Map => todo!(),
+            RunEndEncoded(run_end_type) => {
+                match_run_end_type!(run_end_type, |$T| {
+                    fmt_dyn!(self, RunEndEncodedArray::<$T>, f)
+                })
+            }
         }
     }
 }
 
 /// Creates a new [`Array`] with a [`Array::len`] of 0.
+/// # Panics
+/// This function panics iff `data_type` is not a valid [`DataType`], e.g. a
+/// [`DataType::RunEndEncoded`] whose run ends are not `Int16`, `Int32` or `Int64`.
 pub fn new_empty_array(data_type: DataType) -> Box<dyn Array> {
     use crate::datatypes::PhysicalType::*;
     match data_type.to_physical_type() {
@@ -259,6 +404,8 @@ pub fn new_empty_array(data_type: DataType) -> Box<dyn Array> {
         FixedSizeBinary => Box::new(FixedSizeBinaryArray::new_empty(data_type)),
         Utf8 => Box::new(Utf8Array::<i32>::new_empty(data_type)),
         LargeUtf8 => Box::new(Utf8Array::<i64>::new_empty(data_type)),
+        BinaryView => Box::new(BinaryViewArray::new_empty(data_type)),
+        Utf8View => Box::new(Utf8ViewArray::new_empty(data_type)),
         List => Box::new(ListArray::<i32>::new_empty(data_type)),
         LargeList => Box::new(ListArray::<i64>::new_empty(data_type)),
         FixedSizeList => Box::new(FixedSizeListArray::new_empty(data_type)),
@@ -270,12 +417,19 @@ pub fn new_empty_array(data_type: DataType) -> Box<dyn Array> {
                 Box::new(DictionaryArray::<$T>::new_empty(data_type))
             })
         }
+        RunEndEncoded(run_end_type) => {
+            match_run_end_type!(run_end_type, |$T| {
+                Box::new(RunEndEncodedArray::<$T>::new_empty(data_type))
+            })
+        }
     }
 }
 
 /// Creates a new [`Array`] of [`DataType`] `data_type` and `length`.
 /// The array is guaranteed to have [`Array::null_count`] equal to [`Array::len`]
-/// for all types except Union, which does not have a validity.
+/// for all types except Union and RunEndEncoded, which do not have a validity.
+/// # Panics
+/// This function panics iff `data_type` is not a valid [`DataType`] (see [`new_empty_array`]).
 pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
     use crate::datatypes::PhysicalType::*;
     match data_type.to_physical_type() {
@@ -289,6 +443,8 @@ pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
         FixedSizeBinary => Box::new(FixedSizeBinaryArray::new_null(data_type, length)),
         Utf8 => Box::new(Utf8Array::<i32>::new_null(data_type, length)),
         LargeUtf8 => Box::new(Utf8Array::<i64>::new_null(data_type, length)),
+        BinaryView => Box::new(BinaryViewArray::new_null(data_type, length)),
+        Utf8View => Box::new(Utf8ViewArray::new_null(data_type, length)),
         List => Box::new(ListArray::<i32>::new_null(data_type, length)),
         LargeList => Box::new(ListArray::<i64>::new_null(data_type, length)),
         FixedSizeList => Box::new(FixedSizeListArray::new_null(data_type, length)),
@@ -300,6 +456,11 @@ pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
                 Box::new(DictionaryArray::<$T>::new_null(data_type, length))
             })
         }
+        RunEndEncoded(run_end_type) => {
+            match_run_end_type!(run_end_type, |$T| {
+                Box::new(RunEndEncodedArray::<$T>::new_null(data_type, length))
+            })
+        }
     }
 }
 
@@ -327,6 +488,8 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
         FixedSizeBinary => clone_dyn!(array, FixedSizeBinaryArray),
         Utf8 => clone_dyn!(array, Utf8Array::<i32>),
         LargeUtf8 => clone_dyn!(array, Utf8Array::<i64>),
+        BinaryView => clone_dyn!(array, BinaryViewArray),
+        Utf8View => clone_dyn!(array, Utf8ViewArray),
         List => clone_dyn!(array, ListArray::<i32>),
         LargeList => clone_dyn!(array, ListArray::<i64>),
         FixedSizeList => clone_dyn!(array, FixedSizeListArray),
@@ -338,6 +501,11 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
                 clone_dyn!(array, DictionaryArray::<$T>)
             })
         }
+        RunEndEncoded(run_end_type) => {
+            match_run_end_type!(run_end_type, |$T| {
+                clone_dyn!(array, RunEndEncodedArray::<$T>)
+            })
+        }
     }
 }
 
@@ -350,14 +518,17 @@ impl<'a> AsRef<(dyn Array + 'a)> for dyn Array {
 }
 
 mod binary;
+mod binview;
 mod boolean;
 mod dictionary;
 mod fixed_size_binary;
 mod fixed_size_list;
 mod list;
 mod map;
+mod mutable;
 mod null;
 mod primitive;
+mod run_end_encoded;
 mod specification;
 mod struct_;
 mod union;
@@ -374,16 +545,24 @@ pub use fmt::{get_display, get_value_display};
 
 pub use crate::types::Offset;
 pub use binary::{BinaryArray, BinaryValueIter, MutableBinaryArray};
+pub use binview::{
+    BinaryViewArray, BinaryViewArrayGeneric, BinaryViewValueIter, MutableBinaryViewArray,
+    Utf8ViewArray, View, ViewType, MAX_INLINE_SIZE,
+};
 pub use boolean::{BooleanArray, MutableBooleanArray};
 pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray};
 pub use fixed_size_binary::{FixedSizeBinaryArray, MutableFixedSizeBinaryArray};
 pub use fixed_size_list::{FixedSizeListArray, MutableFixedSizeListArray};
 pub use list::{ListArray, ListValuesIter, MutableListArray};
-pub use map::MapArray;
-pub use null::NullArray;
+pub use map::{MapArray, MutableMapArray};
+pub use mutable::{make_mutable_array, TryPushScalar};
+pub use null::{MutableNullArray, NullArray};
 pub use primitive::*;
-pub use struct_::StructArray;
-pub use union::UnionArray;
+pub use run_end_encoded::{
+    MutableRunEndEncodedArray, RunEndEncodedArray, RunEndEncodedIter, RunEndIndex,
+};
+pub use struct_::{MutableStructArray, StructArray};
+pub use union::{MutableUnionArray, UnionArray};
 pub use utf8::{MutableUtf8Array, Utf8Array, Utf8ValuesIter};
 
 pub(crate) use self::ffi::offset_buffers_children_dictionary;
@@ -0,0 +1,536 @@
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    bitmap::MutableBitmap,
+    datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
+    scalar::*,
+    types::NativeType,
+};
+
+use super::*;
+
+fn new_offsets<O: Offset>(capacity: usize) -> Vec<O> {
+    let mut offsets = Vec::<O>::with_capacity(capacity + 1);
+    offsets.push(O::default());
+    offsets
+}
+
+/// Creates a new empty [`MutableArray`] of [`DataType`] `data_type` with capacity for
+/// `capacity` slots.
+///
+/// Nested types are built recursively, with their children stored as `Box<dyn MutableArray>`.
+/// The returned array can be downcasted to its concrete type via [`MutableArray::as_mut_any`]
+/// or populated in a type-erased manner via [`TryPushScalar`].
+/// # Errors
+/// This function errors iff `data_type` (or one of its children) is an invalid
+/// [`DataType::Map`] or [`DataType::RunEndEncoded`].
+/// # Example
+/// ```
+/// # use arrow2::array::{make_mutable_array, TryPushScalar};
+/// # use arrow2::datatypes::{DataType, Field};
+/// # use arrow2::scalar::{PrimitiveScalar, Utf8Scalar, StructScalar};
+/// # use std::sync::Arc;
+/// # fn main() -> arrow2::error::Result<()> {
+/// let data_type = DataType::Struct(vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ]);
+/// let mut array = make_mutable_array(&data_type, 2)?;
+/// array.try_push_scalar(&StructScalar::new(
+///     data_type.clone(),
+///     Some(vec![
+///         Arc::new(PrimitiveScalar::<i32>::from(Some(1))),
+///         Arc::new(Utf8Scalar::<i32>::new(Some("a"))),
+///     ]),
+/// ))?;
+/// array.push_null();
+///
+/// let array = array.as_box();
+/// assert_eq!(array.len(), 2);
+/// assert_eq!(array.null_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn make_mutable_array(data_type: &DataType, capacity: usize) -> Result<Box<dyn MutableArray>> {
+    use PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Null => Box::new(MutableNullArray::new(data_type.clone(), 0)),
+        Boolean => Box::new(MutableBooleanArray::from_data(
+            data_type.clone(),
+            MutableBitmap::with_capacity(capacity),
+            None,
+        )),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            Box::new(MutablePrimitiveArray::<$T>::with_capacity_from(capacity, data_type.clone()))
+        }),
+        Binary => Box::new(MutableBinaryArray::<i32>::from_data(
+            data_type.clone(),
+            new_offsets(capacity),
+            vec![],
+            None,
+        )),
+        LargeBinary => Box::new(MutableBinaryArray::<i64>::from_data(
+            data_type.clone(),
+            new_offsets(capacity),
+            vec![],
+            None,
+        )),
+        FixedSizeBinary => {
+            let size = FixedSizeBinaryArray::get_size(data_type);
+            Box::new(MutableFixedSizeBinaryArray::from_data(
+                data_type.clone(),
+                Vec::with_capacity(capacity * size),
+                None,
+            ))
+        }
+        Utf8 => Box::new(MutableUtf8Array::<i32>::from_data(
+            data_type.clone(),
+            new_offsets(capacity),
+            vec![],
+            None,
+        )),
+        LargeUtf8 => Box::new(MutableUtf8Array::<i64>::from_data(
+            data_type.clone(),
+            new_offsets(capacity),
+            vec![],
+            None,
+        )),
+        BinaryView => Box::new(MutableBinaryViewArray::<[u8]>::with_capacity(capacity)),
+        Utf8View => Box::new(MutableBinaryViewArray::<str>::with_capacity(capacity)),
+        List => {
+            let values = make_mutable_array(
+                ListArray::<i32>::get_child_field(data_type).data_type(),
+                capacity,
+            )?;
+            Box::new(MutableListArray::<i32, _>::new_from(
+                values,
+                data_type.clone(),
+                capacity,
+            ))
+        }
+        LargeList => {
+            let values = make_mutable_array(
+                ListArray::<i64>::get_child_field(data_type).data_type(),
+                capacity,
+            )?;
+            Box::new(MutableListArray::<i64, _>::new_from(
+                values,
+                data_type.clone(),
+                capacity,
+            ))
+        }
+        FixedSizeList => {
+            let (field, size) = FixedSizeListArray::get_child_and_size(data_type);
+            let values = make_mutable_array(field.data_type(), capacity * size)?;
+            Box::new(MutableFixedSizeListArray::new_with_field(
+                values,
+                &field.name,
+                field.is_nullable,
+                size,
+            ))
+        }
+        Struct => {
+            let values = StructArray::get_fields(data_type)
+                .iter()
+                .map(|field| make_mutable_array(field.data_type(), capacity))
+                .collect::<Result<Vec<_>>>()?;
+            Box::new(MutableStructArray::try_new(data_type.clone(), values)?)
+        }
+        Union => {
+            let fields = UnionArray::get_fields(data_type)
+                .iter()
+                .map(|field| make_mutable_array(field.data_type(), capacity))
+                .collect::<Result<Vec<_>>>()?;
+            Box::new(MutableUnionArray::try_new(data_type.clone(), fields)?)
+        }
+        Map => {
+            let fields =
+                StructArray::try_get_fields(MapArray::try_get_field(data_type)?.data_type())?;
+            if fields.len() != 2 {
+                return Err(ArrowError::InvalidArgumentError(
+                    "MapArray's inner `Struct` must have 2 fields (keys and maps)".to_string(),
+                ));
+            }
+            let keys = make_mutable_array(fields[0].data_type(), capacity)?;
+            let values = make_mutable_array(fields[1].data_type(), capacity)?;
+            Box::new(MutableMapArray::try_new(data_type.clone(), keys, values)?)
+        }
+        Dictionary(key_type) => {
+            let values =
+                make_mutable_array(DictionaryArray::<i32>::get_child(data_type), capacity)?;
+            match_integer_type!(key_type, |$T| {
+                Box::new(MutableDictionaryArray::<$T, _>::new_from(
+                    values,
+                    data_type.clone(),
+                    capacity,
+                ))
+            })
+        }
+        RunEndEncoded(run_end_type) => {
+            let (_, field) = RunEndEncodedArray::<i32>::try_get_field(data_type)?;
+            let values = make_mutable_array(field.data_type(), capacity)?;
+            try_match_run_end_type!(run_end_type, |$T| {
+                Box::new(MutableRunEndEncodedArray::<$T, _>::new_from(values, data_type.clone()))
+            })
+        }
+    })
+}
+
+/// A trait describing the ability of a [`MutableArray`] to receive a type-erased [`Scalar`].
+///
+/// Together with [`MutableArray::push_null`], this allows populating arrays created by
+/// [`make_mutable_array`] without knowing their concrete types at compile time.
+pub trait TryPushScalar {
+    /// Tries to push `scalar` as a new slot. On error, the array (including its children) is
+    /// left as it was before this call.
+    ///
+    /// The scalars of a [`DataType::RunEndEncoded`] array are scalars of its values, as
+    /// returned by [`new_scalar`] on a [`RunEndEncodedArray`].
+    /// # Errors
+    /// Errors iff the physical type of `scalar` (or of one of its children) differs from the
+    /// physical type of the array, or the array (or one of its children) was not created
+    /// by [`make_mutable_array`].
+    fn try_push_scalar(&mut self, scalar: &dyn Scalar) -> Result<()>;
+}
+
+fn downcast_mut<M: MutableArray + 'static>(array: &mut dyn MutableArray) -> Result<&mut M> {
+    array.as_mut_any().downcast_mut::<M>().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "try_push_scalar does not support {}; use `make_mutable_array` to create it",
+            std::any::type_name::<M>()
+        ))
+    })
+}
+
+fn downcast_scalar<S: Scalar + 'static>(scalar: &dyn Scalar) -> &S {
+    scalar.as_any().downcast_ref::<S>().unwrap()
+}
+
+impl<'a> TryPushScalar for dyn MutableArray + 'a {
+    fn try_push_scalar(&mut self, scalar: &dyn Scalar) -> Result<()> {
+        let len = self.len();
+        let result = try_push_dyn(self, scalar);
+        if result.is_err() {
+            // a child may have received values before the error
+            truncate(self, len);
+        }
+        result
+    }
+}
+
+fn try_push_dyn(array: &mut dyn MutableArray, scalar: &dyn Scalar) -> Result<()> {
+    use PhysicalType::*;
+    let physical_type = array.data_type().to_physical_type();
+    // the scalars of run-end encoded arrays are scalars of their values
+    let expected = if let RunEndEncoded(_) = physical_type {
+        RunEndEncodedArray::<i32>::get_field(array.data_type())
+            .data_type()
+            .to_physical_type()
+    } else {
+        physical_type
+    };
+    if expected != scalar.data_type().to_physical_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot push a scalar of type {:?} to an array of type {:?}",
+            scalar.data_type(),
+            array.data_type()
+        )));
+    }
+    if !scalar.is_valid() {
+        array.push_null();
+        return Ok(());
+    }
+
+    match physical_type {
+        Null => array.push_null(),
+        Boolean => {
+            let scalar = downcast_scalar::<BooleanScalar>(scalar);
+            downcast_mut::<MutableBooleanArray>(array)?.push(scalar.value())
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let scalar = downcast_scalar::<PrimitiveScalar<$T>>(scalar);
+            downcast_mut::<MutablePrimitiveArray<$T>>(array)?.push(scalar.value())
+        }),
+        Binary => {
+            let scalar = downcast_scalar::<BinaryScalar<i32>>(scalar);
+            downcast_mut::<MutableBinaryArray<i32>>(array)?.push(scalar.value())
+        }
+        LargeBinary => {
+            let scalar = downcast_scalar::<BinaryScalar<i64>>(scalar);
+            downcast_mut::<MutableBinaryArray<i64>>(array)?.push(scalar.value())
+        }
+        FixedSizeBinary => {
+            let scalar = downcast_scalar::<FixedSizeBinaryScalar>(scalar);
+            downcast_mut::<MutableFixedSizeBinaryArray>(array)?.try_push(scalar.value())?
+        }
+        Utf8 => {
+            let scalar = downcast_scalar::<Utf8Scalar<i32>>(scalar);
+            downcast_mut::<MutableUtf8Array<i32>>(array)?.push(scalar.value())
+        }
+        LargeUtf8 => {
+            let scalar = downcast_scalar::<Utf8Scalar<i64>>(scalar);
+            downcast_mut::<MutableUtf8Array<i64>>(array)?.push(scalar.value())
+        }
+        BinaryView => {
+            let scalar = downcast_scalar::<BinaryViewScalar<[u8]>>(scalar);
+            downcast_mut::<MutableBinaryViewArray<[u8]>>(array)?.try_push(scalar.value())?
+        }
+        Utf8View => {
+            let scalar = downcast_scalar::<BinaryViewScalar<str>>(scalar);
+            downcast_mut::<MutableBinaryViewArray<str>>(array)?.try_push(scalar.value())?
+        }
+        List => {
+            let scalar = downcast_scalar::<ListScalar<i32>>(scalar);
+            let array = downcast_mut::<MutableListArray<i32, Box<dyn MutableArray>>>(array)?;
+            try_push_values(array.mut_values().as_mut(), scalar.values().as_ref())?;
+            array.try_push_valid()?
+        }
+        LargeList => {
+            let scalar = downcast_scalar::<ListScalar<i64>>(scalar);
+            let array = downcast_mut::<MutableListArray<i64, Box<dyn MutableArray>>>(array)?;
+            try_push_values(array.mut_values().as_mut(), scalar.values().as_ref())?;
+            array.try_push_valid()?
+        }
+        FixedSizeList => {
+            let scalar = downcast_scalar::<FixedSizeListScalar>(scalar);
+            let array = downcast_mut::<MutableFixedSizeListArray<Box<dyn MutableArray>>>(array)?;
+            // the scalar is valid and thus has values
+            try_push_values(
+                array.mut_values().as_mut(),
+                scalar.values().unwrap().as_ref(),
+            )?;
+            array.try_push_valid()?
+        }
+        Struct => {
+            let scalar = downcast_scalar::<StructScalar>(scalar);
+            let array = downcast_mut::<MutableStructArray>(array)?;
+            array
+                .mut_values()
+                .iter_mut()
+                .zip(scalar.values().iter())
+                .try_for_each(|(value, scalar)| value.try_push_scalar(scalar.as_ref()))?;
+            array.try_push_valid()?
+        }
+        Union => {
+            let scalar = downcast_scalar::<UnionScalar>(scalar);
+            downcast_mut::<MutableUnionArray>(array)?
+                .try_push_variant_with(scalar.type_(), |field| {
+                    field.try_push_scalar(scalar.value().as_ref())
+                })?
+        }
+        Map => {
+            let scalar = downcast_scalar::<MapScalar>(scalar);
+            let array = downcast_mut::<
+                MutableMapArray<Box<dyn MutableArray>, Box<dyn MutableArray>>,
+            >(array)?;
+            let entries = scalar
+                .values()
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .unwrap();
+            try_push_values(array.mut_keys().as_mut(), entries.values()[0].as_ref())?;
+            try_push_values(array.mut_values().as_mut(), entries.values()[1].as_ref())?;
+            array.try_push_valid()?
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let scalar = downcast_scalar::<DictionaryScalar<$T>>(scalar);
+            // the scalar is valid and thus has a value
+            downcast_mut::<MutableDictionaryArray<$T, Box<dyn MutableArray>>>(array)?
+                .try_push_scalar(scalar.value().unwrap().as_ref())?
+        }),
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            downcast_mut::<MutableRunEndEncodedArray<$T, Box<dyn MutableArray>>>(array)?
+                .try_push_scalar(scalar)?
+        }),
+    };
+    Ok(())
+}
+
+fn try_push_values(array: &mut dyn MutableArray, values: &dyn Array) -> Result<()> {
+    (0..values.len())
+        .try_for_each(|index| array.try_push_scalar(new_scalar(values, index).as_ref()))
+}
+
+macro_rules! truncate_with_pop {
+    ($array:expr, $len:expr, $ty:ty) => {{
+        if let Some(array) = $array.as_mut_any().downcast_mut::<$ty>() {
+            while array.len() > $len {
+                array.pop();
+            }
+        }
+    }};
+}
+
+/// Truncates `array`, created by [`make_mutable_array`], to `len` slots, truncating its
+/// children to the slots its (remaining) slots refer to. Arrays of other types are left as is.
+pub(super) fn truncate(array: &mut dyn MutableArray, len: usize) {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => {
+            if let Some(array) = array.as_mut_any().downcast_mut::<MutableNullArray>() {
+                array.truncate(len)
+            }
+        }
+        Boolean => truncate_with_pop!(array, len, MutableBooleanArray),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            truncate_with_pop!(array, len, MutablePrimitiveArray<$T>)
+        }),
+        Binary => truncate_with_pop!(array, len, MutableBinaryArray<i32>),
+        LargeBinary => truncate_with_pop!(array, len, MutableBinaryArray<i64>),
+        FixedSizeBinary => truncate_with_pop!(array, len, MutableFixedSizeBinaryArray),
+        Utf8 => truncate_with_pop!(array, len, MutableUtf8Array<i32>),
+        LargeUtf8 => truncate_with_pop!(array, len, MutableUtf8Array<i64>),
+        BinaryView => {
+            if let Some(array) = array
+                .as_mut_any()
+                .downcast_mut::<MutableBinaryViewArray<[u8]>>()
+            {
+                array.truncate(len)
+            }
+        }
+        Utf8View => {
+            if let Some(array) = array
+                .as_mut_any()
+                .downcast_mut::<MutableBinaryViewArray<str>>()
+            {
+                array.truncate(len)
+            }
+        }
+        List => {
+            if let Some(array) = array
+                .as_mut_any()
+                .downcast_mut::<MutableListArray<i32, Box<dyn MutableArray>>>()
+            {
+                array.truncate(len)
+            }
+        }
+        LargeList => {
+            if let Some(array) = array
+                .as_mut_any()
+                .downcast_mut::<MutableListArray<i64, Box<dyn MutableArray>>>()
+            {
+                array.truncate(len)
+            }
+        }
+        FixedSizeList => {
+            if let Some(array) = array
+                .as_mut_any()
+                .downcast_mut::<MutableFixedSizeListArray<Box<dyn MutableArray>>>()
+            {
+                array.truncate(len)
+            }
+        }
+        Struct => {
+            if let Some(array) = array.as_mut_any().downcast_mut::<MutableStructArray>() {
+                array.truncate(len)
+            }
+        }
+        Union => {
+            if let Some(array) = array.as_mut_any().downcast_mut::<MutableUnionArray>() {
+                array.truncate(len)
+            }
+        }
+        Map => {
+            if let Some(array) = array
+                .as_mut_any()
+                .downcast_mut::<MutableMapArray<Box<dyn MutableArray>, Box<dyn MutableArray>>>()
+            {
+                array.truncate(len)
+            }
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            if let Some(array) = array
+                .as_mut_any()
+                .downcast_mut::<MutableDictionaryArray<$T, Box<dyn MutableArray>>>()
+            {
+                array.truncate(len)
+            }
+        }),
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            if let Some(array) = array
+                .as_mut_any()
+                .downcast_mut::<MutableRunEndEncodedArray<$T, Box<dyn MutableArray>>>()
+            {
+                array.truncate(len)
+            }
+        }),
+    }
+}
+
+/// Feeds the validity and value of `scalar` to `state`, so that equal scalars feed equal bytes.
+pub(super) fn hash_scalar<H: Hasher>(scalar: &dyn Scalar, state: &mut H) {
+    use PhysicalType::*;
+    scalar.is_valid().hash(state);
+    if !scalar.is_valid() {
+        return;
+    }
+    match scalar.data_type().to_physical_type() {
+        Null => {}
+        Boolean => downcast_scalar::<BooleanScalar>(scalar).value().hash(state),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            if let Some(value) = downcast_scalar::<PrimitiveScalar<$T>>(scalar).value() {
+                value.to_le_bytes().as_ref().hash(state)
+            }
+        }),
+        Binary => downcast_scalar::<BinaryScalar<i32>>(scalar)
+            .value()
+            .hash(state),
+        LargeBinary => downcast_scalar::<BinaryScalar<i64>>(scalar)
+            .value()
+            .hash(state),
+        FixedSizeBinary => downcast_scalar::<FixedSizeBinaryScalar>(scalar)
+            .value()
+            .hash(state),
+        Utf8 => downcast_scalar::<Utf8Scalar<i32>>(scalar)
+            .value()
+            .hash(state),
+        LargeUtf8 => downcast_scalar::<Utf8Scalar<i64>>(scalar)
+            .value()
+            .hash(state),
+        BinaryView => downcast_scalar::<BinaryViewScalar<[u8]>>(scalar)
+            .value()
+            .hash(state),
+        Utf8View => downcast_scalar::<BinaryViewScalar<str>>(scalar)
+            .value()
+            .hash(state),
+        List => hash_values(
+            downcast_scalar::<ListScalar<i32>>(scalar).values().as_ref(),
+            state,
+        ),
+        LargeList => hash_values(
+            downcast_scalar::<ListScalar<i64>>(scalar).values().as_ref(),
+            state,
+        ),
+        FixedSizeList => {
+            if let Some(values) = downcast_scalar::<FixedSizeListScalar>(scalar).values() {
+                hash_values(values.as_ref(), state)
+            }
+        }
+        Map => hash_values(
+            downcast_scalar::<MapScalar>(scalar).values().as_ref(),
+            state,
+        ),
+        Struct => downcast_scalar::<StructScalar>(scalar)
+            .values()
+            .iter()
+            .for_each(|value| hash_scalar(value.as_ref(), state)),
+        Union => {
+            let scalar = downcast_scalar::<UnionScalar>(scalar);
+            scalar.type_().hash(state);
+            hash_scalar(scalar.value().as_ref(), state)
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            if let Some(value) = downcast_scalar::<DictionaryScalar<$T>>(scalar).value() {
+                hash_scalar(value.as_ref(), state)
+            }
+        }),
+        // run-end encoded arrays have no scalars of their own
+        RunEndEncoded(_) => {}
+    }
+}
+
+fn hash_values<H: Hasher>(values: &dyn Array, state: &mut H) {
+    values.len().hash(state);
+    (0..values.len()).for_each(|index| hash_scalar(new_scalar(values, index).as_ref(), state))
+}
@@ -1,7 +1,8 @@
 use crate::{bitmap::Bitmap, datatypes::DataType};
 
 use crate::{
-    array::{Array, FromFfi, ToFfi},
+    array::{Array, FromFfi, MutableArray, ToFfi},
+    bitmap::MutableBitmap,
     datatypes::PhysicalType,
     error::ArrowError,
     ffi,
@@ -104,6 +105,74 @@ impl Array for NullArray {
     }
 }
 
+/// The mutable version of [`NullArray`], which only tracks its length.
+#[derive(Debug, Clone)]
+pub struct MutableNullArray {
+    data_type: DataType,
+    length: usize,
+}
+
+impl MutableNullArray {
+    /// Returns a new [`MutableNullArray`] of `length`.
+    /// # Panics
+    /// This function panics iff:
+    /// * The `data_type`'s [`crate::datatypes::PhysicalType`] is not equal to [`crate::datatypes::PhysicalType::Null`].
+    pub fn new(data_type: DataType, length: usize) -> Self {
+        assert_eq!(data_type.to_physical_type(), PhysicalType::Null);
+        Self { data_type, length }
+    }
+
+    // truncates this array to `len` slots
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.length = self.length.min(len)
+    }
+}
+
+impl Default for MutableNullArray {
+    fn default() -> Self {
+        Self::new(DataType::Null, 0)
+    }
+}
+
+impl From<MutableNullArray> for NullArray {
+    fn from(other: MutableNullArray) -> Self {
+        NullArray::new(other.data_type, other.length)
+    }
+}
+
+impl MutableArray for MutableNullArray {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        let length = std::mem::take(&mut self.length);
+        Box::new(NullArray::new(self.data_type.clone(), length))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.length += 1;
+    }
+
+    fn shrink_to_fit(&mut self) {}
+}
+
 impl std::fmt::Debug for NullArray {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "NullArray({})", self.len())
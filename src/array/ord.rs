@@ -4,7 +4,10 @@ use std::cmp::Ordering;
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
-use crate::{array::*, types::NativeType};
+use crate::{
+    array::*,
+    types::{i256, NativeType},
+};
 
 /// Compare the values at two arbitrary indices in two arrays.
 pub type DynComparator = Box<dyn Fn(usize, usize) -> Ordering + Send + Sync>;
@@ -147,6 +150,85 @@ where
     }))
 }
 
+// nulls of nested arrays are smaller than any other value and equal to each other
+fn compare_nullable(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let comparator = build_compare(left, right)?;
+    Ok(
+        match (left.validity().cloned(), right.validity().cloned()) {
+            (None, None) => comparator,
+            (left, right) => Box::new(move |i: usize, j: usize| {
+                let is_valid_left = left.as_ref().map(|x| x.get_bit(i)).unwrap_or(true);
+                let is_valid_right = right.as_ref().map(|x| x.get_bit(j)).unwrap_or(true);
+                match (is_valid_left, is_valid_right) {
+                    (true, true) => (comparator)(i, j),
+                    (false, true) => Ordering::Less,
+                    (true, false) => Ordering::Greater,
+                    (false, false) => Ordering::Equal,
+                }
+            }),
+        },
+    )
+}
+
+// compares `left_length` items from `left_start` against `right_length` items from `right_start`
+#[inline]
+fn compare_ranges(
+    comparator: &DynComparator,
+    (left_start, left_length): (usize, usize),
+    (right_start, right_length): (usize, usize),
+) -> Ordering {
+    (0..left_length.min(right_length))
+        .map(|k| (comparator)(left_start + k, right_start + k))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or_else(|| left_length.cmp(&right_length))
+}
+
+fn compare_list<O: Offset>(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let comparator = compare_nullable(left.values().as_ref(), right.values().as_ref())?;
+    let left = left.offsets().clone();
+    let right = right.offsets().clone();
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        let range = |offsets: &crate::buffer::Buffer<O>, i: usize| {
+            let start = offsets[i].to_usize();
+            (start, offsets[i + 1].to_usize() - start)
+        };
+        compare_ranges(&comparator, range(&left, i), range(&right, j))
+    }))
+}
+
+fn compare_fixed_size_list(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let right = right.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let comparator = compare_nullable(left.values().as_ref(), right.values().as_ref())?;
+    let size = FixedSizeListArray::get_child_and_size(left.data_type()).1;
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        compare_ranges(&comparator, (i * size, size), (j * size, size))
+    }))
+}
+
+fn compare_struct(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+    let comparators = left
+        .values()
+        .iter()
+        .zip(right.values().iter())
+        .map(|(left, right)| compare_nullable(left.as_ref(), right.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        comparators
+            .iter()
+            .map(|comparator| (comparator)(i, j))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
 macro_rules! dyn_dict {
     ($key:ty, $lhs:expr, $rhs:expr) => {{
         let lhs = $lhs.as_any().downcast_ref().unwrap();
@@ -172,6 +254,9 @@ macro_rules! dyn_dict {
 /// # Ok(())
 /// # }
 /// ```
+/// Nested arrays are compared lexicographically: lists element-wise, where a list that is a
+/// prefix of another list is smaller than it, and structs field-wise. Nulls within nested
+/// arrays are smaller than any other value.
 /// # Error
 /// The arrays' [`DataType`] must be equal and the types must have a natural order.
 // This is a factory of comparisons.
@@ -201,14 +286,16 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         | (Date64, Date64)
         | (Time64(Microsecond), Time64(Microsecond))
         | (Time64(Nanosecond), Time64(Nanosecond))
-        | (Timestamp(Second, None), Timestamp(Second, None))
-        | (Timestamp(Millisecond, None), Timestamp(Millisecond, None))
-        | (Timestamp(Microsecond, None), Timestamp(Microsecond, None))
-        | (Timestamp(Nanosecond, None), Timestamp(Nanosecond, None))
+        | (Timestamp(Second, _), Timestamp(Second, _))
+        | (Timestamp(Millisecond, _), Timestamp(Millisecond, _))
+        | (Timestamp(Microsecond, _), Timestamp(Microsecond, _))
+        | (Timestamp(Nanosecond, _), Timestamp(Nanosecond, _))
         | (Duration(Second), Duration(Second))
         | (Duration(Millisecond), Duration(Millisecond))
         | (Duration(Microsecond), Duration(Microsecond))
         | (Duration(Nanosecond), Duration(Nanosecond)) => compare_primitives::<i64>(left, right),
+        (Decimal(_, _), Decimal(_, _)) => compare_primitives::<i128>(left, right),
+        (Decimal256(_, _), Decimal256(_, _)) => compare_primitives::<i256>(left, right),
         (Float32, Float32) => compare_f32(left, right),
         (Float64, Float64) => compare_f64(left, right),
         (Utf8, Utf8) => compare_string::<i32>(left, right),
@@ -233,6 +320,10 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
                 }
             }
         }
+        (List(_), List(_)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (FixedSizeList(_, _), FixedSizeList(_, _)) => compare_fixed_size_list(left, right)?,
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
         (lhs, _) => {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "The data type type {:?} has no natural order",
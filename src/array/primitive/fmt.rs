@@ -2,7 +2,7 @@ use std::fmt::{Debug, Formatter, Result, Write};
 
 use crate::array::Array;
 use crate::datatypes::{IntervalUnit, TimeUnit};
-use crate::types::{days_ms, months_days_ns};
+use crate::types::{days_ms, i256, months_days_ns};
 
 use super::super::super::temporal_conversions;
 use super::super::super::types::NativeType;
@@ -32,7 +32,7 @@ pub fn get_write_value<'a, T: NativeType, F: Write>(
         UInt16 => Box::new(|f, index| write!(f, "{}", array.value(index))),
         UInt32 => Box::new(|f, index| write!(f, "{}", array.value(index))),
         UInt64 => Box::new(|f, index| write!(f, "{}", array.value(index))),
-        Float16 => unreachable!(),
+        Float16 => Box::new(|f, index| write!(f, "{}", array.value(index))),
         Float32 => Box::new(|f, index| write!(f, "{}", array.value(index))),
         Float64 => Box::new(|f, index| write!(f, "{}", array.value(index))),
         Date32 => {
@@ -114,6 +114,24 @@ pub fn get_write_value<'a, T: NativeType, F: Write>(
             };
             dyn_primitive!(array, i128, display)
         }
+        Decimal256(_, scale) => {
+            let scale = *scale;
+            let display = move |x: i256| {
+                let digits = x.to_string();
+                let (sign, digits) = match digits.strip_prefix('-') {
+                    Some(digits) => ("-", digits),
+                    None => ("", digits.as_str()),
+                };
+                if scale == 0 {
+                    return format!("{}{}", sign, digits);
+                }
+                // e.g. `5` of scale 2 is `0.05`
+                let digits = format!("{:0>width$}", digits, width = scale + 1);
+                let (integer, fraction) = digits.split_at(digits.len() - scale);
+                format!("{}{}.{}", sign, integer, fraction)
+            };
+            dyn_primitive!(array, i256, display)
+        }
         _ => unreachable!(),
     }
 }
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{FromFfi, PrimitiveArray},
+    error::{ArrowError, Result},
+    ffi,
+};
+
+use super::super::{ffi::ToFfi, Array};
+use super::{RunEndEncodedArray, RunEndIndex};
+
+unsafe impl<R: RunEndIndex> ToFfi for RunEndEncodedArray<R> {
+    fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
+        vec![]
+    }
+
+    fn children(&self) -> Vec<Arc<dyn Array>> {
+        let run_ends =
+            PrimitiveArray::<R>::from_data(R::PRIMITIVE.into(), self.run_ends.clone(), None);
+        vec![Arc::new(run_ends), self.values.clone()]
+    }
+
+    fn offset(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    fn to_ffi_aligned(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<R: RunEndIndex, A: ffi::ArrowArrayRef> FromFfi<A> for RunEndEncodedArray<R> {
+    unsafe fn try_from_ffi(array: A) -> Result<Self> {
+        let data_type = array.data_type().clone();
+        let length = array.array().len();
+        let offset = array.array().offset();
+
+        let run_ends = ffi::try_from(array.child(0)?)?;
+        let run_ends = run_ends
+            .as_any()
+            .downcast_ref::<PrimitiveArray<R>>()
+            .ok_or_else(|| ArrowError::oos("The run ends must be a primitive array"))?;
+        if run_ends.null_count() != 0 {
+            return Err(ArrowError::oos("The run ends must not contain nulls"));
+        }
+        let values = ffi::try_from(array.child(1)?)?.into();
+
+        let array = Self::try_new(data_type, run_ends.values().clone(), values)?;
+        if offset + length > array.len() {
+            return Err(ArrowError::oos(
+                "The offset and length of a run-end encoded array must be within its run ends",
+            ));
+        }
+        Ok(array.slice(offset, length))
+    }
+}
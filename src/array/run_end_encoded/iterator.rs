@@ -0,0 +1,76 @@
+use crate::{scalar::Scalar, trusted_len::TrustedLen};
+
+use super::{RunEndEncodedArray, RunEndIndex};
+
+/// Iterator of the logical values of a [`RunEndEncodedArray`].
+/// # Implementation
+/// Each item is obtained in amortized `O(1)`, as the iterator tracks the current run.
+#[derive(Debug, Clone)]
+pub struct RunEndEncodedIter<'a, R: RunEndIndex> {
+    array: &'a RunEndEncodedArray<R>,
+    current: usize,
+    run: usize,
+}
+
+impl<'a, R: RunEndIndex> RunEndEncodedIter<'a, R> {
+    /// Creates a new [`RunEndEncodedIter`]
+    pub fn new(array: &'a RunEndEncodedArray<R>) -> Self {
+        let run = if array.is_empty() {
+            0
+        } else {
+            array.physical_index(0)
+        };
+        Self {
+            array,
+            current: 0,
+            run,
+        }
+    }
+}
+
+impl<'a, R: RunEndIndex> Iterator for RunEndEncodedIter<'a, R> {
+    type Item = Box<dyn Scalar>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.array.len() {
+            return None;
+        }
+        let logical = self.array.offset() + self.current;
+        // runs are strictly increasing, so the current run can only move forward
+        while self.array.run_ends()[self.run].to_usize().unwrap() <= logical {
+            self.run += 1;
+        }
+        self.current += 1;
+        Some(crate::scalar::new_scalar(
+            self.array.values().as_ref(),
+            self.run,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.array.len() - self.current;
+        (len, Some(len))
+    }
+}
+
+impl<'a, R: RunEndIndex> IntoIterator for &'a RunEndEncodedArray<R> {
+    type Item = Box<dyn Scalar>;
+    type IntoIter = RunEndEncodedIter<'a, R>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, R: RunEndIndex> RunEndEncodedArray<R> {
+    /// constructs a new iterator over the logical values of this array
+    #[inline]
+    pub fn iter(&'a self) -> RunEndEncodedIter<'a, R> {
+        RunEndEncodedIter::new(self)
+    }
+}
+
+impl<'a, R: RunEndIndex> std::iter::ExactSizeIterator for RunEndEncodedIter<'a, R> {}
+
+unsafe impl<'a, R: RunEndIndex> TrustedLen for RunEndEncodedIter<'a, R> {}
@@ -0,0 +1,305 @@
+use std::sync::Arc;
+
+use crate::{
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::{DataType, Field, IntegerType},
+    error::ArrowError,
+    scalar::{new_scalar, Scalar},
+    types::NativeType,
+};
+
+mod ffi;
+pub(super) mod fmt;
+mod iterator;
+mod mutable;
+pub use iterator::*;
+pub use mutable::*;
+
+use super::{new_empty_array, new_null_array, Array};
+
+/// Trait denoting [`NativeType`]s that can be used as run ends of a [`RunEndEncodedArray`].
+pub trait RunEndIndex:
+    NativeType + num_traits::NumCast + num_traits::FromPrimitive + std::cmp::Ord
+{
+    /// The corresponding [`IntegerType`] of this run end
+    const RUN_END_TYPE: IntegerType;
+}
+
+impl RunEndIndex for i16 {
+    const RUN_END_TYPE: IntegerType = IntegerType::Int16;
+}
+impl RunEndIndex for i32 {
+    const RUN_END_TYPE: IntegerType = IntegerType::Int32;
+}
+impl RunEndIndex for i64 {
+    const RUN_END_TYPE: IntegerType = IntegerType::Int64;
+}
+
+/// An [`Array`] whose values are run-end encoded: each run of equal consecutive values is
+/// stored once in `values`, and `run_ends[i]` is the (exclusive) logical index at which the
+/// run `i` ends. This [`Array`] is useful when the data is highly repetitive, such as
+/// time-series.
+///
+/// Like [`UnionArray`](super::UnionArray), this array has no validity of its own:
+/// null slots are represented by null entries in `values`.
+/// # Implementation
+/// Accessing a logical slot is `O(log(runs))`, as it requires a binary search over the run ends.
+/// Slicing is `O(1)`: run ends are kept untouched and a logical offset is tracked instead.
+#[derive(Clone)]
+pub struct RunEndEncodedArray<R: RunEndIndex> {
+    data_type: DataType,
+    // invariant: strictly increasing and positive; `run_ends.len() == values.len()`
+    run_ends: Buffer<R>,
+    values: Arc<dyn Array>,
+    // invariant: `offset + length <= run_ends.last()`
+    offset: usize,
+    length: usize,
+}
+
+impl<R: RunEndIndex> RunEndEncodedArray<R> {
+    /// Returns a new [`RunEndEncodedArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s logical type is not [`DataType::RunEndEncoded`] with run ends of type `R`
+    /// * The `values`' data type is not equal to the inner field of `data_type`
+    /// * `run_ends.len()` is different from `values.len()`
+    /// * the run ends are not positive and strictly increasing
+    pub fn try_new(
+        data_type: DataType,
+        run_ends: Buffer<R>,
+        values: Arc<dyn Array>,
+    ) -> Result<Self, ArrowError> {
+        let (run_end_type, field) = Self::try_get_field(&data_type)?;
+        if run_end_type != R::RUN_END_TYPE {
+            return Err(ArrowError::oos(
+                "RunEndEncodedArray's run end type must match its generic type",
+            ));
+        }
+        if field.data_type() != values.data_type() {
+            return Err(ArrowError::oos(
+                "RunEndEncodedArray expects `values.data_type` to match its inner DataType",
+            ));
+        }
+        if run_ends.len() != values.len() {
+            return Err(ArrowError::oos(
+                "RunEndEncodedArray expects the number of run ends to equal the number of values",
+            ));
+        }
+
+        let mut previous = 0usize;
+        for end in run_ends.iter() {
+            let end = end
+                .to_usize()
+                .ok_or_else(|| ArrowError::oos("RunEndEncodedArray's run ends must be positive"))?;
+            if end <= previous {
+                return Err(ArrowError::oos(
+                    "RunEndEncodedArray's run ends must be positive and strictly increasing",
+                ));
+            }
+            previous = end;
+        }
+
+        Ok(Self {
+            data_type,
+            run_ends,
+            values,
+            offset: 0,
+            length: previous,
+        })
+    }
+
+    /// Creates a new [`RunEndEncodedArray`].
+    /// # Panics
+    /// This function panics iff:
+    /// * The `data_type`'s logical type is not [`DataType::RunEndEncoded`] with run ends of type `R`
+    /// * The `values`' data type is not equal to the inner field of `data_type`
+    /// * `run_ends.len()` is different from `values.len()`
+    /// * the run ends are not positive and strictly increasing
+    pub fn new(data_type: DataType, run_ends: Buffer<R>, values: Arc<dyn Array>) -> Self {
+        Self::try_new(data_type, run_ends, values).unwrap()
+    }
+
+    /// Creates a new [`RunEndEncodedArray`] whose [`DataType`] is inferred from `values`.
+    /// # Panics
+    /// This function panics iff:
+    /// * `run_ends.len()` is different from `values.len()`
+    /// * the run ends are not positive and strictly increasing
+    pub fn from_data(run_ends: Buffer<R>, values: Arc<dyn Array>) -> Self {
+        let data_type = Self::default_data_type(values.data_type().clone());
+        Self::new(data_type, run_ends, values)
+    }
+
+    /// Returns a new empty [`RunEndEncodedArray`].
+    pub fn new_empty(data_type: DataType) -> Self {
+        let values = new_empty_array(Self::get_field(&data_type).data_type().clone()).into();
+        Self::new(data_type, Buffer::new(), values)
+    }
+
+    /// Returns a new [`RunEndEncodedArray`] of `length` whose all slots are null.
+    /// # Implementation
+    /// This array has a single run whose value is null.
+    pub fn new_null(data_type: DataType, length: usize) -> Self {
+        if length == 0 {
+            return Self::new_empty(data_type);
+        }
+        let values = new_null_array(Self::get_field(&data_type).data_type().clone(), 1).into();
+        let run_end = R::from_usize(length).expect("length to fit the run end type");
+        Self::new(data_type, Buffer::from(vec![run_end]), values)
+    }
+
+    /// Returns the default [`DataType`] of a [`RunEndEncodedArray`] whose values are of
+    /// type `values`: `DataType::RunEndEncoded(R::RUN_END_TYPE, Field::new("values", values, true))`.
+    pub fn default_data_type(values: DataType) -> DataType {
+        DataType::RunEndEncoded(
+            R::RUN_END_TYPE,
+            Box::new(Field::new("values", values, true)),
+        )
+    }
+
+    /// Returns a slice of this [`RunEndEncodedArray`].
+    /// # Implementation
+    /// This operation is `O(1)`.
+    /// # Panics
+    /// iff `offset + length > self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new array cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Returns a slice of this [`RunEndEncodedArray`].
+    /// # Implementation
+    /// This operation is `O(1)`.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        Self {
+            data_type: self.data_type.clone(),
+            run_ends: self.run_ends.clone(),
+            values: self.values.clone(),
+            offset: self.offset + offset,
+            length,
+        }
+    }
+
+    pub(crate) fn try_get_field(data_type: &DataType) -> Result<(IntegerType, &Field), ArrowError> {
+        if let DataType::RunEndEncoded(run_end_type, field) = data_type.to_logical_type() {
+            Ok((*run_end_type, field.as_ref()))
+        } else {
+            Err(ArrowError::oos(
+                "RunEndEncodedArray must be initialized with DataType::RunEndEncoded",
+            ))
+        }
+    }
+
+    pub(crate) fn get_field(data_type: &DataType) -> &Field {
+        Self::try_get_field(data_type).unwrap().1
+    }
+}
+
+// accessors
+impl<R: RunEndIndex> RunEndEncodedArray<R> {
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// The logical offset of this array into its run ends.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the run ends of this array. Note that these are not affected by slicing:
+    /// use [`RunEndEncodedArray::offset`] to map them to the logical slots of this array.
+    #[inline]
+    pub fn run_ends(&self) -> &Buffer<R> {
+        &self.run_ends
+    }
+
+    /// Returns the values of this array, one per run.
+    #[inline]
+    pub fn values(&self) -> &Arc<dyn Array> {
+        &self.values
+    }
+
+    /// Returns the index in [`RunEndEncodedArray::values`] of the logical slot `index`.
+    /// # Implementation
+    /// This operation is `O(log(runs))`.
+    /// # Panics
+    /// iff `index >= self.len()`
+    #[inline]
+    pub fn physical_index(&self, index: usize) -> usize {
+        assert!(index < self.len());
+        let index = self.offset + index;
+        self.run_ends
+            .partition_point(|end| end.to_usize().unwrap() <= index)
+    }
+
+    /// Returns the range `[start, end)` of indices in [`RunEndEncodedArray::values`] covered
+    /// by this (possibly sliced) array.
+    pub fn physical_range(&self) -> (usize, usize) {
+        if self.is_empty() {
+            return (0, 0);
+        }
+        (
+            self.physical_index(0),
+            self.physical_index(self.len() - 1) + 1,
+        )
+    }
+
+    /// Returns whether this array is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the value of the [`RunEndEncodedArray`] at position `i`.
+    /// # Panics
+    /// iff `index >= self.len()`
+    #[inline]
+    pub fn value(&self, index: usize) -> Box<dyn Scalar> {
+        new_scalar(self.values.as_ref(), self.physical_index(index))
+    }
+}
+
+impl<R: RunEndIndex> Array for RunEndEncodedArray<R> {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        None
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice(offset, length))
+    }
+
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+
+    fn with_validity(&self, _: Option<Bitmap>) -> Box<dyn Array> {
+        panic!("cannot set validity of a run-end encoded array")
+    }
+
+    fn to_boxed(&self) -> Box<dyn Array> {
+        Box::new(self.clone())
+    }
+}
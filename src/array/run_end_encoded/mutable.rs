@@ -0,0 +1,318 @@
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::{
+    array::{mutable::hash_scalar, Array, MutableArray, TryExtend, TryPush, TryPushScalar},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    scalar::Scalar,
+};
+
+use super::{RunEndEncodedArray, RunEndIndex};
+
+/// A mutable, strong-typed version of [`RunEndEncodedArray`].
+///
+/// Consecutive equal values are collapsed into a single run as they are pushed.
+/// # Example
+/// Building a run-end encoded array of UTF8 with `i32` run ends.
+/// ```
+/// # use arrow2::array::{MutableRunEndEncodedArray, MutableUtf8Array, TryPush};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut array: MutableRunEndEncodedArray<i32, MutableUtf8Array<i32>> = MutableRunEndEncodedArray::new();
+/// array.try_push(Some("A"))?;
+/// array.try_push(Some("A"))?;
+/// array.push_null();
+/// array.try_push(Some("C"))?;
+/// assert_eq!(array.run_ends(), &[2, 3, 4]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MutableRunEndEncodedArray<R: RunEndIndex, M: MutableArray> {
+    data_type: DataType,
+    run_ends: Vec<R>,
+    // the bytes of the last pushed value (`None` for a null), if any value was pushed
+    last: Option<Option<Vec<u8>>>,
+    // scratch space where the bytes of a pushed value are written, to avoid an allocation per push
+    scratch: Vec<u8>,
+    values: M,
+}
+
+/// A [`Hasher`] that records the bytes it is fed instead of digesting them. Two values are
+/// written to the same bytes iff they are equal, which allows comparing values of any
+/// [`Hash`] type without a collision-prone digest.
+struct ValueBytes<'a>(&'a mut Vec<u8>);
+
+impl Hasher for ValueBytes<'_> {
+    fn finish(&self) -> u64 {
+        unreachable!("ValueBytes records bytes and is never finished")
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes)
+    }
+}
+
+impl<R: RunEndIndex, M: MutableArray> From<MutableRunEndEncodedArray<R, M>>
+    for RunEndEncodedArray<R>
+{
+    fn from(mut other: MutableRunEndEncodedArray<R, M>) -> Self {
+        RunEndEncodedArray::<R>::new(
+            other.data_type,
+            other.run_ends.into(),
+            other.values.as_arc(),
+        )
+    }
+}
+
+impl<R: RunEndIndex, M: MutableArray> From<M> for MutableRunEndEncodedArray<R, M> {
+    fn from(values: M) -> Self {
+        assert!(
+            values.is_empty(),
+            "MutableRunEndEncodedArray must be initialized with empty values"
+        );
+        Self {
+            data_type: RunEndEncodedArray::<R>::default_data_type(values.data_type().clone()),
+            run_ends: vec![],
+            last: None,
+            scratch: vec![],
+            values,
+        }
+    }
+}
+
+impl<R: RunEndIndex, M: MutableArray + Default> MutableRunEndEncodedArray<R, M> {
+    /// Creates an empty [`MutableRunEndEncodedArray`].
+    pub fn new() -> Self {
+        M::default().into()
+    }
+}
+
+impl<R: RunEndIndex, M: MutableArray + Default> Default for MutableRunEndEncodedArray<R, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: RunEndIndex, M: MutableArray> MutableRunEndEncodedArray<R, M> {
+    /// Pushes a null, which extends the current run when the last pushed value was also a null.
+    fn try_push_null(&mut self) -> Result<()> {
+        let end = self.next_end()?;
+        if self.last == Some(None) {
+            *self.run_ends.last_mut().unwrap() = end;
+        } else {
+            self.values.push_null();
+            self.run_ends.push(end);
+            self.last = Some(None);
+        }
+        Ok(())
+    }
+
+    /// Pushes `value`, which extends the current run when it equals the last pushed value.
+    fn try_push_valid<T: Hash>(&mut self, value: T) -> Result<()>
+    where
+        M: TryPush<Option<T>>,
+    {
+        let end = self.next_end()?;
+        self.scratch.clear();
+        value.hash(&mut ValueBytes(&mut self.scratch));
+        if matches!(&self.last, Some(Some(last)) if *last == self.scratch) {
+            *self.run_ends.last_mut().unwrap() = end;
+            return Ok(());
+        }
+
+        // the run is only started once `values` accepted the value, so that an error leaves
+        // this array unchanged
+        self.values.try_push(Some(value))?;
+        self.run_ends.push(end);
+        match &mut self.last {
+            Some(Some(last)) => std::mem::swap(last, &mut self.scratch),
+            _ => self.last = Some(Some(std::mem::take(&mut self.scratch))),
+        }
+        Ok(())
+    }
+
+    fn next_end(&self) -> Result<R> {
+        R::from_usize(self.len() + 1).ok_or(ArrowError::Overflow)
+    }
+
+    /// Pushes a null value
+    /// # Panics
+    /// Panics iff the length of the array does not fit in `R`.
+    pub fn push_null(&mut self) {
+        self.try_push_null().unwrap()
+    }
+
+    /// Returns the (logical) length of this array
+    pub fn len(&self) -> usize {
+        self.run_ends
+            .last()
+            .map(|end| end.to_usize().unwrap())
+            .unwrap_or(0)
+    }
+
+    /// Returns whether this array is empty
+    pub fn is_empty(&self) -> bool {
+        self.run_ends.is_empty()
+    }
+
+    /// Returns the run ends
+    pub fn run_ends(&self) -> &[R] {
+        &self.run_ends
+    }
+
+    /// Returns a reference to the inner values, one per run.
+    pub fn values(&self) -> &M {
+        &self.values
+    }
+
+    /// converts itself into [`Arc<dyn Array>`]
+    pub fn into_arc(self) -> Arc<dyn Array> {
+        let a: RunEndEncodedArray<R> = self.into();
+        Arc::new(a)
+    }
+
+    /// converts itself into [`Box<dyn Array>`]
+    pub fn into_box(self) -> Box<dyn Array> {
+        let a: RunEndEncodedArray<R> = self.into();
+        Box::new(a)
+    }
+
+    /// Shrinks the capacity of the [`MutableRunEndEncodedArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+        self.run_ends.shrink_to_fit();
+    }
+
+    fn take_array(&mut self) -> RunEndEncodedArray<R> {
+        self.last = None;
+        RunEndEncodedArray::<R>::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.run_ends).into(),
+            self.values.as_arc(),
+        )
+    }
+}
+
+impl<R: RunEndIndex> MutableRunEndEncodedArray<R, Box<dyn MutableArray>> {
+    // an empty array of `data_type` whose (empty) values are `values`
+    pub(crate) fn new_from(values: Box<dyn MutableArray>, data_type: DataType) -> Self {
+        Self {
+            data_type,
+            run_ends: vec![],
+            last: None,
+            scratch: vec![],
+            values,
+        }
+    }
+
+    // pushes `scalar`, a scalar of the values, which extends the current run when it equals
+    // the last pushed value
+    pub(crate) fn try_push_scalar(&mut self, scalar: &dyn Scalar) -> Result<()> {
+        if !scalar.is_valid() {
+            return self.try_push_null();
+        }
+        let end = self.next_end()?;
+        self.scratch.clear();
+        hash_scalar(scalar, &mut ValueBytes(&mut self.scratch));
+        if matches!(&self.last, Some(Some(last)) if *last == self.scratch) {
+            *self.run_ends.last_mut().unwrap() = end;
+            return Ok(());
+        }
+
+        self.values.as_mut().try_push_scalar(scalar)?;
+        self.run_ends.push(end);
+        match &mut self.last {
+            Some(Some(last)) => std::mem::swap(last, &mut self.scratch),
+            _ => self.last = Some(Some(std::mem::take(&mut self.scratch))),
+        }
+        Ok(())
+    }
+
+    // truncates this array to `len` slots and its values to the values of their runs
+    pub(crate) fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            // the run of the last slot is the first run ending at or after it
+            let runs = if len == 0 {
+                0
+            } else {
+                self.run_ends
+                    .partition_point(|end| end.to_usize().unwrap() < len)
+                    + 1
+            };
+            self.run_ends.truncate(runs);
+            if let Some(end) = self.run_ends.last_mut() {
+                *end = R::from_usize(len).unwrap();
+            }
+            // the last value is unknown, so that the next value starts a new run
+            self.last = None;
+        }
+        crate::array::mutable::truncate(self.values.as_mut(), self.run_ends.len());
+    }
+}
+
+impl<R: RunEndIndex, M: 'static + MutableArray> MutableArray for MutableRunEndEncodedArray<R, M> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.take_array())
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.take_array())
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
+
+impl<R, M, T: Hash> TryExtend<Option<T>> for MutableRunEndEncodedArray<R, M>
+where
+    R: RunEndIndex,
+    M: MutableArray + TryPush<Option<T>>,
+{
+    fn try_extend<II: IntoIterator<Item = Option<T>>>(&mut self, iter: II) -> Result<()> {
+        for value in iter {
+            self.try_push(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<R, M, T> TryPush<Option<T>> for MutableRunEndEncodedArray<R, M>
+where
+    R: RunEndIndex,
+    M: MutableArray + TryPush<Option<T>>,
+    T: Hash,
+{
+    fn try_push(&mut self, item: Option<T>) -> Result<()> {
+        match item {
+            Some(value) => self.try_push_valid(value),
+            None => self.try_push_null(),
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MutableArray},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::StructArray;
+
+/// The mutable version of [`StructArray`]. See [`MutableArray`] for more details.
+///
+/// A slot is pushed by pushing one value to each of its children and then calling
+/// [`MutableStructArray::try_push_valid`], or by calling [`MutableStructArray::push_null`].
+#[derive(Debug)]
+pub struct MutableStructArray {
+    data_type: DataType,
+    // invariant: all values have the same length
+    values: Vec<Box<dyn MutableArray>>,
+    validity: Option<MutableBitmap>,
+}
+
+impl From<MutableStructArray> for StructArray {
+    fn from(mut other: MutableStructArray) -> Self {
+        StructArray::new(
+            other.data_type,
+            other.values.iter_mut().map(|x| x.as_arc()).collect(),
+            other.validity.map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableStructArray {
+    /// Returns a new [`MutableStructArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Struct`].
+    /// * the children of `data_type` are empty
+    /// * the number of `values` is different from the number of children of `data_type`
+    /// * any of the `values`' data type is different from its corresponding child's data type
+    /// * any of the `values` has a different length than the first one
+    pub fn try_new(data_type: DataType, values: Vec<Box<dyn MutableArray>>) -> Result<Self> {
+        let fields = StructArray::try_get_fields(&data_type)?;
+        if fields.is_empty() {
+            return Err(ArrowError::oos(
+                "A MutableStructArray must contain at least one field",
+            ));
+        }
+        if fields.len() != values.len() {
+            return Err(ArrowError::oos(
+                "A MutableStructArray must have a number of fields in its DataType equal to the number of child values",
+            ));
+        }
+        if fields
+            .iter()
+            .zip(values.iter())
+            .any(|(field, value)| field.data_type() != value.data_type())
+        {
+            return Err(ArrowError::oos(
+                "The children DataTypes of a MutableStructArray must equal the children data types",
+            ));
+        }
+        let len = values[0].len();
+        if values.iter().any(|value| value.len() != len) {
+            return Err(ArrowError::oos(
+                "All children of a MutableStructArray must have the same length",
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            values,
+            validity: None,
+        })
+    }
+
+    /// Returns a new [`MutableStructArray`].
+    /// # Panics
+    /// This function panics under the same conditions as [`MutableStructArray::try_new`] errors.
+    pub fn new(data_type: DataType, values: Vec<Box<dyn MutableArray>>) -> Self {
+        Self::try_new(data_type, values).unwrap()
+    }
+
+    /// The length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values[0].len()
+    }
+
+    /// Whether this array is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The children of this array
+    pub fn values(&self) -> &[Box<dyn MutableArray>] {
+        &self.values
+    }
+
+    /// The children of this array as mutable references
+    pub fn mut_values(&mut self) -> &mut [Box<dyn MutableArray>] {
+        &mut self.values
+    }
+
+    /// Returns the child at position `i` downcasted to `M`, or `None` if it is not an `M`.
+    /// # Panics
+    /// Panics iff `i` is out of bounds.
+    pub fn value<M: MutableArray + 'static>(&mut self, i: usize) -> Option<&mut M> {
+        self.values[i].as_mut_any().downcast_mut::<M>()
+    }
+
+    /// Needs to be called after a value was pushed to each of the children.
+    /// This is a relatively low level function.
+    /// # Errors
+    /// Errors iff the children have different lengths.
+    pub fn try_push_valid(&mut self) -> Result<()> {
+        let len = self.len();
+        if self.values.iter().any(|value| value.len() != len) {
+            return Err(ArrowError::InvalidArgumentError(
+                "All children of a MutableStructArray must have the same length".to_string(),
+            ));
+        }
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    /// Pushes a null slot, pushing a null to each of the children.
+    pub fn push_null(&mut self) {
+        self.values.iter_mut().for_each(|x| x.push_null());
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.len();
+
+        let mut validity = MutableBitmap::with_capacity(len);
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// Converts itself into an [`Array`].
+    pub fn into_arc(self) -> Arc<dyn Array> {
+        let a: StructArray = self.into();
+        Arc::new(a)
+    }
+
+    /// Shrinks the capacity of the [`MutableStructArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.values.iter_mut().for_each(|x| x.shrink_to_fit());
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+
+    fn take_array(&mut self) -> StructArray {
+        StructArray::new(
+            self.data_type.clone(),
+            self.values.iter_mut().map(|x| x.as_arc()).collect(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableStructArray {
+    // truncates this array and its values to `len` slots
+    pub(crate) fn truncate(&mut self, len: usize) {
+        if let Some(validity) = &mut self.validity {
+            validity.truncate(len)
+        }
+        self.values
+            .iter_mut()
+            .for_each(|value| crate::array::mutable::truncate(value.as_mut(), len));
+    }
+}
+
+impl MutableArray for MutableStructArray {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.take_array())
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.take_array())
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
@@ -0,0 +1,331 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    array::{Array, MutableArray, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::UnionArray;
+
+/// The mutable version of [`UnionArray`]. See [`MutableArray`] for more details.
+///
+/// Each slot is pushed to one of its fields (its variant), identified by its type id.
+/// In sparse mode, the remaining fields receive a null so that all fields keep the
+/// length of the array; in dense mode, the offset of the value in its field is recorded instead.
+/// # Example
+/// ```
+/// # use arrow2::array::{Array, MutableArray, MutablePrimitiveArray, MutableUnionArray, MutableUtf8Array, UnionArray};
+/// # use arrow2::datatypes::{DataType, Field, UnionMode};
+/// # fn main() -> arrow2::error::Result<()> {
+/// let fields = vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ];
+/// let data_type = DataType::Union(fields, None, UnionMode::Dense);
+/// let mut array = MutableUnionArray::try_new(
+///     data_type,
+///     vec![
+///         Box::new(MutablePrimitiveArray::<i32>::new()) as Box<dyn MutableArray>,
+///         Box::new(MutableUtf8Array::<i32>::new()),
+///     ],
+/// )?;
+/// array.try_push_variant::<MutablePrimitiveArray<i32>, _>(0, Some(1))?;
+/// array.try_push_variant::<MutableUtf8Array<i32>, _>(1, Some("a"))?;
+/// array.try_push_variant::<MutablePrimitiveArray<i32>, _>(0, None)?;
+///
+/// let array: UnionArray = array.into();
+/// assert_eq!(array.types().as_slice(), &[0, 1, 0]);
+/// assert_eq!(array.offsets().unwrap().as_slice(), &[0, 0, 1]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MutableUnionArray {
+    data_type: DataType,
+    types: Vec<i8>,
+    fields: Vec<Box<dyn MutableArray>>,
+    // `None` when the union is sparse
+    offsets: Option<Vec<i32>>,
+    // maps type ids to the position of their field; `None` when type ids are positions
+    fields_map: Option<HashMap<i8, usize>>,
+}
+
+impl From<MutableUnionArray> for UnionArray {
+    fn from(mut other: MutableUnionArray) -> Self {
+        UnionArray::new(
+            other.data_type,
+            other.types.into(),
+            other.fields.iter_mut().map(|x| x.as_arc()).collect(),
+            other.offsets.map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableUnionArray {
+    /// Returns a new empty [`MutableUnionArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s logical type is not [`DataType::Union`].
+    /// * the number of `fields` is different from the number of children of `data_type`
+    /// * any of the `fields`' data type is different from its corresponding child's data type
+    /// * any of the `fields` is not empty
+    pub fn try_new(data_type: DataType, fields: Vec<Box<dyn MutableArray>>) -> Result<Self> {
+        let (children, ids, mode) = UnionArray::try_get_all(&data_type)?;
+
+        if children.len() != fields.len() {
+            return Err(ArrowError::oos(
+                "The number of `fields` must equal the number of children fields in DataType::Union",
+            ));
+        }
+        if children
+            .iter()
+            .zip(fields.iter())
+            .any(|(child, field)| child.data_type() != field.data_type())
+        {
+            return Err(ArrowError::oos(
+                "The children DataTypes of a MutableUnionArray must equal the children data types",
+            ));
+        }
+        if fields.iter().any(|field| !field.is_empty()) {
+            return Err(ArrowError::oos(
+                "MutableUnionArray must be initialized with empty fields",
+            ));
+        }
+
+        let fields_map = ids.map(|ids| {
+            ids.iter()
+                .enumerate()
+                .map(|(index, id)| (*id as i8, index))
+                .collect()
+        });
+        let offsets = if mode.is_sparse() { None } else { Some(vec![]) };
+
+        Ok(Self {
+            data_type,
+            types: vec![],
+            fields,
+            offsets,
+            fields_map,
+        })
+    }
+
+    /// Returns a new empty [`MutableUnionArray`].
+    /// # Panics
+    /// This function panics iff:
+    /// * `data_type`'s logical type is not [`DataType::Union`].
+    /// * the number of `fields` is different from the number of children of `data_type`
+    /// * any of the `fields`' data type is different from its corresponding child's data type
+    /// * any of the `fields` is not empty
+    pub fn new(data_type: DataType, fields: Vec<Box<dyn MutableArray>>) -> Self {
+        Self::try_new(data_type, fields).unwrap()
+    }
+
+    /// The length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Whether this array is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// The type ids of this array
+    pub fn types(&self) -> &[i8] {
+        &self.types
+    }
+
+    /// The offsets of this array, when it is dense
+    pub fn offsets(&self) -> Option<&[i32]> {
+        self.offsets.as_deref()
+    }
+
+    /// The fields of this array
+    pub fn fields(&self) -> &[Box<dyn MutableArray>] {
+        &self.fields
+    }
+
+    fn field_index(&self, type_id: i8) -> Result<usize> {
+        let index = match &self.fields_map {
+            Some(map) => map.get(&type_id).copied(),
+            None => Some(type_id as usize).filter(|index| *index < self.fields.len()),
+        };
+        index.ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The type id {} is not part of the union",
+                type_id
+            ))
+        })
+    }
+
+    // truncates this array to `len` slots and its fields to the slots of these
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.types.truncate(len);
+        let lengths = match &mut self.offsets {
+            Some(offsets) => {
+                offsets.truncate(len);
+                // the field of each slot of a dense union has a slot for it
+                let mut lengths = vec![0; self.fields.len()];
+                for type_id in &self.types {
+                    lengths[self.field_index(*type_id).unwrap()] += 1;
+                }
+                lengths
+            }
+            None => vec![len; self.fields.len()],
+        };
+        self.fields
+            .iter_mut()
+            .zip(lengths)
+            .for_each(|(field, length)| crate::array::mutable::truncate(field.as_mut(), length));
+    }
+
+    /// Pushes a new slot of variant `type_id`, whose value is pushed to its field by `push`.
+    /// This is a low level function: prefer [`MutableUnionArray::try_push_variant`] when the
+    /// type of the field is known.
+    /// # Errors
+    /// Errors iff `type_id` is not part of the union, `push` errors, or `push` does not
+    /// push exactly one slot to the field.
+    pub fn try_push_variant_with<F>(&mut self, type_id: i8, push: F) -> Result<()>
+    where
+        F: FnOnce(&mut dyn MutableArray) -> Result<()>,
+    {
+        let index = self.field_index(type_id)?;
+        let field = self.fields[index].as_mut();
+        let length = field.len();
+        let offset = i32::try_from(length).map_err(|_| ArrowError::Overflow)?;
+
+        push(field)?;
+        if field.len() != length + 1 {
+            return Err(ArrowError::InvalidArgumentError(
+                "Pushing a variant to a MutableUnionArray must push exactly one slot to its field"
+                    .to_string(),
+            ));
+        }
+
+        match &mut self.offsets {
+            Some(offsets) => offsets.push(offset),
+            None => self
+                .fields
+                .iter_mut()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .for_each(|(_, field)| field.push_null()),
+        }
+        self.types.push(type_id);
+        Ok(())
+    }
+
+    /// Pushes `value` as a new slot of variant `type_id`, whose field is of type `M`.
+    /// # Errors
+    /// Errors iff `type_id` is not part of the union, its field is not of type `M`, or
+    /// pushing `value` to the field errors.
+    pub fn try_push_variant<M, T>(&mut self, type_id: i8, value: T) -> Result<()>
+    where
+        M: MutableArray + TryPush<T> + 'static,
+    {
+        self.try_push_variant_with(type_id, |field| {
+            field
+                .as_mut_any()
+                .downcast_mut::<M>()
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "The field of type id {} is not a {}",
+                        type_id,
+                        std::any::type_name::<M>()
+                    ))
+                })?
+                .try_push(value)
+        })
+    }
+
+    /// Pushes `value` as a new slot of variant `type_id`, whose field is of type `M`.
+    /// # Panics
+    /// Panics iff `type_id` is not part of the union, its field is not of type `M`, or
+    /// pushing `value` to the field errors.
+    pub fn push_variant<M, T>(&mut self, type_id: i8, value: T)
+    where
+        M: MutableArray + TryPush<T> + 'static,
+    {
+        self.try_push_variant::<M, T>(type_id, value).unwrap()
+    }
+
+    /// Pushes a null slot to the array. Since a [`UnionArray`] has no validity, this pushes
+    /// a null to its first variant.
+    pub fn push_null(&mut self) {
+        let type_id = match UnionArray::get_all(&self.data_type).1 {
+            Some(ids) => ids[0] as i8,
+            None => 0,
+        };
+        self.try_push_variant_with(type_id, |field| {
+            field.push_null();
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    /// Converts itself into an [`Array`].
+    pub fn into_arc(self) -> Arc<dyn Array> {
+        let a: UnionArray = self.into();
+        Arc::new(a)
+    }
+
+    /// Shrinks the capacity of the [`MutableUnionArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        if let Some(offsets) = &mut self.offsets {
+            offsets.shrink_to_fit()
+        }
+        self.fields.iter_mut().for_each(|x| x.shrink_to_fit());
+    }
+
+    fn take_array(&mut self) -> UnionArray {
+        UnionArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            self.fields.iter_mut().map(|x| x.as_arc()).collect(),
+            self.offsets.as_mut().map(|x| std::mem::take(x).into()),
+        )
+    }
+}
+
+impl MutableArray for MutableUnionArray {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.take_array())
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.take_array())
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
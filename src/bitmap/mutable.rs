@@ -110,6 +110,15 @@ impl MutableBitmap {
         Some(value)
     }
 
+    /// Shortens the [`MutableBitmap`] to its first `len` bits. It does nothing when it has
+    /// `len` bits or less.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        if len < self.length {
+            self.length = len;
+            self.buffer.truncate(len.saturating_add(7) / 8);
+        }
+    }
+
     /// Returns the capacity of [`MutableBitmap`] in number of bits.
     #[inline]
     pub fn capacity(&self) -> usize {
@@ -0,0 +1,239 @@
+use crate::array::*;
+use crate::compute::hash::xxhash64;
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// The precision used by [`approx_distinct`], whose standard error is about 0.8%.
+pub const DEFAULT_HLL_PRECISION: u8 = 14;
+
+/// A [HyperLogLog](https://en.wikipedia.org/wiki/HyperLogLog) sketch, that estimates the number
+/// of distinct non-null values of the arrays it is [updated](HyperLogLog::update) with.
+///
+/// A sketch with precision `p` has `2^p` one-byte registers, and its estimate has a standard
+/// error of `1.04 / sqrt(2^p)`.
+/// Sketches are mergeable: updating a sketch with two arrays or [merging](HyperLogLog::merge)
+/// the sketches of each array results in the same sketch. Values are hashed by their bytes with
+/// XXH64 and a fixed seed, which is stable across platforms and versions, so that sketches can
+/// be built in different processes, exchanged via
+/// [`HyperLogLog::registers`] and [`HyperLogLog::try_from_registers`] and merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+fn check_precision(precision: u8) -> Result<()> {
+    if !(4..=18).contains(&precision) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The precision of a HyperLogLog must be between 4 and 18, but it is {}",
+            precision
+        )));
+    }
+    Ok(())
+}
+
+impl HyperLogLog {
+    /// Returns a new empty [`HyperLogLog`] with `2^precision` registers.
+    /// # Errors
+    /// Errors iff `precision` is not between 4 and 18.
+    pub fn try_new(precision: u8) -> Result<Self> {
+        check_precision(precision)?;
+        Ok(Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        })
+    }
+
+    /// Returns a [`HyperLogLog`] from its registers, as returned by [`HyperLogLog::registers`].
+    /// # Errors
+    /// Errors iff the number of registers is not a power of two between `2^4` and `2^18`.
+    pub fn try_from_registers(registers: Vec<u8>) -> Result<Self> {
+        let precision = registers.len().trailing_zeros() as u8;
+        if registers.len() != 1 << precision {
+            return Err(ArrowError::InvalidArgumentError(
+                "The number of registers of a HyperLogLog must be a power of two".to_string(),
+            ));
+        }
+        check_precision(precision)?;
+        Ok(Self {
+            precision,
+            registers,
+        })
+    }
+
+    /// The precision of this sketch
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// The registers of this sketch
+    pub fn registers(&self) -> &[u8] {
+        &self.registers
+    }
+
+    /// Adds a hash to the sketch
+    #[inline]
+    fn add(&mut self, hash: u64) {
+        let index = (hash >> (64 - self.precision)) as usize;
+        // the sentinel bit bounds the rank to `64 - precision + 1`
+        let remaining = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+        let register = &mut self.registers[index];
+        *register = (*register).max(rank);
+    }
+
+    /// Updates this sketch with the non-null values of `array`.
+    /// # Errors
+    /// Errors iff the type of `array` is not supported, see [`can_approx_distinct`].
+    pub fn update(&mut self, array: &dyn Array) -> Result<()> {
+        hashes(array)?
+            .into_iter()
+            .flatten()
+            .for_each(|hash| self.add(hash));
+        Ok(())
+    }
+
+    /// Merges `other` into this sketch, so that this sketch estimates the number of distinct
+    /// values of the arrays of both sketches.
+    /// # Errors
+    /// Errors iff the sketches have different precisions.
+    pub fn merge(&mut self, other: &Self) -> Result<()> {
+        if self.precision != other.precision {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Only HyperLogLogs of the same precision can be merged ({} != {})",
+                self.precision, other.precision
+            )));
+        }
+        self.registers
+            .iter_mut()
+            .zip(other.registers.iter())
+            .for_each(|(lhs, rhs)| *lhs = (*lhs).max(*rhs));
+        Ok(())
+    }
+
+    /// Returns the estimated number of distinct values
+    pub fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum = self
+            .registers
+            .iter()
+            .map(|register| 2f64.powi(-(*register as i32)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+
+        let zeros = self.registers.iter().filter(|x| **x == 0).count();
+        // small range correction via linear counting
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+}
+
+fn hash(bytes: &[u8]) -> u64 {
+    xxhash64(bytes, 0)
+}
+
+fn primitive_hashes<T: NativeType>(array: &dyn Array) -> Vec<Option<u64>> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array
+        .iter()
+        .map(|x| x.map(|x| hash(x.to_le_bytes().as_ref())))
+        .collect()
+}
+
+fn binary_hashes<O: Offset>(array: &dyn Array) -> Vec<Option<u64>> {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    array.iter().map(|x| x.map(hash)).collect()
+}
+
+fn utf8_hashes<O: Offset>(array: &dyn Array) -> Vec<Option<u64>> {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    array
+        .iter()
+        .map(|x| x.map(|x| hash(x.as_bytes())))
+        .collect()
+}
+
+fn dictionary_hashes<K: DictionaryKey>(array: &dyn Array) -> Result<Vec<Option<u64>>> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = hashes(array.values().as_ref())?;
+    Ok(array
+        .keys()
+        .iter()
+        .map(|key| key.and_then(|key| values[key.to_usize().unwrap()]))
+        .collect())
+}
+
+/// The hash of each slot of `array`, `None` for null slots
+fn hashes(array: &dyn Array) -> Result<Vec<Option<u64>>> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Null => vec![None; array.len()],
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array.iter().map(|x| x.map(|x| hash(&[x as u8]))).collect()
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            primitive_hashes::<$T>(array)
+        }),
+        Binary => binary_hashes::<i32>(array),
+        LargeBinary => binary_hashes::<i64>(array),
+        Utf8 => utf8_hashes::<i32>(array),
+        LargeUtf8 => utf8_hashes::<i64>(array),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            dictionary_hashes::<$T>(array)?
+        }),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The `approx_distinct` operator does not support type `{:?}`",
+                array.data_type(),
+            )))
+        }
+    })
+}
+
+/// Whether [`approx_distinct`] and [`HyperLogLog::update`] support `data_type`
+pub fn can_approx_distinct(data_type: &DataType) -> bool {
+    use PhysicalType::*;
+    matches!(
+        data_type.to_physical_type(),
+        Null | Boolean | Primitive(_) | Binary | LargeBinary | Utf8 | LargeUtf8 | Dictionary(_)
+    )
+}
+
+/// Returns the estimated number of distinct non-null values of `array`, using a
+/// [`HyperLogLog`] of precision [`DEFAULT_HLL_PRECISION`].
+///
+/// Use [`HyperLogLog`] directly to estimate the distinct values of multiple arrays
+/// (e.g. the chunks of a column).
+/// # Error
+/// Errors iff the operation is not supported, see [`can_approx_distinct`].
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::{approx_distinct, HyperLogLog};
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(2), Some(1), Some(3)]);
+/// assert_eq!(approx_distinct(&array).unwrap(), 3);
+///
+/// let other = Int32Array::from_slice(&[3, 4]);
+/// let mut sketch = HyperLogLog::try_new(12).unwrap();
+/// sketch.update(&array).unwrap();
+/// sketch.update(&other).unwrap();
+/// assert_eq!(sketch.estimate(), 4);
+/// ```
+pub fn approx_distinct(array: &dyn Array) -> Result<u64> {
+    let mut sketch = HyperLogLog::try_new(DEFAULT_HLL_PRECISION)?;
+    sketch.update(array)?;
+    Ok(sketch.estimate())
+}
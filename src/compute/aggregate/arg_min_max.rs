@@ -0,0 +1,226 @@
+use crate::array::{Array, Offset, PrimitiveArray, Utf8Array};
+use crate::compute::utils::is_greater;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// How [`argmin`] and [`argmax`] handle null values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullPolicy {
+    /// Nulls are ignored
+    #[default]
+    Ignore,
+    /// The result is `None` if the array has a null
+    Propagate,
+}
+
+/// How [`argmin`] and [`argmax`] handle NaN values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NanPolicy {
+    /// NaNs are larger than any other value
+    #[default]
+    Largest,
+    /// NaNs are ignored
+    Ignore,
+    /// The result is the index of the first NaN, if any
+    Propagate,
+}
+
+/// Options of [`argmin`] and [`argmax`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ArgMinMaxOptions {
+    /// How nulls are handled
+    pub nulls: NullPolicy,
+    /// How NaNs are handled
+    pub nans: NanPolicy,
+}
+
+/// Returns the index of the first item of `iter` for which no other item is better, according to
+/// `is_better(a, b)`.
+fn arg_extremum<T, I, F, N>(
+    iter: I,
+    has_nulls: bool,
+    options: ArgMinMaxOptions,
+    is_nan: N,
+    is_better: F,
+) -> Option<usize>
+where
+    I: Iterator<Item = Option<T>>,
+    F: Fn(&T, &T) -> bool,
+    N: Fn(&T) -> bool,
+{
+    if has_nulls && options.nulls == NullPolicy::Propagate {
+        return None;
+    }
+
+    let mut best: Option<(usize, T)> = None;
+    for (index, value) in iter.enumerate() {
+        let value = match value {
+            Some(value) => value,
+            None => continue,
+        };
+        if is_nan(&value) {
+            match options.nans {
+                NanPolicy::Propagate => return Some(index),
+                NanPolicy::Ignore => continue,
+                NanPolicy::Largest => {}
+            }
+        }
+        if best
+            .as_ref()
+            .map(|(_, best)| is_better(&value, best))
+            .unwrap_or(true)
+        {
+            best = Some((index, value));
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
+#[inline]
+#[allow(clippy::eq_op)]
+fn is_nan<T: PartialOrd>(a: &T) -> bool {
+    a != a
+}
+
+/// Returns the index of the minimum of a [`PrimitiveArray`]. See [`argmin`] for details.
+pub fn argmin_primitive<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    options: ArgMinMaxOptions,
+) -> Option<usize> {
+    let has_nulls = array.null_count() > 0;
+    arg_extremum(
+        array.iter(),
+        has_nulls,
+        options,
+        |x| is_nan(*x),
+        |a, b| is_greater(*b, *a),
+    )
+}
+
+/// Returns the index of the maximum of a [`PrimitiveArray`]. See [`argmax`] for details.
+pub fn argmax_primitive<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    options: ArgMinMaxOptions,
+) -> Option<usize> {
+    let has_nulls = array.null_count() > 0;
+    arg_extremum(
+        array.iter(),
+        has_nulls,
+        options,
+        |x| is_nan(*x),
+        |a, b| is_greater(*a, *b),
+    )
+}
+
+/// Returns the index of the minimum of a [`Utf8Array`]. See [`argmin`] for details.
+pub fn argmin_string<O: Offset>(array: &Utf8Array<O>, options: ArgMinMaxOptions) -> Option<usize> {
+    let has_nulls = array.null_count() > 0;
+    arg_extremum(array.iter(), has_nulls, options, |_| false, |a, b| a < b)
+}
+
+/// Returns the index of the maximum of a [`Utf8Array`]. See [`argmax`] for details.
+pub fn argmax_string<O: Offset>(array: &Utf8Array<O>, options: ArgMinMaxOptions) -> Option<usize> {
+    let has_nulls = array.null_count() > 0;
+    arg_extremum(array.iter(), has_nulls, options, |_| false, |a, b| a > b)
+}
+
+fn not_supported(name: &str, data_type: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "The `{}` operator does not support type `{:?}`",
+        name, data_type
+    ))
+}
+
+/// Returns the index of the minimum of `array`, or `None` if there is no minimum
+/// (e.g. all values are null).
+///
+/// The first index is returned when the minimum occurs multiple times.
+/// `options` declares how nulls and NaNs are handled; by default, nulls are ignored and
+/// NaNs are larger than any other value.
+/// # Error
+/// Errors iff the type does not support this operation, see [`can_argmin`].
+/// # Example
+/// ```
+/// use arrow2::array::Float64Array;
+/// use arrow2::compute::aggregate::{argmin, ArgMinMaxOptions, NanPolicy, NullPolicy};
+///
+/// let array = Float64Array::from(&[Some(2.0), None, Some(f64::NAN), Some(1.0), Some(1.0)]);
+/// assert_eq!(argmin(&array, ArgMinMaxOptions::default()).unwrap(), Some(3));
+///
+/// let options = ArgMinMaxOptions {
+///     nulls: NullPolicy::Ignore,
+///     nans: NanPolicy::Propagate,
+/// };
+/// assert_eq!(argmin(&array, options).unwrap(), Some(2));
+///
+/// let options = ArgMinMaxOptions {
+///     nulls: NullPolicy::Propagate,
+///     nans: NanPolicy::Largest,
+/// };
+/// assert_eq!(argmin(&array, options).unwrap(), None);
+/// ```
+pub fn argmin(array: &dyn Array, options: ArgMinMaxOptions) -> Result<Option<usize>> {
+    Ok(match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(_) => {
+            with_match_numeric_type!(array.data_type(), not_supported("argmin", array.data_type()), |$T| {
+                let array = array.as_any().downcast_ref().unwrap();
+                argmin_primitive::<$T>(array, options)
+            })
+        }
+        PhysicalType::Utf8 => argmin_string::<i32>(array.as_any().downcast_ref().unwrap(), options),
+        PhysicalType::LargeUtf8 => {
+            argmin_string::<i64>(array.as_any().downcast_ref().unwrap(), options)
+        }
+        _ => return Err(not_supported("argmin", array.data_type())),
+    })
+}
+
+/// Returns the index of the maximum of `array`, or `None` if there is no maximum
+/// (e.g. all values are null).
+///
+/// The first index is returned when the maximum occurs multiple times.
+/// `options` declares how nulls and NaNs are handled; by default, nulls are ignored and
+/// NaNs are larger than any other value.
+/// # Error
+/// Errors iff the type does not support this operation, see [`can_argmax`].
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::aggregate::{argmax, ArgMinMaxOptions};
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("c"), Some("b"), Some("c")]);
+/// assert_eq!(argmax(&array, ArgMinMaxOptions::default()).unwrap(), Some(2));
+/// ```
+pub fn argmax(array: &dyn Array, options: ArgMinMaxOptions) -> Result<Option<usize>> {
+    Ok(match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(_) => {
+            with_match_numeric_type!(array.data_type(), not_supported("argmax", array.data_type()), |$T| {
+                let array = array.as_any().downcast_ref().unwrap();
+                argmax_primitive::<$T>(array, options)
+            })
+        }
+        PhysicalType::Utf8 => argmax_string::<i32>(array.as_any().downcast_ref().unwrap(), options),
+        PhysicalType::LargeUtf8 => {
+            argmax_string::<i64>(array.as_any().downcast_ref().unwrap(), options)
+        }
+        _ => return Err(not_supported("argmax", array.data_type())),
+    })
+}
+
+/// Whether [`argmin`] supports `data_type`
+pub fn can_argmin(data_type: &DataType) -> bool {
+    match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => !matches!(
+            primitive,
+            PrimitiveType::DaysMs | PrimitiveType::MonthDayNano
+        ),
+        PhysicalType::Utf8 | PhysicalType::LargeUtf8 => true,
+        _ => false,
+    }
+}
+
+/// Whether [`argmax`] supports `data_type`
+pub fn can_argmax(data_type: &DataType) -> bool {
+    can_argmin(data_type)
+}
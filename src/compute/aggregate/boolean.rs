@@ -0,0 +1,64 @@
+use crate::array::BooleanArray;
+use crate::bitmap::Bitmap;
+
+/// Whether `op(values, validity)` is true for any pair of `u64` words of `values` and `validity`.
+/// Bits of the last words beyond the length of the bitmaps are unset.
+fn any_word<F: Fn(u64, u64) -> bool>(values: &Bitmap, validity: &Bitmap, op: F) -> bool {
+    let values = values.chunks::<u64>();
+    let validity = validity.chunks::<u64>();
+
+    let remainder_len = values.remainder_len();
+    let mask = if remainder_len == 0 {
+        0
+    } else {
+        u64::MAX >> (64 - remainder_len)
+    };
+    let remainder = (values.remainder() & mask, validity.remainder() & mask);
+
+    values
+        .zip(validity)
+        .any(|(values, validity)| op(values, validity))
+        || op(remainder.0, remainder.1)
+}
+
+/// Returns whether any of the non-null values of `array` is `true`, and `false` if
+/// `array` has no non-null values.
+///
+/// This operates on the bitmaps, 64 slots at a time.
+/// # Example
+/// ```
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::aggregate::any;
+///
+/// assert!(any(&BooleanArray::from(&[Some(false), None, Some(true)])));
+/// assert!(!any(&BooleanArray::from(&[Some(false), None])));
+/// ```
+pub fn any(array: &BooleanArray) -> bool {
+    match array.validity() {
+        None => array.values().null_count() < array.len(),
+        Some(validity) => any_word(array.values(), validity, |values, validity| {
+            values & validity != 0
+        }),
+    }
+}
+
+/// Returns whether all the non-null values of `array` are `true`, and `true` if
+/// `array` has no non-null values.
+///
+/// This operates on the bitmaps, 64 slots at a time.
+/// # Example
+/// ```
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::aggregate::all;
+///
+/// assert!(all(&BooleanArray::from(&[Some(true), None, Some(true)])));
+/// assert!(!all(&BooleanArray::from(&[Some(false), None, Some(true)])));
+/// ```
+pub fn all(array: &BooleanArray) -> bool {
+    match array.validity() {
+        None => array.values().null_count() == 0,
+        Some(validity) => !any_word(array.values(), validity, |values, validity| {
+            !values & validity != 0
+        }),
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use ahash::RandomState;
+
+use crate::array::*;
+use crate::compute::take::take;
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::super::utils::ByBytes;
+
+/// Returns the number of distinct values of `iter`, where `None` is counted iff `count_nulls`.
+fn count<K: Hash + Eq, I: Iterator<Item = Option<K>>>(iter: I, count_nulls: bool) -> u64 {
+    let mut has_null = false;
+    let set = iter
+        .filter_map(|x| {
+            has_null |= x.is_none();
+            x
+        })
+        .collect::<HashSet<K, RandomState>>();
+    set.len() as u64 + (count_nulls && has_null) as u64
+}
+
+fn count_primitive<T: NativeType>(array: &dyn Array, count_nulls: bool) -> u64 {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    count(array.iter().map(|x| x.map(|x| ByBytes(*x))), count_nulls)
+}
+
+fn count_utf8<O: Offset>(array: &dyn Array, count_nulls: bool) -> u64 {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    count(array.iter(), count_nulls)
+}
+
+fn count_dictionary<K: DictionaryKey>(array: &dyn Array, count_nulls: bool) -> Result<u64> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = array.values().as_ref();
+
+    // only the values whose key is used are counted
+    let mut used = vec![false; values.len()];
+    let mut has_null = false;
+    array.keys().iter().for_each(|key| match key {
+        Some(key) => used[key.to_usize().unwrap()] = true,
+        None => has_null = true,
+    });
+
+    let distinct = if used.iter().all(|x| *x) {
+        has_null |= values.null_count() > 0;
+        count_distinct(values, false)?
+    } else {
+        let indices = used
+            .iter()
+            .enumerate()
+            .filter(|(_, used)| **used)
+            .map(|(index, _)| index as u64)
+            .collect::<Vec<_>>();
+        let values = take(values, &UInt64Array::from_vec(indices))?;
+        has_null |= values.null_count() > 0;
+        count_distinct(values.as_ref(), false)?
+    };
+    Ok(distinct + (count_nulls && has_null) as u64)
+}
+
+/// Whether [`count_distinct`] supports `data_type`
+pub fn can_count_distinct(data_type: &DataType) -> bool {
+    use PhysicalType::*;
+    match data_type.to_physical_type() {
+        Primitive(_) | Utf8 | LargeUtf8 => true,
+        Dictionary(_) => match data_type.to_logical_type() {
+            DataType::Dictionary(_, values, _) => can_count_distinct(values.as_ref()),
+            _ => unreachable!(),
+        },
+        _ => false,
+    }
+}
+
+/// Returns the exact number of distinct values of `array`.
+///
+/// Nulls are counted as one distinct value iff `count_nulls`. Floats are compared by their
+/// bit representation. Dictionary arrays are counted by their values, i.e. keys pointing to equal
+/// values are counted once and a key pointing to a null value is counted as a null; only the
+/// values that are used by a key are hashed.
+/// # Errors
+/// Errors iff the type of `array` is not supported, see [`can_count_distinct`].
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::count_distinct;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(2), Some(1), None]);
+/// assert_eq!(count_distinct(&array, false).unwrap(), 2);
+/// assert_eq!(count_distinct(&array, true).unwrap(), 3);
+/// ```
+pub fn count_distinct(array: &dyn Array, count_nulls: bool) -> Result<u64> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            count_primitive::<$T>(array, count_nulls)
+        }),
+        Utf8 => count_utf8::<i32>(array, count_nulls),
+        LargeUtf8 => count_utf8::<i64>(array, count_nulls),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            count_dictionary::<$T>(array, count_nulls)?
+        }),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The `count_distinct` operator does not support type `{:?}`",
+                array.data_type(),
+            )))
+        }
+    })
+}
@@ -16,6 +16,23 @@ macro_rules! dyn_binary {
     }};
 }
 
+macro_rules! dyn_binview {
+    ($array:expr, $type:ty) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<BinaryViewArrayGeneric<$type>>()
+            .unwrap();
+
+        array.views().len() * std::mem::size_of::<i128>()
+            + array
+                .data_buffers()
+                .iter()
+                .map(|buffer| buffer.len())
+                .sum::<usize>()
+            + validity_size(array.validity())
+    }};
+}
+
 /// Returns the total (heap) allocated size of the array in bytes.
 /// # Implementation
 /// This estimation is the sum of the size of its buffers, validity, including nested arrays.
@@ -54,6 +71,8 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         LargeBinary => dyn_binary!(array, BinaryArray<i64>, i64),
         Utf8 => dyn_binary!(array, Utf8Array<i32>, i32),
         LargeUtf8 => dyn_binary!(array, Utf8Array<i64>, i64),
+        BinaryView => dyn_binview!(array, [u8]),
+        Utf8View => dyn_binview!(array, str),
         List => {
             let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
             estimated_bytes_size(array.values().as_ref())
@@ -108,5 +127,13 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
             let offsets = array.offsets().len() * std::mem::size_of::<i32>();
             offsets + estimated_bytes_size(array.field().as_ref()) + validity_size(array.validity())
         }
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<RunEndEncodedArray<$T>>()
+                .unwrap();
+            array.run_ends().len() * std::mem::size_of::<$T>()
+                + estimated_bytes_size(array.values().as_ref())
+        }),
     }
 }
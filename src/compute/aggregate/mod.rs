@@ -1,4 +1,19 @@
 //! Contains different aggregation functions
+#[cfg(feature = "compute_aggregate")]
+mod arg_min_max;
+#[cfg(feature = "compute_aggregate")]
+pub use arg_min_max::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod approx_distinct;
+#[cfg(feature = "compute_aggregate")]
+pub use approx_distinct::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod count_distinct;
+#[cfg(feature = "compute_aggregate")]
+pub use count_distinct::*;
+
 #[cfg(feature = "compute_aggregate")]
 mod sum;
 #[cfg(feature = "compute_aggregate")]
@@ -9,6 +24,21 @@ mod min_max;
 #[cfg(feature = "compute_aggregate")]
 pub use min_max::*;
 
+#[cfg(feature = "compute_aggregate")]
+mod boolean;
+#[cfg(feature = "compute_aggregate")]
+pub use boolean::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod quantile;
+#[cfg(feature = "compute_aggregate")]
+pub use quantile::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod variance;
+#[cfg(feature = "compute_aggregate")]
+pub use variance::*;
+
 mod memory;
 pub use memory::*;
 #[cfg(feature = "compute_aggregate")]
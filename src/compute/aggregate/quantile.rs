@@ -0,0 +1,160 @@
+use std::cmp::Ordering;
+
+use num_traits::ToPrimitive;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::scalar::{PrimitiveScalar, Scalar};
+use crate::types::NativeType;
+
+/// How [`quantile`] computes a quantile that lies between two values `lower <= higher`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantileInterpolation {
+    /// `lower + (higher - lower) * fraction`, where `fraction` is the fractional part of the
+    /// quantile's position
+    Linear,
+    /// `lower`
+    Lower,
+    /// `higher`
+    Higher,
+    /// `lower` or `higher`, whichever is nearest, rounding half to even positions
+    Nearest,
+    /// `(lower + higher) / 2`
+    Midpoint,
+}
+
+/// Total order of a [`PartialOrd`] where NaN is larger than any other value
+#[inline]
+#[allow(clippy::eq_op)]
+fn total_order<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or_else(|| (a != a).cmp(&(b != b)))
+}
+
+/// Returns the quantile `q` of the non-null values of a [`PrimitiveArray`], or `None`
+/// if it has no non-null values. NaNs are larger than any other value.
+///
+/// The quantile is found by selection (`O(N)` on average) on a copy of the non-null values,
+/// which are not sorted.
+/// # Errors
+/// Errors iff `q` is not within `[0, 1]`.
+pub fn quantile_primitive<T: NativeType + PartialOrd + ToPrimitive>(
+    array: &PrimitiveArray<T>,
+    q: f64,
+    interpolation: QuantileInterpolation,
+) -> Result<Option<f64>> {
+    if !(0.0..=1.0).contains(&q) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The quantile must be within [0, 1] but it is {}",
+            q
+        )));
+    }
+
+    let mut values = match array.validity() {
+        None => array.values().to_vec(),
+        Some(_) => array.iter().flatten().copied().collect(),
+    };
+    if values.is_empty() {
+        return Ok(None);
+    }
+
+    let position = q * (values.len() - 1) as f64;
+    let lower_index = position.floor() as usize;
+    let fraction = position - lower_index as f64;
+
+    let (_, lower, higher) = values.select_nth_unstable_by(lower_index, total_order);
+    let lower = lower.to_f64().unwrap();
+    // the value after `lower` is the minimum of the values after it
+    let higher = higher
+        .iter()
+        .min_by(|a, b| total_order(*a, *b))
+        .map(|x| x.to_f64().unwrap())
+        .unwrap_or(lower);
+
+    Ok(Some(match interpolation {
+        QuantileInterpolation::Linear if fraction == 0.0 => lower,
+        QuantileInterpolation::Linear => lower + (higher - lower) * fraction,
+        QuantileInterpolation::Lower => lower,
+        QuantileInterpolation::Higher if fraction == 0.0 => lower,
+        QuantileInterpolation::Higher => higher,
+        QuantileInterpolation::Nearest => match fraction.partial_cmp(&0.5).unwrap() {
+            Ordering::Less => lower,
+            Ordering::Greater => higher,
+            Ordering::Equal if lower_index & 1 == 0 => lower,
+            Ordering::Equal => higher,
+        },
+        QuantileInterpolation::Midpoint if fraction == 0.0 => lower,
+        QuantileInterpolation::Midpoint => (lower + higher) / 2.0,
+    }))
+}
+
+/// Returns the median of the non-null values of a [`PrimitiveArray`], or `None`
+/// if it has no non-null values. See [`quantile_primitive`] for details.
+pub fn median_primitive<T: NativeType + PartialOrd + ToPrimitive>(
+    array: &PrimitiveArray<T>,
+) -> Option<f64> {
+    quantile_primitive(array, 0.5, QuantileInterpolation::Linear).unwrap()
+}
+
+fn quantile_not_supported(name: &str, data_type: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "The `{}` operator does not support type `{:?}`",
+        name, data_type
+    ))
+}
+
+/// Whether [`quantile`] and [`median`] support `data_type`
+pub fn can_quantile(data_type: &DataType) -> bool {
+    use crate::datatypes::PrimitiveType::*;
+    match (data_type.to_logical_type(), data_type.to_physical_type()) {
+        (DataType::Decimal(_, _), _) => false,
+        (_, PhysicalType::Primitive(primitive)) => !matches!(primitive, DaysMs | MonthDayNano),
+        _ => false,
+    }
+}
+
+/// Returns the quantile `q` of the non-null values of `array` as a [`Scalar`] of type
+/// [`DataType::Float64`], null if `array` has no non-null values.
+/// See [`quantile_primitive`] for details.
+/// # Error
+/// Errors iff `q` is not within `[0, 1]` or the operation is not supported,
+/// see [`can_quantile`].
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::{quantile, QuantileInterpolation};
+/// use arrow2::scalar::{PrimitiveScalar, Scalar};
+///
+/// let array = Int32Array::from(&[Some(4), None, Some(1), Some(3), Some(2)]);
+///
+/// let result = quantile(&array, 0.5, QuantileInterpolation::Linear).unwrap();
+/// assert_eq!(result.as_ref(), &PrimitiveScalar::from(Some(2.5f64)) as &dyn Scalar);
+///
+/// let result = quantile(&array, 0.5, QuantileInterpolation::Lower).unwrap();
+/// assert_eq!(result.as_ref(), &PrimitiveScalar::from(Some(2.0f64)) as &dyn Scalar);
+/// ```
+pub fn quantile(
+    array: &dyn Array,
+    q: f64,
+    interpolation: QuantileInterpolation,
+) -> Result<Box<dyn Scalar>> {
+    if let DataType::Decimal(_, _) = array.data_type().to_logical_type() {
+        return Err(quantile_not_supported("quantile", array.data_type()));
+    }
+    with_match_numeric_type!(array.data_type(), quantile_not_supported("quantile", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(PrimitiveScalar::new(
+            DataType::Float64,
+            quantile_primitive::<$T>(array, q, interpolation)?,
+        )))
+    })
+}
+
+/// Returns the median of the non-null values of `array` as a [`Scalar`] of type
+/// [`DataType::Float64`], null if `array` has no non-null values.
+/// See [`quantile_primitive`] for details.
+/// # Error
+/// Errors iff the operation is not supported, see [`can_quantile`].
+pub fn median(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    quantile(array, 0.5, QuantileInterpolation::Linear)
+}
@@ -1,6 +1,7 @@
 use std::ops::Add;
 
-use crate::types::simd::{i128x8, NativeSimd};
+use crate::types::f16;
+use crate::types::simd::{f16x32, i128x8, NativeSimd};
 
 use super::{SimdOrd, Sum};
 
@@ -94,11 +95,63 @@ macro_rules! simd_ord_int {
     };
 }
 
-pub(super) use simd_add;
-pub(super) use simd_ord_int;
+macro_rules! simd_ord_float {
+    ($simd:tt, $type:ty) => {
+        impl SimdOrd<$type> for $simd {
+            const MIN: $type = <$type>::NAN;
+            const MAX: $type = <$type>::NAN;
+
+            #[inline]
+            fn max_element(self) -> $type {
+                self.0.iter().copied().fold(Self::MIN, <$type>::max)
+            }
+
+            #[inline]
+            fn min_element(self) -> $type {
+                self.0.iter().copied().fold(Self::MAX, <$type>::min)
+            }
+
+            #[inline]
+            fn max_lane(self, x: Self) -> Self {
+                let mut result = <$simd>::default();
+                result
+                    .0
+                    .iter_mut()
+                    .zip(self.0.iter())
+                    .zip(x.0.iter())
+                    .for_each(|((a, b), c)| *a = (*b).max(*c));
+                result
+            }
+
+            #[inline]
+            fn min_lane(self, x: Self) -> Self {
+                let mut result = <$simd>::default();
+                result
+                    .0
+                    .iter_mut()
+                    .zip(self.0.iter())
+                    .zip(x.0.iter())
+                    .for_each(|((a, b), c)| *a = (*b).min(*c));
+                result
+            }
+
+            #[inline]
+            fn new_min() -> Self {
+                Self([Self::MAX; <$simd>::LANES])
+            }
+
+            #[inline]
+            fn new_max() -> Self {
+                Self([Self::MIN; <$simd>::LANES])
+            }
+        }
+    };
+}
 
 simd_add!(i128x8, i128, 8, add);
 simd_ord_int!(i128x8, i128);
+simd_add!(f16x32, f16, 32, add);
+simd_ord_float!(f16x32, f16);
 
 #[cfg(not(feature = "simd"))]
 mod native;
@@ -0,0 +1,122 @@
+use num_traits::ToPrimitive;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::scalar::{PrimitiveScalar, Scalar};
+use crate::types::NativeType;
+
+/// Returns the number of non-null values, their mean and the sum of the squares of their
+/// differences to the mean, computed in a single pass with Welford's algorithm.
+fn welford<T: NativeType + ToPrimitive>(array: &PrimitiveArray<T>) -> (usize, f64, f64) {
+    let mut count = 0;
+    let mut mean = 0.0;
+    let mut m2 = 0.0;
+    let mut update = |value: &T| {
+        let value = value.to_f64().unwrap();
+        count += 1;
+        let delta = value - mean;
+        mean += delta / count as f64;
+        m2 += delta * (value - mean);
+    };
+    match array.validity() {
+        None => array.values().iter().for_each(&mut update),
+        Some(_) => array.iter().flatten().for_each(&mut update),
+    }
+    (count, mean, m2)
+}
+
+/// Returns the variance of the non-null values of a [`PrimitiveArray`] with `ddof`
+/// delta degrees of freedom, i.e. the sum of their squared differences to their mean
+/// divided by `N - ddof`, where `N` is the number of non-null values.
+///
+/// `ddof = 0` is the population variance and `ddof = 1` the sample variance.
+/// Returns `None` if `N <= ddof`.
+/// The variance is computed in a single pass with Welford's algorithm, which is numerically
+/// stable.
+pub fn variance_primitive<T: NativeType + ToPrimitive>(
+    array: &PrimitiveArray<T>,
+    ddof: usize,
+) -> Option<f64> {
+    let (count, _, m2) = welford(array);
+    (count > ddof).then(|| m2 / (count - ddof) as f64)
+}
+
+/// Returns the standard deviation of the non-null values of a [`PrimitiveArray`] with `ddof`
+/// delta degrees of freedom. See [`variance_primitive`] for details.
+pub fn stddev_primitive<T: NativeType + ToPrimitive>(
+    array: &PrimitiveArray<T>,
+    ddof: usize,
+) -> Option<f64> {
+    variance_primitive(array, ddof).map(|x| x.sqrt())
+}
+
+fn variance_not_supported(name: &str, data_type: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "The `{}` operator does not support type `{:?}`",
+        name, data_type
+    ))
+}
+
+/// Whether [`variance`] and [`stddev`] support `data_type`
+pub fn can_variance(data_type: &DataType) -> bool {
+    use crate::datatypes::PrimitiveType::*;
+    match (data_type.to_logical_type(), data_type.to_physical_type()) {
+        (DataType::Decimal(_, _), _) => false,
+        (_, PhysicalType::Primitive(primitive)) => !matches!(primitive, DaysMs | MonthDayNano),
+        _ => false,
+    }
+}
+
+/// Returns the variance of the non-null values of `array` with `ddof` delta degrees of freedom
+/// as a [`Scalar`] of type [`DataType::Float64`], null if the number of non-null values is
+/// smaller or equal to `ddof`.
+///
+/// `ddof = 0` is the population variance and `ddof = 1` the sample variance.
+/// See [`variance_primitive`] for details.
+/// # Error
+/// Errors iff the operation is not supported, see [`can_variance`].
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::variance;
+/// use arrow2::scalar::{PrimitiveScalar, Scalar};
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(2), Some(3), Some(4)]);
+///
+/// let result = variance(&array, 0).unwrap();
+/// assert_eq!(result.as_ref(), &PrimitiveScalar::from(Some(1.25f64)) as &dyn Scalar);
+///
+/// let result = variance(&array, 1).unwrap();
+/// let result = result.as_any().downcast_ref::<PrimitiveScalar<f64>>().unwrap();
+/// assert!((result.value().unwrap() - 5.0 / 3.0).abs() < 1e-12);
+/// ```
+pub fn variance(array: &dyn Array, ddof: usize) -> Result<Box<dyn Scalar>> {
+    if let DataType::Decimal(_, _) = array.data_type().to_logical_type() {
+        return Err(variance_not_supported("variance", array.data_type()));
+    }
+    with_match_numeric_type!(array.data_type(), variance_not_supported("variance", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(PrimitiveScalar::new(
+            DataType::Float64,
+            variance_primitive::<$T>(array, ddof),
+        )))
+    })
+}
+
+/// Returns the standard deviation of the non-null values of `array` with `ddof` delta degrees
+/// of freedom as a [`Scalar`] of type [`DataType::Float64`]. See [`variance`] for details.
+/// # Error
+/// Errors iff the operation is not supported, see [`can_variance`].
+pub fn stddev(array: &dyn Array, ddof: usize) -> Result<Box<dyn Scalar>> {
+    if let DataType::Decimal(_, _) = array.data_type().to_logical_type() {
+        return Err(variance_not_supported("stddev", array.data_type()));
+    }
+    with_match_numeric_type!(array.data_type(), variance_not_supported("stddev", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(PrimitiveScalar::new(
+            DataType::Float64,
+            stddev_primitive::<$T>(array, ddof),
+        )))
+    })
+}
@@ -0,0 +1,121 @@
+//! Definition of transcendental operations with float arrays, such as [`sin`], [`ln`] and
+//! [`sqrt`].
+//!
+//! Like in Rust, values outside of the domain of an operation (e.g. the square root of a negative
+//! number) result in `NaN` or infinity, not in nulls. Nulls are preserved.
+use num_traits::Float;
+
+use crate::{array::PrimitiveArray, compute::arity::binary, compute::arity::unary};
+
+use super::NativeArithmetics;
+
+macro_rules! float_unary {
+    ($(#[$meta:meta])* $name:ident, $op:ident) => {
+        $(#[$meta])*
+        pub fn $name<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+        where
+            T: NativeArithmetics + Float,
+        {
+            unary(array, |x| x.$op(), array.data_type().clone())
+        }
+    };
+}
+
+float_unary!(
+    /// Computes the sine of each value of `array`, in radians.
+    /// # Example
+    /// ```
+    /// use arrow2::compute::arithmetics::basic::sin;
+    /// use arrow2::array::Float64Array;
+    ///
+    /// let a = Float64Array::from(&[Some(0.0), None]);
+    /// assert_eq!(sin(&a), Float64Array::from(&[Some(0.0), None]));
+    /// ```
+    sin,
+    sin
+);
+float_unary!(
+    /// Computes the cosine of each value of `array`, in radians.
+    cos,
+    cos
+);
+float_unary!(
+    /// Computes the tangent of each value of `array`, in radians.
+    tan,
+    tan
+);
+float_unary!(
+    /// Computes the arcsine of each value of `array`, in radians in `[-pi/2, pi/2]`.
+    /// Values outside of `[-1, 1]` result in `NaN`.
+    asin,
+    asin
+);
+float_unary!(
+    /// Computes the arccosine of each value of `array`, in radians in `[0, pi]`.
+    /// Values outside of `[-1, 1]` result in `NaN`.
+    acos,
+    acos
+);
+float_unary!(
+    /// Computes the arctangent of each value of `array`, in radians in `[-pi/2, pi/2]`.
+    atan,
+    atan
+);
+float_unary!(
+    /// Computes the natural logarithm of each value of `array`.
+    /// Zero results in negative infinity and negative values in `NaN`.
+    /// # Example
+    /// ```
+    /// use arrow2::compute::arithmetics::basic::ln;
+    /// use arrow2::array::Float32Array;
+    ///
+    /// let a = Float32Array::from(&[Some(1.0), None, Some(0.0)]);
+    /// assert_eq!(ln(&a), Float32Array::from(&[Some(0.0), None, Some(f32::NEG_INFINITY)]));
+    /// ```
+    ln,
+    ln
+);
+float_unary!(
+    /// Computes the base 10 logarithm of each value of `array`.
+    /// Zero results in negative infinity and negative values in `NaN`.
+    log10,
+    log10
+);
+float_unary!(
+    /// Computes the base 2 logarithm of each value of `array`.
+    /// Zero results in negative infinity and negative values in `NaN`.
+    log2,
+    log2
+);
+float_unary!(
+    /// Computes `e^x` for each value `x` of `array`.
+    exp,
+    exp
+);
+float_unary!(
+    /// Computes the square root of each value of `array`.
+    /// Negative values result in `NaN`.
+    sqrt,
+    sqrt
+);
+
+/// Computes the four quadrant arctangent of `lhs` (`y`) and `rhs` (`x`), in radians in
+/// `[-pi, pi]`.
+/// # Panic
+/// This function panics when the arrays have different lengths.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::basic::atan2;
+/// use arrow2::array::Float64Array;
+///
+/// let y = Float64Array::from(&[Some(0.0), Some(1.0), None]);
+/// let x = Float64Array::from(&[Some(1.0), Some(0.0), Some(1.0)]);
+/// let expected = Float64Array::from(&[Some(0.0), Some(std::f64::consts::FRAC_PI_2), None]);
+/// assert_eq!(atan2(&y, &x), expected);
+/// ```
+pub fn atan2<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Float,
+{
+    binary(lhs, rhs, lhs.data_type().clone(), |y, x| y.atan2(x))
+}
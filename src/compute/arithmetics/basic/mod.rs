@@ -9,12 +9,16 @@ mod add;
 pub use add::*;
 mod div;
 pub use div::*;
+mod math;
+pub use math::*;
 mod mul;
 pub use mul::*;
 mod pow;
 pub use pow::*;
 mod rem;
 pub use rem::*;
+mod round;
+pub use round::*;
 mod sub;
 pub use sub::*;
 
@@ -22,7 +26,10 @@ use std::ops::Neg;
 
 use num_traits::{CheckedNeg, WrappingNeg};
 
-use crate::{array::PrimitiveArray, types::NativeType};
+use crate::{
+    array::PrimitiveArray,
+    types::{f16, NativeType},
+};
 
 use super::super::arity::{unary, unary_checked};
 
@@ -39,6 +46,7 @@ impl NativeArithmetics for i8 {}
 impl NativeArithmetics for i16 {}
 impl NativeArithmetics for i32 {}
 impl NativeArithmetics for i64 {}
+impl NativeArithmetics for f16 {}
 impl NativeArithmetics for f32 {}
 impl NativeArithmetics for f64 {}
 
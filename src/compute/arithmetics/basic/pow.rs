@@ -1,9 +1,9 @@
 //! Definition of basic pow operations with primitive arrays
-use num_traits::{checked_pow, CheckedMul, One, Pow};
+use num_traits::{CheckedMul, Float, One, Pow, ToPrimitive};
 
 use crate::{
     array::PrimitiveArray,
-    compute::arity::{unary, unary_checked},
+    compute::arity::{binary, binary_checked, unary, unary_checked},
 };
 
 use super::NativeArithmetics;
@@ -46,7 +46,74 @@ pub fn checked_powf_scalar<T>(array: &PrimitiveArray<T>, exponent: usize) -> Pri
 where
     T: NativeArithmetics + CheckedMul + One,
 {
-    let op = move |a: T| checked_pow(a, exponent);
+    let op = move |a: T| num_traits::checked_pow(a, exponent);
 
     unary_checked(array, op, array.data_type().clone())
 }
+
+/// Raises the values of `lhs` to the power of the values of `rhs`.
+/// # Panic
+/// This function panics when the arrays have different lengths.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::powf;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(2.0), None, Some(4.0)]);
+/// let b = Float64Array::from(&[Some(3.0), Some(1.0), Some(0.5)]);
+/// let expected = Float64Array::from(&[Some(8.0), None, Some(2.0)]);
+/// assert_eq!(powf(&a, &b), expected);
+/// ```
+pub fn powf<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Float,
+{
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| a.powf(b))
+}
+
+/// Checked operation of raising the values of `lhs` to the power of the values of `rhs`.
+/// Values whose result overflows or whose exponent is negative are set to null.
+/// # Panic
+/// This function panics when the arrays have different lengths.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_pow;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(2), None, Some(2), Some(2)]);
+/// let b = Int8Array::from(&[Some(3), Some(1), Some(7), Some(-1)]);
+/// let expected = Int8Array::from(&[Some(8), None, None, None]);
+/// assert_eq!(checked_pow(&a, &b), expected);
+/// ```
+pub fn checked_pow<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + CheckedMul + One + ToPrimitive,
+{
+    let op = |a: T, b: T| b.to_usize().and_then(|b| num_traits::checked_pow(a, b));
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked operation of raising the values of `lhs` to the power of `rhs`.
+/// Values whose result overflows are set to null, and all values are null if `rhs` is negative.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_pow_scalar;
+/// use arrow2::array::Int32Array;
+///
+/// let a = Int32Array::from(&[Some(3), None, Some(-50000)]);
+/// let expected = Int32Array::from(&[Some(9), None, None]);
+/// assert_eq!(checked_pow_scalar(&a, &2), expected);
+/// ```
+pub fn checked_pow_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + CheckedMul + One + ToPrimitive,
+{
+    match rhs.to_usize() {
+        Some(exponent) => checked_powf_scalar(lhs, exponent),
+        None => PrimitiveArray::new_null(lhs.data_type().clone(), lhs.len()),
+    }
+}
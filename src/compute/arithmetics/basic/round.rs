@@ -0,0 +1,101 @@
+//! Definition of rounding operations with float arrays, to a number of decimal places.
+use num_traits::Float;
+
+use crate::{
+    array::PrimitiveArray,
+    compute::{arithmetics::RoundMode, arity::unary},
+};
+
+use super::NativeArithmetics;
+
+/// Applies `op` to `x` scaled by `10^decimals`, and scales the result back.
+/// `x` is returned as is when the scaling is not representable.
+#[inline]
+fn with_decimals<T: Float, F: Fn(T) -> T>(x: T, decimals: i32, op: F) -> T {
+    let ten = T::from(10.0).unwrap();
+    let result = if decimals >= 0 {
+        let factor = ten.powi(decimals);
+        op(x * factor) / factor
+    } else {
+        // dividing by an exact power of 10 is more precise than multiplying by e.g. `0.01`
+        let factor = ten.powi(-decimals);
+        op(x / factor) * factor
+    };
+    if result.is_finite() || !x.is_finite() {
+        result
+    } else {
+        x
+    }
+}
+
+#[inline]
+fn round_half_even<T: Float>(x: T) -> T {
+    let rounded = x.round();
+    let two = T::one() + T::one();
+    if (rounded - x).abs() == T::from(0.5).unwrap() && rounded % two != T::zero() {
+        rounded - x.signum()
+    } else {
+        rounded
+    }
+}
+
+/// Rounds the values of `array` to `decimals` decimal places, rounding half-way values
+/// according to `mode`.
+///
+/// A negative `decimals` rounds to a power of 10, e.g. `-2` rounds to the nearest hundred.
+/// # Example
+/// ```
+/// use arrow2::array::Float64Array;
+/// use arrow2::compute::arithmetics::{basic::round, RoundMode};
+///
+/// let array = Float64Array::from(&[Some(2.5), None, Some(1.25), Some(1250.0)]);
+///
+/// let result = round(&array, 0, RoundMode::HalfEven);
+/// assert_eq!(result, Float64Array::from(&[Some(2.0), None, Some(1.0), Some(1250.0)]));
+///
+/// let result = round(&array, -2, RoundMode::HalfUp);
+/// assert_eq!(result, Float64Array::from(&[Some(0.0), None, Some(0.0), Some(1300.0)]));
+/// ```
+pub fn round<T>(array: &PrimitiveArray<T>, decimals: i32, mode: RoundMode) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Float,
+{
+    match mode {
+        RoundMode::HalfEven => unary(
+            array,
+            |x| with_decimals(x, decimals, round_half_even),
+            array.data_type().clone(),
+        ),
+        RoundMode::HalfUp => unary(
+            array,
+            |x| with_decimals(x, decimals, T::round),
+            array.data_type().clone(),
+        ),
+    }
+}
+
+/// Rounds the values of `array` down to `decimals` decimal places.
+/// A negative `decimals` rounds down to a power of 10.
+pub fn floor<T>(array: &PrimitiveArray<T>, decimals: i32) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Float,
+{
+    unary(
+        array,
+        |x| with_decimals(x, decimals, T::floor),
+        array.data_type().clone(),
+    )
+}
+
+/// Rounds the values of `array` up to `decimals` decimal places.
+/// A negative `decimals` rounds up to a power of 10.
+pub fn ceil<T>(array: &PrimitiveArray<T>, decimals: i32) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Float,
+{
+    unary(
+        array,
+        |x| with_decimals(x, decimals, T::ceil),
+        array.data_type().clone(),
+    )
+}
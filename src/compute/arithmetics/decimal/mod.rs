@@ -9,6 +9,10 @@ mod div;
 pub use div::*;
 mod mul;
 pub use mul::*;
+mod rescaled;
+pub use rescaled::*;
+mod round;
+pub use round::*;
 mod sub;
 pub use sub::*;
 
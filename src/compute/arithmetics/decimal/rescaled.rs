@@ -0,0 +1,262 @@
+//! Defines arithmetic kernels for decimal [`PrimitiveArray`]s with different precisions and
+//! scales, whose result precision and scale follow the rules of SQL engines.
+//!
+//! For `lhs` of `Decimal(p1, s1)` and `rhs` of `Decimal(p2, s2)`, the result is of type:
+//!
+//! | Operation | Precision                          | Scale                 |
+//! |-----------|------------------------------------|-----------------------|
+//! | add, sub  | `max(p1 - s1, p2 - s2) + s + 1`    | `max(s1, s2)`         |
+//! | mul       | `p1 + p2 + 1`                      | `s1 + s2`             |
+//! | div       | `p1 - s1 + s2 + s`                 | `max(6, s1 + p2 + 1)` |
+//!
+//! When the precision exceeds 38, it is set to 38 and the scale is reduced so that the integral
+//! part keeps its digits, but to no less than `min(s, 6)`. Values whose scale is reduced are
+//! rounded half away from zero.
+//!
+//! Unlike the other decimal kernels, these are checked: they error instead of panicking,
+//! saturating or nulling when a value does not fit in the result precision. Values are computed
+//! at their exact scale before being rounded, so intermediate values must fit in an `i128`.
+use crate::{
+    array::PrimitiveArray,
+    compute::utils::{check_same_len, combine_validities},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::max_value;
+
+const MAX_PRECISION: usize = 38;
+const MIN_ADJUSTED_SCALE: usize = 6;
+
+fn parameters(data_type: &DataType) -> Result<(usize, usize)> {
+    if let DataType::Decimal(p, s) = data_type.to_logical_type() {
+        Ok((*p, *s))
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "Rescaled decimal arithmetics requires decimal arrays, but got {:?}",
+            data_type
+        )))
+    }
+}
+
+/// Bounds the precision to 38, reducing the scale to keep the integral digits.
+fn bounded(precision: usize, scale: usize) -> (usize, usize) {
+    if precision <= MAX_PRECISION {
+        return (precision, scale);
+    }
+    let integral = precision - scale;
+    let min_scale = scale.min(MIN_ADJUSTED_SCALE);
+    let scale = MAX_PRECISION.saturating_sub(integral).max(min_scale);
+    (MAX_PRECISION, scale)
+}
+
+fn pow10(exponent: usize) -> Result<i128> {
+    10i128
+        .checked_pow(exponent as u32)
+        .ok_or(ArrowError::Overflow)
+}
+
+/// Divides `value` by `divisor`, rounding half away from zero.
+#[inline]
+fn div_round(value: i128, divisor: i128) -> i128 {
+    let quotient = value / divisor;
+    let remainder = value % divisor;
+    if remainder.abs() >= divisor.abs() - remainder.abs() {
+        quotient + value.signum() * divisor.signum()
+    } else {
+        quotient
+    }
+}
+
+/// Applies `op` to every pair of valid values, erroring if it errors or if the result does not
+/// fit in `precision`.
+fn apply<F>(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+    (precision, scale): (usize, usize),
+    op: F,
+) -> Result<PrimitiveArray<i128>>
+where
+    F: Fn(i128, i128) -> Result<i128>,
+{
+    check_same_len(lhs, rhs)?;
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let max = max_value(precision);
+
+    let op = |(index, (l, r)): (usize, (&i128, &i128))| {
+        if validity
+            .as_ref()
+            .map(|x| !x.get_bit(index))
+            .unwrap_or(false)
+        {
+            return Ok(0);
+        }
+        let result = op(*l, *r)?;
+        if result.abs() > max {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The result of a decimal operation does not fit in precision {}",
+                precision
+            )));
+        }
+        Ok(result)
+    };
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .enumerate()
+        .map(op)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(PrimitiveArray::<i128>::new(
+        DataType::Decimal(precision, scale),
+        values.into(),
+        validity,
+    ))
+}
+
+fn add_sub(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+    sign: i128,
+) -> Result<PrimitiveArray<i128>> {
+    let (p1, s1) = parameters(lhs.data_type())?;
+    let (p2, s2) = parameters(rhs.data_type())?;
+
+    let exact_scale = s1.max(s2);
+    let (precision, scale) = bounded((p1 - s1).max(p2 - s2) + exact_scale + 1, exact_scale);
+
+    let lhs_shift = pow10(exact_scale - s1)?;
+    let rhs_shift = pow10(exact_scale - s2)?;
+    let reduction = pow10(exact_scale - scale)?;
+
+    apply(lhs, rhs, (precision, scale), |l, r| {
+        let l = l.checked_mul(lhs_shift).ok_or(ArrowError::Overflow)?;
+        let r = r.checked_mul(rhs_shift).ok_or(ArrowError::Overflow)?;
+        let result = l
+            .checked_add(r.checked_mul(sign).ok_or(ArrowError::Overflow)?)
+            .ok_or(ArrowError::Overflow)?;
+        Ok(div_round(result, reduction))
+    })
+}
+
+/// Adds two decimal arrays of any precision and scale; see the [module](self) for the type of
+/// the result.
+/// # Error
+/// Errors iff the arrays are not decimal arrays, have different lengths, or a sum does not fit
+/// in the result.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::decimal::rescaled_add;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// // 1.5 + 0.25, 99.9 + 0.01
+/// let a = PrimitiveArray::from([Some(1_5i128), Some(99_9), None]).to(DataType::Decimal(3, 1));
+/// let b = PrimitiveArray::from([Some(0_25i128), Some(0_01), Some(1)]).to(DataType::Decimal(3, 2));
+/// let result = rescaled_add(&a, &b).unwrap();
+///
+/// let expected = PrimitiveArray::from([Some(1_75i128), Some(99_91), None]).to(DataType::Decimal(5, 2));
+/// assert_eq!(result, expected);
+/// ```
+pub fn rescaled_add(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+) -> Result<PrimitiveArray<i128>> {
+    add_sub(lhs, rhs, 1)
+}
+
+/// Subtracts two decimal arrays of any precision and scale; see the [module](self) for the type
+/// of the result.
+/// # Error
+/// Errors iff the arrays are not decimal arrays, have different lengths, or a difference does
+/// not fit in the result.
+pub fn rescaled_sub(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+) -> Result<PrimitiveArray<i128>> {
+    add_sub(lhs, rhs, -1)
+}
+
+/// Multiplies two decimal arrays of any precision and scale; see the [module](self) for the type
+/// of the result.
+/// # Error
+/// Errors iff the arrays are not decimal arrays, have different lengths, or a product does not
+/// fit in the result.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::decimal::rescaled_mul;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// // 1.5 * 0.25
+/// let a = PrimitiveArray::from([Some(1_5i128)]).to(DataType::Decimal(3, 1));
+/// let b = PrimitiveArray::from([Some(0_25i128)]).to(DataType::Decimal(3, 2));
+/// let result = rescaled_mul(&a, &b).unwrap();
+///
+/// let expected = PrimitiveArray::from([Some(0_375i128)]).to(DataType::Decimal(7, 3));
+/// assert_eq!(result, expected);
+/// ```
+pub fn rescaled_mul(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+) -> Result<PrimitiveArray<i128>> {
+    let (p1, s1) = parameters(lhs.data_type())?;
+    let (p2, s2) = parameters(rhs.data_type())?;
+
+    let exact_scale = s1 + s2;
+    let (precision, scale) = bounded(p1 + p2 + 1, exact_scale);
+    let reduction = pow10(exact_scale - scale)?;
+
+    apply(lhs, rhs, (precision, scale), |l, r| {
+        let result = l.checked_mul(r).ok_or(ArrowError::Overflow)?;
+        Ok(div_round(result, reduction))
+    })
+}
+
+/// Divides two decimal arrays of any precision and scale; see the [module](self) for the type of
+/// the result.
+/// # Error
+/// Errors iff the arrays are not decimal arrays, have different lengths, a valid divisor is zero,
+/// or a quotient does not fit in the result.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::decimal::rescaled_div;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// // 1.0 / 3
+/// let a = PrimitiveArray::from([Some(1_0i128)]).to(DataType::Decimal(2, 1));
+/// let b = PrimitiveArray::from([Some(3i128)]).to(DataType::Decimal(1, 0));
+/// let result = rescaled_div(&a, &b).unwrap();
+///
+/// let expected = PrimitiveArray::from([Some(0_333333i128)]).to(DataType::Decimal(7, 6));
+/// assert_eq!(result, expected);
+/// ```
+pub fn rescaled_div(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+) -> Result<PrimitiveArray<i128>> {
+    let (p1, s1) = parameters(lhs.data_type())?;
+    let (p2, s2) = parameters(rhs.data_type())?;
+
+    let exact_scale = MIN_ADJUSTED_SCALE.max(s1 + p2 + 1);
+    let (precision, scale) = bounded(p1 - s1 + s2 + exact_scale, exact_scale);
+    // `l / r` has scale `s1 - s2`, so either `l` is shifted up or `r` is shifted up
+    let (lhs_shift, rhs_shift) = if scale + s2 >= s1 {
+        (pow10(scale + s2 - s1)?, 1)
+    } else {
+        (1, pow10(s1 - s2 - scale)?)
+    };
+
+    apply(lhs, rhs, (precision, scale), |l, r| {
+        if r == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "Decimal division by zero".to_string(),
+            ));
+        }
+        let l = l.checked_mul(lhs_shift).ok_or(ArrowError::Overflow)?;
+        let r = r.checked_mul(rhs_shift).ok_or(ArrowError::Overflow)?;
+        Ok(div_round(l, r))
+    })
+}
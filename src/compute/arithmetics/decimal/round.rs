@@ -0,0 +1,137 @@
+//! Defines the rounding kernels for [`PrimitiveArray`] representing decimals.
+use crate::{
+    array::PrimitiveArray,
+    compute::{arithmetics::RoundMode, arity::unary_checked},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::max_value;
+
+/// How the quotient of a division by a power of 10 is adjusted given its remainder
+#[derive(Clone, Copy)]
+enum Rounding {
+    Mode(RoundMode),
+    Floor,
+    Ceil,
+}
+
+impl Rounding {
+    /// Returns `value / divisor` rounded.
+    #[inline]
+    fn divide(self, value: i128, divisor: i128) -> i128 {
+        let quotient = value / divisor;
+        let remainder = value % divisor;
+        // the distance to the next multiple of `divisor`, to compare against the remainder
+        // without computing `2 * remainder` (which can overflow)
+        let distance = divisor - remainder.abs();
+        let away_from_zero = match self {
+            Rounding::Floor => remainder < 0,
+            Rounding::Ceil => remainder > 0,
+            Rounding::Mode(RoundMode::HalfUp) => remainder.abs() >= distance,
+            Rounding::Mode(RoundMode::HalfEven) => {
+                remainder.abs() > distance || (remainder.abs() == distance && quotient % 2 != 0)
+            }
+        };
+        if away_from_zero {
+            quotient + value.signum()
+        } else {
+            quotient
+        }
+    }
+}
+
+fn rescale(
+    array: &PrimitiveArray<i128>,
+    decimals: i32,
+    rounding: Rounding,
+) -> Result<PrimitiveArray<i128>> {
+    let (precision, scale) = if let DataType::Decimal(p, s) = array.data_type().to_logical_type() {
+        (*p as i32, *s as i32)
+    } else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Rounding of decimals requires a decimal array, but it is of type {:?}",
+            array.data_type()
+        )));
+    };
+    if decimals >= scale {
+        return Ok(array.clone());
+    }
+
+    // number of digits that are rounded off
+    let digits = scale - decimals;
+    if digits > 38 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Decimals can be rounded by at most 38 digits, but {} were requested",
+            digits
+        )));
+    }
+    let divisor = 10i128.pow(digits as u32);
+
+    let new_scale = decimals.max(0);
+    // the rounding may carry over to a new digit
+    let new_precision = (precision - scale + new_scale + 1).min(38);
+    let multiplier = 10i128.pow((new_scale - decimals) as u32);
+    let max = max_value(new_precision as usize);
+
+    let op = move |value: i128| {
+        rounding
+            .divide(value, divisor)
+            .checked_mul(multiplier)
+            .filter(|value| value.abs() <= max)
+    };
+    Ok(unary_checked(
+        array,
+        op,
+        DataType::Decimal(new_precision as usize, new_scale as usize),
+    ))
+}
+
+/// Rounds the values of a decimal array to `decimals` decimal places, rounding half-way values
+/// according to `mode`.
+///
+/// The result is rescaled to the rounded number of decimal places: an array of
+/// `Decimal(p, s)` with `decimals < s` results in an array of
+/// `Decimal(p - s + max(decimals, 0) + 1, max(decimals, 0))`, where the extra digit of precision
+/// holds a carry (e.g. `9.99` rounded to `10.0`). The precision is capped at 38, and values that
+/// do not fit in it are null. The array is returned as is when `decimals >= s`.
+/// A negative `decimals` rounds to a power of 10, e.g. `-2` rounds to the nearest hundred.
+/// # Error
+/// Errors iff the array is not a decimal array or more than 38 digits are rounded off.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::arithmetics::{decimal::round, RoundMode};
+/// use arrow2::datatypes::DataType;
+///
+/// // 1.25, 9.99, -0.35
+/// let array = PrimitiveArray::from([Some(125i128), Some(999), None, Some(-35)]).to(DataType::Decimal(3, 2));
+///
+/// let result = round(&array, 1, RoundMode::HalfEven).unwrap();
+/// // 1.2, 10.0, -0.4
+/// let expected = PrimitiveArray::from([Some(12i128), Some(100), None, Some(-4)]).to(DataType::Decimal(3, 1));
+/// assert_eq!(result, expected);
+/// ```
+pub fn round(
+    array: &PrimitiveArray<i128>,
+    decimals: i32,
+    mode: RoundMode,
+) -> Result<PrimitiveArray<i128>> {
+    rescale(array, decimals, Rounding::Mode(mode))
+}
+
+/// Rounds the values of a decimal array down to `decimals` decimal places.
+/// The result is rescaled like in [`round`].
+/// # Error
+/// Errors iff the array is not a decimal array or more than 38 digits are rounded off.
+pub fn floor(array: &PrimitiveArray<i128>, decimals: i32) -> Result<PrimitiveArray<i128>> {
+    rescale(array, decimals, Rounding::Floor)
+}
+
+/// Rounds the values of a decimal array up to `decimals` decimal places.
+/// The result is rescaled like in [`round`].
+/// # Error
+/// Errors iff the array is not a decimal array or more than 38 digits are rounded off.
+pub fn ceil(array: &PrimitiveArray<i128>, decimals: i32) -> Result<PrimitiveArray<i128>> {
+    rescale(array, decimals, Rounding::Ceil)
+}
@@ -0,0 +1,117 @@
+//! Defines the addition arithmetic kernels for [`PrimitiveArray`] representing 256-bit decimals.
+use crate::{
+    array::PrimitiveArray,
+    compute::{
+        arithmetics::{ArrayAdd, ArrayCheckedAdd, ArraySaturatingAdd},
+        arity::{binary, binary_checked},
+    },
+    types::i256,
+};
+
+use super::{exceeds, get_parameters, max_value, saturate};
+
+/// Adds two 256-bit decimal [`PrimitiveArray`]s with the same precision and scale.
+/// # Panic
+/// This function panics iff the precisions or scales are different, or the added numbers result
+/// in a number larger than the possible number for the precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::add;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from(1)), None]).to(DataType::Decimal256(50, 2));
+/// let b = PrimitiveArray::from([Some(i256::from(2)), Some(i256::from(2))]).to(DataType::Decimal256(50, 2));
+///
+/// let result = add(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from(3)), None]).to(DataType::Decimal256(50, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn add(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let res = a.checked_add(b).filter(|res| !exceeds(*res, max));
+        res.unwrap_or_else(|| panic!("Overflow in addition presented for precision {}", precision))
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Saturated addition of two 256-bit decimal [`PrimitiveArray`]s with the same precision and
+/// scale. Sums larger than the possible number for the precision are the maximum number for
+/// the precision (or its negative).
+/// # Panic
+/// This function panics iff the precisions or scales are different.
+pub fn saturating_add(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        // precisions are at most 76 digits, so the sum of two valid values never overflows
+        let res = a.saturating_add(b);
+        if exceeds(res, max) {
+            saturate(res, max)
+        } else {
+            res
+        }
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked addition of two 256-bit decimal [`PrimitiveArray`]s with the same precision and
+/// scale. Sums larger than the possible number for the precision are null.
+/// # Panic
+/// This function panics iff the precisions or scales are different.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::checked_add;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from(990)), Some(i256::from(1))]).to(DataType::Decimal256(3, 0));
+/// let b = PrimitiveArray::from([Some(i256::from(10)), Some(i256::from(2))]).to(DataType::Decimal256(3, 0));
+///
+/// let result = checked_add(&a, &b);
+/// let expected = PrimitiveArray::from([None, Some(i256::from(3))]).to(DataType::Decimal256(3, 0));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn checked_add(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| a.checked_add(b).filter(|res| !exceeds(*res, max));
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+// Implementation of ArrayAdd trait for PrimitiveArrays
+impl ArrayAdd<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn add(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        add(self, rhs)
+    }
+}
+
+// Implementation of ArrayCheckedAdd trait for PrimitiveArrays
+impl ArrayCheckedAdd<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_add(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_add(self, rhs)
+    }
+}
+
+// Implementation of ArraySaturatingAdd trait for PrimitiveArrays
+impl ArraySaturatingAdd<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn saturating_add(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        saturating_add(self, rhs)
+    }
+}
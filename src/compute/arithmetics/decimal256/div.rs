@@ -0,0 +1,144 @@
+//! Defines the division arithmetic kernels for [`PrimitiveArray`] representing 256-bit decimals.
+use crate::{
+    array::PrimitiveArray,
+    compute::{
+        arithmetics::{ArrayCheckedDiv, ArrayDiv},
+        arity::{binary, binary_checked, unary},
+    },
+    scalar::{PrimitiveScalar, Scalar},
+    types::i256,
+};
+
+use super::{exceeds, get_parameters, max_value, pow10, saturate};
+
+/// Divides two 256-bit decimal [`PrimitiveArray`]s with the same precision and scale. The
+/// quotient is truncated to the scale.
+/// # Panic
+/// This function panics iff the precisions or scales are different, a divisor is zero, or the
+/// divided numbers result in a number larger than the possible number for the precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::div;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// // 1.00 / 3.00
+/// let a = PrimitiveArray::from([Some(i256::from(100)), None]).to(DataType::Decimal256(50, 2));
+/// let b = PrimitiveArray::from([Some(i256::from(300)), Some(i256::from(1))]).to(DataType::Decimal256(50, 2));
+///
+/// let result = div(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from(33)), None]).to(DataType::Decimal256(50, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn div(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = pow10(scale).unwrap();
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        assert!(b != i256::ZERO, "Found division by zero");
+        // The dividend is scaled up so that the quotient keeps the scale.
+        let res = a
+            .checked_mul(scale)
+            .map(|numeral| numeral / b)
+            .filter(|res| !exceeds(*res, max));
+        res.unwrap_or_else(|| panic!("Overflow in division presented for precision {}", precision))
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Divides a 256-bit decimal [`PrimitiveArray`] by a [`PrimitiveScalar`] with the same
+/// precision and scale. The quotient is truncated to the scale.
+/// # Panic
+/// This function panics iff the precisions or scales are different, the divisor is zero, or the
+/// divided numbers result in a number larger than the possible number for the precision.
+pub fn div_scalar(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveScalar<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let rhs = if let Some(rhs) = rhs.value() {
+        rhs
+    } else {
+        return PrimitiveArray::<i256>::new_null(lhs.data_type().clone(), lhs.len());
+    };
+    assert!(rhs != i256::ZERO, "Found division by zero");
+
+    let scale = pow10(scale).unwrap();
+    let max = max_value(precision);
+
+    let op = move |a: i256| {
+        let res = a
+            .checked_mul(scale)
+            .map(|numeral| numeral / rhs)
+            .filter(|res| !exceeds(*res, max));
+        res.unwrap_or_else(|| panic!("Overflow in division presented for precision {}", precision))
+    };
+
+    unary(lhs, op, lhs.data_type().clone())
+}
+
+/// Saturated division of two 256-bit decimal [`PrimitiveArray`]s with the same precision and
+/// scale. Quotients larger than the possible number for the precision are the maximum number
+/// for the precision (or its negative), and quotients by zero are zero.
+/// # Panic
+/// This function panics iff the precisions or scales are different.
+pub fn saturating_div(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = pow10(scale).unwrap();
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        if b == i256::ZERO {
+            return i256::ZERO;
+        }
+        match a.checked_mul(scale).map(|numeral| numeral / b) {
+            Some(res) if !exceeds(res, max) => res,
+            // an overflowing dividend saturates with the sign of the quotient
+            _ => saturate(a.signum() * b.signum(), max),
+        }
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked division of two 256-bit decimal [`PrimitiveArray`]s with the same precision and
+/// scale. Quotients by zero and quotients larger than the possible number for the precision
+/// are null.
+/// # Panic
+/// This function panics iff the precisions or scales are different.
+pub fn checked_div(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = pow10(scale).unwrap();
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        a.checked_mul(scale)
+            .and_then(|numeral| numeral.checked_div(b))
+            .filter(|res| !exceeds(*res, max))
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+// Implementation of ArrayDiv trait for PrimitiveArrays
+impl ArrayDiv<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn div(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        div(self, rhs)
+    }
+}
+
+// Implementation of ArrayCheckedDiv trait for PrimitiveArrays
+impl ArrayCheckedDiv<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_div(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_div(self, rhs)
+    }
+}
@@ -0,0 +1,78 @@
+//! Defines the arithmetic kernels for [`Decimal256`](crate::datatypes::DataType::Decimal256)
+//! `PrimitiveArrays`, which hold the same operations as the kernels of
+//! [`decimal`](super::decimal) for decimals of up to 76 digits.
+
+mod add;
+pub use add::*;
+mod div;
+pub use div::*;
+mod mul;
+pub use mul::*;
+mod rescaled;
+pub use rescaled::*;
+mod sub;
+pub use sub::*;
+
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::i256;
+
+/// The maximum precision of a [`DataType::Decimal256`]
+pub const MAX_PRECISION: usize = 76;
+
+/// `10^exponent`, or `None` if it does not fit in a [`i256`]
+#[inline]
+fn pow10(exponent: usize) -> Option<i256> {
+    i256::from_i128(10).checked_pow(exponent as u32)
+}
+
+/// Maximum value that can exist with a selected precision
+#[inline]
+fn max_value(precision: usize) -> i256 {
+    pow10(precision.min(MAX_PRECISION)).unwrap() - i256::ONE
+}
+
+/// Whether the absolute value of `value` is larger than `max`
+#[inline]
+fn exceeds(value: i256, max: i256) -> bool {
+    value > max || value < -max
+}
+
+/// `max` with the sign of `value`
+#[inline]
+fn saturate(value: i256, max: i256) -> i256 {
+    if value.is_negative() {
+        -max
+    } else {
+        max
+    }
+}
+
+fn get_parameters(lhs: &DataType, rhs: &DataType) -> Result<(usize, usize)> {
+    if let (DataType::Decimal256(lhs_p, lhs_s), DataType::Decimal256(rhs_p, rhs_s)) =
+        (lhs.to_logical_type(), rhs.to_logical_type())
+    {
+        if lhs_p == rhs_p && lhs_s == rhs_s {
+            Ok((*lhs_p, *lhs_s))
+        } else {
+            Err(ArrowError::InvalidArgumentError(
+                "Arrays must have the same precision and scale".to_string(),
+            ))
+        }
+    } else {
+        Err(ArrowError::InvalidArgumentError(
+            "Arrays must be of type Decimal256".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_value() {
+        assert_eq!(i256::from_i128(999), max_value(3));
+        assert_eq!("9".repeat(76), max_value(76).to_string());
+    }
+}
@@ -0,0 +1,155 @@
+//! Defines the multiplication arithmetic kernels for [`PrimitiveArray`] representing 256-bit
+//! decimals.
+use crate::{
+    array::PrimitiveArray,
+    compute::{
+        arithmetics::{ArrayCheckedMul, ArrayMul, ArraySaturatingMul},
+        arity::{binary, binary_checked, unary},
+    },
+    scalar::{PrimitiveScalar, Scalar},
+    types::i256,
+};
+
+use super::{exceeds, get_parameters, max_value, pow10, saturate};
+
+/// Multiplies two 256-bit decimal [`PrimitiveArray`]s with the same precision and scale. The
+/// product is truncated to the scale.
+/// # Panic
+/// This function panics iff the precisions or scales are different, or the multiplied numbers
+/// result in a number larger than the possible number for the precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::mul;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// // 1.50 * 2.25
+/// let a = PrimitiveArray::from([Some(i256::from(150)), None]).to(DataType::Decimal256(50, 2));
+/// let b = PrimitiveArray::from([Some(i256::from(225)), Some(i256::from(1))]).to(DataType::Decimal256(50, 2));
+///
+/// let result = mul(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from(337)), None]).to(DataType::Decimal256(50, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn mul(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = pow10(scale).unwrap();
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        // The multiplication is done using the numbers without scale, whose product has twice
+        // the scale and thus is divided by 10^scale.
+        let res = a
+            .checked_mul(b)
+            .map(|res| res / scale)
+            .filter(|res| !exceeds(*res, max));
+        res.unwrap_or_else(|| {
+            panic!(
+                "Overflow in multiplication presented for precision {}",
+                precision
+            )
+        })
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Multiplies a 256-bit decimal [`PrimitiveArray`] by a [`PrimitiveScalar`] with the same
+/// precision and scale. The product is truncated to the scale.
+/// # Panic
+/// This function panics iff the precisions or scales are different, or the multiplied numbers
+/// result in a number larger than the possible number for the precision.
+pub fn mul_scalar(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveScalar<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let rhs = if let Some(rhs) = rhs.value() {
+        rhs
+    } else {
+        return PrimitiveArray::<i256>::new_null(lhs.data_type().clone(), lhs.len());
+    };
+
+    let scale = pow10(scale).unwrap();
+    let max = max_value(precision);
+
+    let op = move |a: i256| {
+        let res = a
+            .checked_mul(rhs)
+            .map(|res| res / scale)
+            .filter(|res| !exceeds(*res, max));
+        res.unwrap_or_else(|| {
+            panic!(
+                "Overflow in multiplication presented for precision {}",
+                precision
+            )
+        })
+    };
+
+    unary(lhs, op, lhs.data_type().clone())
+}
+
+/// Saturated multiplication of two 256-bit decimal [`PrimitiveArray`]s with the same precision
+/// and scale. Products larger than the possible number for the precision are the maximum number
+/// for the precision (or its negative).
+/// # Panic
+/// This function panics iff the precisions or scales are different.
+pub fn saturating_mul(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = pow10(scale).unwrap();
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| match a.checked_mul(b).map(|res| res / scale) {
+        Some(res) if !exceeds(res, max) => res,
+        Some(res) => saturate(res, max),
+        None => saturate(a.signum() * b.signum(), max),
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked multiplication of two 256-bit decimal [`PrimitiveArray`]s with the same precision
+/// and scale. Products larger than the possible number for the precision are null.
+/// # Panic
+/// This function panics iff the precisions or scales are different.
+pub fn checked_mul(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = pow10(scale).unwrap();
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        a.checked_mul(b)
+            .map(|res| res / scale)
+            .filter(|res| !exceeds(*res, max))
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+// Implementation of ArrayMul trait for PrimitiveArrays
+impl ArrayMul<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn mul(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        mul(self, rhs)
+    }
+}
+
+// Implementation of ArrayCheckedMul trait for PrimitiveArrays
+impl ArrayCheckedMul<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_mul(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_mul(self, rhs)
+    }
+}
+
+// Implementation of ArraySaturatingMul trait for PrimitiveArrays
+impl ArraySaturatingMul<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn saturating_mul(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        saturating_mul(self, rhs)
+    }
+}
@@ -0,0 +1,225 @@
+//! Defines arithmetic kernels for 256-bit decimal [`PrimitiveArray`]s with different precisions
+//! and scales, whose result precision and scale follow the same rules as the
+//! [rescaled kernels](crate::compute::arithmetics::decimal::rescaled_add) of
+//! [`Decimal`](crate::datatypes::DataType::Decimal), with a maximum precision of 76 instead of 38.
+use crate::{
+    array::PrimitiveArray,
+    compute::utils::{check_same_len, combine_validities},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::i256,
+};
+
+use super::{exceeds, max_value, MAX_PRECISION};
+
+const MIN_ADJUSTED_SCALE: usize = 6;
+
+fn parameters(data_type: &DataType) -> Result<(usize, usize)> {
+    if let DataType::Decimal256(p, s) = data_type.to_logical_type() {
+        Ok((*p, *s))
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "Rescaled decimal arithmetics requires Decimal256 arrays, but got {:?}",
+            data_type
+        )))
+    }
+}
+
+/// Bounds the precision to 76, reducing the scale to keep the integral digits.
+fn bounded(precision: usize, scale: usize) -> (usize, usize) {
+    if precision <= MAX_PRECISION {
+        return (precision, scale);
+    }
+    let integral = precision - scale;
+    let min_scale = scale.min(MIN_ADJUSTED_SCALE);
+    let scale = MAX_PRECISION.saturating_sub(integral).max(min_scale);
+    (MAX_PRECISION, scale)
+}
+
+fn pow10(exponent: usize) -> Result<i256> {
+    super::pow10(exponent).ok_or(ArrowError::Overflow)
+}
+
+/// Divides `value` by `divisor`, rounding half away from zero.
+#[inline]
+fn div_round(value: i256, divisor: i256) -> Result<i256> {
+    let quotient = value.checked_div(divisor).ok_or(ArrowError::Overflow)?;
+    let remainder = value.checked_rem(divisor).ok_or(ArrowError::Overflow)?;
+    let remainder = remainder.checked_abs().ok_or(ArrowError::Overflow)?;
+    let divisor_abs = divisor.checked_abs().ok_or(ArrowError::Overflow)?;
+    if remainder >= divisor_abs - remainder {
+        quotient
+            .checked_add(value.signum() * divisor.signum())
+            .ok_or(ArrowError::Overflow)
+    } else {
+        Ok(quotient)
+    }
+}
+
+/// Applies `op` to every pair of valid values, erroring if it errors or if the result does not
+/// fit in `precision`.
+fn apply<F>(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+    (precision, scale): (usize, usize),
+    op: F,
+) -> Result<PrimitiveArray<i256>>
+where
+    F: Fn(i256, i256) -> Result<i256>,
+{
+    check_same_len(lhs, rhs)?;
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let max = max_value(precision);
+
+    let op = |(index, (l, r)): (usize, (&i256, &i256))| {
+        if validity
+            .as_ref()
+            .map(|x| !x.get_bit(index))
+            .unwrap_or(false)
+        {
+            return Ok(i256::ZERO);
+        }
+        let result = op(*l, *r)?;
+        if exceeds(result, max) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The result of a decimal operation does not fit in precision {}",
+                precision
+            )));
+        }
+        Ok(result)
+    };
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .enumerate()
+        .map(op)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(PrimitiveArray::<i256>::new(
+        DataType::Decimal256(precision, scale),
+        values.into(),
+        validity,
+    ))
+}
+
+fn add_sub(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+    subtract: bool,
+) -> Result<PrimitiveArray<i256>> {
+    let (p1, s1) = parameters(lhs.data_type())?;
+    let (p2, s2) = parameters(rhs.data_type())?;
+
+    let exact_scale = s1.max(s2);
+    let (precision, scale) = bounded((p1 - s1).max(p2 - s2) + exact_scale + 1, exact_scale);
+
+    let lhs_shift = pow10(exact_scale - s1)?;
+    let rhs_shift = pow10(exact_scale - s2)?;
+    let reduction = pow10(exact_scale - scale)?;
+
+    apply(lhs, rhs, (precision, scale), |l, r| {
+        let l = l.checked_mul(lhs_shift).ok_or(ArrowError::Overflow)?;
+        let r = r.checked_mul(rhs_shift).ok_or(ArrowError::Overflow)?;
+        let result = if subtract {
+            l.checked_sub(r)
+        } else {
+            l.checked_add(r)
+        };
+        div_round(result.ok_or(ArrowError::Overflow)?, reduction)
+    })
+}
+
+/// Adds two 256-bit decimal arrays of any precision and scale; see the [module](self) for the
+/// type of the result.
+/// # Error
+/// Errors iff the arrays are not 256-bit decimal arrays, have different lengths, or a sum does
+/// not fit in the result.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::rescaled_add;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// // 1.5 + 0.25
+/// let a = PrimitiveArray::from([Some(i256::from(1_5)), None]).to(DataType::Decimal256(40, 1));
+/// let b = PrimitiveArray::from([Some(i256::from(0_25)), Some(i256::from(1))]).to(DataType::Decimal256(3, 2));
+/// let result = rescaled_add(&a, &b).unwrap();
+///
+/// let expected = PrimitiveArray::from([Some(i256::from(1_75)), None]).to(DataType::Decimal256(42, 2));
+/// assert_eq!(result, expected);
+/// ```
+pub fn rescaled_add(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    add_sub(lhs, rhs, false)
+}
+
+/// Subtracts two 256-bit decimal arrays of any precision and scale; see the [module](self) for
+/// the type of the result.
+/// # Error
+/// Errors iff the arrays are not 256-bit decimal arrays, have different lengths, or a
+/// difference does not fit in the result.
+pub fn rescaled_sub(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    add_sub(lhs, rhs, true)
+}
+
+/// Multiplies two 256-bit decimal arrays of any precision and scale; see the [module](self) for
+/// the type of the result.
+/// # Error
+/// Errors iff the arrays are not 256-bit decimal arrays, have different lengths, or a product
+/// does not fit in the result.
+pub fn rescaled_mul(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    let (p1, s1) = parameters(lhs.data_type())?;
+    let (p2, s2) = parameters(rhs.data_type())?;
+
+    let exact_scale = s1 + s2;
+    let (precision, scale) = bounded(p1 + p2 + 1, exact_scale);
+    let reduction = pow10(exact_scale - scale)?;
+
+    apply(lhs, rhs, (precision, scale), |l, r| {
+        let result = l.checked_mul(r).ok_or(ArrowError::Overflow)?;
+        div_round(result, reduction)
+    })
+}
+
+/// Divides two 256-bit decimal arrays of any precision and scale; see the [module](self) for the
+/// type of the result.
+/// # Error
+/// Errors iff the arrays are not 256-bit decimal arrays, have different lengths, a valid divisor
+/// is zero, or a quotient does not fit in the result.
+pub fn rescaled_div(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    let (p1, s1) = parameters(lhs.data_type())?;
+    let (p2, s2) = parameters(rhs.data_type())?;
+
+    let exact_scale = MIN_ADJUSTED_SCALE.max(s1 + p2 + 1);
+    let (precision, scale) = bounded(p1 - s1 + s2 + exact_scale, exact_scale);
+    // `l / r` has scale `s1 - s2`, so either `l` is shifted up or `r` is shifted up
+    let (lhs_shift, rhs_shift) = if scale + s2 >= s1 {
+        (pow10(scale + s2 - s1)?, i256::ONE)
+    } else {
+        (i256::ONE, pow10(s1 - s2 - scale)?)
+    };
+
+    apply(lhs, rhs, (precision, scale), |l, r| {
+        if r == i256::ZERO {
+            return Err(ArrowError::InvalidArgumentError(
+                "Decimal division by zero".to_string(),
+            ));
+        }
+        let l = l.checked_mul(lhs_shift).ok_or(ArrowError::Overflow)?;
+        let r = r.checked_mul(rhs_shift).ok_or(ArrowError::Overflow)?;
+        div_round(l, r)
+    })
+}
@@ -0,0 +1,122 @@
+//! Defines the subtraction arithmetic kernels for [`PrimitiveArray`] representing 256-bit decimals.
+use crate::{
+    array::PrimitiveArray,
+    compute::{
+        arithmetics::{ArrayCheckedSub, ArraySaturatingSub, ArraySub},
+        arity::{binary, binary_checked},
+    },
+    types::i256,
+};
+
+use super::{exceeds, get_parameters, max_value, saturate};
+
+/// Subtracts two 256-bit decimal [`PrimitiveArray`]s with the same precision and scale.
+/// # Panic
+/// This function panics iff the precisions or scales are different, or the subtracted numbers result
+/// in a number larger than the possible number for the precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::sub;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from(1)), None]).to(DataType::Decimal256(50, 2));
+/// let b = PrimitiveArray::from([Some(i256::from(2)), Some(i256::from(2))]).to(DataType::Decimal256(50, 2));
+///
+/// let result = sub(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from(-1)), None]).to(DataType::Decimal256(50, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn sub(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let res = a.checked_sub(b).filter(|res| !exceeds(*res, max));
+        res.unwrap_or_else(|| {
+            panic!(
+                "Overflow in subtraction presented for precision {}",
+                precision
+            )
+        })
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Saturated subtraction of two 256-bit decimal [`PrimitiveArray`]s with the same precision and
+/// scale. Differences larger than the possible number for the precision are the maximum number for
+/// the precision (or its negative).
+/// # Panic
+/// This function panics iff the precisions or scales are different.
+pub fn saturating_sub(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        // precisions are at most 76 digits, so the difference of two valid values never overflows
+        let res = a.saturating_sub(b);
+        if exceeds(res, max) {
+            saturate(res, max)
+        } else {
+            res
+        }
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked subtraction of two 256-bit decimal [`PrimitiveArray`]s with the same precision and
+/// scale. Differences larger than the possible number for the precision are null.
+/// # Panic
+/// This function panics iff the precisions or scales are different.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::checked_sub;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from(-990)), Some(i256::from(1))]).to(DataType::Decimal256(3, 0));
+/// let b = PrimitiveArray::from([Some(i256::from(10)), Some(i256::from(2))]).to(DataType::Decimal256(3, 0));
+///
+/// let result = checked_sub(&a, &b);
+/// let expected = PrimitiveArray::from([None, Some(i256::from(-1))]).to(DataType::Decimal256(3, 0));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn checked_sub(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| a.checked_sub(b).filter(|res| !exceeds(*res, max));
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+// Implementation of ArraySub trait for PrimitiveArrays
+impl ArraySub<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn sub(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        sub(self, rhs)
+    }
+}
+
+// Implementation of ArrayCheckedSub trait for PrimitiveArrays
+impl ArrayCheckedSub<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_sub(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_sub(self, rhs)
+    }
+}
+
+// Implementation of ArraySaturatingSub trait for PrimitiveArrays
+impl ArraySaturatingSub<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn saturating_sub(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        saturating_sub(self, rhs)
+    }
+}
@@ -11,18 +11,89 @@
 //! * overflowing: returns an extra [`Bitmap`] denoting whether the operation overflowed.
 //! * adaptive: for [`Decimal`](crate::datatypes::DataType::Decimal) only,
 //!   adjusts the precision and scale to make the resulting value fit.
+//!
+//! The kernels of [`Decimal`](crate::datatypes::DataType::Decimal) and
+//! [`Decimal256`](crate::datatypes::DataType::Decimal256) arrays are in [`decimal`] and
+//! [`decimal256`] respectively.
+//!
+//! The dynamically typed kernels, such as [`add`] and [`add_scalar`], also accept
+//! [`DictionaryArray`]s: an operation with a [`Scalar`] is applied once to the dictionary's values
+//! and reuses its keys, while an operation between two arrays unpacks the dictionaries first.
 #[forbid(unsafe_code)]
 pub mod basic;
 pub mod decimal;
+pub mod decimal256;
 pub mod time;
 
 use crate::{
     array::{Array, DictionaryArray, PrimitiveArray},
     bitmap::Bitmap,
-    datatypes::{DataType, IntervalUnit, TimeUnit},
+    compute::take::take,
+    datatypes::{DataType, IntervalUnit, PhysicalType, TimeUnit},
+    error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
+    types::f16,
 };
 
+/// Returns the values of a dictionary array, with its keys applied.
+fn unpack_dictionary(array: &dyn Array) -> Box<dyn Array> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Dictionary(key) => match_integer_type!(key, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            take(array.values().as_ref(), array.keys()).unwrap()
+        }),
+        _ => array.to_boxed(),
+    }
+}
+
+fn is_dictionary(array: &dyn Array) -> bool {
+    matches!(
+        array.data_type().to_physical_type(),
+        PhysicalType::Dictionary(_)
+    )
+}
+
+/// Applies `op` to `lhs` and `rhs` after unpacking the ones that are dictionary arrays, or
+/// returns `None` if none of them is.
+fn unpack_dictionaries(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    op: fn(&dyn Array, &dyn Array) -> Box<dyn Array>,
+) -> Option<Box<dyn Array>> {
+    if !is_dictionary(lhs) && !is_dictionary(rhs) {
+        return None;
+    }
+    Some(op(
+        unpack_dictionary(lhs).as_ref(),
+        unpack_dictionary(rhs).as_ref(),
+    ))
+}
+
+/// Applies `op` to the values of `lhs` with `rhs`, reusing its keys, or returns `None` if `lhs`
+/// is not a dictionary array.
+fn dictionary_scalar(
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+    op: fn(&dyn Array, &dyn Scalar) -> Box<dyn Array>,
+) -> Option<Box<dyn Array>> {
+    match lhs.data_type().to_physical_type() {
+        PhysicalType::Dictionary(key) => Some(match_integer_type!(key, |$T| {
+            let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let values = op(lhs.values().as_ref(), rhs).into();
+            Box::new(DictionaryArray::<$T>::from_data(lhs.keys().clone(), values)) as Box<dyn Array>
+        })),
+        _ => None,
+    }
+}
+
+/// Returns the data type of the values of a dictionary
+fn values_type(data_type: &DataType) -> &DataType {
+    match data_type.to_logical_type() {
+        DataType::Dictionary(_, values, _) => values.as_ref(),
+        _ => data_type,
+    }
+}
+
 // Macro to evaluate match branch in arithmetic function.
 macro_rules! primitive {
     ($lhs:expr, $rhs:expr, $op:tt, $type:ty) => {{
@@ -37,7 +108,7 @@ macro_rules! primitive {
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith {
-    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )? $(, intervals = $op_intervals:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
@@ -52,6 +123,7 @@ macro_rules! arith {
             (UInt16, UInt16) => primitive!(lhs, rhs, $op, u16),
             (UInt32, UInt32) => primitive!(lhs, rhs, $op, u32),
             (UInt64, UInt64) => primitive!(lhs, rhs, $op, u64),
+            (Float16, Float16) => primitive!(lhs, rhs, $op, f16),
             (Float32, Float32) => primitive!(lhs, rhs, $op, f32),
             (Float64, Float64) => primitive!(lhs, rhs, $op, f64),
             $ (
@@ -60,6 +132,11 @@ macro_rules! arith {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 Box::new(decimal::$op_decimal(lhs, rhs)) as Box<dyn Array>
             }
+            (Decimal256(_, _), Decimal256(_, _)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(decimal256::$op_decimal(lhs, rhs)) as Box<dyn Array>
+            }
             )?
             $ (
             (Time32(TimeUnit::Second), Duration(_))
@@ -92,6 +169,13 @@ macro_rules! arith {
                 time::$op_timestamp(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
             )?
+            $ (
+            (Interval(IntervalUnit::MonthDayNano), Interval(IntervalUnit::MonthDayNano)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(time::$op_intervals(lhs, rhs)) as Box<dyn Array>
+            }
+            )?
             _ => todo!(
                 "Addition of {:?} with {:?} is not supported",
                 lhs.data_type(),
@@ -113,24 +197,20 @@ macro_rules! primitive_scalar {
             .downcast_ref::<PrimitiveScalar<$type>>()
             .unwrap();
 
-        let rhs = if let Some(rhs) = rhs.value() {
-            rhs
-        } else {
-            return Box::new(PrimitiveArray::<$type>::new_null(
+        match rhs.value() {
+            Some(rhs) => Box::new(basic::$op::<$type>(lhs, &rhs)) as Box<dyn Array>,
+            None => Box::new(PrimitiveArray::<$type>::new_null(
                 lhs.data_type().clone(),
                 lhs.len(),
-            )) as Box<dyn Array>;
-        };
-
-        let result = basic::$op::<$type>(lhs, &rhs);
-        Box::new(result) as Box<dyn Array>
+            )) as Box<dyn Array>,
+        }
     }};
 }
 
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith_scalar {
-    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )? $(, intervals = $op_intervals:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
@@ -145,6 +225,7 @@ macro_rules! arith_scalar {
             (UInt16, UInt16) => primitive_scalar!(lhs, rhs, $op, u16),
             (UInt32, UInt32) => primitive_scalar!(lhs, rhs, $op, u32),
             (UInt64, UInt64) => primitive_scalar!(lhs, rhs, $op, u64),
+            (Float16, Float16) => primitive_scalar!(lhs, rhs, $op, f16),
             (Float32, Float32) => primitive_scalar!(lhs, rhs, $op, f32),
             (Float64, Float64) => primitive_scalar!(lhs, rhs, $op, f64),
             $ (
@@ -153,6 +234,11 @@ macro_rules! arith_scalar {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 Box::new(decimal::$op_decimal(lhs, rhs)) as Box<dyn Array>
             }
+            (Decimal256(_, _), Decimal256(_, _)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(decimal256::$op_decimal(lhs, rhs)) as Box<dyn Array>
+            }
             )?
             $ (
             (Time32(TimeUnit::Second), Duration(_))
@@ -185,6 +271,13 @@ macro_rules! arith_scalar {
                 time::$op_timestamp(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
             )?
+            $ (
+            (Interval(IntervalUnit::MonthDayNano), Interval(IntervalUnit::MonthDayNano)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(time::$op_intervals(lhs, rhs)) as Box<dyn Array>
+            }
+            )?
             _ => todo!(
                 "Addition of {:?} with {:?} is not supported",
                 lhs.data_type(),
@@ -201,12 +294,17 @@ macro_rules! arith_scalar {
 /// * the arrays have a different length
 /// * one of the arrays is a timestamp with timezone and the timezone is not valid.
 pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
+    if let Some(result) = unpack_dictionaries(lhs, rhs, add) {
+        return result;
+    }
     arith!(
         lhs,
         rhs,
         add,
+        decimal = add,
         duration = add_duration,
-        interval = add_interval
+        interval = add_interval,
+        intervals = add_intervals
     )
 }
 
@@ -217,17 +315,22 @@ pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// * the arrays have a different length
 /// * one of the arrays is a timestamp with timezone and the timezone is not valid.
 pub fn add_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+    if let Some(result) = dictionary_scalar(lhs, rhs, add_scalar) {
+        return result;
+    }
     arith_scalar!(
         lhs,
         rhs,
         add_scalar,
         duration = add_duration_scalar,
-        interval = add_interval_scalar
+        interval = add_interval_scalar,
+        intervals = add_intervals_scalar
     )
 }
 
 /// Returns whether two [`DataType`]s can be added by [`add`].
 pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
+    let (lhs, rhs) = (values_type(lhs), values_type(rhs));
     use DataType::*;
     matches!(
         (lhs, rhs),
@@ -240,9 +343,11 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt32, UInt32)
             | (UInt64, UInt64)
             | (Float64, Float64)
+            | (Float16, Float16)
             | (Float32, Float32)
             | (Duration(_), Duration(_))
             | (Decimal(_, _), Decimal(_, _))
+            | (Decimal256(_, _), Decimal256(_, _))
             | (Date32, Duration(_))
             | (Date64, Duration(_))
             | (Time32(TimeUnit::Millisecond), Duration(_))
@@ -251,6 +356,10 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
             | (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano))
+            | (
+                Interval(IntervalUnit::MonthDayNano),
+                Interval(IntervalUnit::MonthDayNano)
+            )
     )
 }
 
@@ -261,13 +370,17 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
 /// * the arrays have a different length
 /// * one of the arrays is a timestamp with timezone and the timezone is not valid.
 pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
+    if let Some(result) = unpack_dictionaries(lhs, rhs, sub) {
+        return result;
+    }
     arith!(
         lhs,
         rhs,
         sub,
         decimal = sub,
         duration = subtract_duration,
-        timestamp = subtract_timestamps
+        timestamp = subtract_timestamps,
+        intervals = sub_intervals
     )
 }
 
@@ -278,17 +391,22 @@ pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// * the arrays have a different length
 /// * one of the arrays is a timestamp with timezone and the timezone is not valid.
 pub fn sub_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+    if let Some(result) = dictionary_scalar(lhs, rhs, sub_scalar) {
+        return result;
+    }
     arith_scalar!(
         lhs,
         rhs,
         sub_scalar,
         duration = sub_duration_scalar,
-        timestamp = sub_timestamps_scalar
+        timestamp = sub_timestamps_scalar,
+        intervals = sub_intervals_scalar
     )
 }
 
 /// Returns whether two [`DataType`]s can be subtracted by [`sub`].
 pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
+    let (lhs, rhs) = (values_type(lhs), values_type(rhs));
     use DataType::*;
     matches!(
         (lhs, rhs),
@@ -301,9 +419,11 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt32, UInt32)
             | (UInt64, UInt64)
             | (Float64, Float64)
+            | (Float16, Float16)
             | (Float32, Float32)
             | (Duration(_), Duration(_))
             | (Decimal(_, _), Decimal(_, _))
+            | (Decimal256(_, _), Decimal256(_, _))
             | (Date32, Duration(_))
             | (Date64, Duration(_))
             | (Time32(TimeUnit::Millisecond), Duration(_))
@@ -312,6 +432,10 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
             | (Timestamp(_, None), Timestamp(_, None))
+            | (
+                Interval(IntervalUnit::MonthDayNano),
+                Interval(IntervalUnit::MonthDayNano)
+            )
     )
 }
 
@@ -321,6 +445,9 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
 /// * the opertion is not supported for the logical types (use [`can_mul`] to check)
 /// * the arrays have a different length
 pub fn mul(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
+    if let Some(result) = unpack_dictionaries(lhs, rhs, mul) {
+        return result;
+    }
     arith!(lhs, rhs, mul, decimal = mul)
 }
 
@@ -329,11 +456,15 @@ pub fn mul(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_mul`] to check)
 pub fn mul_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+    if let Some(result) = dictionary_scalar(lhs, rhs, mul_scalar) {
+        return result;
+    }
     arith_scalar!(lhs, rhs, mul_scalar, decimal = mul_scalar)
 }
 
 /// Returns whether two [`DataType`]s can be multiplied by [`mul`].
 pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
+    let (lhs, rhs) = (values_type(lhs), values_type(rhs));
     use DataType::*;
     matches!(
         (lhs, rhs),
@@ -346,8 +477,10 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt32, UInt32)
             | (UInt64, UInt64)
             | (Float64, Float64)
+            | (Float16, Float16)
             | (Float32, Float32)
             | (Decimal(_, _), Decimal(_, _))
+            | (Decimal256(_, _), Decimal256(_, _))
     )
 }
 
@@ -357,6 +490,9 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
 /// * the opertion is not supported for the logical types (use [`can_div`] to check)
 /// * the arrays have a different length
 pub fn div(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
+    if let Some(result) = unpack_dictionaries(lhs, rhs, div) {
+        return result;
+    }
     arith!(lhs, rhs, div, decimal = div)
 }
 
@@ -365,6 +501,9 @@ pub fn div(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_div`] to check)
 pub fn div_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+    if let Some(result) = dictionary_scalar(lhs, rhs, div_scalar) {
+        return result;
+    }
     arith_scalar!(lhs, rhs, div_scalar, decimal = div_scalar)
 }
 
@@ -379,11 +518,127 @@ pub fn can_div(lhs: &DataType, rhs: &DataType) -> bool {
 /// * the opertion is not supported for the logical types (use [`can_rem`] to check)
 /// * the arrays have a different length
 pub fn rem(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
+    if let Some(result) = unpack_dictionaries(lhs, rhs, rem) {
+        return result;
+    }
     arith!(lhs, rhs, rem)
 }
 
 /// Returns whether two [`DataType`]s "can be remainder" by [`rem`].
 pub fn can_rem(lhs: &DataType, rhs: &DataType) -> bool {
+    let (lhs, rhs) = (values_type(lhs), values_type(rhs));
+    use DataType::*;
+    matches!(
+        (lhs, rhs),
+        (Int8, Int8)
+            | (Int16, Int16)
+            | (Int32, Int32)
+            | (Int64, Int64)
+            | (UInt8, UInt8)
+            | (UInt16, UInt16)
+            | (UInt32, UInt32)
+            | (UInt64, UInt64)
+            | (Float64, Float64)
+            | (Float16, Float16)
+            | (Float32, Float32)
+    )
+}
+
+// `basic::powf_scalar` takes its exponent by value
+macro_rules! primitive_pow_scalar {
+    ($lhs:expr, $rhs:expr, powf_scalar, $type:ty) => {{
+        let lhs = $lhs
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$type>>()
+            .unwrap();
+        let rhs = $rhs
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<$type>>()
+            .unwrap();
+
+        let result = match rhs.value() {
+            Some(rhs) => basic::powf_scalar::<$type>(lhs, rhs),
+            None => PrimitiveArray::<$type>::new_null(lhs.data_type().clone(), lhs.len()),
+        };
+        Box::new(result) as Box<dyn Array>
+    }};
+    ($lhs:expr, $rhs:expr, $op:tt, $type:ty) => {{
+        primitive_scalar!($lhs, $rhs, $op, $type)
+    }};
+}
+
+// Macro to create a `match` statement dispatching to the checked integer or float version of
+// `pow`, depending on the array's logical types
+macro_rules! pow {
+    ($lhs:expr, $rhs:expr, $checked:tt, $float:tt, $rhs_type:ident) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        use DataType::*;
+        match (lhs.data_type(), rhs.data_type()) {
+            (Int8, Int8) => $rhs_type!(lhs, rhs, $checked, i8),
+            (Int16, Int16) => $rhs_type!(lhs, rhs, $checked, i16),
+            (Int32, Int32) => $rhs_type!(lhs, rhs, $checked, i32),
+            (Int64, Int64) => $rhs_type!(lhs, rhs, $checked, i64),
+            (UInt8, UInt8) => $rhs_type!(lhs, rhs, $checked, u8),
+            (UInt16, UInt16) => $rhs_type!(lhs, rhs, $checked, u16),
+            (UInt32, UInt32) => $rhs_type!(lhs, rhs, $checked, u32),
+            (UInt64, UInt64) => $rhs_type!(lhs, rhs, $checked, u64),
+            (Float32, Float32) => $rhs_type!(lhs, rhs, $float, f32),
+            (Float64, Float64) => $rhs_type!(lhs, rhs, $float, f64),
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Power of {:?} with {:?} is not supported",
+                    lhs.data_type(),
+                    rhs.data_type()
+                )))
+            }
+        }
+    }};
+}
+
+/// Raises an [`Array`] to the power of another [`Array`].
+///
+/// Integers are raised with [`basic::checked_pow`], i.e. values that overflow or whose exponent
+/// is negative are null; floats are raised with [`basic::powf`].
+/// # Error
+/// This function errors iff the operation is not supported for the logical types (use
+/// [`can_pow`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn pow(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    if is_dictionary(lhs) || is_dictionary(rhs) {
+        return pow(
+            unpack_dictionary(lhs).as_ref(),
+            unpack_dictionary(rhs).as_ref(),
+        );
+    }
+    Ok(pow!(lhs, rhs, checked_pow, powf, primitive))
+}
+
+/// Raises an [`Array`] to the power of a [`Scalar`]. See [`pow`] for details.
+/// # Error
+/// This function errors iff the operation is not supported for the logical types (use
+/// [`can_pow`] to check).
+pub fn pow_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
+    if let PhysicalType::Dictionary(key) = lhs.data_type().to_physical_type() {
+        return match_integer_type!(key, |$T| {
+            let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let values = pow_scalar(lhs.values().as_ref(), rhs)?.into();
+            Ok(Box::new(DictionaryArray::<$T>::from_data(lhs.keys().clone(), values)) as Box<dyn Array>)
+        });
+    }
+    Ok(pow!(
+        lhs,
+        rhs,
+        checked_pow_scalar,
+        powf_scalar,
+        primitive_pow_scalar
+    ))
+}
+
+/// Returns whether two [`DataType`]s can be raised by [`pow`].
+pub fn can_pow(lhs: &DataType, rhs: &DataType) -> bool {
+    let (lhs, rhs) = (values_type(lhs), values_type(rhs));
     use DataType::*;
     matches!(
         (lhs, rhs),
@@ -405,16 +660,18 @@ macro_rules! with_match_negatable {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::{days_ms, months_days_ns};
+    use crate::types::{days_ms, f16, i256, months_days_ns};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 | UInt16 | UInt32 | UInt64=> todo!(),
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -460,12 +717,23 @@ pub fn can_neg(data_type: &DataType) -> bool {
             | Primitive(Int32)
             | Primitive(Int64)
             | Primitive(Float64)
+            | Primitive(Float16)
             | Primitive(Float32)
             | Primitive(DaysMs)
             | Primitive(MonthDayNano)
     )
 }
 
+/// How values that are exactly halfway between two rounded values are rounded, used by
+/// [`basic::round`] and [`decimal::round`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundMode {
+    /// Rounds half-way values to the nearest even value, e.g. `2.5` to `2` and `3.5` to `4`
+    HalfEven,
+    /// Rounds half-way values away from zero, e.g. `2.5` to `3` and `-2.5` to `-3`
+    HalfUp,
+}
+
 /// Defines basic addition operation for primitive arrays
 pub trait ArrayAdd<Rhs>: Sized {
     /// Adds itself to `rhs`
@@ -14,13 +14,13 @@ use std::ops::{Add, Sub};
 use num_traits::AsPrimitive;
 
 use crate::{
-    array::PrimitiveArray,
-    compute::arity::{binary, unary},
-    datatypes::{DataType, TimeUnit},
+    array::{new_null_array, Array, PrimitiveArray},
+    compute::arity::{binary, binary_checked, unary, unary_checked},
+    datatypes::{DataType, IntervalUnit, TimeUnit},
     error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
-    temporal_conversions,
-    types::{months_days_ns, NativeType},
+    temporal_conversions::{self, SECONDS_IN_DAY},
+    types::{days_ms, months_days_ns, NativeType},
 };
 
 /// Creates the scale required to add or subtract a Duration to a time array
@@ -311,7 +311,219 @@ pub fn sub_timestamps_scalar(
     Ok(unary(lhs, op, DataType::Duration(*timeunit_a)))
 }
 
+/// Adds two [`DataType::Interval`] arrays of [`IntervalUnit::MonthDayNano`], adding each
+/// component (months, days and nanoseconds) of the intervals independently.
+/// # Panic
+/// This function panics iff the arrays have different lengths or the operation overflows.
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::arithmetics::time::add_intervals;
+/// use arrow2::types::months_days_ns;
+///
+/// let lhs = PrimitiveArray::from([Some(months_days_ns::new(1, 2, 3)), None]);
+/// let rhs = PrimitiveArray::from([Some(months_days_ns::new(1, -2, 1)), Some(months_days_ns::new(1, 1, 1))]);
+///
+/// let result = add_intervals(&lhs, &rhs);
+/// let expected = PrimitiveArray::from([Some(months_days_ns::new(2, 0, 4)), None]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn add_intervals(
+    lhs: &PrimitiveArray<months_days_ns>,
+    rhs: &PrimitiveArray<months_days_ns>,
+) -> PrimitiveArray<months_days_ns> {
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| a + b)
+}
+
+/// Adds a [`DataType::Interval`] scalar of [`IntervalUnit::MonthDayNano`] to an array of the
+/// same type (see [`add_intervals`]).
+pub fn add_intervals_scalar(
+    lhs: &PrimitiveArray<months_days_ns>,
+    rhs: &PrimitiveScalar<months_days_ns>,
+) -> PrimitiveArray<months_days_ns> {
+    match rhs.value() {
+        Some(rhs) => unary(lhs, |a| a + rhs, lhs.data_type().clone()),
+        None => PrimitiveArray::new_null(lhs.data_type().clone(), lhs.len()),
+    }
+}
+
+/// Checked addition of two [`DataType::Interval`] arrays of [`IntervalUnit::MonthDayNano`]
+/// (see [`add_intervals`]). A value is null when any of its components overflows.
+/// # Panic
+/// This function panics iff the arrays have different lengths.
+pub fn checked_add_intervals(
+    lhs: &PrimitiveArray<months_days_ns>,
+    rhs: &PrimitiveArray<months_days_ns>,
+) -> PrimitiveArray<months_days_ns> {
+    binary_checked(lhs, rhs, lhs.data_type().clone(), |a, b| a.checked_add(b))
+}
+
+/// Subtracts two [`DataType::Interval`] arrays of [`IntervalUnit::MonthDayNano`], subtracting
+/// each component (months, days and nanoseconds) of the intervals independently.
+/// # Panic
+/// This function panics iff the arrays have different lengths or the operation overflows.
+pub fn sub_intervals(
+    lhs: &PrimitiveArray<months_days_ns>,
+    rhs: &PrimitiveArray<months_days_ns>,
+) -> PrimitiveArray<months_days_ns> {
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| a - b)
+}
+
+/// Subtracts a [`DataType::Interval`] scalar of [`IntervalUnit::MonthDayNano`] from an array of
+/// the same type (see [`sub_intervals`]).
+pub fn sub_intervals_scalar(
+    lhs: &PrimitiveArray<months_days_ns>,
+    rhs: &PrimitiveScalar<months_days_ns>,
+) -> PrimitiveArray<months_days_ns> {
+    match rhs.value() {
+        Some(rhs) => unary(lhs, |a| a - rhs, lhs.data_type().clone()),
+        None => PrimitiveArray::new_null(lhs.data_type().clone(), lhs.len()),
+    }
+}
+
+/// Checked subtraction of two [`DataType::Interval`] arrays of [`IntervalUnit::MonthDayNano`]
+/// (see [`sub_intervals`]). A value is null when any of its components overflows.
+/// # Panic
+/// This function panics iff the arrays have different lengths.
+pub fn checked_sub_intervals(
+    lhs: &PrimitiveArray<months_days_ns>,
+    rhs: &PrimitiveArray<months_days_ns>,
+) -> PrimitiveArray<months_days_ns> {
+    binary_checked(lhs, rhs, lhs.data_type().clone(), |a, b| a.checked_sub(b))
+}
+
+/// Returns the number of `time_unit`s in a second.
+fn units_per_second(time_unit: TimeUnit) -> i64 {
+    match time_unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => temporal_conversions::MILLISECONDS,
+        TimeUnit::Microsecond => temporal_conversions::MICROSECONDS,
+        TimeUnit::Nanosecond => temporal_conversions::NANOSECONDS,
+    }
+}
+
+/// Returns the values of a `Date32`, `Date64` or `Timestamp` array as `i64`, together with
+/// their [`TimeUnit`] and timezone.
+fn to_instants(array: &dyn Array) -> Result<(PrimitiveArray<i64>, TimeUnit, Option<&String>)> {
+    match array.data_type().to_logical_type() {
+        DataType::Date32 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            let seconds = unary(array, |x| x as i64 * SECONDS_IN_DAY, DataType::Int64);
+            Ok((seconds, TimeUnit::Second, None))
+        }
+        DataType::Date64 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            Ok((array.clone(), TimeUnit::Millisecond, None))
+        }
+        DataType::Timestamp(time_unit, timezone) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            Ok((array.clone(), *time_unit, timezone.as_ref()))
+        }
+        dt => Err(ArrowError::InvalidArgumentError(format!(
+            "date_diff does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Returns the difference `lhs - rhs` between two `Date32`, `Date64` or `Timestamp` arrays as
+/// a [`DataType::Duration`] of `time_unit`.
+///
+/// The arrays can have different types and time units. Timestamps with a timezone represent
+/// instants in UTC and can be subtracted regardless of their timezones, but not from dates or
+/// timestamps without a timezone.
+/// Differences are truncated towards zero when `time_unit` is coarser than the units of the
+/// arrays, and are null when they overflow.
+/// # Errors
+/// This function errors iff the types are not supported (see [`can_date_diff`]) or the arrays
+/// have different lengths.
+/// # Examples
+/// ```
+/// use arrow2::array::{Int32Array, Int64Array};
+/// use arrow2::compute::arithmetics::time::date_diff;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// // 1970-01-03 and 1970-01-01
+/// let dates = Int32Array::from(&[Some(2), Some(0), None]).to(DataType::Date32);
+/// // 1970-01-01 00:00:01.500
+/// let timestamps = Int64Array::from_slice(&[1_500, 1_500, 1_500])
+///     .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+///
+/// let result = date_diff(&dates, &timestamps, TimeUnit::Second).unwrap();
+/// let expected = Int64Array::from(&[Some(172_798), Some(-1), None])
+///     .to(DataType::Duration(TimeUnit::Second));
+/// assert_eq!(result, expected);
+/// ```
+pub fn date_diff(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    time_unit: TimeUnit,
+) -> Result<PrimitiveArray<i64>> {
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Arrays must have the same length".to_string(),
+        ));
+    }
+    let (lhs, lhs_unit, lhs_timezone) = to_instants(lhs)?;
+    let (rhs, rhs_unit, rhs_timezone) = to_instants(rhs)?;
+    if lhs_timezone.is_some() != rhs_timezone.is_some() {
+        return Err(ArrowError::InvalidArgumentError(
+            "date_diff requires both arguments to either have or not have a timezone".to_string(),
+        ));
+    }
+
+    // subtract in the finest unit of both arrays, and then convert to `time_unit`
+    let lhs_units = units_per_second(lhs_unit);
+    let rhs_units = units_per_second(rhs_unit);
+    let units = lhs_units.max(rhs_units);
+    let (lhs_scale, rhs_scale) = (units / lhs_units, units / rhs_units);
+    let target_units = units_per_second(time_unit);
+
+    let op = move |lhs: i64, rhs: i64| {
+        let difference = lhs
+            .checked_mul(lhs_scale)?
+            .checked_sub(rhs.checked_mul(rhs_scale)?)?;
+        if target_units >= units {
+            difference.checked_mul(target_units / units)
+        } else {
+            Some(difference / (units / target_units))
+        }
+    };
+    Ok(binary_checked(
+        &lhs,
+        &rhs,
+        DataType::Duration(time_unit),
+        op,
+    ))
+}
+
+/// Returns whether [`date_diff`] supports arrays of types `lhs` and `rhs`.
+pub fn can_date_diff(lhs: &DataType, rhs: &DataType) -> bool {
+    use DataType::*;
+    match (lhs.to_logical_type(), rhs.to_logical_type()) {
+        (Timestamp(_, Some(_)), Timestamp(_, Some(_))) => true,
+        (Timestamp(_, Some(_)), _) | (_, Timestamp(_, Some(_))) => false,
+        (lhs, rhs) => {
+            matches!(lhs, Date32 | Date64 | Timestamp(_, None))
+                && matches!(rhs, Date32 | Date64 | Timestamp(_, None))
+        }
+    }
+}
+
 /// Adds an interval to a [`DataType::Timestamp`].
+///
+/// The interval is added as in [`temporal_conversions::add_interval`] (or
+/// [`temporal_conversions::add_naive_interval`] without timezone). Values whose result is out of
+/// range are null.
 pub fn add_interval(
     timestamp: &PrimitiveArray<i64>,
     interval: &PrimitiveArray<months_days_ns>,
@@ -321,7 +533,7 @@ pub fn add_interval(
             let time_unit = *time_unit;
             let timezone = temporal_conversions::parse_offset(timezone_str);
             match timezone {
-                Ok(timezone) => Ok(binary(
+                Ok(timezone) => Ok(binary_checked(
                     timestamp,
                     interval,
                     timestamp.data_type().clone(),
@@ -334,7 +546,7 @@ pub fn add_interval(
                 #[cfg(feature = "chrono-tz")]
                 Err(_) => {
                     let timezone = temporal_conversions::parse_offset_tz(timezone_str)?;
-                    Ok(binary(
+                    Ok(binary_checked(
                         timestamp,
                         interval,
                         timestamp.data_type().clone(),
@@ -354,7 +566,7 @@ pub fn add_interval(
         }
         DataType::Timestamp(time_unit, None) => {
             let time_unit = *time_unit;
-            Ok(binary(
+            Ok(binary_checked(
                 timestamp,
                 interval,
                 timestamp.data_type().clone(),
@@ -369,7 +581,7 @@ pub fn add_interval(
     }
 }
 
-/// Adds an interval to a [`DataType::Timestamp`].
+/// Adds an interval to a [`DataType::Timestamp`] (see [`add_interval`]).
 pub fn add_interval_scalar(
     timestamp: &PrimitiveArray<i64>,
     interval: &PrimitiveScalar<months_days_ns>,
@@ -388,7 +600,7 @@ pub fn add_interval_scalar(
             let time_unit = *time_unit;
             let timezone = temporal_conversions::parse_offset(timezone_str);
             match timezone {
-                Ok(timezone) => Ok(unary(
+                Ok(timezone) => Ok(unary_checked(
                     timestamp,
                     |timestamp| {
                         temporal_conversions::add_interval(
@@ -400,7 +612,7 @@ pub fn add_interval_scalar(
                 #[cfg(feature = "chrono-tz")]
                 Err(_) => {
                     let timezone = temporal_conversions::parse_offset_tz(timezone_str)?;
-                    Ok(unary(
+                    Ok(unary_checked(
                         timestamp,
                         |timestamp| {
                             temporal_conversions::add_interval(
@@ -419,7 +631,7 @@ pub fn add_interval_scalar(
         }
         DataType::Timestamp(time_unit, None) => {
             let time_unit = *time_unit;
-            Ok(unary(
+            Ok(unary_checked(
                 timestamp,
                 |timestamp| {
                     temporal_conversions::add_naive_interval(timestamp, time_unit, interval)
@@ -432,3 +644,250 @@ pub fn add_interval_scalar(
         )),
     }
 }
+
+fn year_month_to_interval(months: i32) -> months_days_ns {
+    months_days_ns::new(months, 0, 0)
+}
+
+fn day_time_to_interval(interval: days_ms) -> months_days_ns {
+    months_days_ns::new(
+        0,
+        interval.days(),
+        interval.milliseconds() as i64 * 1_000_000,
+    )
+}
+
+/// Converts an interval array of any [`IntervalUnit`] to [`IntervalUnit::MonthDayNano`],
+/// negating it when `negate` is true.
+fn to_month_day_nano(interval: &dyn Array, negate: bool) -> Result<PrimitiveArray<months_days_ns>> {
+    let data_type = DataType::Interval(IntervalUnit::MonthDayNano);
+    let sign = |x: months_days_ns| if negate { -x } else { x };
+    match interval.data_type().to_logical_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            let interval = interval.as_any().downcast_ref().unwrap();
+            Ok(unary(
+                interval,
+                |x| sign(year_month_to_interval(x)),
+                data_type,
+            ))
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            let interval = interval.as_any().downcast_ref().unwrap();
+            Ok(unary(
+                interval,
+                |x| sign(day_time_to_interval(x)),
+                data_type,
+            ))
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let interval = interval.as_any().downcast_ref().unwrap();
+            Ok(unary(interval, sign, data_type))
+        }
+        dt => Err(ArrowError::InvalidArgumentError(format!(
+            "Expected an interval, got {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Converts an interval scalar of any [`IntervalUnit`] to a [`months_days_ns`], negating it
+/// when `negate` is true.
+fn scalar_to_month_day_nano(interval: &dyn Scalar, negate: bool) -> Result<Option<months_days_ns>> {
+    let interval = match interval.data_type().to_logical_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => interval
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<i32>>()
+            .unwrap()
+            .value()
+            .map(year_month_to_interval),
+        DataType::Interval(IntervalUnit::DayTime) => interval
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<days_ms>>()
+            .unwrap()
+            .value()
+            .map(day_time_to_interval),
+        DataType::Interval(IntervalUnit::MonthDayNano) => interval
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<months_days_ns>>()
+            .unwrap()
+            .value(),
+        dt => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected an interval, got {:?}",
+                dt
+            )))
+        }
+    };
+    Ok(interval.map(|x| if negate { -x } else { x }))
+}
+
+fn add_date32_interval(days: i32, interval: months_days_ns) -> Option<i32> {
+    let seconds = days as i64 * SECONDS_IN_DAY;
+    let seconds = temporal_conversions::add_naive_interval(seconds, TimeUnit::Second, interval)?;
+    seconds.div_euclid(SECONDS_IN_DAY).try_into().ok()
+}
+
+type IntervalOp = Box<dyn Fn(i64, months_days_ns) -> Option<i64>>;
+
+/// Returns the function that adds an interval to a value of a `Date64` or `Timestamp` array.
+fn interval_op(time: &DataType) -> Result<IntervalOp> {
+    match time.to_logical_type() {
+        DataType::Date64 => Ok(Box::new(|time, interval| {
+            temporal_conversions::add_naive_interval(time, TimeUnit::Millisecond, interval)
+        })),
+        DataType::Timestamp(time_unit, None) => {
+            let time_unit = *time_unit;
+            Ok(Box::new(move |time, interval| {
+                temporal_conversions::add_naive_interval(time, time_unit, interval)
+            }))
+        }
+        DataType::Timestamp(time_unit, Some(timezone_str)) => {
+            let time_unit = *time_unit;
+            match temporal_conversions::parse_offset(timezone_str) {
+                Ok(timezone) => Ok(Box::new(move |time, interval| {
+                    temporal_conversions::add_interval(time, time_unit, interval, &timezone)
+                })),
+                #[cfg(feature = "chrono-tz")]
+                Err(_) => {
+                    let timezone = temporal_conversions::parse_offset_tz(timezone_str)?;
+                    Ok(Box::new(move |time, interval| {
+                        temporal_conversions::add_interval(time, time_unit, interval, &timezone)
+                    }))
+                }
+                #[cfg(not(feature = "chrono-tz"))]
+                _ => Err(ArrowError::InvalidArgumentError(format!(
+                    "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+                    timezone_str
+                ))),
+            }
+        }
+        dt => Err(ArrowError::InvalidArgumentError(format!(
+            "Adding an interval is not supported for {:?}",
+            dt
+        ))),
+    }
+}
+
+fn calendar_interval(
+    time: &dyn Array,
+    interval: &PrimitiveArray<months_days_ns>,
+) -> Result<Box<dyn Array>> {
+    if time.len() != interval.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Arrays must have the same length".to_string(),
+        ));
+    }
+    if time.data_type().to_logical_type() == &DataType::Date32 {
+        let time = time.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        return Ok(Box::new(binary_checked(
+            time,
+            interval,
+            time.data_type().clone(),
+            add_date32_interval,
+        )));
+    }
+    let op = interval_op(time.data_type())?;
+    let time = time.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+    Ok(Box::new(binary_checked(
+        time,
+        interval,
+        time.data_type().clone(),
+        op,
+    )))
+}
+
+fn calendar_interval_scalar(
+    time: &dyn Array,
+    interval: Option<months_days_ns>,
+) -> Result<Box<dyn Array>> {
+    if time.data_type().to_logical_type() == &DataType::Date32 {
+        let time = time.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        return Ok(match interval {
+            Some(interval) => Box::new(unary_checked(
+                time,
+                |x| add_date32_interval(x, interval),
+                time.data_type().clone(),
+            )),
+            None => new_null_array(time.data_type().clone(), time.len()),
+        });
+    }
+    let op = interval_op(time.data_type())?;
+    let time = time.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+    Ok(match interval {
+        Some(interval) => Box::new(unary_checked(
+            time,
+            |x| op(x, interval),
+            time.data_type().clone(),
+        )),
+        None => new_null_array(time.data_type().clone(), time.len()),
+    })
+}
+
+/// Adds an interval array of any [`IntervalUnit`] to a `Date32`, `Date64` or `Timestamp` array.
+///
+/// Months are added in calendar terms, clamping the day to the last day of the resulting
+/// month (e.g. 2021-01-31 plus one month is 2021-02-28), followed by days and the remaining
+/// (sub-day) time. The months and days are added to the local date and time of timestamps with
+/// a timezone, so that e.g. one day after 12:00 is 12:00 even across a change of daylight saving
+/// time, while the sub-day time is added as elapsed time (see
+/// [`temporal_conversions::add_interval`]).
+/// The sub-day time of an interval added to a `Date32` is rounded towards the past day.
+/// The result has the same type as `time`; values whose result is out of range are null.
+/// # Errors
+/// This function errors iff the types are not supported (see [`can_calendar_interval`]),
+/// the arrays have different lengths, or the timezone of `time` cannot be parsed.
+/// # Examples
+/// ```
+/// use arrow2::array::{Int32Array, PrimitiveArray};
+/// use arrow2::compute::arithmetics::time::add_calendar_interval;
+/// use arrow2::datatypes::{DataType, IntervalUnit};
+///
+/// // 2021-01-31 and 2020-01-31
+/// let dates = Int32Array::from(&[Some(18_658), Some(18_292), None]).to(DataType::Date32);
+/// let months = Int32Array::from(&[Some(1), Some(1), Some(1)])
+///     .to(DataType::Interval(IntervalUnit::YearMonth));
+///
+/// let result = add_calendar_interval(&dates, &months).unwrap();
+/// // 2021-02-28 and 2020-02-29
+/// let expected = Int32Array::from(&[Some(18_686), Some(18_321), None]).to(DataType::Date32);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn add_calendar_interval(time: &dyn Array, interval: &dyn Array) -> Result<Box<dyn Array>> {
+    calendar_interval(time, &to_month_day_nano(interval, false)?)
+}
+
+/// Subtracts an interval array of any [`IntervalUnit`] from a `Date32`, `Date64` or `Timestamp`
+/// array, i.e. adds the negated interval (see [`add_calendar_interval`]).
+pub fn sub_calendar_interval(time: &dyn Array, interval: &dyn Array) -> Result<Box<dyn Array>> {
+    calendar_interval(time, &to_month_day_nano(interval, true)?)
+}
+
+/// Adds an interval scalar of any [`IntervalUnit`] to a `Date32`, `Date64` or `Timestamp`
+/// array (see [`add_calendar_interval`]).
+pub fn add_calendar_interval_scalar(
+    time: &dyn Array,
+    interval: &dyn Scalar,
+) -> Result<Box<dyn Array>> {
+    calendar_interval_scalar(time, scalar_to_month_day_nano(interval, false)?)
+}
+
+/// Subtracts an interval scalar of any [`IntervalUnit`] from a `Date32`, `Date64` or
+/// `Timestamp` array (see [`sub_calendar_interval`]).
+pub fn sub_calendar_interval_scalar(
+    time: &dyn Array,
+    interval: &dyn Scalar,
+) -> Result<Box<dyn Array>> {
+    calendar_interval_scalar(time, scalar_to_month_day_nano(interval, true)?)
+}
+
+/// Returns whether an interval of type `interval` can be added to or subtracted from an array
+/// of type `time` by [`add_calendar_interval`] and [`sub_calendar_interval`].
+pub fn can_calendar_interval(time: &DataType, interval: &DataType) -> bool {
+    matches!(
+        (time.to_logical_type(), interval.to_logical_type()),
+        (
+            DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _),
+            DataType::Interval(_)
+        )
+    )
+}
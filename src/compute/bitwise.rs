@@ -1,8 +1,11 @@
-//! Contains bitwise operators: [`or`], [`and`], [`xor`] and [`not`].
+//! Contains bitwise operators: [`or`], [`and`], [`xor`], [`not`], [`shift_left`],
+//! [`shift_right`] and their checked variants [`checked_shift_left`] and [`checked_shift_right`].
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
+use num_traits::{AsPrimitive, CheckedShl, CheckedShr, ToPrimitive, WrappingShl, WrappingShr};
+
 use crate::array::PrimitiveArray;
-use crate::compute::arity::{binary, unary};
+use crate::compute::arity::{binary, binary_checked, unary, unary_checked};
 use crate::types::NativeType;
 
 /// Performs `OR` operation on two [`PrimitiveArray`]s.
@@ -73,3 +76,122 @@ where
 {
     unary(lhs, |a| a & *rhs, lhs.data_type().clone())
 }
+
+/// Shifts the values of `lhs` to the left by the values of `rhs`.
+///
+/// The shift amount is taken modulo the number of bits of `T`, so that e.g. shifting an `i32`
+/// by `33` is the same as shifting it by `1`. Negative amounts are first reinterpreted as
+/// unsigned, so that shifting an `i32` by `-1` is the same as shifting it by `31`. Use
+/// [`checked_shift_left`] to get nulls for these amounts instead.
+/// # Panic
+/// This function panics when the arrays have different lengths.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::bitwise::shift_left;
+///
+/// let a = Int32Array::from(&[Some(1), None, Some(3), Some(1)]);
+/// let b = Int32Array::from(&[Some(2), Some(1), Some(1), Some(33)]);
+/// let result = shift_left(&a, &b);
+/// assert_eq!(result, Int32Array::from(&[Some(4), None, Some(6), Some(2)]));
+/// ```
+pub fn shift_left<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShl + AsPrimitive<u32>,
+{
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        a.wrapping_shl(b.as_())
+    })
+}
+
+/// Shifts the values of `lhs` to the right by the values of `rhs`.
+///
+/// The shift is arithmetic for signed integers (i.e. it preserves the sign) and logical for
+/// unsigned integers. The shift amount wraps as in [`shift_left`]; use [`checked_shift_right`]
+/// to get nulls for amounts that are negative or not smaller than the number of bits of `T`.
+/// # Panic
+/// This function panics when the arrays have different lengths.
+pub fn shift_right<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShr + AsPrimitive<u32>,
+{
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        a.wrapping_shr(b.as_())
+    })
+}
+
+/// Shifts the values of a [`PrimitiveArray`] to the left by a scalar.
+/// See [`shift_left`] for details.
+pub fn shift_left_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShl + AsPrimitive<u32>,
+{
+    let rhs = rhs.as_();
+    unary(lhs, |a| a.wrapping_shl(rhs), lhs.data_type().clone())
+}
+
+/// Shifts the values of a [`PrimitiveArray`] to the right by a scalar.
+/// See [`shift_right`] for details.
+pub fn shift_right_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShr + AsPrimitive<u32>,
+{
+    let rhs = rhs.as_();
+    unary(lhs, |a| a.wrapping_shr(rhs), lhs.data_type().clone())
+}
+
+/// Shifts the values of `lhs` to the left by the values of `rhs`. Amounts that are negative or
+/// not smaller than the number of bits of `T` result in a null.
+/// # Panic
+/// This function panics when the arrays have different lengths.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::bitwise::checked_shift_left;
+///
+/// let a = Int32Array::from(&[Some(1), None, Some(3), Some(1)]);
+/// let b = Int32Array::from(&[Some(2), Some(1), Some(-1), Some(32)]);
+/// let result = checked_shift_left(&a, &b);
+/// assert_eq!(result, Int32Array::from(&[Some(4), None, None, None]));
+/// ```
+pub fn checked_shift_left<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedShl + ToPrimitive,
+{
+    binary_checked(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        a.checked_shl(b.to_u32()?)
+    })
+}
+
+/// Shifts the values of `lhs` to the right by the values of `rhs`. Amounts that are negative or
+/// not smaller than the number of bits of `T` result in a null.
+/// # Panic
+/// This function panics when the arrays have different lengths.
+pub fn checked_shift_right<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedShr + ToPrimitive,
+{
+    binary_checked(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        a.checked_shr(b.to_u32()?)
+    })
+}
+
+/// Shifts the values of a [`PrimitiveArray`] to the left by a scalar.
+/// See [`checked_shift_left`] for details.
+pub fn checked_shift_left_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedShl + ToPrimitive,
+{
+    let rhs = rhs.to_u32();
+    unary_checked(lhs, |a| a.checked_shl(rhs?), lhs.data_type().clone())
+}
+
+/// Shifts the values of a [`PrimitiveArray`] to the right by a scalar.
+/// See [`checked_shift_right`] for details.
+pub fn checked_shift_right_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedShr + ToPrimitive,
+{
+    let rhs = rhs.to_u32();
+    unary_checked(lhs, |a| a.checked_shr(rhs?), lhs.data_type().clone())
+}
@@ -80,6 +80,35 @@ where
     }
 }
 
+/// Casts a [`BinaryArray`] to a [`Utf8Array`], making any value that is not valid utf8 a Null or,
+/// when `lossy` is `true`, replacing its invalid sequences by `U+FFFD` (the replacement character).
+/// # Errors
+/// This function errors if the values do not fit in the offsets of `O`.
+pub fn binary_to_utf8<I: Offset, O: Offset>(
+    from: &BinaryArray<I>,
+    lossy: bool,
+) -> Result<Utf8Array<O>> {
+    let mut array = MutableUtf8Array::<O>::with_capacity(from.len());
+    if lossy {
+        for x in from.iter() {
+            array.try_push(x.map(String::from_utf8_lossy))?;
+        }
+    } else {
+        for x in from.iter() {
+            array.try_push(x.and_then(|x| simdutf8::basic::from_utf8(x).ok()))?;
+        }
+    }
+    Ok(array.into())
+}
+
+pub(super) fn binary_to_utf8_dyn<I: Offset, O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    binary_to_utf8::<I, O>(from, options.lossy_utf8).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
 /// Cast [`BinaryArray`] to [`DictionaryArray`], also known as packing.
 /// # Errors
 /// This function errors if the maximum key is smaller than the number of distinct elements
@@ -1,7 +1,7 @@
 use num_traits::{AsPrimitive, Float, NumCast};
 
 use crate::error::Result;
-use crate::types::NativeType;
+use crate::types::{i256, NativeType};
 use crate::{array::*, datatypes::DataType};
 
 #[inline]
@@ -134,3 +134,215 @@ where
     let from = from.as_any().downcast_ref().unwrap();
     Ok(Box::new(decimal_to_integer::<T>(from)))
 }
+
+/// The maximum precision of a [`DataType::Decimal256`]
+const MAX_DECIMAL256_PRECISION: usize = 76;
+
+/// `10^exponent`, or `None` if it does not fit in a [`i256`]
+#[inline]
+pub(super) fn decimal256_pow10(exponent: usize) -> Option<i256> {
+    i256::from_i128(10).checked_pow(exponent as u32)
+}
+
+/// Returns `value` if it can be represented with `precision` digits
+#[inline]
+pub(super) fn decimal256_in_precision(value: i256, precision: usize) -> Option<i256> {
+    let max = decimal256_pow10(precision.min(MAX_DECIMAL256_PRECISION)).unwrap() - i256::ONE;
+    if value > max || value < -max {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Returns `value` of scale `from_scale` with scale `to_scale`, truncating any extra digits,
+/// or `None` on overflow
+#[inline]
+fn rescale_decimal256(value: i256, from_scale: usize, to_scale: usize) -> Option<i256> {
+    if from_scale > to_scale {
+        // a factor larger than any `i256` truncates every value to zero
+        Some(
+            decimal256_pow10(from_scale - to_scale)
+                .map(|factor| value / factor)
+                .unwrap_or(i256::ZERO),
+        )
+    } else {
+        value.checked_mul(decimal256_pow10(to_scale - from_scale)?)
+    }
+}
+
+fn decimal256_scale(data_type: &DataType) -> usize {
+    if let DataType::Decimal256(_, s) = data_type.to_logical_type() {
+        *s
+    } else {
+        panic!("internal error: i256 is always a decimal")
+    }
+}
+
+fn decimal_scale(data_type: &DataType) -> usize {
+    if let DataType::Decimal(_, s) = data_type.to_logical_type() {
+        *s
+    } else {
+        panic!("internal error: i128 is always a decimal")
+    }
+}
+
+/// Returns a [`PrimitiveArray<i256>`] with the casted values. Values are `None` on overflow
+pub fn decimal256_to_decimal256(
+    from: &PrimitiveArray<i256>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256> {
+    let from_scale = decimal256_scale(from.data_type());
+
+    let values = from.iter().map(|x| {
+        x.and_then(|x| rescale_decimal256(*x, from_scale, to_scale))
+            .and_then(|x| decimal256_in_precision(x, to_precision))
+    });
+    PrimitiveArray::<i256>::from_trusted_len_iter(values)
+        .to(DataType::Decimal256(to_precision, to_scale))
+}
+
+pub(super) fn decimal256_to_decimal256_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal256_to_decimal256(
+        from,
+        to_precision,
+        to_scale,
+    )))
+}
+
+/// Returns a [`PrimitiveArray<i256>`] with the casted values. Values are `None` on overflow
+pub fn decimal_to_decimal256(
+    from: &PrimitiveArray<i128>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256> {
+    let from_scale = decimal_scale(from.data_type());
+
+    let values = from.iter().map(|x| {
+        x.and_then(|x| rescale_decimal256(i256::from_i128(*x), from_scale, to_scale))
+            .and_then(|x| decimal256_in_precision(x, to_precision))
+    });
+    PrimitiveArray::<i256>::from_trusted_len_iter(values)
+        .to(DataType::Decimal256(to_precision, to_scale))
+}
+
+pub(super) fn decimal_to_decimal256_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_decimal256(
+        from,
+        to_precision,
+        to_scale,
+    )))
+}
+
+/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
+pub fn decimal256_to_decimal(
+    from: &PrimitiveArray<i256>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i128> {
+    let from_scale = decimal256_scale(from.data_type());
+
+    let values = from.iter().map(|x| {
+        x.and_then(|x| rescale_decimal256(*x, from_scale, to_scale))
+            .and_then(|x| decimal256_in_precision(x, to_precision))
+            .and_then(|x| x.to_i128())
+    });
+    PrimitiveArray::<i128>::from_trusted_len_iter(values)
+        .to(DataType::Decimal(to_precision, to_scale))
+}
+
+pub(super) fn decimal256_to_decimal_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal256_to_decimal(
+        from,
+        to_precision,
+        to_scale,
+    )))
+}
+
+/// Returns a [`PrimitiveArray`] of floats with the casted values.
+pub fn decimal256_to_float<T>(from: &PrimitiveArray<i256>) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+    f64: AsPrimitive<T>,
+{
+    let from_scale = decimal256_scale(from.data_type());
+
+    let div = 10_f64.powi(from_scale as i32);
+    let values = from
+        .values()
+        .iter()
+        .map(|x| (x.to_f64() / div).as_())
+        .collect();
+
+    PrimitiveArray::<T>::new(T::PRIMITIVE.into(), values, from.validity().cloned())
+}
+
+pub(super) fn decimal256_to_float_dyn<T>(from: &dyn Array) -> Result<Box<dyn Array>>
+where
+    T: NativeType + Float,
+    f64: AsPrimitive<T>,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal256_to_float::<T>(from)))
+}
+
+/// Returns a [`PrimitiveArray`] of integers with the casted values, truncated towards zero.
+/// Values are `None` on overflow
+pub fn decimal256_to_integer<T>(from: &PrimitiveArray<i256>) -> PrimitiveArray<T>
+where
+    T: NativeType + NumCast,
+{
+    let from_scale = decimal256_scale(from.data_type());
+
+    let values = from.iter().map(|x| {
+        x.and_then(|x| rescale_decimal256(*x, from_scale, 0))
+            .and_then(|x| x.to_i128())
+            .and_then(T::from)
+    });
+
+    PrimitiveArray::from_trusted_len_iter(values)
+}
+
+pub(super) fn decimal256_to_integer_dyn<T>(from: &dyn Array) -> Result<Box<dyn Array>>
+where
+    T: NativeType + NumCast,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal256_to_integer::<T>(from)))
+}
+
+/// Returns a [`Utf8Array`] where every element is the decimal representation of the value,
+/// e.g. `"-1.20"` for `-120` of scale 2.
+pub fn decimal256_to_utf8<O: Offset>(from: &PrimitiveArray<i256>) -> Utf8Array<O> {
+    let display = get_value_display(from, "");
+
+    let iter = from.iter().enumerate().map(|(index, x)| {
+        x.map(|_| {
+            let mut value = String::new();
+            display(&mut value, index).unwrap();
+            value
+        })
+    });
+    Utf8Array::<O>::from_trusted_len_iter(iter)
+}
+
+pub(super) fn decimal256_to_utf8_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal256_to_utf8::<O>(from)))
+}
@@ -49,7 +49,7 @@ pub fn wrapping_dictionary_to_dictionary_values<K: DictionaryKey>(
         values_type,
         CastOptions {
             wrapped: true,
-            partial: false,
+            ..Default::default()
         },
     )?
     .into();
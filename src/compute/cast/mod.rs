@@ -16,6 +16,7 @@ pub use utf8_to::*;
 
 use crate::{
     array::*,
+    compute::take::take,
     datatypes::*,
     error::{ArrowError, Result},
 };
@@ -30,6 +31,24 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to false
+    /// whether an overflowing numeric cast should saturate to the minimum or maximum value of the
+    /// target type (i.e. `256i16 as u8 = 255`) instead of being `None`. `NaN`s are still `None`.
+    /// Ignored when `wrapped` is `true`.
+    pub saturating: bool,
+    /// default to false
+    /// whether a cast should error instead of returning `None` for a (non-null) value that cannot
+    /// be cast, e.g. on overflow or when a string cannot be parsed. The error names the first
+    /// offending row. The values of nested arrays (e.g. of a list) are checked as a whole.
+    pub strict: bool,
+    /// default to false
+    /// whether casting a list to a `FixedSizeList` should pad shorter lists with nulls and
+    /// truncate longer ones, instead of erroring.
+    pub resize_lists: bool,
+    /// default to false
+    /// whether casting binary to strings should replace invalid utf8 sequences by `U+FFFD` (the
+    /// replacement character) instead of converting the value to `None`.
+    pub lossy_utf8: bool,
 }
 
 impl CastOptions {
@@ -99,12 +118,23 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         }
         (List(list_from), LargeList(list_to)) if list_from == list_to => true,
         (LargeList(list_from), List(list_to)) if list_from == list_to => true,
+        (List(list_from) | LargeList(list_from), FixedSizeList(list_to, _)) => {
+            can_cast_types(&list_from.data_type, &list_to.data_type)
+        }
+        (FixedSizeList(list_from, _), List(list_to) | LargeList(list_to)) => {
+            can_cast_types(&list_from.data_type, &list_to.data_type)
+        }
+        (FixedSizeList(list_from, from_size), FixedSizeList(list_to, to_size)) => {
+            from_size == to_size && can_cast_types(&list_from.data_type, &list_to.data_type)
+        }
         (_, List(list_to)) => can_cast_types(from_type, &list_to.data_type),
         (Dictionary(_, from_value_type, _), Dictionary(_, to_value_type, _)) => {
             can_cast_types(from_value_type, to_value_type)
         }
         (Dictionary(_, value_type, _), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type, _)) => can_cast_types(from_type, value_type),
+        (Float16, _) => can_cast_types(&Float32, to_type),
+        (_, Float16) => can_cast_types(from_type, &Float32),
 
         (_, Boolean) => is_numeric(from_type),
         (Boolean, _) => {
@@ -115,6 +145,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
                 || to_type == &LargeBinary
         }
 
+        (Utf8 | LargeUtf8, Decimal256(_, _)) => true,
         (Utf8, Date32) => true,
         (Utf8, Date64) => true,
         (Utf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
@@ -127,8 +158,9 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (LargeUtf8, _) => is_numeric(to_type),
         (Timestamp(_, _), Utf8) => true,
         (Timestamp(_, _), LargeUtf8) => true,
-        (_, Utf8) => is_numeric(from_type) || from_type == &Binary,
-        (_, LargeUtf8) => is_numeric(from_type) || from_type == &Binary,
+        (Decimal256(_, _), Utf8 | LargeUtf8) => true,
+        (_, Utf8) => is_numeric(from_type) || matches!(from_type, Binary | LargeBinary),
+        (_, LargeUtf8) => is_numeric(from_type) || matches!(from_type, Binary | LargeBinary),
 
         (Binary, _) => is_numeric(to_type) || to_type == &LargeBinary,
         (LargeBinary, _) => is_numeric(to_type) || to_type == &Binary,
@@ -260,6 +292,35 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Float64
             | Decimal(_, _),
         ) => true,
+        (
+            UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Int8
+            | Int16
+            | Int32
+            | Int64
+            | Float32
+            | Float64
+            | Decimal(_, _),
+            Decimal256(_, _),
+        ) => true,
+        (
+            Decimal256(_, _),
+            UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Int8
+            | Int16
+            | Int32
+            | Int64
+            | Float32
+            | Float64
+            | Decimal(_, _)
+            | Decimal256(_, _),
+        ) => true,
         // end numeric casts
 
         // temporal casts
@@ -290,6 +351,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Timestamp(_, _), Date64) => true,
         (Int64, Duration(_)) => true,
         (Duration(_), Int64) => true,
+        (Duration(_), Duration(_)) => true,
+        (Interval(IntervalUnit::DayTime), Duration(_)) => true,
         (Interval(_), Interval(IntervalUnit::MonthDayNano)) => true,
         (_, _) => false,
     }
@@ -348,6 +411,88 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
     )
 }
 
+fn cast_list_to_fixed_size_list<O: Offset>(
+    array: &ListArray<O>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<FixedSizeListArray> {
+    let (field, size) = FixedSizeListArray::get_child_and_size(to_type);
+    let offsets = array.offsets();
+    let values = array.values();
+
+    let is_sized = offsets
+        .windows(2)
+        .all(|window| (window[1] - window[0]).to_usize() == size);
+    let new_values = if is_sized {
+        values.slice(offsets[0].to_usize(), array.len() * size)
+    } else {
+        let mut indices = Vec::with_capacity(array.len() * size);
+        for (row, window) in offsets.windows(2).enumerate() {
+            let (start, end) = (window[0].to_usize(), window[1].to_usize());
+            if !array.is_valid(row) {
+                indices.resize(indices.len() + size, None);
+                continue;
+            }
+            if end - start != size && !options.resize_lists {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Casting to a FixedSizeList of size {} requires lists of that size, but the list at row {} has {} items",
+                    size,
+                    row,
+                    end - start
+                )));
+            }
+            indices.extend((start..end.min(start + size)).map(|index| Some(index as u64)));
+            indices.resize(indices.len() + size.saturating_sub(end - start), None);
+        }
+        take(values.as_ref(), &UInt64Array::from(indices))?
+    };
+    let new_values = cast(new_values.as_ref(), field.data_type(), options)?;
+
+    Ok(FixedSizeListArray::new(
+        to_type.clone(),
+        new_values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+fn cast_fixed_size_list_to_list<O: Offset>(
+    array: &FixedSizeListArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<O>> {
+    let (_, size) = FixedSizeListArray::get_child_and_size(array.data_type());
+    let offsets = (0..=array.len())
+        .map(|row| O::from_usize(row * size).ok_or(ArrowError::Overflow))
+        .collect::<Result<Vec<_>>>()?;
+    let new_values = cast(
+        array.values().as_ref(),
+        ListArray::<O>::get_child_type(to_type),
+        options,
+    )?;
+
+    Ok(ListArray::<O>::new(
+        to_type.clone(),
+        offsets.into(),
+        new_values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+fn cast_fixed_size_list(
+    array: &FixedSizeListArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<FixedSizeListArray> {
+    let (field, _) = FixedSizeListArray::get_child_and_size(to_type);
+    let new_values = cast(array.values().as_ref(), field.data_type(), options)?;
+
+    Ok(FixedSizeListArray::new(
+        to_type.clone(),
+        new_values.into(),
+        array.validity().cloned(),
+    ))
+}
+
 /// Cast `array` to the provided data type and return a new [`Array`] with
 /// type `to_type`, if possible.
 ///
@@ -356,19 +501,64 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
 /// * Boolean to Utf8: `true` => '1', `false` => `0`
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
+/// * Binary to Utf8: values that are not valid utf8 return null, unless `options.lossy_utf8`
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
 /// * List to List: the underlying data type is cast
+/// * List to FixedSizeList: every (non-null) list must have the FixedSizeList's size, unless
+///   `options.resize_lists` pads or truncates them
+/// * FixedSizeList to List: the underlying data type is cast
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
+/// * Float16 to and from any type castable from and to Float32: the cast goes through Float32
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Duration to Duration: overflowing cast will be None, precision lost when going to a coarser unit
+/// * Interval(DayTime) to Duration: lossy or overflowing cast will be None
+/// * Decimal256 to and from numeric, Decimal and Utf8: overflowing casts, and strings that can't
+///   be parsed or that don't fit in the precision, will be None. Extra fractional digits are
+///   truncated
 /// Unsupported Casts
 /// * To or from `StructArray`
 /// * List to primitive
 /// * Utf8 to boolean
-/// * Interval and duration
+/// * Interval and duration, other than the above
+///
+/// See [`CastOptions`] for how values that cannot be cast are handled.
 pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Result<Box<dyn Array>> {
+    let result = cast_array(array, to_type, options)?;
+    if options.strict {
+        check_strict(array, result.as_ref())?;
+    }
+    Ok(result)
+}
+
+/// Errors iff a valid value of `array` is null in its `result`, i.e. it could not be cast.
+fn check_strict(array: &dyn Array, result: &dyn Array) -> Result<()> {
+    // the values of dictionaries are cast (and checked) on their own, and a valid key may
+    // point to a null value
+    if result.null_count() == 0 || matches!(array.data_type(), DataType::Dictionary(..)) {
+        return Ok(());
+    }
+    if let Some(row) = (0..array.len()).find(|row| array.is_valid(*row) && result.is_null(*row)) {
+        let mut value = String::new();
+        get_display(array, "")(&mut value, row).unwrap();
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Casting from {:?} to {:?} failed at row {}: the value {} cannot be cast",
+            array.data_type(),
+            result.data_type(),
+            row,
+            value
+        )));
+    }
+    Ok(())
+}
+
+fn cast_array(
+    array: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
     use DataType::*;
     let from_type = array.data_type();
 
@@ -413,6 +603,34 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             to_type,
         ))
         .map(|x| Box::new(x) as Box<dyn Array>),
+        (List(_), FixedSizeList(..)) => cast_list_to_fixed_size_list::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            to_type,
+            options,
+        )
+        .map(|x| Box::new(x) as Box<dyn Array>),
+        (LargeList(_), FixedSizeList(..)) => cast_list_to_fixed_size_list::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            to_type,
+            options,
+        )
+        .map(|x| Box::new(x) as Box<dyn Array>),
+        (FixedSizeList(..), List(_)) => cast_fixed_size_list_to_list::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            to_type,
+            options,
+        )
+        .map(|x| Box::new(x) as Box<dyn Array>),
+        (FixedSizeList(..), LargeList(_)) => cast_fixed_size_list_to_list::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            to_type,
+            options,
+        )
+        .map(|x| Box::new(x) as Box<dyn Array>),
+        (FixedSizeList(_, from_size), FixedSizeList(_, to_size)) if from_size == to_size => {
+            cast_fixed_size_list(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
 
         (_, List(to)) => {
             // cast primitive to list's primitive
@@ -431,6 +649,14 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (_, Dictionary(index_type, value_type, _)) => match_integer_type!(index_type, |$T| {
             cast_to_dictionary::<$T>(array, value_type, options)
         }),
+        (Float16, _) => {
+            let array = f16_to_f32(array.as_any().downcast_ref().unwrap());
+            cast(&array, to_type, options)
+        }
+        (_, Float16) => {
+            let array = cast(array, &Float32, options)?;
+            Ok(Box::new(f32_to_f16(array.as_any().downcast_ref().unwrap())))
+        }
         (_, Boolean) => match from_type {
             UInt8 => primitive_to_boolean_dyn::<u8>(array, to_type.clone()),
             UInt16 => primitive_to_boolean_dyn::<u16>(array, to_type.clone()),
@@ -479,6 +705,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i32, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i32, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i32, f64>(array, to_type, options),
+            Decimal256(p, s) => utf8_to_decimal256_dyn::<i32>(array, *p, *s),
             Date32 => utf8_to_date32_dyn::<i32>(array),
             Date64 => utf8_to_date64_dyn::<i32>(array),
             LargeUtf8 => Ok(Box::new(utf8_to_large_utf8(
@@ -504,6 +731,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i64, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i64, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i64, f64>(array, to_type, options),
+            Decimal256(p, s) => utf8_to_decimal256_dyn::<i64>(array, *p, *s),
             Date32 => utf8_to_date32_dyn::<i64>(array),
             Date64 => utf8_to_date64_dyn::<i64>(array),
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap())
@@ -529,17 +757,9 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i32>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i32>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i32>(array),
-            Binary => {
-                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-
-                // perf todo: the offsets are equal; we can speed-up this
-                let iter = array
-                    .iter()
-                    .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
-
-                let array = Utf8Array::<i32>::from_trusted_len_iter(iter);
-                Ok(Box::new(array))
-            }
+            Decimal256(_, _) => decimal256_to_utf8_dyn::<i32>(array),
+            Binary => binary_to_utf8_dyn::<i32, i32>(array, options),
+            LargeBinary => binary_to_utf8_dyn::<i64, i32>(array, options),
             Timestamp(from_unit, Some(tz)) => {
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(timestamp_to_utf8::<i32>(from, *from_unit, tz)?))
@@ -565,17 +785,9 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i64>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i64>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i64>(array),
-            Binary => {
-                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-
-                // perf todo: the offsets are equal; we can speed-up this
-                let iter = array
-                    .iter()
-                    .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
-
-                let array = Utf8Array::<i64>::from_trusted_len_iter(iter);
-                Ok(Box::new(array))
-            }
+            Decimal256(_, _) => decimal256_to_utf8_dyn::<i64>(array),
+            Binary => binary_to_utf8_dyn::<i32, i64>(array, options),
+            LargeBinary => binary_to_utf8_dyn::<i64, i64>(array, options),
             Timestamp(from_unit, Some(tz)) => {
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(timestamp_to_utf8::<i64>(from, *from_unit, tz)?))
@@ -788,6 +1000,33 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Decimal(_, _), Float32) => decimal_to_float_dyn::<f32>(array),
         (Decimal(_, _), Float64) => decimal_to_float_dyn::<f64>(array),
         (Decimal(_, _), Decimal(to_p, to_s)) => decimal_to_decimal_dyn(array, *to_p, *to_s),
+
+        (UInt8, Decimal256(p, s)) => integer_to_decimal256_dyn::<u8>(array, *p, *s),
+        (UInt16, Decimal256(p, s)) => integer_to_decimal256_dyn::<u16>(array, *p, *s),
+        (UInt32, Decimal256(p, s)) => integer_to_decimal256_dyn::<u32>(array, *p, *s),
+        (UInt64, Decimal256(p, s)) => integer_to_decimal256_dyn::<u64>(array, *p, *s),
+        (Int8, Decimal256(p, s)) => integer_to_decimal256_dyn::<i8>(array, *p, *s),
+        (Int16, Decimal256(p, s)) => integer_to_decimal256_dyn::<i16>(array, *p, *s),
+        (Int32, Decimal256(p, s)) => integer_to_decimal256_dyn::<i32>(array, *p, *s),
+        (Int64, Decimal256(p, s)) => integer_to_decimal256_dyn::<i64>(array, *p, *s),
+        (Float32, Decimal256(p, s)) => float_to_decimal256_dyn::<f32>(array, *p, *s),
+        (Float64, Decimal256(p, s)) => float_to_decimal256_dyn::<f64>(array, *p, *s),
+        (Decimal(_, _), Decimal256(to_p, to_s)) => decimal_to_decimal256_dyn(array, *to_p, *to_s),
+
+        (Decimal256(_, _), UInt8) => decimal256_to_integer_dyn::<u8>(array),
+        (Decimal256(_, _), UInt16) => decimal256_to_integer_dyn::<u16>(array),
+        (Decimal256(_, _), UInt32) => decimal256_to_integer_dyn::<u32>(array),
+        (Decimal256(_, _), UInt64) => decimal256_to_integer_dyn::<u64>(array),
+        (Decimal256(_, _), Int8) => decimal256_to_integer_dyn::<i8>(array),
+        (Decimal256(_, _), Int16) => decimal256_to_integer_dyn::<i16>(array),
+        (Decimal256(_, _), Int32) => decimal256_to_integer_dyn::<i32>(array),
+        (Decimal256(_, _), Int64) => decimal256_to_integer_dyn::<i64>(array),
+        (Decimal256(_, _), Float32) => decimal256_to_float_dyn::<f32>(array),
+        (Decimal256(_, _), Float64) => decimal256_to_float_dyn::<f64>(array),
+        (Decimal256(_, _), Decimal(to_p, to_s)) => decimal256_to_decimal_dyn(array, *to_p, *to_s),
+        (Decimal256(_, _), Decimal256(to_p, to_s)) => {
+            decimal256_to_decimal256_dyn(array, *to_p, *to_s)
+        }
         // end numeric casts
 
         // temporal casts
@@ -842,6 +1081,12 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
 
         (Int64, Duration(_)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Duration(_), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
+        (Duration(from_unit), Duration(to_unit)) => {
+            primitive_dyn!(array, duration_to_duration, *from_unit, *to_unit)
+        }
+        (Interval(IntervalUnit::DayTime), Duration(to_unit)) => {
+            primitive_dyn!(array, days_ms_to_duration, *to_unit)
+        }
 
         (Interval(IntervalUnit::DayTime), Interval(IntervalUnit::MonthDayNano)) => {
             primitive_dyn!(array, days_ms_to_months_days_ns)
@@ -866,6 +1111,17 @@ fn cast_to_dictionary<K: DictionaryKey>(
     dict_value_type: &DataType,
     options: CastOptions,
 ) -> Result<Box<dyn Array>> {
+    // strings are packed without casting them to the values' type first
+    match (array.data_type(), dict_value_type) {
+        (DataType::Utf8, DataType::LargeUtf8) => {
+            return utf8_to_dictionary_dyn::<i32, i64, K>(array)
+        }
+        (DataType::LargeUtf8, DataType::Utf8) => {
+            return utf8_to_dictionary_dyn::<i64, i32, K>(array)
+        }
+        _ => {}
+    }
+
     let array = cast(array, dict_value_type, options)?;
     let array = array.as_ref();
     match *dict_value_type {
@@ -877,8 +1133,8 @@ fn cast_to_dictionary<K: DictionaryKey>(
         DataType::UInt16 => primitive_to_dictionary_dyn::<u16, K>(array),
         DataType::UInt32 => primitive_to_dictionary_dyn::<u32, K>(array),
         DataType::UInt64 => primitive_to_dictionary_dyn::<u64, K>(array),
-        DataType::Utf8 => utf8_to_dictionary_dyn::<i32, K>(array),
-        DataType::LargeUtf8 => utf8_to_dictionary_dyn::<i64, K>(array),
+        DataType::Utf8 => utf8_to_dictionary_dyn::<i32, i32, K>(array),
+        DataType::LargeUtf8 => utf8_to_dictionary_dyn::<i64, i64, K>(array),
         DataType::Binary => binary_to_dictionary_dyn::<i32, K>(array),
         DataType::LargeBinary => binary_to_dictionary_dyn::<i64, K>(array),
         _ => Err(ArrowError::NotYetImplemented(format!(
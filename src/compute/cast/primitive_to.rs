@@ -4,16 +4,17 @@ use num_traits::{AsPrimitive, Float, ToPrimitive};
 
 use crate::datatypes::IntervalUnit;
 use crate::error::Result;
-use crate::types::{days_ms, months_days_ns};
+use crate::types::{days_ms, f16, i256, months_days_ns};
 use crate::{
     array::*,
     bitmap::Bitmap,
-    compute::arity::unary,
+    compute::arity::{unary, unary_checked},
     datatypes::{DataType, TimeUnit},
     temporal_conversions::*,
     types::NativeType,
 };
 
+use super::decimal_to::{decimal256_in_precision, decimal256_pow10};
 use super::CastOptions;
 
 /// Returns a [`BinaryArray`] where every element is the binary representation of the number.
@@ -131,12 +132,16 @@ pub(super) fn primitive_to_primitive_dyn<I, O>(
     options: CastOptions,
 ) -> Result<Box<dyn Array>>
 where
-    I: NativeType + num_traits::NumCast + num_traits::AsPrimitive<O>,
-    O: NativeType + num_traits::NumCast,
+    I: NativeType + num_traits::NumCast + num_traits::AsPrimitive<O> + PartialOrd,
+    O: NativeType + num_traits::NumCast + num_traits::Bounded,
 {
     let from = from.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
     if options.wrapped {
         Ok(Box::new(primitive_as_primitive::<I, O>(from, to_type)))
+    } else if options.saturating {
+        Ok(Box::new(primitive_to_primitive_saturating::<I, O>(
+            from, to_type,
+        )))
     } else {
         Ok(Box::new(primitive_to_primitive::<I, O>(from, to_type)))
     }
@@ -157,6 +162,30 @@ where
     PrimitiveArray::<O>::from_trusted_len_iter(iter).to(to_type.clone())
 }
 
+/// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of another physical type via numeric conversion,
+/// saturating to the minimum or maximum value of `O` on overflow. `NaN`s are cast to `None`.
+pub fn primitive_to_primitive_saturating<I, O>(
+    from: &PrimitiveArray<I>,
+    to_type: &DataType,
+) -> PrimitiveArray<O>
+where
+    I: NativeType + num_traits::NumCast + PartialOrd,
+    O: NativeType + num_traits::NumCast + num_traits::Bounded,
+{
+    let iter = from.iter().map(|v| {
+        v.and_then(|x| {
+            num_traits::cast::cast::<I, O>(*x).or_else(|| {
+                // `I::default()` is zero, and `NaN`s are not comparable to it
+                x.partial_cmp(&I::default()).map(|ordering| match ordering {
+                    std::cmp::Ordering::Less => O::min_value(),
+                    _ => O::max_value(),
+                })
+            })
+        })
+    });
+    PrimitiveArray::<O>::from_trusted_len_iter(iter).to(to_type.clone())
+}
+
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
 pub fn integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
     from: &PrimitiveArray<T>,
@@ -244,6 +273,70 @@ where
     Ok(Box::new(float_to_decimal::<T>(from, precision, scale)))
 }
 
+/// Returns a [`PrimitiveArray<i256>`] with the casted values. Values are `None` on overflow
+pub fn integer_to_decimal256<T: NativeType + AsPrimitive<i128>>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256> {
+    let multiplier = decimal256_pow10(to_scale);
+
+    let values = from.iter().map(|x| {
+        x.and_then(|x| i256::from_i128(x.as_()).checked_mul(multiplier?))
+            .and_then(|x| decimal256_in_precision(x, to_precision))
+    });
+
+    PrimitiveArray::<i256>::from_trusted_len_iter(values)
+        .to(DataType::Decimal256(to_precision, to_scale))
+}
+
+pub(super) fn integer_to_decimal256_dyn<T>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType + AsPrimitive<i128>,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(integer_to_decimal256::<T>(from, precision, scale)))
+}
+
+/// Returns a [`PrimitiveArray<i256>`] with the casted values, truncated towards zero.
+/// Values are `None` on overflow or when they are not finite
+pub fn float_to_decimal256<T>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256>
+where
+    T: NativeType + Float + ToPrimitive,
+{
+    // 1.2 => 12
+    let multiplier = 10_f64.powi(to_scale as i32);
+
+    let values = from.iter().map(|x| {
+        x.and_then(|x| x.to_f64())
+            .and_then(|x| i256::from_f64(x * multiplier))
+            .and_then(|x| decimal256_in_precision(x, to_precision))
+    });
+
+    PrimitiveArray::<i256>::from_trusted_len_iter(values)
+        .to(DataType::Decimal256(to_precision, to_scale))
+}
+
+pub(super) fn float_to_decimal256_dyn<T>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType + Float + ToPrimitive,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(float_to_decimal256::<T>(from, precision, scale)))
+}
+
 /// Cast [`PrimitiveArray`] as a [`PrimitiveArray`]
 /// Same as `number as to_number_type` in rust
 pub fn primitive_as_primitive<I, O>(
@@ -257,6 +350,17 @@ where
     unary(from, num_traits::AsPrimitive::<O>::as_, to_type.clone())
 }
 
+/// Casts a [`PrimitiveArray`] of [`f16`] to [`f32`]. This is lossless.
+pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
+    unary(from, |x| x.to_f32(), DataType::Float32)
+}
+
+/// Casts a [`PrimitiveArray`] of [`f32`] to [`f16`], rounding to the nearest value.
+/// Values whose magnitude is too large to be represented become infinity.
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f32, DataType::Float16)
+}
+
 /// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of the same physical type.
 /// This is O(1).
 pub fn primitive_to_same_primitive<T>(
@@ -581,3 +685,45 @@ pub fn months_to_months_days_ns(from: &PrimitiveArray<i32>) -> PrimitiveArray<mo
         DataType::Interval(IntervalUnit::MonthDayNano),
     )
 }
+
+/// Conversion of durations between units. When converting to a finer unit, values that overflow
+/// are `None`; when converting to a coarser unit, values are truncated.
+pub fn duration_to_duration(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+) -> PrimitiveArray<i64> {
+    let from_size = time_unit_multiple(from_unit);
+    let to_size = time_unit_multiple(to_unit);
+    let to_type = DataType::Duration(to_unit);
+    if from_size >= to_size {
+        unary(from, |x| x / (from_size / to_size), to_type)
+    } else {
+        unary_checked(from, |x| x.checked_mul(to_size / from_size), to_type)
+    }
+}
+
+/// Casts [`days_ms`]s to durations of `to_unit`, taking days as 24 hours. Values are `None`
+/// on overflow and when the conversion is lossy, i.e. when casting to seconds an interval that
+/// is not a whole number of seconds.
+pub fn days_ms_to_duration(
+    from: &PrimitiveArray<days_ms>,
+    to_unit: TimeUnit,
+) -> PrimitiveArray<i64> {
+    let to_size = time_unit_multiple(to_unit);
+    let ms_size = time_unit_multiple(TimeUnit::Millisecond);
+    unary_checked(
+        from,
+        |x| {
+            let ms = (x.days() as i64 * MILLISECONDS_IN_DAY).checked_add(x.milliseconds() as i64)?;
+            if to_size >= ms_size {
+                ms.checked_mul(to_size / ms_size)
+            } else if ms % (ms_size / to_size) == 0 {
+                Some(ms / (ms_size / to_size))
+            } else {
+                None
+            }
+        },
+        DataType::Duration(to_unit),
+    )
+}
@@ -1,5 +1,8 @@
+use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::sync::Arc;
 
+use ahash::RandomState;
 use chrono::Datelike;
 
 use crate::{
@@ -10,9 +13,10 @@ use crate::{
         utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
         utf8_to_timestamp_ns as utf8_to_timestamp_ns_, EPOCH_DAYS_FROM_CE,
     },
-    types::NativeType,
+    types::{i256, NativeType},
 };
 
+use super::decimal_to::decimal256_in_precision;
 use super::CastOptions;
 
 const RFC3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
@@ -60,6 +64,55 @@ where
     }
 }
 
+/// Parses an optionally signed decimal number such as `-12.345` into its digits of scale `scale`,
+/// truncating any further fractional digits.
+fn parse_decimal256(value: &str, scale: usize) -> Option<i256> {
+    let (sign, digits) = match value.as_bytes().first() {
+        Some(b'-') => ("-", &value[1..]),
+        Some(b'+') => ("", &value[1..]),
+        _ => ("", value),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if integer.is_empty() && fraction.is_empty()
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|x| x.is_ascii_digit())
+    {
+        return None;
+    }
+    let fraction = &fraction[..fraction.len().min(scale)];
+    let padding = "0".repeat(scale - fraction.len());
+    format!("{}{}{}{}", sign, integer, fraction, padding)
+        .parse()
+        .ok()
+}
+
+/// Casts a [`Utf8Array`] to a [`PrimitiveArray<i256>`] of [`DataType::Decimal256`], making any
+/// value that cannot be parsed or that does not fit in `precision` a Null. Fractional digits
+/// beyond `scale` are truncated.
+pub fn utf8_to_decimal256<O: Offset>(
+    from: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+) -> PrimitiveArray<i256> {
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| parse_decimal256(x, scale))
+            .and_then(|x| decimal256_in_precision(x, precision))
+    });
+
+    PrimitiveArray::<i256>::from_trusted_len_iter(iter).to(DataType::Decimal256(precision, scale))
+}
+
+pub(super) fn utf8_to_decimal256_dyn<O: Offset>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(utf8_to_decimal256::<O>(from, precision, scale)))
+}
+
 /// Casts a [`Utf8Array`] to a Date32 primitive, making any uncastable value a Null.
 pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
     let iter = from.iter().map(|x| {
@@ -94,11 +147,39 @@ pub(super) fn utf8_to_date64_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn
     Ok(Box::new(utf8_to_date64::<O>(from)))
 }
 
-pub(super) fn utf8_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
+pub(super) fn utf8_to_dictionary_dyn<I: Offset, O: Offset, K: DictionaryKey>(
     from: &dyn Array,
 ) -> Result<Box<dyn Array>> {
     let values = from.as_any().downcast_ref().unwrap();
-    utf8_to_dictionary::<O, K>(values).map(|x| Box::new(x) as Box<dyn Array>)
+    pack_utf8::<I, O, K>(values).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
+/// Packs a [`Utf8Array`] into a [`DictionaryArray`] whose values are a [`Utf8Array<O>`] in the
+/// order of their first occurrence, in a single pass.
+fn pack_utf8<I: Offset, O: Offset, K: DictionaryKey>(
+    from: &Utf8Array<I>,
+) -> Result<DictionaryArray<K>> {
+    let mut map = HashMap::<&str, K, RandomState>::default();
+    let mut values = MutableUtf8Array::<O>::new();
+    let keys = from
+        .iter()
+        .map(|x| match x {
+            Some(x) => match map.get(x) {
+                Some(key) => Ok(*key),
+                None => {
+                    let key = K::from_usize(map.len()).ok_or(ArrowError::Overflow)?;
+                    map.insert(x, key);
+                    values.try_push(Some(x))?;
+                    Ok(key)
+                }
+            },
+            None => Ok(K::default()),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let keys = PrimitiveArray::<K>::from_vec(keys).with_validity(from.validity().cloned());
+    let values: Utf8Array<O> = values.into();
+    Ok(DictionaryArray::<K>::from_data(keys, Arc::new(values)))
 }
 
 /// Cast [`Utf8Array`] to [`DictionaryArray`], also known as packing.
@@ -108,10 +189,7 @@ pub(super) fn utf8_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
 pub fn utf8_to_dictionary<O: Offset, K: DictionaryKey>(
     from: &Utf8Array<O>,
 ) -> Result<DictionaryArray<K>> {
-    let mut array = MutableDictionaryArray::<K, MutableUtf8Array<O>>::new();
-    array.try_extend(from.iter())?;
-
-    Ok(array.into())
+    pack_utf8::<O, O, K>(from)
 }
 
 pub(super) fn utf8_to_naive_timestamp_ns_dyn<O: Offset>(
@@ -0,0 +1,67 @@
+//! Contains the operator [`clamp`], to restrict the values of a [`PrimitiveArray`] to a range.
+use crate::array::PrimitiveArray;
+use crate::compute::arity::unary;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// Returns `array` with each value smaller than `min` replaced by `min` and each value larger
+/// than `max` replaced by `max`, in a single pass.
+///
+/// A bound of `None` is unbounded. The result has the same [`DataType`](crate::datatypes::DataType)
+/// as `array`, so this can be used with temporal arrays, e.g. a `Timestamp` array with `i64`
+/// bounds. Nulls and `NaN`s are preserved.
+/// # Errors
+/// Errors iff `min` is larger than `max` or one of them is `NaN`.
+/// # Example
+/// ```
+/// use arrow2::array::Float32Array;
+/// use arrow2::compute::clamp::clamp;
+///
+/// let array = Float32Array::from(&[Some(-3.0), None, Some(0.5), Some(f32::INFINITY)]);
+/// let result = clamp(&array, Some(-1.0), Some(1.0)).unwrap();
+/// assert_eq!(result, Float32Array::from(&[Some(-1.0), None, Some(0.5), Some(1.0)]));
+/// ```
+pub fn clamp<T>(
+    array: &PrimitiveArray<T>,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    #[allow(clippy::eq_op)]
+    let is_nan = |x: &T| x != x;
+    if min.as_ref().map(is_nan).unwrap_or(false) || max.as_ref().map(is_nan).unwrap_or(false) {
+        return Err(ArrowError::InvalidArgumentError(
+            "The bounds of clamp cannot be NaN".to_string(),
+        ));
+    }
+
+    let data_type = array.data_type().clone();
+    Ok(match (min, max) {
+        (Some(min), Some(max)) => {
+            if min > max {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The lower bound of clamp ({:?}) must not be larger than its upper bound ({:?})",
+                    min, max
+                )));
+            }
+            unary(
+                array,
+                |x| {
+                    if x < min {
+                        min
+                    } else if x > max {
+                        max
+                    } else {
+                        x
+                    }
+                },
+                data_type,
+            )
+        }
+        (Some(min), None) => unary(array, |x| if x < min { min } else { x }, data_type),
+        (None, Some(max)) => unary(array, |x| if x > max { max } else { x }, data_type),
+        (None, None) => array.clone(),
+    })
+}
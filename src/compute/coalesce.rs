@@ -0,0 +1,70 @@
+//! Contains the operator [`coalesce`].
+use crate::array::{growable::make_growable, Array};
+use crate::error::{ArrowError, Result};
+
+/// Returns, for each row, the value of the first of `arrays` that is not null on that row,
+/// or null if all of them are null, i.e. SQL's `COALESCE`.
+///
+/// Consecutive rows taken from the same array are copied at once, so that no intermediate
+/// array is allocated.
+/// # Errors
+/// Errors iff `arrays` is empty or its arrays have different datatypes or lengths.
+/// # Example
+/// ```rust
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::coalesce::coalesce;
+///
+/// let a = Int32Array::from(&[Some(1), None, None, None]);
+/// let b = Int32Array::from(&[Some(2), Some(3), None, None]);
+/// let c = Int32Array::from(&[Some(4), Some(5), Some(6), None]);
+/// let result = coalesce(&[&a, &b, &c]).unwrap();
+///
+/// let expected = Int32Array::from(&[Some(1), Some(3), Some(6), None]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    if arrays.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Coalesce requires at least one array".to_string(),
+        ));
+    }
+    let data_type = arrays[0].data_type();
+    let length = arrays[0].len();
+    if arrays.iter().any(|array| array.data_type() != data_type) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Coalesce requires all arrays to have the same datatype".to_string(),
+        ));
+    }
+    if arrays.iter().any(|array| array.len() != length) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Coalesce requires all arrays to have the same length".to_string(),
+        ));
+    }
+
+    let mut growable = make_growable(arrays, true, length);
+
+    // the array each row is taken from; when all are null, the last one (which is null)
+    let source = |row: usize| {
+        arrays
+            .iter()
+            .position(|array| array.is_valid(row))
+            .unwrap_or(arrays.len() - 1)
+    };
+
+    let mut start = 0;
+    let mut current = 0;
+    for row in 0..length {
+        let index = source(row);
+        if row == 0 {
+            current = index;
+        } else if index != current {
+            growable.extend(current, start, row - start);
+            start = row;
+            current = index;
+        }
+    }
+    if length > 0 {
+        growable.extend(current, start, length - start);
+    }
+    Ok(growable.as_box())
+}
@@ -151,6 +151,26 @@ pub fn gt_eq_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BooleanArray
     compare_op_scalar(lhs, rhs, |a, b| a >= b)
 }
 
+/// Returns whether each value of `lhs` starts with the corresponding value of `rhs`.
+pub fn starts_with<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a.starts_with(b))
+}
+
+/// Returns whether each value of `lhs` starts with `rhs`.
+pub fn starts_with_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a.starts_with(b))
+}
+
+/// Returns whether each value of `lhs` ends with the corresponding value of `rhs`.
+pub fn ends_with<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a.ends_with(b))
+}
+
+/// Returns whether each value of `lhs` ends with `rhs`.
+pub fn ends_with_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a.ends_with(b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,4 +257,44 @@ mod tests {
             vec![false, true, true, true],
         )
     }
+
+    #[test]
+    fn test_starts_with() {
+        test_generic::<i32, _>(
+            vec![b"arrow", b"arrow", b"", b"ar"],
+            vec![b"ar", b"", b"", b"arrow"],
+            starts_with,
+            vec![true, true, true, false],
+        )
+    }
+
+    #[test]
+    fn test_starts_with_scalar() {
+        test_generic_scalar::<i64, _>(
+            vec![b"arrow", b"parquet", b"ar", b"a"],
+            b"ar",
+            starts_with_scalar,
+            vec![true, false, true, false],
+        )
+    }
+
+    #[test]
+    fn test_ends_with() {
+        test_generic::<i32, _>(
+            vec![b"arrow", b"arrow", b"", b"ow"],
+            vec![b"ow", b"", b"", b"arrow"],
+            ends_with,
+            vec![true, true, true, false],
+        )
+    }
+
+    #[test]
+    fn test_ends_with_scalar() {
+        test_generic_scalar::<i64, _>(
+            vec![b"arrow", b"parquet", b"ow", b"w"],
+            b"ow",
+            ends_with_scalar,
+            vec![true, false, true, false],
+        )
+    }
 }
@@ -0,0 +1,240 @@
+//! Comparison functions for [`DictionaryArray`]
+//!
+//! The values of the dictionaries are not materialized: the values of both dictionaries are
+//! ranked once (a single one when both dictionaries have equal values) and the arrays are then
+//! compared by the ranks of their keys. Values are compared like the kernels of their type do
+//! (e.g. [`super::primitive`]), where `NaN` is unordered with any value, including itself, and
+//! `-0.0` is equal to `0.0`.
+use std::cmp::Ordering;
+
+use crate::{
+    array::{
+        ord::build_compare, Array, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray,
+    },
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::{DataType, PhysicalType, PrimitiveType},
+    types::{f16, NativeType},
+};
+
+use super::{finish_eq_validities, finish_neq_validities};
+
+type PartialComparator = Box<dyn Fn(usize, usize) -> Option<Ordering> + Send + Sync>;
+
+fn partial_compare_primitive<T: NativeType + PartialOrd>(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+) -> PartialComparator {
+    let lhs = lhs
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .clone();
+    let rhs = rhs
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| lhs.value(i).partial_cmp(&rhs.value(j)))
+}
+
+/// Returns a comparator of the values of `lhs` and `rhs` with the semantics of the comparison
+/// kernels, i.e. the partial order of floats instead of the total order of [`build_compare`].
+fn build_partial_compare(lhs: &dyn Array, rhs: &dyn Array) -> PartialComparator {
+    match lhs.data_type().to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            partial_compare_primitive::<f16>(lhs, rhs)
+        }
+        PhysicalType::Primitive(PrimitiveType::Float32) => {
+            partial_compare_primitive::<f32>(lhs, rhs)
+        }
+        PhysicalType::Primitive(PrimitiveType::Float64) => {
+            partial_compare_primitive::<f64>(lhs, rhs)
+        }
+        _ => {
+            let compare = build_compare(lhs, rhs).unwrap();
+            Box::new(move |i, j| Some(compare(i, j)))
+        }
+    }
+}
+
+/// Returns the dense rank of each `(array, index)` of `items` according to `compare`, in one
+/// vector per array of `lengths`. Slots not in `items` have no rank.
+fn dense_ranks<F>(
+    mut items: Vec<(usize, usize)>,
+    compare: F,
+    lengths: &[usize],
+) -> Vec<Vec<Option<u32>>>
+where
+    F: Fn(&(usize, usize), &(usize, usize)) -> Ordering,
+{
+    let mut ranks = lengths
+        .iter()
+        .map(|length| vec![None; *length])
+        .collect::<Vec<_>>();
+    items.sort_unstable_by(&compare);
+    let mut rank = 0u32;
+    for (position, item) in items.iter().enumerate() {
+        if position > 0 && compare(&items[position - 1], item) != Ordering::Equal {
+            rank += 1;
+        }
+        ranks[item.0][item.1] = Some(rank);
+    }
+    ranks
+}
+
+/// Returns the ranks of the values of `lhs` and `rhs`, such that comparing two ranks is the same
+/// as comparing the two values. Null values and values unordered with themselves (i.e. `NaN`)
+/// have no rank.
+fn ranks(lhs: &dyn Array, rhs: &dyn Array) -> (Vec<Option<u32>>, Vec<Option<u32>>) {
+    let ordered = |array: &dyn Array, side: usize, compare: &PartialComparator| {
+        (0..array.len())
+            .filter(|index| array.is_valid(*index) && compare(*index, *index).is_some())
+            .map(|index| (side, index))
+            .collect::<Vec<_>>()
+    };
+
+    if lhs == rhs {
+        let compare = build_partial_compare(lhs, lhs);
+        let mut ranks = dense_ranks(
+            ordered(lhs, 0, &compare),
+            |a, b| compare(a.1, b.1).unwrap(),
+            &[lhs.len()],
+        );
+        let ranks = ranks.pop().unwrap();
+        return (ranks.clone(), ranks);
+    }
+
+    let arrays = [lhs, rhs];
+    let comparators = [
+        [
+            build_partial_compare(lhs, lhs),
+            build_partial_compare(lhs, rhs),
+        ],
+        [
+            build_partial_compare(rhs, lhs),
+            build_partial_compare(rhs, rhs),
+        ],
+    ];
+    let mut items = ordered(lhs, 0, &comparators[0][0]);
+    items.extend(ordered(rhs, 1, &comparators[1][1]));
+    let mut ranks = dense_ranks(
+        items,
+        |a, b| comparators[a.0][b.0](a.1, b.1).unwrap(),
+        &[arrays[0].len(), arrays[1].len()],
+    );
+    let rhs = ranks.pop().unwrap();
+    let lhs = ranks.pop().unwrap();
+    (lhs, rhs)
+}
+
+/// Evaluates `op` on the ordering of the values of each slot, `None` when they are unordered.
+/// Returns the result, where null slots are `false`, and the validity of each side, where a slot
+/// is null iff its key or the value it points to is null.
+fn compare_ranks<K, F>(
+    lhs: &DictionaryArray<K>,
+    rhs: &DictionaryArray<K>,
+    op: F,
+) -> (Bitmap, Bitmap, Bitmap)
+where
+    K: DictionaryKey,
+    F: Fn(Option<Ordering>) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let (lhs_ranks, rhs_ranks) = ranks(lhs.values().as_ref(), rhs.values().as_ref());
+
+    // `None` if the slot is null, `Some(None)` if its value is unordered
+    let rank = |array: &DictionaryArray<K>, ranks: &[Option<u32>], index: usize| {
+        if array.keys().is_valid(index) {
+            let key = array.keys().value(index).to_usize().unwrap();
+            array.values().is_valid(key).then(|| ranks[key])
+        } else {
+            None
+        }
+    };
+
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    let mut lhs_validity = MutableBitmap::with_capacity(lhs.len());
+    let mut rhs_validity = MutableBitmap::with_capacity(lhs.len());
+    for index in 0..lhs.len() {
+        let l = rank(lhs, &lhs_ranks, index);
+        let r = rank(rhs, &rhs_ranks, index);
+        lhs_validity.push(l.is_some());
+        rhs_validity.push(r.is_some());
+        values.push(match (l, r) {
+            (Some(l), Some(r)) => op(l.zip(r).map(|(l, r)| l.cmp(&r))),
+            _ => false,
+        });
+    }
+    (values.into(), lhs_validity.into(), rhs_validity.into())
+}
+
+fn compare_op<K, F>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>, op: F) -> BooleanArray
+where
+    K: DictionaryKey,
+    F: Fn(Option<Ordering>) -> bool,
+{
+    let (values, lhs_validity, rhs_validity) = compare_ranks(lhs, rhs, op);
+    BooleanArray::new(
+        DataType::Boolean,
+        values,
+        Some(&lhs_validity & &rhs_validity),
+    )
+}
+
+/// Perform `lhs == rhs` operation on two [`DictionaryArray`]s.
+pub fn eq<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |x| x == Some(Ordering::Equal))
+}
+
+/// Perform `lhs == rhs` operation on two [`DictionaryArray`]s and include validities in
+/// comparison.
+pub fn eq_and_validity<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &DictionaryArray<K>,
+) -> BooleanArray {
+    let (values, lhs_validity, rhs_validity) =
+        compare_ranks(lhs, rhs, |x| x == Some(Ordering::Equal));
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    finish_eq_validities(out, Some(lhs_validity), Some(rhs_validity))
+}
+
+/// Perform `lhs != rhs` operation on two [`DictionaryArray`]s.
+pub fn neq<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |x| x != Some(Ordering::Equal))
+}
+
+/// Perform `lhs != rhs` operation on two [`DictionaryArray`]s and include validities in
+/// comparison.
+pub fn neq_and_validity<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &DictionaryArray<K>,
+) -> BooleanArray {
+    let (values, lhs_validity, rhs_validity) =
+        compare_ranks(lhs, rhs, |x| x != Some(Ordering::Equal));
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    finish_neq_validities(out, Some(lhs_validity), Some(rhs_validity))
+}
+
+/// Perform `lhs < rhs` operation on two [`DictionaryArray`]s.
+pub fn lt<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |x| x == Some(Ordering::Less))
+}
+
+/// Perform `lhs <= rhs` operation on two [`DictionaryArray`]s.
+pub fn lt_eq<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |x| {
+        matches!(x, Some(Ordering::Less | Ordering::Equal))
+    })
+}
+
+/// Perform `lhs > rhs` operation on two [`DictionaryArray`]s.
+pub fn gt<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |x| x == Some(Ordering::Greater))
+}
+
+/// Perform `lhs >= rhs` operation on two [`DictionaryArray`]s.
+pub fn gt_eq<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |x| {
+        matches!(x, Some(Ordering::Greater | Ordering::Equal))
+    })
+}
@@ -10,6 +10,16 @@
 //! The statically typed are available under each module of this module (e.g. [`primitive::eq`], [`primitive::lt_scalar`])
 //! The dynamically typed are available in this module (e.g. [`eq`] or [`lt_scalar`]).
 //!
+//! Floats are compared following IEEE 754 semantics (e.g. `NaN != NaN`). The functions
+//! prefixed with `total_` (e.g. [`total_eq`], [`total_lt_scalar`]) instead compare floats in the
+//! IEEE 754 total order used by [`sort`](crate::compute::sort), see [`total`].
+//!
+//! `Interval(MonthDayNano)` values are ordered by their months, then by their days and then by
+//! their nanoseconds (see [`months_days_ns`](crate::types::months_days_ns)).
+//!
+//! Prefix and suffix matching of strings and binaries is only statically typed, e.g.
+//! [`utf8::starts_with`] or [`binary::ends_with_scalar`].
+//!
 //! # Examples
 //!
 //! Compare two [`PrimitiveArray`]s:
@@ -47,10 +57,13 @@
 use crate::array::*;
 use crate::datatypes::{DataType, IntervalUnit};
 use crate::scalar::*;
+use crate::types::f16;
 
 pub mod binary;
 pub mod boolean;
+pub mod dictionary;
 pub mod primitive;
+pub mod total;
 pub mod utf8;
 
 mod simd;
@@ -69,18 +82,21 @@ macro_rules! match_eq_ord {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
+    use crate::types::{f16, i256, months_days_ns};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => todo!(),
-        MonthDayNano => todo!(),
+        MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -91,19 +107,21 @@ macro_rules! match_eq {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::{days_ms, months_days_ns};
+    use crate::types::{days_ms, f16, i256, months_days_ns};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -150,6 +168,13 @@ macro_rules! compare {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 binary::$op::<i64>(lhs, rhs)
             }
+            Dictionary(key_type) => {
+                match_integer_type!(key_type, |$T| {
+                    let lhs = lhs.as_any().downcast_ref().unwrap();
+                    let rhs = rhs.as_any().downcast_ref().unwrap();
+                    dictionary::$op::<$T>(lhs, rhs)
+                })
+            }
             _ => todo!(
                 "Comparison between {:?} are not yet supported",
                 lhs.data_type()
@@ -180,6 +205,30 @@ pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
     compare!(lhs, rhs, eq_and_validity, match_eq)
 }
 
+/// `==` between two [`Array`]s where nulls are equal to nulls and different from any
+/// value, i.e. SQL's `IS NOT DISTINCT FROM`. The result has no nulls.
+///
+/// This is the same as [`eq_and_validity`].
+/// Use [`can_eq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::comparison::eq_missing;
+///
+/// let lhs = Int32Array::from(&[Some(1), None, None, Some(2)]);
+/// let rhs = Int32Array::from(&[Some(1), None, Some(3), Some(3)]);
+/// let result = eq_missing(&lhs, &rhs);
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, false, false]));
+/// ```
+pub fn eq_missing(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    eq_and_validity(lhs, rhs)
+}
+
 /// Returns whether a [`DataType`] is comparable is supported by [`eq`].
 pub fn can_eq(data_type: &DataType) -> bool {
     can_partial_eq(data_type)
@@ -207,6 +256,20 @@ pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
     compare!(lhs, rhs, neq_and_validity, match_eq)
 }
 
+/// `!=` between two [`Array`]s where nulls are equal to nulls and different from any
+/// value, i.e. SQL's `IS DISTINCT FROM`. The result has no nulls.
+///
+/// This is the same as [`neq_and_validity`].
+/// Use [`can_neq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn neq_missing(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    neq_and_validity(lhs, rhs)
+}
+
 /// Returns whether a [`DataType`] is comparable is supported by [`neq`].
 pub fn can_neq(data_type: &DataType) -> bool {
     can_partial_eq(data_type)
@@ -444,6 +507,221 @@ pub fn can_gt_eq_scalar(data_type: &DataType) -> bool {
     can_partial_eq_and_ord_scalar(data_type)
 }
 
+macro_rules! total_compare {
+    ($lhs:expr, $rhs:expr, $op:tt) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        macro_rules! float {
+            ($T:ty) => {{
+                assert_eq!(
+                    lhs.data_type().to_logical_type(),
+                    rhs.data_type().to_logical_type()
+                );
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                total::$op::<$T>(lhs, rhs)
+            }};
+        }
+        match lhs.data_type().to_logical_type() {
+            DataType::Float16 => float!(f16),
+            DataType::Float32 => float!(f32),
+            DataType::Float64 => float!(f64),
+            _ => $op(lhs, rhs),
+        }
+    }};
+}
+
+macro_rules! total_compare_scalar {
+    ($lhs:expr, $rhs:expr, $op:tt) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        macro_rules! float {
+            ($T:ty) => {{
+                assert_eq!(
+                    lhs.data_type().to_logical_type(),
+                    rhs.data_type().to_logical_type()
+                );
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+                match rhs.value() {
+                    Some(value) => total::$op::<$T>(lhs, value),
+                    None => BooleanArray::new_null(DataType::Boolean, lhs.len()),
+                }
+            }};
+        }
+        match lhs.data_type().to_logical_type() {
+            DataType::Float16 => float!(f16),
+            DataType::Float32 => float!(f32),
+            DataType::Float64 => float!(f64),
+            _ => $op(lhs, rhs),
+        }
+    }};
+}
+
+/// `==` between two [`Array`]s, comparing floats in the IEEE 754 total order.
+///
+/// In this order, `NaN == NaN` (when they have the same bits), `-0.0 < 0.0` and `NaN` is
+/// greater than any other value, which is the order used by [`sort`](crate::compute::sort).
+///
+/// This is the same as [`eq`] for all other types.
+/// Use [`can_eq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Float64Array};
+/// use arrow2::compute::comparison::{eq, total_eq, total_lt};
+///
+/// let lhs = Float64Array::from_slice(&[f64::NAN, -0.0, f64::NAN]);
+/// let rhs = Float64Array::from_slice(&[f64::NAN, 0.0, f64::INFINITY]);
+/// assert_eq!(eq(&lhs, &rhs), BooleanArray::from_slice(&[false, true, false]));
+/// assert_eq!(total_eq(&lhs, &rhs), BooleanArray::from_slice(&[true, false, false]));
+/// assert_eq!(total_lt(&lhs, &rhs), BooleanArray::from_slice(&[false, true, false]));
+/// ```
+pub fn total_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    total_compare!(lhs, rhs, eq)
+}
+
+/// `==` between an [`Array`] and a [`Scalar`], comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`eq_scalar`] for all other types.
+/// Use [`can_eq_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn total_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    total_compare_scalar!(lhs, rhs, eq_scalar)
+}
+
+/// `!=` between two [`Array`]s, comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`neq`] for all other types.
+/// Use [`can_neq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn total_neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    total_compare!(lhs, rhs, neq)
+}
+
+/// `!=` between an [`Array`] and a [`Scalar`], comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`neq_scalar`] for all other types.
+/// Use [`can_neq_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn total_neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    total_compare_scalar!(lhs, rhs, neq_scalar)
+}
+
+/// `<` between two [`Array`]s, comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`lt`] for all other types.
+/// Use [`can_lt`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn total_lt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    total_compare!(lhs, rhs, lt)
+}
+
+/// `<` between an [`Array`] and a [`Scalar`], comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`lt_scalar`] for all other types.
+/// Use [`can_lt_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn total_lt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    total_compare_scalar!(lhs, rhs, lt_scalar)
+}
+
+/// `<=` between two [`Array`]s, comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`lt_eq`] for all other types.
+/// Use [`can_lt_eq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn total_lt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    total_compare!(lhs, rhs, lt_eq)
+}
+
+/// `<=` between an [`Array`] and a [`Scalar`], comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`lt_eq_scalar`] for all other types.
+/// Use [`can_lt_eq_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn total_lt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    total_compare_scalar!(lhs, rhs, lt_eq_scalar)
+}
+
+/// `>` between two [`Array`]s, comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`gt`] for all other types.
+/// Use [`can_gt`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn total_gt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    total_compare!(lhs, rhs, gt)
+}
+
+/// `>` between an [`Array`] and a [`Scalar`], comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`gt_scalar`] for all other types.
+/// Use [`can_gt_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn total_gt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    total_compare_scalar!(lhs, rhs, gt_scalar)
+}
+
+/// `>=` between two [`Array`]s, comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`gt_eq`] for all other types.
+/// Use [`can_gt_eq`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn total_gt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    total_compare!(lhs, rhs, gt_eq)
+}
+
+/// `>=` between an [`Array`] and a [`Scalar`], comparing floats in the IEEE 754 total order.
+///
+/// This is the same as [`gt_eq_scalar`] for all other types.
+/// Use [`can_gt_eq_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn total_gt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    total_compare_scalar!(lhs, rhs, gt_eq_scalar)
+}
+
 // The list of operations currently supported.
 fn can_partial_eq_and_ord_scalar(data_type: &DataType) -> bool {
     if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
@@ -454,6 +732,14 @@ fn can_partial_eq_and_ord_scalar(data_type: &DataType) -> bool {
 
 // The list of operations currently supported.
 fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
+    if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+        // dictionaries are compared by the natural order of their values
+        return can_partial_eq_and_ord(values.as_ref())
+            && !matches!(
+                values.to_logical_type(),
+                DataType::Float16 | DataType::Dictionary(..)
+            );
+    }
     matches!(
         data_type,
         DataType::Boolean
@@ -463,6 +749,7 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::Date32
             | DataType::Time32(_)
             | DataType::Interval(IntervalUnit::YearMonth)
+            | DataType::Interval(IntervalUnit::MonthDayNano)
             | DataType::Int64
             | DataType::Timestamp(_, _)
             | DataType::Date64
@@ -472,11 +759,13 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::UInt16
             | DataType::UInt32
             | DataType::UInt64
+            | DataType::Float16
             | DataType::Float32
             | DataType::Float64
             | DataType::Utf8
             | DataType::LargeUtf8
             | DataType::Decimal(_, _)
+            | DataType::Decimal256(_, _)
             | DataType::Binary
             | DataType::LargeBinary
     )
@@ -488,7 +777,6 @@ fn can_partial_eq(data_type: &DataType) -> bool {
         || matches!(
             data_type.to_logical_type(),
             DataType::Interval(IntervalUnit::DayTime)
-                | DataType::Interval(IntervalUnit::MonthDayNano)
         )
 }
 
@@ -498,7 +786,6 @@ fn can_partial_eq_scalar(data_type: &DataType) -> bool {
         || matches!(
             data_type.to_logical_type(),
             DataType::Interval(IntervalUnit::DayTime)
-                | DataType::Interval(IntervalUnit::MonthDayNano)
         )
 }
 
@@ -520,10 +807,11 @@ fn finish_eq_validities(
         )
         .unwrap(),
         (Some(lhs), Some(rhs)) => {
-            let lhs = BooleanArray::new(DataType::Boolean, lhs, None);
-            let rhs = BooleanArray::new(DataType::Boolean, rhs, None);
-            let eq_validities = compute::comparison::boolean::eq(&lhs, &rhs);
-            compute::boolean::and(&output_without_validities, &eq_validities).unwrap()
+            // equal iff both are valid and equal, or both are null (irrespectively of their values)
+            let both_valid = &lhs & &rhs;
+            let both_null = !&(&lhs | &rhs);
+            let values = &(output_without_validities.values() & &both_valid) | &both_null;
+            BooleanArray::new(DataType::Boolean, values, None)
         }
     }
 }
@@ -545,10 +833,11 @@ fn finish_neq_validities(
             compute::boolean::or(&output_without_validities, &rhs_negated).unwrap()
         }
         (Some(lhs), Some(rhs)) => {
-            let lhs = BooleanArray::new(DataType::Boolean, lhs, None);
-            let rhs = BooleanArray::new(DataType::Boolean, rhs, None);
-            let neq_validities = compute::comparison::boolean::neq(&lhs, &rhs);
-            compute::boolean::or(&output_without_validities, &neq_validities).unwrap()
+            // different iff both are valid and different, or only one of them is null
+            let both_valid = &lhs & &rhs;
+            let one_null = &lhs ^ &rhs;
+            let values = &(output_without_validities.values() & &both_valid) | &one_null;
+            BooleanArray::new(DataType::Boolean, values, None)
         }
     }
 }
@@ -0,0 +1,99 @@
+//! Comparison functions for float [`PrimitiveArray`]s in the IEEE 754 total order.
+//!
+//! Unlike the comparison functions of [`primitive`](super::primitive), which follow IEEE 754
+//! semantics (e.g. `NaN != NaN` and `-0.0 == 0.0`), these order floats by
+//! `-NaN < -inf < ... < -0.0 < +0.0 < ... < +inf < +NaN`, where `NaN`s with the same bits are
+//! equal. This is the order used by [`sort`](crate::compute::sort), so that sorted arrays and
+//! comparisons agree.
+//!
+//! Each float is compared by an integer of the same width that has the same total order,
+//! so that these functions are as fast as comparing integers.
+use crate::{
+    array::{BooleanArray, PrimitiveArray},
+    datatypes::DataType,
+    types::{f16, NativeType},
+};
+
+use super::primitive;
+use super::simd::{Simd8, Simd8PartialEq, Simd8PartialOrd};
+
+/// A float that can be compared in the IEEE 754 total order via an integer key
+pub trait TotalOrderKey: NativeType {
+    /// The integer type of the key
+    type Key: NativeType + Simd8;
+
+    /// Returns an integer whose order is the total order of `self`.
+    fn total_order_key(self) -> Self::Key;
+}
+
+impl TotalOrderKey for f16 {
+    type Key = i16;
+
+    #[inline]
+    fn total_order_key(self) -> i16 {
+        let bits = self.to_bits() as i16;
+        bits ^ (((bits >> 15) as u16) >> 1) as i16
+    }
+}
+
+impl TotalOrderKey for f32 {
+    type Key = i32;
+
+    #[inline]
+    fn total_order_key(self) -> i32 {
+        let bits = self.to_bits() as i32;
+        bits ^ (((bits >> 31) as u32) >> 1) as i32
+    }
+}
+
+impl TotalOrderKey for f64 {
+    type Key = i64;
+
+    #[inline]
+    fn total_order_key(self) -> i64 {
+        let bits = self.to_bits() as i64;
+        bits ^ (((bits >> 63) as u64) >> 1) as i64
+    }
+}
+
+fn keys<T: TotalOrderKey>(array: &PrimitiveArray<T>) -> PrimitiveArray<T::Key> {
+    let values = array
+        .values()
+        .iter()
+        .map(|x| x.total_order_key())
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(
+        DataType::from(<T::Key as NativeType>::PRIMITIVE),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+macro_rules! total_op {
+    ($name:ident, $scalar_name:ident, $trait:ident, $op:literal) => {
+        #[doc = concat!("Perform `lhs ", $op, " rhs` operation on two [`PrimitiveArray`]s in the total order.")]
+        pub fn $name<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray
+        where
+            T: TotalOrderKey,
+            <T::Key as Simd8>::Simd: $trait,
+        {
+            primitive::$name(&keys(lhs), &keys(rhs))
+        }
+
+        #[doc = concat!("Perform `lhs ", $op, " rhs` operation on a [`PrimitiveArray`] and a scalar in the total order.")]
+        pub fn $scalar_name<T>(lhs: &PrimitiveArray<T>, rhs: T) -> BooleanArray
+        where
+            T: TotalOrderKey,
+            <T::Key as Simd8>::Simd: $trait,
+        {
+            primitive::$scalar_name(&keys(lhs), rhs.total_order_key())
+        }
+    };
+}
+
+total_op!(eq, eq_scalar, Simd8PartialEq, "==");
+total_op!(neq, neq_scalar, Simd8PartialEq, "!=");
+total_op!(lt, lt_scalar, Simd8PartialOrd, "<");
+total_op!(lt_eq, lt_eq_scalar, Simd8PartialOrd, "<=");
+total_op!(gt, gt_scalar, Simd8PartialOrd, ">");
+total_op!(gt_eq, gt_eq_scalar, Simd8PartialOrd, ">=");
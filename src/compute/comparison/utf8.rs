@@ -142,6 +142,26 @@ pub fn gt_eq_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
     compare_op_scalar(lhs, rhs, |a, b| a >= b)
 }
 
+/// Returns whether each value of `lhs` starts with the corresponding value of `rhs`.
+pub fn starts_with<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a.starts_with(b))
+}
+
+/// Returns whether each value of `lhs` starts with `rhs`.
+pub fn starts_with_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a.starts_with(b))
+}
+
+/// Returns whether each value of `lhs` ends with the corresponding value of `rhs`.
+pub fn ends_with<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a.ends_with(b))
+}
+
+/// Returns whether each value of `lhs` ends with `rhs`.
+pub fn ends_with_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a.ends_with(b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,4 +310,44 @@ mod tests {
         vec![false, false, true, true]
     );
     */
+
+    #[test]
+    fn test_starts_with() {
+        test_generic::<i32, _>(
+            vec!["arrow", "arrow", "", "ar"],
+            vec!["ar", "", "", "arrow"],
+            starts_with,
+            vec![true, true, true, false],
+        )
+    }
+
+    #[test]
+    fn test_starts_with_scalar() {
+        test_generic_scalar::<i64, _>(
+            vec!["arrow", "parquet", "ar", "a"],
+            "ar",
+            starts_with_scalar,
+            vec![true, false, true, false],
+        )
+    }
+
+    #[test]
+    fn test_ends_with() {
+        test_generic::<i32, _>(
+            vec!["arrow", "arrow", "", "ow"],
+            vec!["ow", "", "", "arrow"],
+            ends_with,
+            vec![true, true, true, false],
+        )
+    }
+
+    #[test]
+    fn test_ends_with_scalar() {
+        test_generic_scalar::<i64, _>(
+            vec!["arrow", "parquet", "ow", "w"],
+            "ow",
+            ends_with_scalar,
+            vec![true, false, true, false],
+        )
+    }
 }
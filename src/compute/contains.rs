@@ -1,14 +1,23 @@
-//! Declares the [`contains`] operator
+//! Declares the [`contains`], [`list_contains`] and [`is_in`] operators
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use ahash::RandomState;
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, ListArray, Offset, PrimitiveArray, Utf8Array},
+    array::{
+        ord::build_compare, Array, BinaryArray, BooleanArray, ListArray, Offset, PrimitiveArray,
+        StructArray, Utf8Array,
+    },
     bitmap::Bitmap,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType},
     error::{ArrowError, Result},
+    scalar::Scalar,
     types::NativeType,
 };
 
-use super::utils::combine_validities;
+use super::utils::{combine_validities, scalar_to_array, ByBytes};
 
 /// Checks if a [`GenericListArray`] contains a value in the [`PrimitiveArray`]
 /// The validity will be equal to the `And` of both arrays.
@@ -196,3 +205,257 @@ pub fn contains(list: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
         ))),
     }
 }
+
+type DynEqual = Box<dyn Fn(usize, usize) -> bool>;
+
+/// Returns a function that returns whether the value at slot `i` of `left` is equal to the
+/// value at slot `j` of `right`, where nulls are equal to nulls.
+fn build_equal(left: &dyn Array, right: &dyn Array) -> DynEqual {
+    let (left, right) = (left.to_boxed(), right.to_boxed());
+    if let PhysicalType::Struct = left.data_type().to_physical_type() {
+        let lhs = left.as_any().downcast_ref::<StructArray>().unwrap();
+        let rhs = right.as_any().downcast_ref::<StructArray>().unwrap();
+        let fields = lhs
+            .values()
+            .iter()
+            .zip(rhs.values())
+            .map(|(lhs, rhs)| build_equal(lhs.as_ref(), rhs.as_ref()))
+            .collect::<Vec<_>>();
+        return Box::new(move |i, j| match (left.is_valid(i), right.is_valid(j)) {
+            (true, true) => fields.iter().all(|equal| equal(i, j)),
+            (lhs, rhs) => lhs == rhs,
+        });
+    }
+    match build_compare(left.as_ref(), right.as_ref()) {
+        Ok(compare) => Box::new(move |i, j| match (left.is_valid(i), right.is_valid(j)) {
+            (true, true) => compare(i, j) == Ordering::Equal,
+            (lhs, rhs) => lhs == rhs,
+        }),
+        // types without a natural order are compared slot by slot
+        Err(_) => Box::new(move |i, j| left.slice(i, 1).as_ref() == right.slice(j, 1).as_ref()),
+    }
+}
+
+fn list_contains_impl<O: Offset, F: Fn(usize, usize) -> bool>(
+    list: &ListArray<O>,
+    validity: Option<Bitmap>,
+    equal: F,
+) -> BooleanArray {
+    let list_values = list.values().as_ref();
+    let values = list.offsets().windows(2).enumerate().map(|(i, window)| {
+        let (start, end) = (window[0].to_usize(), window[1].to_usize());
+        (start..end).any(|k| list_values.is_valid(k) && equal(k, i))
+    });
+    let values = Bitmap::from_trusted_len_iter(values);
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+fn check_list_contains(list: &dyn Array, data_type: &DataType) -> Result<()> {
+    let inner = match list.data_type().to_logical_type() {
+        DataType::List(inner) | DataType::LargeList(inner) => inner.data_type(),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "list_contains requires a list array, got {:?}",
+                list.data_type()
+            )))
+        }
+    };
+    if inner != data_type {
+        return Err(ArrowError::InvalidArgumentError(
+            "list_contains requires the values to be of the logical type of the list's inner array"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns whether each list of `list` contains the corresponding value of `values`.
+///
+/// `list` is a `List` or `LargeList` array whose inner values have the same logical type as
+/// `values`, which can be of any type, including nested types such as structs. Nested values
+/// are equal when all their fields are equal, where nulls are equal to nulls, and floats are
+/// compared in the IEEE 754 total order (e.g. `NaN == NaN`).
+///
+/// The result is null when the list or the value is null. Null elements of a list are not equal
+/// to any value.
+/// # Errors
+/// Errors iff the arrays have different lengths, `list` is not a list array, or the types do
+/// not match.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::contains::list_contains;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![
+///     Some(vec![Some(1), Some(2)]),
+///     Some(vec![Some(1), None]),
+///     None,
+/// ])
+/// .unwrap();
+/// let list: ListArray<i32> = list.into();
+/// let values = Int32Array::from(&[Some(2), Some(3), Some(1)]);
+///
+/// let result = list_contains(&list, &values).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+/// ```
+pub fn list_contains(list: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
+    if list.len() != values.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "list_contains requires arrays of the same length".to_string(),
+        ));
+    }
+    check_list_contains(list, values.data_type())?;
+
+    let validity = combine_validities(list.validity(), values.validity());
+    match list.data_type().to_physical_type() {
+        PhysicalType::List => {
+            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let equal = build_equal(list.values().as_ref(), values);
+            Ok(list_contains_impl(list, validity, equal))
+        }
+        _ => {
+            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let equal = build_equal(list.values().as_ref(), values);
+            Ok(list_contains_impl(list, validity, equal))
+        }
+    }
+}
+
+/// Returns whether each list of `list` contains `value`.
+///
+/// The result is null when the list or `value` is null. See [`list_contains`] for details.
+/// # Errors
+/// Errors iff `list` is not a list array, the types do not match, or the type of `value` is not
+/// supported.
+pub fn list_contains_scalar(list: &dyn Array, value: &dyn Scalar) -> Result<BooleanArray> {
+    check_list_contains(list, value.data_type())?;
+    if !value.is_valid() {
+        return Ok(BooleanArray::new_null(DataType::Boolean, list.len()));
+    }
+    let value = scalar_to_array(value)?;
+
+    let validity = list.validity().cloned();
+    match list.data_type().to_physical_type() {
+        PhysicalType::List => {
+            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let equal = build_equal(list.values().as_ref(), value.as_ref());
+            Ok(list_contains_impl(list, validity, |k, _| equal(k, 0)))
+        }
+        _ => {
+            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let equal = build_equal(list.values().as_ref(), value.as_ref());
+            Ok(list_contains_impl(list, validity, |k, _| equal(k, 0)))
+        }
+    }
+}
+
+fn is_in_iter<K, I, S>(values: I, set: S, nulls_equal: bool) -> BooleanArray
+where
+    K: Hash + Eq,
+    I: Iterator<Item = Option<K>>,
+    S: Iterator<Item = Option<K>>,
+{
+    let mut set_has_null = false;
+    let set = set
+        .filter_map(|x| {
+            set_has_null |= x.is_none();
+            x
+        })
+        .collect::<HashSet<_, RandomState>>();
+
+    values
+        .map(|x| match x {
+            Some(x) if set.contains(&x) => Some(true),
+            Some(_) if set_has_null && !nulls_equal => None,
+            Some(_) => Some(false),
+            None if nulls_equal => Some(set_has_null),
+            None => None,
+        })
+        .collect()
+}
+
+fn is_in_primitive<T: NativeType>(
+    values: &dyn Array,
+    set: &dyn Array,
+    nulls_equal: bool,
+) -> BooleanArray {
+    let values = values.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let set = set.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    is_in_iter(
+        values.iter().map(|x| x.map(|x| ByBytes(*x))),
+        set.iter().map(|x| x.map(|x| ByBytes(*x))),
+        nulls_equal,
+    )
+}
+
+fn is_in_binary<O: Offset>(values: &dyn Array, set: &dyn Array, nulls_equal: bool) -> BooleanArray {
+    let values = values.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let set = set.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    is_in_iter(values.iter(), set.iter(), nulls_equal)
+}
+
+fn is_in_utf8<O: Offset>(values: &dyn Array, set: &dyn Array, nulls_equal: bool) -> BooleanArray {
+    let values = values.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let set = set.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    is_in_iter(values.iter(), set.iter(), nulls_equal)
+}
+
+/// Returns whether each element in `values` is one of the elements of `set`, i.e. SQL's
+/// `values IN (set)`.
+///
+/// `set` is hashed once, so that this is `O(N + M)` where `N` and `M` are the lengths of
+/// `values` and `set`. Floats are compared by their bit representation.
+///
+/// When `nulls_equal` is `false`, nulls follow SQL semantics: a null value results in a null,
+/// and so does a value not found in a `set` containing a null.
+/// When `nulls_equal` is `true`, a null is treated as any other value: a null value is in
+/// `set` iff `set` contains a null, and the result has no nulls.
+/// # Errors
+/// Errors iff `values` and `set` have different types or the type is not supported.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::contains::is_in;
+///
+/// let values = Int32Array::from(&[Some(1), Some(2), None]);
+///
+/// let set = Int32Array::from(&[Some(1), Some(3)]);
+/// let result = is_in(&values, &set, false).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+///
+/// let set = Int32Array::from(&[Some(1), None]);
+/// let result = is_in(&values, &set, false).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(true), None, None]));
+/// let result = is_in(&values, &set, true).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), Some(true)]));
+/// ```
+pub fn is_in(values: &dyn Array, set: &dyn Array, nulls_equal: bool) -> Result<BooleanArray> {
+    if values.data_type() != set.data_type() {
+        return Err(ArrowError::InvalidArgumentError(
+            "is_in requires arrays of the same logical type".to_string(),
+        ));
+    }
+
+    use PhysicalType::*;
+    Ok(match values.data_type().to_physical_type() {
+        Boolean => {
+            let values = values.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let set = set.as_any().downcast_ref::<BooleanArray>().unwrap();
+            is_in_iter(values.iter(), set.iter(), nulls_equal)
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            is_in_primitive::<$T>(values, set, nulls_equal)
+        }),
+        Binary => is_in_binary::<i32>(values, set, nulls_equal),
+        LargeBinary => is_in_binary::<i64>(values, set, nulls_equal),
+        Utf8 => is_in_utf8::<i32>(values, set, nulls_equal),
+        LargeUtf8 => is_in_utf8::<i64>(values, set, nulls_equal),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "is_in is not supported for logical type \"{:?}\"",
+                values.data_type()
+            )))
+        }
+    })
+}
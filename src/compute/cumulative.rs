@@ -0,0 +1,348 @@
+//! Contains cumulative aggregations, such as [`cumsum`], whose slot `i` is the aggregation
+//! of the slots `0..=i` of an array.
+//!
+//! All of them accept a `skip_nulls` argument: when `true`, null slots are null in the result
+//! and are ignored by the aggregation; when `false`, every slot from the first null slot on
+//! is null in the result.
+//!
+//! [`cumsum`] and [`cumprod`] wrap around on integer overflow, while [`checked_cumsum`] and
+//! [`checked_cumprod`] error.
+use crate::array::{Array, PrimitiveArray};
+use crate::bitmap::MutableBitmap;
+use crate::compute::utils::is_greater;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+fn cumulative_not_supported(name: &str, data_type: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "`{}` does not support type `{:?}`",
+        name, data_type
+    ))
+}
+
+/// Applies `op` cumulatively to the non-null values of `array`
+fn cumulative<T, F>(array: &PrimitiveArray<T>, skip_nulls: bool, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    let mut accumulated: Option<T> = None;
+    let mut is_null = false;
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    let values = (0..array.len())
+        .map(|i| {
+            if array.is_valid(i) {
+                let value = array.value(i);
+                accumulated = Some(accumulated.map_or(value, |acc| op(acc, value)));
+                validity.push(!is_null);
+            } else {
+                is_null |= !skip_nulls;
+                validity.push(false);
+            }
+            accumulated.unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    PrimitiveArray::new(array.data_type().clone(), values.into(), validity.into())
+}
+
+/// Applies `op` cumulatively to the non-null values of `array`, erroring when `op` overflows
+fn try_cumulative<T, F>(
+    array: &PrimitiveArray<T>,
+    skip_nulls: bool,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(T, T) -> Option<T>,
+{
+    let mut accumulated: Option<T> = None;
+    let mut is_null = false;
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    let values = (0..array.len())
+        .map(|i| {
+            if array.is_valid(i) {
+                let value = array.value(i);
+                accumulated = Some(match accumulated {
+                    Some(acc) => op(acc, value).ok_or(ArrowError::Overflow)?,
+                    None => value,
+                });
+                validity.push(!is_null);
+            } else {
+                is_null |= !skip_nulls;
+                validity.push(false);
+            }
+            Ok(accumulated.unwrap_or_default())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(PrimitiveArray::new(
+        array.data_type().clone(),
+        values.into(),
+        validity.into(),
+    ))
+}
+
+/// A numeric type with the arithmetic of [`cumsum_primitive`] and [`cumprod_primitive`] and of
+/// their checked variants. Integers wrap around (or return `None`) on overflow, while floats
+/// follow IEEE 754 and thus never overflow.
+pub trait CumulativeArithmetic: NativeType {
+    /// Returns `self + rhs`, wrapping around on overflow
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Returns `self * rhs`, wrapping around on overflow
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Returns `self + rhs`, or `None` on overflow
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Returns `self * rhs`, or `None` on overflow
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! integer_cumulative {
+    ($type:ty) => {
+        impl CumulativeArithmetic for $type {
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$type>::wrapping_add(self, rhs)
+            }
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$type>::wrapping_mul(self, rhs)
+            }
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$type>::checked_add(self, rhs)
+            }
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$type>::checked_mul(self, rhs)
+            }
+        }
+    };
+}
+
+integer_cumulative!(i8);
+integer_cumulative!(i16);
+integer_cumulative!(i32);
+integer_cumulative!(i64);
+integer_cumulative!(i128);
+integer_cumulative!(u8);
+integer_cumulative!(u16);
+integer_cumulative!(u32);
+integer_cumulative!(u64);
+
+macro_rules! float_cumulative {
+    ($type:ty) => {
+        impl CumulativeArithmetic for $type {
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                self + rhs
+            }
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                self * rhs
+            }
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                Some(self + rhs)
+            }
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Some(self * rhs)
+            }
+        }
+    };
+}
+
+float_cumulative!(f32);
+float_cumulative!(f64);
+
+/// Returns the cumulative sum of a [`PrimitiveArray`]. See [`cumsum`] for details.
+pub fn cumsum_primitive<T: CumulativeArithmetic>(
+    array: &PrimitiveArray<T>,
+    skip_nulls: bool,
+) -> PrimitiveArray<T> {
+    cumulative(array, skip_nulls, T::wrapping_add)
+}
+
+/// Returns the cumulative product of a [`PrimitiveArray`]. See [`cumprod`] for details.
+pub fn cumprod_primitive<T: CumulativeArithmetic>(
+    array: &PrimitiveArray<T>,
+    skip_nulls: bool,
+) -> PrimitiveArray<T> {
+    cumulative(array, skip_nulls, T::wrapping_mul)
+}
+
+/// Returns the cumulative sum of a [`PrimitiveArray`]. See [`checked_cumsum`] for details.
+/// # Errors
+/// Errors iff the sum overflows.
+pub fn checked_cumsum_primitive<T: CumulativeArithmetic>(
+    array: &PrimitiveArray<T>,
+    skip_nulls: bool,
+) -> Result<PrimitiveArray<T>> {
+    try_cumulative(array, skip_nulls, T::checked_add)
+}
+
+/// Returns the cumulative product of a [`PrimitiveArray`]. See [`checked_cumprod`] for details.
+/// # Errors
+/// Errors iff the product overflows.
+pub fn checked_cumprod_primitive<T: CumulativeArithmetic>(
+    array: &PrimitiveArray<T>,
+    skip_nulls: bool,
+) -> Result<PrimitiveArray<T>> {
+    try_cumulative(array, skip_nulls, T::checked_mul)
+}
+
+/// Returns the cumulative minimum of a [`PrimitiveArray`]. See [`cummin`] for details.
+pub fn cummin_primitive<T>(array: &PrimitiveArray<T>, skip_nulls: bool) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    cumulative(array, skip_nulls, |acc, value| {
+        if is_greater(acc, value) {
+            value
+        } else {
+            acc
+        }
+    })
+}
+
+/// Returns the cumulative maximum of a [`PrimitiveArray`]. See [`cummax`] for details.
+pub fn cummax_primitive<T>(array: &PrimitiveArray<T>, skip_nulls: bool) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    cumulative(array, skip_nulls, |acc, value| {
+        if is_greater(value, acc) {
+            value
+        } else {
+            acc
+        }
+    })
+}
+
+/// Returns the cumulative sum of `array`, whose slot `i` is the sum of the slots `0..=i`.
+/// The result has the same [`DataType`] as `array`. Integers wrap around on overflow (see
+/// [`checked_cumsum`] to error instead).
+/// # Errors
+/// Errors iff `array` is not numeric.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::cumulative::cumsum;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+/// let result = cumsum(&array, true).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(1), None, Some(4), Some(8)]), result.as_ref());
+///
+/// let result = cumsum(&array, false).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(1), None, None, None]), result.as_ref());
+/// ```
+pub fn cumsum(array: &dyn Array, skip_nulls: bool) -> Result<Box<dyn Array>> {
+    with_match_arithmetic_type!(array.data_type(), cumulative_not_supported("cumsum", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(cumsum_primitive::<$T>(array, skip_nulls)))
+    })
+}
+
+/// Returns the cumulative product of `array`, whose slot `i` is the product of the slots `0..=i`.
+/// The result has the same [`DataType`] as `array`. Integers wrap around on overflow (see
+/// [`checked_cumprod`] to error instead).
+/// # Errors
+/// Errors iff `array` is not numeric or is a decimal.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::cumulative::cumprod;
+///
+/// let array = Int32Array::from(&[Some(2), None, Some(3), Some(4)]);
+/// let result = cumprod(&array, true).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(2), None, Some(6), Some(24)]), result.as_ref());
+/// ```
+pub fn cumprod(array: &dyn Array, skip_nulls: bool) -> Result<Box<dyn Array>> {
+    if let DataType::Decimal(_, _) = array.data_type().to_logical_type() {
+        return Err(cumulative_not_supported("cumprod", array.data_type()));
+    }
+    with_match_arithmetic_type!(array.data_type(), cumulative_not_supported("cumprod", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(cumprod_primitive::<$T>(array, skip_nulls)))
+    })
+}
+
+/// Returns the cumulative sum of `array` like [`cumsum`], but errors on integer overflow.
+/// # Errors
+/// Errors iff `array` is not numeric or the sum overflows.
+/// # Examples
+/// ```
+/// use arrow2::array::Int8Array;
+/// use arrow2::compute::cumulative::checked_cumsum;
+///
+/// let array = Int8Array::from_slice(&[100, 27]);
+/// let result = checked_cumsum(&array, true).unwrap();
+/// assert_eq!(Int8Array::from_slice(&[100, 127]), result.as_ref());
+///
+/// let array = Int8Array::from_slice(&[100, 100]);
+/// assert!(checked_cumsum(&array, true).is_err());
+/// ```
+pub fn checked_cumsum(array: &dyn Array, skip_nulls: bool) -> Result<Box<dyn Array>> {
+    with_match_arithmetic_type!(array.data_type(), cumulative_not_supported("checked_cumsum", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(checked_cumsum_primitive::<$T>(array, skip_nulls)?))
+    })
+}
+
+/// Returns the cumulative product of `array` like [`cumprod`], but errors on integer overflow.
+/// # Errors
+/// Errors iff `array` is not numeric, is a decimal or the product overflows.
+pub fn checked_cumprod(array: &dyn Array, skip_nulls: bool) -> Result<Box<dyn Array>> {
+    if let DataType::Decimal(_, _) = array.data_type().to_logical_type() {
+        return Err(cumulative_not_supported(
+            "checked_cumprod",
+            array.data_type(),
+        ));
+    }
+    with_match_arithmetic_type!(array.data_type(), cumulative_not_supported("checked_cumprod", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(checked_cumprod_primitive::<$T>(array, skip_nulls)?))
+    })
+}
+
+/// Returns the cumulative minimum of `array`, whose slot `i` is the minimum of the slots `0..=i`.
+/// NaNs are larger than any other value. The result has the same [`DataType`] as `array`.
+/// # Errors
+/// Errors iff `array` is not numeric.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::cumulative::cummin;
+///
+/// let array = Int32Array::from(&[Some(3), None, Some(1), Some(4)]);
+/// let result = cummin(&array, true).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(3), None, Some(1), Some(1)]), result.as_ref());
+/// ```
+pub fn cummin(array: &dyn Array, skip_nulls: bool) -> Result<Box<dyn Array>> {
+    with_match_arithmetic_type!(array.data_type(), cumulative_not_supported("cummin", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(cummin_primitive::<$T>(array, skip_nulls)))
+    })
+}
+
+/// Returns the cumulative maximum of `array`, whose slot `i` is the maximum of the slots `0..=i`.
+/// NaNs are larger than any other value. The result has the same [`DataType`] as `array`.
+/// # Errors
+/// Errors iff `array` is not numeric.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::cumulative::cummax;
+///
+/// let array = Int32Array::from(&[Some(3), None, Some(1), Some(4)]);
+/// let result = cummax(&array, true).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(3), None, Some(3), Some(4)]), result.as_ref());
+/// ```
+pub fn cummax(array: &dyn Array, skip_nulls: bool) -> Result<Box<dyn Array>> {
+    with_match_arithmetic_type!(array.data_type(), cumulative_not_supported("cummax", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(cummax_primitive::<$T>(array, skip_nulls)))
+    })
+}
@@ -0,0 +1,110 @@
+//! Contains the operators [`distinct`] and [`distinct_columns`], to remove duplicated rows of
+//! one or more columns.
+use std::collections::HashSet;
+
+use ahash::RandomState;
+
+use crate::{
+    array::{Array, UInt64Array},
+    compute::{
+        sort::row::{RowConverter, SortField},
+        take::take,
+    },
+    error::Result,
+};
+
+/// The order of the rows returned by [`distinct`] and [`distinct_columns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistinctOrder {
+    /// The rows are in the order of their first occurrence.
+    FirstOccurrence,
+    /// The rows are sorted in ascending order with nulls first, column by column.
+    Sorted,
+}
+
+/// Returns the indices of the first occurrence of every distinct row of `columns`, in `order`.
+///
+/// Rows are compared via their [row encoding](crate::compute::sort::row): nulls are equal to
+/// each other, floats are compared by their total order (e.g. `0.0` and `-0.0` are distinct),
+/// and dictionary arrays are compared by their values.
+/// # Errors
+/// This function errors when the columns have different lengths or when the type of a column
+/// is not supported by [`RowConverter`].
+pub fn distinct_indices<A: AsRef<dyn Array>>(
+    columns: &[A],
+    order: DistinctOrder,
+) -> Result<UInt64Array> {
+    let converter = RowConverter::new(
+        columns
+            .iter()
+            .map(|column| SortField::new(column.as_ref().data_type().clone()))
+            .collect(),
+    )?;
+    let rows = converter.convert_columns(columns)?;
+
+    let mut seen = HashSet::<&[u8], RandomState>::default();
+    let mut indices = rows
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| seen.insert(row.data()))
+        .map(|(index, _)| index as u64)
+        .collect::<Vec<_>>();
+
+    if order == DistinctOrder::Sorted {
+        indices.sort_unstable_by_key(|index| rows.row(*index as usize).data());
+    }
+    Ok(UInt64Array::from_vec(indices))
+}
+
+/// Returns the distinct rows of `columns`, i.e. the columns without duplicated rows, in `order`.
+///
+/// See [`distinct_indices`] for how rows are compared.
+/// # Errors
+/// This function errors when the columns have different lengths or when the type of a column
+/// is not supported by [`RowConverter`].
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::distinct::{distinct_columns, DistinctOrder};
+///
+/// let a = Arc::new(Int32Array::from(&[Some(1), Some(1), None, Some(1)])) as Arc<dyn Array>;
+/// let b = Arc::new(Utf8Array::<i32>::from_slice(&["b", "a", "c", "b"])) as Arc<dyn Array>;
+///
+/// let result = distinct_columns(&[a, b], DistinctOrder::FirstOccurrence).unwrap();
+/// assert_eq!(result[0].as_ref(), &Int32Array::from(&[Some(1), Some(1), None]) as &dyn Array);
+/// assert_eq!(result[1].as_ref(), &Utf8Array::<i32>::from_slice(&["b", "a", "c"]) as &dyn Array);
+/// ```
+pub fn distinct_columns<A: AsRef<dyn Array>>(
+    columns: &[A],
+    order: DistinctOrder,
+) -> Result<Vec<Box<dyn Array>>> {
+    let indices = distinct_indices(columns, order)?;
+    columns
+        .iter()
+        .map(|column| take(column.as_ref(), &indices))
+        .collect()
+}
+
+/// Returns the distinct values of `array`, in `order`.
+///
+/// See [`distinct_indices`] for how values are compared.
+/// # Errors
+/// This function errors when the type of `array` is not supported by [`RowConverter`].
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::distinct::{distinct, DistinctOrder};
+///
+/// let array = Int32Array::from(&[Some(3), None, Some(1), Some(3), None]);
+///
+/// let result = distinct(&array, DistinctOrder::FirstOccurrence).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(&[Some(3), None, Some(1)]) as &dyn Array);
+///
+/// let result = distinct(&array, DistinctOrder::Sorted).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(&[None, Some(1), Some(3)]) as &dyn Array);
+/// ```
+pub fn distinct(array: &dyn Array, order: DistinctOrder) -> Result<Box<dyn Array>> {
+    let indices = distinct_indices(&[array.to_boxed()], order)?;
+    take(array, &indices)
+}
@@ -0,0 +1,230 @@
+//! Contains the edit distance operators [`levenshtein`] and [`jaro_winkler`], and their
+//! `_scalar` variants, to fuzzy match \[Large\]String arrays.
+use crate::{
+    array::{Array, Float64Array, PrimitiveArray, UInt32Array, Utf8Array},
+    datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+/// Returns the Levenshtein distance between `lhs` and `rhs`, i.e. the minimum number of
+/// insertions, deletions and substitutions of characters to transform one into the other.
+fn levenshtein_str(lhs: &str, rhs: &str) -> u32 {
+    let rhs = rhs.chars().collect::<Vec<_>>();
+    // the distances between the current prefix of `lhs` and every prefix of `rhs`
+    let mut distances = (0..=rhs.len() as u32).collect::<Vec<_>>();
+    for (i, l) in lhs.chars().enumerate() {
+        let mut diagonal = distances[0];
+        distances[0] = i as u32 + 1;
+        for (j, r) in rhs.iter().enumerate() {
+            let substitution = diagonal + (l != *r) as u32;
+            diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(distances[j + 1] + 1);
+        }
+    }
+    distances[rhs.len()]
+}
+
+/// Returns the Jaro similarity between `lhs` and `rhs`.
+fn jaro(lhs: &[char], rhs: &[char]) -> f64 {
+    if lhs.is_empty() && rhs.is_empty() {
+        return 1.0;
+    }
+    let window = (lhs.len().max(rhs.len()) / 2).saturating_sub(1);
+
+    let mut rhs_matched = vec![false; rhs.len()];
+    let mut lhs_matches = Vec::with_capacity(lhs.len());
+    for (i, l) in lhs.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(rhs.len());
+        if let Some(j) = (start..end).find(|j| !rhs_matched[*j] && rhs[*j] == *l) {
+            rhs_matched[j] = true;
+            lhs_matches.push(*l);
+        }
+    }
+    if lhs_matches.is_empty() {
+        return 0.0;
+    }
+
+    let rhs_matches = rhs
+        .iter()
+        .zip(rhs_matched.iter())
+        .filter(|(_, matched)| **matched)
+        .map(|(r, _)| r);
+    let transpositions = lhs_matches
+        .iter()
+        .zip(rhs_matches)
+        .filter(|(l, r)| l != r)
+        .count();
+
+    let matches = lhs_matches.len() as f64;
+    (matches / lhs.len() as f64
+        + matches / rhs.len() as f64
+        + (matches - transpositions as f64 / 2.0) / matches)
+        / 3.0
+}
+
+/// Returns the Jaro-Winkler similarity between `lhs` and `rhs`, with the standard prefix
+/// scale of `0.1` over at most 4 characters.
+fn jaro_winkler_str(lhs: &str, rhs: &str) -> f64 {
+    let lhs = lhs.chars().collect::<Vec<_>>();
+    let rhs = rhs.chars().collect::<Vec<_>>();
+    let similarity = jaro(&lhs, &rhs);
+    let prefix = lhs
+        .iter()
+        .zip(rhs.iter())
+        .take(4)
+        .take_while(|(l, r)| l == r)
+        .count();
+    similarity + prefix as f64 * 0.1 * (1.0 - similarity)
+}
+
+type Utf8Iter<'a> = Box<dyn Iterator<Item = Option<&'a str>> + 'a>;
+
+fn utf8_iter<'a>(array: &'a dyn Array, name: &str) -> Result<Utf8Iter<'a>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => Ok(Box::new(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .unwrap()
+                .iter(),
+        )),
+        PhysicalType::LargeUtf8 => Ok(Box::new(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .iter(),
+        )),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "{} does not support type {:?}",
+            name,
+            array.data_type()
+        ))),
+    }
+}
+
+fn binary<T: NativeType, F: Fn(&str, &str) -> T>(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    data_type: DataType,
+    name: &str,
+    op: F,
+) -> Result<PrimitiveArray<T>> {
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{} requires arrays of the same length",
+            name
+        )));
+    }
+    let values = utf8_iter(lhs, name)?
+        .zip(utf8_iter(rhs, name)?)
+        .map(|(lhs, rhs)| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => Some(op(lhs, rhs)),
+            _ => None,
+        });
+    Ok(PrimitiveArray::<T>::from_iter(values).to(data_type))
+}
+
+fn unary<T: NativeType, F: Fn(&str) -> T>(
+    array: &dyn Array,
+    data_type: DataType,
+    name: &str,
+    op: F,
+) -> Result<PrimitiveArray<T>> {
+    let values = utf8_iter(array, name)?.map(|value| value.map(&op));
+    Ok(PrimitiveArray::<T>::from_iter(values).to(data_type))
+}
+
+/// Returns the Levenshtein distance between each pair of values of `lhs` and `rhs`, i.e. the
+/// minimum number of insertions, deletions and substitutions of (unicode) characters to
+/// transform one into the other.
+///
+/// The distance is null when any of the values is null.
+/// # Errors
+/// This function errors when the arrays have different lengths or are not \[Large\]String
+/// arrays.
+/// # Example
+/// ```
+/// use arrow2::array::{UInt32Array, Utf8Array};
+/// use arrow2::compute::edit_distance::levenshtein;
+///
+/// let lhs = Utf8Array::<i32>::from(&[Some("kitten"), Some("flaw"), None]);
+/// let rhs = Utf8Array::<i32>::from(&[Some("sitting"), Some("lawn"), Some("a")]);
+/// let result = levenshtein(&lhs, &rhs).unwrap();
+/// assert_eq!(result, UInt32Array::from(&[Some(3), Some(2), None]));
+/// ```
+pub fn levenshtein(lhs: &dyn Array, rhs: &dyn Array) -> Result<UInt32Array> {
+    binary(lhs, rhs, DataType::UInt32, "levenshtein", levenshtein_str)
+}
+
+/// Returns the Levenshtein distance between each value of `lhs` and `rhs`.
+///
+/// The distance is null when the value is null. See [`levenshtein`] for details.
+/// # Errors
+/// This function errors when `lhs` is not a \[Large\]String array.
+pub fn levenshtein_scalar(lhs: &dyn Array, rhs: &str) -> Result<UInt32Array> {
+    unary(lhs, DataType::UInt32, "levenshtein", |lhs| {
+        levenshtein_str(lhs, rhs)
+    })
+}
+
+/// Returns the Jaro-Winkler similarity between each pair of values of `lhs` and `rhs`, from
+/// `0.0` (no similarity) to `1.0` (equal values), computed over (unicode) characters.
+///
+/// The similarity is null when any of the values is null.
+/// # Errors
+/// This function errors when the arrays have different lengths or are not \[Large\]String
+/// arrays.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::edit_distance::jaro_winkler;
+///
+/// let lhs = Utf8Array::<i32>::from_slice(&["martha", "abc"]);
+/// let rhs = Utf8Array::<i32>::from_slice(&["marhta", "abc"]);
+/// let result = jaro_winkler(&lhs, &rhs).unwrap();
+/// assert!((result.value(0) - 0.961).abs() < 1e-3);
+/// assert_eq!(result.value(1), 1.0);
+/// ```
+pub fn jaro_winkler(lhs: &dyn Array, rhs: &dyn Array) -> Result<Float64Array> {
+    binary(
+        lhs,
+        rhs,
+        DataType::Float64,
+        "jaro_winkler",
+        jaro_winkler_str,
+    )
+}
+
+/// Returns the Jaro-Winkler similarity between each value of `lhs` and `rhs`.
+///
+/// The similarity is null when the value is null. See [`jaro_winkler`] for details.
+/// # Errors
+/// This function errors when `lhs` is not a \[Large\]String array.
+pub fn jaro_winkler_scalar(lhs: &dyn Array, rhs: &str) -> Result<Float64Array> {
+    unary(lhs, DataType::Float64, "jaro_winkler", |lhs| {
+        jaro_winkler_str(lhs, rhs)
+    })
+}
+
+/// Checks if an array of type `datatype` can perform [`levenshtein`] and [`jaro_winkler`]
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::edit_distance::can_edit_distance;
+/// use arrow2::datatypes::{DataType};
+///
+/// let data_type = DataType::Utf8;
+/// assert_eq!(can_edit_distance(&data_type), true);
+///
+/// let data_type = DataType::Null;
+/// assert_eq!(can_edit_distance(&data_type), false);
+/// ```
+pub fn can_edit_distance(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::LargeUtf8 | PhysicalType::Utf8
+    )
+}
@@ -4,9 +4,9 @@ use crate::bitmap::utils::{BitChunk, BitChunkIterExact, BitChunksExact};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
 use crate::chunk::Chunk;
 use crate::datatypes::DataType;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::types::simd::{NativeSimd, Simd};
-use crate::types::BitChunkOnes;
+use crate::types::{BitChunkOnes, Index};
 use crate::{array::*, types::NativeType};
 use num_traits::One;
 use num_traits::Zero;
@@ -289,6 +289,77 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
     }
 }
 
+/// Returns the values of `filter` where nulls are `false`
+fn selection(filter: &BooleanArray) -> std::borrow::Cow<'_, Bitmap> {
+    match filter.validity() {
+        Some(validity) => std::borrow::Cow::Owned(filter.values() & validity),
+        None => std::borrow::Cow::Borrowed(filter.values()),
+    }
+}
+
+/// Returns the ranges of rows selected by `filter` (i.e. where it is `true`), as
+/// `(start, length)` pairs in increasing order.
+///
+/// Ranges separated by less than `min_gap` unselected rows are merged into one, so that they
+/// include these unselected rows. This allows trading reading some unselected rows for reading
+/// fewer, longer ranges (e.g. when decoding only the selected rows of a file).
+/// When `min_gap` is `0` or `1`, the ranges contain exactly the selected rows.
+///
+/// Note that the nulls of `filter` are interpreted as `false`.
+/// # Example
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::filter::selection_ranges;
+///
+/// let filter = BooleanArray::from(&[Some(true), Some(true), Some(false), None, Some(true), Some(false), Some(false), Some(false), Some(true)]);
+/// assert_eq!(selection_ranges(&filter, 0), vec![(0, 2), (4, 1), (8, 1)]);
+/// assert_eq!(selection_ranges(&filter, 3), vec![(0, 5), (8, 1)]);
+/// ```
+pub fn selection_ranges(filter: &BooleanArray, min_gap: usize) -> Vec<(usize, usize)> {
+    let values = selection(filter);
+    let mut ranges: Vec<(usize, usize)> = vec![];
+    for (start, len) in SlicesIterator::new(&values) {
+        match ranges.last_mut() {
+            Some((last_start, last_len)) if start - (*last_start + *last_len) < min_gap => {
+                *last_len = start + len - *last_start;
+            }
+            _ => ranges.push((start, len)),
+        }
+    }
+    ranges
+}
+
+/// Returns the indices of the rows selected by `filter` (i.e. where it is `true`), in
+/// increasing order. The result has no nulls.
+///
+/// Note that the nulls of `filter` are interpreted as `false`.
+/// # Errors
+/// Errors iff an index does not fit in `I`.
+/// # Example
+/// ```rust
+/// use arrow2::array::{BooleanArray, UInt32Array};
+/// use arrow2::compute::filter::selection_indices;
+///
+/// let filter = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+/// let indices = selection_indices::<u32>(&filter).unwrap();
+/// assert_eq!(indices, UInt32Array::from_slice(&[0, 3]));
+/// ```
+pub fn selection_indices<I: Index>(filter: &BooleanArray) -> Result<PrimitiveArray<I>> {
+    let values = selection(filter);
+    let iter = SlicesIterator::new(&values);
+    let mut indices = Vec::with_capacity(iter.slots());
+    for (start, len) in iter {
+        for index in start..start + len {
+            indices.push(I::from_usize(index).ok_or(ArrowError::Overflow)?);
+        }
+    }
+    Ok(PrimitiveArray::<I>::new(
+        I::PRIMITIVE.into(),
+        indices.into(),
+        None,
+    ))
+}
+
 /// Returns a new [Chunk] with arrays containing only values matching the filter.
 /// This is a convenience function: filter multiple columns is embarassingly parallel.
 pub fn filter_chunk<A: AsRef<dyn Array>>(
@@ -0,0 +1,379 @@
+//! Contains the operator [`groupby`], to aggregate columns by groups of rows with equal keys.
+//!
+//! Groups are found by encoding the key columns with a [`RowConverter`] and hashing
+//! the resulting rows. Every aggregation is then computed column-wise, in a single pass over
+//! its values.
+use std::collections::{hash_map::Entry, HashMap};
+
+use ahash::RandomState;
+use num_traits::AsPrimitive;
+
+use crate::{
+    array::*,
+    bitmap::MutableBitmap,
+    compute::{
+        cumulative::CumulativeArithmetic,
+        sort::row::{RowConverter, SortField},
+        take::take,
+        utils::is_greater,
+    },
+    datatypes::{DataType, PhysicalType, PrimitiveType},
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+/// An aggregate function supported by [`groupby`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AggregateFunction {
+    /// The number of non-null values of each group, as a [`UInt64Array`]. Supports all types.
+    Count,
+    /// The sum of the non-null values of each group, of the same type as the values.
+    /// Integers wrap around on overflow. Supports all numeric types.
+    Sum,
+    /// The minimum of the non-null values of each group, of the same type as the values.
+    /// Supports booleans, numeric, binary and utf8 types.
+    Min,
+    /// The maximum of the non-null values of each group, of the same type as the values.
+    /// Supports booleans, numeric, binary and utf8 types.
+    Max,
+    /// The mean of the non-null values of each group, as a [`Float64Array`].
+    /// Supports all numeric types except decimals.
+    Mean,
+}
+
+/// A column to be aggregated by [`groupby`]
+#[derive(Clone, Debug)]
+pub struct Aggregation<'a> {
+    /// The values to aggregate
+    pub values: &'a dyn Array,
+    /// The function to aggregate the values of each group with
+    pub function: AggregateFunction,
+}
+
+fn is_numeric(primitive: PrimitiveType) -> bool {
+    use PrimitiveType::*;
+    !matches!(primitive, Int256 | Float16 | DaysMs | MonthDayNano)
+}
+
+fn not_supported(aggregation: &Aggregation) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "groupby does not support {:?} of type {:?}",
+        aggregation.function,
+        aggregation.values.data_type()
+    ))
+}
+
+/// Whether [`groupby`] supports aggregating values of `data_type` with `function`.
+pub fn can_aggregate(function: AggregateFunction, data_type: &DataType) -> bool {
+    use AggregateFunction::*;
+    match (function, data_type.to_physical_type()) {
+        (Count, _) => true,
+        (Sum, PhysicalType::Primitive(primitive)) => is_numeric(primitive),
+        (Mean, PhysicalType::Primitive(primitive)) => {
+            is_numeric(primitive) && !matches!(data_type.to_logical_type(), DataType::Decimal(_, _))
+        }
+        (Min | Max, PhysicalType::Primitive(primitive)) => is_numeric(primitive),
+        (
+            Min | Max,
+            PhysicalType::Boolean
+            | PhysicalType::Utf8
+            | PhysicalType::LargeUtf8
+            | PhysicalType::Binary
+            | PhysicalType::LargeBinary,
+        ) => true,
+        _ => false,
+    }
+}
+
+/// Groups the rows of `keys` by equality and aggregates the `aggregations` of each group.
+///
+/// Returns the keys of each group and the aggregated values of each group, one array per
+/// aggregation. Groups are ordered by their first occurrence in `keys`. Nulls are considered
+/// equal to each other, thus all rows whose keys are null form a single group.
+/// # Errors
+/// This function errors iff:
+/// * `keys` is empty
+/// * `keys` have more than `u32::MAX` rows
+/// * the keys and the values of the aggregations have different lengths
+/// * the type of any of the keys is not supported by [`RowConverter`]
+/// * any of the aggregations is not supported (see [`can_aggregate`])
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Float64Array, Int32Array, UInt64Array, Utf8Array};
+/// use arrow2::compute::groupby::{groupby, AggregateFunction, Aggregation};
+///
+/// let keys = Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("a"), None]);
+/// let values = Int32Array::from(&[Some(1), Some(2), Some(3), Some(4)]);
+///
+/// let (keys, values) = groupby(
+///     &[&keys as &dyn Array],
+///     &[
+///         Aggregation {
+///             values: &values,
+///             function: AggregateFunction::Sum,
+///         },
+///         Aggregation {
+///             values: &values,
+///             function: AggregateFunction::Mean,
+///         },
+///     ],
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     keys[0].as_ref(),
+///     &Utf8Array::<i32>::from(&[Some("a"), Some("b"), None]) as &dyn Array
+/// );
+/// assert_eq!(
+///     values[0].as_ref(),
+///     &Int32Array::from_slice(&[4, 2, 4]) as &dyn Array
+/// );
+/// assert_eq!(
+///     values[1].as_ref(),
+///     &Float64Array::from_slice(&[2.0, 2.0, 4.0]) as &dyn Array
+/// );
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn groupby<A: AsRef<dyn Array>>(
+    keys: &[A],
+    aggregations: &[Aggregation],
+) -> Result<(Vec<Box<dyn Array>>, Vec<Box<dyn Array>>)> {
+    let length = keys
+        .first()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError("groupby requires at least one key".to_string())
+        })?
+        .as_ref()
+        .len();
+    if length > u32::MAX as usize {
+        return Err(ArrowError::InvalidArgumentError(
+            "groupby supports at most u32::MAX rows".to_string(),
+        ));
+    }
+    if aggregations.iter().any(|x| x.values.len() != length) {
+        return Err(ArrowError::InvalidArgumentError(
+            "groupby requires the keys and the values to have the same length".to_string(),
+        ));
+    }
+    if let Some(aggregation) = aggregations
+        .iter()
+        .find(|x| !can_aggregate(x.function, x.values.data_type()))
+    {
+        return Err(not_supported(aggregation));
+    }
+
+    let (groups, first_indices) = group_indices(keys)?;
+    let num_groups = first_indices.len();
+
+    let first_indices = PrimitiveArray::<u32>::from_vec(first_indices);
+    let keys = keys
+        .iter()
+        .map(|key| take(key.as_ref(), &first_indices))
+        .collect::<Result<Vec<_>>>()?;
+
+    let values = aggregations
+        .iter()
+        .map(|aggregation| aggregate(aggregation, &groups, num_groups))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((keys, values))
+}
+
+/// Returns the group of each row and the index of the first row of each group.
+/// `keys` must have at most `u32::MAX` rows.
+fn group_indices<A: AsRef<dyn Array>>(keys: &[A]) -> Result<(Vec<u32>, Vec<u32>)> {
+    let fields = keys
+        .iter()
+        .map(|key| SortField::new(key.as_ref().data_type().clone()))
+        .collect();
+    let rows = RowConverter::new(fields)?.convert_columns(keys)?;
+
+    let mut map = HashMap::with_capacity_and_hasher(rows.len(), RandomState::new());
+    let mut first_indices = vec![];
+    let groups = rows
+        .iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let num_groups = first_indices.len() as u32;
+            match map.entry(row) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    first_indices.push(index as u32);
+                    *entry.insert(num_groups)
+                }
+            }
+        })
+        .collect();
+    Ok((groups, first_indices))
+}
+
+fn aggregate(
+    aggregation: &Aggregation,
+    groups: &[u32],
+    num_groups: usize,
+) -> Result<Box<dyn Array>> {
+    use AggregateFunction::*;
+    let array = aggregation.values;
+    Ok(
+        match (aggregation.function, array.data_type().to_physical_type()) {
+            (Count, _) => Box::new(count(array, groups, num_groups)),
+            (Sum, PhysicalType::Primitive(_)) => {
+                with_match_arithmetic_type!(array.data_type(), not_supported(aggregation), |$T| {
+                    Box::new(sum_primitive::<$T>(array.as_any().downcast_ref().unwrap(), groups, num_groups))
+                })
+            }
+            (Mean, PhysicalType::Primitive(_)) => {
+                with_match_arithmetic_type!(array.data_type(), not_supported(aggregation), |$T| {
+                    Box::new(mean_primitive::<$T>(array.as_any().downcast_ref().unwrap(), groups, num_groups))
+                })
+            }
+            (Min, PhysicalType::Primitive(_)) => {
+                with_match_arithmetic_type!(array.data_type(), not_supported(aggregation), |$T| {
+                    let array = array.as_any().downcast_ref().unwrap();
+                    Box::new(min_max_primitive::<$T, _>(array, groups, num_groups, |a, b| is_greater(b, a)))
+                })
+            }
+            (Max, PhysicalType::Primitive(_)) => {
+                with_match_arithmetic_type!(array.data_type(), not_supported(aggregation), |$T| {
+                    let array = array.as_any().downcast_ref().unwrap();
+                    Box::new(min_max_primitive::<$T, _>(array, groups, num_groups, is_greater))
+                })
+            }
+            (Min | Max, physical_type) => {
+                let is_max = aggregation.function == Max;
+                let indices = match physical_type {
+                    PhysicalType::Boolean => {
+                        let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                        min_max_indices(array.iter(), groups, num_groups, is_max)
+                    }
+                    PhysicalType::Utf8 => {
+                        let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+                        min_max_indices(array.iter(), groups, num_groups, is_max)
+                    }
+                    PhysicalType::LargeUtf8 => {
+                        let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+                        min_max_indices(array.iter(), groups, num_groups, is_max)
+                    }
+                    PhysicalType::Binary => {
+                        let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+                        min_max_indices(array.iter(), groups, num_groups, is_max)
+                    }
+                    PhysicalType::LargeBinary => {
+                        let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+                        min_max_indices(array.iter(), groups, num_groups, is_max)
+                    }
+                    _ => unreachable!(),
+                };
+                take(array, &indices)?
+            }
+            _ => unreachable!(),
+        },
+    )
+}
+
+fn count(array: &dyn Array, groups: &[u32], num_groups: usize) -> UInt64Array {
+    let mut counts = vec![0u64; num_groups];
+    if array.data_type().to_physical_type() == PhysicalType::Null {
+        return UInt64Array::from_vec(counts);
+    }
+    match array.validity() {
+        Some(validity) => groups
+            .iter()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| *is_valid)
+            .for_each(|(group, _)| counts[*group as usize] += 1),
+        None => groups.iter().for_each(|group| counts[*group as usize] += 1),
+    }
+    UInt64Array::from_vec(counts)
+}
+
+fn sum_primitive<T: CumulativeArithmetic>(
+    array: &PrimitiveArray<T>,
+    groups: &[u32],
+    num_groups: usize,
+) -> PrimitiveArray<T> {
+    let mut values = vec![T::default(); num_groups];
+    let mut validity = MutableBitmap::from_len_zeroed(num_groups);
+    array.iter().zip(groups.iter()).for_each(|(value, group)| {
+        if let Some(value) = value {
+            let group = *group as usize;
+            values[group] = values[group].wrapping_add(*value);
+            validity.set(group, true);
+        }
+    });
+    PrimitiveArray::new(array.data_type().clone(), values.into(), validity.into())
+}
+
+fn mean_primitive<T: NativeType + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+    groups: &[u32],
+    num_groups: usize,
+) -> Float64Array {
+    let mut sums = vec![0.0f64; num_groups];
+    let mut counts = vec![0usize; num_groups];
+    array.iter().zip(groups.iter()).for_each(|(value, group)| {
+        if let Some(value) = value {
+            let group = *group as usize;
+            sums[group] += value.as_();
+            counts[group] += 1;
+        }
+    });
+    let validity = counts
+        .iter()
+        .map(|count| *count > 0)
+        .collect::<MutableBitmap>();
+    let values = sums
+        .into_iter()
+        .zip(counts)
+        .map(|(sum, count)| if count > 0 { sum / count as f64 } else { 0.0 })
+        .collect::<Vec<_>>();
+    Float64Array::new(DataType::Float64, values.into(), validity.into())
+}
+
+fn min_max_primitive<T: NativeType + PartialOrd, F: Fn(T, T) -> bool>(
+    array: &PrimitiveArray<T>,
+    groups: &[u32],
+    num_groups: usize,
+    replace: F,
+) -> PrimitiveArray<T> {
+    let mut values = vec![T::default(); num_groups];
+    let mut validity = MutableBitmap::from_len_zeroed(num_groups);
+    array.iter().zip(groups.iter()).for_each(|(value, group)| {
+        if let Some(value) = value {
+            let group = *group as usize;
+            if !validity.get(group) || replace(*value, values[group]) {
+                values[group] = *value;
+                validity.set(group, true);
+            }
+        }
+    });
+    PrimitiveArray::new(array.data_type().clone(), values.into(), validity.into())
+}
+
+/// Returns the index of the minimum (or maximum) value of each group, null if the group
+/// has no valid values. `iter` must have at most `u32::MAX` items.
+fn min_max_indices<T: PartialOrd, I: Iterator<Item = Option<T>>>(
+    iter: I,
+    groups: &[u32],
+    num_groups: usize,
+    is_max: bool,
+) -> UInt32Array {
+    let mut best: Vec<Option<(T, u32)>> = (0..num_groups).map(|_| None).collect();
+    iter.zip(groups.iter())
+        .enumerate()
+        .for_each(|(index, (value, group))| {
+            if let Some(value) = value {
+                let best = &mut best[*group as usize];
+                let replace = match best {
+                    Some((current, _)) => (value > *current) == is_max && value != *current,
+                    None => true,
+                };
+                if replace {
+                    *best = Some((value, index as u32))
+                }
+            }
+        });
+    best.into_iter()
+        .map(|x| x.map(|(_, index)| index))
+        .collect()
+}
@@ -0,0 +1,500 @@
+//! Contains the [`hash`] and typed (e.g. [`hash_primitive`]) operators.
+//!
+//! The hash algorithm and its seed can be chosen via [`hash_with_options`], and the rows of
+//! a [`Chunk`] can be hashed with [`hash_chunk`].
+// multiversion does not copy documentation, causing a false positive
+#![allow(missing_docs)]
+use ahash::{CallHasher, RandomState};
+use multiversion::multiversion;
+use std::hash::Hash;
+
+macro_rules! new_state {
+    () => {
+        RandomState::with_seeds(0, 0, 0, 0)
+    };
+}
+
+use crate::{
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, FixedSizeListArray,
+        ListArray, MapArray, Offset, PrimitiveArray, StructArray, Utf8Array,
+    },
+    chunk::Chunk,
+    datatypes::{DataType, PhysicalType, PrimitiveType},
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+use super::arity::unary;
+
+mod murmur3;
+mod xxhash64;
+
+use murmur3::murmur3_32;
+pub(crate) use xxhash64::xxhash64;
+
+/// The algorithms supported by [`hash_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// [aHash](https://github.com/tkaitchuck/aHash), the fastest one. Its results depend on
+    /// the target (e.g. whether AES instructions are available) and on the version of `ahash`,
+    /// and thus must not be persisted nor shared with other systems.
+    AHash,
+    /// [XXH64](https://github.com/Cyan4973/xxHash), stable across platforms.
+    XxHash64,
+    /// The 32-bit (x86) variant of [MurmurHash3](https://github.com/aappleby/smhasher),
+    /// stable across platforms. Only the lower 32 bits of the seed are used, and the
+    /// 32-bit hash is zero-extended to 64 bits.
+    Murmur3,
+}
+
+/// Options of [`hash_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HashOptions {
+    /// The algorithm to hash with
+    pub algorithm: HashAlgorithm,
+    /// The seed of the algorithm
+    pub seed: u64,
+}
+
+impl Default for HashOptions {
+    /// The options of [`hash`], i.e. [`HashAlgorithm::AHash`] with a seed of `0`.
+    fn default() -> Self {
+        Self {
+            algorithm: HashAlgorithm::AHash,
+            seed: 0,
+        }
+    }
+}
+
+/// Hashes a single value of an array
+trait ValueHasher {
+    fn hash_primitive<T: NativeType + Hash>(&self, value: T) -> u64;
+
+    fn hash_bytes(&self, value: &[u8]) -> u64;
+
+    fn hash_boolean(&self, value: bool) -> u64;
+}
+
+impl ValueHasher for RandomState {
+    #[inline]
+    fn hash_primitive<T: NativeType + Hash>(&self, value: T) -> u64 {
+        T::get_hash(&value, self)
+    }
+
+    #[inline]
+    fn hash_bytes(&self, value: &[u8]) -> u64 {
+        <[u8]>::get_hash(&value, self)
+    }
+
+    #[inline]
+    fn hash_boolean(&self, value: bool) -> u64 {
+        u8::get_hash(&value, self)
+    }
+}
+
+/// Hashes values by their little-endian bytes with a portable algorithm
+struct Portable<F: Fn(&[u8]) -> u64>(F);
+
+impl<F: Fn(&[u8]) -> u64> ValueHasher for Portable<F> {
+    #[inline]
+    fn hash_primitive<T: NativeType + Hash>(&self, value: T) -> u64 {
+        (self.0)(value.to_le_bytes().as_ref())
+    }
+
+    #[inline]
+    fn hash_bytes(&self, value: &[u8]) -> u64 {
+        (self.0)(value)
+    }
+
+    #[inline]
+    fn hash_boolean(&self, value: bool) -> u64 {
+        (self.0)(&[value as u8])
+    }
+}
+
+fn primitive_with<T: NativeType + Hash, H: ValueHasher>(
+    array: &PrimitiveArray<T>,
+    hasher: &H,
+) -> PrimitiveArray<u64> {
+    unary(array, |x| hasher.hash_primitive(x), DataType::UInt64)
+}
+
+fn boolean_with<H: ValueHasher>(array: &BooleanArray, hasher: &H) -> PrimitiveArray<u64> {
+    let values = array
+        .values_iter()
+        .map(|x| hasher.hash_boolean(x))
+        .collect::<Vec<_>>()
+        .into();
+
+    PrimitiveArray::<u64>::new(DataType::UInt64, values, array.validity().cloned())
+}
+
+fn utf8_with<O: Offset, H: ValueHasher>(array: &Utf8Array<O>, hasher: &H) -> PrimitiveArray<u64> {
+    let values = array
+        .values_iter()
+        .map(|x| hasher.hash_bytes(x.as_bytes()))
+        .collect::<Vec<_>>()
+        .into();
+
+    PrimitiveArray::<u64>::new(DataType::UInt64, values, array.validity().cloned())
+}
+
+fn binary_with<O: Offset, H: ValueHasher>(
+    array: &BinaryArray<O>,
+    hasher: &H,
+) -> PrimitiveArray<u64> {
+    let values = array
+        .values_iter()
+        .map(|x| hasher.hash_bytes(x))
+        .collect::<Vec<_>>()
+        .into();
+
+    PrimitiveArray::<u64>::new(DataType::UInt64, values, array.validity().cloned())
+}
+
+#[multiversion]
+#[clone(target = "x86_64+aes+sse3+ssse3+avx+avx2")]
+/// Element-wise hash of a [`PrimitiveArray`]. Validity is preserved.
+pub fn hash_primitive<T: NativeType + Hash>(array: &PrimitiveArray<T>) -> PrimitiveArray<u64> {
+    primitive_with(array, &new_state!())
+}
+
+#[multiversion]
+#[clone(target = "x86_64+aes+sse3+ssse3+avx+avx2")]
+/// Element-wise hash of a [`BooleanArray`]. Validity is preserved.
+pub fn hash_boolean(array: &BooleanArray) -> PrimitiveArray<u64> {
+    boolean_with(array, &new_state!())
+}
+
+#[multiversion]
+#[clone(target = "x86_64+aes+sse3+ssse3+avx+avx2")]
+/// Element-wise hash of a [`Utf8Array`]. Validity is preserved.
+pub fn hash_utf8<O: Offset>(array: &Utf8Array<O>) -> PrimitiveArray<u64> {
+    utf8_with(array, &new_state!())
+}
+
+/// Element-wise hash of a [`BinaryArray`]. Validity is preserved.
+pub fn hash_binary<O: Offset>(array: &BinaryArray<O>) -> PrimitiveArray<u64> {
+    binary_with(array, &new_state!())
+}
+
+macro_rules! with_match_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    use crate::types::{days_ms, months_days_ns};
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        Int128 => __with_ty__! { i128 },
+        DaysMs => __with_ty__! { days_ms },
+        MonthDayNano => __with_ty__! { months_days_ns },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        _ => return Err(ArrowError::NotYetImplemented(format!(
+            "Hash not implemented for type {:?}",
+            $key_type
+        )))
+    }
+})}
+
+/// Returns the element-wise hash of an [`Array`]. Validity is preserved.
+/// Supported DataTypes:
+/// * Boolean types
+/// * All primitive types except `Float32` and `Float64`
+/// * `[Large]Utf8`;
+/// * `[Large]Binary`.
+///
+/// This is the same as [`hash_with_options`] with the default [`HashOptions`].
+/// # Errors
+/// This function errors whenever it does not support the specific `DataType`.
+pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => hash_boolean(array.as_any().downcast_ref().unwrap()),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            hash_primitive::<$T>(array.as_any().downcast_ref().unwrap())
+        }),
+        Binary => hash_binary::<i32>(array.as_any().downcast_ref().unwrap()),
+        LargeBinary => hash_binary::<i64>(array.as_any().downcast_ref().unwrap()),
+        Utf8 => hash_utf8::<i32>(array.as_any().downcast_ref().unwrap()),
+        LargeUtf8 => hash_utf8::<i64>(array.as_any().downcast_ref().unwrap()),
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Hash not implemented for type {:?}",
+                t
+            )))
+        }
+    })
+}
+
+fn hash_with<H: ValueHasher>(array: &dyn Array, hasher: &H) -> Result<PrimitiveArray<u64>> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => boolean_with(array.as_any().downcast_ref().unwrap(), hasher),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            primitive_with::<$T, _>(array.as_any().downcast_ref().unwrap(), hasher)
+        }),
+        Binary => binary_with::<i32, _>(array.as_any().downcast_ref().unwrap(), hasher),
+        LargeBinary => binary_with::<i64, _>(array.as_any().downcast_ref().unwrap(), hasher),
+        Utf8 => utf8_with::<i32, _>(array.as_any().downcast_ref().unwrap(), hasher),
+        LargeUtf8 => utf8_with::<i64, _>(array.as_any().downcast_ref().unwrap(), hasher),
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Hash not implemented for type {:?}",
+                t
+            )))
+        }
+    })
+}
+
+/// Returns the element-wise hash of an [`Array`] with the algorithm and seed of `options`.
+/// Validity is preserved.
+///
+/// With [`HashAlgorithm::XxHash64`] and [`HashAlgorithm::Murmur3`], each value is hashed by
+/// its bytes, so that results are stable across platforms and can be reproduced by other
+/// systems:
+/// * primitive types are hashed by their little-endian bytes (e.g. 4 bytes for a `Int32`);
+/// * booleans are hashed as a single byte, `0` or `1`;
+/// * `[Large]Utf8` and `[Large]Binary` are hashed by the bytes of each value.
+///
+/// The supported types are the ones of [`hash`].
+/// # Errors
+/// This function errors whenever it does not support the specific `DataType`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt64Array};
+/// use arrow2::compute::hash::{hash_with_options, HashAlgorithm, HashOptions};
+///
+/// let array = Int32Array::from(&[Some(1), None]);
+/// let options = HashOptions {
+///     algorithm: HashAlgorithm::Murmur3,
+///     seed: 42,
+/// };
+/// let result = hash_with_options(&array, &options).unwrap();
+/// assert_eq!(result, UInt64Array::from(&[Some(3735386339), None]));
+/// ```
+pub fn hash_with_options(array: &dyn Array, options: &HashOptions) -> Result<PrimitiveArray<u64>> {
+    let seed = options.seed;
+    match options.algorithm {
+        HashAlgorithm::AHash => hash_with(array, &RandomState::with_seeds(seed, seed, seed, seed)),
+        HashAlgorithm::XxHash64 => hash_with(array, &Portable(|x: &[u8]| xxhash64(x, seed))),
+        HashAlgorithm::Murmur3 => hash_with(
+            array,
+            &Portable(|x: &[u8]| murmur3_32(x, seed as u32) as u64),
+        ),
+    }
+}
+
+/// Checks if an array of type `datatype` can be used in [`hash`].
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::hash::can_hash;
+/// use arrow2::datatypes::{DataType};
+///
+/// let data_type = DataType::Int8;
+/// assert_eq!(can_hash(&data_type), true);
+///
+/// let data_type = DataType::Null;
+/// assert_eq!(can_hash(&data_type), false);
+/// ```
+pub fn can_hash(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::Boolean
+            | PhysicalType::Primitive(PrimitiveType::Int8)
+            | PhysicalType::Primitive(PrimitiveType::Int16)
+            | PhysicalType::Primitive(PrimitiveType::Int32)
+            | PhysicalType::Primitive(PrimitiveType::Int64)
+            | PhysicalType::Primitive(PrimitiveType::Int128)
+            | PhysicalType::Primitive(PrimitiveType::DaysMs)
+            | PhysicalType::Primitive(PrimitiveType::MonthDayNano)
+            | PhysicalType::Primitive(PrimitiveType::UInt8)
+            | PhysicalType::Primitive(PrimitiveType::UInt16)
+            | PhysicalType::Primitive(PrimitiveType::UInt32)
+            | PhysicalType::Primitive(PrimitiveType::UInt64)
+            | PhysicalType::Binary
+            | PhysicalType::LargeBinary
+            | PhysicalType::Utf8
+            | PhysicalType::LargeUtf8
+    )
+}
+
+/// The hash of a null row in [`hash_chunk`]
+const NULL_HASH: u64 = 0;
+
+/// Combines the hash of a row so far, `hash`, with the hash of its next `value`.
+#[inline]
+fn combine(hash: u64, value: u64) -> u64 {
+    hash ^ value
+        .wrapping_add(0x9e3779b97f4a7c15)
+        .wrapping_add(hash << 6)
+        .wrapping_add(hash >> 2)
+}
+
+fn combine_ranges<I: Iterator<Item = (usize, usize)>>(values: &[u64], ranges: I) -> Vec<u64> {
+    ranges
+        .map(|(start, end)| {
+            values[start..end]
+                .iter()
+                .fold(combine(0, (end - start) as u64), |hash, value| {
+                    combine(hash, *value)
+                })
+        })
+        .collect()
+}
+
+fn list_rows<O: Offset>(array: &dyn Array, options: &HashOptions) -> Result<Vec<u64>> {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let values = rows(array.values().as_ref(), options)?;
+    Ok(combine_ranges(
+        &values,
+        array
+            .offsets()
+            .windows(2)
+            .map(|w| (w[0].to_usize(), w[1].to_usize())),
+    ))
+}
+
+fn dictionary_rows<K: DictionaryKey>(array: &dyn Array, options: &HashOptions) -> Result<Vec<u64>> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = rows(array.values().as_ref(), options)?;
+    Ok(array
+        .keys()
+        .values()
+        .iter()
+        // null keys may point to any value (or none), and are set to `NULL_HASH` afterwards
+        .map(|key| {
+            key.to_usize()
+                .and_then(|key| values.get(key).copied())
+                .unwrap_or(NULL_HASH)
+        })
+        .collect())
+}
+
+/// Returns the hash of each row of `array`, where nulls are hashed to [`NULL_HASH`]
+fn rows(array: &dyn Array, options: &HashOptions) -> Result<Vec<u64>> {
+    use PhysicalType::*;
+    let mut hashes = match array.data_type().to_physical_type() {
+        Null => vec![NULL_HASH; array.len()],
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let mut hashes = vec![0; array.len()];
+            for field in array.values() {
+                rows(field.as_ref(), options)?
+                    .into_iter()
+                    .zip(hashes.iter_mut())
+                    .for_each(|(value, hash)| *hash = combine(*hash, value));
+            }
+            hashes
+        }
+        List => list_rows::<i32>(array, options)?,
+        LargeList => list_rows::<i64>(array, options)?,
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let size = FixedSizeListArray::get_child_and_size(array.data_type()).1;
+            let values = rows(array.values().as_ref(), options)?;
+            combine_ranges(
+                &values,
+                (0..array.len()).map(|i| (i * size, (i + 1) * size)),
+            )
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let values = rows(array.field().as_ref(), options)?;
+            combine_ranges(
+                &values,
+                array
+                    .offsets()
+                    .windows(2)
+                    .map(|w| (w[0] as usize, w[1] as usize)),
+            )
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            dictionary_rows::<$T>(array, options)?
+        }),
+        _ => hash_with_options(array, options)?.values().to_vec(),
+    };
+    if let Some(validity) = array.validity() {
+        hashes
+            .iter_mut()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| !is_valid)
+            .for_each(|(hash, _)| *hash = NULL_HASH);
+    }
+    Ok(hashes)
+}
+
+/// Returns the hash of each row of `chunk`, combining the hashes of its columns with the
+/// algorithm and seed of `options`. The result has no nulls.
+///
+/// The hash of a row is computed as follows:
+/// * the hash of each of its values is computed with [`hash_with_options`], and nulls are
+///   hashed to `0`;
+/// * the hash of a struct is the combination of the hashes of its fields, and the hash of a
+///   list (or map) is the combination of its length and the hashes of its values;
+/// * dictionaries are hashed by their values, i.e. as if they were not dictionary-encoded;
+/// * the hashes of the columns are combined, starting from `0`, by
+///   `hash ^ (value + 0x9e3779b97f4a7c15 + (hash << 6) + (hash >> 2))` (wrapping on overflow).
+///
+/// Equal rows thus have equal hashes, which makes this suitable for hash joins and for
+/// partitioning rows (e.g. via `hash % partitions`).
+/// The supported types are the ones of [`can_hash_chunk`].
+/// # Errors
+/// This function errors whenever it does not support the `DataType` of a column.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::hash::{hash_chunk, HashOptions};
+///
+/// let a = Int32Array::from(&[Some(1), None, Some(1)]);
+/// let b = Utf8Array::<i32>::from_slice(&["a", "b", "a"]);
+/// let chunk = Chunk::new(vec![Arc::new(a) as Arc<dyn Array>, Arc::new(b)]);
+///
+/// let result = hash_chunk(&chunk, &HashOptions::default()).unwrap();
+/// assert_eq!(result.value(0), result.value(2));
+/// assert_ne!(result.value(0), result.value(1));
+/// ```
+pub fn hash_chunk<A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+    options: &HashOptions,
+) -> Result<PrimitiveArray<u64>> {
+    let mut hashes = vec![0; chunk.len()];
+    for column in chunk.columns() {
+        rows(column.as_ref(), options)?
+            .into_iter()
+            .zip(hashes.iter_mut())
+            .for_each(|(value, hash)| *hash = combine(*hash, value));
+    }
+    Ok(PrimitiveArray::<u64>::new(
+        DataType::UInt64,
+        hashes.into(),
+        None,
+    ))
+}
+
+/// Checks if a column of type `data_type` can be used in [`hash_chunk`].
+///
+/// These are the types supported by [`can_hash`], `Null`, and structs, lists, maps and
+/// dictionaries of them.
+pub fn can_hash_chunk(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::Null => true,
+        DataType::Struct(fields) => fields.iter().all(|f| can_hash_chunk(f.data_type())),
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => can_hash_chunk(field.data_type()),
+        DataType::Dictionary(_, values, _) => can_hash_chunk(values.as_ref()),
+        _ => can_hash(data_type),
+    }
+}
@@ -0,0 +1,41 @@
+//! The 32-bit (x86) variant of the MurmurHash3 algorithm,
+//! see <https://github.com/aappleby/smhasher/blob/master/src/MurmurHash3.cpp>
+
+const C1: u32 = 0xcc9e2d51;
+const C2: u32 = 0x1b873593;
+
+#[inline]
+fn mix_k1(k1: u32) -> u32 {
+    k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2)
+}
+
+/// Returns the MurmurHash3 (x86, 32 bits) hash of `bytes` with `seed`.
+pub fn murmur3_32(bytes: &[u8], seed: u32) -> u32 {
+    let mut hash = seed;
+
+    let mut chunks = bytes.chunks_exact(4);
+    for chunk in &mut chunks {
+        let k1 = u32::from_le_bytes(chunk.try_into().unwrap());
+        hash ^= mix_k1(k1);
+        hash = hash
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe6546b64);
+    }
+
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let k1 = tail
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, byte)| acc | ((*byte as u32) << (8 * i)));
+        hash ^= mix_k1(k1);
+    }
+
+    hash ^= bytes.len() as u32;
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85ebca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2ae35);
+    hash ^ (hash >> 16)
+}
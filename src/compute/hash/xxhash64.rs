@@ -0,0 +1,87 @@
+//! The XXH64 algorithm, see <https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md>
+
+const PRIME_1: u64 = 0x9E3779B185EBCA87;
+const PRIME_2: u64 = 0xC2B2AE3D27D4EB4F;
+const PRIME_3: u64 = 0x165667B19E3779F9;
+const PRIME_4: u64 = 0x85EBCA77C2B2AE63;
+const PRIME_5: u64 = 0x27D4EB2F165667C5;
+
+#[inline]
+fn read_u64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes[..8].try_into().unwrap())
+}
+
+#[inline]
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes[..4].try_into().unwrap())
+}
+
+#[inline]
+fn round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(PRIME_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME_1)
+}
+
+#[inline]
+fn merge(acc: u64, value: u64) -> u64 {
+    (acc ^ round(0, value))
+        .wrapping_mul(PRIME_1)
+        .wrapping_add(PRIME_4)
+}
+
+/// Returns the XXH64 hash of `bytes` with `seed`.
+pub fn xxhash64(bytes: &[u8], seed: u64) -> u64 {
+    let mut remaining = bytes;
+
+    let mut hash = if bytes.len() >= 32 {
+        let mut v1 = seed.wrapping_add(PRIME_1).wrapping_add(PRIME_2);
+        let mut v2 = seed.wrapping_add(PRIME_2);
+        let mut v3 = seed;
+        let mut v4 = seed.wrapping_sub(PRIME_1);
+        while remaining.len() >= 32 {
+            v1 = round(v1, read_u64(remaining));
+            v2 = round(v2, read_u64(&remaining[8..]));
+            v3 = round(v3, read_u64(&remaining[16..]));
+            v4 = round(v4, read_u64(&remaining[24..]));
+            remaining = &remaining[32..];
+        }
+        let hash = v1
+            .rotate_left(1)
+            .wrapping_add(v2.rotate_left(7))
+            .wrapping_add(v3.rotate_left(12))
+            .wrapping_add(v4.rotate_left(18));
+        merge(merge(merge(merge(hash, v1), v2), v3), v4)
+    } else {
+        seed.wrapping_add(PRIME_5)
+    };
+
+    hash = hash.wrapping_add(bytes.len() as u64);
+
+    while remaining.len() >= 8 {
+        hash ^= round(0, read_u64(remaining));
+        hash = hash
+            .rotate_left(27)
+            .wrapping_mul(PRIME_1)
+            .wrapping_add(PRIME_4);
+        remaining = &remaining[8..];
+    }
+    if remaining.len() >= 4 {
+        hash ^= (read_u32(remaining) as u64).wrapping_mul(PRIME_1);
+        hash = hash
+            .rotate_left(23)
+            .wrapping_mul(PRIME_2)
+            .wrapping_add(PRIME_3);
+        remaining = &remaining[4..];
+    }
+    for byte in remaining {
+        hash ^= (*byte as u64).wrapping_mul(PRIME_5);
+        hash = hash.rotate_left(11).wrapping_mul(PRIME_1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME_3);
+    hash ^ (hash >> 32)
+}
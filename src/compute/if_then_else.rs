@@ -1,7 +1,11 @@
-//! Contains the operator [`if_then_else`].
+//! Contains the operator [`if_then_else`], and its variants where either branch is a [`Scalar`]
+//! (e.g. [`if_then_else_array_scalar`]).
 use crate::array::{growable, Array, BooleanArray};
 use crate::bitmap::utils::SlicesIterator;
 use crate::error::{ArrowError, Result};
+use crate::scalar::Scalar;
+
+use super::utils::scalar_to_array as to_array;
 
 /// Returns the values from `lhs` if the predicate is `true` or from the `rhs` if the predicate is false
 /// Returns `None` if the predicate is `None`.
@@ -28,48 +32,178 @@ pub fn if_then_else(
     lhs: &dyn Array,
     rhs: &dyn Array,
 ) -> Result<Box<dyn Array>> {
-    if lhs.data_type() != rhs.data_type() {
+    if_then_else_branches(
+        predicate,
+        Branch {
+            array: lhs,
+            is_scalar: false,
+        },
+        Branch {
+            array: rhs,
+            is_scalar: false,
+        },
+    )
+}
+
+/// A branch of [`if_then_else`]: either an array or a scalar, as an array of length 1
+struct Branch<'a> {
+    array: &'a dyn Array,
+    is_scalar: bool,
+}
+
+impl<'a> Branch<'a> {
+    fn extend(
+        &self,
+        growable: &mut dyn growable::Growable<'a>,
+        index: usize,
+        start: usize,
+        len: usize,
+    ) {
+        if self.is_scalar {
+            (0..len).for_each(|_| growable.extend(index, 0, 1));
+        } else {
+            growable.extend(index, start, len);
+        }
+    }
+}
+
+fn if_then_else_branches(
+    predicate: &BooleanArray,
+    lhs: Branch,
+    rhs: Branch,
+) -> Result<Box<dyn Array>> {
+    if lhs.array.data_type() != rhs.array.data_type() {
         return Err(ArrowError::InvalidArgumentError(format!(
             "If then else requires the arguments to have the same datatypes ({:?} != {:?})",
-            lhs.data_type(),
-            rhs.data_type()
+            lhs.array.data_type(),
+            rhs.array.data_type()
         )));
     }
-    if (lhs.len() != rhs.len()) | (lhs.len() != predicate.len()) {
+    let len = predicate.len();
+    if (!lhs.is_scalar && lhs.array.len() != len) | (!rhs.is_scalar && rhs.array.len() != len) {
         return Err(ArrowError::InvalidArgumentError(format!(
             "If then else requires all arguments to have the same length (predicate = {}, lhs = {}, rhs = {})",
-            predicate.len(),
-            lhs.len(),
-            rhs.len()
+            len,
+            lhs.array.len(),
+            rhs.array.len()
         )));
     }
 
-    let result = if predicate.null_count() > 0 {
-        let mut growable = growable::make_growable(&[lhs, rhs], true, lhs.len());
+    let use_validity = predicate.null_count() > 0;
+    let mut growable = growable::make_growable(&[lhs.array, rhs.array], use_validity, len);
+    let branches = [lhs, rhs];
+    if predicate.null_count() > 0 {
         for (i, v) in predicate.iter().enumerate() {
             match v {
-                Some(v) => growable.extend(!v as usize, i, 1),
+                Some(v) => branches[!v as usize].extend(growable.as_mut(), !v as usize, i, 1),
                 None => growable.extend_validity(1),
             }
         }
-        growable.as_box()
     } else {
-        let mut growable = growable::make_growable(&[lhs, rhs], false, lhs.len());
         let mut start_falsy = 0;
-        let mut total_len = 0;
         for (start, len) in SlicesIterator::new(predicate.values()) {
             if start != start_falsy {
-                growable.extend(1, start_falsy, start - start_falsy);
-                total_len += start - start_falsy;
+                branches[1].extend(growable.as_mut(), 1, start_falsy, start - start_falsy);
             };
-            growable.extend(0, start, len);
-            total_len += len;
+            branches[0].extend(growable.as_mut(), 0, start, len);
             start_falsy = start + len;
         }
-        if total_len != lhs.len() {
-            growable.extend(1, total_len, lhs.len() - total_len);
+        if start_falsy != len {
+            branches[1].extend(growable.as_mut(), 1, start_falsy, len - start_falsy);
         }
-        growable.as_box()
-    };
-    Ok(result)
+    }
+    Ok(growable.as_box())
+}
+
+/// Returns the values from `lhs` if the predicate is `true` or `rhs` if the predicate is false,
+/// i.e. `CASE WHEN predicate THEN lhs ELSE rhs END`.
+/// Returns `None` if the predicate is `None`.
+///
+/// This is the same as [`if_then_else`] with `rhs` repeated, without allocating it.
+/// # Errors
+/// Errors iff `lhs` and `rhs` have different data types, `lhs` and `predicate` have different
+/// lengths, or scalars of the data type are not supported.
+/// # Example
+/// ```rust
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::if_then_else::if_then_else_array_scalar;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let lhs = Int32Array::from_slice(&[1, 2, 3]);
+/// let rhs = PrimitiveScalar::<i32>::from(Some(0));
+/// let predicate = BooleanArray::from(&[Some(true), None, Some(false)]);
+/// let result = if_then_else_array_scalar(&predicate, &lhs, &rhs).unwrap();
+///
+/// assert_eq!(Int32Array::from(&[Some(1), None, Some(0)]), result.as_ref());
+/// ```
+pub fn if_then_else_array_scalar(
+    predicate: &BooleanArray,
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+) -> Result<Box<dyn Array>> {
+    let rhs = to_array(rhs)?;
+    if_then_else_branches(
+        predicate,
+        Branch {
+            array: lhs,
+            is_scalar: false,
+        },
+        Branch {
+            array: rhs.as_ref(),
+            is_scalar: true,
+        },
+    )
+}
+
+/// Returns `lhs` if the predicate is `true` or the values from `rhs` if the predicate is false.
+/// Returns `None` if the predicate is `None`.
+///
+/// This is the same as [`if_then_else`] with `lhs` repeated, without allocating it.
+/// # Errors
+/// Errors iff `lhs` and `rhs` have different data types, `rhs` and `predicate` have different
+/// lengths, or scalars of the data type are not supported.
+pub fn if_then_else_scalar_array(
+    predicate: &BooleanArray,
+    lhs: &dyn Scalar,
+    rhs: &dyn Array,
+) -> Result<Box<dyn Array>> {
+    let lhs = to_array(lhs)?;
+    if_then_else_branches(
+        predicate,
+        Branch {
+            array: lhs.as_ref(),
+            is_scalar: true,
+        },
+        Branch {
+            array: rhs,
+            is_scalar: false,
+        },
+    )
+}
+
+/// Returns `lhs` if the predicate is `true` or `rhs` if the predicate is false.
+/// Returns `None` if the predicate is `None`.
+///
+/// This is the same as [`if_then_else`] with `lhs` and `rhs` repeated, without allocating them.
+/// # Errors
+/// Errors iff `lhs` and `rhs` have different data types or scalars of the data type are not
+/// supported.
+pub fn if_then_else_scalar_scalar(
+    predicate: &BooleanArray,
+    lhs: &dyn Scalar,
+    rhs: &dyn Scalar,
+) -> Result<Box<dyn Array>> {
+    let lhs = to_array(lhs)?;
+    let rhs = to_array(rhs)?;
+    if_then_else_branches(
+        predicate,
+        Branch {
+            array: lhs.as_ref(),
+            is_scalar: true,
+        },
+        Branch {
+            array: rhs.as_ref(),
+            is_scalar: true,
+        },
+    )
 }
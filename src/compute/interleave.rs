@@ -0,0 +1,67 @@
+//! Defines the [`interleave`] kernel, that gathers values from multiple [`Array`]s into one.
+use crate::array::{growable::make_growable, Array};
+use crate::error::{ArrowError, Result};
+
+/// Returns a new [`Array`] with the values of `arrays` at `indices`, where each index is a pair
+/// `(array, row)` pointing to the value `row` of `arrays[array]`.
+///
+/// This is equivalent to, but faster than, taking `indices` from the concatenation of
+/// `arrays`, since runs of consecutive rows of the same array are copied together.
+/// The result has nulls iff any of the gathered values is null.
+/// # Errors
+/// Errors iff:
+/// * `arrays` is empty
+/// * the arrays do not have the same [`DataType`](crate::datatypes::DataType)
+/// * an index is out of bounds
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::interleave::interleave;
+///
+/// let a = Int32Array::from_slice(&[1, 2, 3]);
+/// let b = Int32Array::from(&[Some(4), None]);
+///
+/// let result = interleave(&[&a, &b], &[(1, 0), (0, 2), (0, 0), (1, 1)]).unwrap();
+/// let expected = Int32Array::from(&[Some(4), Some(3), Some(1), None]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn interleave(arrays: &[&dyn Array], indices: &[(usize, usize)]) -> Result<Box<dyn Array>> {
+    let first = arrays.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("interleave requires at least one array".to_string())
+    })?;
+    if arrays.iter().any(|x| x.data_type() != first.data_type()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "interleave requires all arrays to have the same data type".to_string(),
+        ));
+    }
+    if let Some((array, row)) = indices
+        .iter()
+        .find(|(array, row)| !matches!(arrays.get(*array), Some(x) if *row < x.len()))
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "interleave index ({}, {}) is out of bounds",
+            array, row
+        )));
+    }
+
+    let mut growable = make_growable(arrays, false, indices.len());
+
+    // extend by runs of consecutive rows of the same array
+    let mut iter = indices.iter();
+    if let Some(&(mut array, mut start)) = iter.next() {
+        let mut len = 1;
+        for &(next_array, next_row) in iter {
+            if next_array == array && next_row == start + len {
+                len += 1;
+            } else {
+                growable.extend(array, start, len);
+                array = next_array;
+                start = next_row;
+                len = 1;
+            }
+        }
+        growable.extend(array, start, len);
+    }
+
+    Ok(growable.as_box())
+}
@@ -1,5 +1,10 @@
 //! Contains "like" operators such as [`like_utf8`] and [`like_utf8_scalar`].
+//!
+//! The operators suffixed with `_with_escape` (e.g. [`like_utf8_scalar_with_escape`]) follow the
+//! semantics of SQL's `LIKE ... ESCAPE ...`: every character other than the wildcards and the
+//! escape character matches itself, and the escape character makes the next one match itself.
 use std::collections::HashMap;
+use std::hash::Hash;
 
 use regex::bytes::Regex as BytesRegex;
 use regex::Regex;
@@ -10,6 +15,7 @@ use crate::{
     compute::utils::combine_validities,
     datatypes::DataType,
     error::{ArrowError, Result},
+    trusted_len::TrustedLen,
 };
 
 #[inline]
@@ -323,3 +329,357 @@ pub fn like_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> Result
 pub fn nlike_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> Result<BooleanArray> {
     a_like_binary_scalar(lhs, rhs, |x| !x)
 }
+
+/// A token of a LIKE pattern
+enum Token {
+    /// a sequence of bytes that matches itself, and its regex
+    Literal(Vec<u8>, String),
+    /// `_`
+    One,
+    /// `%`
+    Many,
+}
+
+fn push_literal(tokens: &mut Vec<Token>, bytes: &[u8], regex: &str) {
+    if let Some(Token::Literal(literal, literal_regex)) = tokens.last_mut() {
+        literal.extend_from_slice(bytes);
+        literal_regex.push_str(regex);
+    } else {
+        tokens.push(Token::Literal(bytes.to_vec(), regex.to_string()))
+    }
+}
+
+fn tokenize<T: Copy + PartialEq, I: Iterator<Item = T>, F: Fn(&mut Vec<Token>, T)>(
+    mut pattern: I,
+    escape: Option<T>,
+    percent: T,
+    underscore: T,
+    push: F,
+) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    while let Some(c) = pattern.next() {
+        if Some(c) == escape {
+            let c = pattern.next().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(
+                    "LIKE pattern must not end with the escape character".to_string(),
+                )
+            })?;
+            push(&mut tokens, c)
+        } else if c == percent {
+            tokens.push(Token::Many)
+        } else if c == underscore {
+            tokens.push(Token::One)
+        } else {
+            push(&mut tokens, c)
+        }
+    }
+    Ok(tokens)
+}
+
+fn tokenize_utf8(pattern: &str, escape: Option<char>) -> Result<Vec<Token>> {
+    tokenize(pattern.chars(), escape, '%', '_', |tokens, c| {
+        let mut buffer = [0; 4];
+        let c = c.encode_utf8(&mut buffer);
+        push_literal(tokens, c.as_bytes(), &regex::escape(c))
+    })
+}
+
+fn tokenize_binary(pattern: &[u8], escape: Option<u8>) -> Result<Vec<Token>> {
+    tokenize(pattern.iter().copied(), escape, b'%', b'_', |tokens, c| {
+        push_literal(tokens, &[c], &format!("\\x{:02x}", c))
+    })
+}
+
+/// Matches values against a LIKE pattern, avoiding regexes when the pattern is a literal,
+/// a prefix or a suffix.
+enum Matcher {
+    Equals(Vec<u8>),
+    StartsWith(Vec<u8>),
+    EndsWith(Vec<u8>),
+    Regex(BytesRegex),
+}
+
+impl Matcher {
+    /// `unicode` declares whether `_` matches a (UTF-8 encoded) character or a byte.
+    fn try_new(mut tokens: Vec<Token>, unicode: bool) -> Result<Self> {
+        Ok(match tokens.as_mut_slice() {
+            [] => Self::Equals(vec![]),
+            [Token::Literal(literal, _)] => Self::Equals(std::mem::take(literal)),
+            [Token::Literal(literal, _), Token::Many] => Self::StartsWith(std::mem::take(literal)),
+            [Token::Many, Token::Literal(literal, _)] => Self::EndsWith(std::mem::take(literal)),
+            _ => {
+                let mut regex = if unicode { "(?s)^" } else { "(?s-u)^" }.to_string();
+                tokens.iter().for_each(|token| match token {
+                    Token::Literal(_, literal) => regex.push_str(literal),
+                    Token::One => regex.push('.'),
+                    Token::Many => regex.push_str(".*"),
+                });
+                regex.push('$');
+                Self::Regex(BytesRegex::new(&regex).map_err(|e| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Unable to build regex from LIKE pattern: {}",
+                        e
+                    ))
+                })?)
+            }
+        })
+    }
+
+    #[inline]
+    fn is_match(&self, value: &[u8]) -> bool {
+        match self {
+            Self::Equals(literal) => value == literal.as_slice(),
+            Self::StartsWith(prefix) => value.starts_with(prefix),
+            Self::EndsWith(suffix) => value.ends_with(suffix),
+            Self::Regex(regex) => regex.is_match(value),
+        }
+    }
+}
+
+fn like_with_escape<'a, P, L, R, C, F>(
+    lhs: L,
+    rhs: R,
+    validity: Option<Bitmap>,
+    compile: C,
+    op: F,
+) -> Result<BooleanArray>
+where
+    P: Hash + Eq + ?Sized + 'a,
+    L: TrustedLen<Item = &'a [u8]>,
+    R: TrustedLen<Item = Option<&'a P>>,
+    C: Fn(&P) -> Result<Matcher>,
+    F: Fn(bool) -> bool,
+{
+    let mut map = HashMap::new();
+    let values = lhs.zip(rhs).map(|(lhs, pattern)| match pattern {
+        Some(pattern) => {
+            let matcher = if let Some(matcher) = map.get(pattern) {
+                matcher
+            } else {
+                map.insert(pattern, compile(pattern)?);
+                map.get(pattern).unwrap()
+            };
+            Result::Ok(op(matcher.is_match(lhs)))
+        }
+        None => Ok(false),
+    });
+    let values = Bitmap::try_from_trusted_len_iter(values)?;
+    Ok(BooleanArray::new(DataType::Boolean, values, validity))
+}
+
+fn check_same_len(lhs: usize, rhs: usize) -> Result<()> {
+    if lhs != rhs {
+        return Err(ArrowError::InvalidArgumentError(
+            "Cannot perform comparison operation on arrays of different length".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns `lhs LIKE rhs ESCAPE escape` operation on two [`Utf8Array`].
+///
+/// There are two wildcards supported:
+///
+/// * `%` - The percent sign represents zero, one, or multiple characters
+/// * `_` - The underscore represents a single character
+///
+/// Any other character matches itself, and `escape` (if any) makes the character following it
+/// match itself, e.g. `\\%` matches `%` when `escape` is `\\`.
+/// # Error
+/// Errors iff:
+/// * the arrays have a different length
+/// * any of the patterns ends with `escape`
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::like_utf8_with_escape;
+///
+/// let strings = Utf8Array::<i32>::from_slice(&["10%", "100", "a.c", "abc"]);
+/// let patterns = Utf8Array::<i32>::from_slice(&["10!%", "10!%", "a.c", "a.c"]);
+///
+/// let result = like_utf8_with_escape(&strings, &patterns, Some('!')).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false, true, false]));
+/// ```
+pub fn like_utf8_with_escape<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    escape: Option<char>,
+) -> Result<BooleanArray> {
+    a_like_utf8_with_escape(lhs, rhs, escape, |x| x)
+}
+
+/// Returns `lhs NOT LIKE rhs ESCAPE escape` operation on two [`Utf8Array`].
+///
+/// See [`like_utf8_with_escape`] for the supported patterns.
+pub fn nlike_utf8_with_escape<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    escape: Option<char>,
+) -> Result<BooleanArray> {
+    a_like_utf8_with_escape(lhs, rhs, escape, |x| !x)
+}
+
+fn a_like_utf8_with_escape<O: Offset, F: Fn(bool) -> bool>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    escape: Option<char>,
+    op: F,
+) -> Result<BooleanArray> {
+    check_same_len(lhs.len(), rhs.len())?;
+    like_with_escape(
+        lhs.values_iter().map(|x| x.as_bytes()),
+        rhs.iter(),
+        combine_validities(lhs.validity(), rhs.validity()),
+        |pattern: &str| Matcher::try_new(tokenize_utf8(pattern, escape)?, true),
+        op,
+    )
+}
+
+/// Returns `lhs LIKE rhs ESCAPE escape` operation.
+///
+/// See [`like_utf8_with_escape`] for the supported patterns.
+/// # Error
+/// Errors iff the pattern ends with `escape`
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::like_utf8_scalar_with_escape;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["a_1", "ab1", "a_"]);
+///
+/// let result = like_utf8_scalar_with_escape(&array, r"a\_%", Some('\\')).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false, true]));
+/// ```
+pub fn like_utf8_scalar_with_escape<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &str,
+    escape: Option<char>,
+) -> Result<BooleanArray> {
+    a_like_utf8_scalar_with_escape(lhs, rhs, escape, |x| x)
+}
+
+/// Returns `lhs NOT LIKE rhs ESCAPE escape` operation.
+///
+/// See [`like_utf8_with_escape`] for the supported patterns.
+pub fn nlike_utf8_scalar_with_escape<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &str,
+    escape: Option<char>,
+) -> Result<BooleanArray> {
+    a_like_utf8_scalar_with_escape(lhs, rhs, escape, |x| !x)
+}
+
+fn a_like_utf8_scalar_with_escape<O: Offset, F: Fn(bool) -> bool>(
+    lhs: &Utf8Array<O>,
+    rhs: &str,
+    escape: Option<char>,
+    op: F,
+) -> Result<BooleanArray> {
+    let matcher = Matcher::try_new(tokenize_utf8(rhs, escape)?, true)?;
+    let values = lhs
+        .values_iter()
+        .map(|x| op(matcher.is_match(x.as_bytes())));
+    Ok(BooleanArray::new(
+        DataType::Boolean,
+        Bitmap::from_trusted_len_iter(values),
+        lhs.validity().cloned(),
+    ))
+}
+
+/// Returns `lhs LIKE rhs ESCAPE escape` operation on two [`BinaryArray`].
+///
+/// There are two wildcards supported:
+///
+/// * `%` - The percent sign represents zero, one, or multiple bytes
+/// * `_` - The underscore represents a single byte
+///
+/// Any other byte matches itself, and `escape` (if any) makes the byte following it
+/// match itself.
+/// # Error
+/// Errors iff:
+/// * the arrays have a different length
+/// * any of the patterns ends with `escape`
+/// # Example
+/// ```
+/// use arrow2::array::{BinaryArray, BooleanArray};
+/// use arrow2::compute::like::like_binary_with_escape;
+///
+/// let values = BinaryArray::<i32>::from_slice(&[b"a%\xff", b"ab\xff"]);
+/// let patterns = BinaryArray::<i32>::from_slice(&[b"a#%_", b"a#%_"]);
+///
+/// let result = like_binary_with_escape(&values, &patterns, Some(b'#')).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false]));
+/// ```
+pub fn like_binary_with_escape<O: Offset>(
+    lhs: &BinaryArray<O>,
+    rhs: &BinaryArray<O>,
+    escape: Option<u8>,
+) -> Result<BooleanArray> {
+    a_like_binary_with_escape(lhs, rhs, escape, |x| x)
+}
+
+/// Returns `lhs NOT LIKE rhs ESCAPE escape` operation on two [`BinaryArray`].
+///
+/// See [`like_binary_with_escape`] for the supported patterns.
+pub fn nlike_binary_with_escape<O: Offset>(
+    lhs: &BinaryArray<O>,
+    rhs: &BinaryArray<O>,
+    escape: Option<u8>,
+) -> Result<BooleanArray> {
+    a_like_binary_with_escape(lhs, rhs, escape, |x| !x)
+}
+
+fn a_like_binary_with_escape<O: Offset, F: Fn(bool) -> bool>(
+    lhs: &BinaryArray<O>,
+    rhs: &BinaryArray<O>,
+    escape: Option<u8>,
+    op: F,
+) -> Result<BooleanArray> {
+    check_same_len(lhs.len(), rhs.len())?;
+    like_with_escape(
+        lhs.values_iter(),
+        rhs.iter(),
+        combine_validities(lhs.validity(), rhs.validity()),
+        |pattern: &[u8]| Matcher::try_new(tokenize_binary(pattern, escape)?, false),
+        op,
+    )
+}
+
+/// Returns `lhs LIKE rhs ESCAPE escape` operation.
+///
+/// See [`like_binary_with_escape`] for the supported patterns.
+/// # Error
+/// Errors iff the pattern ends with `escape`
+pub fn like_binary_scalar_with_escape<O: Offset>(
+    lhs: &BinaryArray<O>,
+    rhs: &[u8],
+    escape: Option<u8>,
+) -> Result<BooleanArray> {
+    a_like_binary_scalar_with_escape(lhs, rhs, escape, |x| x)
+}
+
+/// Returns `lhs NOT LIKE rhs ESCAPE escape` operation.
+///
+/// See [`like_binary_with_escape`] for the supported patterns.
+pub fn nlike_binary_scalar_with_escape<O: Offset>(
+    lhs: &BinaryArray<O>,
+    rhs: &[u8],
+    escape: Option<u8>,
+) -> Result<BooleanArray> {
+    a_like_binary_scalar_with_escape(lhs, rhs, escape, |x| !x)
+}
+
+fn a_like_binary_scalar_with_escape<O: Offset, F: Fn(bool) -> bool>(
+    lhs: &BinaryArray<O>,
+    rhs: &[u8],
+    escape: Option<u8>,
+    op: F,
+) -> Result<BooleanArray> {
+    let matcher = Matcher::try_new(tokenize_binary(rhs, escape)?, false)?;
+    let values = lhs.values_iter().map(|x| op(matcher.is_match(x)));
+    Ok(BooleanArray::new(
+        DataType::Boolean,
+        Bitmap::from_trusted_len_iter(values),
+        lhs.validity().cloned(),
+    ))
+}
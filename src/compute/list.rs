@@ -0,0 +1,351 @@
+//! Contains operators over \[Large\]List arrays, such as [`explode`], [`flatten`] and
+//! [`list_slice`].
+use crate::{
+    array::{Array, ListArray, Offset, UInt64Array},
+    bitmap::{Bitmap, MutableBitmap},
+    compute::take::take,
+    datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
+};
+
+fn explode_list<O: Offset>(array: &ListArray<O>) -> Result<(Box<dyn Array>, UInt64Array)> {
+    let offsets = array.offsets();
+    let values = array.values();
+
+    // null and empty lists take a (null) row of their own, so the values can only be sliced
+    // when every list is valid and non-empty
+    let is_contiguous = offsets
+        .windows(2)
+        .enumerate()
+        .all(|(row, window)| window[0] < window[1] && array.is_valid(row));
+
+    if is_contiguous {
+        let mut indices = Vec::with_capacity(values.len());
+        offsets.windows(2).enumerate().for_each(|(row, window)| {
+            indices.resize(
+                indices.len() + (window[1] - window[0]).to_usize(),
+                row as u64,
+            )
+        });
+        let start = offsets[0].to_usize();
+        let end = offsets[offsets.len() - 1].to_usize();
+        return Ok((
+            values.slice(start, end - start),
+            UInt64Array::from_vec(indices),
+        ));
+    }
+
+    let mut indices = Vec::with_capacity(values.len());
+    let mut value_indices = Vec::with_capacity(values.len());
+    offsets.windows(2).enumerate().for_each(|(row, window)| {
+        let (start, end) = (window[0].to_usize(), window[1].to_usize());
+        if start == end || !array.is_valid(row) {
+            indices.push(row as u64);
+            value_indices.push(None);
+        } else {
+            indices.resize(indices.len() + end - start, row as u64);
+            value_indices.extend((start..end).map(|index| Some(index as u64)));
+        }
+    });
+    let values = take(values.as_ref(), &UInt64Array::from(value_indices))?;
+    Ok((values, UInt64Array::from_vec(indices)))
+}
+
+/// Flattens a \[Large\]List array into one row per element of its lists.
+///
+/// Returns the exploded values and, for each of them, the index of the list it comes from,
+/// so that the other columns of a [`Chunk`](crate::chunk::Chunk) can be replicated with
+/// [`take`]. Null and empty lists are kept as a single null value, i.e. every row of
+/// `array` contributes at least one row to the result.
+///
+/// When every list is valid and non-empty, the values are a (zero-copy) slice of the
+/// values of `array`.
+/// # Errors
+/// This function errors when `array` is not a \[Large\]List array.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, MutableListArray, MutablePrimitiveArray, ListArray, TryExtend, UInt64Array};
+/// use arrow2::compute::list::explode;
+///
+/// let data = vec![
+///     Some(vec![Some(1), Some(2)]),
+///     None,
+///     Some(vec![]),
+///     Some(vec![Some(3)]),
+/// ];
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(data).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let (values, indices) = explode(&array).unwrap();
+/// let expected = Int32Array::from(&[Some(1), Some(2), None, None, Some(3)]);
+/// assert_eq!(values.as_ref(), &expected as &dyn Array);
+/// assert_eq!(indices, UInt64Array::from_slice(&[0, 0, 1, 2, 3]));
+/// ```
+pub fn explode(array: &dyn Array) -> Result<(Box<dyn Array>, UInt64Array)> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::List => {
+            explode_list(array.as_any().downcast_ref::<ListArray<i32>>().unwrap())
+        }
+        PhysicalType::LargeList => {
+            explode_list(array.as_any().downcast_ref::<ListArray<i64>>().unwrap())
+        }
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "explode does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Policy of [`flatten`] for null inner lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlattenNulls {
+    /// Null inner lists are treated as empty lists.
+    Skip,
+    /// A list is null when any of its inner lists is null.
+    Propagate,
+}
+
+fn flatten_list<O: Offset, I: Offset>(
+    array: &ListArray<O>,
+    nulls: FlattenNulls,
+) -> Result<Box<dyn Array>> {
+    let inner = array
+        .values()
+        .as_any()
+        .downcast_ref::<ListArray<I>>()
+        .unwrap();
+    let outer_offsets = array.offsets();
+    let inner_offsets = inner.offsets();
+
+    let validity = match nulls {
+        FlattenNulls::Skip => array.validity().cloned(),
+        FlattenNulls::Propagate => {
+            let validity = outer_offsets
+                .windows(2)
+                .enumerate()
+                .map(|(row, window)| {
+                    array.is_valid(row)
+                        && (window[0].to_usize()..window[1].to_usize()).all(|i| inner.is_valid(i))
+                })
+                .collect::<MutableBitmap>();
+            let validity: Bitmap = validity.into();
+            if validity.null_count() > 0 {
+                Some(validity)
+            } else {
+                None
+            }
+        }
+    };
+
+    let child = ListArray::<I>::get_child_field(inner.data_type()).clone();
+    let data_type = if I::is_large() {
+        DataType::LargeList(Box::new(child))
+    } else {
+        DataType::List(Box::new(child))
+    };
+
+    // the values of null inner lists are not part of the result; unless there are any, the
+    // offsets can be merged and the values re-used
+    let start = outer_offsets[0].to_usize();
+    let end = outer_offsets[outer_offsets.len() - 1].to_usize();
+    let is_contiguous =
+        (start..end).all(|i| inner.is_valid(i) || inner_offsets[i] == inner_offsets[i + 1]);
+    if is_contiguous {
+        let offsets = outer_offsets
+            .iter()
+            .map(|offset| inner_offsets[offset.to_usize()])
+            .collect::<Vec<_>>();
+        return Ok(Box::new(ListArray::<I>::new(
+            data_type,
+            offsets.into(),
+            inner.values().clone(),
+            validity,
+        )));
+    }
+
+    let mut offsets = Vec::with_capacity(outer_offsets.len());
+    offsets.push(I::zero());
+    let mut indices = Vec::with_capacity(inner.values().len());
+    outer_offsets.windows(2).for_each(|window| {
+        (window[0].to_usize()..window[1].to_usize())
+            .filter(|i| inner.is_valid(*i))
+            .for_each(|i| {
+                let (start, end) = (inner_offsets[i].to_usize(), inner_offsets[i + 1].to_usize());
+                indices.extend((start..end).map(|index| index as u64));
+            });
+        // the result has fewer values than `inner`, so its length fits `I`
+        offsets.push(I::from_usize(indices.len()).unwrap());
+    });
+    let values = take(inner.values().as_ref(), &UInt64Array::from_vec(indices))?;
+    Ok(Box::new(ListArray::<I>::new(
+        data_type,
+        offsets.into(),
+        values.into(),
+        validity,
+    )))
+}
+
+fn flatten_outer<O: Offset>(array: &dyn Array, nulls: FlattenNulls) -> Result<Box<dyn Array>> {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    match array.values().data_type().to_physical_type() {
+        PhysicalType::List => flatten_list::<O, i32>(array, nulls),
+        PhysicalType::LargeList => flatten_list::<O, i64>(array, nulls),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "flatten requires a list of lists, but got {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Flattens one level of nesting of a \[Large\]List array of \[Large\]List arrays, i.e.
+/// concatenates the inner lists of each list.
+///
+/// Null lists remain null, and null inner lists are handled according to `nulls`. The
+/// result has the offset type of the inner lists. Unless a null inner list contains values,
+/// the offsets are merged and the values of the inner lists are re-used (zero-copy).
+/// # Errors
+/// This function errors when `array` is not a \[Large\]List array of \[Large\]List arrays.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, ListArray};
+/// use arrow2::buffer::Buffer;
+/// use arrow2::compute::list::{flatten, FlattenNulls};
+/// use arrow2::datatypes::DataType;
+/// use std::sync::Arc;
+///
+/// let values = Arc::new(Int32Array::from_slice(&[1, 2, 3]));
+/// let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+/// // [[1, 2], null, [3]]
+/// let inner = ListArray::<i32>::from_data(data_type.clone(), Buffer::from(vec![0, 2, 2, 3]), values, Some([true, false, true].into()));
+/// // [[[1, 2], null], [[3]]]
+/// let data_type = ListArray::<i32>::default_datatype(data_type);
+/// let array = ListArray::<i32>::from_data(data_type, Buffer::from(vec![0, 2, 3]), Arc::new(inner), None);
+///
+/// let result = flatten(&array, FlattenNulls::Skip).unwrap();
+/// let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+/// assert_eq!(result.value(0).as_ref(), &Int32Array::from_slice(&[1, 2]) as &dyn Array);
+/// assert_eq!(result.value(1).as_ref(), &Int32Array::from_slice(&[3]) as &dyn Array);
+///
+/// let result = flatten(&array, FlattenNulls::Propagate).unwrap();
+/// assert!(result.is_null(0));
+/// ```
+pub fn flatten(array: &dyn Array, nulls: FlattenNulls) -> Result<Box<dyn Array>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::List => flatten_outer::<i32>(array, nulls),
+        PhysicalType::LargeList => flatten_outer::<i64>(array, nulls),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "flatten does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+fn list_slice_list<O: Offset>(
+    array: &ListArray<O>,
+    start: i64,
+    length: Option<usize>,
+) -> Result<Box<dyn Array>> {
+    let ranges = array
+        .offsets()
+        .windows(2)
+        .map(|window| {
+            let (offset, len) = (window[0].to_usize(), (window[1] - window[0]).to_usize());
+            let begin = if start >= 0 {
+                (start as usize).min(len)
+            } else {
+                len.saturating_sub(start.unsigned_abs() as usize)
+            };
+            let end = length.map_or(len, |length| begin.saturating_add(length).min(len));
+            (offset + begin, offset + end)
+        })
+        .collect::<Vec<_>>();
+
+    // when every slice ends where the next one begins, the values can be re-used
+    let is_contiguous = ranges.windows(2).all(|window| window[0].1 == window[1].0);
+    if is_contiguous {
+        let first = ranges
+            .first()
+            .map_or(array.offsets()[0], |range| O::from_usize(range.0).unwrap());
+        let offsets = std::iter::once(first)
+            .chain(ranges.iter().map(|range| O::from_usize(range.1).unwrap()))
+            .collect::<Vec<_>>();
+        return Ok(Box::new(ListArray::<O>::new(
+            array.data_type().clone(),
+            offsets.into(),
+            array.values().clone(),
+            array.validity().cloned(),
+        )));
+    }
+
+    let mut offsets = Vec::with_capacity(ranges.len() + 1);
+    offsets.push(O::zero());
+    let mut indices = Vec::with_capacity(array.values().len());
+    ranges
+        .into_iter()
+        .enumerate()
+        .for_each(|(row, (start, end))| {
+            if array.is_valid(row) {
+                indices.extend((start..end).map(|index| index as u64));
+            }
+            // the result has fewer values than `array`, so its length fits `O`
+            offsets.push(O::from_usize(indices.len()).unwrap());
+        });
+    let values = take(array.values().as_ref(), &UInt64Array::from_vec(indices))?;
+    Ok(Box::new(ListArray::<O>::new(
+        array.data_type().clone(),
+        offsets.into(),
+        values.into(),
+        array.validity().cloned(),
+    )))
+}
+
+/// Slices every list of a \[Large\]List array, keeping at most `length` items starting at
+/// `start` (or all of the remaining items when `length` is `None`).
+///
+/// A negative `start` counts from the end of each list, e.g. `-2` keeps its last two items.
+/// Slices beyond the bounds of a list are clamped to it, and null lists remain null.
+/// When the slices are contiguous (e.g. `start` is `0` and no list is longer than `length`),
+/// the values of `array` are re-used (zero-copy).
+/// # Errors
+/// This function errors when `array` is not a \[Large\]List array.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_slice;
+///
+/// let data = vec![Some(vec![Some(1), Some(2), Some(3)]), None, Some(vec![Some(4)])];
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(data).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// // the first two items of every list
+/// let result = list_slice(&array, 0, Some(2)).unwrap();
+/// let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+/// assert_eq!(result.value(0).as_ref(), &Int32Array::from_slice(&[1, 2]) as &dyn Array);
+/// assert!(result.is_null(1));
+/// assert_eq!(result.value(2).as_ref(), &Int32Array::from_slice(&[4]) as &dyn Array);
+///
+/// // the last item of every list
+/// let result = list_slice(&array, -1, None).unwrap();
+/// let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+/// assert_eq!(result.value(0).as_ref(), &Int32Array::from_slice(&[3]) as &dyn Array);
+/// ```
+pub fn list_slice(array: &dyn Array, start: i64, length: Option<usize>) -> Result<Box<dyn Array>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::List => list_slice_list(
+            array.as_any().downcast_ref::<ListArray<i32>>().unwrap(),
+            start,
+            length,
+        ),
+        PhysicalType::LargeList => list_slice_list(
+            array.as_any().downcast_ref::<ListArray<i64>>().unwrap(),
+            start,
+            length,
+        ),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "list_slice does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
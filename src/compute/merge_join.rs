@@ -0,0 +1,194 @@
+//! Defines the [`merge_join`] kernel, that computes the indices of the rows matched by a join
+//! between two sides sorted by their keys.
+use std::cmp::Ordering;
+
+use crate::array::{ord, Array, MutablePrimitiveArray, PrimitiveArray};
+use crate::compute::sort::SortOptions;
+use crate::error::{ArrowError, Result};
+use crate::types::Index;
+
+/// The type of join computed by [`merge_join`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinType {
+    /// Only the pairs of rows whose keys are equal
+    Inner,
+    /// The pairs of [`JoinType::Inner`], and the rows on the left without a match
+    Left,
+    /// The pairs of [`JoinType::Inner`], and the rows on either side without a match
+    Full,
+}
+
+type RowComparator<'a> = Box<dyn Fn(usize, usize) -> Ordering + 'a>;
+
+/// Returns a function comparing the row `i` of `lhs` with the row `j` of `rhs` according to
+/// `options`, where nulls are equal among themselves.
+fn row_comparator<'a>(
+    lhs: &'a [&'a dyn Array],
+    rhs: &'a [&'a dyn Array],
+    options: &'a [SortOptions],
+) -> Result<RowComparator<'a>> {
+    let comparators = lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(lhs, rhs)| ord::build_compare(*lhs, *rhs))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i, j| {
+        for (c, comparator) in comparators.iter().enumerate() {
+            let options = &options[c];
+            let ordering = match (lhs[c].is_valid(i), rhs[c].is_valid(j)) {
+                (true, true) => {
+                    let ordering = comparator(i, j);
+                    if options.descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                }
+                (false, false) => Ordering::Equal,
+                (false, true) if options.nulls_first => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (true, false) if options.nulls_first => Ordering::Greater,
+                (true, false) => Ordering::Less,
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }))
+}
+
+/// Returns the end of the group of rows of `keys` equal to the row `start`.
+fn group_end(start: usize, len: usize, comparator: &RowComparator) -> usize {
+    let mut end = start + 1;
+    while end < len && comparator(start, end) == Ordering::Equal {
+        end += 1;
+    }
+    end
+}
+
+struct Indices<I: Index> {
+    left: MutablePrimitiveArray<I>,
+    right: MutablePrimitiveArray<I>,
+}
+
+impl<I: Index> Indices<I> {
+    fn push(&mut self, left: Option<usize>, right: Option<usize>) -> Result<()> {
+        let to_index = |x: usize| I::from_usize(x).ok_or(ArrowError::Overflow);
+        self.left.push(left.map(to_index).transpose()?);
+        self.right.push(right.map(to_index).transpose()?);
+        Ok(())
+    }
+}
+
+/// Returns the indices of the pairs of rows of `left` and `right` matched by a join of
+/// type `join_type` on their keys.
+///
+/// `left` and `right` are the key columns of each side, which must be sorted (e.g. via
+/// [`lexsort`](crate::compute::sort::lexsort)) according to `options`, one per key column.
+/// A row matches all rows of the other side with equal keys; rows with a null key never
+/// match, as in SQL. The result is unspecified if the sides are not sorted.
+///
+/// The result is a pair of arrays of the same length, with the index of the left and right row
+/// of each pair. They are ordered by the left index and then by the right index, with the
+/// unmatched rows of the right side in their sorted position. The index of the other side of
+/// an unmatched row is null.
+/// # Errors
+/// Errors iff:
+/// * `left`, `right` and `options` do not have the same length, or are empty
+/// * the columns of either side do not have the same length
+/// * the key columns do not have the same type or the type is not supported
+/// * the indices do not fit in `I`
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::merge_join::{merge_join, JoinType};
+///
+/// let left = Int32Array::from(&[Some(1), Some(2), Some(2), Some(4)]);
+/// let right = Int32Array::from(&[Some(2), Some(3), Some(4), Some(4)]);
+///
+/// let (l, r) = merge_join::<u32>(&[&left], &[&right], &[Default::default()], JoinType::Left)
+///     .unwrap();
+/// assert_eq!(l, UInt32Array::from_slice(&[0, 1, 2, 3, 3]));
+/// assert_eq!(r, UInt32Array::from(&[None, Some(0), Some(0), Some(2), Some(3)]));
+/// ```
+pub fn merge_join<I: Index>(
+    left: &[&dyn Array],
+    right: &[&dyn Array],
+    options: &[SortOptions],
+    join_type: JoinType,
+) -> Result<(PrimitiveArray<I>, PrimitiveArray<I>)> {
+    if left.is_empty() || left.len() != right.len() || left.len() != options.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "merge_join requires the same, non-zero, number of keys and options on both sides"
+                .to_string(),
+        ));
+    }
+    let left_len = left[0].len();
+    let right_len = right[0].len();
+    if left.iter().any(|x| x.len() != left_len) || right.iter().any(|x| x.len() != right_len) {
+        return Err(ArrowError::InvalidArgumentError(
+            "merge_join requires the keys of each side to have the same length".to_string(),
+        ));
+    }
+
+    let compare = row_comparator(left, right, options)?;
+    let compare_left = row_comparator(left, left, options)?;
+    let compare_right = row_comparator(right, right, options)?;
+    let has_null = |keys: &[&dyn Array], row: usize| keys.iter().any(|x| x.is_null(row));
+
+    let keep_left = join_type != JoinType::Inner;
+    let keep_right = join_type == JoinType::Full;
+
+    let mut indices = Indices::<I> {
+        left: MutablePrimitiveArray::with_capacity(left_len),
+        right: MutablePrimitiveArray::with_capacity(left_len),
+    };
+
+    let mut i = 0;
+    let mut j = 0;
+    while i < left_len && j < right_len {
+        match compare(i, j) {
+            Ordering::Less => {
+                if keep_left {
+                    indices.push(Some(i), None)?;
+                }
+                i += 1;
+            }
+            Ordering::Greater => {
+                if keep_right {
+                    indices.push(None, Some(j))?;
+                }
+                j += 1;
+            }
+            Ordering::Equal => {
+                let left_end = group_end(i, left_len, &compare_left);
+                let right_end = group_end(j, right_len, &compare_right);
+                if has_null(left, i) {
+                    // null keys are equal among themselves but never match
+                    if keep_left {
+                        (i..left_end).try_for_each(|i| indices.push(Some(i), None))?;
+                    }
+                    if keep_right {
+                        (j..right_end).try_for_each(|j| indices.push(None, Some(j)))?;
+                    }
+                } else {
+                    for i in i..left_end {
+                        (j..right_end).try_for_each(|j| indices.push(Some(i), Some(j)))?;
+                    }
+                }
+                i = left_end;
+                j = right_end;
+            }
+        }
+    }
+    if keep_left {
+        (i..left_len).try_for_each(|i| indices.push(Some(i), None))?;
+    }
+    if keep_right {
+        (j..right_len).try_for_each(|j| indices.push(None, Some(j)))?;
+    }
+
+    Ok((indices.left.into(), indices.right.into()))
+}
@@ -0,0 +1,394 @@
+//! Contains [`external_sort`], to sort [`Chunk`]s that do not fit in memory.
+//!
+//! The chunks are sorted in runs of at most [`ExternalSortOptions::max_rows_in_memory`] rows.
+//! Every run but the last is spilled to an Arrow IPC file, and the runs are then merged with a
+//! k-way merge that holds a single [`Chunk`] of each run in memory. The rows of the sort
+//! columns are compared via the [row encoding](crate::compute::sort::row).
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::compute::sort::row::{RowConverter, Rows, SortField};
+use crate::compute::sort::{lexsort_to_indices, SortColumn, SortOptions};
+use crate::compute::take::take;
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+use crate::io::ipc::read::{read_file_metadata, FileReader};
+use crate::io::ipc::write::{FileWriter, WriteOptions};
+
+use super::{take_arrays, MergeSlice};
+
+type ArrayChunk = Chunk<Arc<dyn Array>>;
+type Run = Box<dyn Iterator<Item = Result<ArrayChunk>>>;
+
+// distinguishes the spill files of concurrent sorts of the same process
+static SORT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Options of [`external_sort`].
+#[derive(Debug, Clone)]
+pub struct ExternalSortOptions {
+    /// The directory where runs are spilled to. Spill files never replace existing files.
+    pub spill_directory: PathBuf,
+    /// The maximum number of rows that are sorted in memory, i.e. the length of a run.
+    pub max_rows_in_memory: usize,
+    /// The number of rows of each [`Chunk`] of the result and of the spill files.
+    pub batch_size: usize,
+}
+
+impl ExternalSortOptions {
+    /// Returns new [`ExternalSortOptions`] spilling to the temporary directory of the system.
+    pub fn new(max_rows_in_memory: usize, batch_size: usize) -> Self {
+        Self {
+            spill_directory: std::env::temp_dir(),
+            max_rows_in_memory,
+            batch_size,
+        }
+    }
+
+    /// Returns these options spilling to `spill_directory` instead.
+    pub fn with_spill_directory(mut self, spill_directory: impl Into<PathBuf>) -> Self {
+        self.spill_directory = spill_directory.into();
+        self
+    }
+}
+
+// the rows of `chunk` in sorted order, as a single chunk
+fn sort_run(chunks: &[ArrayChunk], columns: &[(usize, SortOptions)]) -> Result<ArrayChunk> {
+    let length: usize = chunks.iter().map(|chunk| chunk.len()).sum();
+    let slices = chunks
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| (index, 0, chunk.len()))
+        .collect::<Vec<_>>();
+    let arrays = (0..chunks[0].arrays().len())
+        .map(|column| {
+            let arrays = chunks
+                .iter()
+                .map(|chunk| chunk.arrays()[column].as_ref())
+                .collect::<Vec<_>>();
+            take_arrays(&arrays, slices.iter().copied(), Some(length)).into()
+        })
+        .collect::<Vec<Arc<dyn Array>>>();
+
+    let sort_columns = columns
+        .iter()
+        .map(|(column, options)| SortColumn {
+            values: arrays[*column].as_ref(),
+            options: Some(*options),
+        })
+        .collect::<Vec<_>>();
+    let indices = lexsort_to_indices::<u64>(&sort_columns, None)?;
+    let arrays = arrays
+        .iter()
+        .map(|array| take(array.as_ref(), &indices).map(|array| array.into()))
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
+
+// an iterator over `chunk` in chunks of `batch_size` rows
+fn batches(chunk: ArrayChunk, batch_size: usize) -> impl Iterator<Item = ArrayChunk> {
+    let length = chunk.len();
+    (0..length).step_by(batch_size).map(move |start| {
+        let length = batch_size.min(length - start);
+        let arrays = chunk
+            .arrays()
+            .iter()
+            .map(|array| array.slice(start, length).into())
+            .collect();
+        Chunk::new(arrays)
+    })
+}
+
+// creates a new spill file in `directory`, trying the next name whenever a file already exists
+fn create_spill(directory: &Path, id: usize, next: &mut usize) -> Result<(PathBuf, File)> {
+    loop {
+        let path = directory.join(format!(
+            "arrow2-external-sort-{}-{}-{}.arrow",
+            std::process::id(),
+            id,
+            next
+        ));
+        *next += 1;
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(error) if error.kind() == ErrorKind::AlreadyExists => continue,
+            Err(error) => return Err(error.into()),
+        }
+    }
+}
+
+fn spill(chunk: ArrayChunk, schema: &Schema, file: File, batch_size: usize) -> Result<()> {
+    let file = BufWriter::new(file);
+    let mut writer = FileWriter::try_new(file, schema, None, WriteOptions { compression: None })?;
+    for batch in batches(chunk, batch_size) {
+        writer.write(&batch, None)?;
+    }
+    writer.finish()
+}
+
+fn read_spill(path: &PathBuf) -> Result<Run> {
+    let mut file = BufReader::new(File::open(path)?);
+    let metadata = read_file_metadata(&mut file)?;
+    Ok(Box::new(FileReader::new(file, metadata, None)))
+}
+
+// the current chunk of a run during the merge
+struct Cursor {
+    run: Run,
+    chunk: ArrayChunk,
+    rows: Rows,
+    position: usize,
+    // the index of `chunk` in the sources of the batch being merged
+    source: usize,
+}
+
+/// An iterator over the sorted [`Chunk`]s returned by [`external_sort`].
+///
+/// The spill files are removed when this iterator is dropped.
+pub struct ExternalSort {
+    converter: RowConverter,
+    columns: Vec<usize>,
+    batch_size: usize,
+    cursors: Vec<Cursor>,
+    heap: BinaryHeap<Reverse<(Vec<u8>, usize)>>,
+    spills: Vec<PathBuf>,
+}
+
+impl ExternalSort {
+    fn convert(&self, chunk: &ArrayChunk) -> Result<Rows> {
+        let arrays = self
+            .columns
+            .iter()
+            .map(|column| chunk.arrays()[*column].clone())
+            .collect::<Vec<_>>();
+        self.converter.convert_columns(&arrays)
+    }
+
+    // the next non-empty chunk of `run`, if any
+    fn load_chunk(&self, run: &mut Run) -> Result<Option<(ArrayChunk, Rows)>> {
+        for chunk in run {
+            let chunk = chunk?;
+            if !chunk.is_empty() {
+                let rows = self.convert(&chunk)?;
+                return Ok(Some((chunk, rows)));
+            }
+        }
+        Ok(None)
+    }
+
+    fn merge_batch(&mut self) -> Result<ArrayChunk> {
+        let mut sources = self
+            .cursors
+            .iter_mut()
+            .enumerate()
+            .map(|(index, cursor)| {
+                cursor.source = index;
+                cursor.chunk.clone()
+            })
+            .collect::<Vec<_>>();
+        let mut slices: Vec<MergeSlice> = vec![];
+        let mut length = 0;
+
+        while length < self.batch_size {
+            let index = match self.heap.pop() {
+                Some(Reverse((_, index))) => index,
+                None => break,
+            };
+            let cursor = &mut self.cursors[index];
+            match slices.last_mut() {
+                Some(last) if last.0 == cursor.source && last.1 + last.2 == cursor.position => {
+                    last.2 += 1
+                }
+                _ => slices.push((cursor.source, cursor.position, 1)),
+            }
+            cursor.position += 1;
+            length += 1;
+
+            if cursor.position == cursor.chunk.len() {
+                let mut run = std::mem::replace(&mut cursor.run, Box::new(std::iter::empty()));
+                let next = self.load_chunk(&mut run)?;
+                let cursor = &mut self.cursors[index];
+                cursor.run = run;
+                match next {
+                    Some((chunk, rows)) => {
+                        sources.push(chunk.clone());
+                        cursor.chunk = chunk;
+                        cursor.rows = rows;
+                        cursor.position = 0;
+                        cursor.source = sources.len() - 1;
+                    }
+                    None => continue,
+                }
+            }
+            let cursor = &self.cursors[index];
+            let row = cursor.rows.row(cursor.position).data().to_vec();
+            self.heap.push(Reverse((row, index)));
+        }
+
+        let arrays = (0..sources[0].arrays().len())
+            .map(|column| {
+                let arrays = sources
+                    .iter()
+                    .map(|chunk| chunk.arrays()[column].as_ref())
+                    .collect::<Vec<_>>();
+                take_arrays(&arrays, slices.iter().copied(), Some(length)).into()
+            })
+            .collect();
+        Ok(Chunk::new(arrays))
+    }
+}
+
+impl Iterator for ExternalSort {
+    type Item = Result<ArrayChunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.heap.is_empty() {
+            None
+        } else {
+            Some(self.merge_batch())
+        }
+    }
+}
+
+impl Drop for ExternalSort {
+    fn drop(&mut self) {
+        // the readers must be closed before their files can be removed on some platforms
+        self.cursors.clear();
+        for path in &self.spills {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// Sorts `chunks` of `schema` by `columns`, a list of `(column index, options)`, spilling
+/// sorted runs to disk whenever more than `options.max_rows_in_memory` rows are buffered.
+///
+/// The sorted runs are merged lazily: the returned iterator yields [`Chunk`]s of
+/// `options.batch_size` rows while holding a single [`Chunk`] of every run in memory.
+/// Rows that are equal according to `columns` are returned in an unspecified order.
+/// # Errors
+/// This function errors when `columns` is empty or refers to columns that cannot be sorted,
+/// when `chunks` yields an error and on any I/O error while spilling, e.g. when
+/// `options.spill_directory` does not exist. The iterator errors
+/// when reading a spilled run fails.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::merge_sort::external::{external_sort, ExternalSortOptions};
+/// use arrow2::compute::sort::SortOptions;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// # fn main() -> arrow2::error::Result<()> {
+/// let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+/// let chunks = [vec![3, 1], vec![2, 0]].into_iter().map(|values| {
+///     Ok(Chunk::new(vec![Arc::new(Int32Array::from_vec(values)) as Arc<dyn Array>]))
+/// });
+///
+/// // every chunk is a run, and the first one is spilled
+/// let options = ExternalSortOptions::new(2, 3);
+/// let sorted = external_sort(chunks, &schema, &[(0, SortOptions::default())], &options)?
+///     .collect::<arrow2::error::Result<Vec<_>>>()?;
+///
+/// assert_eq!(sorted[0].arrays()[0].as_ref(), &Int32Array::from_slice(&[0, 1, 2]) as &dyn Array);
+/// assert_eq!(sorted[1].arrays()[0].as_ref(), &Int32Array::from_slice(&[3]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+pub fn external_sort<I>(
+    chunks: I,
+    schema: &Schema,
+    columns: &[(usize, SortOptions)],
+    options: &ExternalSortOptions,
+) -> Result<ExternalSort>
+where
+    I: IntoIterator<Item = Result<ArrayChunk>>,
+{
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "external_sort requires at least one column to sort by".to_string(),
+        ));
+    }
+    if options.max_rows_in_memory == 0 || options.batch_size == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "external_sort requires a positive number of rows in memory and per batch".to_string(),
+        ));
+    }
+    let converter = RowConverter::new(
+        columns
+            .iter()
+            .map(|(column, options)| {
+                let data_type = schema.fields[*column].data_type().clone();
+                SortField::new_with_options(data_type, *options)
+            })
+            .collect(),
+    )?;
+
+    let id = SORT_ID.fetch_add(1, Ordering::Relaxed);
+    let mut sort = ExternalSort {
+        converter,
+        columns: columns.iter().map(|(column, _)| *column).collect(),
+        batch_size: options.batch_size,
+        cursors: vec![],
+        heap: BinaryHeap::new(),
+        spills: vec![],
+    };
+
+    // sort the chunks in runs, spilling all but the last one
+    let mut buffer: Vec<ArrayChunk> = vec![];
+    let mut buffered = 0;
+    let mut last = None;
+    let mut next_spill = 0;
+    let mut chunks = chunks.into_iter().peekable();
+    while let Some(chunk) = chunks.next() {
+        let chunk = chunk?;
+        buffered += chunk.len();
+        buffer.push(chunk);
+        if buffered < options.max_rows_in_memory && chunks.peek().is_some() {
+            continue;
+        }
+        let run = sort_run(&buffer, columns)?;
+        buffer.clear();
+        buffered = 0;
+        if chunks.peek().is_none() {
+            last = Some(run);
+        } else {
+            let (path, file) = create_spill(&options.spill_directory, id, &mut next_spill)?;
+            // register the path first so that a partially written file is also removed
+            sort.spills.push(path);
+            spill(run, schema, file, options.batch_size)?;
+        }
+    }
+
+    let mut runs = sort
+        .spills
+        .iter()
+        .map(read_spill)
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(last) = last {
+        let batch_size = options.batch_size;
+        runs.push(Box::new(batches(last, batch_size).map(Ok)));
+    }
+
+    for mut run in runs {
+        if let Some((chunk, rows)) = sort.load_chunk(&mut run)? {
+            let index = sort.cursors.len();
+            sort.heap
+                .push(Reverse((rows.row(0).data().to_vec(), index)));
+            sort.cursors.push(Cursor {
+                run,
+                chunk,
+                rows,
+                position: 0,
+                source: 0,
+            });
+        }
+    }
+    Ok(sort)
+}
@@ -69,6 +69,10 @@ use crate::array::{
 pub use crate::compute::sort::SortOptions;
 use crate::error::Result;
 
+#[cfg(feature = "io_ipc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc")))]
+pub mod external;
+
 /// A slice denoting `(array_index, start, len)` representing a slice from one of N arrays.
 /// This is used to keep track of contiguous blocks of slots.
 /// An array of MergeSlice, `[MergeSlice]`, represents inter-leaved array slices.
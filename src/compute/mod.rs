@@ -30,6 +30,12 @@ pub mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
 pub mod cast;
+#[cfg(feature = "compute_clamp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_clamp")))]
+pub mod clamp;
+#[cfg(feature = "compute_coalesce")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_coalesce")))]
+pub mod coalesce;
 #[cfg(feature = "compute_comparison")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_comparison")))]
 pub mod comparison;
@@ -39,15 +45,30 @@ pub mod concatenate;
 #[cfg(feature = "compute_contains")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_contains")))]
 pub mod contains;
+#[cfg(feature = "compute_cumulative")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_cumulative")))]
+pub mod cumulative;
+#[cfg(feature = "compute_distinct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_distinct")))]
+pub mod distinct;
+#[cfg(feature = "compute_edit_distance")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_edit_distance")))]
+pub mod edit_distance;
 #[cfg(feature = "compute_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
+#[cfg(feature = "compute_groupby")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_groupby")))]
+pub mod groupby;
 #[cfg(feature = "compute_hash")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_hash")))]
 pub mod hash;
 #[cfg(feature = "compute_if_then_else")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_if_then_else")))]
 pub mod if_then_else;
+#[cfg(feature = "compute_interleave")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_interleave")))]
+pub mod interleave;
 #[cfg(feature = "compute_length")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_length")))]
 pub mod length;
@@ -57,21 +78,39 @@ pub mod like;
 #[cfg(feature = "compute_limit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_limit")))]
 pub mod limit;
+#[cfg(feature = "compute_list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_list")))]
+pub mod list;
+#[cfg(feature = "compute_merge_join")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_merge_join")))]
+pub mod merge_join;
 #[cfg(feature = "compute_merge_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_merge_sort")))]
 pub mod merge_sort;
 #[cfg(feature = "compute_nullif")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_nullif")))]
 pub mod nullif;
+#[cfg(feature = "compute_parse")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_parse")))]
+pub mod parse;
 #[cfg(feature = "compute_partition")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_partition")))]
 pub mod partition;
+#[cfg(feature = "compute_rank")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_rank")))]
+pub mod rank;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
+#[cfg(feature = "compute_search_sorted")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_search_sorted")))]
+pub mod search_sorted;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
+#[cfg(feature = "compute_struct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_struct")))]
+pub mod struct_;
 #[cfg(feature = "compute_substring")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_substring")))]
 pub mod substring;
@@ -85,6 +124,9 @@ pub mod temporal;
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_utf8")))]
 pub mod utf8;
 mod utils;
+#[cfg(feature = "compute_value_counts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_value_counts")))]
+pub mod value_counts;
 #[cfg(feature = "compute_window")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_window")))]
 pub mod window;
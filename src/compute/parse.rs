@@ -0,0 +1,381 @@
+//! Contains the operators [`parse`], to parse \[Large\]String arrays into numbers with a
+//! configurable handling of unparseable values, and [`parse_timestamp`], to parse them into
+//! timestamps with multiple fallback formats.
+//!
+//! Contrarily to [`cast`](crate::compute::cast), which makes unparseable values null, this
+//! can error on or report the values that fail to parse, and accepts numbers formatted with
+//! thousands separators or surrounded by whitespace.
+use chrono::format::{parse as parse_datetime, Item, Parsed, StrftimeItems};
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::{
+    array::{Array, MutablePrimitiveArray, Offset, PrimitiveArray, TryPush, Utf8Array},
+    datatypes::{DataType, PhysicalType, PrimitiveType, TimeUnit},
+    error::{ArrowError, Result},
+    temporal_conversions::parse_offset,
+    types::NativeType,
+};
+
+/// What [`parse`] does with values that fail to parse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorPolicy {
+    /// Return an error
+    Error,
+    /// Make them null
+    Null,
+    /// Make them null and return their indices
+    Collect,
+}
+
+/// Options of [`parse`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// What to do with values that fail to parse
+    pub on_error: ParseErrorPolicy,
+    /// A character ignored wherever it appears in a value, e.g. `Some(',')` parses `1,000` as
+    /// `1000`.
+    pub thousands_separator: Option<char>,
+    /// Whether leading and trailing whitespace is ignored
+    pub trim: bool,
+}
+
+impl Default for ParseOptions {
+    /// Unparseable values are null, and values are parsed as is.
+    fn default() -> Self {
+        Self {
+            on_error: ParseErrorPolicy::Null,
+            thousands_separator: None,
+            trim: false,
+        }
+    }
+}
+
+fn parse_value<T: lexical_core::FromLexical>(
+    value: &str,
+    options: &ParseOptions,
+    buffer: &mut String,
+) -> Option<T> {
+    let value = if options.trim { value.trim() } else { value };
+    match options.thousands_separator {
+        Some(separator) if value.contains(separator) => {
+            buffer.clear();
+            buffer.extend(value.chars().filter(|c| *c != separator));
+            lexical_core::parse(buffer.as_bytes()).ok()
+        }
+        _ => lexical_core::parse(value.as_bytes()).ok(),
+    }
+}
+
+/// Parses a [`Utf8Array`] into a [`PrimitiveArray`] of type `to` according to `options`.
+///
+/// Returns the parsed array and the indices of the values that failed to parse, which are
+/// only collected when [`ParseOptions::on_error`] is [`ParseErrorPolicy::Collect`].
+/// Null values are null in the result and are not failures.
+/// # Errors
+/// Errors iff a value fails to parse and [`ParseOptions::on_error`] is
+/// [`ParseErrorPolicy::Error`].
+pub fn parse_primitive<O: Offset, T>(
+    from: &Utf8Array<O>,
+    to: &DataType,
+    options: &ParseOptions,
+) -> Result<(PrimitiveArray<T>, Vec<usize>)>
+where
+    T: NativeType + lexical_core::FromLexical,
+{
+    let mut buffer = String::new();
+    let mut failures = vec![];
+    let mut result = MutablePrimitiveArray::<T>::with_capacity(from.len());
+    for (index, value) in from.iter().enumerate() {
+        let parsed = match value {
+            Some(value) => {
+                let parsed = parse_value(value, options, &mut buffer);
+                if parsed.is_none() {
+                    match options.on_error {
+                        ParseErrorPolicy::Error => {
+                            return Err(ArrowError::InvalidArgumentError(format!(
+                                "Unable to parse \"{}\" at index {} as {:?}",
+                                value, index, to
+                            )))
+                        }
+                        ParseErrorPolicy::Null => {}
+                        ParseErrorPolicy::Collect => failures.push(index),
+                    }
+                }
+                parsed
+            }
+            None => None,
+        };
+        result.try_push(parsed)?;
+    }
+    let result: PrimitiveArray<T> = result.into();
+    Ok((result.to(to.clone()), failures))
+}
+
+fn parse_dyn<O: Offset, T>(
+    from: &dyn Array,
+    to: &DataType,
+    options: &ParseOptions,
+) -> Result<(Box<dyn Array>, Vec<usize>)>
+where
+    T: NativeType + lexical_core::FromLexical,
+{
+    let from = from.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let (array, failures) = parse_primitive::<O, T>(from, to, options)?;
+    Ok((Box::new(array), failures))
+}
+
+/// Returns whether [`parse`] supports parsing an array of type `from` into `to`.
+pub fn can_parse(from: &DataType, to: &DataType) -> bool {
+    use PrimitiveType::*;
+    matches!(
+        from.to_physical_type(),
+        PhysicalType::Utf8 | PhysicalType::LargeUtf8
+    ) && matches!(
+        to.to_physical_type(),
+        PhysicalType::Primitive(
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+        )
+    )
+}
+
+/// Parses a \[Large\]String array into a numeric array of type `to` according to `options`.
+///
+/// Returns the parsed array and the indices of the values that failed to parse, which are
+/// only collected when [`ParseOptions::on_error`] is [`ParseErrorPolicy::Collect`].
+/// Null values are null in the result and are not failures.
+/// # Errors
+/// Errors iff the types are not supported (see [`can_parse`]), or a value fails to parse and
+/// [`ParseOptions::on_error`] is [`ParseErrorPolicy::Error`].
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::parse::{parse, ParseErrorPolicy, ParseOptions};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from(&[Some(" 1,000 "), None, Some("1.5"), Some("-2")]);
+/// let options = ParseOptions {
+///     on_error: ParseErrorPolicy::Collect,
+///     thousands_separator: Some(','),
+///     trim: true,
+/// };
+///
+/// let (result, failures) = parse(&array, &DataType::Int64, &options).unwrap();
+/// let expected = Int64Array::from(&[Some(1000), None, None, Some(-2)]);
+/// assert_eq!(expected, result.as_ref());
+/// assert_eq!(failures, vec![2]);
+/// ```
+pub fn parse(
+    from: &dyn Array,
+    to: &DataType,
+    options: &ParseOptions,
+) -> Result<(Box<dyn Array>, Vec<usize>)> {
+    use PrimitiveType::*;
+    let primitive = match to.to_physical_type() {
+        PhysicalType::Primitive(primitive) if can_parse(from.data_type(), to) => primitive,
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "parse from {:?} to {:?} is not supported",
+                from.data_type(),
+                to
+            )))
+        }
+    };
+    let large = from.data_type().to_physical_type() == PhysicalType::LargeUtf8;
+    macro_rules! dispatch {
+        ($T:ty) => {
+            if large {
+                parse_dyn::<i64, $T>(from, to, options)
+            } else {
+                parse_dyn::<i32, $T>(from, to, options)
+            }
+        };
+    }
+    match primitive {
+        Int8 => dispatch!(i8),
+        Int16 => dispatch!(i16),
+        Int32 => dispatch!(i32),
+        Int64 => dispatch!(i64),
+        UInt8 => dispatch!(u8),
+        UInt16 => dispatch!(u16),
+        UInt32 => dispatch!(u32),
+        UInt64 => dispatch!(u64),
+        Float32 => dispatch!(f32),
+        Float64 => dispatch!(f64),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the number of nanoseconds since the epoch of `datetime`, if it fits in an `i64`.
+fn to_nanoseconds<T: TimeZone>(datetime: &DateTime<T>) -> Option<i64> {
+    datetime
+        .timestamp()
+        .checked_mul(1_000_000_000)?
+        .checked_add(datetime.timestamp_subsec_nanos() as i64)
+}
+
+/// Parses `value` with the first of `formats` that parses it, with `to_timestamp` converting
+/// a successful parse to a timestamp.
+fn parse_with_formats<F: Fn(&Parsed) -> Option<i64>>(
+    value: &str,
+    formats: &[Vec<Item>],
+    to_timestamp: &F,
+) -> Option<i64> {
+    formats.iter().find_map(|items| {
+        let mut parsed = Parsed::new();
+        parse_datetime(&mut parsed, value, items.iter())
+            .ok()
+            .and_then(|_| to_timestamp(&parsed))
+    })
+}
+
+fn parse_timestamp_impl<O: Offset, F: Fn(&Parsed) -> Option<i64>>(
+    array: &Utf8Array<O>,
+    formats: &[Vec<Item>],
+    timezone: Option<String>,
+    to_timestamp: F,
+) -> (PrimitiveArray<i64>, Vec<usize>) {
+    let mut failures = vec![];
+    let values = array.iter().enumerate().map(|(index, value)| {
+        value.and_then(|value| {
+            let timestamp = parse_with_formats(value, formats, &to_timestamp);
+            if timestamp.is_none() {
+                failures.push(index);
+            }
+            timestamp
+        })
+    });
+    let array = PrimitiveArray::from_trusted_len_iter(values)
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, timezone));
+    (array, failures)
+}
+
+fn parse_timestamp_tz<O: Offset, T: TimeZone>(
+    array: &Utf8Array<O>,
+    formats: &[Vec<Item>],
+    timezone: String,
+    tz: T,
+) -> (PrimitiveArray<i64>, Vec<usize>) {
+    parse_timestamp_impl(array, formats, Some(timezone), |parsed| {
+        match parsed.to_datetime() {
+            Ok(datetime) => to_nanoseconds(&datetime),
+            // values without offset are local to `tz`
+            Err(_) => parsed
+                .to_naive_datetime_with_offset(0)
+                .ok()
+                .and_then(|naive| tz.from_local_datetime(&naive).earliest())
+                .and_then(|datetime| to_nanoseconds(&datetime)),
+        }
+    })
+}
+
+#[cfg(feature = "chrono-tz")]
+fn parse_timestamp_chrono_tz<O: Offset>(
+    array: &Utf8Array<O>,
+    formats: &[Vec<Item>],
+    timezone: String,
+) -> Result<(PrimitiveArray<i64>, Vec<usize>)> {
+    let tz = crate::temporal_conversions::parse_offset_tz(&timezone)?;
+    Ok(parse_timestamp_tz(array, formats, timezone, tz))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn parse_timestamp_chrono_tz<O: Offset>(
+    _: &Utf8Array<O>,
+    _: &[Vec<Item>],
+    timezone: String,
+) -> Result<(PrimitiveArray<i64>, Vec<usize>)> {
+    Err(ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone
+    )))
+}
+
+/// Parses a [`Utf8Array`] into a timestamp by trying each of the chrono `formats` in order,
+/// see [`parse_timestamp`].
+pub fn utf8_parse_timestamp<O: Offset>(
+    array: &Utf8Array<O>,
+    formats: &[&str],
+    timezone: Option<String>,
+) -> Result<(PrimitiveArray<i64>, Vec<usize>)> {
+    let formats = formats
+        .iter()
+        .map(|format| {
+            let items = StrftimeItems::new(format).collect::<Vec<_>>();
+            if items.iter().any(|item| matches!(item, Item::Error)) {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "\"{}\" is not a valid datetime format",
+                    format
+                )))
+            } else {
+                Ok(items)
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    match timezone {
+        None => Ok(parse_timestamp_impl(array, &formats, None, |parsed| {
+            parsed
+                .to_naive_datetime_with_offset(0)
+                .ok()
+                .and_then(|datetime| to_nanoseconds(&Utc.from_utc_datetime(&datetime)))
+        })),
+        Some(timezone) => match parse_offset(&timezone) {
+            Ok(tz) => Ok(parse_timestamp_tz(array, &formats, timezone, tz)),
+            Err(_) => parse_timestamp_chrono_tz(array, &formats, timezone),
+        },
+    }
+}
+
+/// Parses a \[Large\]String array into a timestamp by trying each of the chrono `formats`
+/// in order, i.e. a value is parsed by the first format that fully parses it.
+///
+/// Returns an array of type `Timestamp(Nanosecond, timezone)` and the indices of the (non-null)
+/// values that no format parsed, which are null.
+/// * When `timezone` is `None`, offsets in the values are ignored.
+/// * Otherwise, values with an offset are converted to `timezone`, and values without one are
+///   in local time of `timezone`. The feature `"chrono-tz"` enables IANA and zoneinfo formats
+///   for `timezone`.
+/// # Errors
+/// Errors iff `array` is not a \[Large\]String array, any of `formats` is invalid or
+/// `timezone` cannot be parsed.
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::parse::parse_timestamp;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let array = Utf8Array::<i32>::from(&[
+///     Some("1970-01-01 00:00:01"),
+///     Some("01/01/1970 00:00:02"),
+///     None,
+///     Some("1970"),
+/// ]);
+/// let formats = ["%Y-%m-%d %H:%M:%S", "%m/%d/%Y %H:%M:%S"];
+///
+/// let (result, failures) = parse_timestamp(&array, &formats, None).unwrap();
+/// let expected = Int64Array::from(&[Some(1_000_000_000), Some(2_000_000_000), None, None])
+///     .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+/// assert_eq!(expected, result.as_ref());
+/// assert_eq!(failures, vec![3]);
+/// ```
+pub fn parse_timestamp(
+    array: &dyn Array,
+    formats: &[&str],
+    timezone: Option<String>,
+) -> Result<(Box<dyn Array>, Vec<usize>)> {
+    let (array, failures) = match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => {
+            utf8_parse_timestamp::<i32>(array.as_any().downcast_ref().unwrap(), formats, timezone)?
+        }
+        PhysicalType::LargeUtf8 => {
+            utf8_parse_timestamp::<i64>(array.as_any().downcast_ref().unwrap(), formats, timezone)?
+        }
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "parse_timestamp does not support type {:?}",
+                array.data_type()
+            )))
+        }
+    };
+    Ok((Box::new(array), failures))
+}
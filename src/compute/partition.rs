@@ -42,9 +42,7 @@ pub fn lexicographical_partition_ranges(
 struct LexicographicalPartitionIterator {
     comparator: DynComparator,
     num_rows: usize,
-    previous_partition_point: usize,
     partition_point: usize,
-    value_indices: Vec<usize>,
 }
 
 impl LexicographicalPartitionIterator {
@@ -77,13 +75,10 @@ impl LexicographicalPartitionIterator {
             Ordering::Equal
         });
 
-        let value_indices = (0..num_rows).collect::<Vec<usize>>();
         Ok(Self {
             comparator,
             num_rows,
-            previous_partition_point: 0,
             partition_point: 0,
-            value_indices,
         })
     }
 }
@@ -92,26 +87,33 @@ impl Iterator for LexicographicalPartitionIterator {
     type Item = Range<usize>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.partition_point < self.num_rows {
-            // invariant:
-            // value_indices[0..previous_partition_point] all are values <= value_indices[previous_partition_point]
-            // so in order to save time we can do binary search on the value_indices[previous_partition_point..]
-            // and find when any value is greater than value_indices[previous_partition_point]; because we are using
-            // new indices, the new offset is _added_ to the previous_partition_point.
-            //
-            // be careful that idx is of type &usize which points to the actual value within value_indices, which itself
-            // contains usize (0..row_count), providing access to lexicographical_comparator as pointers into the
-            // original columnar data.
-            self.partition_point +=
-                self.value_indices[self.partition_point..].partition_point(|idx| {
-                    (self.comparator)(*idx, self.partition_point) != Ordering::Greater
-                });
-            let start = self.previous_partition_point;
-            let end = self.partition_point;
-            self.previous_partition_point = self.partition_point;
-            Some(Range { start, end })
-        } else {
-            None
+        if self.partition_point >= self.num_rows {
+            return None;
         }
+        let start = self.partition_point;
+        let is_greater = |index: usize| (self.comparator)(index, start) == Ordering::Greater;
+
+        // invariant: all values in [start, end) are equal to the value at `start` and all values
+        // after are greater. Since partitions are usually small, gallop to a row that belongs to
+        // a later partition (or to the end) before the binary search, so that finding a
+        // partition of length `k` costs `O(log k)` comparisons instead of `O(log n)`.
+        let mut bound = 1;
+        while start + bound < self.num_rows && !is_greater(start + bound) {
+            bound *= 2;
+        }
+        // the value at `low` is equal and the value at `high` is greater (or `high` is the end)
+        let mut low = start + bound / 2;
+        let mut high = (start + bound).min(self.num_rows);
+        while low + 1 < high {
+            let middle = low + (high - low) / 2;
+            if is_greater(middle) {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+
+        self.partition_point = high;
+        Some(start..high)
     }
 }
@@ -0,0 +1,99 @@
+//! Defines the rank kernel for [`crate::array::Array`]
+use std::cmp::Ordering;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::compute::sort::{build_compare, SortOptions};
+use crate::datatypes::DataType;
+use crate::error::Result;
+use crate::types::NativeType;
+
+/// How [`rank`] assigns a rank to a group of equal values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RankMethod {
+    /// The average of the ranks of the group, as a `Float64`
+    Average,
+    /// The lowest rank of the group
+    Min,
+    /// The highest rank of the group
+    Max,
+    /// The lowest rank of the group, where ranks increase by one between groups
+    Dense,
+}
+
+/// Returns the rank of each value of `array`, starting at 1, according to `options`.
+///
+/// Equal values are ranked according to `method`. Nulls are ranked as equal among themselves
+/// and placed according to [`SortOptions::nulls_first`].
+/// The result is a non-nullable `Float64` array for [`RankMethod::Average`] and a
+/// non-nullable `UInt64` array otherwise.
+/// # Errors
+/// Errors iff the type of `array` is not supported.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Float64Array, Int32Array, UInt64Array};
+/// use arrow2::compute::rank::{rank, RankMethod};
+///
+/// let array = Int32Array::from(&[Some(3), Some(1), None, Some(3)]);
+///
+/// let result = rank(&array, RankMethod::Min, None).unwrap();
+/// assert_eq!(result.as_ref(), &UInt64Array::from_slice(&[3, 2, 1, 3]) as &dyn Array);
+///
+/// let result = rank(&array, RankMethod::Average, None).unwrap();
+/// assert_eq!(result.as_ref(), &Float64Array::from_slice(&[3.5, 2.0, 1.0, 3.5]) as &dyn Array);
+/// ```
+pub fn rank(
+    array: &dyn Array,
+    method: RankMethod,
+    options: Option<SortOptions>,
+) -> Result<Box<dyn Array>> {
+    let comparator = build_compare(array, options.unwrap_or_default())?;
+
+    let mut indices = (0..array.len()).collect::<Vec<_>>();
+    indices.sort_unstable_by(|a, b| comparator(*a, *b));
+
+    // groups of equal values, as ranges of `indices`
+    let mut groups = Vec::<(usize, usize)>::new();
+    let mut start = 0;
+    for end in 1..=indices.len() {
+        if end == indices.len() || comparator(indices[start], indices[end]) != Ordering::Equal {
+            groups.push((start, end));
+            start = end;
+        }
+    }
+
+    Ok(match method {
+        RankMethod::Average => Box::new(assign(&indices, &groups, DataType::Float64, |s, e, _| {
+            (s + 1 + e) as f64 / 2.0
+        })),
+        RankMethod::Min => Box::new(assign(&indices, &groups, DataType::UInt64, |s, _, _| {
+            s as u64 + 1
+        })),
+        RankMethod::Max => Box::new(assign(&indices, &groups, DataType::UInt64, |_, e, _| {
+            e as u64
+        })),
+        RankMethod::Dense => Box::new(assign(&indices, &groups, DataType::UInt64, |_, _, d| {
+            d as u64 + 1
+        })),
+    })
+}
+
+/// Assigns `op(start, end, group)` to every index of every group
+fn assign<T, F>(
+    indices: &[usize],
+    groups: &[(usize, usize)],
+    data_type: DataType,
+    op: F,
+) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(usize, usize, usize) -> T,
+{
+    let mut values = vec![T::default(); indices.len()];
+    for (group, (start, end)) in groups.iter().enumerate() {
+        let rank = op(*start, *end, group);
+        indices[*start..*end]
+            .iter()
+            .for_each(|index| values[*index] = rank);
+    }
+    PrimitiveArray::<T>::new(data_type, values.into(), None)
+}
@@ -1,13 +1,19 @@
-//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`].
+//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`], the
+//! regex extraction operators [`extract`] and [`extract_groups`], and the regex replacement
+//! operator [`replace`].
+//!
+//! The operators taking a pattern compile it once for the whole array. Their variants suffixed
+//! with `_regex` (e.g. [`extract_regex`]) take an already compiled [`Regex`] instead, so that it
+//! can be reused across arrays.
 
 use std::collections::HashMap;
 
-use regex::Regex;
+pub use regex::Regex;
 
 use super::utils::combine_validities;
-use crate::array::{BooleanArray, Offset, Utf8Array};
-use crate::bitmap::Bitmap;
-use crate::datatypes::DataType;
+use crate::array::{BooleanArray, MutableUtf8Array, Offset, StructArray, TryPush, Utf8Array};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::{DataType, Field};
 use crate::error::{ArrowError, Result};
 
 /// Regex matches
@@ -81,3 +87,199 @@ fn unary_utf8_boolean<O: Offset, F: Fn(&str) -> bool>(
     let values = Bitmap::from_trusted_len_iter(iterator);
     BooleanArray::new(DataType::Boolean, values, validity)
 }
+
+fn compile(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern)
+        .map_err(|e| ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e)))
+}
+
+/// Returns the capture group `group` of the first match of `regex` on each value of `values`,
+/// where the group `0` is the whole match.
+///
+/// The result is null where the value is null, `regex` does not match it, or the group did not
+/// participate in the match.
+/// # Errors
+/// Errors iff `regex` has no group `group`.
+pub fn extract_regex<O: Offset>(
+    values: &Utf8Array<O>,
+    regex: &Regex,
+    group: usize,
+) -> Result<Utf8Array<O>> {
+    if group >= regex.captures_len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The regex \"{}\" has no capture group {}",
+            regex.as_str(),
+            group
+        )));
+    }
+    let mut result = MutableUtf8Array::<O>::with_capacity(values.len());
+    let mut locations = regex.capture_locations();
+    for value in values.iter() {
+        let extracted = value.and_then(|value| {
+            regex
+                .captures_read(&mut locations, value)
+                .and_then(|_| locations.get(group))
+                .map(|(start, end)| &value[start..end])
+        });
+        result.try_push(extracted)?;
+    }
+    Ok(result.into())
+}
+
+/// Returns the capture group `group` of the first match of `pattern` on each value of `values`,
+/// where the group `0` is the whole match.
+///
+/// The result is null where the value is null, `pattern` does not match it, or the group did
+/// not participate in the match.
+/// # Errors
+/// Errors iff `pattern` is not a valid regex or it has no group `group`.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::regex_match::extract;
+///
+/// let values = Utf8Array::<i32>::from(&[Some("a=1"), None, Some("b"), Some("c=22")]);
+/// let result = extract(&values, r"(\w)=(\d+)", 2).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("1"), None, None, Some("22")]));
+/// ```
+pub fn extract<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &str,
+    group: usize,
+) -> Result<Utf8Array<O>> {
+    extract_regex(values, &compile(pattern)?, group)
+}
+
+/// Returns all capture groups (excluding the whole match) of the first match of `regex` on
+/// each value of `values`, as a [`StructArray`] with one `Utf8` field per group.
+///
+/// Fields are named after the name of their group, or its index (starting at `1`) when it
+/// has no name. A field is null where its group did not participate in the match, and the
+/// struct is null where the value is null or `regex` does not match it.
+/// # Errors
+/// Errors iff `regex` has no capture groups.
+pub fn extract_groups_regex<O: Offset>(
+    values: &Utf8Array<O>,
+    regex: &Regex,
+) -> Result<StructArray> {
+    if regex.captures_len() <= 1 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The regex \"{}\" has no capture groups",
+            regex.as_str()
+        )));
+    }
+    let names = regex
+        .capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(index, name)| name.map_or_else(|| index.to_string(), |name| name.to_string()))
+        .collect::<Vec<_>>();
+
+    let mut groups = names
+        .iter()
+        .map(|_| MutableUtf8Array::<O>::with_capacity(values.len()))
+        .collect::<Vec<_>>();
+    let mut validity = MutableBitmap::with_capacity(values.len());
+
+    let mut locations = regex.capture_locations();
+    for value in values.iter() {
+        let captures =
+            value.and_then(|value| regex.captures_read(&mut locations, value).map(|_| value));
+        validity.push(captures.is_some());
+        groups.iter_mut().enumerate().for_each(|(group, array)| {
+            let extracted = captures.and_then(|value| {
+                locations
+                    .get(group + 1)
+                    .map(|(start, end)| &value[start..end])
+            });
+            array.push(extracted)
+        });
+    }
+
+    let fields = names
+        .into_iter()
+        .map(|name| Field::new(name, Utf8Array::<O>::default_data_type(), true))
+        .collect();
+    let groups = groups.into_iter().map(|array| array.into_arc()).collect();
+    Ok(StructArray::from_data(
+        DataType::Struct(fields),
+        groups,
+        validity.into(),
+    ))
+}
+
+/// Returns all capture groups (excluding the whole match) of the first match of `pattern` on
+/// each value of `values`, as a [`StructArray`] with one `Utf8` field per group.
+///
+/// Fields are named after the name of their group, or its index (starting at `1`) when it
+/// has no name. A field is null where its group did not participate in the match, and the
+/// struct is null where the value is null or `pattern` does not match it.
+/// # Errors
+/// Errors iff `pattern` is not a valid regex or it has no capture groups.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::regex_match::extract_groups;
+///
+/// let values = Utf8Array::<i32>::from(&[Some("a=1"), None, Some("b")]);
+/// let result = extract_groups(&values, r"(?P<key>\w)=(\d)").unwrap();
+///
+/// let names = result.fields().iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+/// assert_eq!(names, vec!["key", "2"]);
+/// let expected = Utf8Array::<i32>::from(&[Some("a"), None, None]);
+/// assert_eq!(expected, result.values()[0].as_ref());
+/// assert_eq!(result.validity(), Some(&[true, false, false].into()));
+/// ```
+pub fn extract_groups<O: Offset>(values: &Utf8Array<O>, pattern: &str) -> Result<StructArray> {
+    extract_groups_regex(values, &compile(pattern)?)
+}
+
+/// Replaces the first match (or all matches when `all` is `true`) of `regex` on each value
+/// of `values` by `replacement`.
+///
+/// `replacement` can reference capture groups of the match by index or name, e.g. `$1` or
+/// `${name}`, as in [`Regex::replace`]; `$$` is a literal `$`. Nulls are kept as nulls.
+/// # Errors
+/// Errors iff the result does not fit in an [`Utf8Array<O>`].
+pub fn replace_regex<O: Offset>(
+    values: &Utf8Array<O>,
+    regex: &Regex,
+    replacement: &str,
+    all: bool,
+) -> Result<Utf8Array<O>> {
+    let limit = if all { 0 } else { 1 };
+    let mut result = MutableUtf8Array::<O>::with_capacity(values.len());
+    for value in values.iter() {
+        result.try_push(value.map(|value| regex.replacen(value, limit, replacement)))?;
+    }
+    Ok(result.into())
+}
+
+/// Replaces the first match (or all matches when `all` is `true`) of `pattern` on each value
+/// of `values` by `replacement`.
+///
+/// `replacement` can reference capture groups of the match by index or name, e.g. `$1` or
+/// `${name}`, as in [`Regex::replace`]; `$$` is a literal `$`. Nulls are kept as nulls.
+/// # Errors
+/// Errors iff `pattern` is not a valid regex or the result does not fit in an [`Utf8Array<O>`].
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::regex_match::replace;
+///
+/// let values = Utf8Array::<i32>::from(&[Some("a=1, b=2"), None]);
+///
+/// let result = replace(&values, r"(\w)=(\d)", "$2=$1", false).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("1=a, b=2"), None]));
+///
+/// let result = replace(&values, r"(\w)=(\d)", "$2=$1", true).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("1=a, 2=b"), None]));
+/// ```
+pub fn replace<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &str,
+    replacement: &str,
+    all: bool,
+) -> Result<Utf8Array<O>> {
+    replace_regex(values, &compile(pattern)?, replacement, all)
+}
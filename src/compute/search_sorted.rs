@@ -0,0 +1,93 @@
+//! Defines the [`search_sorted`] kernel, a vectorized binary search over a sorted [`Array`].
+use std::cmp::Ordering;
+
+use crate::array::{ord, Array, PrimitiveArray};
+use crate::compute::sort::SortOptions;
+use crate::error::{ArrowError, Result};
+use crate::types::Index;
+
+/// Which insertion index [`search_sorted`] returns when the needle is found in the haystack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchSide {
+    /// The index of the first value equal to the needle
+    Left,
+    /// The index after the last value equal to the needle
+    Right,
+}
+
+/// Returns, for each value of `needles`, the index at which it would be inserted in
+/// `haystack` to keep it sorted.
+///
+/// `haystack` must be sorted according to `options` (ascending with nulls first when `None`);
+/// nulls are considered equal among themselves. The result is unspecified otherwise.
+/// This is `O(M log(N))` where `M` is the length of `needles` and `N` the length of `haystack`.
+/// # Errors
+/// Errors iff the two arrays have different types or the type is not supported.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::search_sorted::{search_sorted, SearchSide};
+///
+/// let haystack = Int32Array::from_slice(&[1, 2, 2, 5]);
+/// let needles = Int32Array::from_slice(&[0, 2, 3, 6]);
+///
+/// let result = search_sorted::<u32>(&haystack, &needles, SearchSide::Left, None).unwrap();
+/// assert_eq!(result, UInt32Array::from_slice(&[0, 1, 3, 4]));
+///
+/// let result = search_sorted::<u32>(&haystack, &needles, SearchSide::Right, None).unwrap();
+/// assert_eq!(result, UInt32Array::from_slice(&[0, 3, 3, 4]));
+/// ```
+pub fn search_sorted<I: Index>(
+    haystack: &dyn Array,
+    needles: &dyn Array,
+    side: SearchSide,
+    options: Option<SortOptions>,
+) -> Result<PrimitiveArray<I>> {
+    let options = options.unwrap_or_default();
+    let comparator = ord::build_compare(haystack, needles)?;
+
+    // compares `haystack[i]` with `needles[j]`
+    let compare = |i: usize, j: usize| match (haystack.is_valid(i), needles.is_valid(j)) {
+        (true, true) => {
+            let ordering = comparator(i, j);
+            if options.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        }
+        (false, false) => Ordering::Equal,
+        (false, true) if options.nulls_first => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (true, false) if options.nulls_first => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    };
+
+    // whether the insertion index of the needle is after `haystack[i]`
+    let is_before = |i: usize, j: usize| match side {
+        SearchSide::Left => compare(i, j) == Ordering::Less,
+        SearchSide::Right => compare(i, j) != Ordering::Greater,
+    };
+
+    let values = (0..needles.len())
+        .map(|j| {
+            let mut low = 0;
+            let mut high = haystack.len();
+            while low < high {
+                let middle = low + (high - low) / 2;
+                if is_before(middle, j) {
+                    low = middle + 1;
+                } else {
+                    high = middle;
+                }
+            }
+            I::from_usize(low).ok_or(ArrowError::Overflow)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(PrimitiveArray::<I>::new(
+        I::PRIMITIVE.into(),
+        values.into(),
+        None,
+    ))
+}
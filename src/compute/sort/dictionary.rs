@@ -0,0 +1,60 @@
+use std::cmp::Ordering;
+
+use crate::array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::bitmap::Bitmap;
+use crate::error::Result;
+use crate::types::Index;
+
+use super::{common, lex_sort, sort_to_indices, SortOptions};
+
+/// Returns the rank of every value of `values`, where equal values have equal ranks.
+fn rank(values: &dyn Array) -> Result<Vec<usize>> {
+    let options = SortOptions::default();
+    let sorted = sort_to_indices::<u64>(values, &options, None)?;
+    let comparator = lex_sort::build_compare(values, options)?;
+
+    let mut ranks = vec![0; values.len()];
+    let mut current = 0;
+    sorted.values().windows(2).for_each(|window| {
+        let (previous, index) = (window[0] as usize, window[1] as usize);
+        if (comparator)(previous, index) != Ordering::Equal {
+            current += 1;
+        }
+        ranks[index] = current;
+    });
+    Ok(ranks)
+}
+
+/// Sorts the keys of `array` by the rank of the values they point to, so that the values
+/// (the dictionary) are only compared once.
+pub(super) fn indices_sorted_unstable_by_rank<I: Index, K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    let keys = array.keys();
+    let values = array.values().as_ref();
+    let ranks = rank(values)?;
+
+    // a key pointing to a null value is null
+    let validity = values.validity().map(|_| {
+        keys.iter()
+            .map(|key| match key {
+                Some(key) => values.is_valid(key.to_usize().unwrap()),
+                None => false,
+            })
+            .collect::<Bitmap>()
+    });
+    let validity = validity.as_ref().or_else(|| keys.validity());
+
+    let get = |index: usize| ranks[keys.value(index).to_usize().unwrap()];
+    let cmp = |lhs: &usize, rhs: &usize| lhs.cmp(rhs);
+    Ok(common::indices_sorted_unstable_by(
+        validity,
+        get,
+        cmp,
+        array.len(),
+        options,
+        limit,
+    ))
+}
@@ -1,24 +1,23 @@
 //! Contains operators to sort individual and slices of [`Array`]s.
-use std::cmp::Ordering;
-
 use crate::array::ord;
 use crate::compute::take;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
-use crate::{
-    array::*,
-    types::{Index, NativeType},
-};
+use crate::{array::*, types::Index};
 
 mod binary;
 mod boolean;
 mod common;
+mod dictionary;
 mod lex_sort;
 mod primitive;
+pub mod row;
+mod select;
 mod utf8;
 
 pub(crate) use lex_sort::build_compare;
 pub use lex_sort::{lexsort, lexsort_to_indices, SortColumn};
+pub use select::select_k_indices;
 
 macro_rules! dyn_sort {
     ($ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
@@ -94,6 +93,9 @@ macro_rules! dyn_sort_indices {
 }
 
 /// Sort elements from `values` into a non-nullable [`PrimitiveArray`] of indices that sort `values`.
+///
+/// Nested arrays (lists and structs) are sorted lexicographically, as defined by
+/// [`build_compare`](crate::array::ord::build_compare).
 pub fn sort_to_indices<I: Index>(
     values: &dyn Array,
     options: &SortOptions,
@@ -151,65 +153,13 @@ pub fn sort_to_indices<I: Index>(
             options,
             limit,
         )),
-        DataType::List(field) => {
-            let (v, n) = partition_validity(values);
-            match &field.data_type {
-                DataType::Int8 => Ok(sort_list::<I, i32, i8>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i32, i16>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i32, i32>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i32, i64>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i32, u8>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i32, u16>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i32, u32>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i32, u64>(values, v, n, options, limit)),
-                t => Err(ArrowError::NotYetImplemented(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                ))),
-            }
-        }
-        DataType::LargeList(field) => {
-            let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<I, i64, i8>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i64, i16>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i64, i32>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i64, i64>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i64, u8>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i64, u16>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i64, u32>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i64, u64>(values, v, n, options, limit)),
-                t => Err(ArrowError::NotYetImplemented(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                ))),
-            }
-        }
-        DataType::FixedSizeList(field, _) => {
-            let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<I, i32, i8>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i32, i16>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i32, i32>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i32, i64>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i32, u8>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i32, u16>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i32, u32>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i32, u64>(values, v, n, options, limit)),
-                t => Err(ArrowError::NotYetImplemented(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                ))),
-            }
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Struct(_) => sort_nested::<I>(values, options, limit),
+        DataType::Dictionary(key_type, value_type, _) if can_sort(value_type.as_ref()) => {
+            sort_dict::<I>(values, key_type, options, limit)
         }
-        DataType::Dictionary(key_type, value_type, _) => match value_type.as_ref() {
-            DataType::Utf8 => Ok(sort_dict::<I, i32>(values, key_type, options, limit)),
-            DataType::LargeUtf8 => Ok(sort_dict::<I, i64>(values, key_type, options, limit)),
-            t => Err(ArrowError::NotYetImplemented(format!(
-                "Sort not supported for dictionary type with keys {:?}",
-                t
-            ))),
-        },
         t => Err(ArrowError::NotYetImplemented(format!(
             "Sort not supported for data type {:?}",
             t
@@ -217,14 +167,14 @@ pub fn sort_to_indices<I: Index>(
     }
 }
 
-fn sort_dict<I: Index, O: Offset>(
+fn sort_dict<I: Index>(
     values: &dyn Array,
     key_type: &IntegerType,
     options: &SortOptions,
     limit: Option<usize>,
-) -> PrimitiveArray<I> {
+) -> Result<PrimitiveArray<I>> {
     match_integer_type!(key_type, |$T| {
-        utf8::indices_sorted_unstable_by_dictionary::<I, $T, O>(
+        dictionary::indices_sorted_unstable_by_rank::<I, $T>(
             values.as_any().downcast_ref().unwrap(),
             options,
             limit,
@@ -270,26 +220,21 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::Binary
         | DataType::LargeBinary => true,
         DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
-            matches!(
-                field.data_type(),
-                DataType::Int8
-                    | DataType::Int16
-                    | DataType::Int32
-                    | DataType::Int64
-                    | DataType::UInt8
-                    | DataType::UInt16
-                    | DataType::UInt32
-                    | DataType::UInt64
-            )
-        }
-        DataType::Dictionary(_, value_type, _) => {
-            matches!(*value_type.as_ref(), DataType::Utf8 | DataType::LargeUtf8)
+            can_sort_nested(field.data_type())
         }
+        DataType::Struct(fields) => fields
+            .iter()
+            .all(|field| can_sort_nested(field.data_type())),
+        DataType::Dictionary(_, value_type, _) => can_sort(value_type.as_ref()),
         _ => false,
     }
 }
 
 /// Options that define how sort kernels should behave
+///
+/// Floats are always sorted in the IEEE 754 total order, i.e. `-0.0` before `0.0` and `NaN`
+/// after every other value, which is the order of the comparison functions prefixed with
+/// `total_` of [`comparison`](crate::compute::comparison) (e.g. `total_lt`).
 #[derive(Clone, Copy, Debug)]
 pub struct SortOptions {
     /// Whether to sort in descending order
@@ -308,69 +253,48 @@ impl Default for SortOptions {
     }
 }
 
-fn sort_list<I, O, T>(
+// whether `data_type` can be compared by [`ord::build_compare`] as a child of a nested array
+fn can_sort_nested(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Interval(IntervalUnit::YearMonth)
+        | DataType::Timestamp(_, _)
+        | DataType::Decimal(_, _)
+        | DataType::Decimal256(_, _) => true,
+        DataType::Interval(_) => false,
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            can_sort_nested(field.data_type())
+        }
+        DataType::Struct(fields) => fields
+            .iter()
+            .all(|field| can_sort_nested(field.data_type())),
+        DataType::Dictionary(_, value_type, _) => can_sort_nested(value_type.as_ref()),
+        other => can_sort(other),
+    }
+}
+
+// sorts nested arrays lexicographically, see [`ord::build_compare`]
+fn sort_nested<I: Index>(
     values: &dyn Array,
-    value_indices: Vec<I>,
-    null_indices: Vec<I>,
     options: &SortOptions,
     limit: Option<usize>,
-) -> PrimitiveArray<I>
-where
-    I: Index,
-    O: Offset,
-    T: NativeType + std::cmp::PartialOrd,
-{
-    let mut valids: Vec<(I, Box<dyn Array>)> = values
-        .as_any()
-        .downcast_ref::<FixedSizeListArray>()
-        .map_or_else(
-            || {
-                let values = values.as_any().downcast_ref::<ListArray<O>>().unwrap();
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index.to_usize())))
-                    .collect()
-            },
-            |values| {
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index.to_usize())))
-                    .collect()
-            },
-        );
+) -> Result<PrimitiveArray<I>> {
+    let (mut valids, nulls) = partition_validity::<I>(values);
+    let comparator = ord::build_compare(values, values)?;
 
     if !options.descending {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()))
+        valids.sort_by(|a, b| (comparator)(a.to_usize(), b.to_usize()))
     } else {
-        valids.sort_by(|a, b| cmp_array(b.1.as_ref(), a.1.as_ref()))
+        valids.sort_by(|a, b| (comparator)(b.to_usize(), a.to_usize()))
     }
 
-    let values = valids.iter().map(|tuple| tuple.0);
-
     let mut values = if options.nulls_first {
-        null_indices.into_iter().chain(values).collect::<Vec<I>>()
+        nulls.into_iter().chain(valids).collect::<Vec<I>>()
     } else {
-        values.chain(null_indices.into_iter()).collect::<Vec<I>>()
+        valids.into_iter().chain(nulls).collect::<Vec<I>>()
     };
 
     values.truncate(limit.unwrap_or(values.len()));
 
     let data_type = I::PRIMITIVE.into();
-    PrimitiveArray::<I>::new(data_type, values.into(), None)
-}
-
-/// Compare two `Array`s based on the ordering defined in [ord](crate::array::ord).
-fn cmp_array(a: &dyn Array, b: &dyn Array) -> Ordering {
-    let cmp_op = ord::build_compare(a, b).unwrap();
-    let length = a.len().max(b.len());
-
-    for i in 0..length {
-        let result = cmp_op(i, i);
-        if result != Ordering::Equal {
-            return result;
-        }
-    }
-    Ordering::Equal
+    Ok(PrimitiveArray::<I>::new(data_type, values.into(), None))
 }
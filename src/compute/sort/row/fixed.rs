@@ -0,0 +1,270 @@
+use crate::{
+    array::{BooleanArray, FixedSizeBinaryArray, PrimitiveArray},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    types::{days_ms, f16, i256, months_days_ns, NativeType},
+};
+
+use super::{null_sentinel, Rows, SortOptions};
+
+/// The sentinel of a valid slot of a fixed-width type
+const VALID: u8 = 1;
+
+/// A type whose values can be encoded into a fixed number of bytes whose
+/// lexicographical order equals the (total) order of the values.
+pub trait FixedLengthEncoding: Copy {
+    /// The number of bytes of the encoding
+    const ENCODED_LEN: usize;
+
+    /// Writes the encoding of `self` into `out`, of length `ENCODED_LEN`.
+    fn encode(self, out: &mut [u8]);
+
+    /// Reads a value from `bytes`, of length `ENCODED_LEN`.
+    fn decode(bytes: &[u8]) -> Self;
+}
+
+macro_rules! encode_unsigned {
+    ($ty:ty) => {
+        impl FixedLengthEncoding for $ty {
+            const ENCODED_LEN: usize = std::mem::size_of::<$ty>();
+
+            #[inline]
+            fn encode(self, out: &mut [u8]) {
+                out.copy_from_slice(&self.to_be_bytes());
+            }
+
+            #[inline]
+            fn decode(bytes: &[u8]) -> Self {
+                Self::from_be_bytes(bytes.try_into().unwrap())
+            }
+        }
+    };
+}
+
+// signed integers are encoded as big endian with the sign bit flipped, so that
+// negative numbers are ordered before positive ones
+macro_rules! encode_signed {
+    ($ty:ty) => {
+        impl FixedLengthEncoding for $ty {
+            const ENCODED_LEN: usize = std::mem::size_of::<$ty>();
+
+            #[inline]
+            fn encode(self, out: &mut [u8]) {
+                let mut bytes = self.to_be_bytes();
+                bytes[0] ^= 0x80;
+                out.copy_from_slice(&bytes);
+            }
+
+            #[inline]
+            fn decode(bytes: &[u8]) -> Self {
+                let mut bytes: [u8; std::mem::size_of::<$ty>()] = bytes.try_into().unwrap();
+                bytes[0] ^= 0x80;
+                Self::from_be_bytes(bytes)
+            }
+        }
+    };
+}
+
+// floats are converted to signed integers whose order is IEEE 754's totalOrder:
+// the bits of negative numbers (except the sign) are flipped.
+macro_rules! encode_float {
+    ($ty:ty, $signed:ty, $unsigned:ty) => {
+        impl FixedLengthEncoding for $ty {
+            const ENCODED_LEN: usize = std::mem::size_of::<$signed>();
+
+            #[inline]
+            fn encode(self, out: &mut [u8]) {
+                let bits = self.to_bits() as $signed;
+                let bits = bits ^ (((bits >> (<$signed>::BITS - 1)) as $unsigned) >> 1) as $signed;
+                bits.encode(out)
+            }
+
+            #[inline]
+            fn decode(bytes: &[u8]) -> Self {
+                let bits = <$signed>::decode(bytes);
+                let bits = bits ^ (((bits >> (<$signed>::BITS - 1)) as $unsigned) >> 1) as $signed;
+                Self::from_bits(bits as $unsigned)
+            }
+        }
+    };
+}
+
+encode_unsigned!(u8);
+encode_unsigned!(u16);
+encode_unsigned!(u32);
+encode_unsigned!(u64);
+encode_signed!(i8);
+encode_signed!(i16);
+encode_signed!(i32);
+encode_signed!(i64);
+encode_signed!(i128);
+encode_signed!(i256);
+encode_float!(f16, i16, u16);
+encode_float!(f32, i32, u32);
+encode_float!(f64, i64, u64);
+
+impl FixedLengthEncoding for days_ms {
+    const ENCODED_LEN: usize = 8;
+
+    #[inline]
+    fn encode(self, out: &mut [u8]) {
+        self.days().encode(&mut out[..4]);
+        self.milliseconds().encode(&mut out[4..]);
+    }
+
+    #[inline]
+    fn decode(bytes: &[u8]) -> Self {
+        Self::new(i32::decode(&bytes[..4]), i32::decode(&bytes[4..]))
+    }
+}
+
+impl FixedLengthEncoding for months_days_ns {
+    const ENCODED_LEN: usize = 16;
+
+    #[inline]
+    fn encode(self, out: &mut [u8]) {
+        self.months().encode(&mut out[..4]);
+        self.days().encode(&mut out[4..8]);
+        self.ns().encode(&mut out[8..]);
+    }
+
+    #[inline]
+    fn decode(bytes: &[u8]) -> Self {
+        Self::new(
+            i32::decode(&bytes[..4]),
+            i32::decode(&bytes[4..8]),
+            i64::decode(&bytes[8..]),
+        )
+    }
+}
+
+#[inline]
+fn invert(bytes: &mut [u8]) {
+    bytes.iter_mut().for_each(|x| *x = !*x)
+}
+
+/// Encodes a slot of `size` bytes, written by `encode` when the slot is valid.
+#[inline]
+fn encode_slot<F: FnOnce(&mut [u8])>(
+    rows: &mut Rows,
+    is_valid: bool,
+    size: usize,
+    options: SortOptions,
+    encode: F,
+) {
+    let buffer = &mut rows.buffer;
+    if is_valid {
+        buffer.push(VALID);
+        let start = buffer.len();
+        buffer.resize(start + size, 0);
+        encode(&mut buffer[start..]);
+        if options.descending {
+            invert(&mut buffer[start..])
+        }
+    } else {
+        buffer.push(null_sentinel(options));
+        buffer.resize(buffer.len() + size, 0);
+    }
+    rows.end_row();
+}
+
+/// Decodes `rows.len()` slots of `size` bytes, advancing each row past its slot.
+/// `decode` receives the (ascending) bytes of each slot; null slots are decoded from zeros.
+fn decode_slots<T, F: FnMut(&[u8]) -> T>(
+    rows: &mut [&[u8]],
+    size: usize,
+    options: SortOptions,
+    mut decode: F,
+) -> (Vec<T>, MutableBitmap) {
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    let mut scratch = Vec::with_capacity(size);
+    let values = rows
+        .iter_mut()
+        .map(|row| {
+            let (slot, remaining) = row.split_at(1 + size);
+            *row = remaining;
+
+            let is_valid = slot[0] == VALID;
+            validity.push(is_valid);
+            scratch.clear();
+            scratch.extend_from_slice(&slot[1..]);
+            if is_valid && options.descending {
+                invert(&mut scratch)
+            }
+            decode(&scratch)
+        })
+        .collect();
+    (values, validity)
+}
+
+pub(super) fn encode_primitive<T: NativeType + FixedLengthEncoding>(
+    rows: &mut Rows,
+    array: &PrimitiveArray<T>,
+    options: SortOptions,
+) {
+    array.iter().for_each(|value| {
+        encode_slot(rows, value.is_some(), T::ENCODED_LEN, options, |out| {
+            value.unwrap().encode(out)
+        })
+    })
+}
+
+pub(super) fn decode_primitive<T: NativeType + FixedLengthEncoding>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: SortOptions,
+) -> PrimitiveArray<T> {
+    let (values, validity) = decode_slots(rows, T::ENCODED_LEN, options, T::decode);
+    PrimitiveArray::new(data_type, values.into(), validity.into())
+}
+
+pub(super) fn encode_boolean(rows: &mut Rows, array: &BooleanArray, options: SortOptions) {
+    array.iter().for_each(|value| {
+        encode_slot(rows, value.is_some(), 1, options, |out| {
+            out[0] = value.unwrap() as u8
+        })
+    })
+}
+
+pub(super) fn decode_boolean(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: SortOptions,
+) -> BooleanArray {
+    let (values, validity) = decode_slots(rows, 1, options, |bytes| bytes[0] != 0);
+    BooleanArray::new(
+        data_type,
+        MutableBitmap::from_iter(values).into(),
+        validity.into(),
+    )
+}
+
+pub(super) fn encode_fixed_size_binary(
+    rows: &mut Rows,
+    array: &FixedSizeBinaryArray,
+    options: SortOptions,
+) {
+    let size = array.size();
+    array.iter().for_each(|value| {
+        encode_slot(rows, value.is_some(), size, options, |out| {
+            out.copy_from_slice(value.unwrap())
+        })
+    })
+}
+
+pub(super) fn decode_fixed_size_binary(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: SortOptions,
+) -> FixedSizeBinaryArray {
+    let size = FixedSizeBinaryArray::get_size(&data_type);
+    let mut values = Vec::with_capacity(rows.len() * size);
+    let (_, validity) = decode_slots(rows, size, options, |bytes| values.extend_from_slice(bytes));
+    FixedSizeBinaryArray::new(data_type, values.into(), validity.into())
+}
+
+/// The number of bytes of an encoded slot of a fixed-width type of `size` bytes
+#[inline]
+pub(super) fn encoded_len(size: usize) -> usize {
+    1 + size
+}
@@ -0,0 +1,649 @@
+//! A row-oriented encoding of columns whose rows can be compared byte-wise.
+//!
+//! Comparing many columns via [`crate::array::ord::build_compare`] requires one dynamically
+//! dispatched comparison per column per pair of rows. Instead, a [`RowConverter`] encodes
+//! the columns of a [`Chunk`](crate::chunk::Chunk) (or any slice of arrays) into [`Rows`],
+//! where every [`Row`] is a sequence of bytes whose lexicographical order
+//! (i.e. `memcmp`) equals the order of the original rows according to each
+//! column's [`SortOptions`]. The rows can be decoded back into arrays via
+//! [`RowConverter::convert_rows`].
+//!
+//! # Encoding
+//! Each slot is encoded as a sentinel byte, identifying nulls, followed by its value:
+//! * fixed-width types (booleans, primitives and fixed-size binary) are encoded into a fixed
+//!   number of bytes, e.g. integers in big endian with the sign bit flipped and floats
+//!   according to IEEE 754's totalOrder.
+//! * variable-length types (binary and utf8) are encoded in blocks of 32 bytes, each followed
+//!   by a continuation marker or, in the last block, by its length.
+//! * structs are encoded as their sentinel followed by the encoding of each field.
+//! * lists are encoded as a variable-length value of the concatenated encoding of its items.
+//! * dictionaries are encoded as their values.
+//!
+//! Descending order is achieved by inverting the bytes of the encoding of each value.
+//!
+//! The encoding is not stable across versions of this crate nor across [`RowConverter`]s with
+//! different fields and should thus not be persisted.
+//!
+//! # Example
+//! ```
+//! use std::sync::Arc;
+//! use arrow2::array::{Array, Int32Array, Utf8Array};
+//! use arrow2::chunk::Chunk;
+//! use arrow2::compute::sort::row::{RowConverter, SortField};
+//! use arrow2::compute::sort::SortOptions;
+//! use arrow2::datatypes::DataType;
+//! # fn main() -> arrow2::error::Result<()> {
+//! let a = Int32Array::from(&[Some(1), Some(1), None]);
+//! let b = Utf8Array::<i32>::from(&[Some("b"), Some("a"), Some("c")]);
+//! let chunk = Chunk::new(vec![Arc::new(a.clone()) as Arc<dyn Array>, Arc::new(b.clone())]);
+//!
+//! let converter = RowConverter::new(vec![
+//!     SortField::new(DataType::Int32),
+//!     SortField::new_with_options(
+//!         DataType::Utf8,
+//!         SortOptions {
+//!             descending: true,
+//!             nulls_first: true,
+//!         },
+//!     ),
+//! ])?;
+//! let rows = converter.convert_columns(chunk.arrays())?;
+//!
+//! // nulls first, then by `a` ascending and then by `b` descending
+//! assert!(rows.row(2) < rows.row(0));
+//! assert!(rows.row(0) < rows.row(1));
+//!
+//! // and back
+//! let arrays = converter.convert_rows(rows.iter())?;
+//! assert_eq!(arrays[0].as_ref(), &a as &dyn Array);
+//! assert_eq!(arrays[1].as_ref(), &b as &dyn Array);
+//! # Ok(())
+//! # }
+//! ```
+use std::sync::Arc;
+
+use crate::{
+    array::*,
+    bitmap::MutableBitmap,
+    datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
+};
+
+use super::SortOptions;
+
+mod fixed;
+mod variable;
+
+/// The sentinel of a valid row of a column of type [`DataType::Null`] and of a valid struct
+const VALID: u8 = 1;
+
+/// The sentinel of a null slot
+#[inline]
+fn null_sentinel(options: SortOptions) -> u8 {
+    if options.nulls_first {
+        0
+    } else {
+        0xFF
+    }
+}
+
+/// The [`DataType`] and [`SortOptions`] of a column to be encoded by a [`RowConverter`].
+#[derive(Debug, Clone)]
+pub struct SortField {
+    /// The data type of the column
+    pub data_type: DataType,
+    /// The options used to order the column
+    pub options: SortOptions,
+}
+
+impl SortField {
+    /// Creates a new [`SortField`] with the default [`SortOptions`].
+    pub fn new(data_type: DataType) -> Self {
+        Self::new_with_options(data_type, SortOptions::default())
+    }
+
+    /// Creates a new [`SortField`].
+    pub fn new_with_options(data_type: DataType, options: SortOptions) -> Self {
+        Self { data_type, options }
+    }
+}
+
+/// Converts columns into [`Rows`] and [`Rows`] back into columns.
+/// See the [module level documentation](self) for details.
+#[derive(Debug, Clone)]
+pub struct RowConverter {
+    fields: Vec<SortField>,
+}
+
+impl RowConverter {
+    /// Creates a new [`RowConverter`] for columns described by `fields`.
+    /// # Errors
+    /// This function errors iff any of the data types of the fields is not supported.
+    /// Supported types are all types except [`DataType::FixedSizeList`], [`DataType::Map`],
+    /// [`DataType::Union`] and [`DataType::RunEndEncoded`] (including as children).
+    pub fn new(fields: Vec<SortField>) -> Result<Self> {
+        fields
+            .iter()
+            .try_for_each(|field| check_data_type(&field.data_type))?;
+        Ok(Self { fields })
+    }
+
+    /// The fields of this [`RowConverter`]
+    pub fn fields(&self) -> &[SortField] {
+        &self.fields
+    }
+
+    /// Encodes `columns` into [`Rows`].
+    /// # Errors
+    /// This function errors iff:
+    /// * the number of columns is different from the number of fields
+    /// * the data type of any column is different from the data type of its field
+    /// * the columns have different lengths
+    pub fn convert_columns<A: AsRef<dyn Array>>(&self, columns: &[A]) -> Result<Rows> {
+        if columns.len() != self.fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "RowConverter expects {} columns but {} were passed",
+                self.fields.len(),
+                columns.len()
+            )));
+        }
+        if let Some((column, field)) = columns
+            .iter()
+            .zip(self.fields.iter())
+            .find(|(column, field)| column.as_ref().data_type() != &field.data_type)
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "RowConverter expects a column of type {:?} but a column of type {:?} was passed",
+                field.data_type,
+                column.as_ref().data_type()
+            )));
+        }
+        let length = columns.first().map(|x| x.as_ref().len()).unwrap_or(0);
+        if columns.iter().any(|x| x.as_ref().len() != length) {
+            return Err(ArrowError::InvalidArgumentError(
+                "RowConverter expects columns of equal length".to_string(),
+            ));
+        }
+
+        let mut encoded = columns
+            .iter()
+            .zip(self.fields.iter())
+            .map(|(column, field)| encode(column.as_ref(), field.options))
+            .collect::<Result<Vec<_>>>()?;
+
+        if encoded.len() == 1 {
+            return Ok(encoded.pop().unwrap());
+        }
+
+        let size = encoded.iter().map(|x| x.buffer.len()).sum();
+        let mut rows = Rows::with_capacity(length, size);
+        for i in 0..length {
+            encoded
+                .iter()
+                .for_each(|column| rows.buffer.extend_from_slice(column.row(i).data));
+            rows.end_row();
+        }
+        Ok(rows)
+    }
+
+    /// Decodes `rows` back into columns.
+    /// # Errors
+    /// This function errors iff the rows were not created by a [`RowConverter`] with the
+    /// same fields as this one.
+    pub fn convert_rows<'a, I: IntoIterator<Item = Row<'a>>>(
+        &self,
+        rows: I,
+    ) -> Result<Vec<Box<dyn Array>>> {
+        let mut rows = rows.into_iter().map(|row| row.data).collect::<Vec<_>>();
+
+        // the lengths are validated so that decoding below does not panic
+        rows.iter().try_for_each(|row| {
+            let mut row = *row;
+            for field in self.fields.iter() {
+                let length = encoded_len(&field.data_type, field.options, row)
+                    .ok_or_else(|| ArrowError::oos("Row does not match the RowConverter"))?;
+                row = &row[length..];
+            }
+            if row.is_empty() {
+                Ok(())
+            } else {
+                Err(ArrowError::oos("Row does not match the RowConverter"))
+            }
+        })?;
+
+        self.fields
+            .iter()
+            .map(|field| decode(&field.data_type, field.options, &mut rows))
+            .collect()
+    }
+}
+
+/// A set of rows encoded by a [`RowConverter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rows {
+    buffer: Vec<u8>,
+    // the end of each row in `buffer`, starting with 0
+    offsets: Vec<usize>,
+}
+
+impl Rows {
+    fn with_capacity(length: usize, size: usize) -> Self {
+        let mut offsets = Vec::with_capacity(length + 1);
+        offsets.push(0);
+        Self {
+            buffer: Vec::with_capacity(size),
+            offsets,
+        }
+    }
+
+    /// Marks the end of the row currently being written.
+    #[inline]
+    fn end_row(&mut self) {
+        self.offsets.push(self.buffer.len())
+    }
+
+    /// The number of rows
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Whether there are no rows
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the row at position `i`.
+    /// # Panics
+    /// Panics iff `i >= self.len()`.
+    #[inline]
+    pub fn row(&self, i: usize) -> Row<'_> {
+        Row {
+            data: &self.buffer[self.offsets[i]..self.offsets[i + 1]],
+        }
+    }
+
+    /// Returns an iterator over the rows
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Row<'_>> + '_ {
+        (0..self.len()).map(move |i| self.row(i))
+    }
+
+    /// The number of bytes of all rows
+    pub fn size(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+/// A row of [`Rows`]. Rows encoded by the same [`RowConverter`] can be compared, whose
+/// order is the order of the original rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Row<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Row<'a> {
+    /// The bytes of this row
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl AsRef<[u8]> for Row<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.data
+    }
+}
+
+fn check_data_type(data_type: &DataType) -> Result<()> {
+    use PhysicalType::*;
+    match data_type.to_physical_type() {
+        Null | Boolean | Primitive(_) | Binary | LargeBinary | FixedSizeBinary | Utf8
+        | LargeUtf8 | BinaryView | Utf8View => Ok(()),
+        Struct => StructArray::get_fields(data_type)
+            .iter()
+            .try_for_each(|field| check_data_type(field.data_type())),
+        List => check_data_type(ListArray::<i32>::get_child_field(data_type).data_type()),
+        LargeList => check_data_type(ListArray::<i64>::get_child_field(data_type).data_type()),
+        Dictionary(_) => match data_type.to_logical_type() {
+            DataType::Dictionary(_, values, _) => check_data_type(values),
+            _ => unreachable!(),
+        },
+        FixedSizeList | Map | Union | RunEndEncoded(_) => Err(ArrowError::NotYetImplemented(
+            format!("RowConverter does not support {:?}", data_type),
+        )),
+    }
+}
+
+/// The options of the items of a list, so that when the items' encoding is itself encoded
+/// with `options`, nulls are ordered according to `options.nulls_first`
+#[inline]
+fn list_items_options(options: SortOptions) -> SortOptions {
+    SortOptions {
+        descending: false,
+        nulls_first: options.nulls_first != options.descending,
+    }
+}
+
+/// Encodes `array` into one row per slot.
+fn encode(array: &dyn Array, options: SortOptions) -> Result<Rows> {
+    use PhysicalType::*;
+    let mut rows = Rows::with_capacity(array.len(), 0);
+    match array.data_type().to_physical_type() {
+        Null => (0..array.len()).for_each(|_| {
+            rows.buffer.push(null_sentinel(options));
+            rows.end_row()
+        }),
+        Boolean => {
+            fixed::encode_boolean(&mut rows, array.as_any().downcast_ref().unwrap(), options)
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            fixed::encode_primitive::<$T>(&mut rows, array.as_any().downcast_ref().unwrap(), options)
+        }),
+        FixedSizeBinary => fixed::encode_fixed_size_binary(
+            &mut rows,
+            array.as_any().downcast_ref().unwrap(),
+            options,
+        ),
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            variable::encode(&mut rows, array.iter(), options)
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            variable::encode(&mut rows, array.iter(), options)
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            variable::encode(
+                &mut rows,
+                array.iter().map(|x| x.map(|x| x.as_bytes())),
+                options,
+            )
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            variable::encode(
+                &mut rows,
+                array.iter().map(|x| x.map(|x| x.as_bytes())),
+                options,
+            )
+        }
+        BinaryView => {
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            variable::encode(&mut rows, array.iter(), options)
+        }
+        Utf8View => {
+            let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            variable::encode(
+                &mut rows,
+                array.iter().map(|x| x.map(|x| x.as_bytes())),
+                options,
+            )
+        }
+        Struct => encode_struct(&mut rows, array.as_any().downcast_ref().unwrap(), options)?,
+        List => encode_list::<i32>(&mut rows, array.as_any().downcast_ref().unwrap(), options)?,
+        LargeList => {
+            encode_list::<i64>(&mut rows, array.as_any().downcast_ref().unwrap(), options)?
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            encode_dictionary::<$T>(&mut rows, array.as_any().downcast_ref().unwrap(), options)?
+        }),
+        FixedSizeList | Map | Union | RunEndEncoded(_) => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "RowConverter does not support {:?}",
+                array.data_type()
+            )))
+        }
+    };
+    Ok(rows)
+}
+
+/// Returns the encoding of a null slot of `data_type`
+fn encode_null(data_type: &DataType, options: SortOptions) -> Result<Vec<u8>> {
+    let null = new_null_array(data_type.clone(), 1);
+    encode(null.as_ref(), options).map(|rows| rows.buffer)
+}
+
+fn encode_struct(rows: &mut Rows, array: &StructArray, options: SortOptions) -> Result<()> {
+    let values = array
+        .values()
+        .iter()
+        .map(|value| encode(value.as_ref(), options))
+        .collect::<Result<Vec<_>>>()?;
+    // nulls are encoded with the (canonical) encoding of null fields, so that all null
+    // slots compare equal regardless of their fields' values
+    let null = array
+        .fields()
+        .iter()
+        .map(|field| encode_null(field.data_type(), options))
+        .collect::<Result<Vec<_>>>()?
+        .concat();
+
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            rows.buffer.push(VALID);
+            values
+                .iter()
+                .for_each(|value| rows.buffer.extend_from_slice(value.row(i).data));
+        } else {
+            rows.buffer.push(null_sentinel(options));
+            rows.buffer.extend_from_slice(&null);
+        }
+        rows.end_row();
+    }
+    Ok(())
+}
+
+fn encode_list<O: Offset>(
+    rows: &mut Rows,
+    array: &ListArray<O>,
+    options: SortOptions,
+) -> Result<()> {
+    let values = encode(array.values().as_ref(), list_items_options(options))?;
+
+    let mut items = vec![];
+    for (i, window) in array.offsets().windows(2).enumerate() {
+        if array.is_valid(i) {
+            items.clear();
+            (window[0].to_usize()..window[1].to_usize())
+                .for_each(|item| items.extend_from_slice(values.row(item).data));
+            variable::encode_one(&mut rows.buffer, Some(&items), options);
+        } else {
+            variable::encode_one(&mut rows.buffer, None, options);
+        }
+        rows.end_row();
+    }
+    Ok(())
+}
+
+fn encode_dictionary<K: DictionaryKey>(
+    rows: &mut Rows,
+    array: &DictionaryArray<K>,
+    options: SortOptions,
+) -> Result<()> {
+    let values = encode(array.values().as_ref(), options)?;
+    let null = encode_null(array.values().data_type(), options)?;
+
+    array.keys().iter().for_each(|key| {
+        match key.and_then(|key| key.to_usize()) {
+            Some(key) => rows.buffer.extend_from_slice(values.row(key).data),
+            None => rows.buffer.extend_from_slice(&null),
+        };
+        rows.end_row();
+    });
+    Ok(())
+}
+
+/// Returns the number of bytes of the encoded slot of `data_type` at the start of `row`,
+/// or `None` if `row` is too short.
+fn encoded_len(data_type: &DataType, options: SortOptions, row: &[u8]) -> Option<usize> {
+    use PhysicalType::*;
+    let length = match data_type.to_physical_type() {
+        Null => 1,
+        Boolean => fixed::encoded_len(1),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            fixed::encoded_len(<$T as fixed::FixedLengthEncoding>::ENCODED_LEN)
+        }),
+        FixedSizeBinary => fixed::encoded_len(FixedSizeBinaryArray::get_size(data_type)),
+        Binary | LargeBinary | Utf8 | LargeUtf8 | BinaryView | Utf8View | List | LargeList => {
+            variable::encoded_len(row, options)?
+        }
+        Struct => {
+            let mut length = 1;
+            for field in StructArray::get_fields(data_type) {
+                length += encoded_len(field.data_type(), options, row.get(length..)?)?;
+            }
+            length
+        }
+        Dictionary(_) => match data_type.to_logical_type() {
+            DataType::Dictionary(_, values, _) => encoded_len(values, options, row)?,
+            _ => unreachable!(),
+        },
+        FixedSizeList | Map | Union | RunEndEncoded(_) => return None,
+    };
+    (length <= row.len()).then_some(length)
+}
+
+/// Decodes one slot of `data_type` from each row, advancing each row past the slot.
+fn decode(
+    data_type: &DataType,
+    options: SortOptions,
+    rows: &mut [&[u8]],
+) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Null => {
+            rows.iter_mut().for_each(|row| *row = &row[1..]);
+            Box::new(NullArray::new(data_type.clone(), rows.len()))
+        }
+        Boolean => Box::new(fixed::decode_boolean(rows, data_type.clone(), options)),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            Box::new(fixed::decode_primitive::<$T>(rows, data_type.clone(), options))
+        }),
+        FixedSizeBinary => Box::new(fixed::decode_fixed_size_binary(
+            rows,
+            data_type.clone(),
+            options,
+        )),
+        Binary => Box::new(decode_binary::<i32>(rows, data_type.clone(), options)?),
+        LargeBinary => Box::new(decode_binary::<i64>(rows, data_type.clone(), options)?),
+        Utf8 => Box::new(decode_utf8::<i32>(rows, data_type.clone(), options)?),
+        LargeUtf8 => Box::new(decode_utf8::<i64>(rows, data_type.clone(), options)?),
+        BinaryView => {
+            let array = decode_binary::<i64>(rows, DataType::LargeBinary, options)?;
+            let array: BinaryViewArray =
+                MutableBinaryViewArray::<[u8]>::from_iter(array.iter()).into();
+            Box::new(array)
+        }
+        Utf8View => {
+            let array = decode_utf8::<i64>(rows, DataType::LargeUtf8, options)?;
+            let array: Utf8ViewArray =
+                MutableBinaryViewArray::<str>::from_iter(array.iter()).into();
+            Box::new(array)
+        }
+        Struct => Box::new(decode_struct(rows, data_type.clone(), options)?),
+        List => Box::new(decode_list::<i32>(rows, data_type.clone(), options)?),
+        LargeList => Box::new(decode_list::<i64>(rows, data_type.clone(), options)?),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            Box::new(decode_dictionary::<$T>(rows, data_type, options)?)
+        }),
+        FixedSizeList | Map | Union | RunEndEncoded(_) => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "RowConverter does not support {:?}",
+                data_type
+            )))
+        }
+    })
+}
+
+fn decode_binary<O: Offset>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: SortOptions,
+) -> Result<BinaryArray<O>> {
+    let (offsets, values, validity) = variable::decode::<O>(rows, options)?;
+    BinaryArray::try_new(data_type, offsets.into(), values.into(), validity.into())
+}
+
+fn decode_utf8<O: Offset>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: SortOptions,
+) -> Result<Utf8Array<O>> {
+    let (offsets, values, validity) = variable::decode::<O>(rows, options)?;
+    Utf8Array::try_new(data_type, offsets.into(), values.into(), validity.into())
+}
+
+fn decode_struct(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: SortOptions,
+) -> Result<StructArray> {
+    let validity = rows
+        .iter_mut()
+        .map(|row| {
+            let is_valid = row[0] == VALID;
+            *row = &row[1..];
+            is_valid
+        })
+        .collect::<MutableBitmap>();
+
+    let values = StructArray::get_fields(&data_type)
+        .iter()
+        .map(|field| decode(field.data_type(), options, rows).map(Arc::from))
+        .collect::<Result<Vec<_>>>()?;
+
+    StructArray::try_new(data_type, values, validity.into())
+}
+
+fn decode_list<O: Offset>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: SortOptions,
+) -> Result<ListArray<O>> {
+    let (offsets, values, validity) = variable::decode::<i64>(rows, options)?;
+
+    // split the concatenated items of each list into one row per item
+    let field = ListArray::<O>::get_child_field(&data_type);
+    let items_options = list_items_options(options);
+    let mut items = vec![];
+    let mut items_offsets = Vec::with_capacity(offsets.len());
+    items_offsets.push(O::default());
+    for window in offsets.windows(2) {
+        let mut list = &values[window[0] as usize..window[1] as usize];
+        while !list.is_empty() {
+            let length = encoded_len(field.data_type(), items_options, list)
+                .ok_or_else(|| ArrowError::oos("Row does not match the RowConverter"))?;
+            items.push(&list[..length]);
+            list = &list[length..];
+        }
+        items_offsets.push(O::from_usize(items.len()).ok_or(ArrowError::Overflow)?);
+    }
+
+    let values = decode(field.data_type(), items_options, &mut items)?;
+    ListArray::try_new(
+        data_type,
+        items_offsets.into(),
+        values.into(),
+        validity.into(),
+    )
+}
+
+fn decode_dictionary<K: DictionaryKey>(
+    rows: &mut [&[u8]],
+    data_type: &DataType,
+    options: SortOptions,
+) -> Result<DictionaryArray<K>> {
+    let values = match data_type.to_logical_type() {
+        DataType::Dictionary(_, values, _) => decode(values, options, rows)?,
+        _ => unreachable!(),
+    };
+
+    let keys = (0..values.len())
+        .map(|key| K::from_usize(key).ok_or(ArrowError::Overflow))
+        .collect::<Result<Vec<_>>>()?;
+    let keys =
+        PrimitiveArray::<K>::new(K::PRIMITIVE.into(), keys.into(), values.validity().cloned());
+    Ok(DictionaryArray::<K>::from_data(keys, values.into()))
+}
@@ -0,0 +1,137 @@
+//! Encoding of variable-length values.
+//!
+//! A null is encoded as the null sentinel, an empty value as [`EMPTY_SENTINEL`], and a
+//! non-empty value as [`NON_EMPTY_SENTINEL`] followed by the value split in blocks of
+//! [`BLOCK_SIZE`] bytes. Each block is followed by [`BLOCK_CONTINUATION`] when more blocks
+//! follow; the last block is padded with zeros and followed by its (unpadded) length.
+//! This guarantees that the lexicographical order of the encoding is the order of the values,
+//! including values that are prefixes of other values.
+//! For descending order, every byte but the null sentinel is inverted.
+use crate::{
+    array::Offset,
+    bitmap::MutableBitmap,
+    error::{ArrowError, Result},
+};
+
+use super::{null_sentinel, Rows, SortOptions};
+
+const BLOCK_SIZE: usize = 32;
+const BLOCK_CONTINUATION: u8 = 0xFF;
+const EMPTY_SENTINEL: u8 = 1;
+const NON_EMPTY_SENTINEL: u8 = 2;
+
+#[inline]
+fn sentinel(sentinel: u8, options: SortOptions) -> u8 {
+    if options.descending {
+        !sentinel
+    } else {
+        sentinel
+    }
+}
+
+/// Appends the encoding of `value` to `out`.
+pub(super) fn encode_one(out: &mut Vec<u8>, value: Option<&[u8]>, options: SortOptions) {
+    match value {
+        None => out.push(null_sentinel(options)),
+        Some([]) => out.push(sentinel(EMPTY_SENTINEL, options)),
+        Some(value) => {
+            out.push(sentinel(NON_EMPTY_SENTINEL, options));
+            let start = out.len();
+
+            for (i, block) in value.chunks(BLOCK_SIZE).enumerate() {
+                out.extend_from_slice(block);
+                if (i + 1) * BLOCK_SIZE < value.len() {
+                    out.push(BLOCK_CONTINUATION);
+                } else {
+                    out.resize(out.len() + BLOCK_SIZE - block.len(), 0);
+                    out.push(block.len() as u8);
+                }
+            }
+
+            if options.descending {
+                out[start..].iter_mut().for_each(|x| *x = !*x)
+            }
+        }
+    }
+}
+
+/// Encodes every value of `iter` as a new row of `rows`.
+pub(super) fn encode<'a, I: Iterator<Item = Option<&'a [u8]>>>(
+    rows: &mut Rows,
+    iter: I,
+    options: SortOptions,
+) {
+    iter.for_each(|value| {
+        encode_one(&mut rows.buffer, value, options);
+        rows.end_row();
+    })
+}
+
+/// Returns the number of bytes of the encoded value at the start of `row`,
+/// or `None` if `row` is too short.
+pub(super) fn encoded_len(row: &[u8], options: SortOptions) -> Option<usize> {
+    if *row.first()? != sentinel(NON_EMPTY_SENTINEL, options) {
+        return Some(1);
+    }
+    let mut position = 1;
+    loop {
+        let marker = sentinel(*row.get(position + BLOCK_SIZE)?, options);
+        position += BLOCK_SIZE + 1;
+        if marker != BLOCK_CONTINUATION {
+            return Some(position);
+        }
+    }
+}
+
+/// Decodes the value at the start of `row`, appending it to `out`.
+/// Returns the number of bytes of the encoded value and whether the value is valid.
+pub(super) fn decode_one(row: &[u8], out: &mut Vec<u8>, options: SortOptions) -> (usize, bool) {
+    if row[0] == null_sentinel(options) {
+        return (1, false);
+    }
+    if row[0] != sentinel(NON_EMPTY_SENTINEL, options) {
+        return (1, true);
+    }
+
+    let mut position = 1;
+    loop {
+        let block = &row[position..position + BLOCK_SIZE];
+        let marker = sentinel(row[position + BLOCK_SIZE], options);
+        let length = if marker == BLOCK_CONTINUATION {
+            BLOCK_SIZE
+        } else {
+            marker as usize
+        };
+
+        let start = out.len();
+        out.extend_from_slice(&block[..length]);
+        if options.descending {
+            out[start..].iter_mut().for_each(|x| *x = !*x)
+        }
+
+        position += BLOCK_SIZE + 1;
+        if marker != BLOCK_CONTINUATION {
+            return (position, true);
+        }
+    }
+}
+
+/// Decodes one value from each row, advancing each row past its value.
+/// Returns the offsets, values and validity of the decoded values.
+pub(super) fn decode<O: Offset>(
+    rows: &mut [&[u8]],
+    options: SortOptions,
+) -> Result<(Vec<O>, Vec<u8>, MutableBitmap)> {
+    let mut offsets = Vec::with_capacity(rows.len() + 1);
+    offsets.push(O::default());
+    let mut values = vec![];
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+
+    for row in rows.iter_mut() {
+        let (length, is_valid) = decode_one(row, &mut values, options);
+        *row = &row[length..];
+        validity.push(is_valid);
+        offsets.push(O::from_usize(values.len()).ok_or(ArrowError::Overflow)?);
+    }
+    Ok((offsets, values, validity))
+}
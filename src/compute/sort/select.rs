@@ -0,0 +1,126 @@
+use std::cmp::Ordering;
+
+use crate::{
+    array::PrimitiveArray,
+    error::{ArrowError, Result},
+    types::Index,
+};
+
+use super::lex_sort::build_compare;
+use super::SortColumn;
+
+/// Returns the indices of the `k` first rows of `columns` according to their [`SortOptions`](super::SortOptions),
+/// i.e. the `k` smallest (or largest, when descending) rows, in order.
+///
+/// Contrarily to [`lexsort_to_indices`](super::lexsort_to_indices), this function does not
+/// sort all rows: it keeps the `k` first rows in a binary heap, which is `O(N log(k))`.
+/// Equal rows are ordered by their index, so that the result equals the first `k` indices
+/// of a stable sort.
+/// # Errors
+/// Errors iff `columns` is empty, the columns have different lengths or the
+/// type of any of the columns is not supported.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::sort::{select_k_indices, SortColumn, SortOptions};
+///
+/// let array = Int32Array::from(&[Some(3), None, Some(1), Some(3), Some(2)]);
+/// let columns = [SortColumn {
+///     values: &array,
+///     options: Some(SortOptions {
+///         descending: true,
+///         nulls_first: false,
+///     }),
+/// }];
+///
+/// let indices = select_k_indices::<u32>(&columns, 3).unwrap();
+/// assert_eq!(indices, UInt32Array::from_slice(&[0, 3, 4]));
+/// ```
+pub fn select_k_indices<I: Index>(columns: &[SortColumn], k: usize) -> Result<PrimitiveArray<I>> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "select_k_indices requires at least one column".to_string(),
+        ));
+    }
+    let row_count = columns[0].values.len();
+    if columns.iter().any(|item| item.values.len() != row_count) {
+        return Err(ArrowError::InvalidArgumentError(
+            "select_k_indices columns have different row counts".to_string(),
+        ));
+    };
+
+    let comparators = columns
+        .iter()
+        .map(|column| build_compare(column.values, column.options.unwrap_or_default()))
+        .collect::<Result<Vec<_>>>()?;
+
+    // total order: by the columns and then by the index
+    let compare = |a: usize, b: usize| -> Ordering {
+        comparators
+            .iter()
+            .map(|comparator| comparator(a, b))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or_else(|| a.cmp(&b))
+    };
+
+    let k = k.min(row_count);
+    // a max-heap of the `k` first rows seen so far, whose root is the last of them
+    let mut heap = Vec::<usize>::with_capacity(k);
+    if k > 0 {
+        for index in 0..row_count {
+            if heap.len() < k {
+                heap.push(index);
+                sift_up(&mut heap, &compare);
+            } else if compare(index, heap[0]) == Ordering::Less {
+                heap[0] = index;
+                sift_down(&mut heap, &compare);
+            }
+        }
+    }
+    heap.sort_unstable_by(|a, b| compare(*a, *b));
+
+    let values = heap
+        .into_iter()
+        .map(|index| I::from_usize(index).ok_or(ArrowError::Overflow))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(PrimitiveArray::<I>::new(
+        I::PRIMITIVE.into(),
+        values.into(),
+        None,
+    ))
+}
+
+/// Restores the heap property after pushing an element to the end of `heap`
+fn sift_up<F: Fn(usize, usize) -> Ordering>(heap: &mut [usize], compare: &F) {
+    let mut child = heap.len() - 1;
+    while child > 0 {
+        let parent = (child - 1) / 2;
+        if compare(heap[child], heap[parent]) != Ordering::Greater {
+            break;
+        }
+        heap.swap(child, parent);
+        child = parent;
+    }
+}
+
+/// Restores the heap property after replacing the root of `heap`
+fn sift_down<F: Fn(usize, usize) -> Ordering>(heap: &mut [usize], compare: &F) {
+    let mut parent = 0;
+    loop {
+        let left = 2 * parent + 1;
+        let right = left + 1;
+        let mut largest = parent;
+        if left < heap.len() && compare(heap[left], heap[largest]) == Ordering::Greater {
+            largest = left;
+        }
+        if right < heap.len() && compare(heap[right], heap[largest]) == Ordering::Greater {
+            largest = right;
+        }
+        if largest == parent {
+            break;
+        }
+        heap.swap(parent, largest);
+        parent = largest;
+    }
+}
@@ -0,0 +1,102 @@
+//! Contains the operator [`get_field`], to extract (nested) children of Struct and Map arrays.
+use crate::{
+    array::{Array, ListArray, MapArray, StructArray},
+    datatypes::PhysicalType,
+    error::{ArrowError, Result},
+};
+
+use super::utils::combine_validities;
+
+fn get_struct_field(array: &StructArray, name: &str) -> Result<Box<dyn Array>> {
+    let index = array
+        .fields()
+        .iter()
+        .position(|field| field.name == name)
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "get_field could not find the field \"{}\" in {:?}",
+                name,
+                array.data_type()
+            ))
+        })?;
+    let child = array.values()[index].as_ref();
+
+    // a null struct nullifies all of its children
+    if array.null_count() == 0 {
+        return Ok(child.to_boxed());
+    }
+    match child.data_type().to_physical_type() {
+        PhysicalType::Null => return Ok(child.to_boxed()),
+        PhysicalType::Union | PhysicalType::RunEndEncoded(_) => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "get_field does not support the field \"{}\" of type {:?} in a struct with nulls",
+                name,
+                child.data_type()
+            )))
+        }
+        _ => {}
+    }
+    let validity = combine_validities(array.validity(), child.validity());
+    Ok(child.with_validity(validity))
+}
+
+/// Returns the child of `array` at `path`, where every item of `path` is the name of a field
+/// of a Struct array, with the validity of its parents merged into it.
+///
+/// A Map array is traversed through its entries, i.e. the first item of `path` that follows
+/// it names the field of its entries (e.g. `"keys"` or `"values"`), and the result is then a
+/// List array with the offsets and validity of the Map array. An empty `path` returns
+/// `array` itself.
+/// # Errors
+/// This function errors when a field of `path` does not exist, when a parent is neither
+/// a Struct nor a Map array, or when a Union or RunEndEncoded field is a child of a Struct
+/// array with nulls, since their validity cannot be merged.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, StructArray};
+/// use arrow2::compute::struct_::get_field;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// let inner = StructArray::from_data(
+///     DataType::Struct(vec![Field::new("b", DataType::Int32, true)]),
+///     vec![Arc::new(Int32Array::from_slice(&[1, 2, 3]))],
+///     None,
+/// );
+/// let array = StructArray::from_data(
+///     DataType::Struct(vec![Field::new("a", inner.data_type().clone(), true)]),
+///     vec![Arc::new(inner)],
+///     Some([true, false, true].into()),
+/// );
+///
+/// let result = get_field(&array, &["a", "b"]).unwrap();
+/// let expected = Int32Array::from(&[Some(1), None, Some(3)]);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+pub fn get_field(array: &dyn Array, path: &[&str]) -> Result<Box<dyn Array>> {
+    let (name, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return Ok(array.to_boxed()),
+    };
+    match array.data_type().to_physical_type() {
+        PhysicalType::Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            get_field(get_struct_field(array, name)?.as_ref(), rest)
+        }
+        PhysicalType::Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let values = get_field(array.field().as_ref(), path)?;
+            Ok(Box::new(ListArray::<i32>::new(
+                ListArray::<i32>::default_datatype(values.data_type().clone()),
+                array.offsets().clone(),
+                values.into(),
+                array.validity().cloned(),
+            )))
+        }
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "get_field requires a Struct or Map array to get the field \"{}\", but got {:?}",
+            name,
+            array.data_type()
+        ))),
+    }
+}
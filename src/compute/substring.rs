@@ -15,11 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines kernel to extract a substring of a \[Large\]StringArray
+//! Defines kernels to extract a substring of a \[Large\]StringArray, by byte ([`substring`])
+//! or by character ([`substring_by_char`])
 
 use crate::{
     array::*,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType},
     error::{ArrowError, Result},
 };
 
@@ -160,6 +161,102 @@ pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<
     }
 }
 
+/// Returns the substring of `value` of `length` chars (or until its end) starting at the
+/// char `start`, where a negative `start` counts from the end of `value`.
+fn substring_chars(value: &str, start: i64, length: Option<u64>) -> &str {
+    let length = length.map(|x| x as usize);
+    if value.is_ascii() {
+        let len = value.len() as i64;
+        let start = if start >= 0 {
+            start.min(len)
+        } else {
+            (len + start).max(0)
+        } as usize;
+        let end = length.map_or(value.len(), |length| {
+            start.saturating_add(length).min(value.len())
+        });
+        return &value[start..end];
+    }
+
+    let start = if start >= 0 {
+        start as usize
+    } else {
+        let chars = value.chars().count() as i64;
+        (chars + start).max(0) as usize
+    };
+    let mut indices = value.char_indices().map(|(index, _)| index);
+    let start = match indices.nth(start) {
+        Some(start) => start,
+        None => return "",
+    };
+    let end = match length {
+        Some(0) => start,
+        Some(length) => indices.nth(length - 1).unwrap_or(value.len()),
+        None => value.len(),
+    };
+    &value[start..end]
+}
+
+fn utf8_substring_by_char<O: Offset>(
+    array: &Utf8Array<O>,
+    start: i64,
+    length: Option<u64>,
+) -> Utf8Array<O> {
+    let iter = array
+        .values_iter()
+        .map(|value| substring_chars(value, start, length));
+
+    let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
+    new.with_validity(array.validity().cloned())
+}
+
+/// Returns a new array with the substring of each string of `array` of `length` characters
+/// (or until the end of the string), starting at the character `start` (0-based).
+///
+/// A negative `start` counts from the end of the string, i.e. `-1` is its last character,
+/// as in Python's `value[start:start + length]`; a `start` before the beginning of the string
+/// is clamped to it. Unlike [`substring`], `start` and `length` count (unicode) characters,
+/// not bytes, so that the result is always valid UTF-8 for multi-byte characters.
+/// # Errors
+/// Errors iff `array` is not a \[Large\]Utf8 array.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::substring::substring_by_char;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("héllo"), None, Some("wörld")]);
+///
+/// let result = substring_by_char(&array, 1, Some(3)).unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("éll"), None, Some("örl")]);
+/// assert_eq!(expected, result.as_ref());
+///
+/// let result = substring_by_char(&array, -2, None).unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("lo"), None, Some("ld")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn substring_by_char(
+    array: &dyn Array,
+    start: i64,
+    length: Option<u64>,
+) -> Result<Box<dyn Array>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => Ok(Box::new(utf8_substring_by_char(
+            array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap(),
+            start,
+            length,
+        ))),
+        PhysicalType::LargeUtf8 => Ok(Box::new(utf8_substring_by_char(
+            array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap(),
+            start,
+            length,
+        ))),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "substring_by_char does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
 /// Checks if an array of type `datatype` can perform substring operation
 ///
 /// # Examples
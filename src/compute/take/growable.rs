@@ -0,0 +1,23 @@
+use crate::array::{growable::make_growable, Array, PrimitiveArray};
+
+use super::Index;
+
+/// `take` implementation for any array, via its [`Growable`](crate::array::growable::Growable).
+/// This is used for the types without a specialized implementation (e.g. unions and maps).
+pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Box<dyn Array> {
+    let mut growable = make_growable(&[values], indices.null_count() > 0, indices.len());
+
+    if indices.null_count() > 0 {
+        indices.iter().for_each(|index| match index {
+            Some(index) => growable.extend(0, index.to_usize(), 1),
+            None => growable.extend_validity(1),
+        });
+    } else {
+        indices
+            .values()
+            .iter()
+            .for_each(|index| growable.extend(0, index.to_usize(), 1));
+    }
+
+    growable.as_box()
+}
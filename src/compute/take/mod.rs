@@ -28,6 +28,7 @@ mod binary;
 mod boolean;
 mod dict;
 mod generic_binary;
+mod growable;
 mod list;
 mod primitive;
 mod structure;
@@ -37,6 +38,9 @@ pub(crate) use boolean::take as take_boolean;
 
 /// Returns a new [`Array`] with only indices at `indices`. Null indices are taken as nulls.
 /// The returned array has a length equal to `indices.len()`.
+///
+/// All types are supported; null indices on a union are taken as nulls of its first field,
+/// since unions have no validity.
 pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result<Box<dyn Array>> {
     if indices.len() == 0 {
         return Ok(new_empty_array(values.data_type().clone()));
@@ -90,11 +94,13 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let array = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(list::take::<i64, O>(array, indices)))
         }
-        t => unimplemented!("Take not supported for data type {:?}", t),
+        FixedSizeBinary | FixedSizeList | Union | Map | BinaryView | Utf8View
+        | RunEndEncoded(_) => Ok(growable::take::<O>(values, indices)),
     }
 }
 
-/// Checks if an array of type `datatype` can perform take operation
+/// Checks if an array of type `datatype` can perform take operation. This is the case of all
+/// types.
 ///
 /// # Examples
 /// ```
@@ -104,37 +110,6 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
 /// let data_type = DataType::Int8;
 /// assert_eq!(can_take(&data_type), true);
 /// ```
-pub fn can_take(data_type: &DataType) -> bool {
-    matches!(
-        data_type,
-        DataType::Null
-            | DataType::Boolean
-            | DataType::Int8
-            | DataType::Int16
-            | DataType::Int32
-            | DataType::Date32
-            | DataType::Time32(_)
-            | DataType::Interval(_)
-            | DataType::Int64
-            | DataType::Date64
-            | DataType::Time64(_)
-            | DataType::Duration(_)
-            | DataType::Timestamp(_, _)
-            | DataType::UInt8
-            | DataType::UInt16
-            | DataType::UInt32
-            | DataType::UInt64
-            | DataType::Float16
-            | DataType::Float32
-            | DataType::Float64
-            | DataType::Decimal(_, _)
-            | DataType::Utf8
-            | DataType::LargeUtf8
-            | DataType::Binary
-            | DataType::LargeBinary
-            | DataType::Struct(_)
-            | DataType::List(_)
-            | DataType::LargeList(_)
-            | DataType::Dictionary(..)
-    )
+pub fn can_take(_data_type: &DataType) -> bool {
+    true
 }
@@ -17,7 +17,10 @@
 
 //! Defines temporal kernels for time and date related functions.
 
-use chrono::{Datelike, Timelike};
+use std::fmt::{Display, Write};
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{Datelike, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone, Timelike, Utc};
 
 use crate::array::*;
 use crate::datatypes::*;
@@ -49,6 +52,17 @@ trait U32IsoWeek: Datelike {
 impl U32IsoWeek for chrono::NaiveDateTime {}
 impl<T: chrono::TimeZone> U32IsoWeek for chrono::DateTime<T> {}
 
+// Create and implement a trait that returns the quarter of a
+// `chrono::Datelike` as `u32`
+trait U32Quarter: Datelike {
+    fn u32_quarter(&self) -> u32 {
+        (self.month() - 1) / 3 + 1
+    }
+}
+
+impl U32Quarter for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> U32Quarter for chrono::DateTime<T> {}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Datelike` methods on Arrays
 macro_rules! date_like {
@@ -109,6 +123,87 @@ pub fn iso_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     date_like!(u32_iso_week, array, DataType::UInt32)
 }
 
+/// Extracts the quarter of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 1 to 4.
+/// Use [`can_quarter`] to check if this operation is supported for the target [`DataType`].
+pub fn quarter(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_like!(u32_quarter, array, DataType::UInt32)
+}
+
+/// Extracts the day of the year (ordinal day) of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 1 to 366 (Last day depends on the year).
+/// Use [`can_day_of_year`] to check if this operation is supported for the target [`DataType`].
+pub fn day_of_year(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_like!(ordinal, array, DataType::UInt32)
+}
+
+/// Extracts the day of the week of a temporal array as [`PrimitiveArray<u32>`], for weeks
+/// starting on `first_day`.
+/// `first_day` is 1, the day after it is 2, ..., the day before it is 7, so that
+/// `day_of_week(array, Weekday::Mon)` is [`weekday`].
+/// Use [`can_day_of_week`] to check if this operation is supported for the target [`DataType`]
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::temporal::day_of_week;
+/// use arrow2::datatypes::DataType;
+///
+/// // 1970-01-01 was a Thursday
+/// let array = Int32Array::from_slice(&[0, 3]).to(DataType::Date32);
+/// let result = day_of_week(&array, chrono::Weekday::Sun).unwrap();
+/// assert_eq!(result, UInt32Array::from_slice(&[5, 1]));
+/// ```
+pub fn day_of_week(array: &dyn Array, first_day: chrono::Weekday) -> Result<PrimitiveArray<u32>> {
+    let shift = first_day.num_days_from_monday();
+    let weekdays = weekday(array)?;
+    Ok(unary(
+        &weekdays,
+        |x| (x - 1 + 7 - shift) % 7 + 1,
+        DataType::UInt32,
+    ))
+}
+
+/// Extracts the number of seconds since the epoch (1970-01-01 00:00:00 UTC) of a temporal
+/// array as [`PrimitiveArray<i64>`], rounded towards negative infinity.
+/// Use [`can_epoch`] to check if this operation is supported for the target [`DataType`].
+/// # Example
+/// ```
+/// use arrow2::array::Int64Array;
+/// use arrow2::compute::temporal::epoch;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let array = Int64Array::from(&[Some(1_500), Some(-1), None])
+///     .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+/// let result = epoch(&array).unwrap();
+/// assert_eq!(result, Int64Array::from(&[Some(1), Some(-1), None]));
+/// ```
+pub fn epoch(array: &dyn Array) -> Result<PrimitiveArray<i64>> {
+    let per_second = match array.data_type().to_logical_type() {
+        DataType::Date32 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            return Ok(unary(array, |x| x as i64 * SECONDS_IN_DAY, DataType::Int64));
+        }
+        DataType::Date64 | DataType::Timestamp(TimeUnit::Millisecond, _) => MILLISECONDS,
+        DataType::Timestamp(TimeUnit::Second, _) => 1,
+        DataType::Timestamp(TimeUnit::Microsecond, _) => MICROSECONDS,
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => NANOSECONDS,
+        dt => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "\"epoch\" does not support type {:?}",
+                dt
+            )))
+        }
+    };
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .unwrap();
+    Ok(unary(array, |x| x.div_euclid(per_second), DataType::Int64))
+}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Timelike` methods on Arrays
 macro_rules! time_like {
@@ -353,6 +448,26 @@ pub fn can_iso_week(data_type: &DataType) -> bool {
     can_date(data_type)
 }
 
+/// Checks if an array of type `data_type` can perform quarter operation
+pub fn can_quarter(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform day of year operation
+pub fn can_day_of_year(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform day of week operation
+pub fn can_day_of_week(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform epoch operation
+pub fn can_epoch(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
 fn can_date(data_type: &DataType) -> bool {
     matches!(
         data_type,
@@ -401,3 +516,535 @@ fn can_time(data_type: &DataType) -> bool {
             | DataType::Timestamp(_, _)
     )
 }
+
+/// Formats every valid value of `array` with `format` to a [`Utf8Array`].
+fn format_impl<T, D, F>(array: &PrimitiveArray<T>, format: F) -> Result<Utf8Array<i32>>
+where
+    T: NativeType,
+    D: Display,
+    F: Fn(T) -> D,
+{
+    let mut values = String::new();
+    let mut offsets = Vec::<i32>::with_capacity(array.len() + 1);
+    offsets.push(0);
+    for value in array.iter() {
+        if let Some(value) = value {
+            write!(values, "{}", format(*value)).map_err(|_| {
+                ArrowError::InvalidArgumentError(
+                    "strftime format is not supported by the type".to_string(),
+                )
+            })?;
+        }
+        offsets.push(i32::try_from(values.len()).map_err(|_| ArrowError::Overflow)?);
+    }
+
+    // Safety: the values were written from `str`s, and the offsets are at their boundaries
+    Ok(unsafe {
+        Utf8Array::<i32>::new_unchecked(
+            DataType::Utf8,
+            offsets.into(),
+            values.into_bytes().into(),
+            array.validity().cloned(),
+        )
+    })
+}
+
+fn strftime_tz<T>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    items: &[Item],
+) -> Result<Utf8Array<i32>>
+where
+    T: chrono::TimeZone,
+    T::Offset: Display,
+{
+    format_impl(array, |x| {
+        timestamp_to_datetime(x, time_unit, &timezone).format_with_items(items.iter())
+    })
+}
+
+#[cfg(feature = "chrono-tz")]
+fn strftime_chrono_tz(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    items: &[Item],
+) -> Result<Utf8Array<i32>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    strftime_tz(array, time_unit, timezone, items)
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn strftime_chrono_tz(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: &[Item],
+) -> Result<Utf8Array<i32>> {
+    Err(ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Formats a temporal array to a [`Utf8Array`] with the chrono `format`, e.g. `"%Y-%m-%d"`.
+///
+/// Timestamps are formatted in `timezone` when it is provided, in their own timezone
+/// otherwise, and as naive timestamps when they have none. Null values remain null.
+/// Use [`can_strftime`] to check if this operation is supported for the target [`DataType`].
+/// # Errors
+/// This function errors when the type of `array` is not supported, `format` is invalid or
+/// contains specifiers not supported by the type (e.g. `"%H"` for `Date32`), or `timezone`
+/// cannot be parsed.
+/// The feature `"chrono-tz"` enables IANA and zoneinfo formats for timezones.
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::temporal::strftime;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let array = Int64Array::from(&[Some(1_000), None])
+///     .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+///
+/// let result = strftime(&array, "%Y-%m-%d %H:%M:%S", None).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("1970-01-01 00:00:01"), None]));
+///
+/// let result = strftime(&array, "%H:%M %z", Some("+02:00")).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("02:00 +0200"), None]));
+/// ```
+pub fn strftime(array: &dyn Array, format: &str, timezone: Option<&str>) -> Result<Utf8Array<i32>> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "\"{}\" is not a valid strftime format",
+            format
+        )));
+    }
+    let items = items.as_slice();
+
+    match array.data_type().to_logical_type() {
+        DataType::Date32 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            format_impl(array, |x| date32_to_date(x).format_with_items(items.iter()))
+        }
+        DataType::Date64 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            format_impl(array, |x| {
+                date64_to_datetime(x).format_with_items(items.iter())
+            })
+        }
+        DataType::Time32(time_unit) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let to_time = match time_unit {
+                TimeUnit::Second => time32s_to_time,
+                _ => time32ms_to_time,
+            };
+            format_impl(array, |x| to_time(x).format_with_items(items.iter()))
+        }
+        DataType::Time64(time_unit) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let to_time = match time_unit {
+                TimeUnit::Microsecond => time64us_to_time,
+                _ => time64ns_to_time,
+            };
+            format_impl(array, |x| to_time(x).format_with_items(items.iter()))
+        }
+        DataType::Timestamp(time_unit, own_timezone) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let time_unit = *time_unit;
+            match timezone.or(own_timezone.as_deref()) {
+                None => format_impl(array, |x| {
+                    timestamp_to_naive_datetime(x, time_unit).format_with_items(items.iter())
+                }),
+                Some(timezone_str) => {
+                    if let Ok(timezone) = parse_offset(timezone_str) {
+                        strftime_tz(array, time_unit, timezone, items)
+                    } else {
+                        strftime_chrono_tz(array, time_unit, timezone_str, items)
+                    }
+                }
+            }
+        }
+        dt => Err(ArrowError::NotYetImplemented(format!(
+            "\"strftime\" does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Checks if an array of type `data_type` can perform [`strftime`]
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::temporal::can_strftime;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// assert_eq!(can_strftime(&DataType::Time32(TimeUnit::Second)), true);
+/// assert_eq!(can_strftime(&DataType::Int8), false);
+/// ```
+pub fn can_strftime(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::Date32
+            | DataType::Date64
+            | DataType::Time32(_)
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _)
+    )
+}
+
+/// The unit to which [`date_trunc`] truncates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TruncUnit {
+    /// The first day of the year
+    Year,
+    /// The first day of the quarter
+    Quarter,
+    /// The first day of the month
+    Month,
+    /// The Monday of the (ISO) week
+    Week,
+    /// Midnight
+    Day,
+    /// The start of the hour
+    Hour,
+    /// The start of the minute
+    Minute,
+    /// The start of the second
+    Second,
+}
+
+fn truncate_date(date: NaiveDate, unit: TruncUnit) -> NaiveDate {
+    match unit {
+        TruncUnit::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap(),
+        TruncUnit::Quarter => {
+            NaiveDate::from_ymd_opt(date.year(), (date.month() - 1) / 3 * 3 + 1, 1).unwrap()
+        }
+        TruncUnit::Month => NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap(),
+        TruncUnit::Week => NaiveDate::from_num_days_from_ce_opt(
+            date.num_days_from_ce() - date.weekday().num_days_from_monday() as i32,
+        )
+        .unwrap(),
+        _ => date,
+    }
+}
+
+fn truncate_datetime(datetime: NaiveDateTime, unit: TruncUnit) -> NaiveDateTime {
+    let (hour, minute, second) = match unit {
+        TruncUnit::Hour => (datetime.hour(), 0, 0),
+        TruncUnit::Minute => (datetime.hour(), datetime.minute(), 0),
+        TruncUnit::Second => (datetime.hour(), datetime.minute(), datetime.second()),
+        _ => (0, 0, 0),
+    };
+    truncate_date(datetime.date(), unit)
+        .and_hms_opt(hour, minute, second)
+        .unwrap()
+}
+
+/// Returns the timestamp in `time_unit` of `datetime` (in UTC), if it fits in an `i64`.
+fn naive_datetime_to_timestamp(datetime: NaiveDateTime, time_unit: TimeUnit) -> Option<i64> {
+    let datetime = Utc.from_utc_datetime(&datetime);
+    let seconds = datetime.timestamp();
+    let nanoseconds = datetime.timestamp_subsec_nanos() as i64;
+    match time_unit {
+        TimeUnit::Second => Some(seconds),
+        TimeUnit::Millisecond => seconds
+            .checked_mul(MILLISECONDS)?
+            .checked_add(nanoseconds / 1_000_000),
+        TimeUnit::Microsecond => seconds
+            .checked_mul(MICROSECONDS)?
+            .checked_add(nanoseconds / 1_000),
+        TimeUnit::Nanosecond => seconds.checked_mul(NANOSECONDS)?.checked_add(nanoseconds),
+    }
+}
+
+/// Truncates the instant `utc` to `unit` in the local time of `timezone`, returning the
+/// (UTC) instant at which the truncated local time starts.
+fn truncate_in_timezone<T: TimeZone>(
+    utc: NaiveDateTime,
+    unit: TruncUnit,
+    timezone: &T,
+) -> NaiveDateTime {
+    let local = timezone.from_utc_datetime(&utc).naive_local();
+    let truncated = truncate_datetime(local, unit);
+    match timezone.from_local_datetime(&truncated) {
+        LocalResult::Single(datetime) => datetime.naive_utc(),
+        // the local time happens twice (e.g. when DST ends): the latest that is not after `utc`
+        LocalResult::Ambiguous(earliest, latest) => {
+            if latest.naive_utc() <= utc {
+                latest.naive_utc()
+            } else {
+                earliest.naive_utc()
+            }
+        }
+        // the local time does not exist (e.g. midnight when DST starts at midnight): the
+        // offset before the gap maps it to the end of the gap, i.e. to the first local time
+        // after it
+        LocalResult::None => {
+            let before = truncated - chrono::Duration::days(1);
+            let offset = timezone.offset_from_utc_datetime(&before).fix();
+            truncated - chrono::Duration::seconds(offset.local_minus_utc() as i64)
+        }
+    }
+}
+
+fn date_trunc_timestamps<T: TimeZone>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    unit: TruncUnit,
+    timezone: T,
+) -> PrimitiveArray<i64> {
+    let values = array.iter().map(|x| {
+        x.and_then(|x| {
+            let utc = timestamp_to_naive_datetime(*x, time_unit);
+            let truncated = truncate_in_timezone(utc, unit, &timezone);
+            naive_datetime_to_timestamp(truncated, time_unit)
+        })
+    });
+    PrimitiveArray::<i64>::from_trusted_len_iter(values).to(array.data_type().clone())
+}
+
+#[cfg(feature = "chrono-tz")]
+fn date_trunc_chrono_tz(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    unit: TruncUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    Ok(date_trunc_timestamps(array, time_unit, unit, timezone))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn date_trunc_chrono_tz(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    _: TruncUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i64>> {
+    Err(ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Truncates every value of a temporal array to `unit`, as in SQL's `DATE_TRUNC`.
+///
+/// The result has the same type as `array`. Timestamps with a timezone are truncated in the
+/// local time of their timezone, i.e. to the instant at which e.g. their local day starts,
+/// which handles DST transitions. Values whose truncation does not fit in the type are null.
+/// `Date32` arrays are left unchanged when `unit` is smaller than a day.
+/// Use [`can_date_trunc`] to check if this operation is supported for the target [`DataType`].
+/// # Errors
+/// This function errors when the type of `array` is not supported, or its timezone cannot be
+/// parsed. The feature `"chrono-tz"` enables IANA and zoneinfo formats for timezones.
+/// # Example
+/// ```
+/// use arrow2::array::Int64Array;
+/// use arrow2::compute::temporal::{date_trunc, TruncUnit};
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// // 1970-02-14 01:02:03 and 1970-01-01 00:00:00 in UTC
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some("+02:00".to_string()));
+/// let array = Int64Array::from(&[Some(3_805_323), Some(0), None]).to(data_type.clone());
+///
+/// let result = date_trunc(&array, TruncUnit::Month).unwrap();
+/// // 1970-02-01 00:00:00 and 1970-01-01 00:00:00 in +02:00
+/// let expected = Int64Array::from(&[Some(2_671_200), Some(-7_200), None]).to(data_type);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn date_trunc(array: &dyn Array, unit: TruncUnit) -> Result<Box<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::Date32 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            let op =
+                |x| truncate_date(date32_to_date(x), unit).num_days_from_ce() - EPOCH_DAYS_FROM_CE;
+            Ok(Box::new(unary(array, op, array.data_type().clone())))
+        }
+        DataType::Date64 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            let values = array.iter().map(|x| {
+                x.and_then(|x| {
+                    let truncated = truncate_datetime(date64_to_datetime(*x), unit);
+                    naive_datetime_to_timestamp(truncated, TimeUnit::Millisecond)
+                })
+            });
+            Ok(Box::new(
+                PrimitiveArray::<i64>::from_trusted_len_iter(values).to(array.data_type().clone()),
+            ))
+        }
+        DataType::Timestamp(time_unit, None) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(date_trunc_timestamps(
+                array, *time_unit, unit, Utc,
+            )))
+        }
+        DataType::Timestamp(time_unit, Some(timezone_str)) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(if let Ok(timezone) = parse_offset(timezone_str) {
+                date_trunc_timestamps(array, *time_unit, unit, timezone)
+            } else {
+                date_trunc_chrono_tz(array, *time_unit, unit, timezone_str)?
+            }))
+        }
+        dt => Err(ArrowError::NotYetImplemented(format!(
+            "\"date_trunc\" does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Checks if an array of type `data_type` can perform [`date_trunc`]
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::temporal::can_date_trunc;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// assert_eq!(can_date_trunc(&DataType::Date32), true);
+/// assert_eq!(can_date_trunc(&DataType::Time32(TimeUnit::Second)), false);
+/// ```
+pub fn can_date_trunc(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Interprets naive timestamps as local times of `timezone`, returning the corresponding UTC
+/// instants.
+fn localize_timestamps<T: TimeZone>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    data_type: DataType,
+) -> PrimitiveArray<i64> {
+    let values = array.iter().map(|x| {
+        x.and_then(|x| {
+            let local = timestamp_to_naive_datetime(*x, time_unit);
+            let datetime = timezone.from_local_datetime(&local).earliest()?;
+            naive_datetime_to_timestamp(datetime.naive_utc(), time_unit)
+        })
+    });
+    PrimitiveArray::<i64>::from_trusted_len_iter(values).to(data_type)
+}
+
+#[cfg(feature = "chrono-tz")]
+fn localize_chrono_tz(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    data_type: DataType,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    Ok(localize_timestamps(array, time_unit, timezone, data_type))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn localize_chrono_tz(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: DataType,
+) -> Result<PrimitiveArray<i64>> {
+    Err(ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+#[cfg(feature = "chrono-tz")]
+fn check_chrono_tz(timezone_str: &str) -> Result<()> {
+    parse_offset_tz(timezone_str).map(|_| ())
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn check_chrono_tz(timezone_str: &str) -> Result<()> {
+    Err(ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Converts a timestamp array to the timezone `timezone`.
+///
+/// Timestamps with a timezone represent instants in UTC, so converting them to another
+/// timezone only changes the timezone of the [`DataType`]: the values are not copied nor
+/// changed.
+/// Timestamps without a timezone are interpreted as local times of `timezone` and converted
+/// to the corresponding instants. Local times that happen twice (e.g. when DST ends) are
+/// mapped to the earliest instant; local times that do not exist (e.g. when DST starts) and
+/// instants that do not fit in the type are null.
+/// Use [`can_convert_timezone`] to check if this operation is supported for the target
+/// [`DataType`].
+/// # Errors
+/// This function errors when `array` is not a timestamp array or `timezone` cannot be parsed.
+/// The feature `"chrono-tz"` enables IANA and zoneinfo formats for timezones.
+/// # Example
+/// ```
+/// use arrow2::array::Int64Array;
+/// use arrow2::compute::temporal::convert_timezone;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// // 1970-01-01 02:00:00 (naive)
+/// let array = Int64Array::from(&[Some(7_200), None])
+///     .to(DataType::Timestamp(TimeUnit::Second, None));
+///
+/// let result = convert_timezone(&array, "+02:00".to_string()).unwrap();
+/// // 1970-01-01 02:00:00 in +02:00
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some("+02:00".to_string()));
+/// assert_eq!(result, Int64Array::from(&[Some(0), None]).to(data_type));
+///
+/// let result = convert_timezone(&result, "+01:00".to_string()).unwrap();
+/// // 1970-01-01 01:00:00 in +01:00
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+/// assert_eq!(result, Int64Array::from(&[Some(0), None]).to(data_type));
+/// ```
+pub fn convert_timezone(array: &dyn Array, timezone: String) -> Result<PrimitiveArray<i64>> {
+    let offset = parse_offset(&timezone);
+    match array.data_type().to_logical_type() {
+        DataType::Timestamp(time_unit, None) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let data_type = DataType::Timestamp(*time_unit, Some(timezone.clone()));
+            if let Ok(offset) = offset {
+                Ok(localize_timestamps(array, *time_unit, offset, data_type))
+            } else {
+                localize_chrono_tz(array, *time_unit, &timezone, data_type)
+            }
+        }
+        DataType::Timestamp(time_unit, Some(_)) => {
+            if offset.is_err() {
+                check_chrono_tz(&timezone)?;
+            }
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            Ok(array
+                .clone()
+                .to(DataType::Timestamp(*time_unit, Some(timezone))))
+        }
+        dt => Err(ArrowError::NotYetImplemented(format!(
+            "\"convert_timezone\" does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Checks if an array of type `data_type` can perform [`convert_timezone`]
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::temporal::can_convert_timezone;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// assert_eq!(can_convert_timezone(&DataType::Timestamp(TimeUnit::Second, None)), true);
+/// assert_eq!(can_convert_timezone(&DataType::Date64), false);
+/// ```
+pub fn can_convert_timezone(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Timestamp(_, _))
+}
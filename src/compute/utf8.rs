@@ -1,11 +1,41 @@
 //! Defines common maps to a [`Utf8Array`]
 
+use std::sync::Arc;
+
 use crate::{
-    array::{Array, Offset, Utf8Array},
-    datatypes::DataType,
+    array::{Array, Int64Array, ListArray, Offset, Utf8Array},
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::{DataType, PhysicalType},
     error::{ArrowError, Result},
 };
 
+use super::utils::combine_validities;
+
+/// Applies `f` to every value of `array`, where `f` writes the new value to a [`String`].
+/// Contrarily to [`utf8_apply`], this does not allocate a [`String`] per value.
+fn utf8_write<O: Offset, F: FnMut(&str, &mut String)>(
+    array: &Utf8Array<O>,
+    mut f: F,
+) -> Result<Utf8Array<O>> {
+    let mut values = String::with_capacity(array.values().len());
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    offsets.push(O::zero());
+    for value in array.values_iter() {
+        f(value, &mut values);
+        offsets.push(O::from_usize(values.len()).ok_or(ArrowError::Overflow)?);
+    }
+
+    // Safety: the values were written from `str`s, and the offsets are at their boundaries
+    Ok(unsafe {
+        Utf8Array::<O>::new_unchecked(
+            array.data_type().clone(),
+            offsets.into(),
+            values.into_bytes().into(),
+            array.validity().cloned(),
+        )
+    })
+}
+
 /// utf8_apply will apply `Fn(&str) -> String` to every value in Utf8Array.
 pub fn utf8_apply<O: Offset, F: Fn(&str) -> String>(f: F, array: &Utf8Array<O>) -> Utf8Array<O> {
     let iter = array.values_iter().map(f);
@@ -97,3 +127,617 @@ pub fn lower(array: &dyn Array) -> Result<Box<dyn Array>> {
 pub fn can_lower(data_type: &DataType) -> bool {
     matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
 }
+
+/// Writes `length` chars of `pad`, repeated as needed, to `result`.
+fn write_pad(pad: &str, length: usize, result: &mut String) {
+    result.extend(pad.chars().cycle().take(length));
+}
+
+/// Writes `value` padded to `length` chars to `result`
+fn write_padded(value: &str, length: usize, pad: &str, left: bool, result: &mut String) {
+    let chars = value.chars().count();
+    if chars >= length {
+        let end = value
+            .char_indices()
+            .nth(length)
+            .map_or(value.len(), |(index, _)| index);
+        result.push_str(&value[..end]);
+    } else if pad.is_empty() {
+        result.push_str(value);
+    } else if left {
+        write_pad(pad, length - chars, result);
+        result.push_str(value);
+    } else {
+        result.push_str(value);
+        write_pad(pad, length - chars, result);
+    }
+}
+
+fn utf8_pad(array: &dyn Array, length: usize, pad: &str, left: bool) -> Result<Box<dyn Array>> {
+    let name = if left { "lpad" } else { "rpad" };
+    match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => Ok(Box::new(utf8_write(
+            array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap(),
+            |value, result| write_padded(value, length, pad, left, result),
+        )?)),
+        PhysicalType::LargeUtf8 => Ok(Box::new(utf8_write(
+            array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap(),
+            |value, result| write_padded(value, length, pad, left, result),
+        )?)),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "{} does not support type {:?}",
+            name,
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns a new `Array` where each of the elements is left-padded with `pad` (repeated as
+/// needed) to `length` characters.
+///
+/// Elements longer than `length` characters are truncated to their first `length` characters,
+/// and elements are left unchanged when `pad` is empty, as in SQL's `LPAD`.
+/// Lengths are counted in (unicode) characters, not in bytes.
+/// this function errors when the passed array is not a \[Large\]String array.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::lpad;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("1"), None, Some("123456")]);
+/// let result = lpad(&array, 4, "0").unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("0001"), None, Some("1234")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn lpad(array: &dyn Array, length: usize, pad: &str) -> Result<Box<dyn Array>> {
+    utf8_pad(array, length, pad, true)
+}
+
+/// Returns a new `Array` where each of the elements is right-padded with `pad` (repeated as
+/// needed) to `length` characters.
+///
+/// Elements longer than `length` characters are truncated to their first `length` characters,
+/// and elements are left unchanged when `pad` is empty, as in SQL's `RPAD`.
+/// Lengths are counted in (unicode) characters, not in bytes.
+/// this function errors when the passed array is not a \[Large\]String array.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::rpad;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("ab"), None, Some("é")]);
+/// let result = rpad(&array, 5, "-=").unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("ab-=-"), None, Some("é-=-=")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn rpad(array: &dyn Array, length: usize, pad: &str) -> Result<Box<dyn Array>> {
+    utf8_pad(array, length, pad, false)
+}
+
+/// Checks if an array of type `datatype` can perform [`lpad`] and [`rpad`]
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::utf8::can_pad;
+/// use arrow2::datatypes::{DataType};
+///
+/// let data_type = DataType::Utf8;
+/// assert_eq!(can_pad(&data_type), true);
+///
+/// let data_type = DataType::Null;
+/// assert_eq!(can_pad(&data_type), false);
+/// ```
+pub fn can_pad(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::LargeUtf8 | PhysicalType::Utf8
+    )
+}
+
+fn utf8_split<O: Offset>(
+    array: &Utf8Array<O>,
+    delimiter: &str,
+    max_splits: Option<usize>,
+) -> Result<ListArray<O>> {
+    let mut values = Vec::<u8>::with_capacity(array.values().len());
+    let mut values_offsets = Vec::<O>::with_capacity(array.len() + 1);
+    values_offsets.push(O::zero());
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    offsets.push(O::zero());
+
+    let to_offset = |x: usize| O::from_usize(x).ok_or(ArrowError::Overflow);
+    for (i, value) in array.values_iter().enumerate() {
+        // nulls are empty lists
+        if array.is_valid(i) {
+            let push = |part: &str| {
+                values.extend_from_slice(part.as_bytes());
+                values_offsets.push(to_offset(values.len())?);
+                Result::Ok(())
+            };
+            match max_splits {
+                Some(max_splits) => value.splitn(max_splits + 1, delimiter).try_for_each(push)?,
+                None => value.split(delimiter).try_for_each(push)?,
+            }
+        }
+        offsets.push(to_offset(values_offsets.len() - 1)?);
+    }
+
+    // Safety: the values were split at char boundaries of `str`s
+    let values = unsafe {
+        Utf8Array::<O>::new_unchecked(
+            array.data_type().clone(),
+            values_offsets.into(),
+            values.into(),
+            None,
+        )
+    };
+    Ok(ListArray::<O>::new(
+        ListArray::<O>::default_datatype(array.data_type().clone()),
+        offsets.into(),
+        Arc::new(values),
+        array.validity().cloned(),
+    ))
+}
+
+/// Returns a new `Array` where each of the elements is split into a list of the substrings
+/// separated by `delimiter`, with at most `max_splits` splits (i.e. `max_splits + 1`
+/// substrings, where the last one contains the remainder of the element).
+///
+/// A `Utf8` array is split into a `List` of `Utf8`, and a `LargeUtf8` array into a `LargeList`
+/// of `LargeUtf8`. Null elements are null lists.
+/// # Errors
+/// This function errors when the passed array is not a \[Large\]String array, or when
+/// `delimiter` is empty.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, Utf8Array};
+/// use arrow2::compute::utf8::split;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a,b,,c"), None, Some("d")]);
+/// let result = split(&array, ",", None).unwrap();
+/// let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+///
+/// assert_eq!(result.offsets().as_slice(), &[0, 4, 4, 5]);
+/// assert_eq!(result.validity(), Some(&[true, false, true].into()));
+/// let values = Utf8Array::<i32>::from_slice(&["a", "b", "", "c", "d"]);
+/// assert_eq!(values, result.values().as_ref());
+/// ```
+pub fn split(
+    array: &dyn Array,
+    delimiter: &str,
+    max_splits: Option<usize>,
+) -> Result<Box<dyn Array>> {
+    if delimiter.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "split requires a non-empty delimiter".to_string(),
+        ));
+    }
+    match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => Ok(Box::new(utf8_split(
+            array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap(),
+            delimiter,
+            max_splits,
+        )?)),
+        PhysicalType::LargeUtf8 => Ok(Box::new(utf8_split(
+            array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap(),
+            delimiter,
+            max_splits,
+        )?)),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "split does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Checks if an array of type `datatype` can perform [`split`]
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::utf8::can_split;
+/// use arrow2::datatypes::{DataType};
+///
+/// let data_type = DataType::Utf8;
+/// assert_eq!(can_split(&data_type), true);
+///
+/// let data_type = DataType::Null;
+/// assert_eq!(can_split(&data_type), false);
+/// ```
+pub fn can_split(data_type: &DataType) -> bool {
+    can_pad(data_type)
+}
+
+/// Repeats every value of `array` `times(i)` times, where `i` is the index of the value.
+/// The size of the result is computed upfront, so that its values are allocated only once.
+fn utf8_repeat<O: Offset, F: Fn(usize) -> usize>(
+    array: &Utf8Array<O>,
+    times: F,
+    validity: Option<Bitmap>,
+) -> Result<Utf8Array<O>> {
+    let is_valid = |i: usize| validity.as_ref().map(|x| x.get_bit(i)).unwrap_or(true);
+    // nulls are empty strings
+    let times = |i: usize| if is_valid(i) { times(i) } else { 0 };
+
+    let length = array
+        .values_iter()
+        .enumerate()
+        .try_fold(0usize, |acc, (i, value)| {
+            value
+                .len()
+                .checked_mul(times(i))
+                .and_then(|len| acc.checked_add(len))
+        })
+        .ok_or(ArrowError::Overflow)?;
+    O::from_usize(length).ok_or(ArrowError::Overflow)?;
+
+    let mut values = String::with_capacity(length);
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    offsets.push(O::zero());
+    for (i, value) in array.values_iter().enumerate() {
+        if !value.is_empty() {
+            (0..times(i)).for_each(|_| values.push_str(value));
+        }
+        // cannot overflow: `values.len() <= length`, which fits in `O`
+        offsets.push(O::from_usize(values.len()).unwrap());
+    }
+
+    // Safety: the values were written from `str`s, and the offsets are at their boundaries
+    Ok(unsafe {
+        Utf8Array::<O>::new_unchecked(
+            array.data_type().clone(),
+            offsets.into(),
+            values.into_bytes().into(),
+            validity,
+        )
+    })
+}
+
+fn repeat_by_fn<F: Fn(usize) -> usize>(
+    array: &dyn Array,
+    times: F,
+    validity: Option<Bitmap>,
+) -> Result<Box<dyn Array>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => Ok(Box::new(utf8_repeat(
+            array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap(),
+            times,
+            validity,
+        )?)),
+        PhysicalType::LargeUtf8 => Ok(Box::new(utf8_repeat(
+            array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap(),
+            times,
+            validity,
+        )?)),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "repeat does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns a new `Array` where each of the elements is repeated `times` times.
+/// # Errors
+/// This function errors when the passed array is not a \[Large\]String array, or when the
+/// result does not fit in it.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::repeat;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("ab"), None, Some("")]);
+/// let result = repeat(&array, 3).unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("ababab"), None, Some("")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn repeat(array: &dyn Array, times: usize) -> Result<Box<dyn Array>> {
+    repeat_by_fn(array, |_| times, array.validity().cloned())
+}
+
+/// Returns a new `Array` where each of the elements is repeated by the corresponding
+/// element of `times`.
+///
+/// Elements whose number of repetitions is null are null, and elements repeated a negative
+/// number of times are empty.
+/// # Errors
+/// This function errors when the passed array is not a \[Large\]String array, when the arrays
+/// have different lengths, or when the result does not fit in the passed array's type.
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::utf8::repeat_by;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("ab"), Some("c"), Some("d")]);
+/// let times = Int64Array::from(&[Some(2), None, Some(-1)]);
+/// let result = repeat_by(&array, &times).unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("abab"), None, Some("")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn repeat_by(array: &dyn Array, times: &Int64Array) -> Result<Box<dyn Array>> {
+    if array.len() != times.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "repeat_by requires arrays of the same length".to_string(),
+        ));
+    }
+    let validity = combine_validities(array.validity(), times.validity());
+    let values = times.values();
+    repeat_by_fn(array, |i| values[i].max(0) as usize, validity)
+}
+
+/// Checks if an array of type `datatype` can perform [`repeat`] and [`repeat_by`]
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::utf8::can_repeat;
+/// use arrow2::datatypes::{DataType};
+///
+/// let data_type = DataType::Utf8;
+/// assert_eq!(can_repeat(&data_type), true);
+///
+/// let data_type = DataType::Null;
+/// assert_eq!(can_repeat(&data_type), false);
+/// ```
+pub fn can_repeat(data_type: &DataType) -> bool {
+    can_pad(data_type)
+}
+
+fn utf8_trim(
+    array: &dyn Array,
+    characters: &str,
+    left: bool,
+    right: bool,
+) -> Result<Box<dyn Array>> {
+    let is_trimmed = |c: char| characters.contains(c);
+    let trim = |value: &str, result: &mut String| {
+        let value = if left {
+            value.trim_start_matches(is_trimmed)
+        } else {
+            value
+        };
+        let value = if right {
+            value.trim_end_matches(is_trimmed)
+        } else {
+            value
+        };
+        result.push_str(value)
+    };
+    match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => Ok(Box::new(utf8_write(
+            array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap(),
+            trim,
+        )?)),
+        PhysicalType::LargeUtf8 => Ok(Box::new(utf8_write(
+            array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap(),
+            trim,
+        )?)),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "trim does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns a new `Array` where each of the elements has removed, from both its start and its
+/// end, the longest sequence of characters contained in `characters`, as in SQL's
+/// `TRIM(BOTH characters FROM array)`.
+/// this function errors when the passed array is not a \[Large\]String array.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::trim;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("xyaxy"), None, Some("yyy")]);
+/// let result = trim(&array, "xy").unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("a"), None, Some("")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn trim(array: &dyn Array, characters: &str) -> Result<Box<dyn Array>> {
+    utf8_trim(array, characters, true, true)
+}
+
+/// Returns a new `Array` where each of the elements has removed, from its start, the longest
+/// sequence of characters contained in `characters`, as in SQL's
+/// `TRIM(LEADING characters FROM array)`.
+/// this function errors when the passed array is not a \[Large\]String array.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::ltrim;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("  a "), None]);
+/// let result = ltrim(&array, " ").unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("a "), None]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn ltrim(array: &dyn Array, characters: &str) -> Result<Box<dyn Array>> {
+    utf8_trim(array, characters, true, false)
+}
+
+/// Returns a new `Array` where each of the elements has removed, from its end, the longest
+/// sequence of characters contained in `characters`, as in SQL's
+/// `TRIM(TRAILING characters FROM array)`.
+/// this function errors when the passed array is not a \[Large\]String array.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::rtrim;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("0.500"), None]);
+/// let result = rtrim(&array, "0").unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("0.5"), None]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn rtrim(array: &dyn Array, characters: &str) -> Result<Box<dyn Array>> {
+    utf8_trim(array, characters, false, true)
+}
+
+/// Checks if an array of type `datatype` can perform [`trim`], [`ltrim`] and [`rtrim`]
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::utf8::can_trim;
+/// use arrow2::datatypes::{DataType};
+///
+/// let data_type = DataType::Utf8;
+/// assert_eq!(can_trim(&data_type), true);
+///
+/// let data_type = DataType::Null;
+/// assert_eq!(can_trim(&data_type), false);
+/// ```
+pub fn can_trim(data_type: &DataType) -> bool {
+    can_pad(data_type)
+}
+
+/// An argument of [`concat_ws`].
+#[derive(Debug, Clone, Copy)]
+pub enum ConcatArg<'a> {
+    /// A \[Large\]String array, whose values are concatenated row by row
+    Array(&'a dyn Array),
+    /// A value concatenated to every row
+    Scalar(Option<&'a str>),
+}
+
+enum TypedArg<'a, O: Offset> {
+    Array(&'a Utf8Array<O>),
+    Scalar(Option<&'a str>),
+}
+
+impl<'a, O: Offset> TypedArg<'a, O> {
+    #[inline]
+    fn get(&self, i: usize) -> Option<&'a str> {
+        match self {
+            Self::Array(array) => array.is_valid(i).then(|| array.value(i)),
+            Self::Scalar(value) => *value,
+        }
+    }
+}
+
+fn utf8_concat_ws<O: Offset>(
+    args: &[ConcatArg],
+    len: usize,
+    separator: &str,
+    skip_nulls: bool,
+) -> Result<Utf8Array<O>> {
+    let args = args
+        .iter()
+        .map(|arg| match arg {
+            ConcatArg::Array(array) => {
+                TypedArg::Array(array.as_any().downcast_ref::<Utf8Array<O>>().unwrap())
+            }
+            ConcatArg::Scalar(value) => TypedArg::Scalar(*value),
+        })
+        .collect::<Vec<_>>();
+
+    // an upper bound of the size of the values
+    let capacity = args
+        .iter()
+        .map(|arg| match arg {
+            TypedArg::Array(array) => array.values().len(),
+            TypedArg::Scalar(value) => value.map(|x| x.len()).unwrap_or(0) * len,
+        })
+        .sum::<usize>()
+        + separator.len() * args.len().saturating_sub(1) * len;
+
+    let mut values = String::with_capacity(capacity);
+    let mut offsets = Vec::<O>::with_capacity(len + 1);
+    offsets.push(O::zero());
+    let mut validity = MutableBitmap::with_capacity(len);
+    for i in 0..len {
+        let start = values.len();
+        let mut is_valid = true;
+        let mut is_first = true;
+        for arg in args.iter() {
+            match arg.get(i) {
+                Some(value) => {
+                    if !is_first {
+                        values.push_str(separator);
+                    }
+                    values.push_str(value);
+                    is_first = false;
+                }
+                None if skip_nulls => {}
+                None => {
+                    is_valid = false;
+                    break;
+                }
+            }
+        }
+        if !is_valid {
+            values.truncate(start);
+        }
+        validity.push(is_valid);
+        offsets.push(O::from_usize(values.len()).ok_or(ArrowError::Overflow)?);
+    }
+
+    // Safety: the values were written from `str`s, and the offsets are at their boundaries
+    Ok(unsafe {
+        Utf8Array::<O>::new_unchecked(
+            Utf8Array::<O>::default_data_type(),
+            offsets.into(),
+            values.into_bytes().into(),
+            validity.into(),
+        )
+    })
+}
+
+/// Returns a new `Array` where each element is the concatenation of the elements of `args`
+/// at that row, separated by `separator`, as in SQL's `CONCAT_WS`.
+///
+/// Scalars are concatenated to every row. When `skip_nulls` is `true`, null elements are
+/// skipped and the result has no nulls (an element with only nulls is empty); otherwise, an
+/// element is null when any of the concatenated elements is.
+/// # Errors
+/// This function errors when `args` contains no array, when the arrays have different
+/// lengths or types, or when they are not \[Large\]String arrays.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::{concat_ws, ConcatArg};
+///
+/// let a = Utf8Array::<i32>::from(&[Some("a"), None, Some("c")]);
+/// let b = Utf8Array::<i32>::from(&[Some("1"), Some("2"), None]);
+/// let args = [
+///     ConcatArg::Array(&a),
+///     ConcatArg::Scalar(Some("-")),
+///     ConcatArg::Array(&b),
+/// ];
+///
+/// let result = concat_ws(&args, "/", true).unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("a/-/1"), Some("-/2"), Some("c/-")]);
+/// assert_eq!(expected, result.as_ref());
+///
+/// let result = concat_ws(&args, "/", false).unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("a/-/1"), None, None]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn concat_ws(args: &[ConcatArg], separator: &str, skip_nulls: bool) -> Result<Box<dyn Array>> {
+    let mut arrays = args.iter().filter_map(|arg| match arg {
+        ConcatArg::Array(array) => Some(*array),
+        ConcatArg::Scalar(_) => None,
+    });
+    let first = arrays.next().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("concat_ws requires at least one array".to_string())
+    })?;
+    if arrays.any(|array| array.len() != first.len() || array.data_type() != first.data_type()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "concat_ws requires arrays of the same length and type".to_string(),
+        ));
+    }
+    match first.data_type().to_physical_type() {
+        PhysicalType::Utf8 => Ok(Box::new(utf8_concat_ws::<i32>(
+            args,
+            first.len(),
+            separator,
+            skip_nulls,
+        )?)),
+        PhysicalType::LargeUtf8 => Ok(Box::new(utf8_concat_ws::<i64>(
+            args,
+            first.len(),
+            separator,
+            skip_nulls,
+        )?)),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "concat_ws does not support type {:?}",
+            first.data_type()
+        ))),
+    }
+}
@@ -4,6 +4,85 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+#[cfg(any(feature = "compute_contains", feature = "compute_if_then_else"))]
+use crate::{
+    array::{
+        new_null_array, BinaryArray, BooleanArray, NullArray, PrimitiveArray, StructArray,
+        Utf8Array,
+    },
+    scalar::{BinaryScalar, BooleanScalar, PrimitiveScalar, Scalar, StructScalar, Utf8Scalar},
+};
+
+#[cfg(any(
+    feature = "compute_aggregate",
+    feature = "compute_contains",
+    feature = "compute_value_counts"
+))]
+use crate::types::NativeType;
+#[cfg(any(
+    feature = "compute_aggregate",
+    feature = "compute_contains",
+    feature = "compute_value_counts"
+))]
+use std::hash::{Hash, Hasher};
+
+/// Returns an array of length 1 with the value of `scalar`
+#[cfg(any(feature = "compute_contains", feature = "compute_if_then_else"))]
+pub fn scalar_to_array(scalar: &dyn Scalar) -> Result<Box<dyn Array>> {
+    use crate::datatypes::PhysicalType::*;
+    let data_type = scalar.data_type().clone();
+    Ok(match data_type.to_physical_type() {
+        Null => Box::new(NullArray::new(data_type, 1)),
+        Boolean => {
+            let scalar = scalar.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            Box::new(BooleanArray::from([scalar.value()]))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let scalar = scalar.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            Box::new(PrimitiveArray::<$T>::from([scalar.value()]).to(data_type))
+        }),
+        Utf8 => {
+            let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            Box::new(Utf8Array::<i32>::from([scalar.value()]))
+        }
+        LargeUtf8 => {
+            let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            Box::new(Utf8Array::<i64>::from([scalar.value()]))
+        }
+        Binary => {
+            let scalar = scalar.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
+            Box::new(BinaryArray::<i32>::from([scalar.value()]))
+        }
+        LargeBinary => {
+            let scalar = scalar.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
+            Box::new(BinaryArray::<i64>::from([scalar.value()]))
+        }
+        Struct => {
+            let scalar = scalar.as_any().downcast_ref::<StructScalar>().unwrap();
+            let values = if scalar.is_valid() {
+                scalar
+                    .values()
+                    .iter()
+                    .map(|value| scalar_to_array(value.as_ref()).map(|x| x.into()))
+                    .collect::<Result<Vec<_>>>()?
+            } else {
+                StructArray::get_fields(&data_type)
+                    .iter()
+                    .map(|field| new_null_array(field.data_type().clone(), 1).into())
+                    .collect()
+            };
+            let validity = (!scalar.is_valid()).then(|| Bitmap::from([false]));
+            Box::new(StructArray::from_data(data_type, values, validity))
+        }
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Scalars of type {:?} are not supported",
+                scalar.data_type()
+            )))
+        }
+    })
+}
+
 pub fn combine_validities(lhs: Option<&Bitmap>, rhs: Option<&Bitmap>) -> Option<Bitmap> {
     match (lhs, rhs) {
         (Some(lhs), None) => Some(lhs.clone()),
@@ -23,3 +102,54 @@ pub fn check_same_len(lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
     }
     Ok(())
 }
+
+/// Whether `a > b`, considering NaN larger than any other value
+#[cfg(any(
+    feature = "compute_aggregate",
+    feature = "compute_cumulative",
+    feature = "compute_groupby",
+    feature = "compute_window"
+))]
+#[inline]
+#[allow(clippy::eq_op)]
+pub fn is_greater<T: PartialOrd>(a: T, b: T) -> bool {
+    // `x != x` iff `x` is NaN
+    a > b || (a != a && b == b)
+}
+
+/// A native value hashed and compared by its bytes, since floats are neither [`Hash`] nor [`Eq`]
+#[cfg(any(
+    feature = "compute_aggregate",
+    feature = "compute_contains",
+    feature = "compute_value_counts"
+))]
+pub struct ByBytes<T: NativeType>(pub T);
+
+#[cfg(any(
+    feature = "compute_aggregate",
+    feature = "compute_contains",
+    feature = "compute_value_counts"
+))]
+impl<T: NativeType> Hash for ByBytes<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_le_bytes().as_ref().hash(state)
+    }
+}
+
+#[cfg(any(
+    feature = "compute_aggregate",
+    feature = "compute_contains",
+    feature = "compute_value_counts"
+))]
+impl<T: NativeType> PartialEq for ByBytes<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_le_bytes().as_ref() == other.0.to_le_bytes().as_ref()
+    }
+}
+
+#[cfg(any(
+    feature = "compute_aggregate",
+    feature = "compute_contains",
+    feature = "compute_value_counts"
+))]
+impl<T: NativeType> Eq for ByBytes<T> {}
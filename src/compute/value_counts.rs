@@ -0,0 +1,129 @@
+//! Contains the operator [`value_counts`], to count the occurrences of each distinct value
+//! of an [`Array`].
+use std::collections::{hash_map::Entry, HashMap};
+use std::hash::Hash;
+
+use ahash::RandomState;
+
+use crate::{
+    array::*,
+    compute::take::take,
+    datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+use super::utils::ByBytes;
+
+/// Returns the group of each item of `iter`, and the index of the first item of each group.
+fn groups<K: Hash + Eq, I: Iterator<Item = K>>(iter: I) -> (Vec<usize>, Vec<usize>) {
+    let mut map = HashMap::<K, usize, RandomState>::default();
+    let mut first = vec![];
+    let groups = iter
+        .enumerate()
+        .map(|(index, key)| match map.entry(key) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                first.push(index);
+                *entry.insert(first.len() - 1)
+            }
+        })
+        .collect();
+    (groups, first)
+}
+
+fn primitive_groups<T: NativeType>(array: &dyn Array) -> (Vec<usize>, Vec<usize>) {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    groups(array.iter().map(|x| x.map(|x| ByBytes(*x))))
+}
+
+fn binary_groups<O: Offset>(array: &dyn Array) -> (Vec<usize>, Vec<usize>) {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    groups(array.iter())
+}
+
+fn utf8_groups<O: Offset>(array: &dyn Array) -> (Vec<usize>, Vec<usize>) {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    groups(array.iter())
+}
+
+fn dictionary_groups<K: DictionaryKey>(array: &dyn Array) -> Result<(Vec<usize>, Vec<usize>)> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = array.values().as_ref();
+    // keys pointing to equal values must be in the same group
+    let (value_groups, _) = dyn_groups(values)?;
+    Ok(groups(array.keys().iter().map(|key| {
+        key.map(|key| key.to_usize().unwrap())
+            .filter(|key| values.is_valid(*key))
+            .map(|key| value_groups[key])
+    })))
+}
+
+fn dyn_groups(array: &dyn Array) -> Result<(Vec<usize>, Vec<usize>)> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Null => groups((0..array.len()).map(|_| ())),
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            groups(array.iter())
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            primitive_groups::<$T>(array)
+        }),
+        Binary => binary_groups::<i32>(array),
+        LargeBinary => binary_groups::<i64>(array),
+        Utf8 => utf8_groups::<i32>(array),
+        LargeUtf8 => utf8_groups::<i64>(array),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            dictionary_groups::<$T>(array)?
+        }),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "value_counts does not support the type {:?}",
+                array.data_type()
+            )))
+        }
+    })
+}
+
+/// Whether [`value_counts`] supports `data_type`.
+pub fn can_value_counts(data_type: &DataType) -> bool {
+    use PhysicalType::*;
+    matches!(
+        data_type.to_physical_type(),
+        Null | Boolean | Primitive(_) | Binary | LargeBinary | Utf8 | LargeUtf8 | Dictionary(_)
+    )
+}
+
+/// Returns the distinct values of `array` and the number of times each of them occurs on it.
+///
+/// The distinct values are of the same type as `array` and in the order of their first
+/// occurrence; nulls are counted together as a single (null) value.
+/// Floats are compared by their bit representation, so that all `NaN`s with the same bits
+/// are counted together. Dictionary arrays are counted by their values, i.e. keys pointing to
+/// equal values are counted together, and a key pointing to a null value is counted as a null.
+/// # Errors
+/// Errors iff the type of `array` is not supported, see [`can_value_counts`].
+/// # Example
+/// ```
+/// use arrow2::array::{Array, UInt64Array, Utf8Array};
+/// use arrow2::compute::value_counts::value_counts;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("b"), Some("a"), None, Some("a")]);
+/// let (values, counts) = value_counts(&array).unwrap();
+///
+/// let expected = Utf8Array::<i32>::from(&[Some("a"), None, Some("b")]);
+/// assert_eq!(values.as_ref(), &expected as &dyn Array);
+/// assert_eq!(counts, UInt64Array::from_slice(&[3, 2, 1]));
+/// ```
+pub fn value_counts(array: &dyn Array) -> Result<(Box<dyn Array>, UInt64Array)> {
+    let (groups, first) = dyn_groups(array)?;
+
+    let mut counts = vec![0u64; first.len()];
+    groups.into_iter().for_each(|group| counts[group] += 1);
+
+    let indices = UInt64Array::from_vec(first.into_iter().map(|index| index as u64).collect());
+    let values = take(array, &indices)?;
+
+    Ok((values, UInt64Array::from_vec(counts)))
+}
@@ -15,14 +15,23 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines windowing functions, like `shift`ing
+//! Defines windowing functions, like `shift`ing, [`lead`], [`lag`] and rolling aggregates
+//! such as [`rolling_sum`]
 
-use crate::compute::concatenate::concatenate;
-use num_traits::{abs, clamp};
+use std::collections::VecDeque;
+
+use crate::compute::{concatenate::concatenate, utils::is_greater};
+use num_traits::{abs, clamp, AsPrimitive, Float};
 
 use crate::{
-    array::{new_null_array, Array},
+    array::{
+        make_mutable_array, new_null_array, Array, Float64Array, PrimitiveArray, TryPushScalar,
+    },
+    bitmap::MutableBitmap,
+    datatypes::DataType,
     error::{ArrowError, Result},
+    scalar::Scalar,
+    types::NativeType,
 };
 
 /// Shifts array by defined number of items (to left or right)
@@ -63,3 +72,492 @@ pub fn shift(array: &dyn Array, offset: i64) -> Result<Box<dyn Array>> {
         concatenate(&[slice.as_ref(), null_array.as_ref()])
     }
 }
+
+/// Returns an array of `length` slots of `default`, or of nulls when `default` is `None` or null.
+fn fill(
+    data_type: &DataType,
+    length: usize,
+    default: Option<&dyn Scalar>,
+) -> Result<Box<dyn Array>> {
+    match default {
+        Some(default) if default.data_type() != data_type => {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "The default value must be of the array's type. Default is of type {:?}, array is of type {:?}",
+                default.data_type(),
+                data_type
+            )))
+        }
+        Some(default) if default.is_valid() => {
+            let mut array = make_mutable_array(data_type, length)?;
+            (0..length).try_for_each(|_| array.as_mut().try_push_scalar(default))?;
+            Ok(array.as_box())
+        }
+        _ => Ok(new_null_array(data_type.clone(), length)),
+    }
+}
+
+/// Returns an array whose slot `i` is the slot `i + offset` of `array`, or `default` (null if
+/// `None`) when `i + offset` is out of bounds.
+///
+/// `offset` can be larger than the length of the array, in which case all slots are `default`.
+/// # Errors
+/// Errors iff `default`'s [`DataType`] differs from `array`'s or
+/// [`make_mutable_array`] does not support it.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::lead;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let result = lead(&array, 1, None).unwrap();
+/// assert_eq!(Int32Array::from(&[None, Some(3), None]), result.as_ref());
+///
+/// let result = lead(&array, 2, Some(&PrimitiveScalar::from(Some(0i32)))).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(3), Some(0), Some(0)]), result.as_ref());
+/// ```
+pub fn lead(
+    array: &dyn Array,
+    offset: usize,
+    default: Option<&dyn Scalar>,
+) -> Result<Box<dyn Array>> {
+    let offset = offset.min(array.len());
+    let slice = array.slice(offset, array.len() - offset);
+    let fill = fill(array.data_type(), offset, default)?;
+    concatenate(&[slice.as_ref(), fill.as_ref()])
+}
+
+/// Returns an array whose slot `i` is the slot `i - offset` of `array`, or `default` (null if
+/// `None`) when `i < offset`.
+///
+/// `offset` can be larger than the length of the array, in which case all slots are `default`.
+/// # Errors
+/// Errors iff `default`'s [`DataType`] differs from `array`'s or
+/// [`make_mutable_array`] does not support it.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::window::lag;
+/// use arrow2::scalar::Utf8Scalar;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("c")]);
+/// let result = lag(&array, 1, Some(&Utf8Scalar::<i32>::new(Some("z")))).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from(&[Some("z"), Some("a"), None]), result.as_ref());
+/// ```
+pub fn lag(
+    array: &dyn Array,
+    offset: usize,
+    default: Option<&dyn Scalar>,
+) -> Result<Box<dyn Array>> {
+    let offset = offset.min(array.len());
+    let slice = array.slice(0, array.len() - offset);
+    let fill = fill(array.data_type(), offset, default)?;
+    concatenate(&[fill.as_ref(), slice.as_ref()])
+}
+
+fn rolling_not_supported(name: &str, data_type: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "`{}` does not support type `{:?}`",
+        name, data_type
+    ))
+}
+
+fn check_window(window: usize, min_periods: usize) -> Result<()> {
+    if window == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "The window of a rolling aggregate must be larger than zero".to_string(),
+        ));
+    }
+    if min_periods > window {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The min_periods of a rolling aggregate must be smaller or equal to its window. min_periods is {}, window is {}",
+            min_periods, window
+        )));
+    }
+    Ok(())
+}
+
+/// The running sum of the values in a window, to which values are added and from which they
+/// are removed as the window slides (see [`RollingSum`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WindowSum<T> {
+    sum: T,
+    // the error of `sum` of a compensated (Neumaier) summation of floats
+    compensation: T,
+    // the number of NaNs, +inf and -inf in the window, which are not added to `sum` so that
+    // they do not leave it NaN once they leave the window
+    nans: usize,
+    positive_infinities: usize,
+    negative_infinities: usize,
+}
+
+/// A numeric type whose rolling sum can be computed by [`rolling_sum_primitive`].
+///
+/// Integers wrap around on overflow. Floats are summed with compensation, so that removing a
+/// large value from the window does not lose the small ones (e.g. the sum of `[1e17, 1]` minus
+/// `1e17` is `1`), and non-finite values only affect the sum of the windows that contain them.
+pub trait RollingSum: NativeType {
+    /// Adds `value` to `window`
+    fn add(window: &mut WindowSum<Self>, value: Self);
+    /// Removes `value`, previously added, from `window`
+    fn remove(window: &mut WindowSum<Self>, value: Self);
+    /// Returns the sum of the values of `window`
+    fn sum(window: &WindowSum<Self>) -> Self;
+}
+
+macro_rules! integer_rolling_sum {
+    ($type:ty) => {
+        impl RollingSum for $type {
+            #[inline]
+            fn add(window: &mut WindowSum<Self>, value: Self) {
+                window.sum = window.sum.wrapping_add(value);
+            }
+
+            #[inline]
+            fn remove(window: &mut WindowSum<Self>, value: Self) {
+                window.sum = window.sum.wrapping_sub(value);
+            }
+
+            #[inline]
+            fn sum(window: &WindowSum<Self>) -> Self {
+                window.sum
+            }
+        }
+    };
+}
+
+integer_rolling_sum!(i8);
+integer_rolling_sum!(i16);
+integer_rolling_sum!(i32);
+integer_rolling_sum!(i64);
+integer_rolling_sum!(i128);
+integer_rolling_sum!(u8);
+integer_rolling_sum!(u16);
+integer_rolling_sum!(u32);
+integer_rolling_sum!(u64);
+
+#[inline]
+fn add_finite<T: Float>(window: &mut WindowSum<T>, value: T) {
+    let sum = window.sum + value;
+    window.compensation = if window.sum.abs() >= value.abs() {
+        window.compensation + ((window.sum - sum) + value)
+    } else {
+        window.compensation + ((value - sum) + window.sum)
+    };
+    window.sum = sum;
+}
+
+#[inline]
+fn count_non_finite<T: Float>(window: &mut WindowSum<T>, value: T) -> &mut usize {
+    if value.is_nan() {
+        &mut window.nans
+    } else if value > T::zero() {
+        &mut window.positive_infinities
+    } else {
+        &mut window.negative_infinities
+    }
+}
+
+macro_rules! float_rolling_sum {
+    ($type:ty) => {
+        impl RollingSum for $type {
+            #[inline]
+            fn add(window: &mut WindowSum<Self>, value: Self) {
+                if value.is_finite() {
+                    add_finite(window, value)
+                } else {
+                    *count_non_finite(window, value) += 1
+                }
+            }
+
+            #[inline]
+            fn remove(window: &mut WindowSum<Self>, value: Self) {
+                if value.is_finite() {
+                    add_finite(window, -value)
+                } else {
+                    *count_non_finite(window, value) -= 1
+                }
+            }
+
+            #[inline]
+            fn sum(window: &WindowSum<Self>) -> Self {
+                match (
+                    window.nans > 0,
+                    window.positive_infinities > 0,
+                    window.negative_infinities > 0,
+                ) {
+                    (true, _, _) | (false, true, true) => Self::NAN,
+                    (false, true, false) => Self::INFINITY,
+                    (false, false, true) => Self::NEG_INFINITY,
+                    (false, false, false) => window.sum + window.compensation,
+                }
+            }
+        }
+    };
+}
+
+float_rolling_sum!(f32);
+float_rolling_sum!(f64);
+
+/// Returns the rolling sum of a [`PrimitiveArray`]. See [`rolling_sum`] for details.
+/// # Panics
+/// Panics iff `window` is zero.
+pub fn rolling_sum_primitive<T: RollingSum>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+) -> PrimitiveArray<T> {
+    assert!(window > 0);
+    let mut sum = WindowSum::<T>::default();
+    let mut count = 0;
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    let values = (0..array.len())
+        .map(|i| {
+            if array.is_valid(i) {
+                T::add(&mut sum, array.value(i));
+                count += 1;
+            }
+            if i >= window && array.is_valid(i - window) {
+                T::remove(&mut sum, array.value(i - window));
+                count -= 1;
+                if count == 0 {
+                    // drop the rounding errors accumulated by the values that left the window
+                    sum = WindowSum::default();
+                }
+            }
+            validity.push(count >= min_periods);
+            T::sum(&sum)
+        })
+        .collect::<Vec<_>>();
+
+    PrimitiveArray::new(array.data_type().clone(), values.into(), validity.into())
+}
+
+/// Returns the rolling mean of a [`PrimitiveArray`]. See [`rolling_mean`] for details.
+/// # Panics
+/// Panics iff `window` is zero.
+pub fn rolling_mean_primitive<T>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+) -> Float64Array
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    assert!(window > 0);
+    let min_periods = min_periods.max(1);
+    let mut sum = WindowSum::<f64>::default();
+    let mut count = 0;
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    let values = (0..array.len())
+        .map(|i| {
+            if array.is_valid(i) {
+                f64::add(&mut sum, array.value(i).as_());
+                count += 1;
+            }
+            if i >= window && array.is_valid(i - window) {
+                f64::remove(&mut sum, array.value(i - window).as_());
+                count -= 1;
+                if count == 0 {
+                    sum = WindowSum::default();
+                }
+            }
+            validity.push(count >= min_periods);
+            if count > 0 {
+                f64::sum(&sum) / count as f64
+            } else {
+                0.0
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Float64Array::new(DataType::Float64, values.into(), validity.into())
+}
+
+/// Rolling extremum via a monotonic queue of the indices of the window's candidates,
+/// where `is_better(a, b)` is whether `a` is a better candidate than `b`.
+fn rolling_extremum<T, F>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+    is_better: F,
+) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+    F: Fn(T, T) -> bool,
+{
+    assert!(window > 0);
+    let min_periods = min_periods.max(1);
+    let mut candidates = VecDeque::<usize>::with_capacity(window);
+    let mut count = 0;
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    let values = (0..array.len())
+        .map(|i| {
+            if array.is_valid(i) {
+                let value = array.value(i);
+                while candidates
+                    .back()
+                    .map(|back| !is_better(array.value(*back), value))
+                    .unwrap_or(false)
+                {
+                    candidates.pop_back();
+                }
+                candidates.push_back(i);
+                count += 1;
+            }
+            if i >= window {
+                if array.is_valid(i - window) {
+                    count -= 1;
+                }
+                if candidates.front() == Some(&(i - window)) {
+                    candidates.pop_front();
+                }
+            }
+            validity.push(count >= min_periods);
+            candidates
+                .front()
+                .map(|front| array.value(*front))
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    PrimitiveArray::new(array.data_type().clone(), values.into(), validity.into())
+}
+
+/// Returns the rolling minimum of a [`PrimitiveArray`]. See [`rolling_min`] for details.
+/// # Panics
+/// Panics iff `window` is zero.
+pub fn rolling_min_primitive<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+) -> PrimitiveArray<T> {
+    rolling_extremum(array, window, min_periods, |a, b| is_greater(b, a))
+}
+
+/// Returns the rolling maximum of a [`PrimitiveArray`]. See [`rolling_max`] for details.
+/// # Panics
+/// Panics iff `window` is zero.
+pub fn rolling_max_primitive<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+) -> PrimitiveArray<T> {
+    rolling_extremum(array, window, min_periods, is_greater)
+}
+
+/// Returns the rolling sum of `array`, whose slot `i` is the sum of the non-null values
+/// of the slots `i + 1 - window..=i` of `array`.
+///
+/// A slot is null when its window has fewer than `min_periods` non-null values. The result
+/// has the same [`DataType`] as `array`. Integers wrap around on overflow, and the sum of
+/// floats is compensated and only NaN (or infinite) in the windows with a NaN (or an infinity)
+/// (see [`RollingSum`]).
+///
+/// This function is `O(N)` and independent of `window`.
+/// # Errors
+/// Errors iff `window` is zero, `min_periods > window` or `array` is not numeric.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::rolling_sum;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+/// let result = rolling_sum(&array, 2, 1).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(1), Some(1), Some(3), Some(7)]), result.as_ref());
+///
+/// let result = rolling_sum(&array, 2, 2).unwrap();
+/// assert_eq!(Int32Array::from(&[None, None, None, Some(7)]), result.as_ref());
+/// ```
+pub fn rolling_sum(array: &dyn Array, window: usize, min_periods: usize) -> Result<Box<dyn Array>> {
+    check_window(window, min_periods)?;
+    with_match_arithmetic_type!(array.data_type(), rolling_not_supported("rolling_sum", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(rolling_sum_primitive::<$T>(array, window, min_periods)))
+    })
+}
+
+/// Returns the rolling mean of `array` as a [`Float64Array`], whose slot `i` is the mean of
+/// the non-null values of the slots `i + 1 - window..=i` of `array`.
+///
+/// A slot is null when its window has fewer than `min_periods` (and at least one)
+/// non-null values.
+///
+/// This function is `O(N)` and independent of `window`.
+/// # Errors
+/// Errors iff `window` is zero, `min_periods > window` or `array` is not numeric.
+/// # Examples
+/// ```
+/// use arrow2::array::{Float64Array, Int32Array};
+/// use arrow2::compute::window::rolling_mean;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+/// let result = rolling_mean(&array, 3, 2).unwrap();
+/// assert_eq!(Float64Array::from(&[None, None, Some(2.0), Some(3.5)]), result.as_ref());
+/// ```
+pub fn rolling_mean(
+    array: &dyn Array,
+    window: usize,
+    min_periods: usize,
+) -> Result<Box<dyn Array>> {
+    check_window(window, min_periods)?;
+    if let DataType::Decimal(_, _) = array.data_type().to_logical_type() {
+        return Err(rolling_not_supported("rolling_mean", array.data_type()));
+    }
+    with_match_arithmetic_type!(array.data_type(), rolling_not_supported("rolling_mean", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(rolling_mean_primitive::<$T>(array, window, min_periods)))
+    })
+}
+
+/// Returns the rolling minimum of `array`, whose slot `i` is the minimum of the non-null values
+/// of the slots `i + 1 - window..=i` of `array`. NaNs are larger than any other value.
+///
+/// A slot is null when its window has fewer than `min_periods` (and at least one)
+/// non-null values. The result has the same [`DataType`] as `array`.
+///
+/// This function is `O(N)` and independent of `window`.
+/// # Errors
+/// Errors iff `window` is zero, `min_periods > window` or `array` is not numeric.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::rolling_min;
+///
+/// let array = Int32Array::from(&[Some(3), None, Some(1), Some(4)]);
+/// let result = rolling_min(&array, 2, 1).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(3), Some(3), Some(1), Some(1)]), result.as_ref());
+/// ```
+pub fn rolling_min(array: &dyn Array, window: usize, min_periods: usize) -> Result<Box<dyn Array>> {
+    check_window(window, min_periods)?;
+    with_match_arithmetic_type!(array.data_type(), rolling_not_supported("rolling_min", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(rolling_min_primitive::<$T>(array, window, min_periods)))
+    })
+}
+
+/// Returns the rolling maximum of `array`, whose slot `i` is the maximum of the non-null values
+/// of the slots `i + 1 - window..=i` of `array`. NaNs are larger than any other value.
+///
+/// A slot is null when its window has fewer than `min_periods` (and at least one)
+/// non-null values. The result has the same [`DataType`] as `array`.
+///
+/// This function is `O(N)` and independent of `window`.
+/// # Errors
+/// Errors iff `window` is zero, `min_periods > window` or `array` is not numeric.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::rolling_max;
+///
+/// let array = Int32Array::from(&[Some(3), None, Some(1), Some(4)]);
+/// let result = rolling_max(&array, 2, 1).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(3), Some(3), Some(1), Some(4)]), result.as_ref());
+/// ```
+pub fn rolling_max(array: &dyn Array, window: usize, min_periods: usize) -> Result<Box<dyn Array>> {
+    check_window(window, min_periods)?;
+    with_match_arithmetic_type!(array.data_type(), rolling_not_supported("rolling_max", array.data_type()), |$T| {
+        let array = array.as_any().downcast_ref().unwrap();
+        Ok(Box::new(rolling_max_primitive::<$T>(array, window, min_periods)))
+    })
+}
@@ -99,6 +99,13 @@ pub enum DataType {
     Utf8,
     /// A variable-length UTF-8 encoded string whose offsets are represented as [`i64`].
     LargeUtf8,
+    /// Opaque binary data of variable length represented by views: each value is a 16-byte
+    /// view that either inlines the value (up to 12 bytes) or holds a 4-byte prefix of it
+    /// and its location in one of multiple data buffers.
+    BinaryView,
+    /// A variable-length UTF-8 encoded string represented by views.
+    /// See [`DataType::BinaryView`] for its layout.
+    Utf8View,
     /// A list of some logical data type whose offsets are represented as [`i32`].
     List(Box<Field>),
     /// A list of some logical data type with a fixed number of elements.
@@ -149,11 +156,25 @@ pub enum DataType {
     ///
     /// The `bool` value indicates the `Dictionary` is sorted if set to `true`.
     Dictionary(IntegerType, Box<DataType>, bool),
+    /// A run-end encoded array (`run_ends_type`, `values`), where each run of equal
+    /// consecutive values is stored once, together with the (exclusive) logical index
+    /// at which the run ends.
+    ///
+    /// Run-end encoded arrays are used to store columns of highly repetitive values
+    /// (e.g. time-series) using less memory than dictionary encoding, at the cost of
+    /// a `O(log(runs))` random access.
+    ///
+    /// Only [`IntegerType::Int16`], [`IntegerType::Int32`] and [`IntegerType::Int64`]
+    /// are valid run end types.
+    RunEndEncoded(IntegerType, Box<Field>),
     /// Decimal value with precision and scale
     /// precision is the number of digits in the number and
     /// scale is the number of decimal places.
     /// The number 999.99 has a precision of 5 and scale of 2.
     Decimal(usize, usize),
+    /// Decimal value with precision and scale, like [`DataType::Decimal`] but backed by a
+    /// 256-bit integer ([`i256`](crate::types::i256)), so that the precision can be up to 76.
+    Decimal256(usize, usize),
     /// Extension type.
     Extension(String, Box<DataType>, Option<String>),
 }
@@ -233,11 +254,12 @@ impl DataType {
                 PhysicalType::Primitive(PrimitiveType::Int64)
             }
             Decimal(_, _) => PhysicalType::Primitive(PrimitiveType::Int128),
+            Decimal256(_, _) => PhysicalType::Primitive(PrimitiveType::Int256),
             UInt8 => PhysicalType::Primitive(PrimitiveType::UInt8),
             UInt16 => PhysicalType::Primitive(PrimitiveType::UInt16),
             UInt32 => PhysicalType::Primitive(PrimitiveType::UInt32),
             UInt64 => PhysicalType::Primitive(PrimitiveType::UInt64),
-            Float16 => unreachable!(),
+            Float16 => PhysicalType::Primitive(PrimitiveType::Float16),
             Float32 => PhysicalType::Primitive(PrimitiveType::Float32),
             Float64 => PhysicalType::Primitive(PrimitiveType::Float64),
             Interval(IntervalUnit::DayTime) => PhysicalType::Primitive(PrimitiveType::DaysMs),
@@ -249,6 +271,8 @@ impl DataType {
             LargeBinary => PhysicalType::LargeBinary,
             Utf8 => PhysicalType::Utf8,
             LargeUtf8 => PhysicalType::LargeUtf8,
+            BinaryView => PhysicalType::BinaryView,
+            Utf8View => PhysicalType::Utf8View,
             List(_) => PhysicalType::List,
             FixedSizeList(_, _) => PhysicalType::FixedSizeList,
             LargeList(_) => PhysicalType::LargeList,
@@ -256,6 +280,7 @@ impl DataType {
             Union(_, _, _) => PhysicalType::Union,
             Map(_, _) => PhysicalType::Map,
             Dictionary(key, _, _) => PhysicalType::Dictionary(*key),
+            RunEndEncoded(run_ends, _) => PhysicalType::RunEndEncoded(*run_ends),
             Extension(_, key, _) => key.to_physical_type(),
         }
     }
@@ -299,6 +324,8 @@ impl From<PrimitiveType> for DataType {
             PrimitiveType::UInt32 => DataType::UInt32,
             PrimitiveType::UInt64 => DataType::UInt64,
             PrimitiveType::Int128 => DataType::Decimal(32, 32),
+            PrimitiveType::Int256 => DataType::Decimal256(32, 32),
+            PrimitiveType::Float16 => DataType::Float16,
             PrimitiveType::Float32 => DataType::Float32,
             PrimitiveType::Float64 => DataType::Float64,
             PrimitiveType::DaysMs => DataType::Interval(IntervalUnit::DayTime),
@@ -25,6 +25,10 @@ pub enum PhysicalType {
     Utf8,
     /// A variable-length string in Unicode with UFT-8 encoding and 64-bit offsets.
     LargeUtf8,
+    /// Opaque binary data of variable length represented by views.
+    BinaryView,
+    /// A variable-length string in Unicode with UTF-8 encoding represented by views.
+    Utf8View,
     /// A list of some data type with variable length.
     List,
     /// A list of some data type with fixed length.
@@ -39,6 +43,8 @@ pub enum PhysicalType {
     Map,
     /// A dictionary encoded array by `IntegerType`.
     Dictionary(IntegerType),
+    /// A run-end encoded array whose run ends are represented by `IntegerType`.
+    RunEndEncoded(IntegerType),
 }
 
 impl PhysicalType {
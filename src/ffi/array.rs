@@ -44,6 +44,13 @@ pub unsafe fn try_from<A: ArrowArrayRef>(array: A) -> Result<Box<dyn Array>> {
         }
         Union => Box::new(UnionArray::try_from_ffi(array)?),
         Map => Box::new(MapArray::try_from_ffi(array)?),
+        BinaryView => Box::new(BinaryViewArray::try_from_ffi(array)?),
+        Utf8View => Box::new(Utf8ViewArray::try_from_ffi(array)?),
+        RunEndEncoded(run_end_type) => {
+            try_match_run_end_type!(run_end_type, |$T| {
+                Box::new(RunEndEncodedArray::<$T>::try_from_ffi(array)?)
+            })
+        }
     })
 }
 
@@ -89,6 +96,28 @@ struct PrivateData {
     buffers_ptr: Box<[*const std::os::raw::c_void]>,
     children_ptr: Box<[*mut ArrowArray]>,
     dictionary_ptr: Option<*mut ArrowArray>,
+    variadic_buffer_sizes: Option<Box<[i64]>>,
+}
+
+/// Returns the size of each data buffer of a view array, which the C data interface
+/// requires as the last buffer of view arrays.
+fn variadic_buffer_sizes(array: &dyn Array) -> Option<Box<[i64]>> {
+    fn sizes<T: ViewType + ?Sized>(array: &dyn Array) -> Box<[i64]> {
+        let array = array
+            .as_any()
+            .downcast_ref::<BinaryViewArrayGeneric<T>>()
+            .unwrap();
+        array
+            .data_buffers()
+            .iter()
+            .map(|buffer| buffer.len() as i64)
+            .collect()
+    }
+    match array.data_type().to_physical_type() {
+        PhysicalType::BinaryView => Some(sizes::<[u8]>(array)),
+        PhysicalType::Utf8View => Some(sizes::<str>(array)),
+        _ => None,
+    }
 }
 
 impl ArrowArray {
@@ -100,14 +129,21 @@ impl ArrowArray {
         let (offset, buffers, children, dictionary) =
             offset_buffers_children_dictionary(array.as_ref());
 
+        let variadic_buffer_sizes = variadic_buffer_sizes(array.as_ref());
+
         let buffers_ptr = buffers
             .iter()
             .map(|maybe_buffer| match maybe_buffer {
                 Some(b) => b.as_ptr() as *const std::os::raw::c_void,
                 None => std::ptr::null(),
             })
+            .chain(
+                variadic_buffer_sizes
+                    .as_ref()
+                    .map(|sizes| sizes.as_ptr() as *const std::os::raw::c_void),
+            )
             .collect::<Box<[_]>>();
-        let n_buffers = buffers.len() as i64;
+        let n_buffers = buffers_ptr.len() as i64;
 
         let children_ptr = children
             .into_iter()
@@ -126,6 +162,7 @@ impl ArrowArray {
             buffers_ptr,
             children_ptr,
             dictionary_ptr,
+            variadic_buffer_sizes,
         });
 
         Self {
@@ -249,6 +286,8 @@ fn buffer_offset(array: &ArrowArray, data_type: &DataType, i: usize) -> usize {
     use PhysicalType::*;
     match (data_type.to_physical_type(), i) {
         (LargeUtf8, 2) | (LargeBinary, 2) | (Utf8, 2) | (Binary, 2) => 0,
+        // data buffers and buffer sizes of view arrays are not offsetted
+        (Utf8View, i) | (BinaryView, i) if i >= 2 => 0,
         _ => array.offset as usize,
     }
 }
@@ -304,6 +343,17 @@ fn buffer_len(array: &ArrowArray, data_type: &DataType, i: usize) -> Result<usiz
             // get last offset
             (unsafe { *offset_buffer.add(len - 1) }) as usize
         }
+        (PhysicalType::Utf8View, i) | (PhysicalType::BinaryView, i) if i >= 2 => {
+            // the last buffer contains the size of each data buffer (buffers 2..n_buffers - 1)
+            let n_buffers = array.n_buffers as usize;
+            if i == n_buffers - 1 {
+                n_buffers - 3
+            } else {
+                let sizes = unsafe { *(array.buffers as *mut *const u8).add(n_buffers - 1) };
+                let sizes = sizes as *const i64;
+                (unsafe { *sizes.add(i - 2) }) as usize
+            }
+        }
         // buffer len of primitive types
         _ => array.offset as usize + array.length as usize,
     })
@@ -68,6 +68,13 @@ impl ArrowSchema {
                 .iter()
                 .map(|field| Box::new(ArrowSchema::new(field)))
                 .collect::<Vec<_>>(),
+            DataType::RunEndEncoded(run_ends, field) => {
+                let run_ends = Field::new("run_ends", (*run_ends).into(), false);
+                vec![
+                    Box::new(ArrowSchema::new(&run_ends)),
+                    Box::new(ArrowSchema::new(field.as_ref())),
+                ]
+            }
             _ => vec![],
         };
         // note: this cannot be done along with the above because the above is fallible and this op leaks.
@@ -255,6 +262,8 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> Result<DataType> {
         "Z" => DataType::LargeBinary,
         "u" => DataType::Utf8,
         "U" => DataType::LargeUtf8,
+        "vz" => DataType::BinaryView,
+        "vu" => DataType::Utf8View,
         "tdD" => DataType::Date32,
         "tdm" => DataType::Date64,
         "tts" => DataType::Time32(TimeUnit::Second),
@@ -281,6 +290,19 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> Result<DataType> {
             let is_sorted = (schema.flags & 4) != 0;
             DataType::Map(Box::new(to_field(child)?), is_sorted)
         }
+        "+r" => {
+            let run_ends = to_integer_type(schema.child(0).format())?;
+            if !matches!(
+                run_ends,
+                IntegerType::Int16 | IntegerType::Int32 | IntegerType::Int64
+            ) {
+                return Err(ArrowError::OutOfSpec(
+                    "Run ends can only be Int16, Int32 or Int64".to_string(),
+                ));
+            }
+            let values = to_field(schema.child(1))?;
+            DataType::RunEndEncoded(run_ends, Box::new(values))
+        }
         "+s" => {
             let children = (0..schema.n_children as usize)
                 .map(|x| to_field(schema.child(x)))
@@ -315,25 +337,31 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> Result<DataType> {
                         "Decimal must contain 2 or 3 comma-separated values".to_string(),
                     ));
                 };
-                if parts.len() == 3 {
-                    let bit_width = parts[0].parse::<usize>().map_err(|_| {
+                let bit_width = if parts.len() == 3 {
+                    parts[2].parse::<usize>().map_err(|_| {
                         ArrowError::OutOfSpec(
                             "Decimal bit width is not a valid integer".to_string(),
                         )
-                    })?;
-                    if bit_width != 128 {
-                        return Err(ArrowError::OutOfSpec(
-                            "Decimal256 is not supported".to_string(),
-                        ));
-                    }
-                }
+                    })?
+                } else {
+                    128
+                };
                 let precision = parts[0].parse::<usize>().map_err(|_| {
                     ArrowError::OutOfSpec("Decimal precision is not a valid integer".to_string())
                 })?;
                 let scale = parts[1].parse::<usize>().map_err(|_| {
                     ArrowError::OutOfSpec("Decimal scale is not a valid integer".to_string())
                 })?;
-                DataType::Decimal(precision, scale)
+                match bit_width {
+                    128 => DataType::Decimal(precision, scale),
+                    256 => DataType::Decimal256(precision, scale),
+                    _ => {
+                        return Err(ArrowError::OutOfSpec(format!(
+                            "Decimal bit width must be 128 or 256, but it is {}",
+                            bit_width
+                        )))
+                    }
+                }
             } else if !parts.is_empty() && ((parts[0] == "+us") || (parts[0] == "+ud")) {
                 // union
                 let mode = UnionMode::sparse(parts[0] == "+us");
@@ -381,6 +409,8 @@ fn to_format(data_type: &DataType) -> String {
         DataType::LargeBinary => "Z".to_string(),
         DataType::Utf8 => "u".to_string(),
         DataType::LargeUtf8 => "U".to_string(),
+        DataType::BinaryView => "vz".to_string(),
+        DataType::Utf8View => "vu".to_string(),
         DataType::Date32 => "tdD".to_string(),
         DataType::Date64 => "tdm".to_string(),
         DataType::Time32(TimeUnit::Second) => "tts".to_string(),
@@ -416,6 +446,7 @@ fn to_format(data_type: &DataType) -> String {
             )
         }
         DataType::Decimal(precision, scale) => format!("d:{},{}", precision, scale),
+        DataType::Decimal256(precision, scale) => format!("d:{},{},256", precision, scale),
         DataType::List(_) => "+l".to_string(),
         DataType::LargeList(_) => "+L".to_string(),
         DataType::Struct(_) => "+s".to_string(),
@@ -435,6 +466,7 @@ fn to_format(data_type: &DataType) -> String {
             r
         }
         DataType::Map(_, _) => "+m".to_string(),
+        DataType::RunEndEncoded(_, _) => "+r".to_string(),
         DataType::Dictionary(index, _, _) => to_format(&(*index).into()),
         DataType::Extension(_, inner, _) => to_format(inner.as_ref()),
     }
@@ -448,6 +480,8 @@ pub(super) fn get_child(data_type: &DataType, index: usize) -> Result<DataType>
         (0, DataType::Map(field, _)) => Ok(field.data_type().clone()),
         (index, DataType::Struct(fields)) => Ok(fields[index].data_type().clone()),
         (index, DataType::Union(fields, _, _)) => Ok(fields[index].data_type().clone()),
+        (0, DataType::RunEndEncoded(run_ends, _)) => Ok((*run_ends).into()),
+        (1, DataType::RunEndEncoded(_, field)) => Ok(field.data_type().clone()),
         (child, data_type) => Err(ArrowError::OutOfSpec(format!(
             "Requested child {} to type {:?} that has no such child",
             child, data_type
@@ -0,0 +1,83 @@
+use std::collections::VecDeque;
+use std::io::{Read, Seek};
+
+use crate::array::{BinaryViewArrayGeneric, ViewType};
+use crate::buffer::Buffer;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+use super::super::read_basic::*;
+use super::super::{Compression, IpcBuffer, Node};
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_binview<T: ViewType + ?Sized, R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
+    data_type: DataType,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+) -> Result<BinaryViewArrayGeneric<T>> {
+    let field_node = field_nodes.pop_front().ok_or_else(|| {
+        ArrowError::oos(format!(
+            "IPC: unable to fetch the field for {:?}. The file or stream is corrupted.",
+            data_type
+        ))
+    })?;
+
+    let validity = read_validity(
+        buffers,
+        field_node,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+    )?;
+
+    let views: Buffer<i128> = read_buffer(
+        buffers,
+        field_node.length() as usize,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+    )?;
+
+    let num_buffers = variadic_buffer_counts.pop_front().ok_or_else(|| {
+        ArrowError::oos("IPC: missing the number of data buffers of a view array.")
+    })?;
+    let data_buffers = (0..num_buffers)
+        .map(|_| read_bytes(buffers, reader, block_offset, is_little_endian, compression))
+        .collect::<Result<Vec<_>>>()?;
+
+    BinaryViewArrayGeneric::<T>::try_new(data_type, views, data_buffers.into(), validity)
+}
+
+pub fn skip_binview(
+    field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<()> {
+    let _ = field_nodes.pop_front().ok_or_else(|| {
+        ArrowError::oos("IPC: unable to fetch the field for view. The file or stream is corrupted.")
+    })?;
+
+    let _ = buffers
+        .pop_front()
+        .ok_or_else(|| ArrowError::oos("IPC: missing validity buffer."))?;
+    let _ = buffers
+        .pop_front()
+        .ok_or_else(|| ArrowError::oos("IPC: missing views buffer."))?;
+
+    let num_buffers = variadic_buffer_counts.pop_front().ok_or_else(|| {
+        ArrowError::oos("IPC: missing the number of data buffers of a view array.")
+    })?;
+    for _ in 0..num_buffers {
+        let _ = buffers
+            .pop_front()
+            .ok_or_else(|| ArrowError::oos("IPC: missing data buffer."))?;
+    }
+    Ok(())
+}
@@ -14,6 +14,7 @@ use super::super::{Compression, IpcBuffer, Node, Version};
 #[allow(clippy::too_many_arguments)]
 pub fn read_fixed_size_list<R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
     data_type: DataType,
     ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -44,6 +45,7 @@ pub fn read_fixed_size_list<R: Read + Seek>(
 
     let values = read(
         field_nodes,
+        variadic_buffer_counts,
         field,
         &ipc_field.fields[0],
         buffers,
@@ -59,6 +61,7 @@ pub fn read_fixed_size_list<R: Read + Seek>(
 
 pub fn skip_fixed_size_list(
     field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
     data_type: &DataType,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> Result<()> {
@@ -74,5 +77,10 @@ pub fn skip_fixed_size_list(
 
     let (field, _) = FixedSizeListArray::get_child_and_size(data_type);
 
-    skip(field_nodes, field.data_type(), buffers)
+    skip(
+        field_nodes,
+        variadic_buffer_counts,
+        field.data_type(),
+        buffers,
+    )
 }
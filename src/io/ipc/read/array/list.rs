@@ -16,6 +16,7 @@ use super::super::{Compression, IpcBuffer, Node, Version};
 #[allow(clippy::too_many_arguments)]
 pub fn read_list<O: Offset, R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
     data_type: DataType,
     ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -60,6 +61,7 @@ where
 
     let values = read(
         field_nodes,
+        variadic_buffer_counts,
         field,
         &ipc_field.fields[0],
         buffers,
@@ -75,6 +77,7 @@ where
 
 pub fn skip_list<O: Offset>(
     field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
     data_type: &DataType,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> Result<()> {
@@ -91,5 +94,5 @@ pub fn skip_list<O: Offset>(
 
     let data_type = ListArray::<O>::get_child_type(data_type);
 
-    skip(field_nodes, data_type, buffers)
+    skip(field_nodes, variadic_buffer_counts, data_type, buffers)
 }
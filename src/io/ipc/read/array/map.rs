@@ -15,6 +15,7 @@ use super::super::{Compression, IpcBuffer, Node, Version};
 #[allow(clippy::too_many_arguments)]
 pub fn read_map<R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
     data_type: DataType,
     ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -56,6 +57,7 @@ pub fn read_map<R: Read + Seek>(
 
     let field = read(
         field_nodes,
+        variadic_buffer_counts,
         field,
         &ipc_field.fields[0],
         buffers,
@@ -71,6 +73,7 @@ pub fn read_map<R: Read + Seek>(
 
 pub fn skip_map(
     field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
     data_type: &DataType,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> Result<()> {
@@ -87,5 +90,5 @@ pub fn skip_map(
 
     let data_type = MapArray::get_field(data_type).data_type();
 
-    skip(field_nodes, data_type, buffers)
+    skip(field_nodes, variadic_buffer_counts, data_type, buffers)
 }
@@ -22,3 +22,7 @@ mod union;
 pub use union::*;
 mod map;
 pub use map::*;
+mod run_end_encoded;
+pub use run_end_encoded::*;
+mod binview;
+pub use binview::*;
@@ -0,0 +1,115 @@
+use std::collections::VecDeque;
+use std::io::{Read, Seek};
+
+use crate::array::{PrimitiveArray, RunEndEncodedArray, RunEndIndex};
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+
+use super::super::super::IpcField;
+use super::super::deserialize::{read, skip};
+use super::super::Dictionaries;
+use super::super::{Compression, IpcBuffer, Node, Version};
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_run_end_encoded<R: RunEndIndex, Re: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
+    data_type: DataType,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut Re,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    version: Version,
+) -> Result<RunEndEncodedArray<R>> {
+    let field_node = field_nodes.pop_front().ok_or_else(|| {
+        ArrowError::oos(format!(
+            "IPC: unable to fetch the field for {:?}. The file or stream is corrupted.",
+            data_type
+        ))
+    })?;
+
+    let run_ends = Field::new("run_ends", R::RUN_END_TYPE.into(), false);
+    let run_ends = read(
+        field_nodes,
+        variadic_buffer_counts,
+        &run_ends,
+        &IpcField::default(),
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+    )?;
+    let run_ends = run_ends
+        .as_any()
+        .downcast_ref::<PrimitiveArray<R>>()
+        .unwrap()
+        .values()
+        .clone();
+
+    let field = RunEndEncodedArray::<R>::get_field(&data_type);
+    let values = read(
+        field_nodes,
+        variadic_buffer_counts,
+        field,
+        &ipc_field.fields[0],
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+    )?;
+
+    let array = RunEndEncodedArray::try_new(data_type, run_ends, values)?;
+
+    // the last run may end after the array's length
+    let length = field_node.length() as usize;
+    if length > array.len() {
+        return Err(ArrowError::oos(
+            "IPC: the run ends of a RunEndEncoded array must cover its length",
+        ));
+    }
+    Ok(array.slice(0, length))
+}
+
+pub fn skip_run_end_encoded(
+    field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
+    data_type: &DataType,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<()> {
+    let _ = field_nodes.pop_front().ok_or_else(|| {
+        ArrowError::oos(
+            "IPC: unable to fetch the field for run-end encoded. The file or stream is corrupted.",
+        )
+    })?;
+
+    let (run_end_type, field) =
+        if let DataType::RunEndEncoded(run_end_type, field) = data_type.to_logical_type() {
+            (run_end_type, field)
+        } else {
+            return Err(ArrowError::oos(
+                "IPC: a run-end encoded array must have a DataType::RunEndEncoded",
+            ));
+        };
+
+    skip(
+        field_nodes,
+        variadic_buffer_counts,
+        &(*run_end_type).into(),
+        buffers,
+    )?;
+    skip(
+        field_nodes,
+        variadic_buffer_counts,
+        field.data_type(),
+        buffers,
+    )
+}
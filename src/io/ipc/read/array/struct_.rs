@@ -14,6 +14,7 @@ use super::super::{Compression, IpcBuffer, Node, Version};
 #[allow(clippy::too_many_arguments)]
 pub fn read_struct<R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
     data_type: DataType,
     ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -48,6 +49,7 @@ pub fn read_struct<R: Read + Seek>(
         .map(|(field, ipc_field)| {
             read(
                 field_nodes,
+                variadic_buffer_counts,
                 field,
                 ipc_field,
                 buffers,
@@ -66,6 +68,7 @@ pub fn read_struct<R: Read + Seek>(
 
 pub fn skip_struct(
     field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
     data_type: &DataType,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> Result<()> {
@@ -81,7 +84,12 @@ pub fn skip_struct(
 
     let fields = StructArray::get_fields(data_type);
 
-    fields
-        .iter()
-        .try_for_each(|field| skip(field_nodes, field.data_type(), buffers))
+    fields.iter().try_for_each(|field| {
+        skip(
+            field_nodes,
+            variadic_buffer_counts,
+            field.data_type(),
+            buffers,
+        )
+    })
 }
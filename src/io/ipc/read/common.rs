@@ -98,6 +98,21 @@ pub fn read_record_batch<R: Read + Seek>(
         .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain field nodes"))?;
     let mut field_nodes = field_nodes.iter().collect::<VecDeque<_>>();
 
+    let mut variadic_buffer_counts = batch
+        .variadic_buffer_counts()?
+        .map(|counts| {
+            counts
+                .iter()
+                .map(|count| {
+                    usize::try_from(count).map_err(|_| {
+                        ArrowError::oos("IPC: the number of variadic buffers cannot be negative")
+                    })
+                })
+                .collect::<Result<VecDeque<_>>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+
     let columns = if let Some(projection) = projection {
         let projection =
             ProjectionIter::new(projection, fields.iter().zip(ipc_schema.fields.iter()));
@@ -106,6 +121,7 @@ pub fn read_record_batch<R: Read + Seek>(
             .map(|maybe_field| match maybe_field {
                 ProjectionResult::Selected((field, ipc_field)) => Ok(Some(read(
                     &mut field_nodes,
+                    &mut variadic_buffer_counts,
                     field,
                     ipc_field,
                     &mut buffers,
@@ -117,7 +133,12 @@ pub fn read_record_batch<R: Read + Seek>(
                     version,
                 )?)),
                 ProjectionResult::NotSelected((field, _)) => {
-                    skip(&mut field_nodes, &field.data_type, &mut buffers)?;
+                    skip(
+                        &mut field_nodes,
+                        &mut variadic_buffer_counts,
+                        &field.data_type,
+                        &mut buffers,
+                    )?;
                     Ok(None)
                 }
             })
@@ -130,6 +151,7 @@ pub fn read_record_batch<R: Read + Seek>(
             .map(|(field, ipc_field)| {
                 read(
                     &mut field_nodes,
+                    &mut variadic_buffer_counts,
                     field,
                     ipc_field,
                     &mut buffers,
@@ -154,7 +176,11 @@ fn find_first_dict_field_d<'a>(
     use DataType::*;
     match data_type {
         Dictionary(_, inner, _) => find_first_dict_field_d(id, inner.as_ref(), ipc_field),
-        List(field) | LargeList(field) | FixedSizeList(field, ..) | Map(field, ..) => {
+        List(field)
+        | LargeList(field)
+        | FixedSizeList(field, ..)
+        | Map(field, ..)
+        | RunEndEncoded(_, field) => {
             find_first_dict_field(id, field.as_ref(), &ipc_field.fields[0])
         }
         Union(fields, ..) | Struct(fields) => {
@@ -18,6 +18,7 @@ use super::{IpcBuffer, Node};
 #[allow(clippy::too_many_arguments)]
 pub fn read<R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
     field: &Field,
     ipc_field: &IpcField,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -117,6 +118,7 @@ pub fn read<R: Read + Seek>(
         }
         List => read_list::<i32, _>(
             field_nodes,
+            variadic_buffer_counts,
             data_type,
             ipc_field,
             buffers,
@@ -130,6 +132,7 @@ pub fn read<R: Read + Seek>(
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         LargeList => read_list::<i64, _>(
             field_nodes,
+            variadic_buffer_counts,
             data_type,
             ipc_field,
             buffers,
@@ -143,6 +146,7 @@ pub fn read<R: Read + Seek>(
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         FixedSizeList => read_fixed_size_list(
             field_nodes,
+            variadic_buffer_counts,
             data_type,
             ipc_field,
             buffers,
@@ -156,6 +160,7 @@ pub fn read<R: Read + Seek>(
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         Struct => read_struct(
             field_nodes,
+            variadic_buffer_counts,
             data_type,
             ipc_field,
             buffers,
@@ -184,6 +189,7 @@ pub fn read<R: Read + Seek>(
         }
         Union => read_union(
             field_nodes,
+            variadic_buffer_counts,
             data_type,
             ipc_field,
             buffers,
@@ -197,6 +203,7 @@ pub fn read<R: Read + Seek>(
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         Map => read_map(
             field_nodes,
+            variadic_buffer_counts,
             data_type,
             ipc_field,
             buffers,
@@ -208,11 +215,54 @@ pub fn read<R: Read + Seek>(
             version,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
+        RunEndEncoded(run_end_type) => try_match_run_end_type!(run_end_type, |$T| {
+            read_run_end_encoded::<$T, _>(
+                field_nodes,
+                variadic_buffer_counts,
+                data_type,
+                ipc_field,
+                buffers,
+                reader,
+                dictionaries,
+                block_offset,
+                is_little_endian,
+                compression,
+                version,
+            )
+            .map(|x| Arc::new(x) as Arc<dyn Array>)
+        }),
+        BinaryView => {
+            let array = read_binview::<[u8], _>(
+                field_nodes,
+                variadic_buffer_counts,
+                data_type,
+                buffers,
+                reader,
+                block_offset,
+                is_little_endian,
+                compression,
+            )?;
+            Ok(Arc::new(array))
+        }
+        Utf8View => {
+            let array = read_binview::<str, _>(
+                field_nodes,
+                variadic_buffer_counts,
+                data_type,
+                buffers,
+                reader,
+                block_offset,
+                is_little_endian,
+                compression,
+            )?;
+            Ok(Arc::new(array))
+        }
     }
 }
 
 pub fn skip(
     field_nodes: &mut VecDeque<Node>,
+    variadic_buffer_counts: &mut VecDeque<usize>,
     data_type: &DataType,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> Result<()> {
@@ -224,12 +274,18 @@ pub fn skip(
         LargeBinary | Binary => skip_binary(field_nodes, buffers),
         LargeUtf8 | Utf8 => skip_utf8(field_nodes, buffers),
         FixedSizeBinary => skip_fixed_size_binary(field_nodes, buffers),
-        List => skip_list::<i32>(field_nodes, data_type, buffers),
-        LargeList => skip_list::<i64>(field_nodes, data_type, buffers),
-        FixedSizeList => skip_fixed_size_list(field_nodes, data_type, buffers),
-        Struct => skip_struct(field_nodes, data_type, buffers),
+        List => skip_list::<i32>(field_nodes, variadic_buffer_counts, data_type, buffers),
+        LargeList => skip_list::<i64>(field_nodes, variadic_buffer_counts, data_type, buffers),
+        FixedSizeList => {
+            skip_fixed_size_list(field_nodes, variadic_buffer_counts, data_type, buffers)
+        }
+        Struct => skip_struct(field_nodes, variadic_buffer_counts, data_type, buffers),
         Dictionary(_) => skip_dictionary(field_nodes, buffers),
-        Union => skip_union(field_nodes, data_type, buffers),
-        Map => skip_map(field_nodes, data_type, buffers),
+        Union => skip_union(field_nodes, variadic_buffer_counts, data_type, buffers),
+        Map => skip_map(field_nodes, variadic_buffer_counts, data_type, buffers),
+        RunEndEncoded(_) => {
+            skip_run_end_encoded(field_nodes, variadic_buffer_counts, data_type, buffers)
+        }
+        BinaryView | Utf8View => skip_binview(field_nodes, variadic_buffer_counts, buffers),
     }
 }
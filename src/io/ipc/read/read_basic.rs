@@ -140,6 +140,44 @@ pub fn read_buffer<T: NativeType, R: Read + Seek>(
     }
 }
 
+/// Reads a buffer of bytes whose length is only known from the IPC buffer itself,
+/// such as the data buffers of view arrays.
+pub fn read_bytes<R: Read + Seek>(
+    buf: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+) -> Result<Buffer<u8>> {
+    let buf = buf
+        .pop_front()
+        .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
+
+    let start = block_offset + buf.offset() as u64;
+    reader.seek(SeekFrom::Start(start))?;
+
+    let buffer_length = buf.length() as usize;
+
+    if let Some(compression) = compression {
+        // compressed buffers start with their uncompressed length
+        let mut length = [0u8; 8];
+        reader.read_exact(&mut length)?;
+        let length = usize::try_from(i64::from_le_bytes(length)).map_err(|_| {
+            ArrowError::oos("IPC: the uncompressed length of a buffer cannot be negative")
+        })?;
+        reader.seek(SeekFrom::Start(start))?;
+        Ok(
+            read_compressed_buffer(reader, buffer_length, length, is_little_endian, compression)?
+                .into(),
+        )
+    } else {
+        Ok(
+            read_uncompressed_buffer(reader, buffer_length, buffer_length, is_little_endian)?
+                .into(),
+        )
+    }
+}
+
 fn read_uncompressed_bitmap<R: Read + Seek>(
     length: usize,
     bytes: usize,
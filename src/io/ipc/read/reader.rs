@@ -137,25 +137,19 @@ pub(super) fn deserialize_footer(footer_data: &[u8]) -> Result<FileMetadata> {
         ArrowError::OutOfSpec("Unable to get record batches from footer".to_string())
     })?;
 
-    let blocks = blocks
-        .iter()
-        .map(|block| Ok(block.try_into()?))
-        .collect::<Result<Vec<_>>>()?;
+    let blocks = blocks.iter().map(|block| block.into()).collect::<Vec<_>>();
 
     let ipc_schema = footer
         .schema()?
         .ok_or_else(|| ArrowError::OutOfSpec("Unable to get the schema from footer".to_string()))?;
     let (schema, ipc_schema) = fb_to_schema(ipc_schema)?;
 
-    let dictionaries = footer
-        .dictionaries()?
-        .map(|dictionaries| {
-            dictionaries
-                .into_iter()
-                .map(|x| Ok(x.try_into()?))
-                .collect::<Result<Vec<_>>>()
-        })
-        .transpose()?;
+    let dictionaries = footer.dictionaries()?.map(|dictionaries| {
+        dictionaries
+            .into_iter()
+            .map(|x| x.into())
+            .collect::<Vec<_>>()
+    });
 
     Ok(FileMetadata {
         schema,
@@ -134,6 +134,8 @@ fn get_data_type(
         LargeBinary(_) => (DataType::LargeBinary, IpcField::default()),
         Utf8(_) => (DataType::Utf8, IpcField::default()),
         LargeUtf8(_) => (DataType::LargeUtf8, IpcField::default()),
+        BinaryView(_) => (DataType::BinaryView, IpcField::default()),
+        Utf8View(_) => (DataType::Utf8View, IpcField::default()),
         FixedSizeBinary(fixed) => (
             DataType::FixedSizeBinary(fixed.byte_width()? as usize),
             IpcField::default(),
@@ -197,8 +199,18 @@ fn get_data_type(
             (DataType::Duration(time_unit), IpcField::default())
         }
         Decimal(decimal) => {
-            let data_type =
-                DataType::Decimal(decimal.precision()? as usize, decimal.scale()? as usize);
+            let precision = decimal.precision()? as usize;
+            let scale = decimal.scale()? as usize;
+            let data_type = match decimal.bit_width()? {
+                128 => DataType::Decimal(precision, scale),
+                256 => DataType::Decimal256(precision, scale),
+                other => {
+                    return Err(ArrowError::nyi(format!(
+                        "IPC: decimals of bit width {} are not supported",
+                        other
+                    )))
+                }
+            };
             (data_type, IpcField::default())
         }
         List(_) => {
@@ -316,6 +328,45 @@ fn get_data_type(
                 },
             )
         }
+        RunEndEncoded(_) => {
+            let children = field
+                .children()?
+                .ok_or_else(|| ArrowError::oos("IPC: RunEndEncoded must contain children"))?;
+            let run_ends = children
+                .get(0)
+                .ok_or_else(|| ArrowError::oos("IPC: RunEndEncoded must contain two children"))??;
+            let values = children
+                .get(1)
+                .ok_or_else(|| ArrowError::oos("IPC: RunEndEncoded must contain two children"))??;
+
+            let (run_ends, _) = deserialize_field(run_ends)?;
+            let run_end_type = match run_ends.data_type() {
+                DataType::Int16 => IntegerType::Int16,
+                DataType::Int32 => IntegerType::Int32,
+                DataType::Int64 => IntegerType::Int64,
+                other => {
+                    return Err(ArrowError::oos(format!(
+                        "IPC: the run ends of RunEndEncoded cannot be {:?}",
+                        other
+                    )))
+                }
+            };
+            let (field, ipc_field) = deserialize_field(values)?;
+
+            (
+                DataType::RunEndEncoded(run_end_type, Box::new(field)),
+                IpcField {
+                    fields: vec![ipc_field],
+                    dictionary_id: None,
+                },
+            )
+        }
+        // not supported by arrow2
+        ListView(_) | LargeListView(_) => {
+            return Err(ArrowError::nyi(
+                "IPC: list view types are not yet supported",
+            ))
+        }
     })
 }
 
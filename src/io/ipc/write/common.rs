@@ -40,7 +40,7 @@ fn encode_dictionary(
     use PhysicalType::*;
     match array.data_type().to_physical_type() {
         Utf8 | LargeUtf8 | Binary | LargeBinary | Primitive(_) | Boolean | Null
-        | FixedSizeBinary => Ok(()),
+        | FixedSizeBinary | BinaryView | Utf8View => Ok(()),
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             let dict_id = field.dictionary_id
                 .ok_or_else(|| ArrowError::InvalidArgumentError("Dictionaries must have an associated id".to_string()))?;
@@ -169,6 +169,21 @@ fn encode_dictionary(
                 encoded_dictionaries,
             )
         }
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            let values = array
+                .as_any()
+                .downcast_ref::<RunEndEncodedArray<$T>>()
+                .unwrap()
+                .values();
+            let field = &field.fields[0]; // todo: error instead
+            encode_dictionary(
+                field,
+                values.as_ref(),
+                options,
+                dictionary_tracker,
+                encoded_dictionaries,
+            )
+        }),
     }
 }
 
@@ -212,14 +227,74 @@ fn serialize_compression(
     }
 }
 
+/// Pushes the number of data buffers of every view array of `array` to `counts`, in the order
+/// in which they are written. Dictionary values are written to their own batch.
+fn set_variadic_buffer_counts(counts: &mut Vec<i64>, array: &dyn Array) {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        BinaryView => {
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            counts.push(array.data_buffers().len() as i64);
+        }
+        Utf8View => {
+            let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            counts.push(array.data_buffers().len() as i64);
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            for values in array.values() {
+                set_variadic_buffer_counts(counts, values.as_ref());
+            }
+        }
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            set_variadic_buffer_counts(counts, array.values().as_ref());
+        }
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            set_variadic_buffer_counts(counts, array.values().as_ref());
+        }
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            set_variadic_buffer_counts(counts, array.values().as_ref());
+        }
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            for values in array.fields() {
+                set_variadic_buffer_counts(counts, values.as_ref());
+            }
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            set_variadic_buffer_counts(counts, array.field().as_ref());
+        }
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            let array = array.as_any().downcast_ref::<RunEndEncodedArray<$T>>().unwrap();
+            set_variadic_buffer_counts(counts, array.values().as_ref());
+        }),
+        Null | Boolean | Primitive(_) | Binary | LargeBinary | FixedSizeBinary | Utf8
+        | LargeUtf8 | Dictionary(_) => {}
+    }
+}
+
+fn serialize_variadic_buffer_counts(counts: Vec<i64>) -> Option<Vec<i64>> {
+    if counts.is_empty() {
+        None
+    } else {
+        Some(counts)
+    }
+}
+
 /// Write [`Chunk`] into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the batch's data
 fn columns_to_bytes(columns: &Chunk<Arc<dyn Array>>, options: &WriteOptions) -> EncodedData {
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
+    let mut variadic_buffer_counts = vec![];
     let mut offset = 0;
     for array in columns.arrays() {
+        set_variadic_buffer_counts(&mut variadic_buffer_counts, array.as_ref());
         write(
             array.as_ref(),
             &mut buffers,
@@ -241,6 +316,7 @@ fn columns_to_bytes(columns: &Chunk<Arc<dyn Array>>, options: &WriteOptions) ->
                 nodes: Some(nodes),
                 buffers: Some(buffers),
                 compression,
+                variadic_buffer_counts: serialize_variadic_buffer_counts(variadic_buffer_counts),
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -267,6 +343,8 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
+    let mut variadic_buffer_counts = vec![];
+    set_variadic_buffer_counts(&mut variadic_buffer_counts, array.values().as_ref());
 
     let length = write_dictionary(
         array,
@@ -291,6 +369,9 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
                     nodes: Some(nodes),
                     buffers: Some(buffers),
                     compression,
+                    variadic_buffer_counts: serialize_variadic_buffer_counts(
+                        variadic_buffer_counts,
+                    ),
                 })),
                 is_delta: false,
             },
@@ -32,7 +32,11 @@ fn default_ipc_field(data_type: &DataType, current_id: &mut i64) -> IpcField {
     use crate::datatypes::DataType::*;
     match data_type.to_logical_type() {
         // single child => recurse
-        Map(inner, ..) | FixedSizeList(inner, _) | LargeList(inner) | List(inner) => IpcField {
+        Map(inner, ..)
+        | FixedSizeList(inner, _)
+        | LargeList(inner)
+        | List(inner)
+        | RunEndEncoded(_, inner) => IpcField {
             fields: vec![default_ipc_field(inner.data_type(), current_id)],
             dictionary_id: None,
         },
@@ -197,10 +197,17 @@ fn serialize_type(data_type: &DataType) -> arrow_format::ipc::Type {
             scale: *scale as i32,
             bit_width: 128,
         })),
+        Decimal256(precision, scale) => ipc::Type::Decimal(Box::new(ipc::Decimal {
+            precision: *precision as i32,
+            scale: *scale as i32,
+            bit_width: 256,
+        })),
         Binary => ipc::Type::Binary(Box::new(ipc::Binary {})),
         LargeBinary => ipc::Type::LargeBinary(Box::new(ipc::LargeBinary {})),
         Utf8 => ipc::Type::Utf8(Box::new(ipc::Utf8 {})),
         LargeUtf8 => ipc::Type::LargeUtf8(Box::new(ipc::LargeUtf8 {})),
+        BinaryView => ipc::Type::BinaryView(Box::new(ipc::BinaryView {})),
+        Utf8View => ipc::Type::Utf8View(Box::new(ipc::Utf8View {})),
         FixedSizeBinary(size) => ipc::Type::FixedSizeBinary(Box::new(ipc::FixedSizeBinary {
             byte_width: *size as i32,
         })),
@@ -250,6 +257,7 @@ fn serialize_type(data_type: &DataType) -> arrow_format::ipc::Type {
         Struct(_) => ipc::Type::Struct(Box::new(ipc::Struct {})),
         Dictionary(_, v, _) => serialize_type(v),
         Extension(_, v, _) => serialize_type(v),
+        RunEndEncoded(_, _) => ipc::Type::RunEndEncoded(Box::new(ipc::RunEndEncoded {})),
     }
 }
 
@@ -281,7 +289,10 @@ fn serialize_children(data_type: &DataType, ipc_field: &IpcField) -> Vec<arrow_f
         | LargeBinary
         | Utf8
         | LargeUtf8
-        | Decimal(_, _) => vec![],
+        | BinaryView
+        | Utf8View
+        | Decimal(_, _)
+        | Decimal256(_, _) => vec![],
         FixedSizeList(inner, _) | LargeList(inner) | List(inner) | Map(inner, _) => {
             vec![serialize_field(inner, &ipc_field.fields[0])]
         }
@@ -292,6 +303,14 @@ fn serialize_children(data_type: &DataType, ipc_field: &IpcField) -> Vec<arrow_f
             .collect(),
         Dictionary(_, inner, _) => serialize_children(inner, ipc_field),
         Extension(_, inner, _) => serialize_children(inner, ipc_field),
+        RunEndEncoded(run_end_type, inner) => {
+            // the run ends are the first child; only the values may be dictionary-encoded
+            let run_ends = Field::new("run_ends", (*run_end_type).into(), false);
+            vec![
+                serialize_field(&run_ends, &IpcField::default()),
+                serialize_field(inner, &ipc_field.fields[0]),
+            ]
+        }
     }
 }
 
@@ -153,6 +153,38 @@ fn write_utf8<O: Offset>(
     );
 }
 
+fn write_binview<T: ViewType + ?Sized>(
+    array: &BinaryViewArrayGeneric<T>,
+    buffers: &mut Vec<ipc::Buffer>,
+    arrow_data: &mut Vec<u8>,
+    offset: &mut i64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+) {
+    write_bitmap(
+        array.validity(),
+        array.len(),
+        buffers,
+        arrow_data,
+        offset,
+        compression,
+    );
+
+    write_buffer(
+        array.views(),
+        buffers,
+        arrow_data,
+        offset,
+        is_little_endian,
+        compression,
+    );
+
+    // the number of data buffers is declared in the record batch's `variadic_buffer_counts`
+    for data in array.data_buffers().iter() {
+        write_bytes(data, buffers, arrow_data, offset, compression);
+    }
+}
+
 fn write_fixed_size_binary(
     array: &FixedSizeBinaryArray,
     buffers: &mut Vec<ipc::Buffer>,
@@ -385,6 +417,46 @@ fn write_fixed_size_list(
     );
 }
 
+fn write_run_end_encoded<R: RunEndIndex>(
+    array: &RunEndEncodedArray<R>,
+    buffers: &mut Vec<ipc::Buffer>,
+    arrow_data: &mut Vec<u8>,
+    nodes: &mut Vec<ipc::FieldNode>,
+    offset: &mut i64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+) {
+    // IPC has no offset for run-end encoded arrays: only the runs of the (possibly sliced)
+    // array are written, with run ends relative to its start and the last one truncated.
+    let (start, end) = array.physical_range();
+    let run_ends = array.run_ends()[start..end]
+        .iter()
+        .map(|run_end| {
+            let run_end = run_end.to_usize().unwrap() - array.offset();
+            R::from_usize(run_end.min(array.len())).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    write(
+        &PrimitiveArray::<R>::from_vec(run_ends),
+        buffers,
+        arrow_data,
+        nodes,
+        offset,
+        is_little_endian,
+        compression,
+    );
+    write(
+        array.values().slice(start, end - start).as_ref(),
+        buffers,
+        arrow_data,
+        nodes,
+        offset,
+        is_little_endian,
+        compression,
+    );
+}
+
 // use `write_keys` to either write keys or values
 #[allow(clippy::too_many_arguments)]
 pub(super) fn write_dictionary<K: DictionaryKey>(
@@ -560,6 +632,33 @@ pub fn write(
                 compression,
             );
         }
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            write_run_end_encoded::<$T>(
+                array.as_any().downcast_ref().unwrap(),
+                buffers,
+                arrow_data,
+                nodes,
+                offset,
+                is_little_endian,
+                compression,
+            );
+        }),
+        BinaryView => write_binview::<[u8]>(
+            array.as_any().downcast_ref().unwrap(),
+            buffers,
+            arrow_data,
+            offset,
+            is_little_endian,
+            compression,
+        ),
+        Utf8View => write_binview::<str>(
+            array.as_any().downcast_ref().unwrap(),
+            buffers,
+            arrow_data,
+            offset,
+            is_little_endian,
+            compression,
+        ),
     }
 }
 
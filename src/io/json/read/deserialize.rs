@@ -12,7 +12,7 @@ use crate::{
     bitmap::MutableBitmap,
     datatypes::{DataType, IntervalUnit},
     error::ArrowError,
-    types::NativeType,
+    types::{f16, NativeType},
 };
 
 /// A function that converts a &Value into an optional tuple of a byte slice and a Value.
@@ -226,7 +226,7 @@ pub(crate) fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) ->
         DataType::UInt16 => Arc::new(deserialize_int::<u16, _>(rows, data_type)),
         DataType::UInt32 => Arc::new(deserialize_int::<u32, _>(rows, data_type)),
         DataType::UInt64 => Arc::new(deserialize_int::<u64, _>(rows, data_type)),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => Arc::new(deserialize_float::<f16, _>(rows, data_type)),
         DataType::Float32 => Arc::new(deserialize_float::<f32, _>(rows, data_type)),
         DataType::Float64 => Arc::new(deserialize_float::<f64, _>(rows, data_type)),
         DataType::Utf8 => Arc::new(deserialize_utf8::<i32, _>(rows)),
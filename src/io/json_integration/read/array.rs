@@ -11,7 +11,7 @@ use crate::{
     datatypes::{DataType, PhysicalType, PrimitiveType, Schema},
     error::{ArrowError, Result},
     io::ipc::IpcField,
-    types::{days_ms, months_days_ns, NativeType},
+    types::{days_ms, f16, i256, months_days_ns, NativeType},
 };
 
 use super::super::{ArrowJsonBatch, ArrowJsonColumn, ArrowJsonDictionaryBatch};
@@ -108,7 +108,13 @@ fn to_primitive_months_days_ns(
     PrimitiveArray::<months_days_ns>::new(data_type, values, validity)
 }
 
-fn to_decimal(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray<i128> {
+fn to_decimal<T: NativeType + std::str::FromStr>(
+    json_col: &ArrowJsonColumn,
+    data_type: DataType,
+) -> PrimitiveArray<T>
+where
+    T::Err: std::fmt::Debug,
+{
     let validity = to_validity(&json_col.validity);
     let values = json_col
         .data
@@ -116,14 +122,14 @@ fn to_decimal(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray
         .unwrap()
         .iter()
         .map(|value| match value {
-            Value::String(x) => x.parse::<i128>().unwrap(),
+            Value::String(x) => x.parse::<T>().unwrap(),
             _ => {
                 panic!()
             }
         })
         .collect();
 
-    PrimitiveArray::<i128>::new(data_type, values, validity)
+    PrimitiveArray::<T>::new(data_type, values, validity)
 }
 
 fn to_primitive<T: NativeType + NumCast>(
@@ -131,7 +137,10 @@ fn to_primitive<T: NativeType + NumCast>(
     data_type: DataType,
 ) -> PrimitiveArray<T> {
     let validity = to_validity(&json_col.validity);
-    let values = if data_type == DataType::Float64 || data_type == DataType::Float32 {
+    let values = if matches!(
+        data_type,
+        DataType::Float16 | DataType::Float32 | DataType::Float64
+    ) {
         json_col
             .data
             .as_ref()
@@ -280,7 +289,8 @@ pub fn to_array(
         Primitive(PrimitiveType::Int16) => Ok(Arc::new(to_primitive::<i16>(json_col, data_type))),
         Primitive(PrimitiveType::Int32) => Ok(Arc::new(to_primitive::<i32>(json_col, data_type))),
         Primitive(PrimitiveType::Int64) => Ok(Arc::new(to_primitive::<i64>(json_col, data_type))),
-        Primitive(PrimitiveType::Int128) => Ok(Arc::new(to_decimal(json_col, data_type))),
+        Primitive(PrimitiveType::Int128) => Ok(Arc::new(to_decimal::<i128>(json_col, data_type))),
+        Primitive(PrimitiveType::Int256) => Ok(Arc::new(to_decimal::<i256>(json_col, data_type))),
         Primitive(PrimitiveType::DaysMs) => Ok(Arc::new(to_primitive_days_ms(json_col, data_type))),
         Primitive(PrimitiveType::MonthDayNano) => {
             Ok(Arc::new(to_primitive_months_days_ns(json_col, data_type)))
@@ -289,6 +299,7 @@ pub fn to_array(
         Primitive(PrimitiveType::UInt16) => Ok(Arc::new(to_primitive::<u16>(json_col, data_type))),
         Primitive(PrimitiveType::UInt32) => Ok(Arc::new(to_primitive::<u32>(json_col, data_type))),
         Primitive(PrimitiveType::UInt64) => Ok(Arc::new(to_primitive::<u64>(json_col, data_type))),
+        Primitive(PrimitiveType::Float16) => Ok(Arc::new(to_primitive::<f16>(json_col, data_type))),
         Primitive(PrimitiveType::Float32) => Ok(Arc::new(to_primitive::<f32>(json_col, data_type))),
         Primitive(PrimitiveType::Float64) => Ok(Arc::new(to_primitive::<f64>(json_col, data_type))),
         Binary => Ok(to_binary::<i32>(json_col, data_type)),
@@ -400,6 +411,12 @@ pub fn to_array(
             Ok(Arc::new(array))
         }
         Map => to_map(json_col, data_type, field, dictionaries),
+        RunEndEncoded(_) => Err(ArrowError::nyi(
+            "Reading run-end encoded arrays from the JSON integration format is not yet supported",
+        )),
+        BinaryView | Utf8View => Err(ArrowError::nyi(
+            "Reading view arrays from the JSON integration format is not yet supported",
+        )),
     }
 }
 
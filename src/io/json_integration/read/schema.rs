@@ -187,7 +187,16 @@ fn to_data_type(item: &Value, mut children: Vec<Field>) -> Result<DataType> {
                 )),
             };
 
-            DataType::Decimal(precision?, scale?)
+            match item.get("bitWidth").and_then(|x| x.as_u64()) {
+                None | Some(128) => DataType::Decimal(precision?, scale?),
+                Some(256) => DataType::Decimal256(precision?, scale?),
+                Some(other) => {
+                    return Err(ArrowError::OutOfSpec(format!(
+                        "Decimal bit width must be 128 or 256, but it is {}",
+                        other
+                    )))
+                }
+            }
         }
         "floatingpoint" => match item.get("precision") {
             Some(p) if p == "HALF" => DataType::Float16,
@@ -0,0 +1,473 @@
+//! APIs of parquet modular encryption, the encryption of the footer and of the columns of
+//! parquet files with AES-GCM.
+//!
+//! Files are encrypted by [`FileWriter::try_new_with_encryption`] following
+//! [`FileEncryptionProperties`], and decrypted by [`read_metadata_with_decryption`] and
+//! [`FileReader::try_new_with_decryption`] following [`FileDecryptionProperties`], whose keys
+//! are either declared or retrieved by a [`KeyRetriever`] from the key metadata stored in the
+//! file (e.g. from a key management service).
+//!
+//! Both the encrypted footer mode (files starting and ending with `PARE`) and the plaintext
+//! footer mode (whose footer is signed and readable by readers without keys) are supported.
+//! Files are written with the algorithm `AES_GCM_V1`; files written with `AES_GCM_V1` and
+//! `AES_GCM_CTR_V1` can be read. The column indexes, offset indexes and bloom filters of
+//! encrypted columns are written encrypted but can't be read by this crate.
+//!
+//! [`FileWriter::try_new_with_encryption`]: crate::io::parquet::write::FileWriter::try_new_with_encryption
+//! [`read_metadata_with_decryption`]: crate::io::parquet::read::read_metadata_with_decryption
+//! [`FileReader::try_new_with_decryption`]: crate::io::parquet::read::FileReader::try_new_with_decryption
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use aes::{Aes128, Aes192, Aes256};
+use aes_gcm::aead::consts::U12;
+use aes_gcm::aead::generic_array::GenericArray;
+use aes_gcm::{AeadInPlace, AesGcm, KeyInit};
+use ctr::cipher::{InnerIvInit, StreamCipher};
+use rand::{rngs::OsRng, RngCore};
+
+use crate::error::{ArrowError, Result};
+
+const NONCE_LENGTH: usize = 12;
+const TAG_LENGTH: usize = 16;
+
+/// A key with which columns or footers are encrypted.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionKey {
+    /// The AES key, of 16, 24 or 32 bytes
+    pub key: Vec<u8>,
+    /// The metadata stored in the file from which a [`KeyRetriever`] retrieves the key, e.g.
+    /// its id in a key management service
+    pub key_metadata: Option<Vec<u8>>,
+}
+
+impl EncryptionKey {
+    /// Returns a new [`EncryptionKey`] without key metadata.
+    pub fn new(key: Vec<u8>) -> Self {
+        Self {
+            key,
+            key_metadata: None,
+        }
+    }
+}
+
+impl Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionKey")
+            .field("key", &"<redacted>")
+            .field("key_metadata", &self.key_metadata)
+            .finish()
+    }
+}
+
+/// How a file is encrypted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEncryptionProperties {
+    /// The key of the footer, which also encrypts the columns without a key of their own
+    pub footer_key: EncryptionKey,
+    /// The keys of the columns encrypted with keys of their own, by the path of the column in
+    /// the parquet schema (the names of its fields joined by `.`)
+    pub column_keys: HashMap<String, EncryptionKey>,
+    /// The paths of the columns that are not encrypted
+    pub plaintext_columns: Vec<String>,
+    /// A prefix of the AAD of every module, e.g. the name of the file, so that modules can't be
+    /// exchanged between files
+    pub aad_prefix: Option<Vec<u8>>,
+    /// Whether `aad_prefix` is stored in the file. When it is not, readers must supply it.
+    pub store_aad_prefix: bool,
+    /// Whether the footer is written in plaintext (and signed with the footer key), so that
+    /// readers without keys can read the schema and the plaintext columns
+    pub plaintext_footer: bool,
+}
+
+impl FileEncryptionProperties {
+    /// Returns new [`FileEncryptionProperties`] encrypting the footer and every column with
+    /// `footer_key`.
+    pub fn new(footer_key: EncryptionKey) -> Self {
+        Self {
+            footer_key,
+            column_keys: HashMap::new(),
+            plaintext_columns: vec![],
+            aad_prefix: None,
+            store_aad_prefix: true,
+            plaintext_footer: false,
+        }
+    }
+}
+
+/// Retrieves the keys of encrypted files from the key metadata stored in them.
+pub trait KeyRetriever: Send + Sync {
+    /// Returns the key identified by `key_metadata`.
+    fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// The keys with which encrypted files are read.
+#[derive(Clone, Default)]
+pub struct FileDecryptionProperties {
+    /// The key of the footer. When `None`, it is retrieved with `key_retriever`.
+    pub footer_key: Option<Vec<u8>>,
+    /// The keys of the columns encrypted with keys of their own, by their path in the parquet
+    /// schema (the names of its fields joined by `.`). The keys of the other columns are
+    /// retrieved with `key_retriever`.
+    pub column_keys: HashMap<String, Vec<u8>>,
+    /// Retrieves the keys that are not declared
+    pub key_retriever: Option<Arc<dyn KeyRetriever>>,
+    /// The prefix of the AAD of the file, required when it is not stored in the file
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+impl FileDecryptionProperties {
+    /// Returns new [`FileDecryptionProperties`] retrieving every key with `key_retriever`.
+    pub fn with_key_retriever(key_retriever: Arc<dyn KeyRetriever>) -> Self {
+        Self {
+            key_retriever: Some(key_retriever),
+            ..Default::default()
+        }
+    }
+
+    // Returns the key declared, or retrieved from `key_metadata`
+    pub(crate) fn key(
+        &self,
+        declared: Option<&Vec<u8>>,
+        key_metadata: Option<&[u8]>,
+    ) -> Result<Option<Vec<u8>>> {
+        if let Some(key) = declared {
+            return Ok(Some(key.clone()));
+        }
+        match (&self.key_retriever, key_metadata) {
+            (Some(retriever), Some(key_metadata)) => retriever.retrieve_key(key_metadata).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDecryptionProperties")
+            .field(
+                "footer_key",
+                &self.footer_key.as_ref().map(|_| "<redacted>"),
+            )
+            .field("column_keys", &self.column_keys.keys().collect::<Vec<_>>())
+            .field("key_retriever", &self.key_retriever.is_some())
+            .field("aad_prefix", &self.aad_prefix)
+            .finish()
+    }
+}
+
+/// The types of the modules of a file, which are part of their AAD
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+    ColumnIndex = 6,
+    OffsetIndex = 7,
+    BloomFilterHeader = 8,
+    BloomFilterBitset = 9,
+}
+
+/// Returns the AAD of the module of type `module_type` of the column chunk `column` of the row
+/// group `row_group` of a file whose AAD is `file_aad`. `page` is the ordinal of the page of
+/// data pages and their headers.
+/// # Error
+/// Errors iff an ordinal does not fit in an `i16`.
+pub(crate) fn module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group: usize,
+    column: usize,
+    page: Option<usize>,
+) -> Result<Vec<u8>> {
+    let mut aad = footer_aad(file_aad);
+    aad[file_aad.len()] = module_type as u8;
+    for ordinal in [Some(row_group), Some(column), page].into_iter().flatten() {
+        let ordinal = i16::try_from(ordinal).map_err(|_| {
+            ArrowError::InvalidArgumentError(
+                "Encrypted parquet files can't have more than 32767 row groups, columns or pages per column chunk"
+                    .to_string(),
+            )
+        })?;
+        aad.extend_from_slice(&ordinal.to_le_bytes());
+    }
+    Ok(aad)
+}
+
+/// Returns the AAD of the footer of a file whose AAD is `file_aad`
+pub(crate) fn footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    let mut aad = file_aad.to_vec();
+    aad.push(ModuleType::Footer as u8);
+    aad
+}
+
+/// An AES-GCM key encrypting and decrypting modules. Modules are stored as the length of what
+/// follows (4 bytes, little endian), a nonce (12 bytes), the ciphertext and, for AES-GCM, its
+/// tag (16 bytes).
+#[derive(Clone)]
+pub(crate) enum ModuleCipher {
+    Aes128(Box<(AesGcm<Aes128, U12>, Aes128)>),
+    Aes192(Box<(AesGcm<Aes192, U12>, Aes192)>),
+    Aes256(Box<(AesGcm<Aes256, U12>, Aes256)>),
+}
+
+macro_rules! with_cipher {
+    ($self:expr, |$cipher:ident| $body:expr) => {
+        with_cipher!($self, |$cipher, _| $body)
+    };
+    ($self:expr, |$cipher:tt, $aes:tt| $body:expr) => {
+        match $self {
+            ModuleCipher::Aes128(ciphers) => {
+                let ($cipher, $aes) = ciphers.as_ref();
+                $body
+            }
+            ModuleCipher::Aes192(ciphers) => {
+                let ($cipher, $aes) = ciphers.as_ref();
+                $body
+            }
+            ModuleCipher::Aes256(ciphers) => {
+                let ($cipher, $aes) = ciphers.as_ref();
+                $body
+            }
+        }
+    };
+}
+
+impl ModuleCipher {
+    /// # Error
+    /// Errors iff `key` is not 16, 24 or 32 bytes long.
+    pub fn try_new(key: &[u8]) -> Result<Self> {
+        Ok(match key.len() {
+            16 => {
+                let key = GenericArray::from_slice(key);
+                Self::Aes128(Box::new((AesGcm::new(key), Aes128::new(key))))
+            }
+            24 => {
+                let key = GenericArray::from_slice(key);
+                Self::Aes192(Box::new((AesGcm::new(key), Aes192::new(key))))
+            }
+            32 => {
+                let key = GenericArray::from_slice(key);
+                Self::Aes256(Box::new((AesGcm::new(key), Aes256::new(key))))
+            }
+            other => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "AES keys must have 16, 24 or 32 bytes, but the key has {} bytes",
+                    other
+                )))
+            }
+        })
+    }
+
+    fn encrypt_in_place(
+        &self,
+        nonce: &[u8; NONCE_LENGTH],
+        aad: &[u8],
+        data: &mut [u8],
+    ) -> [u8; TAG_LENGTH] {
+        let nonce = GenericArray::from_slice(nonce);
+        let tag = with_cipher!(self, |cipher| cipher
+            .encrypt_in_place_detached(nonce, aad, data)
+            .expect(
+                "parquet modules are smaller than the maximum length of AES-GCM plaintexts"
+            ));
+        tag.into()
+    }
+
+    /// Encrypts `plaintext` to a module authenticated with `aad`
+    pub fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Vec<u8> {
+        let nonce = new_nonce();
+        let length = NONCE_LENGTH + plaintext.len() + TAG_LENGTH;
+        let mut module = Vec::with_capacity(4 + length);
+        module.extend_from_slice(&(length as u32).to_le_bytes());
+        module.extend_from_slice(&nonce);
+        module.extend_from_slice(plaintext);
+        let tag = self.encrypt_in_place(&nonce, aad, &mut module[4 + NONCE_LENGTH..]);
+        module.extend_from_slice(&tag);
+        module
+    }
+
+    /// Returns the signature of `plaintext` with `aad`: a nonce followed by the tag of the
+    /// encryption of `plaintext`
+    pub fn sign(&self, plaintext: &[u8], aad: &[u8]) -> Vec<u8> {
+        self.signature(new_nonce(), plaintext, aad)
+    }
+
+    /// Verifies that `signature` is the signature of `plaintext` with `aad`
+    pub fn verify(&self, plaintext: &[u8], aad: &[u8], signature: &[u8]) -> Result<()> {
+        let nonce = signature
+            .get(..NONCE_LENGTH)
+            .filter(|_| signature.len() == NONCE_LENGTH + TAG_LENGTH)
+            .ok_or_else(|| {
+                ArrowError::ExternalFormat(
+                    "The signature of the footer of a parquet file must have 28 bytes".to_string(),
+                )
+            })?;
+        // the tag of the signature authenticates the encryption of the footer
+        let mut ciphertext = plaintext.to_vec();
+        self.encrypt_in_place(nonce.try_into().unwrap(), aad, &mut ciphertext);
+        let nonce = GenericArray::from_slice(nonce);
+        let tag = GenericArray::from_slice(&signature[NONCE_LENGTH..]);
+        with_cipher!(self, |cipher| cipher.decrypt_in_place_detached(
+            nonce,
+            aad,
+            &mut ciphertext,
+            tag
+        ))
+        .map_err(|_| {
+            ArrowError::ExternalFormat(
+                "The signature of the footer of the parquet file is invalid: either the footer key is wrong or the footer was modified"
+                    .to_string(),
+            )
+        })
+    }
+
+    fn signature(&self, nonce: [u8; NONCE_LENGTH], plaintext: &[u8], aad: &[u8]) -> Vec<u8> {
+        let mut ciphertext = plaintext.to_vec();
+        let tag = self.encrypt_in_place(&nonce, aad, &mut ciphertext);
+        let mut signature = nonce.to_vec();
+        signature.extend_from_slice(&tag);
+        signature
+    }
+
+    /// Decrypts the module encrypted with AES-GCM at the beginning of `data`, returning its
+    /// plaintext and the data after it.
+    /// # Error
+    /// Errors iff the module is truncated or can't be authenticated with `aad`.
+    pub fn decrypt<'a>(&self, data: &'a [u8], aad: &[u8]) -> Result<(Vec<u8>, &'a [u8])> {
+        let (module, remaining) = split_module(data, NONCE_LENGTH + TAG_LENGTH)?;
+        let (nonce, ciphertext) = module.split_at(NONCE_LENGTH);
+        let (ciphertext, tag) = ciphertext.split_at(ciphertext.len() - TAG_LENGTH);
+        let mut plaintext = ciphertext.to_vec();
+        let nonce = GenericArray::from_slice(nonce);
+        let tag = GenericArray::from_slice(tag);
+        with_cipher!(self, |cipher| cipher.decrypt_in_place_detached(
+            nonce,
+            aad,
+            &mut plaintext,
+            tag
+        ))
+        .map_err(|_| {
+            ArrowError::ExternalFormat(
+                "A module of the parquet file can't be decrypted: either the key is wrong or the module was modified"
+                    .to_string(),
+            )
+        })?;
+        Ok((plaintext, remaining))
+    }
+
+    /// Decrypts the module encrypted with AES-CTR at the beginning of `data`, returning its
+    /// plaintext and the data after it.
+    /// # Error
+    /// Errors iff the module is truncated.
+    pub fn decrypt_ctr<'a>(&self, data: &'a [u8]) -> Result<(Vec<u8>, &'a [u8])> {
+        let (module, remaining) = split_module(data, NONCE_LENGTH)?;
+        let (nonce, ciphertext) = module.split_at(NONCE_LENGTH);
+        // the counter of the 16 bytes IV starts at 1
+        let mut iv = [0; 16];
+        iv[..NONCE_LENGTH].copy_from_slice(nonce);
+        iv[15] = 1;
+        let iv = GenericArray::from_slice(&iv);
+        let mut plaintext = ciphertext.to_vec();
+        with_cipher!(self, |_, aes| ctr::Ctr32BE::from_core(
+            ctr::CtrCore::inner_iv_init(aes.clone(), iv)
+        )
+        .apply_keystream(&mut plaintext));
+        Ok((plaintext, remaining))
+    }
+}
+
+/// Returns `length` random bytes
+pub(crate) fn random_bytes(length: usize) -> Vec<u8> {
+    let mut bytes = vec![0; length];
+    OsRng.fill_bytes(&mut bytes);
+    bytes
+}
+
+fn new_nonce() -> [u8; NONCE_LENGTH] {
+    let mut nonce = [0; NONCE_LENGTH];
+    OsRng.fill_bytes(&mut nonce);
+    nonce
+}
+
+// splits `data` into the module at its beginning (without its length) and the data after it
+fn split_module(data: &[u8], min_length: usize) -> Result<(&[u8], &[u8])> {
+    let error =
+        || ArrowError::ExternalFormat("A module of the parquet file is truncated".to_string());
+    let length = data
+        .get(..4)
+        .map(|length| u32::from_le_bytes(length.try_into().unwrap()) as usize)
+        .ok_or_else(error)?;
+    if length < min_length || data.len() - 4 < length {
+        return Err(error());
+    }
+    Ok(data[4..].split_at(length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_round_trip() {
+        let cipher = ModuleCipher::try_new(&[1; 32]).unwrap();
+        let module = cipher.encrypt(b"hello", b"aad");
+        assert_eq!(module.len(), 4 + 12 + 5 + 16);
+
+        let mut data = module.clone();
+        data.extend_from_slice(b"rest");
+        let (plaintext, remaining) = cipher.decrypt(&data, b"aad").unwrap();
+        assert_eq!(plaintext, b"hello");
+        assert_eq!(remaining, b"rest");
+
+        assert!(cipher.decrypt(&data, b"other").is_err());
+        assert!(cipher.decrypt(&module[..module.len() - 1], b"aad").is_err());
+    }
+
+    #[test]
+    fn invalid_key() {
+        assert!(ModuleCipher::try_new(&[1; 16]).is_ok());
+        assert!(ModuleCipher::try_new(&[1; 24]).is_ok());
+        assert!(ModuleCipher::try_new(&[1; 32]).is_ok());
+        assert!(ModuleCipher::try_new(&[1; 15]).is_err());
+    }
+
+    #[test]
+    fn ctr() {
+        // the first block of the key stream is the encryption of the nonce followed by the
+        // counter 1, i.e. the tag of the GCM test case 1 of McGrew and Viega
+        let cipher = ModuleCipher::try_new(&[0; 16]).unwrap();
+        let mut module = 28u32.to_le_bytes().to_vec();
+        module.extend_from_slice(&[0; 28]);
+        let (plaintext, remaining) = cipher.decrypt_ctr(&module).unwrap();
+        assert_eq!(
+            plaintext,
+            [
+                0x58, 0xe2, 0xfc, 0xce, 0xfa, 0x7e, 0x30, 0x61, 0x36, 0x7f, 0x1d, 0x57, 0xa4, 0xe7,
+                0x45, 0x5a
+            ]
+        );
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn signature() {
+        let cipher = ModuleCipher::try_new(&[1; 16]).unwrap();
+        let signature = cipher.sign(b"footer", b"aad");
+        cipher.verify(b"footer", b"aad", &signature).unwrap();
+        assert!(cipher.verify(b"footes", b"aad", &signature).is_err());
+    }
+
+    #[test]
+    fn aad() {
+        let aad = module_aad(b"file", ModuleType::DataPage, 1, 2, Some(3)).unwrap();
+        assert_eq!(aad, b"file\x02\x01\x00\x02\x00\x03\x00");
+        let aad = module_aad(b"file", ModuleType::ColumnMetaData, 1, 2, None).unwrap();
+        assert_eq!(aad, b"file\x01\x01\x00\x02\x00");
+        assert_eq!(footer_aad(b"file"), b"file\x00");
+        assert!(module_aad(b"file", ModuleType::DataPage, 1, 2, Some(1 << 15)).is_err());
+    }
+}
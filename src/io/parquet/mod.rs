@@ -1,11 +1,20 @@
 //! APIs to read from and write to Parquet format.
 use crate::error::ArrowError;
 
+#[cfg(feature = "io_parquet_encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_encryption")))]
+pub mod encryption;
 pub mod read;
 pub mod write;
 
 const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
 
+/// The key of the metadata of a [`Field`](crate::datatypes::Field) with the id of its parquet
+/// field (e.g. `"1"`), as used by pyarrow. The writer sets the `field_id` of the parquet field of
+/// fields with this key, and the fields inferred from a parquet schema have it for parquet
+/// fields with a `field_id`.
+pub const PARQUET_FIELD_ID_META_KEY: &str = "PARQUET:field_id";
+
 impl From<parquet2::error::Error> for ArrowError {
     fn from(error: parquet2::error::Error) -> Self {
         match error {
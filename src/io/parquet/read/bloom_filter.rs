@@ -0,0 +1,175 @@
+//! APIs to read and probe the (split block) bloom filters of parquet column chunks.
+//!
+//! A bloom filter answers whether a value is possibly contained in a column chunk, which
+//! allows skipping row groups on point lookups of high-cardinality columns (e.g. ids), where
+//! statistics are of little use.
+//!
+//! Bloom filters are written via [`ColumnOptions::bloom_filter`](crate::io::parquet::write::ColumnOptions::bloom_filter).
+use std::io::{Read, Seek};
+
+// re-exported to build and probe bitsets directly
+pub use parquet2::bloom_filter::{hash_byte, hash_native, insert, is_in_set};
+
+use crate::array::*;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::io::parquet::write::decimal_length_from_precision;
+use crate::types::NativeType;
+
+use super::ColumnChunkMetaData;
+
+/// Reads the bitset of the bloom filter of `column` from `reader`, returning `None` when the
+/// column chunk has no bloom filter or a bloom filter that is not supported
+/// (i.e. not an uncompressed split block bloom filter).
+/// # Error
+/// Errors iff the bloom filter can't be read, including when the column is encrypted.
+pub fn read<R: Read + Seek>(
+    column: &ColumnChunkMetaData,
+    reader: &mut R,
+) -> Result<Option<Vec<u8>>> {
+    super::check_not_encrypted([column], "bloom filter")?;
+    let mut bitset = vec![];
+    parquet2::bloom_filter::read(column, reader, &mut bitset)?;
+    Ok(if bitset.is_empty() {
+        None
+    } else {
+        Some(bitset)
+    })
+}
+
+fn hash_primitive<T, P, F>(array: &dyn Array, f: F) -> Vec<Option<u64>>
+where
+    T: NativeType,
+    P: parquet2::types::NativeType,
+    F: Fn(T) -> P,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array
+        .iter()
+        .map(|x| x.map(|x| hash_native(f(*x))))
+        .collect()
+}
+
+fn hash_binary<O: Offset>(array: &dyn Array) -> Vec<Option<u64>> {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    array.iter().map(|x| x.map(hash_byte)).collect()
+}
+
+fn hash_utf8<O: Offset>(array: &dyn Array) -> Vec<Option<u64>> {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    array.iter().map(|x| x.map(hash_byte)).collect()
+}
+
+fn hash_dictionary<K: DictionaryKey>(array: &dyn Array) -> Result<Vec<Option<u64>>> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = hash(array.values().as_ref())?;
+    Ok(array
+        .keys()
+        .iter()
+        .map(|key| key.and_then(|key| values[key.to_usize().unwrap()]))
+        .collect())
+}
+
+// the hash of each value of `array` according to how it is written to parquet
+pub(crate) fn hash(array: &dyn Array) -> Result<Vec<Option<u64>>> {
+    use PrimitiveType::*;
+    Ok(match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(primitive) => match primitive {
+            Int8 => hash_primitive(array, |x: i8| x as i32),
+            Int16 => hash_primitive(array, |x: i16| x as i32),
+            Int32 => hash_primitive(array, |x: i32| x),
+            Int64 => hash_primitive(array, |x: i64| x),
+            UInt8 => hash_primitive(array, |x: u8| x as i32),
+            UInt16 => hash_primitive(array, |x: u16| x as i32),
+            UInt32 => hash_primitive(array, |x: u32| x as i32),
+            UInt64 => hash_primitive(array, |x: u64| x as i64),
+            Float32 => hash_primitive(array, |x: f32| x),
+            Float64 => hash_primitive(array, |x: f64| x),
+            Int128 => match array.data_type().to_logical_type() {
+                DataType::Decimal(precision, _) if *precision <= 9 => {
+                    hash_primitive(array, |x: i128| x as i32)
+                }
+                DataType::Decimal(precision, _) if *precision <= 18 => {
+                    hash_primitive(array, |x: i128| x as i64)
+                }
+                DataType::Decimal(precision, _) => {
+                    let size = decimal_length_from_precision(*precision);
+                    let array = array
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<i128>>()
+                        .unwrap();
+                    array
+                        .iter()
+                        .map(|x| x.map(|x| hash_byte(&x.to_be_bytes()[16 - size..])))
+                        .collect()
+                }
+                other => {
+                    return Err(ArrowError::NotYetImplemented(format!(
+                        "Bloom filters for the type {:?}",
+                        other
+                    )))
+                }
+            },
+            other => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Bloom filters for the type {:?}",
+                    other
+                )))
+            }
+        },
+        PhysicalType::Binary => hash_binary::<i32>(array),
+        PhysicalType::LargeBinary => hash_binary::<i64>(array),
+        PhysicalType::Utf8 => hash_utf8::<i32>(array),
+        PhysicalType::LargeUtf8 => hash_utf8::<i64>(array),
+        PhysicalType::FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            array.iter().map(|x| x.map(hash_byte)).collect()
+        }
+        PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            hash_dictionary::<$T>(array)?
+        }),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Bloom filters for the type {:?}",
+                array.data_type()
+            )))
+        }
+    })
+}
+
+/// Returns whether each value of `values` is possibly contained in the bloom filter `bitset`.
+///
+/// `false` means that the value is certainly not in the column chunk of the filter, so that
+/// its row group can be skipped. Values are hashed according to how this crate writes them
+/// to parquet (e.g. [`DataType::UInt32`] as parquet's `INT32`), so `values` must be of the
+/// type the column is read as. Nulls are not part of bloom filters and are thus null.
+/// # Errors
+/// This function errors when `bitset` is not a valid split block bloom filter (i.e. its
+/// length is not a positive multiple of 32) and when the type of `values` is not supported
+/// (booleans and nested types).
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int64Array};
+/// use arrow2::io::parquet::read::bloom_filter::contains;
+///
+/// // an empty bloom filter of one block
+/// let bitset = vec![0; 32];
+///
+/// let result = contains(&bitset, &Int64Array::from(&[Some(1), None])).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(false), None]));
+/// ```
+pub fn contains(bitset: &[u8], values: &dyn Array) -> Result<BooleanArray> {
+    if bitset.is_empty() || !bitset.chunks_exact(32).remainder().is_empty() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "A split block bloom filter must have a positive multiple of 32 bytes, but it has {}",
+            bitset.len()
+        )));
+    }
+    Ok(hash(values)?
+        .into_iter()
+        .map(|hash| hash.map(|hash| is_in_set(bitset, hash)))
+        .collect())
+}
@@ -23,44 +23,6 @@ use super::super::utils::{
 use super::super::DataPages;
 use super::{super::utils, utils::*};
 
-/*
-fn read_delta_optional<O: Offset>(
-    validity_buffer: &[u8],
-    values_buffer: &[u8],
-    additional: usize,
-    values: &mut Binary<O>,
-    validity: &mut MutableBitmap,
-) {
-    let Binary {
-        offsets,
-        values,
-        last_offset,
-    } = values;
-
-    // values_buffer: first 4 bytes are len, remaining is values
-    let mut values_iterator = delta_length_byte_array::Decoder::new(values_buffer);
-    let offsets_iterator = values_iterator.by_ref().map(|x| {
-        *last_offset += O::from_usize(x as usize).unwrap();
-        *last_offset
-    });
-
-    let mut page_validity = OptionalPageValidity::new(validity_buffer, additional);
-
-    // offsets:
-    extend_from_decoder(
-        validity,
-        &mut page_validity,
-        None,
-        offsets,
-        offsets_iterator,
-    );
-
-    // values:
-    let new_values = values_iterator.into_values();
-    values.extend_from_slice(new_values);
-}
- */
-
 #[derive(Debug)]
 pub(super) struct Required<'a> {
     pub values: SizedBinaryIter<'a>,
@@ -168,6 +130,10 @@ enum State<'a> {
     FilteredOptional(FilteredOptionalPageValidity<'a>, BinaryIter<'a>),
     FilteredRequiredDictionary(FilteredRequiredDictionary<'a>),
     FilteredOptionalDictionary(FilteredOptionalPageValidity<'a>, ValuesDictionary<'a>),
+    Delta(Delta<'a>),
+    OptionalDelta(OptionalPageValidity<'a>, Delta<'a>),
+    FilteredDelta(Delta<'a>),
+    FilteredOptionalDelta(FilteredOptionalPageValidity<'a>, Delta<'a>),
 }
 
 impl<'a> utils::PageState<'a> for State<'a> {
@@ -181,6 +147,10 @@ impl<'a> utils::PageState<'a> for State<'a> {
             State::FilteredOptional(validity, _) => validity.len(),
             State::FilteredRequiredDictionary(values) => values.len(),
             State::FilteredOptionalDictionary(optional, _) => optional.len(),
+            State::Delta(values) => values.len(),
+            State::OptionalDelta(optional, _) => optional.len(),
+            State::FilteredDelta(values) => values.len(),
+            State::FilteredOptionalDelta(optional, _) => optional.len(),
         }
     }
 }
@@ -292,6 +262,29 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
                     BinaryIter::new(values),
                 ))
             }
+            (
+                Encoding::DeltaLengthByteArray | Encoding::DeltaByteArray,
+                _,
+                is_optional,
+                is_filtered,
+            ) => {
+                let (_, _, values) = utils::split_buffer(page);
+                let values = if page.encoding() == Encoding::DeltaLengthByteArray {
+                    Delta::try_from_lengths(values)?
+                } else {
+                    Delta::try_from_prefixes(values)?
+                };
+
+                Ok(match (is_optional, is_filtered) {
+                    (false, false) => State::Delta(values),
+                    (true, false) => State::OptionalDelta(OptionalPageValidity::new(page), values),
+                    (false, true) => State::FilteredDelta(values.filter(get_selected_rows(page))),
+                    (true, true) => State::FilteredOptionalDelta(
+                        FilteredOptionalPageValidity::new(page),
+                        values,
+                    ),
+                })
+            }
             _ => Err(utils::not_implemented(page)),
         }
     }
@@ -401,6 +394,25 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
                     &mut page_values.values.by_ref().map(op),
                 )
             }
+            State::Delta(page) | State::FilteredDelta(page) => {
+                for x in page.iter().take(additional) {
+                    values.push(x)
+                }
+            }
+            State::OptionalDelta(page_validity, page_values) => extend_from_decoder(
+                validity,
+                page_validity,
+                Some(additional),
+                values,
+                page_values.iter(),
+            ),
+            State::FilteredOptionalDelta(page_validity, page_values) => extend_from_decoder(
+                validity,
+                page_validity,
+                Some(additional),
+                values,
+                page_values.iter(),
+            ),
         }
     }
 }
@@ -1,4 +1,10 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use parquet2::{deserialize::SliceFilteredIter, encoding::delta_bitpacked, indexes::Interval};
+
 use crate::array::Offset;
+use crate::error::{ArrowError, Result};
 
 use super::super::utils::Pushable;
 
@@ -148,3 +154,115 @@ impl<'a> Iterator for SizedBinaryIter<'a> {
         (self.remaining, Some(self.remaining))
     }
 }
+
+/// The values of a page encoded with `DELTA_LENGTH_BYTE_ARRAY` or `DELTA_BYTE_ARRAY`, whose
+/// lengths (and prefixes) are all stored before the values and must thus be decoded up front.
+#[derive(Debug)]
+pub struct Delta<'a> {
+    values: Cow<'a, [u8]>,
+    // the (start, end) of each remaining value in `values`
+    ranges: std::vec::IntoIter<(usize, usize)>,
+}
+
+impl<'a> Delta<'a> {
+    /// Decodes `values` encoded as `DELTA_LENGTH_BYTE_ARRAY`, which are borrowed
+    pub fn try_from_lengths(values: &'a [u8]) -> Result<Self> {
+        let mut lengths = delta_bitpacked::Decoder::new(values);
+        let mut ranges = Vec::with_capacity(lengths.size_hint().0);
+        let mut start = 0;
+        for length in lengths.by_ref() {
+            let length = usize::try_from(length)
+                .map_err(|_| ArrowError::oos("The length of a binary value must be positive"))?;
+            ranges.push((start, start + length));
+            start += length;
+        }
+        let values = &values[lengths.consumed_bytes()..];
+        if start > values.len() {
+            return Err(ArrowError::oos(
+                "The lengths of a DELTA_LENGTH_BYTE_ARRAY page exceed its values",
+            ));
+        }
+        Ok(Self {
+            values: Cow::Borrowed(&values[..start]),
+            ranges: ranges.into_iter(),
+        })
+    }
+
+    /// Decodes `values` encoded as `DELTA_BYTE_ARRAY`, whose prefixes are copied into new values
+    pub fn try_from_prefixes(values: &'a [u8]) -> Result<Self> {
+        let mut prefixes = delta_bitpacked::Decoder::new(values);
+        let prefix_lengths = prefixes.by_ref().collect::<Vec<_>>();
+        let suffixes = Self::try_from_lengths(&values[prefixes.consumed_bytes()..])?;
+        if prefix_lengths.len() != suffixes.len() {
+            return Err(ArrowError::oos(
+                "A DELTA_BYTE_ARRAY page must have as many prefixes as suffixes",
+            ));
+        }
+
+        let mut buffer = Vec::with_capacity(suffixes.values.len());
+        let mut ranges = Vec::with_capacity(prefix_lengths.len());
+        let mut previous = (0, 0);
+        for (prefix_length, (start, end)) in prefix_lengths.into_iter().zip(suffixes.ranges) {
+            let prefix_length = usize::try_from(prefix_length)
+                .ok()
+                .filter(|length| *length <= previous.1 - previous.0)
+                .ok_or_else(|| {
+                    ArrowError::oos(
+                        "The prefix of a DELTA_BYTE_ARRAY value must be part of the previous value",
+                    )
+                })?;
+            let offset = buffer.len();
+            buffer.extend_from_within(previous.0..previous.0 + prefix_length);
+            buffer.extend_from_slice(&suffixes.values[start..end]);
+            previous = (offset, buffer.len());
+            ranges.push(previous);
+        }
+        Ok(Self {
+            values: Cow::Owned(buffer),
+            ranges: ranges.into_iter(),
+        })
+    }
+
+    /// Only keeps the values selected by `rows`
+    pub fn filter(self, rows: VecDeque<Interval>) -> Self {
+        let ranges = SliceFilteredIter::new(self.ranges, rows).collect::<Vec<_>>();
+        Self {
+            values: self.values,
+            ranges: ranges.into_iter(),
+        }
+    }
+
+    /// The number of remaining values
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// An iterator over (and consuming) the remaining values
+    pub fn iter(&mut self) -> DeltaIter<'_> {
+        DeltaIter {
+            values: &self.values,
+            ranges: &mut self.ranges,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DeltaIter<'b> {
+    values: &'b [u8],
+    ranges: &'b mut std::vec::IntoIter<(usize, usize)>,
+}
+
+impl<'b> Iterator for DeltaIter<'b> {
+    type Item = &'b [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ranges
+            .next()
+            .map(|(start, end)| &self.values[start..end])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ranges.size_hint()
+    }
+}
@@ -18,7 +18,9 @@ use crate::{
 
 use self::nested_utils::{InitNested, NestedArrayIter, NestedState};
 use parquet2::schema::types::PrimitiveType;
+pub(crate) use simple::int96_to_i128_ns;
 use simple::page_iter_to_arrays;
+pub use simple::Int96Overflow;
 
 use super::*;
 
@@ -90,6 +92,7 @@ fn columns_to_iter_recursive<'a, I: 'a>(
     field: Field,
     mut init: Vec<InitNested>,
     chunk_size: usize,
+    int96_overflow: Int96Overflow,
 ) -> Result<NestedArrayIter<'a>>
 where
     I: DataPages,
@@ -102,6 +105,7 @@ where
                 types.pop().unwrap(),
                 field.data_type,
                 chunk_size,
+                int96_overflow,
             )?
             .map(|x| Ok((NestedState::new(vec![]), x?))),
         ));
@@ -255,6 +259,7 @@ where
                 inner.as_ref().clone(),
                 init,
                 chunk_size,
+                int96_overflow,
             )?;
             let iter = iter.map(move |x| {
                 let (mut nested, array) = x?;
@@ -268,12 +273,16 @@ where
                 .iter()
                 .rev()
                 .map(|f| {
+                    // every field consumes the (last) columns of its leaves
+                    let n = n_columns(&f.data_type);
+                    let at = columns.len() - n;
                     columns_to_iter_recursive(
-                        vec![columns.pop().unwrap()],
-                        vec![types.pop().unwrap()],
+                        columns.split_off(at),
+                        types.split_off(at),
                         f.clone(),
-                        vec![init.pop().unwrap()],
+                        init.split_off(at),
                         chunk_size,
+                        int96_overflow,
                     )
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -284,6 +293,33 @@ where
     })
 }
 
+/// Returns the number of (parquet) columns that a [`DataType`] contains.
+fn n_columns(data_type: &DataType) -> usize {
+    use crate::datatypes::PhysicalType::*;
+    match data_type.to_physical_type() {
+        List | FixedSizeList | LargeList => {
+            let a = data_type.to_logical_type();
+            if let DataType::List(inner) = a {
+                n_columns(&inner.data_type)
+            } else if let DataType::LargeList(inner) = a {
+                n_columns(&inner.data_type)
+            } else if let DataType::FixedSizeList(inner, _) = a {
+                n_columns(&inner.data_type)
+            } else {
+                unreachable!()
+            }
+        }
+        Struct => {
+            if let DataType::Struct(fields) = data_type.to_logical_type() {
+                fields.iter().map(|inner| n_columns(&inner.data_type)).sum()
+            } else {
+                unreachable!()
+            }
+        }
+        _ => 1,
+    }
+}
+
 fn field_to_init(field: &Field) -> Vec<InitNested> {
     use crate::datatypes::PhysicalType::*;
     match field.data_type.to_physical_type() {
@@ -307,7 +343,7 @@ fn field_to_init(field: &Field) -> Vec<InitNested> {
         }
         Struct => {
             let inner = if let DataType::Struct(fields) = field.data_type.to_logical_type() {
-                fields.iter().rev().map(field_to_init).collect::<Vec<_>>()
+                fields.iter().map(field_to_init).collect::<Vec<_>>()
             } else {
                 unreachable!()
             };
@@ -324,6 +360,7 @@ fn field_to_init(field: &Field) -> Vec<InitNested> {
 /// An iterator adapter that maps multiple iterators of [`DataPages`] into an iterator of [`Array`]s.
 ///
 /// The arrays are guaranteed to be at most of size `chunk_size` and data type `field.data_type`.
+/// `INT96` timestamps out of the range of their unit error (see [`Int96Overflow`]).
 pub fn column_iter_to_arrays<'a, I: 'a>(
     columns: Vec<I>,
     types: Vec<&PrimitiveType>,
@@ -332,10 +369,24 @@ pub fn column_iter_to_arrays<'a, I: 'a>(
 ) -> Result<ArrayIter<'a>>
 where
     I: DataPages,
+{
+    _column_iter_to_arrays(columns, types, field, chunk_size, Int96Overflow::default())
+}
+
+pub(super) fn _column_iter_to_arrays<'a, I>(
+    columns: Vec<I>,
+    types: Vec<&PrimitiveType>,
+    field: Field,
+    chunk_size: usize,
+    int96_overflow: Int96Overflow,
+) -> Result<ArrayIter<'a>>
+where
+    I: 'a + DataPages,
 {
     let init = field_to_init(&field);
 
     Ok(Box::new(
-        columns_to_iter_recursive(columns, types, field, init, chunk_size)?.map(|x| x.map(|x| x.1)),
+        columns_to_iter_recursive(columns, types, field, init, chunk_size, int96_overflow)?
+            .map(|x| x.map(|x| x.1)),
     ))
 }
@@ -18,6 +18,11 @@ pub trait Nested: std::fmt::Debug + Send + Sync {
 
     fn is_nullable(&self) -> bool;
 
+    /// whether this is repeated (i.e. a list), which adds a definition level
+    fn is_repeated(&self) -> bool {
+        false
+    }
+
     /// number of rows
     fn len(&self) -> usize;
 
@@ -79,6 +84,10 @@ impl Nested for NestedOptional {
         true
     }
 
+    fn is_repeated(&self) -> bool {
+        true
+    }
+
     fn push(&mut self, value: i64, is_valid: bool) {
         self.offsets.push(value);
         self.validity.push(is_valid);
@@ -116,6 +125,10 @@ impl Nested for NestedValid {
         false
     }
 
+    fn is_repeated(&self) -> bool {
+        true
+    }
+
     fn push(&mut self, value: i64, _is_valid: bool) {
         self.offsets.push(value);
     }
@@ -188,7 +201,7 @@ impl Nested for NestedStruct {
     }
 
     fn is_nullable(&self) -> bool {
-        false
+        true
     }
 
     fn push(&mut self, _value: i64, is_valid: bool) {
@@ -264,8 +277,23 @@ fn init_nested(init: &InitNested, capacity: usize) -> NestedState {
     NestedState::new(container)
 }
 
+type Levels<'a> = Box<dyn Iterator<Item = u32> + 'a>;
+
+// the levels of a page, which are not stored (and thus all zero) when `max_level` is zero
+fn levels<'a>(buffer: &'a [u8], max_level: i16, num_values: usize) -> Levels<'a> {
+    if max_level == 0 {
+        Box::new((0..num_values).map(|_| 0))
+    } else {
+        Box::new(HybridRleDecoder::new(
+            buffer,
+            get_bit_width(max_level),
+            num_values,
+        ))
+    }
+}
+
 pub struct NestedPage<'a> {
-    iter: std::iter::Peekable<std::iter::Zip<HybridRleDecoder<'a>, HybridRleDecoder<'a>>>,
+    iter: std::iter::Peekable<std::iter::Zip<Levels<'a>, Levels<'a>>>,
 }
 
 impl<'a> NestedPage<'a> {
@@ -275,10 +303,8 @@ impl<'a> NestedPage<'a> {
         let max_rep_level = page.descriptor.max_rep_level;
         let max_def_level = page.descriptor.max_def_level;
 
-        let reps =
-            HybridRleDecoder::new(rep_levels, get_bit_width(max_rep_level), page.num_values());
-        let defs =
-            HybridRleDecoder::new(def_levels, get_bit_width(max_def_level), page.num_values());
+        let reps = levels(rep_levels, max_rep_level, page.num_values());
+        let defs = levels(def_levels, max_def_level, page.num_values());
 
         let iter = reps.zip(defs).peekable();
 
@@ -399,7 +425,7 @@ fn extend_offsets2<'a>(page: &mut NestedPage<'a>, nested: &mut NestedState, addi
 
     let mut cum_sum = vec![0u32; nested.len() + 1];
     for (i, nest) in nested.iter().enumerate() {
-        let delta = if nest.is_nullable() { 2 } else { 1 };
+        let delta = nest.is_nullable() as u32 + nest.is_repeated() as u32;
         cum_sum[i + 1] = cum_sum[i] + delta;
     }
 
@@ -10,7 +10,10 @@ use parquet2::{
 };
 
 use crate::{
-    array::MutablePrimitiveArray, bitmap::MutableBitmap, datatypes::DataType, error::Result,
+    array::MutablePrimitiveArray,
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
     types::NativeType,
 };
 
@@ -62,6 +65,62 @@ impl<'a> Values<'a> {
     }
 }
 
+/// The values of a page encoded as `BYTE_STREAM_SPLIT`, where the `k`-th byte of every value
+/// is stored in the `k`-th stream (of length the number of values).
+#[derive(Debug)]
+pub(super) struct ByteStreamSplit<'a, P: ParquetNativeType> {
+    values: &'a [u8],
+    num_values: usize,
+    index: usize,
+    phantom: std::marker::PhantomData<P>,
+}
+
+impl<'a, P: ParquetNativeType> ByteStreamSplit<'a, P> {
+    pub fn try_new(page: &'a DataPage) -> Result<Self> {
+        let (_, _, values) = utils::split_buffer(page);
+        let size = std::mem::size_of::<P>();
+        if values.len() % size != 0 {
+            return Err(ArrowError::oos(
+                "A BYTE_STREAM_SPLIT page must have a multiple of the size of its type",
+            ));
+        }
+        Ok(Self {
+            values,
+            num_values: values.len() / size,
+            index: 0,
+            phantom: std::marker::PhantomData,
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.num_values - self.index
+    }
+}
+
+impl<'a, P: ParquetNativeType> Iterator for ByteStreamSplit<'a, P> {
+    type Item = P;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.num_values {
+            return None;
+        }
+        let size = std::mem::size_of::<P>();
+        let mut bytes = [0u8; 16];
+        bytes[..size]
+            .iter_mut()
+            .zip(self.values[self.index..].iter().step_by(self.num_values))
+            .for_each(|(byte, value)| *byte = *value);
+        self.index += 1;
+        Some(P::from_le_bytes(bytes[..size].try_into().unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct ValuesDictionary<'a, P>
 where
@@ -102,6 +161,10 @@ where
     OptionalDictionary(OptionalPageValidity<'a>, ValuesDictionary<'a, P>),
     FilteredRequired(FilteredRequiredValues<'a>),
     FilteredOptional(FilteredOptionalPageValidity<'a>, Values<'a>),
+    ByteStreamSplit(ByteStreamSplit<'a, P>),
+    OptionalByteStreamSplit(OptionalPageValidity<'a>, ByteStreamSplit<'a, P>),
+    FilteredByteStreamSplit(SliceFilteredIter<ByteStreamSplit<'a, P>>),
+    FilteredOptionalByteStreamSplit(FilteredOptionalPageValidity<'a>, ByteStreamSplit<'a, P>),
 }
 
 impl<'a, P> utils::PageState<'a> for State<'a, P>
@@ -116,6 +179,10 @@ where
             State::OptionalDictionary(optional, _) => optional.len(),
             State::FilteredRequired(values) => values.len(),
             State::FilteredOptional(optional, _) => optional.len(),
+            State::ByteStreamSplit(values) => values.len(),
+            State::OptionalByteStreamSplit(optional, _) => optional.len(),
+            State::FilteredByteStreamSplit(values) => values.size_hint().0,
+            State::FilteredOptionalByteStreamSplit(optional, _) => optional.len(),
         }
     }
 }
@@ -200,6 +267,23 @@ where
                 FilteredOptionalPageValidity::new(page),
                 Values::new::<P>(page),
             )),
+            (Encoding::ByteStreamSplit, _, is_optional, is_filtered) => {
+                let values = ByteStreamSplit::try_new(page)?;
+                Ok(match (is_optional, is_filtered) {
+                    (false, false) => State::ByteStreamSplit(values),
+                    (true, false) => {
+                        State::OptionalByteStreamSplit(OptionalPageValidity::new(page), values)
+                    }
+                    (false, true) => State::FilteredByteStreamSplit(SliceFilteredIter::new(
+                        values,
+                        get_selected_rows(page),
+                    )),
+                    (true, true) => State::FilteredOptionalByteStreamSplit(
+                        FilteredOptionalPageValidity::new(page),
+                        values,
+                    ),
+                })
+            }
             _ => Err(utils::not_implemented(page)),
         }
     }
@@ -267,6 +351,30 @@ where
                     page_values.values.by_ref().map(decode).map(self.op),
                 );
             }
+            State::ByteStreamSplit(page) => {
+                values.extend(page.by_ref().map(self.op).take(remaining));
+            }
+            State::FilteredByteStreamSplit(page) => {
+                values.extend(page.by_ref().map(self.op).take(remaining));
+            }
+            State::OptionalByteStreamSplit(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(remaining),
+                    values,
+                    page_values.by_ref().map(self.op),
+                )
+            }
+            State::FilteredOptionalByteStreamSplit(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(remaining),
+                    values,
+                    page_values.by_ref().map(self.op),
+                )
+            }
         }
     }
 }
@@ -1,14 +1,14 @@
 use std::sync::Arc;
 
-use parquet2::{
-    schema::types::{
-        PhysicalType, PrimitiveLogicalType, PrimitiveType, TimeUnit as ParquetTimeUnit,
-    },
-    types::int96_to_i64_ns,
+use parquet2::schema::types::{
+    PhysicalType, PrimitiveLogicalType, PrimitiveType, TimeUnit as ParquetTimeUnit,
 };
 
 use crate::{
-    array::{Array, BinaryArray, DictionaryKey, MutablePrimitiveArray, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, DictionaryArray, DictionaryKey, MutablePrimitiveArray, PrimitiveArray,
+        Utf8Array,
+    },
     datatypes::{DataType, IntervalUnit, TimeUnit},
     error::{ArrowError, Result},
     types::NativeType,
@@ -56,6 +56,70 @@ where
     })
 }
 
+/// How to deserialize timestamps of parquet's (deprecated) `INT96` type that are outside of the
+/// range of the [`TimeUnit`] they are read as, e.g. dates after the year 2262 read as nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Int96Overflow {
+    /// Deserializing them errors
+    #[default]
+    Error,
+    /// They saturate to the minimum or maximum timestamp of the unit
+    Saturate,
+}
+
+// the (temporary) type of INT96 values, in nanoseconds since the epoch, before being converted
+// to the unit they are read as
+const INT96_DATA_TYPE: DataType = DataType::Decimal(38, 0);
+
+// the number of nanoseconds since the epoch of an INT96, made of the nanoseconds within the
+// day (the first 8 bytes) and the julian day (the last 4). Unlike `i64`, an `i128` holds
+// every INT96.
+pub(crate) fn int96_to_i128_ns(value: [u32; 3]) -> i128 {
+    const JULIAN_DAY_OF_EPOCH: i128 = 2_440_588;
+    const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+
+    let day = value[2] as i128;
+    let nanoseconds = (((value[1] as u64) << 32) + value[0] as u64) as i128;
+    (day - JULIAN_DAY_OF_EPOCH) * NANOS_PER_DAY + nanoseconds
+}
+
+fn int96_to_timestamp(
+    array: &PrimitiveArray<i128>,
+    data_type: DataType,
+    time_unit: TimeUnit,
+    int96_overflow: Int96Overflow,
+) -> Result<PrimitiveArray<i64>> {
+    let factor = match time_unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    };
+    let values = array
+        .values()
+        .iter()
+        .map(|nanoseconds| {
+            // rounds towards negative infinity, i.e. to the start of the unit for times before the epoch
+            let x = nanoseconds.div_euclid(factor);
+            match int96_overflow {
+                Int96Overflow::Error => i64::try_from(x).map_err(|_| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "The INT96 timestamp of {} nanoseconds since the epoch is out of the range of timestamps in {:?}",
+                        nanoseconds,
+                        time_unit
+                    ))
+                }),
+                Int96Overflow::Saturate => Ok(x.clamp(i64::MIN as i128, i64::MAX as i128) as i64),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::from_data(
+        data_type,
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
 /// An iterator adapter that maps an iterator of DataPages into an iterator of Arrays
 /// of [`DataType`] `data_type` and `chunk_size`.
 pub fn page_iter_to_arrays<'a, I: 'a + DataPages>(
@@ -63,6 +127,7 @@ pub fn page_iter_to_arrays<'a, I: 'a + DataPages>(
     type_: &PrimitiveType,
     data_type: DataType,
     chunk_size: usize,
+    int96_overflow: Int96Overflow,
 ) -> Result<ArrayIter<'a>> {
     use DataType::*;
 
@@ -115,6 +180,7 @@ pub fn page_iter_to_arrays<'a, I: 'a + DataPages>(
                 data_type,
                 chunk_size,
                 time_unit,
+                int96_overflow,
             );
         }
 
@@ -214,7 +280,14 @@ pub fn page_iter_to_arrays<'a, I: 'a + DataPages>(
 
         Dictionary(key_type, _, _) => {
             return match_integer_type!(key_type, |$K| {
-                dict_read::<$K, _>(pages, physical_type, logical_type, data_type, chunk_size)
+                dict_read::<$K, _>(
+                    pages,
+                    physical_type,
+                    logical_type,
+                    data_type,
+                    chunk_size,
+                    int96_overflow,
+                )
             })
         }
 
@@ -234,20 +307,15 @@ fn timestamp<'a, I: 'a + DataPages>(
     data_type: DataType,
     chunk_size: usize,
     time_unit: TimeUnit,
+    int96_overflow: Int96Overflow,
 ) -> Result<ArrayIter<'a>> {
     if physical_type == &PhysicalType::Int96 {
-        if time_unit == TimeUnit::Nanosecond {
-            return Ok(dyn_iter(iden(primitive::Iter::new(
-                pages,
-                data_type,
-                chunk_size,
-                int96_to_i64_ns,
-            ))));
-        } else {
-            return Err(ArrowError::nyi(
-                "Can't decode int96 to timestamp other than ns",
-            ));
-        }
+        let iter = primitive::Iter::new(pages, INT96_DATA_TYPE, chunk_size, int96_to_i128_ns);
+        return Ok(dyn_iter(iter.map(move |x| {
+            x.and_then(|x| {
+                int96_to_timestamp(&x.into(), data_type.clone(), time_unit, int96_overflow)
+            })
+        })));
     };
     if physical_type != &PhysicalType::Int64 {
         return Err(ArrowError::nyi(
@@ -295,20 +363,38 @@ fn timestamp_dict<'a, K: DictionaryKey, I: 'a + DataPages>(
     data_type: DataType,
     chunk_size: usize,
     time_unit: TimeUnit,
+    int96_overflow: Int96Overflow,
 ) -> Result<ArrayIter<'a>> {
     if physical_type == &PhysicalType::Int96 {
-        if time_unit == TimeUnit::Nanosecond {
-            return Ok(dyn_iter(primitive::DictIter::<K, _, _, _, _>::new(
-                pages,
-                DataType::Timestamp(TimeUnit::Nanosecond, None),
-                chunk_size,
-                int96_to_i64_ns,
-            )));
-        } else {
-            return Err(ArrowError::nyi(
-                "Can't decode int96 to timestamp other than ns",
-            ));
-        }
+        let values_data_type = match &data_type {
+            DataType::Dictionary(_, values, _) => values.as_ref().clone(),
+            _ => data_type,
+        };
+        let iter = primitive::DictIter::<K, _, _, _, _>::new(
+            pages,
+            INT96_DATA_TYPE,
+            chunk_size,
+            int96_to_i128_ns,
+        );
+        return Ok(dyn_iter(iter.map(move |x| {
+            x.and_then(|x| {
+                let values = x
+                    .values()
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i128>>()
+                    .unwrap();
+                let values = int96_to_timestamp(
+                    values,
+                    values_data_type.clone(),
+                    time_unit,
+                    int96_overflow,
+                )?;
+                Ok(DictionaryArray::<K>::from_data(
+                    x.keys().clone(),
+                    Arc::new(values),
+                ))
+            })
+        })));
     };
 
     let unit = if let Some(PrimitiveLogicalType::Timestamp { unit, .. }) = logical_type {
@@ -431,6 +517,7 @@ fn dict_read<'a, K: DictionaryKey, I: 'a + DataPages>(
     logical_type: &Option<PrimitiveLogicalType>,
     data_type: DataType,
     chunk_size: usize,
+    int96_overflow: Int96Overflow,
 ) -> Result<ArrayIter<'a>> {
     use DataType::*;
     let values_data_type = if let Dictionary(_, v, _) = &data_type {
@@ -485,6 +572,7 @@ fn dict_read<'a, K: DictionaryKey, I: 'a + DataPages>(
                 data_type,
                 chunk_size,
                 time_unit,
+                int96_overflow,
             );
         }
 
@@ -0,0 +1,401 @@
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
+use parquet_format_async_temp::{
+    ColumnCryptoMetaData, ColumnMetaData, EncryptionAlgorithm, FileCryptoMetaData, PageHeader,
+};
+
+use crate::error::{ArrowError, Result};
+use crate::io::parquet::encryption::{
+    footer_aad, module_aad, FileDecryptionProperties, ModuleCipher, ModuleType,
+};
+
+use super::row_group::{has_dictionary, ChunkDecryptor};
+use super::{ColumnChunkMetaData, FileMetaData, ENCRYPTED_MAGIC};
+
+/// Reads the metadata of a parquet file that may be encrypted, decrypting its footer and the
+/// metadata of its columns with the keys of `properties`. Returns the metadata and the
+/// [`FileDecryptor`] of the column chunks of the file.
+///
+/// Files that are not encrypted are also read, in which case the decryptor returns their
+/// column chunks as is.
+/// # Error
+/// Errors iff the metadata can't be read, the key of the footer (of files with an encrypted
+/// footer) or of any encrypted column can't be declared nor retrieved, the file requires an AAD
+/// prefix that `properties` does not have, or the footer or the metadata of a column can't be
+/// authenticated (e.g. because a key is wrong or the file was modified).
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    properties: &FileDecryptionProperties,
+) -> Result<(FileMetaData, FileDecryptor)> {
+    let file_size = reader.seek(SeekFrom::End(0))?;
+    if file_size < 12 {
+        return Err(ArrowError::ExternalFormat(
+            "A parquet file must contain a header and a footer with at least 12 bytes".to_string(),
+        ));
+    }
+    let mut end = [0; 8];
+    reader.seek(SeekFrom::End(-8))?;
+    reader.read_exact(&mut end)?;
+    let footer_length = u32::from_le_bytes(end[..4].try_into().unwrap()) as u64;
+    if footer_length + 12 > file_size {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The footer of the parquet file has {} bytes but the file only has {}",
+            footer_length, file_size
+        )));
+    }
+    let mut footer = vec![0; footer_length as usize];
+    reader.seek(SeekFrom::End(-8 - footer_length as i64))?;
+    reader.read_exact(&mut footer)?;
+
+    let (mut metadata, crypto) = match &end[4..] {
+        magic if magic == ENCRYPTED_MAGIC => decrypt_footer(&footer, properties)?,
+        b"PAR1" => read_plaintext_footer(&footer, properties)?,
+        _ => {
+            return Err(ArrowError::ExternalFormat(
+                "The file is not a parquet file: it does not end with its magic".to_string(),
+            ))
+        }
+    };
+
+    let columns = match crypto {
+        Some(crypto) => decrypt_columns(&mut metadata, &crypto, properties)?,
+        None => HashMap::new(),
+    };
+    let metadata = FileMetaData::try_from_thrift(metadata)?;
+    let decryptor = FileDecryptor {
+        columns: Arc::new(columns),
+    };
+    Ok((metadata, decryptor))
+}
+
+// how the modules of a file are encrypted
+struct FileCrypto {
+    file_aad: Vec<u8>,
+    // whether pages are encrypted with AES-CTR (`AES_GCM_CTR_V1`)
+    ctr: bool,
+    footer_cipher: Option<Arc<ModuleCipher>>,
+}
+
+fn file_crypto(
+    algorithm: &EncryptionAlgorithm,
+    properties: &FileDecryptionProperties,
+) -> Result<(Vec<u8>, bool)> {
+    let (aad_prefix, aad_file_unique, supply_aad_prefix, ctr) = match algorithm {
+        EncryptionAlgorithm::AESGCMV1(algorithm) => (
+            &algorithm.aad_prefix,
+            &algorithm.aad_file_unique,
+            algorithm.supply_aad_prefix,
+            false,
+        ),
+        EncryptionAlgorithm::AESGCMCTRV1(algorithm) => (
+            &algorithm.aad_prefix,
+            &algorithm.aad_file_unique,
+            algorithm.supply_aad_prefix,
+            true,
+        ),
+    };
+
+    let mut file_aad = match (aad_prefix, &properties.aad_prefix) {
+        (Some(stored), Some(supplied)) if stored != supplied => {
+            return Err(ArrowError::InvalidArgumentError(
+                "The AAD prefix differs from the one stored in the parquet file".to_string(),
+            ))
+        }
+        (Some(prefix), _) | (None, Some(prefix)) => prefix.clone(),
+        (None, None) if supply_aad_prefix == Some(true) => {
+            return Err(ArrowError::InvalidArgumentError(
+                "The parquet file requires the AAD prefix with which it was written".to_string(),
+            ))
+        }
+        (None, None) => vec![],
+    };
+    file_aad.extend_from_slice(aad_file_unique.as_deref().unwrap_or_default());
+    Ok((file_aad, ctr))
+}
+
+fn read_thrift_error(error: parquet_format_async_temp::thrift::Error) -> ArrowError {
+    ArrowError::from_external_error(error)
+}
+
+// reads the footer of a file with an encrypted footer: its crypto metadata followed by the
+// encrypted metadata of the file
+fn decrypt_footer(
+    footer: &[u8],
+    properties: &FileDecryptionProperties,
+) -> Result<(parquet_format_async_temp::FileMetaData, Option<FileCrypto>)> {
+    let mut remaining = footer;
+    let crypto_metadata =
+        FileCryptoMetaData::read_from_in_protocol(&mut TCompactInputProtocol::new(&mut remaining))
+            .map_err(read_thrift_error)?;
+    let (file_aad, ctr) = file_crypto(&crypto_metadata.encryption_algorithm, properties)?;
+
+    let key = properties
+        .key(
+            properties.footer_key.as_ref(),
+            crypto_metadata.key_metadata.as_deref(),
+        )?
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "The footer of the parquet file is encrypted but its key was neither declared nor retrieved"
+                    .to_string(),
+            )
+        })?;
+    let footer_cipher = ModuleCipher::try_new(&key)?;
+    let (footer, _) = footer_cipher.decrypt(remaining, &footer_aad(&file_aad))?;
+    let metadata = parquet_format_async_temp::FileMetaData::read_from_in_protocol(
+        &mut TCompactInputProtocol::new(footer.as_slice()),
+    )
+    .map_err(read_thrift_error)?;
+
+    let crypto = FileCrypto {
+        file_aad,
+        ctr,
+        footer_cipher: Some(Arc::new(footer_cipher)),
+    };
+    Ok((metadata, Some(crypto)))
+}
+
+// reads the footer of a file with a plaintext footer, which is signed when the file is
+// encrypted. The signature is verified when the key of the footer is available.
+fn read_plaintext_footer(
+    footer: &[u8],
+    properties: &FileDecryptionProperties,
+) -> Result<(parquet_format_async_temp::FileMetaData, Option<FileCrypto>)> {
+    let mut signature = footer;
+    let metadata = parquet_format_async_temp::FileMetaData::read_from_in_protocol(
+        &mut TCompactInputProtocol::new(&mut signature),
+    )
+    .map_err(read_thrift_error)?;
+    let algorithm = match &metadata.encryption_algorithm {
+        Some(algorithm) => algorithm,
+        None => return Ok((metadata, None)),
+    };
+    let (file_aad, ctr) = file_crypto(algorithm, properties)?;
+
+    let key = properties.key(
+        properties.footer_key.as_ref(),
+        metadata.footer_signing_key_metadata.as_deref(),
+    )?;
+    let footer_cipher = key.map(|key| ModuleCipher::try_new(&key)).transpose()?;
+    if let Some(cipher) = &footer_cipher {
+        let signed = &footer[..footer.len() - signature.len()];
+        cipher.verify(signed, &footer_aad(&file_aad), signature)?;
+    }
+
+    let crypto = FileCrypto {
+        file_aad,
+        ctr,
+        footer_cipher: footer_cipher.map(Arc::new),
+    };
+    Ok((metadata, Some(crypto)))
+}
+
+// decrypts the metadata of the encrypted columns of `metadata`, returning their decryptors by
+// the start of their column chunks
+fn decrypt_columns(
+    metadata: &mut parquet_format_async_temp::FileMetaData,
+    crypto: &FileCrypto,
+    properties: &FileDecryptionProperties,
+) -> Result<HashMap<u64, ColumnDecryptor>> {
+    let mut column_ciphers = HashMap::<String, Arc<ModuleCipher>>::new();
+    let mut columns = HashMap::new();
+    for (index, row_group) in metadata.row_groups.iter_mut().enumerate() {
+        let row_group_ordinal = row_group
+            .ordinal
+            .map(|ordinal| ordinal as usize)
+            .unwrap_or(index);
+        for (column_ordinal, column) in row_group.columns.iter_mut().enumerate() {
+            let cipher = match &column.crypto_metadata {
+                None => continue,
+                Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)) => {
+                    crypto.footer_cipher.clone().ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(
+                            "A column of the parquet file is encrypted with the footer key, which was neither declared nor retrieved"
+                                .to_string(),
+                        )
+                    })?
+                }
+                Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(column_key)) => {
+                    let path = column_key.path_in_schema.join(".");
+                    match column_ciphers.get(&path) {
+                        Some(cipher) => cipher.clone(),
+                        None => {
+                            let key = properties
+                                .key(
+                                    properties.column_keys.get(&path),
+                                    column_key.key_metadata.as_deref(),
+                                )?
+                                .ok_or_else(|| {
+                                    ArrowError::InvalidArgumentError(format!(
+                                        "The column \"{}\" of the parquet file is encrypted but its key was neither declared nor retrieved",
+                                        path
+                                    ))
+                                })?;
+                            let cipher = Arc::new(ModuleCipher::try_new(&key)?);
+                            column_ciphers.insert(path, cipher.clone());
+                            cipher
+                        }
+                    }
+                }
+            };
+
+            if let Some(encrypted) = &column.encrypted_column_metadata {
+                let aad = module_aad(
+                    &crypto.file_aad,
+                    ModuleType::ColumnMetaData,
+                    row_group_ordinal,
+                    column_ordinal,
+                    None,
+                )?;
+                let (plaintext, _) = cipher.decrypt(encrypted, &aad)?;
+                let meta_data = ColumnMetaData::read_from_in_protocol(
+                    &mut TCompactInputProtocol::new(plaintext.as_slice()),
+                )
+                .map_err(read_thrift_error)?;
+                column.meta_data = Some(meta_data);
+            }
+            let meta_data = column.meta_data.as_ref().ok_or_else(|| {
+                ArrowError::ExternalFormat(
+                    "A column chunk of the parquet file has no metadata".to_string(),
+                )
+            })?;
+            let start = meta_data
+                .dictionary_page_offset
+                .unwrap_or(meta_data.data_page_offset) as u64;
+            columns.insert(
+                start,
+                ColumnDecryptor {
+                    row_group: row_group_ordinal,
+                    column: column_ordinal,
+                    cipher,
+                    file_aad: crypto.file_aad.clone(),
+                    ctr: crypto.ctr,
+                },
+            );
+        }
+    }
+    Ok(columns)
+}
+
+#[derive(Clone)]
+struct ColumnDecryptor {
+    row_group: usize,
+    column: usize,
+    cipher: Arc<ModuleCipher>,
+    file_aad: Vec<u8>,
+    ctr: bool,
+}
+
+impl ColumnDecryptor {
+    fn decrypt(&self, column: &ColumnChunkMetaData, chunk: &[u8]) -> Result<Vec<u8>> {
+        let mut result = Vec::with_capacity(chunk.len());
+        let mut remaining = chunk;
+        let mut dictionary = column.dictionary_page_offset().is_some() || has_dictionary(column);
+        let mut page_ordinal = 0;
+        while !remaining.is_empty() {
+            let (header_type, page_type, page) = if dictionary {
+                (
+                    ModuleType::DictionaryPageHeader,
+                    ModuleType::DictionaryPage,
+                    None,
+                )
+            } else {
+                (
+                    ModuleType::DataPageHeader,
+                    ModuleType::DataPage,
+                    Some(page_ordinal),
+                )
+            };
+            let aad = |module_type| {
+                module_aad(
+                    &self.file_aad,
+                    module_type,
+                    self.row_group,
+                    self.column,
+                    page,
+                )
+            };
+
+            let (header, data) = self.cipher.decrypt(remaining, &aad(header_type)?)?;
+            let mut header = PageHeader::read_from_in_protocol(&mut TCompactInputProtocol::new(
+                header.as_slice(),
+            ))
+            .map_err(read_thrift_error)?;
+            let (page, data) = if self.ctr {
+                self.cipher.decrypt_ctr(data)?
+            } else {
+                self.cipher.decrypt(data, &aad(page_type)?)?
+            };
+            remaining = data;
+
+            header.compressed_page_size = page.len() as i32;
+            let mut protocol = TCompactOutputProtocol::new(&mut result);
+            header
+                .write_to_out_protocol(&mut protocol)
+                .and_then(|_| protocol.flush())
+                .map_err(ArrowError::from_external_error)?;
+            result.extend_from_slice(&page);
+
+            if dictionary {
+                dictionary = false;
+            } else {
+                page_ordinal += 1;
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Decrypts the column chunks of a file read by [`read_metadata_with_decryption`].
+///
+/// [`FileReader::try_new_with_decryption`](super::FileReader::try_new_with_decryption) and
+/// [`RowGroupReader::set_decryptor`](super::RowGroupReader::set_decryptor) decrypt the column
+/// chunks they read; column chunks read by other APIs (e.g. [`read_columns`](super::read_columns))
+/// must be decrypted via [`FileDecryptor::decrypt_column_chunk`] before they are deserialized.
+#[derive(Clone)]
+pub struct FileDecryptor {
+    columns: Arc<HashMap<u64, ColumnDecryptor>>,
+}
+
+impl std::fmt::Debug for FileDecryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDecryptor")
+            .field("encrypted_columns", &self.columns.len())
+            .finish()
+    }
+}
+
+impl FileDecryptor {
+    /// Returns the column chunk `chunk` of `column` (e.g. as read by
+    /// [`read_columns`](super::read_columns)) decrypted, so that its pages can be read and
+    /// deserialized. Column chunks of columns that are not encrypted are returned as is.
+    /// # Error
+    /// Errors iff the column chunk is encrypted but `column` is not a column of the file of this
+    /// decryptor, or any of its pages can't be authenticated.
+    pub fn decrypt_column_chunk(
+        &self,
+        column: &ColumnChunkMetaData,
+        chunk: Vec<u8>,
+    ) -> Result<Vec<u8>> {
+        match self.columns.get(&column.byte_range().0) {
+            Some(decryptor) => decryptor.decrypt(column, &chunk),
+            None if column.column_chunk().crypto_metadata.is_some() => {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "The encrypted column \"{}\" is not a column of the file of this decryptor",
+                    column.descriptor().path_in_schema.join(".")
+                )))
+            }
+            None => Ok(chunk),
+        }
+    }
+
+    pub(super) fn to_chunk_decryptor(&self) -> ChunkDecryptor {
+        let decryptor = self.clone();
+        Arc::new(move |column, chunk| decryptor.decrypt_column_chunk(column, chunk))
+    }
+}
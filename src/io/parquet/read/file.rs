@@ -4,13 +4,23 @@ use std::sync::Arc;
 use crate::array::Array;
 use crate::chunk::Chunk;
 use crate::datatypes::Schema;
-use crate::io::parquet::read::read_columns_many;
 use crate::{
     datatypes::Field,
     error::{ArrowError, Result},
 };
 
-use super::{infer_schema, read_metadata, FileMetaData, RowGroupDeserializer, RowGroupMetaData};
+#[cfg(feature = "io_parquet_encryption")]
+use super::encryption::{read_metadata_with_decryption, FileDecryptor};
+#[cfg(feature = "io_parquet_encryption")]
+use crate::io::parquet::encryption::FileDecryptionProperties;
+
+use super::page_filter::read_columns_many_pruned;
+use super::row_filter::read_columns_many_filtered;
+use super::row_group::{_read_columns_many, ChunkDecryptor};
+use super::{
+    infer_schema, read_metadata, FileMetaData, Int96Overflow, PageIndexFilter, RowFilter,
+    RowGroupDeserializer, RowGroupMetaData,
+};
 
 type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool + Send + Sync>;
 
@@ -24,7 +34,6 @@ type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool + Send + Sync>;
 pub struct FileReader<R: Read + Seek> {
     row_groups: RowGroupReader<R>,
     metadata: FileMetaData,
-    remaining_rows: usize,
     current_row_group: Option<RowGroupDeserializer>,
 }
 
@@ -45,7 +54,56 @@ impl<R: Read + Seek> FileReader<R> {
         groups_filter: Option<GroupFilter>,
     ) -> Result<Self> {
         let metadata = read_metadata(&mut reader)?;
+        Self::try_from_metadata(
+            reader,
+            metadata,
+            projection,
+            chunk_size,
+            limit,
+            groups_filter,
+        )
+    }
 
+    /// Creates a new [`FileReader`] of a file that may be encrypted, by reading and decrypting
+    /// the metadata from `reader` with the keys of `properties` (see
+    /// [`read_metadata_with_decryption`]). The column chunks are decrypted once read.
+    ///
+    /// # Error
+    /// This function errors iff:
+    /// * reading or decrypting the metadata from the reader fails
+    /// * it is not possible to derive an arrow schema from the parquet file
+    /// * the projection contains columns that do not exist
+    #[cfg(feature = "io_parquet_encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_encryption")))]
+    pub fn try_new_with_decryption(
+        mut reader: R,
+        properties: &FileDecryptionProperties,
+        projection: Option<&[usize]>,
+        chunk_size: Option<usize>,
+        limit: Option<usize>,
+        groups_filter: Option<GroupFilter>,
+    ) -> Result<Self> {
+        let (metadata, decryptor) = read_metadata_with_decryption(&mut reader, properties)?;
+        let mut file_reader = Self::try_from_metadata(
+            reader,
+            metadata,
+            projection,
+            chunk_size,
+            limit,
+            groups_filter,
+        )?;
+        file_reader.row_groups.set_decryptor(decryptor);
+        Ok(file_reader)
+    }
+
+    fn try_from_metadata(
+        reader: R,
+        metadata: FileMetaData,
+        projection: Option<&[usize]>,
+        chunk_size: Option<usize>,
+        limit: Option<usize>,
+        groups_filter: Option<GroupFilter>,
+    ) -> Result<Self> {
         let schema = infer_schema(&metadata)?;
 
         let schema_metadata = schema.metadata;
@@ -92,7 +150,6 @@ impl<R: Read + Seek> FileReader<R> {
         Ok(Self {
             row_groups,
             metadata,
-            remaining_rows: limit.unwrap_or(usize::MAX),
             current_row_group: None,
         })
     }
@@ -112,16 +169,28 @@ impl<R: Read + Seek> FileReader<R> {
         self.row_groups.set_groups_filter(groups_filter);
     }
 
-    fn next_row_group(&mut self) -> Result<Option<RowGroupDeserializer>> {
-        let result = self.row_groups.next().transpose()?;
+    /// Sets the filter of the rows of every row group, so that only its rows are read
+    pub fn set_row_filter(&mut self, row_filter: RowFilter) {
+        self.row_groups.set_row_filter(row_filter);
+    }
 
-        self.remaining_rows = self.remaining_rows.saturating_sub(
-            result
-                .as_ref()
-                .map(|x| x.num_rows())
-                .unwrap_or(self.remaining_rows),
-        );
-        Ok(result)
+    /// Sets the filter of the pages of every row group, so that only the rows of its pages
+    /// are read. It cannot be used together with a row filter.
+    pub fn set_page_filter(&mut self, page_filter: PageIndexFilter) {
+        self.row_groups.set_page_filter(page_filter);
+    }
+
+    /// Sets how `INT96` timestamps out of the range of their unit are read (by default, they error).
+    ///
+    /// The unit is the one of the inferred schema; use [`RowGroupReader`] with a schema inferred
+    /// via [`infer_schema_with_options`](super::infer_schema_with_options) to read them in another.
+    pub fn set_int96_overflow(&mut self, int96_overflow: Int96Overflow) {
+        self.row_groups.set_int96_overflow(int96_overflow);
+    }
+
+    fn next_row_group(&mut self) -> Result<Option<RowGroupDeserializer>> {
+        // the limit is applied by the row groups
+        self.row_groups.next().transpose()
     }
 }
 
@@ -129,11 +198,6 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
     type Item = Result<Chunk<Arc<dyn Array>>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining_rows == 0 {
-            // reached the limit
-            return None;
-        }
-
         if let Some(row_group) = &mut self.current_row_group {
             match row_group.next() {
                 // no more chunks in the current row group => try a new one
@@ -176,6 +240,10 @@ pub struct RowGroupReader<R: Read + Seek> {
     reader: R,
     schema: Schema,
     groups_filter: Option<GroupFilter>,
+    row_filter: Option<RowFilter>,
+    page_filter: Option<PageIndexFilter>,
+    int96_overflow: Int96Overflow,
+    decryptor: Option<ChunkDecryptor>,
     row_groups: Vec<RowGroupMetaData>,
     chunk_size: Option<usize>,
     remaining_rows: usize,
@@ -196,6 +264,10 @@ impl<R: Read + Seek> RowGroupReader<R> {
             reader,
             schema,
             groups_filter,
+            row_filter: None,
+            page_filter: None,
+            int96_overflow: Int96Overflow::default(),
+            decryptor: None,
             row_groups,
             chunk_size,
             remaining_rows: limit.unwrap_or(usize::MAX),
@@ -208,6 +280,31 @@ impl<R: Read + Seek> RowGroupReader<R> {
         self.groups_filter = Some(groups_filter);
     }
 
+    /// Sets the filter of the rows of every row group, so that only its rows are read
+    pub fn set_row_filter(&mut self, row_filter: RowFilter) {
+        self.row_filter = Some(row_filter);
+    }
+
+    /// Sets the filter of the pages of every row group, so that only the rows of its pages
+    /// are read. It cannot be used together with a row filter.
+    pub fn set_page_filter(&mut self, page_filter: PageIndexFilter) {
+        self.page_filter = Some(page_filter);
+    }
+
+    /// Sets how `INT96` timestamps out of the range of the unit of their field are read
+    /// (by default, they error).
+    pub fn set_int96_overflow(&mut self, int96_overflow: Int96Overflow) {
+        self.int96_overflow = int96_overflow;
+    }
+
+    /// Sets the decryptor of the column chunks of encrypted files, whose `row_groups` must have
+    /// been read by [`read_metadata_with_decryption`].
+    #[cfg(feature = "io_parquet_encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_encryption")))]
+    pub fn set_decryptor(&mut self, decryptor: FileDecryptor) {
+        self.decryptor = Some(decryptor.to_chunk_decryptor());
+    }
+
     #[inline]
     fn _next(&mut self) -> Result<Option<RowGroupDeserializer>> {
         if self.schema.fields.is_empty() {
@@ -232,21 +329,48 @@ impl<R: Read + Seek> RowGroupReader<R> {
         }
         self.current_group += 1;
 
-        let column_chunks = read_columns_many(
-            &mut self.reader,
-            row_group,
-            self.schema.fields.clone(),
-            self.chunk_size,
-        )?;
+        if self.row_filter.is_some() && self.page_filter.is_some() {
+            return Err(ArrowError::InvalidArgumentError(
+                "A row filter and a page filter cannot be used together".to_string(),
+            ));
+        }
 
-        let result = RowGroupDeserializer::new(
-            column_chunks,
-            row_group.num_rows() as usize,
-            Some(self.remaining_rows),
-        );
-        self.remaining_rows = self
-            .remaining_rows
-            .saturating_sub(row_group.num_rows() as usize);
+        let (num_rows, column_chunks) = if let Some(page_filter) = self.page_filter.as_ref() {
+            read_columns_many_pruned(
+                &mut self.reader,
+                row_group,
+                &self.schema.fields,
+                self.chunk_size,
+                Some(self.remaining_rows),
+                page_filter,
+                self.int96_overflow,
+                self.decryptor.as_ref(),
+            )?
+        } else if let Some(row_filter) = self.row_filter.as_ref() {
+            read_columns_many_filtered(
+                &mut self.reader,
+                row_group,
+                &self.schema.fields,
+                self.chunk_size,
+                row_filter,
+                self.int96_overflow,
+                self.decryptor.as_ref(),
+            )?
+        } else {
+            let column_chunks = _read_columns_many(
+                &mut self.reader,
+                row_group,
+                self.schema.fields.clone(),
+                self.chunk_size,
+                Some(self.remaining_rows),
+                self.int96_overflow,
+                self.decryptor.as_ref(),
+            )?;
+            (row_group.num_rows(), column_chunks)
+        };
+
+        let result = RowGroupDeserializer::new(column_chunks, num_rows, Some(self.remaining_rows));
+        self.remaining_rows = self.remaining_rows.saturating_sub(num_rows);
         Ok(Some(result))
     }
 }
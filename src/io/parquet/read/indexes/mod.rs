@@ -14,7 +14,7 @@ use std::io::{Read, Seek};
 
 use crate::datatypes::Field;
 use crate::{
-    array::{Array, UInt64Array},
+    array::{ord::build_compare, Array, UInt64Array},
     datatypes::DataType,
     error::ArrowError,
 };
@@ -121,12 +121,14 @@ fn populate_dt(data_type: &DataType, container: &mut Vec<DataType>) {
 /// # Implementation
 /// This function is IO-bounded and calls `reader.read_exact` exactly once.
 /// # Error
-/// Errors iff the indexes can't be read or their deserialization to arrow is incorrect (e.g. invalid utf-8)
+/// Errors iff the indexes can't be read (including when a column is encrypted) or their
+/// deserialization to arrow is incorrect (e.g. invalid utf-8)
 pub fn read_columns_indexes<R: Read + Seek>(
     reader: &mut R,
     chunks: &[ColumnChunkMetaData],
     fields: &[Field],
 ) -> Result<Vec<ColumnIndex>, ArrowError> {
+    super::check_not_encrypted(chunks, "column indexes")?;
     let indexes = _read_columns_indexes(reader, chunks)?;
 
     // map arrow fields to the corresponding columns in parquet taking into account
@@ -139,3 +141,45 @@ pub fn read_columns_indexes<R: Read + Seek>(
 
     deserialize(&indexes, data_types)
 }
+
+/// Returns whether each page of `index` may contain values within `[lower, upper]`, where a
+/// missing bound is unbounded. Pages whose values are all null (i.e. without a minimum
+/// and maximum) never do.
+///
+/// The result can be mapped to the rows to read via [`compute_rows`](super::compute_rows),
+/// so that the pages of a row group that can't contain any value in the range are never
+/// read nor decompressed (see [`read_columns_many_indexed`](super::read_columns_many_indexed)).
+/// # Error
+/// Errors iff the bounds are not arrays of a single item of the data type of `index`, or
+/// the data type has no natural order.
+pub fn select_pages_in_range(
+    index: &ColumnIndex,
+    lower: Option<&dyn Array>,
+    upper: Option<&dyn Array>,
+) -> Result<Vec<bool>, ArrowError> {
+    let mut selected = (0..index.min.len())
+        .map(|page| index.min.is_valid(page) && index.max.is_valid(page))
+        .collect::<Vec<_>>();
+
+    for (bound, values, is_outside) in [
+        (lower, index.max.as_ref(), std::cmp::Ordering::Less),
+        (upper, index.min.as_ref(), std::cmp::Ordering::Greater),
+    ] {
+        let bound = match bound {
+            Some(bound) => bound,
+            None => continue,
+        };
+        if bound.len() != 1 || bound.is_null(0) {
+            return Err(ArrowError::InvalidArgumentError(
+                "The bounds to select pages must have a single non-null value".to_string(),
+            ));
+        }
+        let comparator = build_compare(values, bound)?;
+        selected
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, is_selected)| **is_selected)
+            .for_each(|(page, is_selected)| *is_selected = comparator(page, 0) != is_outside);
+    }
+    Ok(selected)
+}
@@ -1,12 +1,12 @@
 use parquet2::indexes::PageIndex;
 use parquet2::schema::types::{PrimitiveLogicalType, PrimitiveType, TimeUnit as ParquetTimeUnit};
-use parquet2::types::int96_to_i64_ns;
 
 use crate::array::{Array, MutablePrimitiveArray, PrimitiveArray};
 use crate::datatypes::{DataType, TimeUnit};
 use crate::trusted_len::TrustedLen;
 use crate::types::NativeType;
 
+use super::super::deserialize::int96_to_i128_ns;
 use super::ColumnIndex;
 
 #[inline]
@@ -129,10 +129,17 @@ fn deserialize_int96<I: TrustedLen<Item = Option<[u32; 3]>>>(
     iter: I,
     data_type: DataType,
 ) -> Box<dyn Array> {
-    Box::new(
-        PrimitiveArray::<i64>::from_trusted_len_iter(iter.map(|x| x.map(int96_to_i64_ns)))
-            .to(data_type),
-    )
+    let factor = match data_type.to_logical_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => 1_000_000_000,
+        DataType::Timestamp(TimeUnit::Millisecond, _) => 1_000_000,
+        DataType::Timestamp(TimeUnit::Microsecond, _) => 1_000,
+        _ => 1,
+    };
+    // the bounds of a page saturate to the range of the unit
+    let iter = iter.map(|x| {
+        x.map(|x| (int96_to_i128_ns(x) / factor).clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    });
+    Box::new(PrimitiveArray::<i64>::from_trusted_len_iter(iter).to(data_type))
 }
 
 #[inline]
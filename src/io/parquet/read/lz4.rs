@@ -0,0 +1,137 @@
+//! Support for column chunks compressed with parquet's legacy (deprecated) `LZ4` codec, which
+//! `parquet2` does not decompress.
+//!
+//! The codec never specified a framing: parquet-mr (and parquet-cpp up to 4.0) wrote Hadoop's
+//! `Lz4Codec` framing while other writers wrote a raw LZ4 block (the framing of `LZ4_RAW`).
+//! Like parquet-cpp, pages are decompressed with the Hadoop framing and then, when it fails,
+//! as a raw LZ4 block.
+use std::borrow::Cow;
+use std::io::Cursor;
+
+use parquet2::compression::Compression;
+use parquet2::metadata::ColumnChunkMetaData;
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
+use parquet_format_async_temp::{CompressionCodec, PageHeader, PageType};
+
+use crate::error::{ArrowError, Result};
+
+// decompresses `input` in the framing of Hadoop's `Lz4Codec`, a sequence of blocks of
+// `[decompressed length: u32 BE][compressed length: u32 BE][lz4 block]`. Returns whether
+// `input` was of this framing and decompressed into exactly `output`.
+fn decompress_hadoop(mut input: &[u8], mut output: &mut [u8]) -> bool {
+    while input.len() >= 8 {
+        let decompressed_length = u32::from_be_bytes(input[..4].try_into().unwrap()) as usize;
+        let compressed_length = u32::from_be_bytes(input[4..8].try_into().unwrap()) as usize;
+        input = &input[8..];
+        if compressed_length > input.len() || decompressed_length > output.len() {
+            return false;
+        }
+        let (block, remaining) = input.split_at(compressed_length);
+        let (buffer, remaining_output) =
+            std::mem::take(&mut output).split_at_mut(decompressed_length);
+        match lz4::block::decompress_to_buffer(block, Some(decompressed_length as i32), buffer) {
+            Ok(length) if length == decompressed_length => {}
+            _ => return false,
+        }
+        input = remaining;
+        output = remaining_output;
+    }
+    input.is_empty() && output.is_empty()
+}
+
+fn decompress(input: &[u8], output: &mut [u8]) -> Result<()> {
+    if decompress_hadoop(input, output) {
+        return Ok(());
+    }
+    let length = lz4::block::decompress_to_buffer(input, Some(output.len() as i32), output)?;
+    if length != output.len() {
+        return Err(ArrowError::OutOfSpec(format!(
+            "A page compressed with LZ4 decompressed to {} bytes but its header declares {}",
+            length,
+            output.len()
+        )));
+    }
+    Ok(())
+}
+
+// decompresses the page of `header` whose (compressed) data is `data` into `page`
+fn decompress_page(header: &PageHeader, data: &[u8], page: &mut [u8]) -> Result<()> {
+    if header.type_ == PageType::DATA_PAGE_V2 {
+        // the levels of v2 pages are not compressed
+        let v2 = header.data_page_header_v2.as_ref().ok_or_else(|| {
+            ArrowError::OutOfSpec("A data page v2 must have a data page v2 header".to_string())
+        })?;
+        let levels = (v2.definition_levels_byte_length + v2.repetition_levels_byte_length) as usize;
+        if levels > data.len() || levels > page.len() {
+            return Err(ArrowError::OutOfSpec(
+                "The levels of a data page v2 are larger than the page".to_string(),
+            ));
+        }
+        let (data_levels, data) = data.split_at(levels);
+        let (page_levels, page) = page.split_at_mut(levels);
+        page_levels.copy_from_slice(data_levels);
+        if !v2.is_compressed.unwrap_or(true) {
+            if data.len() != page.len() {
+                return Err(ArrowError::OutOfSpec(
+                    "An uncompressed data page v2 has different compressed and uncompressed sizes"
+                        .to_string(),
+                ));
+            }
+            page.copy_from_slice(data);
+            return Ok(());
+        }
+        decompress(data, page)
+    } else {
+        decompress(data, page)
+    }
+}
+
+/// Returns `column` and its `chunk` with all pages decompressed when `column` is compressed
+/// with the legacy `LZ4` codec, and `column` and `chunk` unchanged otherwise.
+pub(super) fn decompress_legacy(
+    column: &ColumnChunkMetaData,
+    chunk: Vec<u8>,
+) -> Result<(Cow<'_, ColumnChunkMetaData>, Vec<u8>)> {
+    if column.compression() != Compression::Lz4 {
+        return Ok((Cow::Borrowed(column), chunk));
+    }
+
+    let mut reader = Cursor::new(chunk.as_slice());
+    let mut result = Vec::with_capacity(column.uncompressed_size().max(0) as usize);
+    while (reader.position() as usize) < chunk.len() {
+        let mut header = {
+            let mut protocol = TCompactInputProtocol::new(&mut reader);
+            PageHeader::read_from_in_protocol(&mut protocol)
+                .map_err(ArrowError::from_external_error)?
+        };
+        let start = reader.position() as usize;
+        let end = start + header.compressed_page_size.max(0) as usize;
+        let data = chunk.get(start..end).ok_or_else(|| {
+            ArrowError::OutOfSpec("A page is larger than its column chunk".to_string())
+        })?;
+        reader.set_position(end as u64);
+
+        let mut page = vec![0; header.uncompressed_page_size.max(0) as usize];
+        decompress_page(&header, data, &mut page)?;
+
+        header.compressed_page_size = header.uncompressed_page_size;
+        // the checksum is of the compressed page
+        header.crc = None;
+        let mut protocol = TCompactOutputProtocol::new(&mut result);
+        header
+            .write_to_out_protocol(&mut protocol)
+            .and_then(|_| protocol.flush())
+            .map_err(ArrowError::from_external_error)?;
+        result.extend_from_slice(&page);
+    }
+
+    let mut column_chunk = column.column_chunk().clone();
+    if let Some(metadata) = column_chunk.meta_data.as_mut() {
+        metadata.codec = CompressionCodec::UNCOMPRESSED;
+        metadata.total_compressed_size = result.len() as i64;
+    }
+    let column = ColumnChunkMetaData::new(column_chunk, column.descriptor().clone());
+    Ok((Cow::Owned(column), result))
+}
@@ -1,24 +1,35 @@
 //! APIs to read from Parquet format.
 #![allow(clippy::type_complexity)]
 
+#[cfg(feature = "io_parquet_bloom_filter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+pub mod bloom_filter;
 mod deserialize;
+#[cfg(feature = "io_parquet_encryption")]
+mod encryption;
 mod file;
 mod indexes;
+#[cfg(feature = "io_parquet_compression")]
+mod lz4;
+mod page_filter;
+pub mod ranged;
+mod row_filter;
 mod row_group;
 pub mod schema;
 pub mod statistics;
 
 use std::{
-    io::{Read, Seek},
+    io::{Read, Seek, SeekFrom},
     sync::Arc,
 };
 
-use futures::{AsyncRead, AsyncSeek};
+use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
 
 // re-exports of parquet2's relevant APIs
 pub use parquet2::{
     error::Error as ParquetError,
     fallible_streaming_iterator,
+    indexes::{compute_rows, select_pages, FilteredPage, Interval},
     metadata::{ColumnChunkMetaData, ColumnDescriptor, RowGroupMetaData},
     page::{CompressedDataPage, DataPage, DataPageHeader},
     read::{
@@ -36,13 +47,23 @@ pub use parquet2::{
     FallibleStreamingIterator,
 };
 
-use crate::{array::Array, error::Result};
+use crate::{
+    array::Array,
+    error::{ArrowError, Result},
+};
 
-pub use deserialize::{column_iter_to_arrays, get_page_iterator};
+pub use deserialize::{column_iter_to_arrays, get_page_iterator, Int96Overflow};
+#[cfg(feature = "io_parquet_encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_encryption")))]
+pub use encryption::{read_metadata_with_decryption, FileDecryptor};
 pub use file::{FileReader, RowGroupReader};
-pub use indexes::{read_columns_indexes, ColumnIndex};
+pub use indexes::{read_columns_indexes, select_pages_in_range, ColumnIndex};
+pub use page_filter::PageIndexFilter;
+pub use row_filter::RowFilter;
 pub use row_group::*;
-pub use schema::{infer_schema, FileMetaData};
+pub use schema::{
+    infer_schema, infer_schema_with_options, project_fields, FileMetaData, SchemaInferenceOptions,
+};
 
 /// Trait describing a [`FallibleStreamingIterator`] of [`DataPage`]
 pub trait DataPages:
@@ -58,14 +79,75 @@ impl<I: FallibleStreamingIterator<Item = DataPage, Error = ParquetError> + Send
 /// Type def for a sharable, boxed dyn [`Iterator`] of arrays
 pub type ArrayIter<'a> = Box<dyn Iterator<Item = Result<Arc<dyn Array>>> + Send + Sync + 'a>;
 
+// the magic of files with an encrypted footer (parquet modular encryption)
+const ENCRYPTED_MAGIC: &[u8; 4] = b"PARE";
+
+fn encryption_error() -> ArrowError {
+    ArrowError::InvalidArgumentError(
+        "The footer of the parquet file is encrypted: read its metadata via `read_metadata_with_decryption` (feature \"io_parquet_encryption\")"
+            .to_string(),
+    )
+}
+
+// errors iff any of `columns` is encrypted, as their `what` (e.g. bloom filters) are encrypted
+// and can't be read
+fn check_not_encrypted<'a, I: IntoIterator<Item = &'a ColumnChunkMetaData>>(
+    columns: I,
+    what: &str,
+) -> Result<()> {
+    match columns
+        .into_iter()
+        .find(|column| column.column_chunk().crypto_metadata.is_some())
+    {
+        Some(column) => Err(ArrowError::NotYetImplemented(format!(
+            "Reading the {} of the encrypted column \"{}\"",
+            what,
+            column.descriptor().path_in_schema.join(".")
+        ))),
+        None => Ok(()),
+    }
+}
+
 /// Reads parquets' metadata syncronously.
+///
+/// The metadata of files encrypted with a plaintext footer is read without being verified, and
+/// only their plaintext columns can be read.
+/// # Error
+/// Errors iff the metadata can't be read, including when the footer is encrypted, whose file
+/// must be read via `read_metadata_with_decryption` (feature `io_parquet_encryption`).
 pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetaData> {
-    Ok(_read_metadata(reader)?)
+    _read_metadata(reader).map_err(|error| {
+        let mut magic = [0; 4];
+        let is_encrypted = reader.seek(SeekFrom::End(-4)).is_ok()
+            && reader.read_exact(&mut magic).is_ok()
+            && &magic == ENCRYPTED_MAGIC;
+        if is_encrypted {
+            encryption_error()
+        } else {
+            error.into()
+        }
+    })
 }
 
 /// Reads parquets' metadata asynchronously.
+/// # Error
+/// Errors iff the metadata can't be read, including when the footer is encrypted, as files with
+/// an encrypted footer can only be read synchronously.
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
     reader: &mut R,
 ) -> Result<FileMetaData> {
-    Ok(_read_metadata_async(reader).await?)
+    match _read_metadata_async(reader).await {
+        Ok(metadata) => Ok(metadata),
+        Err(error) => {
+            let mut magic = [0; 4];
+            let is_encrypted = reader.seek(SeekFrom::End(-4)).await.is_ok()
+                && reader.read_exact(&mut magic).await.is_ok()
+                && &magic == ENCRYPTED_MAGIC;
+            Err(if is_encrypted {
+                encryption_error()
+            } else {
+                error.into()
+            })
+        }
+    }
 }
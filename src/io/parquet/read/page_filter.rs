@@ -0,0 +1,116 @@
+use std::io::{Read, Seek};
+use std::sync::Arc;
+
+use parquet2::indexes::compute_rows;
+use parquet2::read::read_pages_locations;
+
+use crate::array::Array;
+use crate::datatypes::Field;
+use crate::error::{ArrowError, Result};
+
+use super::indexes::{read_columns_indexes, select_pages_in_range};
+use super::row_group::{
+    _read_columns_many, _read_columns_many_indexed, get_field_columns, ChunkDecryptor,
+};
+use super::{ArrayIter, Int96Overflow, RowGroupMetaData};
+
+/// A range of the values of a field that selects the pages of every row group to read, via
+/// the column indexes of the pages (which this crate writes alongside statistics).
+///
+/// Only the rows of the pages whose values may be in the range are read; the pages of the
+/// other rows of every field are neither read nor decompressed. This is not an exact filter:
+/// the rows read may have values outside of the range, and row groups without column and
+/// offset indexes (or encrypted) are read in full.
+#[derive(Debug, Clone)]
+pub struct PageIndexFilter {
+    field: usize,
+    lower: Option<Arc<dyn Array>>,
+    upper: Option<Arc<dyn Array>>,
+}
+
+impl PageIndexFilter {
+    /// Creates a new [`PageIndexFilter`] selecting the pages of the field `field` (the index of
+    /// the field in the fields read) whose values may be within `lower` and `upper`
+    /// (inclusive), arrays with a single non-null value of the field's leaf type.
+    /// The field may be nested (e.g. a list), but must have a single leaf.
+    pub fn new(field: usize, lower: Option<Arc<dyn Array>>, upper: Option<Arc<dyn Array>>) -> Self {
+        Self {
+            field,
+            lower,
+            upper,
+        }
+    }
+
+    /// The index of the field whose column indexes select the pages
+    pub fn field(&self) -> usize {
+        self.field
+    }
+}
+
+/// Reads the rows of `fields` of `row_group` of the pages selected by `page_filter`,
+/// returning the number of rows read and an iterator of arrays of `chunk_size` for every field.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn read_columns_many_pruned<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: &[Field],
+    chunk_size: Option<usize>,
+    limit: Option<usize>,
+    page_filter: &PageIndexFilter,
+    int96_overflow: Int96Overflow,
+    decryptor: Option<&ChunkDecryptor>,
+) -> Result<(usize, Vec<ArrayIter<'static>>)> {
+    let field = fields.get(page_filter.field).ok_or_else(|| {
+        ArrowError::InvalidArgumentError("The field of the page filter does not exist".to_string())
+    })?;
+    let columns = get_field_columns(row_group.columns(), field);
+    if columns.len() != 1 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The field \"{}\" of the page filter must have a single leaf, but it has {}",
+            field.name,
+            columns.len()
+        )));
+    }
+    let column = columns[0].clone();
+
+    // the indexes of encrypted columns are encrypted
+    let has_indexes = row_group.columns().iter().all(|column| {
+        let column = column.column_chunk();
+        column.offset_index_offset.is_some()
+            && column.column_index_offset.is_some()
+            && column.crypto_metadata.is_none()
+    });
+    if !has_indexes {
+        let columns = _read_columns_many(
+            reader,
+            row_group,
+            fields.to_vec(),
+            chunk_size,
+            limit,
+            int96_overflow,
+            decryptor,
+        )?;
+        return Ok((row_group.num_rows(), columns));
+    }
+
+    let columns = [column];
+    let indexes = read_columns_indexes(reader, &columns, std::slice::from_ref(field))?;
+    let selected = select_pages_in_range(
+        &indexes[0],
+        page_filter.lower.as_deref(),
+        page_filter.upper.as_deref(),
+    )?;
+    let locations = read_pages_locations(reader, &columns)?;
+    let rows = compute_rows(&selected, &locations[0], row_group.num_rows())?;
+
+    let num_rows = rows.iter().map(|interval| interval.length).sum();
+    let columns = _read_columns_many_indexed(
+        reader,
+        row_group,
+        fields.to_vec(),
+        chunk_size,
+        &rows,
+        int96_overflow,
+    )?;
+    Ok((num_rows, columns))
+}
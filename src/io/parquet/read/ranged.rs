@@ -0,0 +1,270 @@
+//! APIs to read parquet files from sources read by byte ranges, such as object stores
+//! (e.g. S3), where every request has a high latency.
+//!
+//! Byte ranges close to each other are coalesced into a single request, so that reading the
+//! columns of a row group issues a handful of large requests instead of one per column chunk.
+use std::io::Cursor;
+use std::ops::Range;
+
+use futures::{
+    future::{try_join_all, BoxFuture},
+    stream::{self, BoxStream},
+    StreamExt,
+};
+use parquet2::metadata::ColumnChunkMetaData;
+
+use crate::datatypes::Field;
+use crate::error::{ArrowError, Result};
+
+use super::row_group::{get_field_columns, to_task};
+use super::{
+    read_metadata, to_deserializer, ArrayIter, FileMetaData, RowGroupMetaData, RowGroupTask,
+};
+
+// the number of bytes read from the end of a file to read its metadata in a single request
+const DEFAULT_FOOTER_READ_SIZE: u64 = 64 * 1024;
+// the footer of a parquet file: the length of its metadata and the magic
+const FOOTER_SIZE: u64 = 8;
+
+/// A source of bytes that are read by ranges, such as a file in an object store.
+pub trait RangeRead: Send + Sync {
+    /// Returns the bytes of the source in `range`.
+    fn get_range(&self, range: Range<u64>) -> BoxFuture<'_, std::io::Result<Vec<u8>>>;
+
+    /// Returns the bytes of the source in each of `ranges`, in the order of `ranges`.
+    ///
+    /// By default, this requests every range concurrently via [`RangeRead::get_range`].
+    fn get_ranges<'a>(
+        &'a self,
+        ranges: &'a [Range<u64>],
+    ) -> BoxFuture<'a, std::io::Result<Vec<Vec<u8>>>> {
+        Box::pin(try_join_all(
+            ranges.iter().map(|range| self.get_range(range.clone())),
+        ))
+    }
+}
+
+/// Options to read parquet files from a [`RangeRead`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangedReadOptions {
+    /// The maximum number of bytes between two ranges for them to be read in a single request
+    /// (and the bytes in between discarded).
+    pub max_gap: u64,
+    /// The maximum number of bytes of a request of coalesced ranges. Ranges larger than it are
+    /// still read in a single request.
+    pub max_request_size: u64,
+    /// The number of row groups read ahead of the one being consumed by
+    /// [`read_row_group_tasks_ranged`].
+    pub prefetch: usize,
+}
+
+impl Default for RangedReadOptions {
+    fn default() -> Self {
+        Self {
+            max_gap: 1024 * 1024,
+            max_request_size: 32 * 1024 * 1024,
+            prefetch: 1,
+        }
+    }
+}
+
+// coalesces `ranges` into the (sorted and disjoint) ranges of the requests that read them
+fn coalesce(ranges: &[Range<u64>], options: &RangedReadOptions) -> Vec<Range<u64>> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_unstable_by_key(|range| range.start);
+
+    let mut coalesced: Vec<Range<u64>> = vec![];
+    for range in sorted {
+        match coalesced.last_mut() {
+            // ranges within the last request are always part of it
+            Some(last)
+                if range.end <= last.end
+                    || (range.start <= last.end.saturating_add(options.max_gap)
+                        && range.end - last.start <= options.max_request_size) =>
+            {
+                last.end = last.end.max(range.end)
+            }
+            _ => coalesced.push(range),
+        }
+    }
+    coalesced
+}
+
+/// Returns the bytes of `reader` in each of `ranges`, in the order of `ranges`, reading
+/// ranges close to each other in a single request (see [`RangedReadOptions`]).
+pub async fn get_ranges_coalesced<R: RangeRead + ?Sized>(
+    reader: &R,
+    ranges: &[Range<u64>],
+    options: &RangedReadOptions,
+) -> Result<Vec<Vec<u8>>> {
+    let requests = coalesce(ranges, options);
+    let buffers = reader.get_ranges(&requests).await?;
+    if buffers.len() != requests.len()
+        || buffers
+            .iter()
+            .zip(requests.iter())
+            .any(|(buffer, request)| buffer.len() as u64 != request.end - request.start)
+    {
+        return Err(ArrowError::ExternalFormat(
+            "The ranges returned by the reader differ from the requested ones".to_string(),
+        ));
+    }
+
+    Ok(ranges
+        .iter()
+        .map(|range| {
+            // the coalesced ranges are sorted and disjoint
+            let index = requests.partition_point(|request| request.end < range.end);
+            let request = &requests[index];
+            let start = (range.start - request.start) as usize;
+            let end = (range.end - request.start) as usize;
+            buffers[index][start..end].to_vec()
+        })
+        .collect())
+}
+
+/// Reads the metadata of the parquet file of `file_size` bytes from `reader`, in a single
+/// request when the metadata is smaller than 64KiB and two otherwise.
+/// # Error
+/// Errors iff the metadata can't be read.
+pub async fn read_metadata_ranged<R: RangeRead + ?Sized>(
+    reader: &R,
+    file_size: u64,
+) -> Result<FileMetaData> {
+    if file_size < FOOTER_SIZE {
+        return Err(ArrowError::OutOfSpec(
+            "A parquet file must have at least 8 bytes".to_string(),
+        ));
+    }
+    let start = file_size.saturating_sub(DEFAULT_FOOTER_READ_SIZE);
+    let mut tail = reader.get_range(start..file_size).await?;
+
+    let length = tail.len();
+    if (length as u64) < FOOTER_SIZE {
+        return Err(ArrowError::ExternalFormat(
+            "The range returned by the reader differs from the requested one".to_string(),
+        ));
+    }
+    let metadata_length = u32::from_le_bytes(tail[length - 8..length - 4].try_into().unwrap());
+    let footer_length = metadata_length as u64 + FOOTER_SIZE;
+    if footer_length > tail.len() as u64 && footer_length <= file_size {
+        let mut metadata = reader.get_range(file_size - footer_length..start).await?;
+        metadata.extend_from_slice(&tail);
+        tail = metadata;
+    }
+
+    // the metadata only refers to absolute positions of the file
+    read_metadata(&mut Cursor::new(tail))
+}
+
+// reads the column chunks of every field of `fields` of `row_group` to memory
+async fn read_columns_ranged<'a, R: RangeRead + ?Sized>(
+    reader: &R,
+    row_group: &'a RowGroupMetaData,
+    fields: &[Field],
+    options: &RangedReadOptions,
+) -> Result<Vec<Vec<(&'a ColumnChunkMetaData, Vec<u8>)>>> {
+    let field_columns = fields
+        .iter()
+        .map(|field| get_field_columns(row_group.columns(), field))
+        .collect::<Vec<_>>();
+
+    let ranges = field_columns
+        .iter()
+        .flatten()
+        .map(|meta| {
+            let (start, length) = meta.byte_range();
+            start..start + length
+        })
+        .collect::<Vec<_>>();
+    let mut chunks = get_ranges_coalesced(reader, &ranges, options)
+        .await?
+        .into_iter();
+
+    Ok(field_columns
+        .into_iter()
+        .map(|columns| {
+            columns
+                .into_iter()
+                .map(|meta| (meta, chunks.next().unwrap()))
+                .collect()
+        })
+        .collect())
+}
+
+/// Returns a vector of iterators of [`Array`](crate::array::Array) ([`ArrayIter`])
+/// corresponding to the top level parquet fields whose name matches `fields`'s names, with at
+/// most `limit` rows.
+///
+/// # Implementation
+/// This operation is IO-bounded: it reads the column chunks of all fields of `row_group` to
+/// memory, coalescing their byte ranges into as few requests as `options` allow.
+pub async fn read_columns_many_ranged<'a, R: RangeRead + ?Sized>(
+    reader: &R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    limit: Option<usize>,
+    options: &RangedReadOptions,
+) -> Result<Vec<ArrayIter<'a>>> {
+    let num_rows = limit.unwrap_or(usize::MAX).min(row_group.num_rows());
+    let field_columns = read_columns_ranged(reader, row_group, &fields, options).await?;
+
+    field_columns
+        .into_iter()
+        .zip(fields)
+        .map(|(columns, field)| to_deserializer(columns, field, num_rows, chunk_size))
+        .collect()
+}
+
+/// Reads the columns of `fields` of `row_group` to memory, returning a [`RowGroupTask`] that
+/// deserializes at most `limit` rows.
+///
+/// # Implementation
+/// This operation is IO-bounded: it reads the column chunks of all fields of `row_group` to
+/// memory, coalescing their byte ranges into as few requests as `options` allow.
+pub async fn read_row_group_task_ranged<R: RangeRead + ?Sized>(
+    reader: &R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    limit: Option<usize>,
+    options: &RangedReadOptions,
+) -> Result<RowGroupTask> {
+    let num_rows = limit.unwrap_or(usize::MAX).min(row_group.num_rows());
+    let field_columns = read_columns_ranged(reader, row_group, &fields, options).await?;
+    Ok(to_task(field_columns, fields, num_rows))
+}
+
+/// Returns a [`Stream`](futures::Stream) of the [`RowGroupTask`]s of `fields` of every row
+/// group in `row_groups`, that together deserialize at most `limit` rows.
+///
+/// While a task is consumed, the column chunks of the next `options.prefetch` row groups are
+/// already being read.
+pub fn read_row_group_tasks_ranged<'a, R: RangeRead + ?Sized>(
+    reader: &'a R,
+    row_groups: &'a [RowGroupMetaData],
+    fields: Vec<Field>,
+    limit: Option<usize>,
+    options: RangedReadOptions,
+) -> BoxStream<'a, Result<RowGroupTask>> {
+    let mut remaining = limit.unwrap_or(usize::MAX);
+    let row_groups = row_groups
+        .iter()
+        .map(|row_group| {
+            let limit = remaining.min(row_group.num_rows());
+            remaining -= limit;
+            (row_group, limit)
+        })
+        .filter(|(_, limit)| *limit > 0)
+        .collect::<Vec<_>>();
+
+    stream::iter(row_groups)
+        .map(move |(row_group, limit)| {
+            let fields = fields.clone();
+            async move {
+                read_row_group_task_ranged(reader, row_group, fields, Some(limit), &options).await
+            }
+        })
+        .buffered(options.prefetch + 1)
+        .boxed()
+}
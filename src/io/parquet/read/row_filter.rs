@@ -0,0 +1,180 @@
+use std::io::{Read, Seek};
+use std::sync::Arc;
+
+use crate::array::{new_empty_array, Array, BooleanArray};
+use crate::bitmap::utils::SlicesIterator;
+use crate::chunk::Chunk;
+use crate::compute::filter::filter;
+use crate::datatypes::Field;
+use crate::error::{ArrowError, Result};
+
+use super::row_group::{
+    _read_columns_many, _read_columns_many_indexed, single_array, to_chunks, ChunkDecryptor,
+};
+use super::{ArrayIter, Int96Overflow, Interval, RowGroupMetaData};
+
+type Predicate = Arc<dyn Fn(&Chunk<Arc<dyn Array>>) -> Result<BooleanArray> + Send + Sync>;
+
+/// A predicate over some of the fields of a parquet file that selects the rows to read.
+///
+/// The fields of the predicate are read and deserialized first; the remaining fields are then
+/// only deserialized for the rows for which the predicate is `true` (late materialization).
+/// Pages without any of these rows are not even read nor decompressed when the row group has
+/// offset indexes (which this crate writes alongside statistics) and is not encrypted.
+#[derive(Clone)]
+pub struct RowFilter {
+    projection: Vec<usize>,
+    predicate: Predicate,
+}
+
+impl std::fmt::Debug for RowFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowFilter")
+            .field("projection", &self.projection)
+            .finish()
+    }
+}
+
+impl RowFilter {
+    /// Creates a new [`RowFilter`] whose `predicate` is evaluated on a [`Chunk`] with the
+    /// fields in `projection` (indices of the fields read), in the order of `projection`, of
+    /// every row group. Rows where the predicate is null are not selected.
+    pub fn new(
+        projection: Vec<usize>,
+        predicate: impl Fn(&Chunk<Arc<dyn Array>>) -> Result<BooleanArray> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            projection,
+            predicate: Arc::new(predicate),
+        }
+    }
+
+    /// The indices of the fields the predicate is evaluated on
+    pub fn projection(&self) -> &[usize] {
+        &self.projection
+    }
+}
+
+/// Reads the `fields` of `row_group` that satisfy `row_filter`, returning the number of
+/// selected rows and an iterator of arrays of `chunk_size` for every field.
+pub(super) fn read_columns_many_filtered<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: &[Field],
+    chunk_size: Option<usize>,
+    row_filter: &RowFilter,
+    int96_overflow: Int96Overflow,
+    decryptor: Option<&ChunkDecryptor>,
+) -> Result<(usize, Vec<ArrayIter<'static>>)> {
+    let predicate_fields = row_filter
+        .projection
+        .iter()
+        .map(|index| {
+            fields.get(*index).cloned().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(
+                    "The projection of the row filter contains fields that do not exist"
+                        .to_string(),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let columns = _read_columns_many(
+        reader,
+        row_group,
+        predicate_fields.clone(),
+        None,
+        None,
+        int96_overflow,
+        decryptor,
+    )?;
+    let columns = columns
+        .into_iter()
+        .zip(predicate_fields.iter())
+        .map(|(iter, field)| single_array(iter, &field.data_type))
+        .collect::<Result<Vec<_>>>()?;
+    let chunk = Chunk::try_new(columns)?;
+
+    let mask = (row_filter.predicate)(&chunk)?;
+    if mask.len() != row_group.num_rows() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The predicate of the row filter returned {} rows but the row group has {}",
+            mask.len(),
+            row_group.num_rows()
+        )));
+    }
+    let selection = match mask.validity() {
+        Some(validity) => mask.values() & validity,
+        None => mask.values().clone(),
+    };
+    let num_selected = selection.len() - selection.null_count();
+    let mask = BooleanArray::from_data(mask.data_type().clone(), selection, None);
+
+    // the fields of the predicate were already read
+    let mut arrays = vec![None; fields.len()];
+    for (index, array) in row_filter.projection.iter().zip(chunk.into_arrays()) {
+        arrays[*index] = Some(Arc::from(filter(array.as_ref(), &mask)?));
+    }
+
+    let remaining = (0..fields.len())
+        .filter(|index| arrays[*index].is_none())
+        .collect::<Vec<_>>();
+    if num_selected == 0 {
+        remaining.into_iter().for_each(|index| {
+            arrays[index] = Some(new_empty_array(fields[index].data_type.clone()).into())
+        });
+    } else {
+        // the offset indexes of encrypted columns are encrypted
+        let has_offset_indexes = row_group.columns().iter().all(|column| {
+            let column = column.column_chunk();
+            column.offset_index_offset.is_some() && column.crypto_metadata.is_none()
+        });
+        let (indexed, unindexed): (Vec<_>, Vec<_>) =
+            remaining.into_iter().partition(|_| has_offset_indexes);
+
+        // only the selected rows are deserialized
+        let rows = SlicesIterator::new(mask.values())
+            .map(|(start, length)| Interval::new(start, length))
+            .collect::<Vec<_>>();
+        if !indexed.is_empty() {
+            let indexed_fields = indexed.iter().map(|index| fields[*index].clone()).collect();
+            let columns = _read_columns_many_indexed(
+                reader,
+                row_group,
+                indexed_fields,
+                None,
+                &rows,
+                int96_overflow,
+            )?;
+            for (index, iter) in indexed.into_iter().zip(columns) {
+                arrays[index] = Some(single_array(iter, &fields[index].data_type)?);
+            }
+        }
+
+        // all rows are deserialized and then filtered
+        let unindexed_fields = unindexed
+            .iter()
+            .map(|index| fields[*index].clone())
+            .collect();
+        let columns = _read_columns_many(
+            reader,
+            row_group,
+            unindexed_fields,
+            None,
+            None,
+            int96_overflow,
+            decryptor,
+        )?;
+        for (index, iter) in unindexed.into_iter().zip(columns) {
+            let array = single_array(iter, &fields[index].data_type)?;
+            arrays[index] = Some(filter(array.as_ref(), &mask)?.into());
+        }
+    }
+
+    let chunk_size = chunk_size.unwrap_or(usize::MAX).min(num_selected).max(1);
+    let columns = arrays
+        .into_iter()
+        .map(|array| to_chunks(array.unwrap(), chunk_size))
+        .collect();
+    Ok((num_selected, columns))
+}
@@ -1,5 +1,5 @@
 use std::{
-    io::{Read, Seek},
+    io::{Cursor, Read, Seek, SeekFrom},
     sync::Arc,
 };
 
@@ -8,17 +8,32 @@ use futures::{
     AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt,
 };
 use parquet2::{
+    error::Error as ParquetError,
+    indexes::{select_pages, FilteredPage, Interval, PageLocation},
     metadata::ColumnChunkMetaData,
-    read::{BasicDecompressor, PageReader},
+    page::{CompressedDataPage, DataPage},
+    read::{decompress, read_pages_locations, BasicDecompressor, IndexedPageReader, PageReader},
+    schema::types::PrimitiveType,
+    FallibleStreamingIterator,
 };
 
 use crate::{
-    array::Array, chunk::Chunk, datatypes::Field, error::Result,
-    io::parquet::read::column_iter_to_arrays,
+    array::{new_empty_array, Array, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray},
+    bitmap::MutableBitmap,
+    chunk::Chunk,
+    compute::cast::{cast, CastOptions},
+    compute::filter::filter,
+    datatypes::{DataType, Field, PhysicalType},
+    error::{ArrowError, Result},
 };
 
-use super::ArrayIter;
+use super::deserialize::_column_iter_to_arrays;
 use super::RowGroupMetaData;
+use super::{ArrayIter, DataPages, Int96Overflow};
+
+/// Decrypts the column chunks of encrypted files once they are read
+pub(super) type ChunkDecryptor =
+    Arc<dyn Fn(&ColumnChunkMetaData, Vec<u8>) -> Result<Vec<u8>> + Send + Sync>;
 
 /// An [`Iterator`] of [`Chunk`] that (dynamically) adapts a vector of iterators of [`Array`] into
 /// an iterator of [`Chunk`].
@@ -71,7 +86,7 @@ impl Iterator for RowGroupDeserializer {
             .map(|iter| {
                 let array = iter.next().unwrap()?;
                 Ok(if array.len() > self.remaining_rows {
-                    array.slice(0, array.len() - self.remaining_rows).into()
+                    array.slice(0, self.remaining_rows).into()
                 } else {
                     array
                 })
@@ -89,7 +104,7 @@ impl Iterator for RowGroupDeserializer {
 
 /// Returns all [`ColumnChunkMetaData`] associated to `field_name`.
 /// For non-nested parquet types, this returns a single column
-pub(super) fn get_field_columns<'a>(
+fn get_named_columns<'a>(
     columns: &'a [ColumnChunkMetaData],
     field_name: &str,
 ) -> Vec<&'a ColumnChunkMetaData> {
@@ -99,6 +114,41 @@ pub(super) fn get_field_columns<'a>(
         .collect()
 }
 
+// pushes the path of every column group of `field` to `paths`: the fields of a struct are
+// groups of the same name, while every other field stores all of its columns under its path
+fn field_paths<'a>(field: &'a Field, path: &mut Vec<&'a str>, paths: &mut Vec<Vec<&'a str>>) {
+    path.push(&field.name);
+    match field.data_type.to_logical_type() {
+        DataType::Struct(fields) => fields
+            .iter()
+            .for_each(|field| field_paths(field, path, paths)),
+        _ => paths.push(path.clone()),
+    }
+    path.pop();
+}
+
+/// Returns all [`ColumnChunkMetaData`] associated to `field`, where the fields of its
+/// (nested) structs may be a subset of the fields of the file (see
+/// [`project_fields`](super::project_fields)).
+/// For non-nested parquet types, this returns a single column
+pub(super) fn get_field_columns<'a>(
+    columns: &'a [ColumnChunkMetaData],
+    field: &Field,
+) -> Vec<&'a ColumnChunkMetaData> {
+    let mut paths = vec![];
+    field_paths(field, &mut vec![], &mut paths);
+    columns
+        .iter()
+        .filter(|x| {
+            let column_path = &x.descriptor().path_in_schema;
+            paths.iter().any(|path| {
+                path.len() <= column_path.len()
+                    && path.iter().zip(column_path.iter()).all(|(a, b)| a == b)
+            })
+        })
+        .collect()
+}
+
 /// Reads all columns that are part of the parquet field `field_name`
 /// # Implementation
 /// This operation is IO-bounded `O(C)` where C is the number of columns associated to
@@ -108,9 +158,23 @@ pub fn read_columns<'a, R: Read + Seek>(
     columns: &'a [ColumnChunkMetaData],
     field_name: &str,
 ) -> Result<Vec<(&'a ColumnChunkMetaData, Vec<u8>)>> {
-    get_field_columns(columns, field_name)
+    _read_columns(reader, get_named_columns(columns, field_name), None)
+}
+
+fn _read_columns<'a, R: Read + Seek>(
+    reader: &mut R,
+    columns: Vec<&'a ColumnChunkMetaData>,
+    decryptor: Option<&ChunkDecryptor>,
+) -> Result<Vec<(&'a ColumnChunkMetaData, Vec<u8>)>> {
+    columns
         .into_iter()
-        .map(|meta| _read_single_column(reader, meta))
+        .map(|meta| {
+            let (meta, chunk) = _read_single_column(reader, meta)?;
+            match decryptor {
+                Some(decryptor) => Ok((meta, decryptor(meta, chunk)?)),
+                None => Ok((meta, chunk)),
+            }
+        })
         .collect()
 }
 
@@ -122,7 +186,7 @@ where
     R: Read + Seek,
 {
     let (start, len) = meta.byte_range();
-    reader.seek(std::io::SeekFrom::Start(start))?;
+    reader.seek(SeekFrom::Start(start))?;
     let mut chunk = vec![0; len as usize];
     reader.read_exact(&mut chunk)?;
     Ok((meta, chunk))
@@ -138,7 +202,7 @@ where
 {
     let mut reader = factory().await?;
     let (start, len) = meta.byte_range();
-    reader.seek(std::io::SeekFrom::Start(start)).await?;
+    reader.seek(SeekFrom::Start(start)).await?;
     let mut chunk = vec![0; len as usize];
     reader.read_exact(&mut chunk).await?;
     Result::Ok((meta, chunk))
@@ -161,7 +225,19 @@ pub async fn read_columns_async<
     columns: &'a [ColumnChunkMetaData],
     field_name: &str,
 ) -> Result<Vec<(&'a ColumnChunkMetaData, Vec<u8>)>> {
-    let futures = get_field_columns(columns, field_name)
+    _read_columns_async(factory, get_named_columns(columns, field_name)).await
+}
+
+async fn _read_columns_async<
+    'a,
+    'b,
+    R: AsyncRead + AsyncSeek + Send + Unpin,
+    F: Fn() -> BoxFuture<'b, std::io::Result<R>> + Clone,
+>(
+    factory: F,
+    columns: Vec<&'a ColumnChunkMetaData>,
+) -> Result<Vec<(&'a ColumnChunkMetaData, Vec<u8>)>> {
+    let futures = columns
         .into_iter()
         .map(|meta| async { _read_single_column_async(factory.clone(), meta).await });
 
@@ -169,36 +245,292 @@ pub async fn read_columns_async<
 }
 
 /// Converts a vector of columns associated with the parquet field whose name is [`Field`]
-/// to an iterator of [`Array`], [`ArrayIter`] of chunk size `chunk_size`.
+/// to an iterator of [`Array`], [`ArrayIter`] of chunk size `chunk_size` with at most
+/// `num_rows` rows.
+///
+/// Pages are only decompressed and deserialized once the iterator reaches them, so that
+/// a `num_rows` smaller than the number of rows of the columns skips their remaining pages.
 pub fn to_deserializer<'a>(
     columns: Vec<(&ColumnChunkMetaData, Vec<u8>)>,
     field: Field,
     num_rows: usize,
     chunk_size: Option<usize>,
+) -> Result<ArrayIter<'a>> {
+    _to_deserializer(
+        columns,
+        field,
+        num_rows,
+        chunk_size,
+        Int96Overflow::default(),
+    )
+}
+
+fn _to_deserializer<'a>(
+    columns: Vec<(&ColumnChunkMetaData, Vec<u8>)>,
+    field: Field,
+    num_rows: usize,
+    chunk_size: Option<usize>,
+    int96_overflow: Int96Overflow,
 ) -> Result<ArrayIter<'a>> {
     let chunk_size = chunk_size.unwrap_or(usize::MAX).min(num_rows);
 
+    let has_dictionary = columns.iter().all(|(column, _)| has_dictionary(column));
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
         .map(|(column_meta, chunk)| {
-            let pages = PageReader::new(
-                std::io::Cursor::new(chunk),
-                column_meta,
-                Arc::new(|_, _| true),
-                vec![],
-            );
-            (
+            #[cfg(feature = "io_parquet_compression")]
+            let (column, chunk) = super::lz4::decompress_legacy(column_meta, chunk)?;
+            #[cfg(not(feature = "io_parquet_compression"))]
+            let column = column_meta;
+            let pages = PageReader::new(Cursor::new(chunk), &column, Arc::new(|_, _| true), vec![]);
+            Ok((
                 BasicDecompressor::new(pages, vec![]),
                 &column_meta.descriptor().descriptor.primitive_type,
-            )
+            ))
         })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
         .unzip();
 
-    column_iter_to_arrays(columns, types, field, chunk_size)
+    let iter = column_iter_to_arrays(
+        columns,
+        types,
+        field,
+        chunk_size,
+        int96_overflow,
+        has_dictionary,
+    )?;
+
+    // stops (and truncates the last array) once `num_rows` were deserialized
+    Ok(Box::new(iter.scan(num_rows, |remaining, array| {
+        if *remaining == 0 {
+            return None;
+        }
+        Some(array.map(|array| {
+            let length = array.len().min(*remaining);
+            *remaining -= length;
+            if length < array.len() {
+                array.slice(0, length).into()
+            } else {
+                array
+            }
+        }))
+    })))
+}
+
+// whether `column` has a dictionary page, as declared by its encodings
+pub(super) fn has_dictionary(column: &ColumnChunkMetaData) -> bool {
+    use parquet_format_async_temp::Encoding;
+    column.column_encoding().iter().any(|encoding| {
+        *encoding == Encoding::PLAIN_DICTIONARY || *encoding == Encoding::RLE_DICTIONARY
+    })
+}
+
+// Deserializes the `columns` of `field`. A dictionary field whose column chunk has no dictionary
+// page, e.g. because its dictionary exceeded the limits of `ColumnOptions` when written, is
+// deserialized to its values, which are then wrapped in dictionaries.
+fn column_iter_to_arrays<'a, I: 'a + DataPages>(
+    columns: Vec<I>,
+    types: Vec<&PrimitiveType>,
+    field: Field,
+    chunk_size: usize,
+    int96_overflow: Int96Overflow,
+    has_dictionary: bool,
+) -> Result<ArrayIter<'a>> {
+    match field.data_type.to_logical_type() {
+        DataType::Dictionary(key_type, values, _) if !has_dictionary => {
+            let key_type = *key_type;
+            let data_type = field.data_type.clone();
+            let values = Field::new(&field.name, values.as_ref().clone(), field.is_nullable);
+            let iter = _column_iter_to_arrays(columns, types, values, chunk_size, int96_overflow)?;
+            Ok(Box::new(iter.map(move |values| {
+                match_integer_type!(key_type, |$T| {
+                    values_to_dictionary::<$T>(values?, &data_type)
+                })
+            })))
+        }
+        _ => _column_iter_to_arrays(columns, types, field, chunk_size, int96_overflow),
+    }
+}
+
+// `values` as a dictionary of `data_type`, whose keys are the positions of the values when they
+// fit in the keys and the (deduplicated) values otherwise
+fn values_to_dictionary<K: DictionaryKey>(
+    values: Arc<dyn Array>,
+    data_type: &DataType,
+) -> Result<Arc<dyn Array>> {
+    if K::from_usize(values.len().saturating_sub(1)).is_none() {
+        return cast(values.as_ref(), data_type, CastOptions::default()).map(|array| array.into());
+    }
+    let keys = (0..values.len())
+        .map(|index| K::from_usize(index).unwrap())
+        .collect::<Vec<_>>();
+    let keys =
+        PrimitiveArray::<K>::new(K::PRIMITIVE.into(), keys.into(), values.validity().cloned());
+    Ok(Arc::new(DictionaryArray::<K>::from_data(keys, values)))
+}
+
+/// The column chunks of a field of a row group, read to memory by [`read_row_group_task`].
+///
+/// Deserializing it is CPU-bounded and independent of the reader, the other fields and the
+/// other row groups, so that tasks can be deserialized in parallel (e.g. on a thread pool).
+#[derive(Debug, Clone)]
+pub struct ColumnTask {
+    columns: Vec<(ColumnChunkMetaData, Vec<u8>)>,
+    field: Field,
+    num_rows: usize,
+}
+
+impl ColumnTask {
+    /// The [`Field`] of this task
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
+
+    /// Deserializes this task into an [`ArrayIter`] of arrays of `chunk_size` rows.
+    pub fn deserialize(self, chunk_size: Option<usize>) -> Result<ArrayIter<'static>> {
+        let (metas, chunks): (Vec<_>, Vec<_>) = self.columns.into_iter().unzip();
+        let columns = metas.iter().zip(chunks).collect();
+        to_deserializer(columns, self.field, self.num_rows, chunk_size)
+    }
+}
+
+/// The fields of a row group, read to memory by [`read_row_group_task`].
+///
+/// A [`RowGroupTask`] owns its data: it can be sent to another thread and deserialized
+/// independently of the other row groups, either as a whole via [`RowGroupTask::deserialize`]
+/// or field by field via [`RowGroupTask::into_columns`].
+#[derive(Debug, Clone)]
+pub struct RowGroupTask {
+    columns: Vec<ColumnTask>,
+    num_rows: usize,
+}
+
+impl RowGroupTask {
+    /// The number of rows that this task deserializes
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns the tasks of each field, in the order of the fields
+    pub fn into_columns(self) -> Vec<ColumnTask> {
+        self.columns
+    }
+
+    /// Deserializes all fields of this task into [`Chunk`]s of `chunk_size` rows.
+    pub fn deserialize(self, chunk_size: Option<usize>) -> Result<RowGroupDeserializer> {
+        let columns = self
+            .columns
+            .into_iter()
+            .map(|column| column.deserialize(chunk_size))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(RowGroupDeserializer::new(columns, self.num_rows, None))
+    }
+}
+
+pub(super) fn to_task(
+    field_columns: Vec<Vec<(&ColumnChunkMetaData, Vec<u8>)>>,
+    fields: Vec<Field>,
+    num_rows: usize,
+) -> RowGroupTask {
+    let columns = field_columns
+        .into_iter()
+        .zip(fields)
+        .map(|(columns, field)| ColumnTask {
+            columns: columns
+                .into_iter()
+                .map(|(meta, chunk)| (meta.clone(), chunk))
+                .collect(),
+            field,
+            num_rows,
+        })
+        .collect();
+    RowGroupTask { columns, num_rows }
+}
+
+/// Reads the columns of `fields` of `row_group` to memory, returning a [`RowGroupTask`] that
+/// deserializes at most `limit` rows.
+///
+/// Use it to read row groups with a single reader while deserializing them in parallel.
+/// # Implementation
+/// This operation is IO-bounded `O(C)` where C is the number of columns of the fields.
+/// # Example
+/// ```no_run
+/// use std::fs::File;
+/// use arrow2::error::Result;
+/// use arrow2::io::parquet::read::{infer_schema, read_metadata, read_row_group_task};
+///
+/// # fn main() -> Result<()> {
+/// let mut reader = File::open("data.parquet")?;
+/// let metadata = read_metadata(&mut reader)?;
+/// let schema = infer_schema(&metadata)?;
+///
+/// // IO-bounded: read the row groups sequentially
+/// let tasks = metadata
+///     .row_groups
+///     .iter()
+///     .map(|row_group| read_row_group_task(&mut reader, row_group, schema.fields.clone(), None))
+///     .collect::<Result<Vec<_>>>()?;
+///
+/// // CPU-bounded: deserialize the row groups in parallel
+/// let handles = tasks
+///     .into_iter()
+///     .map(|task| std::thread::spawn(move || task.deserialize(None)?.collect::<Result<Vec<_>>>()))
+///     .collect::<Vec<_>>();
+///
+/// // the chunks, in the order of the row groups
+/// for handle in handles {
+///     let chunks = handle.join().unwrap()?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_row_group_task<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    limit: Option<usize>,
+) -> Result<RowGroupTask> {
+    let field_columns = fields
+        .iter()
+        .map(|field| _read_columns(reader, get_field_columns(row_group.columns(), field), None))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(to_task(field_columns, fields, limit_rows(row_group, limit)))
+}
+
+/// Reads the columns of `fields` of `row_group` to memory, returning a [`RowGroupTask`] that
+/// deserializes at most `limit` rows.
+///
+/// # Implementation
+/// This operation is IO-bounded `O(C)` where C is the number of columns of the fields.
+/// It does so asynchronously via `join_all`
+pub async fn read_row_group_task_async<
+    'b,
+    R: AsyncRead + AsyncSeek + Send + Unpin,
+    F: Fn() -> BoxFuture<'b, std::io::Result<R>> + Clone,
+>(
+    factory: F,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    limit: Option<usize>,
+) -> Result<RowGroupTask> {
+    let futures = fields.iter().map(|field| {
+        _read_columns_async(
+            factory.clone(),
+            get_field_columns(row_group.columns(), field),
+        )
+    });
+    let field_columns = try_join_all(futures).await?;
+    Ok(to_task(field_columns, fields, limit_rows(row_group, limit)))
+}
+
+// the number of rows to read from `row_group` given a `limit`
+fn limit_rows(row_group: &RowGroupMetaData, limit: Option<usize>) -> usize {
+    limit.unwrap_or(usize::MAX).min(row_group.num_rows())
 }
 
 /// Returns a vector of iterators of [`Array`] ([`ArrayIter`]) corresponding to the top
-/// level parquet fields whose name matches `fields`'s names.
+/// level parquet fields whose name matches `fields`'s names, with at most `limit` rows.
 ///
 /// # Implementation
 /// This operation is IO-bounded `O(C)` where C is the number of columns in the row group -
@@ -212,25 +544,58 @@ pub fn read_columns_many<'a, R: Read + Seek>(
     row_group: &RowGroupMetaData,
     fields: Vec<Field>,
     chunk_size: Option<usize>,
+    limit: Option<usize>,
+) -> Result<Vec<ArrayIter<'a>>> {
+    _read_columns_many(
+        reader,
+        row_group,
+        fields,
+        chunk_size,
+        limit,
+        Int96Overflow::default(),
+        None,
+    )
+}
+
+pub(super) fn _read_columns_many<'a, R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    limit: Option<usize>,
+    int96_overflow: Int96Overflow,
+    decryptor: Option<&ChunkDecryptor>,
 ) -> Result<Vec<ArrayIter<'a>>> {
     // reads all the necessary columns for all fields from the row group
     // This operation is IO-bounded `O(C)` where C is the number of columns in the row group
     let field_columns = fields
         .iter()
-        .map(|field| read_columns(reader, row_group.columns(), &field.name))
+        .map(|field| {
+            _read_columns(
+                reader,
+                get_field_columns(row_group.columns(), field),
+                decryptor,
+            )
+        })
         .collect::<Result<Vec<_>>>()?;
 
     field_columns
         .into_iter()
         .zip(fields.into_iter())
         .map(|(columns, field)| {
-            to_deserializer(columns, field, row_group.num_rows() as usize, chunk_size)
+            _to_deserializer(
+                columns,
+                field,
+                limit_rows(row_group, limit),
+                chunk_size,
+                int96_overflow,
+            )
         })
         .collect()
 }
 
 /// Returns a vector of iterators of [`Array`] corresponding to the top level parquet fields whose
-/// name matches `fields`'s names.
+/// name matches `fields`'s names, with at most `limit` rows.
 ///
 /// This operation is IO-bounded `O(C)` where C is the number of columns in the row group -
 /// it reads all the columns to memory from the row group associated to the requested fields.
@@ -249,10 +614,14 @@ pub async fn read_columns_many_async<
     row_group: &RowGroupMetaData,
     fields: Vec<Field>,
     chunk_size: Option<usize>,
+    limit: Option<usize>,
 ) -> Result<Vec<ArrayIter<'a>>> {
-    let futures = fields
-        .iter()
-        .map(|field| read_columns_async(factory.clone(), row_group.columns(), &field.name));
+    let futures = fields.iter().map(|field| {
+        _read_columns_async(
+            factory.clone(),
+            get_field_columns(row_group.columns(), field),
+        )
+    });
 
     let field_columns = try_join_all(futures).await?;
 
@@ -260,7 +629,358 @@ pub async fn read_columns_many_async<
         .into_iter()
         .zip(fields.into_iter())
         .map(|(columns, field)| {
-            to_deserializer(columns, field, row_group.num_rows() as usize, chunk_size)
+            to_deserializer(columns, field, limit_rows(row_group, limit), chunk_size)
+        })
+        .collect()
+}
+
+/// A [`Cursor`] over bytes read from the position `offset` of a file, that is seeked by
+/// positions of the file.
+struct OffsetCursor {
+    inner: Cursor<Vec<u8>>,
+    offset: u64,
+}
+
+impl Read for OffsetCursor {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl Seek for OffsetCursor {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                SeekFrom::Start(pos.checked_sub(self.offset).ok_or_else(|| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidInput, "seek before the chunk")
+                })?)
+            }
+            other => other,
+        };
+        Ok(self.inner.seek(pos)? + self.offset)
+    }
+}
+
+/// Reads the dictionary page and the pages of `meta` that contain rows in `rows` to memory,
+/// returning a reader of them.
+fn _read_single_column_pages<R: Read + Seek>(
+    reader: &mut R,
+    meta: &ColumnChunkMetaData,
+    locations: &[PageLocation],
+    rows: &[Interval],
+    num_rows: usize,
+) -> Result<IndexedPageReader<OffsetCursor>> {
+    let column_start = meta.byte_range().0;
+    let dictionary_end = locations
+        .first()
+        .map(|location| location.offset as u64)
+        .unwrap_or(column_start);
+
+    let mut data = vec![0; (dictionary_end - column_start) as usize];
+    reader.seek(SeekFrom::Start(column_start))?;
+    reader.read_exact(&mut data)?;
+
+    // the pages are laid out contiguously, after the dictionary page
+    let pages = select_pages(rows, locations, num_rows)?
+        .into_iter()
+        .filter(|page| !page.selected_rows.is_empty())
+        .map(|page| {
+            let start = column_start + data.len() as u64;
+            reader.seek(SeekFrom::Start(page.start))?;
+            data.resize(data.len() + page.length, 0);
+            reader.read_exact(&mut data[(start - column_start) as usize..])?;
+            Ok(FilteredPage { start, ..page })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let reader = OffsetCursor {
+        inner: Cursor::new(data),
+        offset: column_start,
+    };
+    Ok(IndexedPageReader::new(reader, meta, pages, vec![], vec![]))
+}
+
+/// A [`FallibleStreamingIterator`] of the decompressed pages of `iter` that deserializes all
+/// rows of every page, as the deserializers of nested types do not support selecting rows.
+struct WholePages<I: Iterator<Item = std::result::Result<CompressedDataPage, ParquetError>>> {
+    iter: I,
+    buffer: Vec<u8>,
+    current: Option<DataPage>,
+}
+
+impl<I> FallibleStreamingIterator for WholePages<I>
+where
+    I: Iterator<Item = std::result::Result<CompressedDataPage, ParquetError>>,
+{
+    type Item = DataPage;
+    type Error = ParquetError;
+
+    fn advance(&mut self) -> std::result::Result<(), ParquetError> {
+        self.current = match self.iter.next() {
+            Some(page) => {
+                let mut page = decompress(page?, &mut self.buffer)?;
+                page.selected_rows = None;
+                Some(page)
+            }
+            None => None,
+        };
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&DataPage> {
+        self.current.as_ref()
+    }
+}
+
+// sorts `intervals` and merges the overlapping and adjacent ones
+fn merge_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort_unstable_by_key(|interval| interval.start);
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for interval in intervals.into_iter().filter(|interval| interval.length > 0) {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.start + last.length => {
+                let end = (last.start + last.length).max(interval.start + interval.length);
+                last.length = end - last.start;
+            }
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
+
+// The rows of the pages containing `rows` of every column of `locations` (the leaves of a
+// field). As the pages of the leaves may start at different rows, the rows are expanded
+// until the pages of every leaf contain exactly the same rows.
+fn rows_of_pages(
+    locations: &[&Vec<PageLocation>],
+    rows: &[Interval],
+    num_rows: usize,
+) -> Result<Vec<Interval>> {
+    let mut rows = merge_intervals(rows.to_vec());
+    loop {
+        let mut covered = vec![];
+        for locations in locations {
+            let pages = select_pages(&rows, locations, num_rows)?;
+            covered.extend(
+                pages
+                    .iter()
+                    .zip(locations.iter())
+                    .filter(|(page, _)| !page.selected_rows.is_empty())
+                    .map(|(page, location)| {
+                        Interval::new(location.first_row_index as usize, page.num_rows)
+                    }),
+            );
+        }
+        let covered = merge_intervals(covered);
+        // the pages contain the rows, so that this only grows until it is stable
+        if covered == rows {
+            return Ok(rows);
+        }
+        rows = covered;
+    }
+}
+
+// the single array of `iter`, which was created with a chunk size of all its rows
+pub(super) fn single_array(
+    mut iter: ArrayIter<'static>,
+    data_type: &DataType,
+) -> Result<Arc<dyn Array>> {
+    iter.next()
+        .unwrap_or_else(|| Ok(new_empty_array(data_type.clone()).into()))
+}
+
+// slices `array` in arrays of `chunk_size`
+pub(super) fn to_chunks(array: Arc<dyn Array>, chunk_size: usize) -> ArrayIter<'static> {
+    let length = array.len();
+    Box::new(
+        (0..length)
+            .step_by(chunk_size)
+            .map(move |offset| Ok(array.slice(offset, chunk_size.min(length - offset)).into())),
+    )
+}
+
+// whether the type is stored in parquet via repetition levels or more than one column
+pub(super) fn is_nested(data_type: &DataType) -> bool {
+    !matches!(
+        data_type.to_physical_type(),
+        PhysicalType::Null
+            | PhysicalType::Boolean
+            | PhysicalType::Primitive(_)
+            | PhysicalType::Binary
+            | PhysicalType::FixedSizeBinary
+            | PhysicalType::LargeBinary
+            | PhysicalType::Utf8
+            | PhysicalType::LargeUtf8
+            | PhysicalType::Dictionary(_)
+    )
+}
+
+/// Returns a vector of iterators of [`Array`] ([`ArrayIter`]) corresponding to the top
+/// level parquet fields whose name matches `fields`'s names, restricted to the rows in `rows`.
+///
+/// Only the pages containing rows in `rows` are read and decompressed, as declared by the
+/// offset indexes of the row group. The iterators return the selected rows of every field,
+/// i.e. they are aligned, and `rows` are usually derived from the column indexes
+/// (e.g. via [`select_pages_in_range`](super::select_pages_in_range) and
+/// [`compute_rows`](super::compute_rows)).
+///
+/// Nested fields (e.g. lists) are read from the pages containing the rows of every of their
+/// leaves, deserialized in full and then filtered.
+/// # Error
+/// Errors iff the row group has no offset indexes or reading or deserializing any of the
+/// pages fails.
+/// # Implementation
+/// This operation is IO-bounded `O(P)` where P is the number of selected pages.
+pub fn read_columns_many_indexed<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    rows: &[Interval],
+) -> Result<Vec<ArrayIter<'static>>> {
+    _read_columns_many_indexed(
+        reader,
+        row_group,
+        fields,
+        chunk_size,
+        rows,
+        Int96Overflow::default(),
+    )
+}
+
+pub(super) fn _read_columns_many_indexed<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    rows: &[Interval],
+    int96_overflow: Int96Overflow,
+) -> Result<Vec<ArrayIter<'static>>> {
+    let num_rows = row_group.num_rows();
+    super::check_not_encrypted(row_group.columns(), "offset indexes")?;
+    let locations = read_pages_locations(reader, row_group.columns())?;
+    if locations.iter().any(|locations| locations.is_empty()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Page pruning requires the offset indexes of the row group".to_string(),
+        ));
+    }
+
+    let selected_rows = rows
+        .iter()
+        .map(|interval| interval.length.min(num_rows.saturating_sub(interval.start)))
+        .sum::<usize>();
+    let chunk_size = chunk_size.unwrap_or(usize::MAX).min(selected_rows);
+
+    fields
+        .into_iter()
+        .map(|field| {
+            if is_nested(&field.data_type) {
+                return read_nested_indexed(
+                    reader,
+                    row_group,
+                    &locations,
+                    field,
+                    chunk_size,
+                    rows,
+                    int96_overflow,
+                );
+            }
+
+            let has_dictionary = get_field_columns(row_group.columns(), &field)
+                .into_iter()
+                .all(has_dictionary);
+            let (columns, types): (Vec<_>, Vec<_>) = row_group
+                .columns()
+                .iter()
+                .zip(locations.iter())
+                .filter(|(meta, _)| meta.descriptor().path_in_schema[0] == field.name)
+                .map(|(meta, locations)| {
+                    let pages = _read_single_column_pages(reader, meta, locations, rows, num_rows)?;
+                    Ok((
+                        BasicDecompressor::new(pages, vec![]),
+                        &meta.descriptor().descriptor.primitive_type,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .unzip();
+
+            column_iter_to_arrays(
+                columns,
+                types,
+                field,
+                chunk_size,
+                int96_overflow,
+                has_dictionary,
+            )
         })
         .collect()
 }
+
+// reads the rows `rows` of the nested `field` by deserializing all rows of the pages containing
+// them and filtering them
+fn read_nested_indexed<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    locations: &[Vec<PageLocation>],
+    field: Field,
+    chunk_size: usize,
+    rows: &[Interval],
+    int96_overflow: Int96Overflow,
+) -> Result<ArrayIter<'static>> {
+    let num_rows = row_group.num_rows();
+    let (columns, locations): (Vec<_>, Vec<_>) = row_group
+        .columns()
+        .iter()
+        .zip(locations.iter())
+        .filter(|(meta, _)| meta.descriptor().path_in_schema[0] == field.name)
+        .unzip();
+    let pages_rows = rows_of_pages(&locations, rows, num_rows)?;
+    let num_pages_rows = pages_rows.iter().map(|x| x.length).sum::<usize>();
+
+    let has_dictionary = columns.iter().all(|meta| has_dictionary(meta));
+    let (iters, types): (Vec<_>, Vec<_>) = columns
+        .into_iter()
+        .zip(locations)
+        .map(|(meta, locations)| {
+            let pages = _read_single_column_pages(reader, meta, locations, &pages_rows, num_rows)?;
+            Ok((
+                WholePages {
+                    iter: pages,
+                    buffer: vec![],
+                    current: None,
+                },
+                &meta.descriptor().descriptor.primitive_type,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    let data_type = field.data_type.clone();
+    let array = column_iter_to_arrays(
+        iters,
+        types,
+        field,
+        num_pages_rows.max(1),
+        int96_overflow,
+        has_dictionary,
+    )?;
+    let array = single_array(array, &data_type)?;
+
+    // the rows of the pages that are in `rows`
+    let mut selected = MutableBitmap::from_len_zeroed(num_rows);
+    for interval in rows {
+        let end = (interval.start + interval.length).min(num_rows);
+        (interval.start.min(end)..end).for_each(|row| selected.set(row, true));
+    }
+    let mut mask = MutableBitmap::with_capacity(num_pages_rows);
+    for interval in &pages_rows {
+        (interval.start..interval.start + interval.length)
+            .for_each(|row| mask.push(selected.get(row)));
+    }
+    let mask = BooleanArray::from_data(DataType::Boolean, mask.into(), None);
+    let array = filter(array.as_ref(), &mask)?;
+
+    Ok(to_chunks(array.into(), chunk_size.max(1)))
+}
@@ -8,11 +8,41 @@ use parquet2::schema::{
 };
 
 use crate::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
+use crate::io::parquet::PARQUET_FIELD_ID_META_KEY;
+
+/// Options to infer an arrow schema from the parquet schema of a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SchemaInferenceOptions {
+    /// The [`TimeUnit`] of the timestamps of parquet's (deprecated) `INT96` type, which
+    /// have a precision of nanoseconds but a range of years far larger than `i64` nanoseconds.
+    /// Spark, for example, writes them with dates out of the range of
+    /// [`TimeUnit::Nanosecond`] (after the year 2262).
+    pub int96_coerce_to_timeunit: TimeUnit,
+}
+
+impl Default for SchemaInferenceOptions {
+    fn default() -> Self {
+        Self {
+            int96_coerce_to_timeunit: TimeUnit::Nanosecond,
+        }
+    }
+}
 
 /// Converts [`ParquetType`]s to a [`Field`], ignoring parquet fields that do not contain
 /// any physical column.
 pub fn parquet_to_arrow_schema(fields: &[ParquetType]) -> Vec<Field> {
-    fields.iter().filter_map(to_field).collect::<Vec<_>>()
+    parquet_to_arrow_schema_with_options(fields, &SchemaInferenceOptions::default())
+}
+
+/// Like [`parquet_to_arrow_schema`] but with `options`.
+pub fn parquet_to_arrow_schema_with_options(
+    fields: &[ParquetType],
+    options: &SchemaInferenceOptions,
+) -> Vec<Field> {
+    fields
+        .iter()
+        .filter_map(|field| to_field(field, options))
+        .collect::<Vec<_>>()
 }
 
 fn from_int32(
@@ -169,7 +199,10 @@ fn from_fixed_len_byte_array(
 }
 
 /// Maps a [`PhysicalType`] with optional metadata to a [`DataType`]
-fn to_primitive_type_inner(primitive_type: &PrimitiveType) -> DataType {
+fn to_primitive_type_inner(
+    primitive_type: &PrimitiveType,
+    options: &SchemaInferenceOptions,
+) -> DataType {
     match primitive_type.physical_type {
         PhysicalType::Boolean => DataType::Boolean,
         PhysicalType::Int32 => {
@@ -178,7 +211,7 @@ fn to_primitive_type_inner(primitive_type: &PrimitiveType) -> DataType {
         PhysicalType::Int64 => {
             from_int64(primitive_type.logical_type, primitive_type.converted_type)
         }
-        PhysicalType::Int96 => DataType::Timestamp(TimeUnit::Nanosecond, None),
+        PhysicalType::Int96 => DataType::Timestamp(options.int96_coerce_to_timeunit, None),
         PhysicalType::Float => DataType::Float32,
         PhysicalType::Double => DataType::Float64,
         PhysicalType::ByteArray => {
@@ -195,14 +228,15 @@ fn to_primitive_type_inner(primitive_type: &PrimitiveType) -> DataType {
 /// Entry point for converting parquet primitive type to arrow type.
 ///
 /// This function takes care of repetition.
-fn to_primitive_type(primitive_type: &PrimitiveType) -> DataType {
-    let base_type = to_primitive_type_inner(primitive_type);
+fn to_primitive_type(primitive_type: &PrimitiveType, options: &SchemaInferenceOptions) -> DataType {
+    let base_type = to_primitive_type_inner(primitive_type, options);
 
     if primitive_type.field_info.repetition == Repetition::Repeated {
+        // a repeated field outside of a list is a (required) list of required elements
         DataType::List(Box::new(Field::new(
             &primitive_type.field_info.name,
             base_type,
-            is_nullable(&primitive_type.field_info),
+            false,
         )))
     } else {
         base_type
@@ -214,19 +248,23 @@ fn non_repeated_group(
     converted_type: &Option<GroupConvertedType>,
     fields: &[ParquetType],
     parent_name: &str,
+    options: &SchemaInferenceOptions,
 ) -> Option<DataType> {
     debug_assert!(!fields.is_empty());
     match (logical_type, converted_type) {
-        (Some(GroupLogicalType::List), _) => to_list(fields, parent_name),
-        (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name),
-        _ => to_struct(fields),
+        (Some(GroupLogicalType::List), _) => to_list(fields, parent_name, options),
+        (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name, options),
+        _ => to_struct(fields, options),
     }
 }
 
 /// Converts a parquet group type to an arrow [`DataType::Struct`].
 /// Returns [`None`] if all its fields are empty
-fn to_struct(fields: &[ParquetType]) -> Option<DataType> {
-    let fields = fields.iter().filter_map(to_field).collect::<Vec<Field>>();
+fn to_struct(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option<DataType> {
+    let fields = fields
+        .iter()
+        .filter_map(|field| to_field(field, options))
+        .collect::<Vec<Field>>();
     if fields.is_empty() {
         None
     } else {
@@ -243,16 +281,17 @@ fn to_group_type(
     converted_type: &Option<GroupConvertedType>,
     fields: &[ParquetType],
     parent_name: &str,
+    options: &SchemaInferenceOptions,
 ) -> Option<DataType> {
     debug_assert!(!fields.is_empty());
     if field_info.repetition == Repetition::Repeated {
         Some(DataType::List(Box::new(Field::new(
             &field_info.name,
-            to_struct(fields)?,
-            is_nullable(field_info),
+            to_struct(fields, options)?,
+            false,
         ))))
     } else {
-        non_repeated_group(logical_type, converted_type, fields, parent_name)
+        non_repeated_group(logical_type, converted_type, fields, parent_name, options)
     }
 }
 
@@ -268,62 +307,67 @@ pub(crate) fn is_nullable(field_info: &FieldInfo) -> bool {
 /// Converts parquet schema to arrow field.
 /// Returns `None` iff the parquet type has no associated primitive types,
 /// i.e. if it is a column-less group type.
-fn to_field(type_: &ParquetType) -> Option<Field> {
-    Some(Field::new(
+fn to_field(type_: &ParquetType, options: &SchemaInferenceOptions) -> Option<Field> {
+    // a repeated field is a list that is never null, only empty
+    let field = Field::new(
         &type_.get_field_info().name,
-        to_data_type(type_)?,
-        is_nullable(type_.get_field_info()),
-    ))
+        to_data_type(type_, options)?,
+        type_.get_field_info().repetition == Repetition::Optional,
+    );
+    Some(with_field_id(field, type_.get_field_info()))
+}
+
+/// Adds the id of the parquet field of `field_info`, if any, to the metadata of `field`.
+fn with_field_id(mut field: Field, field_info: &FieldInfo) -> Field {
+    if let Some(id) = field_info.id {
+        field
+            .metadata
+            .insert(PARQUET_FIELD_ID_META_KEY.to_string(), id.to_string());
+    }
+    field
 }
 
 /// Converts a parquet list to arrow list.
 ///
-/// To fully understand this algorithm, please refer to
-/// [parquet doc](https://github.com/apache/parquet-format/blob/master/LogicalTypes.md).
-fn to_list(fields: &[ParquetType], parent_name: &str) -> Option<DataType> {
+/// Besides the standard 3-level lists, this supports the legacy 2-level lists written by
+/// e.g. parquet-mr (Hive, Avro) following the backward-compatibility rules of the
+/// [parquet doc](https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#backward-compatibility-rules):
+/// when the repeated field is a primitive, a group with more than one field, or a group named
+/// `array` or `{parent_name}_tuple`, it is itself the (required) element. Otherwise, the only
+/// field of the repeated group is the element (e.g. `element` or Hive's `array_element`).
+fn to_list(
+    fields: &[ParquetType],
+    parent_name: &str,
+    options: &SchemaInferenceOptions,
+) -> Option<DataType> {
     let item = fields.first().unwrap();
 
-    let item_type = match item {
-        ParquetType::PrimitiveType(primitive) => Some(to_primitive_type_inner(primitive)),
-        ParquetType::GroupType { fields, .. } => {
+    let item = match item {
+        ParquetType::PrimitiveType(primitive) => with_field_id(
+            Field::new(
+                &primitive.field_info.name,
+                to_primitive_type_inner(primitive, options),
+                false,
+            ),
+            &primitive.field_info,
+        ),
+        ParquetType::GroupType { fields, .. }
             if fields.len() == 1
                 && item.name() != "array"
-                && item.name() != format!("{}_tuple", parent_name)
-            {
-                // extract the repetition field
-                let nested_item = fields.first().unwrap();
-                to_data_type(nested_item)
-            } else {
-                to_struct(fields)
-            }
+                && item.name() != format!("{}_tuple", parent_name) =>
+        {
+            // the repeated group only wraps the element
+            to_field(fields.first().unwrap(), options)?
         }
-    }?;
-
-    // Check that the name of the list child is "list", in which case we
-    // get the child nullability and name (normally "element") from the nested
-    // group type.
-    // Without this step, the child incorrectly inherits the parent's optionality
-    let (list_item_name, item_is_optional) = match item {
         ParquetType::GroupType {
             field_info, fields, ..
-        } if field_info.name == "list" && fields.len() == 1 => {
-            let field = fields.first().unwrap();
-            (
-                &field.get_field_info().name,
-                field.get_field_info().repetition != Repetition::Required,
-            )
-        }
-        _ => (
-            &item.get_field_info().name,
-            item.get_field_info().repetition != Repetition::Required,
+        } => with_field_id(
+            Field::new(item.name(), to_struct(fields, options)?, false),
+            field_info,
         ),
     };
 
-    Some(DataType::List(Box::new(Field::new(
-        list_item_name,
-        item_type,
-        item_is_optional,
-    ))))
+    Some(DataType::List(Box::new(item)))
 }
 
 /// Converts parquet schema to arrow data type.
@@ -335,9 +379,12 @@ fn to_list(fields: &[ParquetType], parent_name: &str) -> Option<DataType> {
 ///
 /// If this schema is a group type and none of its children is reserved in the
 /// conversion, the result is Ok(None).
-pub(crate) fn to_data_type(type_: &ParquetType) -> Option<DataType> {
+pub(crate) fn to_data_type(
+    type_: &ParquetType,
+    options: &SchemaInferenceOptions,
+) -> Option<DataType> {
     match type_ {
-        ParquetType::PrimitiveType(primitive) => Some(to_primitive_type(primitive)),
+        ParquetType::PrimitiveType(primitive) => Some(to_primitive_type(primitive, options)),
         ParquetType::GroupType {
             field_info,
             logical_type,
@@ -353,6 +400,7 @@ pub(crate) fn to_data_type(type_: &ParquetType) -> Option<DataType> {
                     converted_type,
                     fields,
                     &field_info.name,
+                    options,
                 )
             }
         }
@@ -560,7 +608,7 @@ mod tests {
         {
             arrow_fields.push(Field::new(
                 "my_list",
-                DataType::List(Box::new(Field::new("element", DataType::Utf8, true))),
+                DataType::List(Box::new(Field::new("str", DataType::Utf8, false))),
                 true,
             ));
         }
@@ -572,7 +620,7 @@ mod tests {
         {
             arrow_fields.push(Field::new(
                 "my_list",
-                DataType::List(Box::new(Field::new("element", DataType::Int32, true))),
+                DataType::List(Box::new(Field::new("element", DataType::Int32, false))),
                 true,
             ));
         }
@@ -591,7 +639,7 @@ mod tests {
             ]);
             arrow_fields.push(Field::new(
                 "my_list",
-                DataType::List(Box::new(Field::new("element", arrow_struct, true))),
+                DataType::List(Box::new(Field::new("element", arrow_struct, false))),
                 true,
             ));
         }
@@ -607,7 +655,7 @@ mod tests {
             let arrow_struct = DataType::Struct(vec![Field::new("str", DataType::Utf8, false)]);
             arrow_fields.push(Field::new(
                 "my_list",
-                DataType::List(Box::new(Field::new("array", arrow_struct, true))),
+                DataType::List(Box::new(Field::new("array", arrow_struct, false))),
                 true,
             ));
         }
@@ -623,7 +671,7 @@ mod tests {
             let arrow_struct = DataType::Struct(vec![Field::new("str", DataType::Utf8, false)]);
             arrow_fields.push(Field::new(
                 "my_list",
-                DataType::List(Box::new(Field::new("my_list_tuple", arrow_struct, true))),
+                DataType::List(Box::new(Field::new("my_list_tuple", arrow_struct, false))),
                 true,
             ));
         }
@@ -633,8 +681,8 @@ mod tests {
         {
             arrow_fields.push(Field::new(
                 "name",
-                DataType::List(Box::new(Field::new("name", DataType::Int32, true))),
-                true,
+                DataType::List(Box::new(Field::new("name", DataType::Int32, false))),
+                false,
             ));
         }
 
@@ -645,6 +693,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parquet_legacy_lists() -> Result<()> {
+        // 2-level lists written by parquet-mr's avro writer and 3-level lists written by Hive
+        let message_type = "
+        message test_schema {
+          OPTIONAL GROUP avro (LIST) {
+            REPEATED BINARY array (UTF8);
+          }
+          OPTIONAL GROUP avro_struct (LIST) {
+            REPEATED GROUP array {
+              OPTIONAL INT32 a;
+            }
+          }
+          OPTIONAL GROUP hive (LIST) {
+            REPEATED GROUP bag {
+              OPTIONAL INT32 array_element;
+            }
+          }
+        }
+        ";
+
+        let arrow_fields = vec![
+            Field::new(
+                "avro",
+                DataType::List(Box::new(Field::new("array", DataType::Utf8, false))),
+                true,
+            ),
+            Field::new(
+                "avro_struct",
+                DataType::List(Box::new(Field::new(
+                    "array",
+                    DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+                    false,
+                ))),
+                true,
+            ),
+            Field::new(
+                "hive",
+                DataType::List(Box::new(Field::new("array_element", DataType::Int32, true))),
+                true,
+            ),
+        ];
+
+        let parquet_schema = SchemaDescriptor::try_from_message(message_type)?;
+        let fields = parquet_to_arrow_schema(parquet_schema.fields());
+
+        assert_eq!(arrow_fields, fields);
+        Ok(())
+    }
+
     #[test]
     fn test_parquet_list_nullable() -> Result<()> {
         let mut arrow_fields = Vec::new();
@@ -761,9 +859,9 @@ mod tests {
                 DataType::List(Box::new(Field::new(
                     "innerGroup",
                     DataType::Struct(vec![Field::new("leaf3", DataType::Int32, true)]),
-                    true,
+                    false,
                 ))),
-                true,
+                false,
             );
 
             let outer_group_list = Field::new(
@@ -774,9 +872,9 @@ mod tests {
                         Field::new("leaf2", DataType::Int32, true),
                         inner_group_list,
                     ]),
-                    true,
+                    false,
                 ))),
-                true,
+                false,
             );
             arrow_fields.push(outer_group_list);
         }
@@ -837,8 +935,8 @@ mod tests {
             Field::new("string", DataType::Utf8, true),
             Field::new(
                 "bools",
-                DataType::List(Box::new(Field::new("bools", DataType::Boolean, true))),
-                true,
+                DataType::List(Box::new(Field::new("bools", DataType::Boolean, false))),
+                false,
             ),
             Field::new("date", DataType::Date32, true),
             Field::new("time_milli", DataType::Time32(TimeUnit::Millisecond), true),
@@ -962,4 +1060,48 @@ mod tests {
         assert_eq!(arrow_fields, fields);
         Ok(())
     }
+
+    #[test]
+    fn test_field_ids() -> Result<()> {
+        let message_type = "
+        message schema {
+            REQUIRED INT32 a = 1;
+            OPTIONAL INT64 b;
+            OPTIONAL GROUP c (LIST) = 2 {
+                REPEATED GROUP list {
+                    OPTIONAL INT32 element = 3;
+                }
+            }
+        }
+        ";
+        let with_id = |field: Field, id: &str| {
+            field.with_metadata(
+                [(PARQUET_FIELD_ID_META_KEY.to_string(), id.to_string())]
+                    .into_iter()
+                    .collect(),
+            )
+        };
+
+        let arrow_fields = vec![
+            with_id(Field::new("a", DataType::Int32, false), "1"),
+            Field::new("b", DataType::Int64, true),
+            with_id(
+                Field::new(
+                    "c",
+                    DataType::List(Box::new(with_id(
+                        Field::new("element", DataType::Int32, true),
+                        "3",
+                    ))),
+                    true,
+                ),
+                "2",
+            ),
+        ];
+
+        let parquet_schema = SchemaDescriptor::try_from_message(message_type)?;
+        let fields = parquet_to_arrow_schema(parquet_schema.fields());
+
+        assert_eq!(arrow_fields, fields);
+        Ok(())
+    }
 }
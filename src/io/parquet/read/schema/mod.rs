@@ -1,6 +1,6 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use crate::datatypes::Schema;
-use crate::error::Result;
+use crate::datatypes::{DataType, Field, Schema};
+use crate::error::{ArrowError, Result};
 
 mod convert;
 mod metadata;
@@ -10,6 +10,9 @@ pub use parquet2::metadata::{FileMetaData, KeyValue, SchemaDescriptor};
 pub use parquet2::schema::types::ParquetType;
 
 pub(crate) use convert::*;
+pub use convert::{
+    parquet_to_arrow_schema, parquet_to_arrow_schema_with_options, SchemaInferenceOptions,
+};
 
 use self::metadata::parse_key_value_metadata;
 
@@ -20,11 +23,87 @@ use self::metadata::parse_key_value_metadata;
 /// This function errors iff the key `"ARROW:schema"` exists but is not correctly encoded,
 /// indicating that that the file's arrow metadata was incorrectly written.
 pub fn infer_schema(file_metadata: &FileMetaData) -> Result<Schema> {
+    infer_schema_with_options(file_metadata, &None)
+}
+
+/// Like [`infer_schema`] but with `options`, that apply to the types converted from the parquet
+/// schema (i.e. not to the ones declared in `"ARROW:schema"`).
+/// # Error
+/// This function errors iff the key `"ARROW:schema"` exists but is not correctly encoded,
+/// indicating that that the file's arrow metadata was incorrectly written.
+pub fn infer_schema_with_options(
+    file_metadata: &FileMetaData,
+    options: &Option<SchemaInferenceOptions>,
+) -> Result<Schema> {
     let mut metadata = parse_key_value_metadata(file_metadata.key_value_metadata());
 
     let schema = read_schema_from_metadata(&mut metadata)?;
     Ok(schema.unwrap_or_else(|| {
-        let fields = parquet_to_arrow_schema(file_metadata.schema().fields());
+        let options = options.unwrap_or_default();
+        let fields =
+            parquet_to_arrow_schema_with_options(file_metadata.schema().fields(), &options);
         Schema { fields, metadata }
     }))
 }
+
+/// Returns the fields of `fields` at `paths`, where every path is the name of a top level
+/// field followed by the names of the fields of its (nested) structs, e.g.
+/// `&["user", "address", "city"]`.
+///
+/// The result contains, in the order of `fields`, every field that is part of a path, with
+/// its structs restricted to the fields of the paths (in their original order). Reading the
+/// resulting fields (e.g. via [`read_columns_many`](super::read_columns_many) or
+/// [`RowGroupReader`](super::RowGroupReader)) only reads and decompresses their leaf columns.
+/// # Error
+/// This function errors iff a path is empty, a field of a path does not exist, or a path
+/// continues past a field that is not a struct.
+pub fn project_fields(fields: &[Field], paths: &[&[&str]]) -> Result<Vec<Field>> {
+    if let Some(path) = paths
+        .iter()
+        .find(|path| !path.is_empty() && !fields.iter().any(|field| field.name == path[0]))
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The field \"{}\" of the projection does not exist",
+            path[0]
+        )));
+    }
+
+    fields
+        .iter()
+        .filter_map(|field| {
+            let paths = paths
+                .iter()
+                .map(|path| match path.split_first() {
+                    Some((name, rest)) if *name == field.name => Ok(Some(rest)),
+                    Some(_) => Ok(None),
+                    None => Err(ArrowError::InvalidArgumentError(
+                        "The paths of a projection cannot be empty".to_string(),
+                    )),
+                })
+                .filter_map(|path| path.transpose())
+                .collect::<Result<Vec<_>>>();
+            let paths = match paths {
+                Ok(paths) if paths.is_empty() => return None,
+                Ok(paths) => paths,
+                Err(e) => return Some(Err(e)),
+            };
+
+            // a path that ends at this field selects all of it
+            if paths.iter().any(|path| path.is_empty()) {
+                return Some(Ok(field.clone()));
+            }
+            Some(match &field.data_type {
+                DataType::Struct(children) => {
+                    project_fields(children, &paths).map(|children| Field {
+                        data_type: DataType::Struct(children),
+                        ..field.clone()
+                    })
+                }
+                other => Err(ArrowError::InvalidArgumentError(format!(
+                    "The field \"{}\" of the projection is not a struct but {:?}",
+                    field.name, other
+                ))),
+            })
+        })
+        .collect()
+}
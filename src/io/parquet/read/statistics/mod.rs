@@ -390,7 +390,10 @@ fn push(
         Utf8 => utf8::push::<i32>(from, min, max),
         LargeUtf8 => utf8::push::<i64>(from, min, max),
         FixedSizeBinary(_) => fixlen::push(from, min, max),
-        other => todo!("{:?}", other),
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Deserializing parquet stats from {:?} is still not implemented",
+            other
+        ))),
     }
 }
 
@@ -404,7 +407,7 @@ pub fn deserialize(field: &Field, row_groups: &[RowGroupMetaData]) -> Result<Sta
 
     // transpose
     row_groups.iter().try_for_each(|group| {
-        let columns = get_field_columns(group.columns(), field.name.as_ref());
+        let columns = get_field_columns(group.columns(), field);
         let mut stats = columns
             .into_iter()
             .map(|column| {
@@ -425,3 +428,29 @@ pub fn deserialize(field: &Field, row_groups: &[RowGroupMetaData]) -> Result<Sta
 
     Ok(statistics.into())
 }
+
+/// Deserializes the statistics of each of `fields` from all `row_groups`, in the order of `fields`.
+///
+/// The arrays of each [`Statistics`] have one slot per row group, so that row groups can be
+/// pruned by evaluating predicates on them with regular compute kernels (see also [`num_rows`]).
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics of any field fails
+pub fn deserialize_many(
+    fields: &[Field],
+    row_groups: &[RowGroupMetaData],
+) -> Result<Vec<Statistics>> {
+    fields
+        .iter()
+        .map(|field| deserialize(field, row_groups))
+        .collect()
+}
+
+/// Returns the number of rows of each of `row_groups`, e.g. to compare with
+/// [`Statistics::null_count`] when pruning row groups.
+pub fn num_rows(row_groups: &[RowGroupMetaData]) -> UInt64Array {
+    row_groups
+        .iter()
+        .map(|group| Some(group.num_rows() as u64))
+        .collect()
+}
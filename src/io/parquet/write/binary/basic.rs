@@ -68,6 +68,13 @@ pub fn array_to_page<O: Offset>(
             is_optional,
             &mut buffer,
         ),
+        Encoding::DeltaByteArray => {
+            if is_optional {
+                encode_delta_byte_array(array.iter().flatten(), &mut buffer)
+            } else {
+                encode_delta_byte_array(array.values_iter(), &mut buffer)
+            }
+        }
         _ => {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "Datatype {:?} cannot be encoded by {:?} encoding",
@@ -136,14 +143,14 @@ pub(crate) fn encode_delta<O: Offset>(
             let length = offsets.len() - 1 - validity.null_count();
             let lengths = utils::ExactSizedIter::new(lengths, length);
 
-            delta_bitpacked::encode(lengths, buffer);
+            encode_delta_bitpacked(lengths, buffer);
         } else {
             let lengths = offsets.windows(2).map(|w| (w[1] - w[0]).to_usize() as i64);
-            delta_bitpacked::encode(lengths, buffer);
+            encode_delta_bitpacked(lengths, buffer);
         }
     } else {
         let lengths = offsets.windows(2).map(|w| (w[1] - w[0]).to_usize() as i64);
-        delta_bitpacked::encode(lengths, buffer);
+        encode_delta_bitpacked(lengths, buffer);
     }
 
     buffer.extend_from_slice(
@@ -151,6 +158,40 @@ pub(crate) fn encode_delta<O: Offset>(
     )
 }
 
+// parquet2's encoder requires at least one value (e.g. a page of nulls has none)
+fn encode_delta_bitpacked<I: Iterator<Item = i64>>(values: I, buffer: &mut Vec<u8>) {
+    if values.size_hint().1 == Some(0) {
+        // block size (128), number of mini blocks (1), number of values (0) and first value (0)
+        buffer.extend_from_slice(&[128, 1, 1, 0, 0]);
+    } else {
+        delta_bitpacked::encode(values, buffer);
+    }
+}
+
+/// Encodes `values` as `DELTA_BYTE_ARRAY`: the length of the prefix that each value shares
+/// with the previous value, followed by the remaining suffixes as `DELTA_LENGTH_BYTE_ARRAY`.
+pub(crate) fn encode_delta_byte_array<'a, I: Iterator<Item = &'a [u8]>>(
+    values: I,
+    buffer: &mut Vec<u8>,
+) {
+    let mut previous: &[u8] = &[];
+    let (prefix_lengths, suffixes): (Vec<_>, Vec<_>) = values
+        .map(|value| {
+            let prefix_length = previous
+                .iter()
+                .zip(value)
+                .take_while(|(lhs, rhs)| lhs == rhs)
+                .count();
+            previous = value;
+            (prefix_length as i64, &value[prefix_length..])
+        })
+        .unzip();
+
+    encode_delta_bitpacked(prefix_lengths.into_iter(), buffer);
+    encode_delta_bitpacked(suffixes.iter().map(|x| x.len() as i64), buffer);
+    suffixes.iter().for_each(|x| buffer.extend_from_slice(x));
+}
+
 /// Returns the ordering of two binary values. This corresponds to pyarrows' ordering
 /// of statistics.
 pub(crate) fn ord_binary<'a>(a: &'a [u8], b: &'a [u8]) -> std::cmp::Ordering {
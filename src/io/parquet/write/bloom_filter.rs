@@ -0,0 +1,56 @@
+//! Split block bloom filters of the columns written by a [`RowGroupIterator`](super::RowGroupIterator).
+#[cfg(feature = "io_parquet_bloom_filter")]
+use crate::{array::Array, io::parquet::read::bloom_filter};
+
+/// The options of the split block bloom filter of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BloomFilterOptions {
+    /// The size of the bitset in bytes. It is rounded up to a power of two between 32 bytes
+    /// and 128 MiB.
+    pub num_bytes: usize,
+}
+
+impl BloomFilterOptions {
+    /// Returns the options of a bloom filter with a false positive probability of `fpp`
+    /// (e.g. `0.01`) after inserting `ndv` distinct values.
+    pub fn from_ndv_fpp(ndv: usize, fpp: f64) -> Self {
+        let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+        Self {
+            num_bytes: (num_bits / 8.0).ceil() as usize,
+        }
+    }
+
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    fn bitset_len(&self) -> usize {
+        // the bounds of the size of a bitset, as in the reference implementation
+        const MIN_BYTES: usize = 32;
+        const MAX_BYTES: usize = 128 * 1024 * 1024;
+        self.num_bytes
+            .clamp(MIN_BYTES, MAX_BYTES)
+            .next_power_of_two()
+    }
+}
+
+/// Returns the bitset of the bloom filter of the non-null values of `array`, or `None` when
+/// bloom filters of its type are not supported (e.g. booleans).
+#[cfg(feature = "io_parquet_bloom_filter")]
+pub(super) fn build(array: &dyn Array, options: &BloomFilterOptions) -> Option<Vec<u8>> {
+    let hashes = bloom_filter::hash(array).ok()?;
+    let mut bitset = vec![0; options.bitset_len()];
+    hashes
+        .into_iter()
+        .flatten()
+        .for_each(|hash| bloom_filter::insert(&mut bitset, hash));
+    Some(bitset)
+}
+
+/// The bloom filters of the columns of a row group yielded by
+/// [`RowGroupIterator::next_with_bloom_filters`](super::RowGroupIterator::next_with_bloom_filters),
+/// to write with it via [`FileWriter::write_with_bloom_filters`](super::FileWriter::write_with_bloom_filters).
+#[cfg(feature = "io_parquet_bloom_filter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BloomFilters {
+    // the bitset of each column of the row group, `None` for columns without a bloom filter
+    pub(super) bitsets: Vec<Option<Vec<u8>>>,
+}
@@ -20,6 +20,10 @@ where
 {
     let is_optional = is_nullable(&type_.field_info);
 
+    // only the values of the (possibly sliced) lists are written and counted
+    let range = nested.values_range();
+    let array = &array.slice(range.start, range.len());
+
     let validity = array.validity();
 
     let mut buffer = vec![];
@@ -37,11 +41,15 @@ where
         None
     };
 
+    let num_values = levels::num_values(nested.offsets());
+    // null and empty lists have a level but no value
+    let num_nulls = num_values.saturating_sub(array.len() - array.null_count());
+
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
+        num_values,
         nested.offsets().len().saturating_sub(1),
-        array.null_count(),
+        num_nulls,
         repetition_levels_byte_length,
         definition_levels_byte_length,
         statistics,
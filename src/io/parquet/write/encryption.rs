@@ -0,0 +1,547 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use parquet2::metadata::SchemaDescriptor;
+use parquet2::page::CompressedPage;
+use parquet2::write::{DynIter, DynStreamingIterator, RowGroupIter};
+use parquet2::FallibleStreamingIterator;
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
+use parquet_format_async_temp::{
+    AesGcmV1, ColumnCryptoMetaData, ColumnMetaData, EncryptionAlgorithm, EncryptionWithColumnKey,
+    EncryptionWithFooterKey, FileCryptoMetaData, OffsetIndex, PageHeader, PageType,
+};
+
+use crate::error::{ArrowError, Result};
+use crate::io::parquet::encryption::{
+    footer_aad, module_aad, random_bytes, FileEncryptionProperties, ModuleCipher, ModuleType,
+};
+
+use super::file::{read_footer, write_bloom_filter, SortingColumn};
+
+// serializes a thrift struct via its `write_to_out_protocol`
+fn to_thrift<F>(write: F) -> Result<Vec<u8>>
+where
+    F: FnOnce(
+        &mut TCompactOutputProtocol<&mut Vec<u8>>,
+    ) -> parquet_format_async_temp::thrift::Result<usize>,
+{
+    let mut buffer = vec![];
+    let mut protocol = TCompactOutputProtocol::new(&mut buffer);
+    write(&mut protocol)
+        .and_then(|_| protocol.flush())
+        .map_err(ArrowError::from_external_error)?;
+    drop(protocol);
+    Ok(buffer)
+}
+
+// how a column is encrypted
+struct ColumnEncryptor {
+    cipher: Arc<ModuleCipher>,
+    // the path and key metadata of the key of the column, `None` for the footer key
+    column_key: Option<(Vec<String>, Option<Vec<u8>>)>,
+}
+
+// a column chunk written to the file
+struct WrittenChunk {
+    offset: u64,
+    length: u64,
+    // the offset of the dictionary page, which readers need in order to decrypt it
+    dictionary_page: Option<u64>,
+    // the offset and length of the (header and page of the) data pages
+    pages: Vec<(u64, usize)>,
+}
+
+// counts the pages of a column chunk as parquet2 writes them, so that the column chunks that
+// parquet2 writes can be told apart
+struct CountedPages<'a> {
+    pages: DynStreamingIterator<'a, CompressedPage, ArrowError>,
+    counts: Arc<Mutex<Vec<usize>>>,
+    column: usize,
+}
+
+impl<'a> FallibleStreamingIterator for CountedPages<'a> {
+    type Item = CompressedPage;
+    type Error = ArrowError;
+
+    fn advance(&mut self) -> Result<()> {
+        self.pages.advance()?;
+        if self.pages.get().is_some() {
+            self.counts.lock().unwrap()[self.column] += 1;
+        }
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.pages.get()
+    }
+}
+
+/// Encrypts what parquet2 writes to a plaintext file, following [`FileEncryptionProperties`].
+pub(super) struct FileEncryptor {
+    plaintext_footer: bool,
+    algorithm: EncryptionAlgorithm,
+    file_aad: Vec<u8>,
+    footer_cipher: Arc<ModuleCipher>,
+    footer_key_metadata: Option<Vec<u8>>,
+    // the encryption of each column of the schema, `None` for plaintext columns
+    columns: Vec<Option<ColumnEncryptor>>,
+    // the number of pages of each column of the row group being written
+    page_counts: Arc<Mutex<Vec<usize>>>,
+    // the size of the (encrypted) file written so far
+    offset: u64,
+    row_groups: Vec<Vec<WrittenChunk>>,
+}
+
+impl FileEncryptor {
+    /// Returns a new [`FileEncryptor`] of files of `schema`.
+    /// # Error
+    /// Errors iff a key is invalid or a column of `properties` is not a column of `schema`.
+    pub fn try_new(
+        properties: FileEncryptionProperties,
+        schema: &SchemaDescriptor,
+    ) -> Result<Self> {
+        let paths = schema
+            .columns()
+            .iter()
+            .map(|column| column.path_in_schema.join("."))
+            .collect::<HashSet<_>>();
+        let declared = properties
+            .column_keys
+            .keys()
+            .chain(properties.plaintext_columns.iter());
+        if let Some(path) = declared.clone().find(|path| !paths.contains(*path)) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The column \"{}\" of the encryption properties is not a column of the parquet schema",
+                path
+            )));
+        }
+        if let Some(path) = properties
+            .plaintext_columns
+            .iter()
+            .find(|path| properties.column_keys.contains_key(*path))
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The column \"{}\" can't be both encrypted with a key of its own and not encrypted",
+                path
+            )));
+        }
+
+        let footer_cipher = Arc::new(ModuleCipher::try_new(&properties.footer_key.key)?);
+        let columns = schema
+            .columns()
+            .iter()
+            .map(|column| {
+                let path = column.path_in_schema.join(".");
+                if properties.plaintext_columns.contains(&path) {
+                    return Ok(None);
+                }
+                Ok(Some(match properties.column_keys.get(&path) {
+                    Some(key) => ColumnEncryptor {
+                        cipher: Arc::new(ModuleCipher::try_new(&key.key)?),
+                        column_key: Some((column.path_in_schema.clone(), key.key_metadata.clone())),
+                    },
+                    None => ColumnEncryptor {
+                        cipher: footer_cipher.clone(),
+                        column_key: None,
+                    },
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let aad_file_unique = random_bytes(8);
+        let aad_prefix = properties.aad_prefix.unwrap_or_default();
+        let mut file_aad = aad_prefix.clone();
+        file_aad.extend_from_slice(&aad_file_unique);
+        let supply_aad_prefix = !aad_prefix.is_empty() && !properties.store_aad_prefix;
+        let algorithm = EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+            aad_prefix: (!aad_prefix.is_empty() && properties.store_aad_prefix)
+                .then_some(aad_prefix),
+            aad_file_unique: Some(aad_file_unique),
+            supply_aad_prefix: supply_aad_prefix.then_some(true),
+        });
+
+        Ok(Self {
+            plaintext_footer: properties.plaintext_footer,
+            algorithm,
+            file_aad,
+            footer_cipher,
+            footer_key_metadata: properties.footer_key.key_metadata,
+            columns,
+            page_counts: Default::default(),
+            offset: 0,
+            row_groups: vec![],
+        })
+    }
+
+    /// The size of the file written so far
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the magic with which the file starts
+    pub fn start(&mut self) -> &'static [u8] {
+        self.offset = 4;
+        if self.plaintext_footer {
+            b"PAR1"
+        } else {
+            b"PARE"
+        }
+    }
+
+    /// Returns `row_group`, counting its pages as they are written
+    pub fn count_pages<'a>(
+        &mut self,
+        row_group: RowGroupIter<'a, ArrowError>,
+    ) -> RowGroupIter<'a, ArrowError> {
+        self.page_counts.lock().unwrap().clear();
+        let counts = self.page_counts.clone();
+        DynIter::new(row_group.enumerate().map(move |(column, pages)| {
+            counts.lock().unwrap().push(0);
+            let counts = counts.clone();
+            pages.map(|pages| {
+                DynStreamingIterator::new(CountedPages {
+                    pages,
+                    counts,
+                    column,
+                })
+            })
+        }))
+    }
+
+    /// Encrypts `row_group`, as written by parquet2 from the row group of the last
+    /// [`Self::count_pages`]: the pages of every column chunk, each followed by its metadata.
+    pub fn encrypt_row_group(&mut self, mut row_group: &[u8]) -> Result<Vec<u8>> {
+        let row_group_ordinal = self.row_groups.len();
+        let page_counts = self.page_counts.lock().unwrap().clone();
+
+        let mut result = vec![];
+        let mut chunks = vec![];
+        for (column_ordinal, (num_pages, column)) in
+            page_counts.into_iter().zip(self.columns.iter()).enumerate()
+        {
+            let offset = self.offset + result.len() as u64;
+            let mut pages = vec![];
+            let mut dictionary_page = None;
+            let mut page_ordinal = 0;
+            for _ in 0..num_pages {
+                let start = row_group;
+                let mut header = PageHeader::read_from_in_protocol(
+                    &mut TCompactInputProtocol::new(&mut row_group),
+                )
+                .map_err(ArrowError::from_external_error)?;
+                let header_length = start.len() - row_group.len();
+                let (page, remaining) = row_group.split_at(header.compressed_page_size as usize);
+                row_group = remaining;
+
+                let page_offset = self.offset + result.len() as u64;
+                let is_dictionary = header.type_ == PageType::DICTIONARY_PAGE;
+                match column {
+                    Some(column) => {
+                        let (header_type, page_type, ordinal) = if is_dictionary {
+                            (
+                                ModuleType::DictionaryPageHeader,
+                                ModuleType::DictionaryPage,
+                                None,
+                            )
+                        } else {
+                            (
+                                ModuleType::DataPageHeader,
+                                ModuleType::DataPage,
+                                Some(page_ordinal),
+                            )
+                        };
+                        let aad = |module_type| {
+                            module_aad(
+                                &self.file_aad,
+                                module_type,
+                                row_group_ordinal,
+                                column_ordinal,
+                                ordinal,
+                            )
+                        };
+                        let page = column.cipher.encrypt(page, &aad(page_type)?);
+                        header.compressed_page_size = page.len() as i32;
+                        let header = to_thrift(|protocol| header.write_to_out_protocol(protocol))?;
+                        result
+                            .extend_from_slice(&column.cipher.encrypt(&header, &aad(header_type)?));
+                        result.extend_from_slice(&page);
+                    }
+                    None => result.extend_from_slice(&start[..header_length + page.len()]),
+                }
+                if is_dictionary {
+                    dictionary_page = Some(page_offset);
+                } else {
+                    let length = self.offset + result.len() as u64 - page_offset;
+                    pages.push((page_offset, length as usize));
+                    page_ordinal += 1;
+                }
+            }
+            // the metadata of the column chunk is only written to the footer
+            ColumnMetaData::read_from_in_protocol(&mut TCompactInputProtocol::new(&mut row_group))
+                .map_err(ArrowError::from_external_error)?;
+
+            chunks.push(WrittenChunk {
+                offset,
+                length: self.offset + result.len() as u64 - offset,
+                dictionary_page,
+                pages,
+            });
+        }
+        if !row_group.is_empty() {
+            return Err(ArrowError::oos(
+                "The row group written has more column chunks than the parquet schema",
+            ));
+        }
+
+        self.offset += result.len() as u64;
+        self.row_groups.push(chunks);
+        Ok(result)
+    }
+
+    /// Encrypts `footer`, as written by parquet2 at the position `offset` of the plaintext file:
+    /// the column indexes and offset indexes followed by the metadata of the file. The
+    /// `bloom_filters` of each column of each row group are written after the indexes and
+    /// `sorting_columns` are set as the `sorting_columns` of every row group.
+    pub fn encrypt_footer(
+        &mut self,
+        footer: &[u8],
+        offset: u64,
+        sorting_columns: &[SortingColumn],
+        bloom_filters: &[Vec<Option<Vec<u8>>>],
+    ) -> Result<Vec<u8>> {
+        let (_, mut metadata) = read_footer(footer, sorting_columns)?;
+        let index = |index_offset: Option<i64>, length: Option<i32>| {
+            index_offset.zip(length).map(|(index_offset, length)| {
+                let start = (index_offset as u64 - offset) as usize;
+                &footer[start..start + length as usize]
+            })
+        };
+
+        let mut result = vec![];
+        for (row_group_ordinal, (row_group, chunks)) in metadata
+            .row_groups
+            .iter_mut()
+            .zip(self.row_groups.iter())
+            .enumerate()
+        {
+            row_group.file_offset = chunks.first().map(|chunk| chunk.offset as i64);
+            row_group.total_compressed_size =
+                Some(chunks.iter().map(|chunk| chunk.length as i64).sum());
+            for (column_ordinal, ((column, chunk), encryptor)) in row_group
+                .columns
+                .iter_mut()
+                .zip(chunks.iter())
+                .zip(self.columns.iter())
+                .enumerate()
+            {
+                let meta_data = column.meta_data.as_mut().unwrap();
+                meta_data.data_page_offset =
+                    chunk.pages.first().map_or(chunk.offset, |page| page.0) as i64;
+                meta_data.dictionary_page_offset = chunk.dictionary_page.map(|page| page as i64);
+                meta_data.total_compressed_size = chunk.length as i64;
+                column.file_offset = chunk.offset as i64;
+
+                let encrypt = |data: Vec<u8>, module_type| match encryptor {
+                    Some(encryptor) => Ok(encryptor.cipher.encrypt(
+                        &data,
+                        &module_aad(
+                            &self.file_aad,
+                            module_type,
+                            row_group_ordinal,
+                            column_ordinal,
+                            None,
+                        )?,
+                    )),
+                    None => Result::Ok(data),
+                };
+
+                if let Some(column_index) =
+                    index(column.column_index_offset, column.column_index_length)
+                {
+                    let column_index = encrypt(column_index.to_vec(), ModuleType::ColumnIndex)?;
+                    column.column_index_offset = Some((self.offset + result.len() as u64) as i64);
+                    column.column_index_length = Some(column_index.len() as i32);
+                    result.extend_from_slice(&column_index);
+                }
+            }
+        }
+
+        // the offset indexes, whose pages were moved by their encryption
+        for (row_group_ordinal, (row_group, chunks)) in metadata
+            .row_groups
+            .iter_mut()
+            .zip(self.row_groups.iter())
+            .enumerate()
+        {
+            for (column_ordinal, ((column, chunk), encryptor)) in row_group
+                .columns
+                .iter_mut()
+                .zip(chunks.iter())
+                .zip(self.columns.iter())
+                .enumerate()
+            {
+                let offset_index =
+                    match index(column.offset_index_offset, column.offset_index_length) {
+                        Some(offset_index) => offset_index,
+                        None => continue,
+                    };
+                let mut offset_index = OffsetIndex::read_from_in_protocol(
+                    &mut TCompactInputProtocol::new(offset_index),
+                )
+                .map_err(ArrowError::from_external_error)?;
+                if offset_index.page_locations.len() != chunk.pages.len() {
+                    return Err(ArrowError::oos(
+                        "The offset index of a column chunk does not match its data pages",
+                    ));
+                }
+                offset_index
+                    .page_locations
+                    .iter_mut()
+                    .zip(chunk.pages.iter())
+                    .for_each(|(location, (offset, length))| {
+                        location.offset = *offset as i64;
+                        location.compressed_page_size = *length as i32;
+                    });
+                let mut offset_index =
+                    to_thrift(|protocol| offset_index.write_to_out_protocol(protocol))?;
+                if let Some(encryptor) = encryptor {
+                    let aad = module_aad(
+                        &self.file_aad,
+                        ModuleType::OffsetIndex,
+                        row_group_ordinal,
+                        column_ordinal,
+                        None,
+                    )?;
+                    offset_index = encryptor.cipher.encrypt(&offset_index, &aad);
+                }
+                column.offset_index_offset = Some((self.offset + result.len() as u64) as i64);
+                column.offset_index_length = Some(offset_index.len() as i32);
+                result.extend_from_slice(&offset_index);
+            }
+        }
+
+        // the bloom filters, whose header and bitset are encrypted separately
+        for (row_group_ordinal, (row_group, bitsets)) in metadata
+            .row_groups
+            .iter_mut()
+            .zip(bloom_filters)
+            .enumerate()
+        {
+            for (column_ordinal, ((column, bitset), encryptor)) in row_group
+                .columns
+                .iter_mut()
+                .zip(bitsets)
+                .zip(self.columns.iter())
+                .enumerate()
+            {
+                let bitset = match bitset {
+                    Some(bitset) => bitset,
+                    None => continue,
+                };
+                let meta_data = column.meta_data.as_mut().unwrap();
+                meta_data.bloom_filter_offset = Some((self.offset + result.len() as u64) as i64);
+                let mut bloom_filter = vec![];
+                write_bloom_filter(&mut bloom_filter, bitset)?;
+                match encryptor {
+                    Some(encryptor) => {
+                        let aad = |module_type| {
+                            module_aad(
+                                &self.file_aad,
+                                module_type,
+                                row_group_ordinal,
+                                column_ordinal,
+                                None,
+                            )
+                        };
+                        let header = &bloom_filter[..bloom_filter.len() - bitset.len()];
+                        result.extend_from_slice(
+                            &encryptor
+                                .cipher
+                                .encrypt(header, &aad(ModuleType::BloomFilterHeader)?),
+                        );
+                        result.extend_from_slice(
+                            &encryptor
+                                .cipher
+                                .encrypt(bitset, &aad(ModuleType::BloomFilterBitset)?),
+                        );
+                    }
+                    None => result.extend_from_slice(&bloom_filter),
+                }
+            }
+        }
+
+        // the metadata of the columns encrypted with their own key, and of every encrypted
+        // column in plaintext footers, is encrypted separately
+        for (row_group_ordinal, row_group) in metadata.row_groups.iter_mut().enumerate() {
+            for (column_ordinal, (column, encryptor)) in row_group
+                .columns
+                .iter_mut()
+                .zip(self.columns.iter())
+                .enumerate()
+            {
+                let encryptor = match encryptor {
+                    Some(encryptor) => encryptor,
+                    None => continue,
+                };
+                column.crypto_metadata = Some(match &encryptor.column_key {
+                    Some((path_in_schema, key_metadata)) => {
+                        ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                            path_in_schema: path_in_schema.clone(),
+                            key_metadata: key_metadata.clone(),
+                        })
+                    }
+                    None => {
+                        ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey {})
+                    }
+                });
+                if encryptor.column_key.is_none() && !self.plaintext_footer {
+                    continue;
+                }
+
+                let meta_data = column.meta_data.take().unwrap();
+                let aad = module_aad(
+                    &self.file_aad,
+                    ModuleType::ColumnMetaData,
+                    row_group_ordinal,
+                    column_ordinal,
+                    None,
+                )?;
+                let encrypted = to_thrift(|protocol| meta_data.write_to_out_protocol(protocol))?;
+                column.encrypted_column_metadata = Some(encryptor.cipher.encrypt(&encrypted, &aad));
+                if self.plaintext_footer {
+                    // readers without the key can read everything but the statistics
+                    column.meta_data = Some(ColumnMetaData {
+                        statistics: None,
+                        encoding_stats: None,
+                        ..meta_data
+                    });
+                }
+            }
+        }
+
+        let aad = footer_aad(&self.file_aad);
+        let (footer, magic) = if self.plaintext_footer {
+            metadata.encryption_algorithm = Some(self.algorithm.clone());
+            metadata.footer_signing_key_metadata = self.footer_key_metadata.clone();
+            let mut footer = to_thrift(|protocol| metadata.write_to_out_protocol(protocol))?;
+            let signature = self.footer_cipher.sign(&footer, &aad);
+            footer.extend_from_slice(&signature);
+            (footer, b"PAR1")
+        } else {
+            let crypto_metadata =
+                FileCryptoMetaData::new(self.algorithm.clone(), self.footer_key_metadata.clone());
+            let mut footer = to_thrift(|protocol| crypto_metadata.write_to_out_protocol(protocol))?;
+            let metadata = to_thrift(|protocol| metadata.write_to_out_protocol(protocol))?;
+            footer.extend_from_slice(&self.footer_cipher.encrypt(&metadata, &aad));
+            (footer, b"PARE")
+        };
+        result.extend_from_slice(&footer);
+        result.extend_from_slice(&(footer.len() as u32).to_le_bytes());
+        result.extend_from_slice(magic);
+
+        self.offset += result.len() as u64;
+        Ok(result)
+    }
+}
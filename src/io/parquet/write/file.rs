@@ -1,13 +1,23 @@
 use std::io::Write;
+use std::sync::{Arc, Mutex};
 
 use parquet2::metadata::KeyValue;
 use parquet2::metadata::SchemaDescriptor;
 use parquet2::write::RowGroupIter;
 use parquet2::write::WriteOptions as FileWriteOptions;
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
 
 use crate::datatypes::Schema;
 use crate::error::{ArrowError, Result};
+#[cfg(feature = "io_parquet_encryption")]
+use crate::io::parquet::encryption::FileEncryptionProperties;
 
+#[cfg(feature = "io_parquet_encryption")]
+use super::encryption::FileEncryptor;
+#[cfg(feature = "io_parquet_bloom_filter")]
+use super::BloomFilters;
 use super::{schema::schema_to_metadata_key, to_parquet_schema, WriteOptions};
 
 /// Attaches [`Schema`] to `key_value_metadata`
@@ -23,11 +33,55 @@ pub fn add_arrow_schema(
         .or_else(|| Some(vec![schema_to_metadata_key(schema)]))
 }
 
+/// A column by which the rows of every row group are sorted. It is recorded in the
+/// `sorting_columns` of the row groups, so that readers can skip sorting them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortingColumn {
+    /// The index of the column in the parquet schema (i.e. of the leaf)
+    pub column: usize,
+    /// Whether the values are sorted in descending order
+    pub descending: bool,
+    /// Whether nulls come before the other values
+    pub nulls_first: bool,
+}
+
+#[derive(Debug)]
+struct Inner<W: Write> {
+    writer: W,
+    // what is written while this is `Some` is held back, so that it can be edited
+    held_back: Option<Vec<u8>>,
+}
+
+// parquet2 writes to this writer, which `FileWriter` shares to edit the footer (and to encrypt
+// everything, when the file is encrypted).
+#[derive(Debug)]
+struct SharedWriter<W: Write>(Arc<Mutex<Inner<W>>>);
+
+impl<W: Write> Write for SharedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut inner = self.0.lock().unwrap();
+        match &mut inner.held_back {
+            Some(held_back) => held_back.write(buf),
+            None => inner.writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.lock().unwrap().writer.flush()
+    }
+}
+
 /// An interface to write a parquet to a [`Write`]
 pub struct FileWriter<W: Write> {
-    writer: parquet2::write::FileWriter<W>,
+    writer: parquet2::write::FileWriter<SharedWriter<W>>,
+    inner: Arc<Mutex<Inner<W>>>,
     schema: Schema,
     options: WriteOptions,
+    sorting_columns: Vec<SortingColumn>,
+    // the bitsets of the bloom filters of each column of each row group written
+    row_group_bloom_filters: Vec<Vec<Option<Vec<u8>>>>,
+    #[cfg(feature = "io_parquet_encryption")]
+    encryptor: Option<FileEncryptor>,
 }
 
 // Accessors
@@ -57,9 +111,14 @@ impl<W: Write> FileWriter<W> {
 
         let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
 
+        let inner = Arc::new(Mutex::new(Inner {
+            writer,
+            held_back: None,
+        }));
+
         Ok(Self {
             writer: parquet2::write::FileWriter::new(
-                writer,
+                SharedWriter(inner.clone()),
                 parquet_schema,
                 FileWriteOptions {
                     version: options.version,
@@ -67,29 +126,262 @@ impl<W: Write> FileWriter<W> {
                 },
                 created_by,
             ),
+            inner,
             schema,
             options,
+            sorting_columns: vec![],
+            row_group_bloom_filters: vec![],
+            #[cfg(feature = "io_parquet_encryption")]
+            encryptor: None,
         })
     }
 
+    /// Returns a new [`FileWriter`] of a file encrypted following `properties`.
+    /// # Error
+    /// If it is unable to derive a parquet schema from [`Schema`], a key is invalid or a column
+    /// of `properties` is not a column of the parquet schema.
+    #[cfg(feature = "io_parquet_encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_encryption")))]
+    pub fn try_new_with_encryption(
+        writer: W,
+        schema: Schema,
+        options: WriteOptions,
+        properties: FileEncryptionProperties,
+    ) -> Result<Self> {
+        let mut file_writer = Self::try_new(writer, schema, options)?;
+        file_writer.encryptor = Some(FileEncryptor::try_new(
+            properties,
+            file_writer.writer.schema(),
+        )?);
+        // everything parquet2 writes is encrypted before it is written
+        file_writer.inner.lock().unwrap().held_back = Some(vec![]);
+        Ok(file_writer)
+    }
+
+    /// Declares that the rows of every row group are sorted by `columns`, in order of priority,
+    /// which is recorded in the metadata of the row groups when the file [`end`](Self::end)s.
+    /// # Error
+    /// If a column is not a column of the parquet schema.
+    pub fn set_sorting_columns(&mut self, columns: Vec<SortingColumn>) -> Result<()> {
+        let num_columns = self.writer.schema().columns().len();
+        if let Some(column) = columns.iter().find(|x| x.column >= num_columns) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The sorting column {} does not exist, as the schema has {} columns",
+                column.column, num_columns
+            )));
+        }
+        self.sorting_columns = columns;
+        Ok(())
+    }
+
     /// Writes the header of the file
     pub fn start(&mut self) -> Result<()> {
-        Ok(self.writer.start()?)
+        self.writer.start()?;
+        #[cfg(feature = "io_parquet_encryption")]
+        if let Some(encryptor) = &mut self.encryptor {
+            // parquet2 wrote the magic of plaintext files
+            let mut inner = self.inner.lock().unwrap();
+            inner.held_back.as_mut().unwrap().clear();
+            inner.writer.write_all(encryptor.start())?;
+        }
+        Ok(())
     }
 
     /// Writes a row group to the file.
     pub fn write(&mut self, row_group: RowGroupIter<'_, ArrowError>) -> Result<()> {
-        Ok(self.writer.write(row_group)?)
+        self.write_row_group(row_group)?;
+        self.row_group_bloom_filters.push(vec![]);
+        Ok(())
+    }
+
+    /// Writes a row group to the file together with the bloom filters of its columns, as
+    /// yielded by [`RowGroupIterator::next_with_bloom_filters`](super::RowGroupIterator::next_with_bloom_filters).
+    /// # Error
+    /// If `bloom_filters` does not have a (possibly absent) bloom filter per column of the
+    /// parquet schema.
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+    pub fn write_with_bloom_filters(
+        &mut self,
+        row_group: RowGroupIter<'_, ArrowError>,
+        bloom_filters: BloomFilters,
+    ) -> Result<()> {
+        let num_columns = self.writer.schema().columns().len();
+        if bloom_filters.bitsets.len() != num_columns {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The row group has bloom filters for {} columns, but the schema has {} columns",
+                bloom_filters.bitsets.len(),
+                num_columns
+            )));
+        }
+        self.write_row_group(row_group)?;
+        self.row_group_bloom_filters.push(bloom_filters.bitsets);
+        Ok(())
+    }
+
+    fn write_row_group(&mut self, row_group: RowGroupIter<'_, ArrowError>) -> Result<()> {
+        #[cfg(feature = "io_parquet_encryption")]
+        let row_group = match &mut self.encryptor {
+            Some(encryptor) => encryptor.count_pages(row_group),
+            None => row_group,
+        };
+        self.writer.write(row_group)?;
+        #[cfg(feature = "io_parquet_encryption")]
+        if let Some(encryptor) = &mut self.encryptor {
+            let mut inner = self.inner.lock().unwrap();
+            let row_group = std::mem::take(inner.held_back.as_mut().unwrap());
+            let row_group = encryptor.encrypt_row_group(&row_group)?;
+            inner.writer.write_all(&row_group)?;
+        }
+        Ok(())
     }
 
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> Result<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
-        Ok(self.writer.end(key_value_metadata)?)
+        let has_bloom_filters = self
+            .row_group_bloom_filters
+            .iter()
+            .flatten()
+            .any(|bitset| bitset.is_some());
+        #[cfg(feature = "io_parquet_encryption")]
+        let is_encrypted = self.encryptor.is_some();
+        #[cfg(not(feature = "io_parquet_encryption"))]
+        let is_encrypted = false;
+        if self.sorting_columns.is_empty() && !has_bloom_filters && !is_encrypted {
+            return Ok(self.writer.end(key_value_metadata)?);
+        }
+
+        // parquet2 writes neither `sorting_columns`, bloom filters nor encrypted footers: the
+        // footer is held back and edited
+        self.inner.lock().unwrap().held_back = Some(vec![]);
+        let result = self.writer.end(key_value_metadata);
+        let mut inner = self.inner.lock().unwrap();
+        let footer = inner.held_back.take().unwrap();
+        let size = result?;
+
+        let offset = size - footer.len() as u64;
+        #[cfg(feature = "io_parquet_encryption")]
+        if let Some(encryptor) = &mut self.encryptor {
+            let footer = encryptor.encrypt_footer(
+                &footer,
+                offset,
+                &self.sorting_columns,
+                &self.row_group_bloom_filters,
+            )?;
+            inner.writer.write_all(&footer)?;
+            return Ok(encryptor.offset());
+        }
+        let new_footer = rewrite_footer(
+            &footer,
+            offset,
+            &self.sorting_columns,
+            &self.row_group_bloom_filters,
+        )?;
+        inner.writer.write_all(&new_footer)?;
+        Ok(offset + new_footer.len() as u64)
     }
 
     /// Consumes this writer and returns the inner writer
     pub fn into_inner(self) -> W {
-        self.writer.into_inner()
+        drop(self.writer.into_inner());
+        match Arc::try_unwrap(self.inner) {
+            Ok(inner) => inner.into_inner().unwrap().writer,
+            Err(_) => unreachable!("the writer is only shared with parquet2's writer"),
+        }
+    }
+}
+
+impl FileWriter<Vec<u8>> {
+    // takes what was written so far, so that it can be written elsewhere
+    pub(super) fn take_written(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.inner.lock().unwrap().writer)
     }
 }
+
+// Rewrites the footer written by parquet2 at `offset` (the indexes followed by the metadata),
+// setting `columns` as the `sorting_columns` of every row group and writing `bloom_filters`,
+// the bitsets of each column of each row group, after the indexes.
+fn rewrite_footer(
+    footer: &[u8],
+    offset: u64,
+    columns: &[SortingColumn],
+    bloom_filters: &[Vec<Option<Vec<u8>>>],
+) -> Result<Vec<u8>> {
+    let (start, mut metadata) = read_footer(footer, columns)?;
+
+    let mut result = footer[..start].to_vec();
+    for (group, bitsets) in metadata.row_groups.iter_mut().zip(bloom_filters) {
+        for (column, bitset) in group.columns.iter_mut().zip(bitsets) {
+            if let Some(bitset) = bitset {
+                let column = column.meta_data.as_mut().unwrap();
+                column.bloom_filter_offset = Some((offset + result.len() as u64) as i64);
+                write_bloom_filter(&mut result, bitset)?;
+            }
+        }
+    }
+
+    let mut protocol = TCompactOutputProtocol::new(&mut result);
+    let metadata_length = metadata
+        .write_to_out_protocol(&mut protocol)
+        .and_then(|length| protocol.flush().map(|_| length))
+        .map_err(ArrowError::from_external_error)?;
+    result.extend_from_slice(&(metadata_length as i32).to_le_bytes());
+    result.extend_from_slice(b"PAR1");
+    Ok(result)
+}
+
+// Parses the footer written by parquet2 (the indexes followed by the metadata), returning the
+// length of the indexes and the metadata with `columns` as the `sorting_columns` of every row
+// group.
+pub(super) fn read_footer(
+    footer: &[u8],
+    columns: &[SortingColumn],
+) -> Result<(usize, parquet_format_async_temp::FileMetaData)> {
+    let length = footer.len();
+    let metadata_length = u32::from_le_bytes(footer[length - 8..length - 4].try_into().unwrap());
+    let start = length - 8 - metadata_length as usize;
+
+    let mut protocol = TCompactInputProtocol::new(&footer[start..length - 8]);
+    let mut metadata =
+        parquet_format_async_temp::FileMetaData::read_from_in_protocol(&mut protocol)
+            .map_err(ArrowError::from_external_error)?;
+    let columns = columns
+        .iter()
+        .map(|x| {
+            parquet_format_async_temp::SortingColumn::new(
+                x.column as i32,
+                x.descending,
+                x.nulls_first,
+            )
+        })
+        .collect::<Vec<_>>();
+    if !columns.is_empty() {
+        metadata
+            .row_groups
+            .iter_mut()
+            .for_each(|group| group.sorting_columns = Some(columns.clone()));
+    }
+    Ok((start, metadata))
+}
+
+// writes the header of a split block bloom filter followed by its `bitset`
+pub(super) fn write_bloom_filter(writer: &mut Vec<u8>, bitset: &[u8]) -> Result<()> {
+    use parquet_format_async_temp::{
+        BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+        SplitBlockAlgorithm, Uncompressed, XxHash,
+    };
+    let header = BloomFilterHeader::new(
+        bitset.len() as i32,
+        BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        BloomFilterHash::XXHASH(XxHash {}),
+        BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    );
+    let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+    header
+        .write_to_out_protocol(&mut protocol)
+        .and_then(|_| protocol.flush())
+        .map_err(ArrowError::from_external_error)?;
+    writer.extend_from_slice(bitset);
+    Ok(())
+}
@@ -141,6 +141,13 @@ impl<'a, O: Offset> NestedInfo<'a, O> {
     pub fn offsets(&self) -> &'a [O] {
         self.offsets
     }
+
+    /// The range of the values of the lists, which does not start at zero when they are sliced
+    pub fn values_range(&self) -> std::ops::Range<usize> {
+        let start = self.offsets.first().map_or(0, |x| x.to_usize());
+        let end = self.offsets.last().map_or(0, |x| x.to_usize());
+        start..end
+    }
 }
 
 fn write_levels_v1<F: FnOnce(&mut Vec<u8>) -> Result<()>>(
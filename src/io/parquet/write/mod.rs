@@ -1,7 +1,10 @@
 //! APIs to write to Parquet format.
 mod binary;
+mod bloom_filter;
 mod boolean;
 mod dictionary;
+#[cfg(feature = "io_parquet_encryption")]
+mod encryption;
 mod file;
 mod fixed_len_bytes;
 mod levels;
@@ -9,6 +12,7 @@ mod primitive;
 mod row_group;
 mod schema;
 mod sink;
+mod stream;
 mod utf8;
 mod utils;
 
@@ -42,16 +46,23 @@ pub struct WriteOptions {
     /// The page and file version to use
     pub version: Version,
     /// The compression to apply to every page
+    ///
+    /// LZ4 is written as [`CompressionOptions::Lz4Raw`]; the deprecated [`CompressionOptions::Lz4`]
+    /// codec is only supported when reading.
     pub compression: CompressionOptions,
 }
 
 use crate::compute::aggregate::estimated_bytes_size;
-pub use file::FileWriter;
-pub use row_group::{row_group_iter, RowGroupIterator};
+pub use bloom_filter::BloomFilterOptions;
+#[cfg(feature = "io_parquet_bloom_filter")]
+pub use bloom_filter::BloomFilters;
+pub use file::{FileWriter, SortingColumn};
+pub use row_group::{row_group_iter, ColumnOptions, RowGroupIterator};
 pub use schema::to_parquet_type;
 pub use sink::FileSink;
+pub use stream::FileStreamer;
 
-pub(self) fn decimal_length_from_precision(precision: usize) -> usize {
+pub(crate) fn decimal_length_from_precision(precision: usize) -> usize {
     // digits = floor(log_10(2^(8*n - 1) - 1))
     // ceil(digits) = log10(2^(8*n - 1) - 1)
     // 10^ceil(digits) = 2^(8*n - 1) - 1
@@ -80,7 +91,11 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
         (encoding, data_type),
         (Encoding::Plain, _)
             | (
-                Encoding::DeltaLengthByteArray,
+                Encoding::ByteStreamSplit,
+                DataType::Float32 | DataType::Float64
+            )
+            | (
+                Encoding::DeltaLengthByteArray | Encoding::DeltaByteArray,
                 DataType::Binary | DataType::LargeBinary | DataType::Utf8 | DataType::LargeUtf8,
             )
             | (Encoding::RleDictionary, DataType::Dictionary(_, _, _))
@@ -163,37 +178,44 @@ pub fn array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             get_primitive(type_)?,
+            encoding,
         ),
         DataType::UInt16 => primitive::array_to_page::<u16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             get_primitive(type_)?,
+            encoding,
         ),
         DataType::UInt32 => primitive::array_to_page::<u32, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             get_primitive(type_)?,
+            encoding,
         ),
         DataType::UInt64 => primitive::array_to_page::<u64, i64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             get_primitive(type_)?,
+            encoding,
         ),
         DataType::Int8 => primitive::array_to_page::<i8, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             get_primitive(type_)?,
+            encoding,
         ),
         DataType::Int16 => primitive::array_to_page::<i16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             get_primitive(type_)?,
+            encoding,
         ),
         DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
             primitive::array_to_page::<i32, i32>(
                 array.as_any().downcast_ref().unwrap(),
                 options,
                 get_primitive(type_)?,
+                encoding,
             )
         }
         DataType::Int64
@@ -204,16 +226,19 @@ pub fn array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             get_primitive(type_)?,
+            encoding,
         ),
         DataType::Float32 => primitive::array_to_page::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             get_primitive(type_)?,
+            encoding,
         ),
         DataType::Float64 => primitive::array_to_page::<f64, f64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             get_primitive(type_)?,
+            encoding,
         ),
         DataType::Utf8 => utf8::array_to_page::<i32>(
             array.as_any().downcast_ref().unwrap(),
@@ -241,7 +266,7 @@ pub fn array_to_page(
         ),
         DataType::Null => {
             let array = Int32Array::new_null(DataType::Int32, array.len());
-            primitive::array_to_page::<i32, i32>(&array, options, get_primitive(type_)?)
+            primitive::array_to_page::<i32, i32>(&array, options, get_primitive(type_)?, encoding)
         }
         DataType::Interval(IntervalUnit::YearMonth) => {
             let type_ = get_primitive(type_)?;
@@ -319,7 +344,7 @@ pub fn array_to_page(
 
                 let array =
                     PrimitiveArray::<i32>::new(DataType::Int32, values, array.validity().cloned());
-                primitive::array_to_page::<i32, i32>(&array, options, type_)
+                primitive::array_to_page::<i32, i32>(&array, options, type_, encoding)
             } else if precision <= 18 {
                 let values = array
                     .values()
@@ -330,7 +355,7 @@ pub fn array_to_page(
 
                 let array =
                     PrimitiveArray::<i64>::new(DataType::Int64, values, array.validity().cloned());
-                primitive::array_to_page::<i64, i64>(&array, options, type_)
+                primitive::array_to_page::<i64, i64>(&array, options, type_, encoding)
             } else {
                 let size = decimal_length_from_precision(precision);
 
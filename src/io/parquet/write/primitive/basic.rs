@@ -10,7 +10,7 @@ use super::super::utils;
 use super::super::WriteOptions;
 use crate::{
     array::{Array, PrimitiveArray},
-    error::Result,
+    error::{ArrowError, Result},
     io::parquet::read::schema::is_nullable,
     types::NativeType as ArrowNativeType,
 };
@@ -38,10 +38,32 @@ where
     }
 }
 
+/// Encodes the (non-null) values of `array` as `BYTE_STREAM_SPLIT`: the first byte of every
+/// value, followed by the second byte of every value, and so on.
+pub(crate) fn encode_byte_stream_split<T, R>(
+    array: &PrimitiveArray<T>,
+    is_optional: bool,
+    buffer: &mut Vec<u8>,
+) where
+    T: ArrowNativeType,
+    R: NativeType,
+    T: num_traits::AsPrimitive<R>,
+{
+    let mut plain = vec![];
+    encode_plain(array, is_optional, &mut plain);
+
+    let size = std::mem::size_of::<R>();
+    buffer.reserve(plain.len());
+    (0..size).for_each(|byte| {
+        buffer.extend(plain.iter().skip(byte).step_by(size));
+    });
+}
+
 pub fn array_to_page<T, R>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
     type_: PrimitiveType,
+    encoding: Encoding,
 ) -> Result<DataPage>
 where
     T: ArrowNativeType,
@@ -63,7 +85,17 @@ where
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer);
+    match encoding {
+        Encoding::Plain => encode_plain(array, is_optional, &mut buffer),
+        Encoding::ByteStreamSplit => encode_byte_stream_split(array, is_optional, &mut buffer),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Datatype {:?} cannot be encoded by {:?} encoding",
+                array.data_type(),
+                encoding
+            )))
+        }
+    }
 
     let statistics = if options.write_statistics {
         Some(serialize_statistics(&build_statistics(
@@ -84,7 +116,7 @@ where
         statistics,
         type_,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
 
@@ -1,18 +1,93 @@
+use std::collections::{HashMap, VecDeque};
+
 use parquet2::schema::types::ParquetType;
 use parquet2::write::Compressor;
 use parquet2::FallibleStreamingIterator;
 
 use crate::{
-    array::Array,
+    array::{Array, DictionaryArray},
     chunk::Chunk,
-    datatypes::Schema,
+    compute::{aggregate::estimated_bytes_size, concatenate::concatenate, take::take},
+    datatypes::{DataType, Schema},
     error::{ArrowError, Result},
 };
 
 use super::{
-    array_to_pages, to_parquet_schema, DynIter, DynStreamingIterator, Encoding, RowGroupIter,
-    SchemaDescriptor, WriteOptions,
+    array_to_pages, to_parquet_schema, BloomFilterOptions, CompressionOptions, DynIter,
+    DynStreamingIterator, EncodedPage, Encoding, RowGroupIter, SchemaDescriptor, WriteOptions,
 };
+#[cfg(feature = "io_parquet_bloom_filter")]
+use super::{bloom_filter, BloomFilters};
+
+/// Options of a column that override the [`WriteOptions`] and the encoding with which a
+/// [`RowGroupIterator`] writes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ColumnOptions {
+    /// The compression of its pages, or `None` to use [`WriteOptions::compression`]
+    pub compression: Option<CompressionOptions>,
+    /// Its encoding, or `None` to use the encoding passed to [`RowGroupIterator::try_new`]
+    pub encoding: Option<Encoding>,
+    /// The maximum number of values of the dictionary of a dictionary-encoded column.
+    /// Dictionary arrays with more values are plain-encoded instead. `None` means no maximum.
+    pub max_dictionary_cardinality: Option<usize>,
+    /// The maximum size in bytes of the dictionary page of a dictionary-encoded column, as
+    /// estimated from the in-memory size of the values of the dictionary. Dictionary arrays
+    /// with larger values are plain-encoded instead. `None` means no maximum.
+    pub max_dictionary_page_size: Option<usize>,
+    /// The bloom filter to write for the column, or `None` to write none. Bloom filters are
+    /// yielded by [`RowGroupIterator::next_with_bloom_filters`] and require the feature
+    /// `io_parquet_bloom_filter`. Bloom filters of nested fields and of types without bloom
+    /// filters (booleans, nulls, intervals and 256-bit decimals) are not written.
+    pub bloom_filter: Option<BloomFilterOptions>,
+}
+
+// the limits of the dictionary of a dictionary-encoded column, above which it is plain-encoded
+#[derive(Debug, Clone, Copy, Default)]
+struct DictionaryLimits {
+    max_cardinality: Option<usize>,
+    max_page_size: Option<usize>,
+}
+
+impl DictionaryLimits {
+    fn is_exceeded_by(&self, values: &dyn Array) -> bool {
+        self.max_cardinality
+            .map(|max| values.len() > max)
+            .unwrap_or(false)
+            || self
+                .max_page_size
+                .map(|max| estimated_bytes_size(values) > max)
+                .unwrap_or(false)
+    }
+}
+
+// the pages of `array`, plain-encoding the values of dictionary arrays whose dictionary exceeds
+// `limits`
+fn column_to_pages(
+    array: &dyn Array,
+    type_: ParquetType,
+    options: WriteOptions,
+    encoding: Encoding,
+    limits: DictionaryLimits,
+) -> Result<DynIter<'static, Result<EncodedPage>>> {
+    if let (
+        DataType::Dictionary(key_type, _, _),
+        Encoding::PlainDictionary | Encoding::RleDictionary,
+    ) = (array.data_type(), encoding)
+    {
+        let values = match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            if limits.is_exceeded_by(array.values().as_ref()) {
+                Some(take(array.values().as_ref(), array.keys())?)
+            } else {
+                None
+            }
+        });
+        if let Some(values) = values {
+            return array_to_pages(values.as_ref(), type_, options, Encoding::Plain);
+        }
+    }
+    array_to_pages(array, type_, options, encoding)
+}
 
 /// Maps a [`Chunk`] and parquet-specific options to an [`RowGroupIter`] used to
 /// write to parquet
@@ -21,21 +96,36 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
     encodings: Vec<Encoding>,
     fields: Vec<ParquetType>,
     options: WriteOptions,
+) -> RowGroupIter<'static, ArrowError> {
+    let options = vec![options; encodings.len()];
+    let limits = vec![DictionaryLimits::default(); encodings.len()];
+    columns_iter(chunk, encodings, fields, options, limits)
+}
+
+// same as `row_group_iter` but with the options of each column
+fn columns_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
+    chunk: Chunk<A>,
+    encodings: Vec<Encoding>,
+    fields: Vec<ParquetType>,
+    options: Vec<WriteOptions>,
+    limits: Vec<DictionaryLimits>,
 ) -> RowGroupIter<'static, ArrowError> {
     DynIter::new(
         chunk
             .into_arrays()
             .into_iter()
-            .zip(fields.into_iter())
-            .zip(encodings.into_iter())
-            .map(move |((array, type_), encoding)| {
-                array_to_pages(array.as_ref(), type_, options, encoding).map(move |pages| {
-                    let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
-                    let compressed_pages =
-                        Compressor::new(encoded_pages, options.compression, vec![])
-                            .map_err(ArrowError::from);
-                    DynStreamingIterator::new(compressed_pages)
-                })
+            .zip(fields.into_iter().zip(limits))
+            .zip(encodings.into_iter().zip(options))
+            .map(move |((array, (type_, limits)), (encoding, options))| {
+                column_to_pages(array.as_ref(), type_, options, encoding, limits).map(
+                    move |pages| {
+                        let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
+                        let compressed_pages =
+                            Compressor::new(encoded_pages, options.compression, vec![])
+                                .map_err(ArrowError::from);
+                        DynStreamingIterator::new(compressed_pages)
+                    },
+                )
             }),
     )
 }
@@ -45,9 +135,17 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
 /// Use it to create an iterator consumable by the parquet's API.
 pub struct RowGroupIterator<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> {
     iter: I,
-    options: WriteOptions,
+    options: Vec<WriteOptions>,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Encoding>,
+    dictionary_limits: Vec<DictionaryLimits>,
+    target_size: Option<usize>,
+    // the chunks (or their remainders) not yet written when row groups are sized by bytes,
+    // with their estimated size. The size of a remainder is prorated from the size of its chunk,
+    // since the estimated size of a slice counts the whole buffers of e.g. utf8 arrays.
+    pending: VecDeque<(Chunk<Box<dyn Array>>, usize)>,
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    bloom_filters: Vec<Option<BloomFilterOptions>>,
 }
 
 impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGroupIterator<A, I> {
@@ -64,35 +162,287 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
 
         Ok(Self {
             iter,
-            options,
+            options: vec![options; encodings.len()],
             parquet_schema,
+            dictionary_limits: vec![DictionaryLimits::default(); encodings.len()],
+            #[cfg(feature = "io_parquet_bloom_filter")]
+            bloom_filters: vec![None; encodings.len()],
             encodings,
+            target_size: None,
+            pending: VecDeque::new(),
         })
     }
 
+    /// Writes row groups of about `size` bytes instead of a row group per [`Chunk`].
+    ///
+    /// Chunks are combined and sliced so that every row group has about `size` bytes, as
+    /// estimated from the in-memory size of its arrays (i.e. before encoding and compression).
+    /// This is useful when the width of rows varies greatly.
+    pub fn with_target_row_group_size(mut self, size: usize) -> Self {
+        self.target_size = Some(size);
+        self
+    }
+
+    /// Overrides the compression and encoding of the columns in `columns`, e.g. to not
+    /// compress a column of already compressed blobs or to plain-encode a column of ids, and
+    /// limits the size of their dictionaries, e.g. to plain-encode the row groups in which a
+    /// dictionary-encoded column of strings has a high cardinality.
+    ///
+    /// Columns are identified by their path in the parquet schema (see
+    /// [`SchemaDescriptor::columns`]), e.g. `["a"]` for a field `a` of a primitive type or
+    /// `["a", "list", "item"]` for a field `a` of a list type. Only the columns of fields with a
+    /// single leaf (e.g. of lists of a primitive type, but not of structs) can have options.
+    /// # Errors
+    /// This function errors when a path in `columns` is not the path of a column of the schema,
+    /// when it is the path of a leaf of a field with more than one leaf and when a bloom filter
+    /// is requested without the feature `io_parquet_bloom_filter`.
+    pub fn with_column_options(
+        mut self,
+        columns: HashMap<Vec<String>, ColumnOptions>,
+    ) -> Result<Self> {
+        // the index of the field of each leaf column
+        let fields = self
+            .parquet_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .flat_map(|(index, field)| (0..num_leaves(field)).map(move |_| index))
+            .collect::<Vec<_>>();
+        for (path, column) in columns {
+            let index = self
+                .parquet_schema
+                .columns()
+                .iter()
+                .position(|column| column.path_in_schema == path)
+                .map(|leaf| fields[leaf])
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "The schema has no column with path {:?}",
+                        path
+                    ))
+                })?;
+            // the options are applied to the field, which would also apply them to its other leaves
+            if num_leaves(&self.parquet_schema.fields()[index]) > 1 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The column with path {:?} is one of several leaves of its field, whose options can't be set",
+                    path
+                )));
+            }
+            if let Some(compression) = column.compression {
+                self.options[index].compression = compression;
+            }
+            if let Some(encoding) = column.encoding {
+                self.encodings[index] = encoding;
+            }
+            if let Some(bloom_filter) = column.bloom_filter {
+                #[cfg(feature = "io_parquet_bloom_filter")]
+                {
+                    self.bloom_filters[index] = Some(bloom_filter);
+                }
+                #[cfg(not(feature = "io_parquet_bloom_filter"))]
+                {
+                    let _ = bloom_filter;
+                    return Err(ArrowError::InvalidArgumentError(
+                        "Writing bloom filters requires the feature io_parquet_bloom_filter"
+                            .to_string(),
+                    ));
+                }
+            }
+            self.dictionary_limits[index] = DictionaryLimits {
+                max_cardinality: column.max_dictionary_cardinality,
+                max_page_size: column.max_dictionary_page_size,
+            };
+        }
+        Ok(self)
+    }
+
     /// Returns the [`SchemaDescriptor`] of the [`RowGroupIterator`].
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
     }
 }
 
+fn num_leaves(type_: &ParquetType) -> usize {
+    match type_ {
+        ParquetType::PrimitiveType(_) => 1,
+        ParquetType::GroupType { fields, .. } => fields.iter().map(num_leaves).sum(),
+    }
+}
+
+fn chunk_size(chunk: &Chunk<Box<dyn Array>>) -> usize {
+    chunk
+        .arrays()
+        .iter()
+        .map(|array| estimated_bytes_size(array.as_ref()))
+        .sum()
+}
+
+impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGroupIterator<A, I> {
+    // the bitsets of the bloom filters of each leaf column of `chunk`
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    fn bloom_filters<B: AsRef<dyn Array>>(&self, chunk: &Chunk<B>) -> BloomFilters {
+        let mut bitsets = vec![];
+        for ((array, options), type_) in chunk
+            .arrays()
+            .iter()
+            .zip(self.bloom_filters.iter())
+            .zip(self.parquet_schema.fields())
+        {
+            match (options, type_) {
+                (Some(options), ParquetType::PrimitiveType(_)) => {
+                    bitsets.push(bloom_filter::build(array.as_ref(), options))
+                }
+                _ => bitsets.extend((0..num_leaves(type_)).map(|_| None)),
+            }
+        }
+        BloomFilters { bitsets }
+    }
+
+    fn row_group<B: AsRef<dyn Array> + 'static + Send + Sync>(
+        &self,
+        chunk: Chunk<B>,
+    ) -> RowGroupIter<'static, ArrowError> {
+        columns_iter(
+            chunk,
+            self.encodings.clone(),
+            self.parquet_schema.fields().to_vec(),
+            self.options.clone(),
+            self.dictionary_limits.clone(),
+        )
+    }
+
+    // the rows of the next row group of about `target_size` bytes
+    fn next_sized(&mut self, target_size: usize) -> Option<Result<Chunk<Box<dyn Array>>>> {
+        let mut size = self.pending.iter().map(|(_, bytes)| bytes).sum::<usize>();
+        while size < target_size {
+            match self.iter.next() {
+                Some(Ok(chunk)) => {
+                    let chunk = Chunk::new(
+                        chunk
+                            .into_arrays()
+                            .into_iter()
+                            .map(|array| array.as_ref().to_boxed())
+                            .collect(),
+                    );
+                    let bytes = chunk_size(&chunk);
+                    size += bytes;
+                    self.pending.push_back((chunk, bytes));
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => break,
+            }
+        }
+        if self.pending.is_empty() {
+            return None;
+        }
+
+        let mut chunks = vec![];
+        let mut size = 0;
+        while let Some((chunk, chunk_bytes)) = self.pending.pop_front() {
+            let length = chunk.len();
+            if length == 0 || size + chunk_bytes <= target_size {
+                size += chunk_bytes;
+                chunks.push(chunk);
+                continue;
+            }
+
+            // the chunk is sliced at the row reaching `target_size`
+            let row_size = (chunk_bytes / length).max(1);
+            let mut rows = (target_size - size.min(target_size)) / row_size;
+            if chunks.is_empty() {
+                rows = rows.max(1)
+            }
+            if rows < length {
+                let arrays = chunk.arrays();
+                let remainder_bytes = chunk_bytes - chunk_bytes * rows / length;
+                self.pending.push_front((
+                    Chunk::new(
+                        arrays
+                            .iter()
+                            .map(|array| array.slice(rows, length - rows))
+                            .collect(),
+                    ),
+                    remainder_bytes,
+                ));
+                if rows > 0 {
+                    chunks.push(Chunk::new(
+                        arrays.iter().map(|array| array.slice(0, rows)).collect(),
+                    ));
+                }
+            } else {
+                chunks.push(chunk);
+            }
+            break;
+        }
+
+        if chunks.len() == 1 {
+            return chunks.pop().map(Ok);
+        }
+        let columns = (0..self.encodings.len())
+            .map(|column| {
+                let arrays = chunks
+                    .iter()
+                    .map(|chunk| chunk.arrays()[column].as_ref())
+                    .collect::<Vec<_>>();
+                concatenate(&arrays)
+            })
+            .collect::<Result<Vec<_>>>();
+        Some(columns.and_then(Chunk::try_new))
+    }
+}
+
+impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chunk<A>>>>
+    RowGroupIterator<A, I>
+{
+    /// Returns the next row group together with the bloom filters of its columns (see
+    /// [`ColumnOptions::bloom_filter`]), to write via
+    /// [`FileWriter::write_with_bloom_filters`](super::FileWriter::write_with_bloom_filters).
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+    pub fn next_with_bloom_filters(
+        &mut self,
+    ) -> Option<Result<(RowGroupIter<'static, ArrowError>, BloomFilters)>> {
+        if let Some(target_size) = self.target_size {
+            return self.next_sized(target_size).map(|maybe_chunk| {
+                let chunk = maybe_chunk?;
+                let bloom_filters = self.bloom_filters(&chunk);
+                Ok((self.row_group(chunk), bloom_filters))
+            });
+        }
+
+        self.iter.next().map(|maybe_chunk| {
+            let chunk = maybe_chunk?;
+            let bloom_filters = self.bloom_filters(&chunk);
+            Ok((self.row_group(chunk), bloom_filters))
+        })
+    }
+}
+
 impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chunk<A>>>> Iterator
     for RowGroupIterator<A, I>
 {
     type Item = Result<RowGroupIter<'static, ArrowError>>;
 
+    /// # Errors
+    /// Yields an error when bloom filters were requested, since they are only yielded by
+    /// [`RowGroupIterator::next_with_bloom_filters`].
     fn next(&mut self) -> Option<Self::Item> {
-        let options = self.options;
+        #[cfg(feature = "io_parquet_bloom_filter")]
+        if self.bloom_filters.iter().any(|x| x.is_some()) {
+            return Some(Err(ArrowError::InvalidArgumentError(
+                "The bloom filters of the row groups are only yielded by `next_with_bloom_filters`"
+                    .to_string(),
+            )));
+        }
 
-        self.iter.next().map(|maybe_chunk| {
-            let chunk = maybe_chunk?;
-            let encodings = self.encodings.clone();
-            Ok(row_group_iter(
-                chunk,
-                encodings,
-                self.parquet_schema.fields().to_vec(),
-                options,
-            ))
-        })
+        if let Some(target_size) = self.target_size {
+            return self
+                .next_sized(target_size)
+                .map(|maybe_chunk| Ok(self.row_group(maybe_chunk?)));
+        }
+
+        self.iter
+            .next()
+            .map(|maybe_chunk| Ok(self.row_group(maybe_chunk?)))
     }
 }
@@ -17,7 +17,7 @@ use crate::{
     io::parquet::write::decimal_length_from_precision,
 };
 
-use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::{ARROW_SCHEMA_META_KEY, PARQUET_FIELD_ID_META_KEY};
 
 pub fn schema_to_metadata_key(schema: &Schema) -> KeyValue {
     let serialized_schema = schema_to_bytes(schema, &default_ipc_fields(&schema.fields));
@@ -38,8 +38,40 @@ pub fn schema_to_metadata_key(schema: &Schema) -> KeyValue {
     }
 }
 
+// the id of the parquet field of `field`, declared in its metadata
+fn field_id(field: &Field) -> Result<Option<i32>> {
+    field
+        .metadata
+        .get(PARQUET_FIELD_ID_META_KEY)
+        .map(|id| {
+            id.parse::<i32>().map_err(|_| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The field id \"{}\" of the field \"{}\" is not a 32-bit integer",
+                    id, field.name
+                ))
+            })
+        })
+        .transpose()
+}
+
 /// Creates a [`ParquetType`] from a [`Field`].
+///
+/// The parquet field has the id declared in the metadata of `field` under
+/// [`PARQUET_FIELD_ID_META_KEY`](crate::io::parquet::PARQUET_FIELD_ID_META_KEY), if any.
+/// # Error
+/// This function errors when the data type of `field` can't be written to parquet or when
+/// its id is not a 32-bit integer.
 pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
+    let id = field_id(field)?;
+    let mut type_ = to_parquet_type_without_id(field)?;
+    match &mut type_ {
+        ParquetType::PrimitiveType(primitive) => primitive.field_info.id = id,
+        ParquetType::GroupType { field_info, .. } => field_info.id = id,
+    }
+    Ok(type_)
+}
+
+fn to_parquet_type_without_id(field: &Field) -> Result<ParquetType> {
     let name = field.name.clone();
     let repetition = if field.is_nullable {
         Repetition::Optional
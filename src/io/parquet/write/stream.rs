@@ -0,0 +1,158 @@
+use futures::{AsyncWrite, AsyncWriteExt};
+
+use parquet2::metadata::{KeyValue, SchemaDescriptor};
+use parquet2::write::RowGroupIter;
+
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+#[cfg(feature = "io_parquet_encryption")]
+use crate::io::parquet::encryption::FileEncryptionProperties;
+
+#[cfg(feature = "io_parquet_bloom_filter")]
+use super::BloomFilters;
+use super::{FileWriter, SortingColumn, WriteOptions};
+
+/// An interface to write a parquet file to an [`AsyncWrite`], the async counterpart of
+/// [`FileWriter`].
+///
+/// It writes the same files as [`FileWriter`] (including column and offset indexes, sorting
+/// columns, bloom filters and encryption): each row group (and the footer) is encoded by a
+/// [`FileWriter`] into memory and then written to the [`AsyncWrite`], without blocking the
+/// executor on IO.
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use futures::io::Cursor;
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::io::parquet::write::*;
+/// # futures::executor::block_on(async move {
+///
+/// let schema = Schema::from(vec![Field::new("values", DataType::Int32, true)]);
+/// let options = WriteOptions {
+///     write_statistics: true,
+///     compression: CompressionOptions::Uncompressed,
+///     version: Version::V2,
+/// };
+///
+/// let mut writer = FileStreamer::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+/// writer.start().await?;
+/// for i in 0..3 {
+///     let values = Int32Array::from(&[Some(i), None]);
+///     let chunk = Chunk::new(vec![Arc::new(values) as Arc<dyn Array>]);
+///     let row_groups = RowGroupIterator::try_new(
+///         vec![Ok(chunk)].into_iter(),
+///         &schema,
+///         options,
+///         vec![Encoding::Plain],
+///     )?;
+///     for group in row_groups {
+///         writer.write(group?).await?;
+///     }
+/// }
+/// let (_size, buffer) = writer.end(None).await?;
+/// assert!(!buffer.into_inner().is_empty());
+/// # arrow2::error::Result::Ok(())
+/// # }).unwrap();
+/// ```
+pub struct FileStreamer<W: AsyncWrite + Unpin + Send> {
+    writer: FileWriter<Vec<u8>>,
+    sink: W,
+}
+
+// Accessors
+impl<W: AsyncWrite + Unpin + Send> FileStreamer<W> {
+    /// The options assigned to the file
+    pub fn options(&self) -> WriteOptions {
+        self.writer.options()
+    }
+
+    /// The [`SchemaDescriptor`] assigned to this file
+    pub fn parquet_schema(&self) -> &SchemaDescriptor {
+        self.writer.parquet_schema()
+    }
+
+    /// The [`Schema`] assigned to this file
+    pub fn schema(&self) -> &Schema {
+        self.writer.schema()
+    }
+}
+
+impl<W: AsyncWrite + Unpin + Send> FileStreamer<W> {
+    /// Returns a new [`FileStreamer`].
+    /// # Error
+    /// If it is unable to derive a parquet schema from [`Schema`].
+    pub fn try_new(writer: W, schema: Schema, options: WriteOptions) -> Result<Self> {
+        Ok(Self {
+            writer: FileWriter::try_new(vec![], schema, options)?,
+            sink: writer,
+        })
+    }
+
+    /// Returns a new [`FileStreamer`] of a file encrypted following `properties`.
+    /// # Error
+    /// See [`FileWriter::try_new_with_encryption`].
+    #[cfg(feature = "io_parquet_encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_encryption")))]
+    pub fn try_new_with_encryption(
+        writer: W,
+        schema: Schema,
+        options: WriteOptions,
+        properties: FileEncryptionProperties,
+    ) -> Result<Self> {
+        Ok(Self {
+            writer: FileWriter::try_new_with_encryption(vec![], schema, options, properties)?,
+            sink: writer,
+        })
+    }
+
+    /// Declares that the rows of every row group are sorted by `columns`.
+    /// # Error
+    /// See [`FileWriter::set_sorting_columns`].
+    pub fn set_sorting_columns(&mut self, columns: Vec<SortingColumn>) -> Result<()> {
+        self.writer.set_sorting_columns(columns)
+    }
+
+    /// Writes the header of the file
+    pub async fn start(&mut self) -> Result<()> {
+        self.writer.start()?;
+        self.flush().await
+    }
+
+    /// Writes a row group to the file.
+    pub async fn write(&mut self, row_group: RowGroupIter<'_, ArrowError>) -> Result<()> {
+        self.writer.write(row_group)?;
+        self.flush().await
+    }
+
+    /// Writes a row group to the file together with the bloom filters of its columns.
+    /// # Error
+    /// See [`FileWriter::write_with_bloom_filters`].
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+    pub async fn write_with_bloom_filters(
+        &mut self,
+        row_group: RowGroupIter<'_, ArrowError>,
+        bloom_filters: BloomFilters,
+    ) -> Result<()> {
+        self.writer
+            .write_with_bloom_filters(row_group, bloom_filters)?;
+        self.flush().await
+    }
+
+    /// Writes the footer of the parquet file. Returns the total size of the file and the
+    /// inner writer.
+    pub async fn end(mut self, key_value_metadata: Option<Vec<KeyValue>>) -> Result<(u64, W)> {
+        let size = self.writer.end(key_value_metadata)?;
+        self.flush().await?;
+        Ok((size, self.sink))
+    }
+
+    // writes what the `FileWriter` wrote to the `AsyncWrite`
+    async fn flush(&mut self) -> Result<()> {
+        let written = self.writer.take_written();
+        self.sink.write_all(&written).await?;
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use parquet2::{
     statistics::{serialize_statistics, BinaryStatistics, ParquetStatistics, Statistics},
 };
 
-use super::super::binary::{encode_delta, ord_binary};
+use super::super::binary::{encode_delta, encode_delta_byte_array, ord_binary};
 use super::super::utils;
 use super::super::WriteOptions;
 use crate::{
@@ -67,6 +67,13 @@ pub fn array_to_page<O: Offset>(
             is_optional,
             &mut buffer,
         ),
+        Encoding::DeltaByteArray => {
+            if is_optional {
+                encode_delta_byte_array(array.iter().flatten().map(|x| x.as_bytes()), &mut buffer)
+            } else {
+                encode_delta_byte_array(array.values_iter().map(|x| x.as_bytes()), &mut buffer)
+            }
+        }
         _ => {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "Datatype {:?} cannot be encoded by {:?} encoding",
@@ -0,0 +1,50 @@
+use std::marker::PhantomData;
+
+use crate::{array::ViewType, datatypes::DataType};
+
+use super::Scalar;
+
+/// The implementation of [`Scalar`] for view types, semantically equivalent to [`Option<T::Owned>`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinaryViewScalar<T: ViewType + ?Sized> {
+    value: Option<T::Owned>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ViewType + ?Sized> BinaryViewScalar<T> {
+    /// Returns a new [`BinaryViewScalar`]
+    #[inline]
+    pub fn new(value: Option<&T>) -> Self {
+        Self {
+            value: value.map(|x| x.to_owned_value()),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the value irrespectively of the validity.
+    #[inline]
+    pub fn value(&self) -> Option<&T::Owned> {
+        self.value.as_ref()
+    }
+}
+
+impl<T: ViewType + ?Sized> Scalar for BinaryViewScalar<T> {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.value.is_some()
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        if T::IS_UTF8 {
+            &DataType::Utf8View
+        } else {
+            &DataType::BinaryView
+        }
+    }
+}
@@ -45,6 +45,8 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
         LargeUtf8 => dyn_eq!(Utf8Scalar<i64>, lhs, rhs),
         Binary => dyn_eq!(BinaryScalar<i32>, lhs, rhs),
         LargeBinary => dyn_eq!(BinaryScalar<i64>, lhs, rhs),
+        BinaryView => dyn_eq!(BinaryViewScalar<[u8]>, lhs, rhs),
+        Utf8View => dyn_eq!(BinaryViewScalar<str>, lhs, rhs),
         List => dyn_eq!(ListScalar<i32>, lhs, rhs),
         LargeList => dyn_eq!(ListScalar<i64>, lhs, rhs),
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
@@ -54,6 +56,9 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
         FixedSizeBinary => dyn_eq!(FixedSizeBinaryScalar, lhs, rhs),
         FixedSizeList => dyn_eq!(FixedSizeListScalar, lhs, rhs),
         Union => dyn_eq!(UnionScalar, lhs, rhs),
-        Map => unimplemented!("{:?}", Map),
+        Map => dyn_eq!(MapScalar, lhs, rhs),
+        // the scalars of run-end encoded arrays are the scalars of their values (see `new_scalar`),
+        // so there is no run-end encoded scalar to compare
+        RunEndEncoded(_) => false,
     }
 }
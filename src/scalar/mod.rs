@@ -13,10 +13,14 @@ mod utf8;
 pub use utf8::*;
 mod binary;
 pub use binary::*;
+mod binview;
+pub use binview::*;
 mod boolean;
 pub use boolean::*;
 mod list;
 pub use list::*;
+mod map;
+pub use map::*;
 mod null;
 pub use null::*;
 mod struct_;
@@ -80,6 +84,21 @@ macro_rules! dyn_new_list {
     }};
 }
 
+macro_rules! dyn_new_binview {
+    ($array:expr, $index:expr, $type:ty) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<BinaryViewArrayGeneric<$type>>()
+            .unwrap();
+        let value = if array.is_valid($index) {
+            Some(array.value($index))
+        } else {
+            None
+        };
+        Box::new(BinaryViewScalar::<$type>::new(value))
+    }};
+}
+
 /// creates a new [`Scalar`] from an [`Array`].
 pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
     use PhysicalType::*;
@@ -110,6 +129,8 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
         LargeUtf8 => dyn_new_utf8!(array, index, i64),
         Binary => dyn_new_binary!(array, index, i32),
         LargeBinary => dyn_new_binary!(array, index, i64),
+        BinaryView => dyn_new_binview!(array, index, [u8]),
+        Utf8View => dyn_new_binview!(array, index, str),
         List => dyn_new_list!(array, index, i32),
         LargeList => dyn_new_list!(array, index, i64),
         Struct => {
@@ -154,7 +175,15 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
                 array.value(index).into(),
             ))
         }
-        Map => todo!(),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let value = if array.is_valid(index) {
+                Some(array.value(index).into())
+            } else {
+                None
+            };
+            Box::new(MapScalar::new(array.data_type().clone(), value))
+        }
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             let array = array
                 .as_any()
@@ -170,5 +199,12 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
                 value,
             ))
         }),
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<RunEndEncodedArray<$T>>()
+                .unwrap();
+            array.value(index)
+        }),
     }
 }
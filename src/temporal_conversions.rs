@@ -2,7 +2,8 @@
 
 use chrono::{
     format::{parse, Parsed, StrftimeItems},
-    Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime,
+    Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset as _,
+    TimeZone as _,
 };
 
 use crate::error::Result;
@@ -327,71 +328,117 @@ pub fn utf8_to_naive_timestamp_ns<O: Offset>(
     PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(TimeUnit::Nanosecond, None))
 }
 
-fn add_month(year: i32, month: u32, months: i32) -> chrono::NaiveDate {
-    let new_year = (year * 12 + (month - 1) as i32 + months) / 12;
-    let new_month = (year * 12 + (month - 1) as i32 + months) % 12 + 1;
-    chrono::NaiveDate::from_ymd(new_year, new_month as u32, 1)
+/// Returns the date `months` after `date`, or `None` if it is out of range. The day is clamped
+/// to the last day of the resulting month, e.g. one month after 2021-01-31 is 2021-02-28.
+fn add_months(date: NaiveDate, months: i32) -> Option<NaiveDate> {
+    let months = (date.year() * 12 + date.month0() as i32).checked_add(months)?;
+    let (year, month) = (months.div_euclid(12), months.rem_euclid(12) as u32 + 1);
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let last_day = (28..=31)
+        .rev()
+        .find(|day| first.with_day(*day).is_some())
+        .unwrap();
+    first.with_day(date.day().min(last_day))
 }
 
-fn get_days_between_months(year: i32, month: u32, months: i32) -> i64 {
-    add_month(year, month, months)
-        .signed_duration_since(chrono::NaiveDate::from_ymd(year, month, 1))
-        .num_days()
+/// Adds the months and days of `interval` to the date of `datetime`, keeping its time.
+/// Returns `None` if the result is out of range.
+fn add_months_days(datetime: NaiveDateTime, interval: months_days_ns) -> Option<NaiveDateTime> {
+    let date = add_months(datetime.date(), interval.months())?
+        .checked_add_signed(chrono::Duration::days(interval.days() as i64))?;
+    Some(date.and_time(datetime.time()))
 }
 
-/// Adds an `interval` to a `timestamp` in `time_unit` units without timezone.
-#[inline]
-pub fn add_naive_interval(timestamp: i64, time_unit: TimeUnit, interval: months_days_ns) -> i64 {
-    // convert seconds to a DateTime of a given offset.
-    let datetime = match time_unit {
-        TimeUnit::Second => timestamp_s_to_datetime(timestamp),
-        TimeUnit::Millisecond => timestamp_ms_to_datetime(timestamp),
-        TimeUnit::Microsecond => timestamp_us_to_datetime(timestamp),
-        TimeUnit::Nanosecond => timestamp_ns_to_datetime(timestamp),
+/// Converts a timestamp in `time_unit` to [`NaiveDateTime`], or `None` if it is out of range.
+fn checked_timestamp_to_naive_datetime(
+    timestamp: i64,
+    time_unit: TimeUnit,
+) -> Option<NaiveDateTime> {
+    let units = match time_unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => MILLISECONDS,
+        TimeUnit::Microsecond => MICROSECONDS,
+        TimeUnit::Nanosecond => NANOSECONDS,
     };
+    let seconds = timestamp.div_euclid(units);
+    let nanoseconds = timestamp.rem_euclid(units) * (NANOSECONDS / units);
+    let days = seconds.div_euclid(SECONDS_IN_DAY);
+    let days = i32::try_from(days).ok()?.checked_add(EPOCH_DAYS_FROM_CE)?;
+    let time = NaiveTime::from_num_seconds_from_midnight_opt(
+        seconds.rem_euclid(SECONDS_IN_DAY) as u32,
+        nanoseconds as u32,
+    )?;
+    Some(NaiveDate::from_num_days_from_ce_opt(days)?.and_time(time))
+}
 
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days = get_days_between_months(datetime.year(), datetime.month(), interval.months())
-        + interval.days() as i64;
-
-    // add; no leap hours are considered
-    let new_datetime_tz = datetime
-        + chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
-
-    // convert back to the target unit
+/// Converts a [`chrono::DateTime`] to a timestamp in `time_unit`, or `None` if it overflows.
+fn checked_datetime_to_timestamp<T: chrono::TimeZone>(
+    datetime: &chrono::DateTime<T>,
+    time_unit: TimeUnit,
+) -> Option<i64> {
+    let seconds = datetime.timestamp();
+    let nanoseconds = datetime.timestamp_subsec_nanos() as i64;
     match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos(),
+        TimeUnit::Second => Some(seconds),
+        TimeUnit::Millisecond => seconds
+            .checked_mul(MILLISECONDS)?
+            .checked_add(nanoseconds / (NANOSECONDS / MILLISECONDS)),
+        TimeUnit::Microsecond => seconds
+            .checked_mul(MICROSECONDS)?
+            .checked_add(nanoseconds / (NANOSECONDS / MICROSECONDS)),
+        TimeUnit::Nanosecond => seconds.checked_mul(NANOSECONDS)?.checked_add(nanoseconds),
     }
 }
 
+/// Adds an `interval` to a `timestamp` in `time_unit` units without timezone.
+///
+/// The months of `interval` are added first, clamping the day to the last day of the resulting
+/// month (e.g. 2021-01-31 plus one month is 2021-02-28), followed by its days and nanoseconds.
+/// Returns `None` if the result is out of the range of `time_unit`.
+#[inline]
+pub fn add_naive_interval(
+    timestamp: i64,
+    time_unit: TimeUnit,
+    interval: months_days_ns,
+) -> Option<i64> {
+    let datetime = checked_timestamp_to_naive_datetime(timestamp, time_unit)?;
+
+    let datetime = add_months_days(datetime, interval)?
+        .checked_add_signed(chrono::Duration::nanoseconds(interval.ns()))?;
+
+    checked_datetime_to_timestamp(&chrono::Utc.from_utc_datetime(&datetime), time_unit)
+}
+
 /// Adds an `interval` to a `timestamp` in `time_unit` units and timezone `timezone`.
+///
+/// The months and days of `interval` are added to the local date and time in `timezone`, as in
+/// [`add_naive_interval`], so that e.g. one day after 12:00 is 12:00 even across a change of
+/// daylight saving time. Its nanoseconds are then added as elapsed time. A local time that is
+/// ambiguous resolves to its earliest instant, and one that does not exist (i.e. in the gap of
+/// a change of daylight saving time) is resolved with the offset of `timestamp`.
+/// Returns `None` if the result is out of the range of `time_unit`.
 #[inline]
 pub fn add_interval<T: chrono::TimeZone>(
     timestamp: i64,
     time_unit: TimeUnit,
     interval: months_days_ns,
     timezone: &T,
-) -> i64 {
-    // convert seconds to a DateTime of a given offset.
-    let datetime_tz = timestamp_to_datetime(timestamp, time_unit, timezone);
-
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days =
-        get_days_between_months(datetime_tz.year(), datetime_tz.month(), interval.months())
-            + interval.days() as i64;
-
-    // add; tz will take care of leap hours
-    let new_datetime_tz = datetime_tz
-        + chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
+) -> Option<i64> {
+    let datetime_tz =
+        timezone.from_utc_datetime(&checked_timestamp_to_naive_datetime(timestamp, time_unit)?);
+
+    let local = add_months_days(datetime_tz.naive_local(), interval)?;
+    let datetime_tz = match timezone.from_local_datetime(&local) {
+        LocalResult::Single(datetime) => datetime,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => {
+            let offset = datetime_tz.offset().fix().local_minus_utc();
+            let utc = local.checked_sub_signed(chrono::Duration::seconds(offset as i64))?;
+            timezone.from_utc_datetime(&utc)
+        }
+    };
+    let datetime_tz =
+        datetime_tz.checked_add_signed(chrono::Duration::nanoseconds(interval.ns()))?;
 
-    // convert back to the target unit
-    match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos(),
-    }
+    checked_datetime_to_timestamp(&datetime_tz, time_unit)
 }
@@ -1,5 +1,5 @@
 use std::convert::TryFrom;
-use std::ops::Neg;
+use std::ops::{Add, Neg, Sub};
 
 use bytemuck::{Pod, Zeroable};
 
@@ -178,7 +178,11 @@ impl NativeType for days_ms {
 }
 
 /// The in-memory representation of the MonthDayNano variant of the "Interval" logical type.
-#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Zeroable, Pod)]
+///
+/// Intervals are ordered by their months, then by their days and then by their nanoseconds.
+/// Since months and days do not have a fixed length, this order is not the order of their
+/// lengths: e.g. `1` month is larger than `40` days, and `1` day is larger than `25` hours.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroable, Pod)]
 #[allow(non_camel_case_types)]
 #[repr(C)]
 pub struct months_days_ns(i32, i32, i64);
@@ -207,6 +211,26 @@ impl months_days_ns {
     pub fn ns(&self) -> i64 {
         self.2
     }
+
+    /// Adds each component of the intervals, returning `None` if any of them overflows.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_add(rhs.months())?,
+            self.days().checked_add(rhs.days())?,
+            self.ns().checked_add(rhs.ns())?,
+        ))
+    }
+
+    /// Subtracts each component of the intervals, returning `None` if any of them overflows.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_sub(rhs.months())?,
+            self.days().checked_sub(rhs.days())?,
+            self.ns().checked_sub(rhs.ns())?,
+        ))
+    }
 }
 
 impl NativeType for months_days_ns {
@@ -325,3 +349,878 @@ impl Neg for months_days_ns {
         Self::new(-self.months(), -self.days(), -self.ns())
     }
 }
+
+impl Add for months_days_ns {
+    type Output = Self;
+
+    /// Adds each component of the intervals.
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.months() + rhs.months(),
+            self.days() + rhs.days(),
+            self.ns() + rhs.ns(),
+        )
+    }
+}
+
+impl Sub for months_days_ns {
+    type Output = Self;
+
+    /// Subtracts each component of the intervals.
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.months() - rhs.months(),
+            self.days() - rhs.days(),
+            self.ns() - rhs.ns(),
+        )
+    }
+}
+
+/// The in-memory representation of an half-precision floating point number as defined by
+/// IEEE 754 (`binary16`).
+///
+/// Arithmetic and comparisons are performed by converting to and from [`f32`], which
+/// represents every [`f16`] exactly.
+#[derive(Copy, Clone, Default, Zeroable, Pod)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct f16(u16);
+
+impl f16 {
+    /// A NaN (not a number)
+    pub const NAN: Self = Self(0x7E00);
+    /// Positive infinity
+    pub const INFINITY: Self = Self(0x7C00);
+    /// Negative infinity
+    pub const NEG_INFINITY: Self = Self(0xFC00);
+    /// The largest finite value, `65504`
+    pub const MAX: Self = Self(0x7BFF);
+    /// The smallest finite value, `-65504`
+    pub const MIN: Self = Self(0xFBFF);
+
+    /// Creates a [`f16`] from its bit representation.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Returns its bit representation.
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns whether this value is NaN.
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        (self.0 & 0x7C00) == 0x7C00 && (self.0 & 0x03FF) != 0
+    }
+
+    /// Converts itself to a [`f32`]. This conversion is lossless.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        let bits = self.0 as u32;
+        let sign = (bits & 0x8000) << 16;
+        let exp = bits & 0x7C00;
+        let man = bits & 0x03FF;
+
+        if exp == 0x7C00 {
+            // infinity or NaN (keeping the payload)
+            return f32::from_bits(sign | 0x7F80_0000 | (man << 13));
+        }
+        if exp == 0 {
+            if man == 0 {
+                return f32::from_bits(sign);
+            }
+            // subnormal: normalize the mantissa
+            let e = (man as u16).leading_zeros() - 6;
+            let exp = (127 - 15 - e) << 23;
+            let man = (man << (14 + e)) & 0x007F_FFFF;
+            return f32::from_bits(sign | exp | man);
+        }
+        let exp = ((exp >> 10) + 127 - 15) << 23;
+        f32::from_bits(sign | exp | (man << 13))
+    }
+
+    /// Converts a [`f32`] to the nearest [`f16`], rounding half to even. Values whose
+    /// magnitude is too large to be represented become infinity.
+    #[inline]
+    pub fn from_f32(value: f32) -> Self {
+        let x = value.to_bits();
+        let sign = (x & 0x8000_0000) >> 16;
+        let exp = x & 0x7F80_0000;
+        let man = x & 0x007F_FFFF;
+
+        if exp == 0x7F80_0000 {
+            // infinity or NaN; NaNs are kept quiet
+            let nan_bit = if man == 0 { 0 } else { 0x0200 };
+            return Self((sign | 0x7C00 | nan_bit | (man >> 13)) as u16);
+        }
+
+        let half_exp = ((exp >> 23) as i32) - 127 + 15;
+        if half_exp >= 0x1F {
+            // overflow
+            return Self((sign | 0x7C00) as u16);
+        }
+        if half_exp <= 0 {
+            if 14 - half_exp > 24 {
+                // underflow
+                return Self(sign as u16);
+            }
+            // subnormal
+            let man = man | 0x0080_0000;
+            let mut half_man = man >> (14 - half_exp);
+            let round_bit = 1 << (13 - half_exp);
+            if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+                half_man += 1;
+            }
+            return Self((sign | half_man) as u16);
+        }
+
+        let half = sign | ((half_exp as u32) << 10) | (man >> 13);
+        let round_bit = 0x0000_1000;
+        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+            // a carry into the exponent correctly rounds up to the next binade (or infinity)
+            Self((half + 1) as u16)
+        } else {
+            Self(half as u16)
+        }
+    }
+
+    /// Converts itself to a [`f64`]. This conversion is lossless.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    /// Returns the maximum of two numbers, ignoring NaN (like [`f32::max`]).
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().max(other.to_f32()))
+    }
+
+    /// Returns the minimum of two numbers, ignoring NaN (like [`f32::min`]).
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().min(other.to_f32()))
+    }
+}
+
+impl From<f16> for f32 {
+    #[inline]
+    fn from(value: f16) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<f16> for f64 {
+    #[inline]
+    fn from(value: f16) -> Self {
+        value.to_f64()
+    }
+}
+
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl std::fmt::Debug for f16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.to_f32())
+    }
+}
+
+impl std::fmt::Display for f16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_f32())
+    }
+}
+
+impl NativeType for f16 {
+    const PRIMITIVE: PrimitiveType = PrimitiveType::Float16;
+    type Bytes = [u8; 2];
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_ne_bytes(&self) -> Self::Bytes {
+        self.0.to_ne_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Self(u16::from_be_bytes(bytes))
+    }
+}
+
+macro_rules! f16_binary_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl std::ops::$trait for f16 {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self::from_f32(self.to_f32().$method(rhs.to_f32()))
+            }
+        }
+
+        impl std::ops::$assign_trait for f16 {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = std::ops::$trait::$method(*self, rhs)
+            }
+        }
+    };
+}
+
+f16_binary_op!(Add, add, AddAssign, add_assign);
+f16_binary_op!(Sub, sub, SubAssign, sub_assign);
+f16_binary_op!(Mul, mul, MulAssign, mul_assign);
+f16_binary_op!(Div, div, DivAssign, div_assign);
+f16_binary_op!(Rem, rem, RemAssign, rem_assign);
+
+impl Neg for f16 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self(self.0 ^ 0x8000)
+    }
+}
+
+impl std::iter::Sum for f16 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::from_f32(iter.map(|x| x.to_f32()).sum())
+    }
+}
+
+impl num_traits::ToPrimitive for f16 {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        num_traits::ToPrimitive::to_i64(&f16::to_f32(*self))
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        num_traits::ToPrimitive::to_u64(&f16::to_f32(*self))
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f16::to_f32(*self))
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f16::to_f64(*self))
+    }
+}
+
+impl num_traits::NumCast for f16 {
+    #[inline]
+    fn from<T: num_traits::ToPrimitive>(n: T) -> Option<Self> {
+        n.to_f32().map(Self::from_f32)
+    }
+}
+
+/// The in-memory representation of a signed 256-bit integer in two's complement, the physical
+/// type of [`DataType::Decimal256`](crate::datatypes::DataType::Decimal256).
+///
+/// Like the primitive integers, its operators panic on overflow; the `checked_*` methods
+/// return `None` instead.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Zeroable, Pod)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct i256 {
+    // the least significant half first, so that its layout is little endian
+    lo: u128,
+    hi: i128,
+}
+
+impl i256 {
+    /// The value `0`
+    pub const ZERO: Self = Self { lo: 0, hi: 0 };
+    /// The value `1`
+    pub const ONE: Self = Self { lo: 1, hi: 0 };
+    /// The largest value, `2^255 - 1`
+    pub const MAX: Self = Self {
+        lo: u128::MAX,
+        hi: i128::MAX,
+    };
+    /// The smallest value, `-2^255`
+    pub const MIN: Self = Self {
+        lo: 0,
+        hi: i128::MIN,
+    };
+
+    /// Creates a [`i256`] from its least significant and most significant 128 bits.
+    #[inline]
+    pub const fn from_parts(lo: u128, hi: i128) -> Self {
+        Self { lo, hi }
+    }
+
+    /// Returns its least significant and most significant 128 bits.
+    #[inline]
+    pub const fn to_parts(self) -> (u128, i128) {
+        (self.lo, self.hi)
+    }
+
+    /// Creates a [`i256`] from a [`i128`].
+    #[inline]
+    pub const fn from_i128(value: i128) -> Self {
+        Self {
+            lo: value as u128,
+            hi: value >> 127,
+        }
+    }
+
+    /// Returns itself as a [`i128`], or `None` if it does not fit in one.
+    #[inline]
+    pub const fn to_i128(self) -> Option<i128> {
+        if self.hi == (self.lo as i128) >> 127 {
+            Some(self.lo as i128)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the nearest [`f64`].
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        match self.to_i128() {
+            Some(value) => value as f64,
+            None => self.hi as f64 * 2f64.powi(128) + self.lo as f64,
+        }
+    }
+
+    /// Returns `value` truncated towards zero, or `None` if it is not finite or does not fit in
+    /// a [`i256`].
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        let value = value.trunc();
+        if value.abs() < 2f64.powi(127) {
+            return Some(Self::from_i128(value as i128));
+        }
+        if value >= 2f64.powi(255) || value < -(2f64.powi(255)) {
+            return None;
+        }
+        // `value` has 53 significant bits, so it is an integer multiple of 2^75
+        let magnitude = value.abs();
+        let hi = (magnitude / 2f64.powi(128)).floor();
+        let lo = magnitude - hi * 2f64.powi(128);
+        let result = Self {
+            lo: lo as u128,
+            hi: hi as i128,
+        };
+        if value < 0.0 {
+            result.checked_neg()
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Returns whether it is negative.
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.hi < 0
+    }
+
+    /// Returns `-1`, `0` or `1` depending on its sign.
+    #[inline]
+    pub fn signum(self) -> Self {
+        if self.is_negative() {
+            Self::from_i128(-1)
+        } else if self == Self::ZERO {
+            Self::ZERO
+        } else {
+            Self::ONE
+        }
+    }
+
+    /// Wrapping (modular) addition.
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        let hi = self.hi.wrapping_add(rhs.hi).wrapping_add(carry as i128);
+        Self { lo, hi }
+    }
+
+    /// Wrapping (modular) subtraction.
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        let hi = self.hi.wrapping_sub(rhs.hi).wrapping_sub(borrow as i128);
+        Self { lo, hi }
+    }
+
+    /// Checked addition, returning `None` on overflow.
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        let (hi, overflow) = self.hi.overflowing_add(rhs.hi);
+        // adding the carry overflows back iff the sum of the most significant halves overflowed
+        // below the minimum by one
+        let (hi, carry_overflow) = hi.overflowing_add(carry as i128);
+        if overflow != carry_overflow {
+            None
+        } else {
+            Some(Self { lo, hi })
+        }
+    }
+
+    /// Checked subtraction, returning `None` on overflow.
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        let (hi, overflow) = self.hi.overflowing_sub(rhs.hi);
+        let (hi, borrow_overflow) = hi.overflowing_sub(borrow as i128);
+        if overflow != borrow_overflow {
+            None
+        } else {
+            Some(Self { lo, hi })
+        }
+    }
+
+    /// Checked negation, returning `None` for [`i256::MIN`].
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        Self::ZERO.checked_sub(self)
+    }
+
+    /// Checked absolute value, returning `None` for [`i256::MIN`].
+    #[inline]
+    pub const fn checked_abs(self) -> Option<Self> {
+        if self.is_negative() {
+            self.checked_neg()
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Checked multiplication, returning `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        if let (Some(lhs), Some(rhs)) = (self.to_i128(), rhs.to_i128()) {
+            if let Some(result) = lhs.checked_mul(rhs) {
+                return Some(Self::from_i128(result));
+            }
+        }
+        let negative = self.is_negative() != rhs.is_negative();
+        let result = self.magnitude().checked_mul(rhs.magnitude())?;
+        Self::from_magnitude(result, negative)
+    }
+
+    /// Checked division, truncating towards zero and returning `None` if `rhs` is zero or on
+    /// overflow (`i256::MIN / -1`).
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+
+    /// Checked remainder, with the sign of `self` and returning `None` if `rhs` is zero or on
+    /// overflow (`i256::MIN % -1`).
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(_, remainder)| remainder)
+    }
+
+    fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        if rhs == Self::ZERO || (self == Self::MIN && rhs == Self::from_i128(-1)) {
+            return None;
+        }
+        if let (Some(lhs), Some(rhs)) = (self.to_i128(), rhs.to_i128()) {
+            if let (Some(quotient), Some(remainder)) = (lhs.checked_div(rhs), lhs.checked_rem(rhs))
+            {
+                return Some((Self::from_i128(quotient), Self::from_i128(remainder)));
+            }
+        }
+        let (quotient, remainder) = self.magnitude().div_rem(rhs.magnitude());
+        Some((
+            Self::from_magnitude(quotient, self.is_negative() != rhs.is_negative())?,
+            Self::from_magnitude(remainder, self.is_negative())?,
+        ))
+    }
+
+    /// Checked exponentiation, returning `None` on overflow.
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+
+    /// Saturating addition.
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or(if rhs.is_negative() {
+            Self::MIN
+        } else {
+            Self::MAX
+        })
+    }
+
+    /// Saturating subtraction.
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(if rhs.is_negative() {
+            Self::MAX
+        } else {
+            Self::MIN
+        })
+    }
+
+    /// Saturating multiplication.
+    #[inline]
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .unwrap_or(if self.is_negative() != rhs.is_negative() {
+                Self::MIN
+            } else {
+                Self::MAX
+            })
+    }
+
+    /// Returns itself from its little endian bytes.
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        Self {
+            lo: u128::from_le_bytes(bytes[..16].try_into().unwrap()),
+            hi: i128::from_le_bytes(bytes[16..].try_into().unwrap()),
+        }
+    }
+
+    // the absolute value, as an unsigned integer
+    fn magnitude(self) -> U256 {
+        let value = if self.is_negative() {
+            Self::ZERO.wrapping_sub(self)
+        } else {
+            self
+        };
+        U256::from_parts(value.lo, value.hi as u128)
+    }
+
+    // the value of `magnitude` with a sign, or `None` if it does not fit
+    fn from_magnitude(magnitude: U256, negative: bool) -> Option<Self> {
+        let (lo, hi) = magnitude.to_parts();
+        let value = Self { lo, hi: hi as i128 };
+        match (negative, value.is_negative()) {
+            (false, false) => Some(value),
+            (true, false) => Some(Self::ZERO.wrapping_sub(value)),
+            // only -2^255 has a magnitude of 2^255
+            (true, true) if value == Self::MIN => Some(value),
+            _ => None,
+        }
+    }
+}
+
+// an unsigned 256-bit integer, as 64-bit limbs in little endian order
+#[derive(Copy, Clone, PartialEq, Eq)]
+struct U256([u64; 4]);
+
+impl U256 {
+    const ZERO: Self = Self([0; 4]);
+
+    fn from_parts(lo: u128, hi: u128) -> Self {
+        Self([lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64])
+    }
+
+    fn to_parts(self) -> (u128, u128) {
+        let limbs = self.0;
+        (
+            limbs[0] as u128 | (limbs[1] as u128) << 64,
+            limbs[2] as u128 | (limbs[3] as u128) << 64,
+        )
+    }
+
+    fn leading_zeros(&self) -> u32 {
+        let (lo, hi) = self.to_parts();
+        if hi == 0 {
+            128 + lo.leading_zeros()
+        } else {
+            hi.leading_zeros()
+        }
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let mut result = [0u64; 8];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &b) in rhs.0.iter().enumerate() {
+                let value = a as u128 * b as u128 + result[i + j] as u128 + carry;
+                result[i + j] = value as u64;
+                carry = value >> 64;
+            }
+            result[i + 4] = carry as u64;
+        }
+        if result[4..].iter().any(|limb| *limb != 0) {
+            None
+        } else {
+            Some(Self([result[0], result[1], result[2], result[3]]))
+        }
+    }
+
+    // the quotient and remainder of the division by a non-zero u64
+    fn div_rem_u64(self, divisor: u64) -> (Self, u64) {
+        let mut quotient = [0u64; 4];
+        let mut remainder = 0u128;
+        for i in (0..4).rev() {
+            let value = remainder << 64 | self.0[i] as u128;
+            quotient[i] = (value / divisor as u128) as u64;
+            remainder = value % divisor as u128;
+        }
+        (Self(quotient), remainder as u64)
+    }
+
+    // the quotient and remainder of the division by a non-zero divisor
+    fn div_rem(self, divisor: Self) -> (Self, Self) {
+        if divisor.0[1..].iter().all(|limb| *limb == 0) {
+            let (quotient, remainder) = self.div_rem_u64(divisor.0[0]);
+            return (quotient, Self([remainder, 0, 0, 0]));
+        }
+        let (mut remainder_lo, mut remainder_hi) = self.to_parts();
+        let (divisor_lo, divisor_hi) = divisor.to_parts();
+        if self.leading_zeros() > divisor.leading_zeros() {
+            return (Self::ZERO, self);
+        }
+        // shift-subtract long division
+        let shift = divisor.leading_zeros() - self.leading_zeros();
+        let (mut d_lo, mut d_hi) = shl(divisor_lo, divisor_hi, shift);
+        let (mut q_lo, mut q_hi) = (0u128, 0u128);
+        for _ in 0..=shift {
+            q_hi = q_hi << 1 | q_lo >> 127;
+            q_lo <<= 1;
+            if (remainder_hi, remainder_lo) >= (d_hi, d_lo) {
+                let (lo, borrow) = remainder_lo.overflowing_sub(d_lo);
+                remainder_hi = remainder_hi - d_hi - borrow as u128;
+                remainder_lo = lo;
+                q_lo |= 1;
+            }
+            d_lo = d_lo >> 1 | d_hi << 127;
+            d_hi >>= 1;
+        }
+        (
+            Self::from_parts(q_lo, q_hi),
+            Self::from_parts(remainder_lo, remainder_hi),
+        )
+    }
+}
+
+// shifts a 256-bit integer of halves `lo` and `hi` left by `shift < 256` bits
+fn shl(lo: u128, hi: u128, shift: u32) -> (u128, u128) {
+    match shift {
+        0 => (lo, hi),
+        1..=127 => (lo << shift, hi << shift | lo >> (128 - shift)),
+        _ => (0, lo << (shift - 128)),
+    }
+}
+
+impl PartialOrd for i256 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for i256 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.hi, self.lo).cmp(&(other.hi, other.lo))
+    }
+}
+
+impl From<i128> for i256 {
+    #[inline]
+    fn from(value: i128) -> Self {
+        Self::from_i128(value)
+    }
+}
+
+impl std::fmt::Debug for i256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::fmt::Display for i256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(value) = self.to_i128() {
+            return write!(f, "{}", value);
+        }
+        // the digits in groups of 19, the most that fit in a u64, least significant first
+        let mut groups = vec![];
+        let mut magnitude = self.magnitude();
+        while magnitude != U256::ZERO {
+            let (quotient, group) = magnitude.div_rem_u64(10u64.pow(19));
+            groups.push(group);
+            magnitude = quotient;
+        }
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        let mut groups = groups.iter().rev();
+        write!(f, "{}", groups.next().unwrap())?;
+        groups.try_for_each(|group| write!(f, "{:019}", group))
+    }
+}
+
+impl std::str::FromStr for i256 {
+    type Err = crate::error::ArrowError;
+
+    /// Parses an optionally signed sequence of decimal digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error =
+            || crate::error::ArrowError::InvalidArgumentError(format!("{} is not a i256", s));
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if digits.is_empty() || !digits.bytes().all(|x| x.is_ascii_digit()) {
+            return Err(error());
+        }
+        // accumulated negatively so that `i256::MIN` can be parsed
+        let ten = Self::from_i128(10);
+        let negated = digits.bytes().try_fold(Self::ZERO, |acc, digit| {
+            acc.checked_mul(ten)?
+                .checked_sub(Self::from_i128((digit - b'0') as i128))
+        });
+        let value = if negative {
+            negated
+        } else {
+            negated.and_then(Self::checked_neg)
+        };
+        value.ok_or_else(error)
+    }
+}
+
+impl NativeType for i256 {
+    const PRIMITIVE: PrimitiveType = PrimitiveType::Int256;
+    type Bytes = [u8; 32];
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        let mut result = [0; 32];
+        result[..16].copy_from_slice(&self.lo.to_le_bytes());
+        result[16..].copy_from_slice(&self.hi.to_le_bytes());
+        result
+    }
+
+    #[inline]
+    fn to_ne_bytes(&self) -> Self::Bytes {
+        #[cfg(target_endian = "little")]
+        return self.to_le_bytes();
+        #[cfg(target_endian = "big")]
+        return self.to_be_bytes();
+    }
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        let mut result = [0; 32];
+        result[..16].copy_from_slice(&self.hi.to_be_bytes());
+        result[16..].copy_from_slice(&self.lo.to_be_bytes());
+        result
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Self {
+            hi: i128::from_be_bytes(bytes[..16].try_into().unwrap()),
+            lo: u128::from_be_bytes(bytes[16..].try_into().unwrap()),
+        }
+    }
+}
+
+macro_rules! i256_binary_op {
+    ($trait:ident, $method:ident, $checked:ident, $assign_trait:ident, $assign_method:ident, $error:expr) => {
+        impl std::ops::$trait for i256 {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$checked(rhs).expect($error)
+            }
+        }
+
+        impl std::ops::$assign_trait for i256 {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = std::ops::$trait::$method(*self, rhs)
+            }
+        }
+    };
+}
+
+i256_binary_op!(
+    Add,
+    add,
+    checked_add,
+    AddAssign,
+    add_assign,
+    "attempt to add with overflow"
+);
+i256_binary_op!(
+    Sub,
+    sub,
+    checked_sub,
+    SubAssign,
+    sub_assign,
+    "attempt to subtract with overflow"
+);
+i256_binary_op!(
+    Mul,
+    mul,
+    checked_mul,
+    MulAssign,
+    mul_assign,
+    "attempt to multiply with overflow"
+);
+i256_binary_op!(
+    Div,
+    div,
+    checked_div,
+    DivAssign,
+    div_assign,
+    "attempt to divide by zero or with overflow"
+);
+i256_binary_op!(
+    Rem,
+    rem,
+    checked_rem,
+    RemAssign,
+    rem_assign,
+    "attempt to calculate the remainder with a divisor of zero or with overflow"
+);
+
+impl Neg for i256 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
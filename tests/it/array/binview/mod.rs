@@ -0,0 +1,144 @@
+mod mutable;
+
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    scalar::{new_scalar, BinaryViewScalar},
+};
+
+const LONG: &str = "a string longer than 12 bytes";
+
+#[test]
+fn basics() {
+    let array = Utf8ViewArray::from(&[Some("hello"), None, Some(LONG)]);
+
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.data_type(), &DataType::Utf8View);
+    assert_eq!(array.value(0), "hello");
+    assert_eq!(array.value(2), LONG);
+    assert!(array.view(0).is_inline());
+    assert!(!array.view(2).is_inline());
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, false, true])));
+    assert_eq!(array.data_buffers().len(), 1);
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![Some("hello"), None, Some(LONG)]
+    );
+
+    let array = array.slice(1, 2);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![None, Some(LONG)]);
+}
+
+#[test]
+fn binary() {
+    let array = BinaryViewArray::from_slice(&[b"a".as_ref(), LONG.as_bytes()]);
+    assert_eq!(array.data_type(), &DataType::BinaryView);
+    assert_eq!(array.value(0), b"a");
+    assert_eq!(array.value(1), LONG.as_bytes());
+    assert_eq!(format!("{:?}", array.slice(0, 1)), "BinaryViewArray[[97]]");
+}
+
+#[test]
+fn try_new() {
+    let data = Buffer::from(LONG.as_bytes().to_vec());
+    let views = Buffer::from(vec![
+        View::new(b"hi", 0, 0).as_i128(),
+        View::new(&LONG.as_bytes()[3..], 0, 3).as_i128(),
+    ]);
+    let array =
+        Utf8ViewArray::try_new(DataType::Utf8View, views.clone(), vec![data].into(), None).unwrap();
+    assert_eq!(array.value(0), "hi");
+    assert_eq!(array.value(1), &LONG[3..]);
+
+    // missing data buffer
+    assert!(Utf8ViewArray::try_new(DataType::Utf8View, views, Arc::new([]), None).is_err());
+}
+
+#[test]
+fn try_new_invalid() {
+    let data = Buffer::from(LONG.as_bytes().to_vec());
+    // out of bounds
+    let views = Buffer::from(vec![View::new(LONG.as_bytes(), 0, 1).as_i128()]);
+    assert!(
+        Utf8ViewArray::try_new(DataType::Utf8View, views, vec![data.clone()].into(), None).is_err()
+    );
+
+    // prefix mismatch
+    let mut view = View::new(LONG.as_bytes(), 0, 0);
+    view.prefix = 0;
+    let views = Buffer::from(vec![view.as_i128()]);
+    assert!(Utf8ViewArray::try_new(
+        DataType::Utf8View,
+        views.clone(),
+        vec![data.clone()].into(),
+        None
+    )
+    .is_err());
+
+    // invalid utf8 is valid binary
+    let views = Buffer::from(vec![View::new(&[0xff, 0xfe], 0, 0).as_i128()]);
+    assert!(Utf8ViewArray::try_new(DataType::Utf8View, views.clone(), Arc::new([]), None).is_err());
+    assert!(
+        BinaryViewArray::try_new(DataType::BinaryView, views.clone(), Arc::new([]), None).is_ok()
+    );
+
+    // wrong data type
+    assert!(Utf8ViewArray::try_new(DataType::Utf8, views, Arc::new([]), None).is_err());
+}
+
+#[test]
+fn new_null_and_empty() {
+    let array = new_null_array(DataType::Utf8View, 3);
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.null_count(), 3);
+
+    let array = new_empty_array(DataType::BinaryView);
+    assert_eq!(array.len(), 0);
+}
+
+#[test]
+fn debug() {
+    let array = Utf8ViewArray::from(&[Some("a"), None, Some(LONG)]);
+    assert_eq!(
+        format!("{:?}", array),
+        format!("Utf8ViewArray[a, None, {}]", LONG)
+    );
+}
+
+#[test]
+fn equal() {
+    let lhs = Utf8ViewArray::from(&[Some("a"), None, Some(LONG)]);
+    // same values, different buffers
+    let data = Buffer::from(format!("xx{}", LONG).into_bytes());
+    let views = Buffer::from(vec![
+        View::new(b"a", 0, 0).as_i128(),
+        0,
+        View::new(LONG.as_bytes(), 0, 2).as_i128(),
+    ]);
+    let rhs = Utf8ViewArray::new(
+        DataType::Utf8View,
+        views,
+        vec![data].into(),
+        Some(Bitmap::from([true, false, true])),
+    );
+    assert_eq!(lhs, rhs);
+
+    let rhs = Utf8ViewArray::from(&[Some("a"), None, Some("b")]);
+    assert!(lhs != rhs);
+}
+
+#[test]
+fn scalar() {
+    let array = Utf8ViewArray::from(&[Some("a"), None]);
+    let scalar = new_scalar(&array, 0);
+    let scalar = scalar
+        .as_any()
+        .downcast_ref::<BinaryViewScalar<str>>()
+        .unwrap();
+    assert_eq!(scalar.value(), Some(&"a".to_string()));
+    assert!(!new_scalar(&array, 1).is_valid());
+}
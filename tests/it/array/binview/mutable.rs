@@ -0,0 +1,43 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::error::Result;
+
+#[test]
+fn push() -> Result<()> {
+    let mut array = MutableBinaryViewArray::<str>::new();
+    array.push(Some("a"));
+    array.push_null();
+    array.try_push(Some("a string longer than 12 bytes"))?;
+    assert_eq!(array.len(), 3);
+
+    let array: Utf8ViewArray = array.into();
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![Some("a"), None, Some("a string longer than 12 bytes")]
+    );
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, false, true])));
+    Ok(())
+}
+
+#[test]
+fn multiple_buffers() {
+    let value = "x".repeat(5000);
+    let array = MutableBinaryViewArray::<str>::from_values_iter((0..4).map(|_| &value));
+    let array: Utf8ViewArray = array.into();
+
+    // the first buffer has a capacity of 8KiB, so values are spread across multiple buffers
+    assert!(array.data_buffers().len() > 1);
+    assert!(array.values_iter().all(|x| x == value));
+}
+
+#[test]
+fn as_box() {
+    let mut array = MutableBinaryViewArray::<[u8]>::from_iter([Some(b"a".as_ref()), None]);
+    let boxed = array.as_box();
+    assert_eq!(boxed.len(), 2);
+    assert_eq!(array.len(), 0);
+    assert_eq!(
+        boxed.as_ref(),
+        &BinaryViewArray::from(&[Some(b"a".as_ref()), None]) as &dyn Array
+    );
+}
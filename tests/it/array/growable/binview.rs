@@ -0,0 +1,36 @@
+use arrow2::array::{
+    growable::{Growable, GrowableBinaryViewArray},
+    Utf8ViewArray,
+};
+
+const LONG: &str = "a string longer than 12 bytes";
+
+#[test]
+fn no_nulls() {
+    let array = Utf8ViewArray::from_slice(["a", LONG, "c"]);
+
+    let mut a = GrowableBinaryViewArray::new(vec![&array], false, 0);
+    a.extend(0, 1, 2);
+
+    let result: Utf8ViewArray = a.into();
+
+    let expected = Utf8ViewArray::from_slice([LONG, "c"]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn multiple_with_nulls() {
+    let array1 = Utf8ViewArray::from(&[Some("a"), Some(LONG)]);
+    let long = format!("{} again", LONG);
+    let array2 = Utf8ViewArray::from(&[None, Some(long.as_str())]);
+
+    let mut a = GrowableBinaryViewArray::new(vec![&array1, &array2], false, 5);
+    a.extend(1, 0, 2);
+    a.extend_validity(1);
+    a.extend(0, 0, 2);
+
+    let result: Utf8ViewArray = a.into();
+
+    let expected = Utf8ViewArray::from(&[None, Some(long.as_str()), None, Some("a"), Some(LONG)]);
+    assert_eq!(result, expected);
+}
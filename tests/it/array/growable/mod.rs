@@ -1,11 +1,14 @@
 mod binary;
+mod binview;
 mod boolean;
 mod dictionary;
 mod fixed_binary;
 mod fixed_size_list;
 mod list;
+mod map;
 mod null;
 mod primitive;
+mod run_end_encoded;
 mod struct_;
 mod union;
 mod utf8;
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use arrow2::array::growable::{Growable, GrowableRunEndEncoded};
+use arrow2::array::*;
+
+#[test]
+fn single() {
+    let values = Arc::new(Int32Array::from_slice(&[1, 2, 3]));
+    let array = RunEndEncodedArray::<i32>::from_data(vec![2, 5, 6].into(), values);
+
+    let mut growable = GrowableRunEndEncoded::new(vec![&array], 0);
+    growable.extend(0, 1, 4);
+
+    let result: RunEndEncodedArray<i32> = growable.into();
+
+    let values = Arc::new(Int32Array::from_slice(&[1, 2]));
+    let expected = RunEndEncodedArray::<i32>::from_data(vec![1, 4].into(), values);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn multi_with_nulls() {
+    let values = Arc::new(Int32Array::from_slice(&[1, 2]));
+    let array1 = RunEndEncodedArray::<i32>::from_data(vec![2, 4].into(), values);
+    let values = Arc::new(Int32Array::from_slice(&[3]));
+    let array2 = RunEndEncodedArray::<i32>::from_data(vec![3].into(), values).slice(1, 2);
+
+    let mut growable = GrowableRunEndEncoded::new(vec![&array1, &array2], 0);
+    growable.extend(0, 3, 1);
+    growable.extend_validity(2);
+    growable.extend(1, 0, 2);
+
+    let result: RunEndEncodedArray<i32> = growable.into();
+
+    let values = Arc::new(Int32Array::from(&[Some(2), None, Some(3)]));
+    let expected = RunEndEncodedArray::<i32>::from_data(vec![1, 3, 5].into(), values);
+    assert_eq!(result, expected);
+}
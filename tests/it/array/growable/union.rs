@@ -70,3 +70,126 @@ fn dense() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn dense_multiple() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![3, 5]), UnionMode::Dense);
+    let array = UnionArray::from_data(
+        data_type.clone(),
+        vec![3, 5, 3].into(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a")])) as Arc<dyn Array>,
+        ],
+        Some(vec![0, 0, 1].into()),
+    );
+    let other = UnionArray::from_data(
+        data_type.clone(),
+        vec![5, 5].into(),
+        vec![
+            Arc::new(Int32Array::from(&[] as &[Option<i32>])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("b"), Some("c")])) as Arc<dyn Array>,
+        ],
+        Some(vec![0, 1].into()),
+    );
+
+    let mut a = GrowableUnion::new(vec![&array, &other], 10);
+    a.extend(0, 1, 2);
+    a.extend(1, 1, 1);
+    a.extend_validity(1);
+    let result: UnionArray = a.into();
+
+    let expected = UnionArray::from_data(
+        data_type,
+        vec![5, 3, 5, 3].into(),
+        vec![
+            Arc::new(Int32Array::from(&[None, None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("c")])) as Arc<dyn Array>,
+        ],
+        Some(vec![0, 0, 1, 1].into()),
+    );
+    assert_eq!(result, expected);
+    assert_eq!(result.offsets(), expected.offsets());
+    Ok(())
+}
+
+#[test]
+fn sparse_sliced() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+    let array = UnionArray::from_data(
+        data_type.clone(),
+        vec![0, 1, 0, 1].into(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(3), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[None, Some("b"), None, Some("d")])) as Arc<dyn Array>,
+        ],
+        None,
+    );
+    let sliced = array.slice(1, 3);
+
+    let mut a = GrowableUnion::new(vec![&sliced], 10);
+    a.extend(0, 1, 2);
+    a.extend_validity(1);
+    let result: UnionArray = a.into();
+
+    let expected = UnionArray::from_data(
+        data_type,
+        vec![0, 1, 0].into(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(3), None, None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[None, Some("d"), None])) as Arc<dyn Array>,
+        ],
+        None,
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn extend_validity() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(fields.clone(), None, mode);
+        let (a, b, offsets) = if mode.is_sparse() {
+            let a = Int32Array::from_slice(&[1, 2]);
+            let b = Utf8Array::<i32>::from_slice(&["a", "b"]);
+            (a, b, None)
+        } else {
+            let a = Int32Array::from_slice(&[1]);
+            let b = Utf8Array::<i32>::from_slice(&["b"]);
+            (a, b, Some(vec![0, 0].into()))
+        };
+        let array = UnionArray::from_data(
+            data_type,
+            vec![0, 1].into(),
+            vec![Arc::new(a), Arc::new(b)],
+            offsets,
+        );
+
+        let mut a = GrowableUnion::new(vec![&array], 10);
+        a.extend(0, 1, 1);
+        a.extend_validity(1);
+        let result: UnionArray = a.into();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.types().as_slice(), &[1, 0]);
+        assert_eq!(
+            format!("{:?}", result.value(0)),
+            format!("{:?}", array.value(1))
+        );
+        assert!(!result.value(1).is_valid());
+    }
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{iter::FromIterator, sync::Arc};
 
 use arrow2::{
     array::*,
@@ -49,3 +49,76 @@ fn basics() {
         )) as Box<dyn Array>
     );
 }
+
+#[test]
+fn mutable_push() {
+    let mut array = MutableMapArray::new(
+        MutableUtf8Array::<i32>::new(),
+        MutablePrimitiveArray::<i32>::new(),
+    );
+    array
+        .try_extend(vec![
+            Some(vec![(Some("a"), Some(1)), (Some("b"), None)]),
+            None,
+            Some(vec![]),
+            Some(vec![(Some("c"), Some(3))]),
+        ])
+        .unwrap();
+    assert_eq!(array.len(), 4);
+
+    let array: MapArray = array.into();
+    assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 2, 3]);
+    assert_eq!(array.validity(), Some(&[true, false, true, true].into()));
+
+    let dt = DataType::Struct(vec![
+        Field::new("keys", DataType::Utf8, false),
+        Field::new("values", DataType::Int32, true),
+    ]);
+    let expected = StructArray::new(
+        dt,
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as _,
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])),
+        ],
+        None,
+    );
+    assert_eq!(array.field().as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn mutable_push_valid() {
+    let mut array = MutableMapArray::new(
+        MutableUtf8Array::<i32>::new(),
+        MutablePrimitiveArray::<i32>::new(),
+    );
+    array.mut_keys().push(Some("a"));
+    assert!(array.try_push_valid().is_err());
+
+    array.mut_values().push(Some(1));
+    array.try_push_valid().unwrap();
+    assert_eq!(array.offsets(), &vec![0, 1]);
+}
+
+#[test]
+fn mutable_errors() {
+    let data_type = DataType::List(Box::new(Field::new("a", DataType::Int32, true)));
+    assert!(MutableMapArray::try_new(
+        data_type,
+        MutablePrimitiveArray::<i32>::new(),
+        MutablePrimitiveArray::<i32>::new()
+    )
+    .is_err());
+
+    let data_type = MapArray::default_datatype(DataType::Utf8, DataType::Int32);
+    assert!(MutableMapArray::try_new(
+        data_type.clone(),
+        MutablePrimitiveArray::<i32>::new(),
+        MutablePrimitiveArray::<i32>::new()
+    )
+    .is_err());
+
+    let keys = MutableUtf8Array::<i32>::from_iter([Some("a")]);
+    assert!(
+        MutableMapArray::try_new(data_type, keys, MutablePrimitiveArray::<i32>::new()).is_err()
+    );
+}
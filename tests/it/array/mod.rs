@@ -1,4 +1,5 @@
 mod binary;
+mod binview;
 mod boolean;
 mod dictionary;
 mod equal;
@@ -9,13 +10,22 @@ mod list;
 mod map;
 mod ord;
 mod primitive;
+mod run_end_encoded;
 mod struct_;
 mod union;
 mod utf8;
 
-use arrow2::array::{clone, new_empty_array, new_null_array, Array, PrimitiveArray};
+use std::sync::Arc;
+
+use arrow2::array::{
+    clone, make_mutable_array, new_empty_array, new_null_array, Array, BooleanArray,
+    DictionaryArray, FixedSizeListArray, Int32Array, Int8Array, ListArray, MapArray,
+    MutableListArray, MutablePrimitiveArray, NullArray, PrimitiveArray, RunEndEncodedArray,
+    StructArray, TryExtend, TryPushScalar, UnionArray, Utf8Array, Utf8ViewArray,
+};
 use arrow2::bitmap::Bitmap;
-use arrow2::datatypes::{DataType, Field, UnionMode};
+use arrow2::datatypes::{DataType, Field, IntegerType, UnionMode};
+use arrow2::scalar::{new_scalar, ListScalar, PrimitiveScalar, StructScalar, Utf8Scalar};
 
 #[test]
 fn nulls() {
@@ -73,6 +83,228 @@ fn empty() {
     assert!(a);
 }
 
+#[test]
+fn make_mutable() {
+    let datatypes = vec![
+        DataType::Null,
+        DataType::Boolean,
+        DataType::Int32,
+        DataType::Float64,
+        DataType::Utf8,
+        DataType::LargeBinary,
+        DataType::Utf8View,
+        DataType::FixedSizeBinary(2),
+        DataType::List(Box::new(Field::new("a", DataType::Binary, true))),
+        DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 2),
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]),
+        DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("keys", DataType::Utf8, false),
+                    Field::new("values", DataType::Int32, true),
+                ]),
+                false,
+            )),
+            false,
+        ),
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+        DataType::RunEndEncoded(
+            IntegerType::Int16,
+            Box::new(Field::new("values", DataType::Utf8, true)),
+        ),
+    ];
+    for data_type in datatypes {
+        let mut array = make_mutable_array(&data_type, 10).unwrap();
+        assert_eq!(array.data_type(), &data_type);
+        (0..10).for_each(|_| array.push_null());
+        assert_eq!(array.as_box(), new_null_array(data_type, 10));
+    }
+
+    let data_type = DataType::RunEndEncoded(
+        IntegerType::UInt32,
+        Box::new(Field::new("values", DataType::Utf8, true)),
+    );
+    assert!(make_mutable_array(&data_type, 10).is_err());
+}
+
+fn push_scalars(array: &dyn Array) -> Box<dyn Array> {
+    let mut mutable = make_mutable_array(array.data_type(), array.len()).unwrap();
+    for i in 0..array.len() {
+        mutable
+            .try_push_scalar(new_scalar(array, i).as_ref())
+            .unwrap();
+    }
+    mutable.as_box()
+}
+
+#[test]
+fn push_scalar() {
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![Some(vec![Some(1), None]), None, Some(vec![])])
+        .unwrap();
+    let list: ListArray<i32> = list.into();
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let values = vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("a"), Some("b"), None])),
+    ];
+    let struct_ = StructArray::new(
+        DataType::Struct(fields.clone()),
+        values.clone(),
+        Some([true, false, true].into()),
+    );
+    let sparse = UnionArray::new(
+        DataType::Union(fields.clone(), None, UnionMode::Sparse),
+        vec![0, 1, 1].into(),
+        values,
+        None,
+    );
+    let dense = UnionArray::new(
+        DataType::Union(fields, None, UnionMode::Dense),
+        vec![0, 1, 0].into(),
+        vec![
+            Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from([Some("a")])),
+        ],
+        Some(vec![0, 0, 1].into()),
+    );
+    let fixed_size_list = FixedSizeListArray::new(
+        DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 2),
+        Arc::new(Int32Array::from([Some(1), None, Some(3), Some(4)])),
+        Some([false, true].into()),
+    );
+    let entries = DataType::Struct(vec![
+        Field::new("keys", DataType::Utf8, false),
+        Field::new("values", DataType::Int32, true),
+    ]);
+    let map = MapArray::new(
+        DataType::Map(
+            Box::new(Field::new("entries", entries.clone(), false)),
+            false,
+        ),
+        vec![0, 2, 2, 3].into(),
+        Arc::new(StructArray::new(
+            entries,
+            vec![
+                Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>,
+                Arc::new(Int32Array::from([Some(1), None, Some(3)])),
+            ],
+            None,
+        )),
+        Some([true, false, true].into()),
+    );
+    let dictionary = DictionaryArray::<i8>::from_data(
+        Int8Array::from([Some(1), None, Some(0), Some(1)]),
+        Arc::new(Utf8Array::<i32>::from([Some("a"), None])),
+    );
+    let run_end_encoded = RunEndEncodedArray::<i32>::from_data(
+        vec![2, 3, 6, 7].into(),
+        Arc::new(Utf8Array::<i32>::from([
+            Some("a"),
+            None,
+            Some("b"),
+            Some("c"),
+        ])),
+    );
+
+    let arrays: Vec<Box<dyn Array>> = vec![
+        Box::new(NullArray::new(DataType::Null, 2)),
+        Box::new(BooleanArray::from([Some(true), None])),
+        Box::new(Int32Array::from([Some(1), None, Some(3)])),
+        Box::new(Utf8Array::<i64>::from([Some("a"), None])),
+        Box::new(Utf8ViewArray::from([Some("a"), None, Some("a long value")])),
+        Box::new(list),
+        Box::new(fixed_size_list),
+        Box::new(struct_),
+        Box::new(sparse),
+        Box::new(dense),
+        Box::new(map),
+        Box::new(dictionary),
+        Box::new(run_end_encoded.clone()),
+    ];
+    for array in arrays {
+        assert_eq!(push_scalars(array.as_ref()), array);
+    }
+
+    // equal consecutive values are pushed to the same run
+    let array = push_scalars(&run_end_encoded);
+    let array = array
+        .as_any()
+        .downcast_ref::<RunEndEncodedArray<i32>>()
+        .unwrap();
+    assert_eq!(array.run_ends().as_slice(), &[2, 3, 6, 7]);
+}
+
+#[test]
+fn push_scalar_errors() {
+    let mut array = make_mutable_array(&DataType::Int32, 0).unwrap();
+    assert!(array
+        .try_push_scalar(&Utf8Scalar::<i32>::new(Some("a")))
+        .is_err());
+
+    let mut array: Box<dyn arrow2::array::MutableArray> =
+        Box::new(MutableListArray::<i32, MutablePrimitiveArray<i32>>::new());
+    let scalar = new_scalar(&ListArray::<i32>::new_null(array.data_type().clone(), 1), 0);
+    // null slots do not need to downcast the array
+    array.try_push_scalar(scalar.as_ref()).unwrap();
+
+    let values = Int32Array::from_slice([1]);
+    let scalar =
+        arrow2::scalar::ListScalar::<i32>::new(array.data_type().clone(), Some(Arc::new(values)));
+    assert!(array.try_push_scalar(&scalar).is_err());
+    assert!(array
+        .try_push_scalar(&PrimitiveScalar::<i32>::from(Some(1)))
+        .is_err());
+}
+
+#[test]
+fn push_scalar_rolls_back() {
+    // the second field errors after the first was pushed
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Int32, true),
+    ]);
+    let mut array = make_mutable_array(&data_type, 0).unwrap();
+    let scalar = StructScalar::new(
+        data_type.clone(),
+        Some(vec![
+            Arc::new(PrimitiveScalar::<i32>::from(Some(1))),
+            Arc::new(Utf8Scalar::<i32>::new(Some("a"))),
+        ]),
+    );
+    assert!(array.try_push_scalar(&scalar).is_err());
+    array.push_null();
+    assert_eq!(array.as_box(), new_null_array(data_type, 1));
+
+    // the list errors after some of its values were pushed, when the keys overflow
+    let dictionary = DataType::Dictionary(IntegerType::Int8, Box::new(DataType::Int32), false);
+    let data_type = DataType::List(Box::new(Field::new("item", dictionary, true)));
+    let mut array = make_mutable_array(&data_type, 0).unwrap();
+    let list = |values: std::ops::Range<i32>| {
+        let keys = Int8Array::from_values((0..values.len() as i8).collect::<Vec<_>>());
+        let values = Int32Array::from_values(values);
+        let values = DictionaryArray::<i8>::from_data(keys, Arc::new(values));
+        ListScalar::<i32>::new(data_type.clone(), Some(Arc::new(values)))
+    };
+    array.try_push_scalar(&list(0..100)).unwrap();
+    assert!(array.try_push_scalar(&list(100..200)).is_err());
+    array.try_push_scalar(&list(0..10)).unwrap();
+
+    let array = array.as_box();
+    let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.offsets().as_slice(), &[0, 100, 110]);
+    assert_eq!(array.values().len(), 110);
+}
+
 #[test]
 fn test_clone() {
     let datatypes = vec![
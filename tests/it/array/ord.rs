@@ -101,3 +101,17 @@ fn dict_i32() -> Result<()> {
     assert_eq!(Ordering::Greater, (cmp)(2, 3));
     Ok(())
 }
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let values = Int32Array::from(&[Some(1), Some(2), Some(1), None, Some(1), Some(2)]);
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), 2);
+    let array = FixedSizeListArray::from_data(data_type, std::sync::Arc::new(values), None);
+
+    let cmp = build_compare(&array, &array)?;
+
+    assert_eq!(Ordering::Greater, (cmp)(0, 1));
+    assert_eq!(Ordering::Equal, (cmp)(0, 2));
+    assert_eq!(Ordering::Less, (cmp)(1, 2));
+    Ok(())
+}
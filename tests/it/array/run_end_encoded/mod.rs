@@ -0,0 +1,139 @@
+mod mutable;
+
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    datatypes::{DataType, Field, IntegerType},
+    error::Result,
+    scalar::PrimitiveScalar,
+};
+
+fn values_of(array: &RunEndEncodedArray<i32>) -> Vec<Option<i32>> {
+    array
+        .iter()
+        .map(|x| {
+            x.as_any()
+                .downcast_ref::<PrimitiveScalar<i32>>()
+                .unwrap()
+                .value()
+        })
+        .collect()
+}
+
+#[test]
+fn basics() {
+    let values = Arc::new(Int32Array::from(&[Some(1), None, Some(2)]));
+    let array = RunEndEncodedArray::<i32>::from_data(vec![2, 3, 6].into(), values);
+
+    assert_eq!(array.len(), 6);
+    assert_eq!(
+        array.data_type(),
+        &DataType::RunEndEncoded(
+            IntegerType::Int32,
+            Box::new(Field::new("values", DataType::Int32, true))
+        )
+    );
+    assert_eq!(array.physical_index(0), 0);
+    assert_eq!(array.physical_index(1), 0);
+    assert_eq!(array.physical_index(2), 1);
+    assert_eq!(array.physical_index(5), 2);
+    assert_eq!(
+        values_of(&array),
+        vec![Some(1), Some(1), None, Some(2), Some(2), Some(2)]
+    );
+    assert!(!array.value(2).is_valid());
+}
+
+#[test]
+fn slice() {
+    let values = Arc::new(Int32Array::from(&[Some(1), None, Some(2)]));
+    let array = RunEndEncodedArray::<i32>::from_data(vec![2, 3, 6].into(), values);
+
+    let sliced = array.slice(1, 3);
+    assert_eq!(sliced.len(), 3);
+    assert_eq!(sliced.offset(), 1);
+    assert_eq!(sliced.physical_range(), (0, 3));
+    assert_eq!(values_of(&sliced), vec![Some(1), None, Some(2)]);
+
+    let sliced = array.slice(3, 2);
+    assert_eq!(sliced.physical_range(), (2, 3));
+    assert_eq!(values_of(&sliced), vec![Some(2), Some(2)]);
+}
+
+#[test]
+fn try_new_invalid() {
+    let values = Arc::new(Int32Array::from_slice(&[1, 2]));
+    let data_type = RunEndEncodedArray::<i32>::default_data_type(DataType::Int32);
+
+    // not strictly increasing
+    assert!(RunEndEncodedArray::<i32>::try_new(
+        data_type.clone(),
+        vec![2, 2].into(),
+        values.clone()
+    )
+    .is_err());
+    // not positive
+    assert!(RunEndEncodedArray::<i32>::try_new(
+        data_type.clone(),
+        vec![0, 2].into(),
+        values.clone()
+    )
+    .is_err());
+    // wrong number of run ends
+    assert!(
+        RunEndEncodedArray::<i32>::try_new(data_type.clone(), vec![2].into(), values.clone())
+            .is_err()
+    );
+    // wrong run end type
+    assert!(
+        RunEndEncodedArray::<i64>::try_new(data_type, vec![1, 2].into(), values.clone()).is_err()
+    );
+    // run ends that cannot be run ends
+    let data_type = DataType::RunEndEncoded(
+        IntegerType::Int8,
+        Box::new(Field::new("values", DataType::Int32, true)),
+    );
+    assert!(RunEndEncodedArray::<i16>::try_new(data_type, vec![1, 2].into(), values).is_err());
+}
+
+#[test]
+fn new_null_and_empty() {
+    let data_type = RunEndEncodedArray::<i16>::default_data_type(DataType::Utf8);
+
+    let array = new_null_array(data_type.clone(), 4);
+    let array = array
+        .as_any()
+        .downcast_ref::<RunEndEncodedArray<i16>>()
+        .unwrap();
+    assert_eq!(array.len(), 4);
+    assert_eq!(array.values().len(), 1);
+    assert!(array.iter().all(|x| !x.is_valid()));
+
+    let array = new_empty_array(data_type);
+    assert_eq!(array.len(), 0);
+}
+
+#[test]
+fn debug() {
+    let values = Arc::new(Utf8Array::<i32>::from(&[Some("a"), None, Some("b")]));
+    let array = RunEndEncodedArray::<i32>::from_data(vec![2, 3, 4].into(), values);
+
+    assert_eq!(format!("{:?}", array), "RunEndEncodedArray[a, a, None, b]");
+}
+
+#[test]
+fn equal() -> Result<()> {
+    let values = Arc::new(Int32Array::from_slice(&[1, 2]));
+    let lhs = RunEndEncodedArray::<i32>::from_data(vec![2, 4].into(), values);
+
+    // same logical values, different runs
+    let values = Arc::new(Int32Array::from_slice(&[1, 1, 2]));
+    let rhs = RunEndEncodedArray::<i32>::from_data(vec![1, 2, 4].into(), values);
+    assert_eq!(lhs, rhs);
+
+    let values = Arc::new(Int32Array::from_slice(&[1, 3]));
+    let rhs = RunEndEncodedArray::<i32>::from_data(vec![2, 4].into(), values);
+    assert!(lhs != rhs);
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::error::Result;
+
+#[test]
+fn push() -> Result<()> {
+    let mut array = MutableRunEndEncodedArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_push(Some("a"))?;
+    array.try_push(Some("a"))?;
+    array.push_null();
+    array.push_null();
+    array.try_push(Some("b"))?;
+    array.try_push(Some("a"))?;
+
+    assert_eq!(array.len(), 6);
+    assert_eq!(array.run_ends(), &[2, 4, 5, 6]);
+    assert_eq!(array.values().len(), 4);
+
+    let array: RunEndEncodedArray<i32> = array.into();
+    let expected = RunEndEncodedArray::<i32>::from_data(
+        vec![2, 4, 5, 6].into(),
+        Arc::new(Utf8Array::<i32>::from(&[
+            Some("a"),
+            None,
+            Some("b"),
+            Some("a"),
+        ])),
+    );
+    assert_eq!(array, expected);
+    Ok(())
+}
+
+#[test]
+fn push_distinct_values() -> Result<()> {
+    let mut array = MutableRunEndEncodedArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(vec![
+        Some("ab"),
+        Some("a"),
+        Some("ab"),
+        Some(""),
+        None,
+        Some(""),
+    ])?;
+
+    assert_eq!(array.run_ends(), &[1, 2, 3, 4, 5, 6]);
+    Ok(())
+}
+
+#[test]
+fn extend() -> Result<()> {
+    let mut array = MutableRunEndEncodedArray::<i16, MutablePrimitiveArray<i64>>::new();
+    array.try_extend(vec![Some(1), Some(1), Some(1), Some(2), None])?;
+
+    let array = array.as_box();
+    let array = array
+        .as_any()
+        .downcast_ref::<RunEndEncodedArray<i16>>()
+        .unwrap();
+    assert_eq!(array.run_ends().as_slice(), &[3, 4, 5]);
+    assert_eq!(
+        array.values().as_ref(),
+        &Int64Array::from(&[Some(1), Some(2), None]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn overflow() {
+    let mut array = MutableRunEndEncodedArray::<i16, MutablePrimitiveArray<i32>>::new();
+    array
+        .try_extend(std::iter::repeat(Some(1)).take(i16::MAX as usize))
+        .unwrap();
+    assert!(array.try_push(Some(1)).is_err());
+}
+
+#[test]
+fn push_error_leaves_array_unchanged() -> Result<()> {
+    // the keys of the values overflow after 256 distinct values
+    let values = MutableDictionaryArray::<u8, MutablePrimitiveArray<i32>>::new();
+    let mut array = MutableRunEndEncodedArray::<i32, _>::from(values);
+    array.try_extend((0..256).map(Some))?;
+    assert!(array.try_push(Some(256)).is_err());
+    assert_eq!(array.len(), 256);
+    assert_eq!(array.run_ends().len(), 256);
+    assert_eq!(array.values().len(), 256);
+
+    // the run of the last value is extended rather than the run of the rejected value
+    array.try_push(Some(255))?;
+    array.try_push(Some(0))?;
+    assert_eq!(&array.run_ends()[254..], &[255, 257, 258]);
+    assert_eq!(array.values().len(), 257);
+    Ok(())
+}
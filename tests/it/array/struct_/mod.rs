@@ -25,3 +25,41 @@ fn debug() {
         "StructArray[{b: false, c: 42}, {b: false, c: 28}, None, {b: true, c: 31}]"
     );
 }
+
+#[test]
+fn mutable() {
+    let fields = vec![
+        Field::new("b", DataType::Boolean, true),
+        Field::new("c", DataType::Int32, true),
+    ];
+    let mut array = MutableStructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![
+            Box::new(MutableBooleanArray::new()),
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+        ],
+    );
+    array
+        .value::<MutableBooleanArray>(0)
+        .unwrap()
+        .push(Some(true));
+    assert!(array.try_push_valid().is_err());
+    array
+        .value::<MutablePrimitiveArray<i32>>(1)
+        .unwrap()
+        .push(Some(1));
+    array.try_push_valid().unwrap();
+    array.push_null();
+    assert!(array.value::<MutableBooleanArray>(1).is_none());
+
+    let array: StructArray = array.into();
+    let expected = StructArray::new(
+        DataType::Struct(fields),
+        vec![
+            std::sync::Arc::new(BooleanArray::from([Some(true), None])) as _,
+            std::sync::Arc::new(Int32Array::from([Some(1), None])),
+        ],
+        Some([true, false].into()),
+    );
+    assert_eq!(array, expected);
+}
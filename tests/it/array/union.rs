@@ -266,3 +266,94 @@ fn scalar() -> Result<()> {
 
     Ok(())
 }
+
+fn mutable_fields() -> Vec<Box<dyn MutableArray>> {
+    vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ]
+}
+
+#[test]
+fn mutable_sparse() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+
+    let mut array = MutableUnionArray::try_new(data_type.clone(), mutable_fields())?;
+    array.try_push_variant::<MutablePrimitiveArray<i32>, _>(0, Some(1))?;
+    array.push_null();
+    array.try_push_variant::<MutableUtf8Array<i32>, _>(1, Some("c"))?;
+    assert_eq!(array.len(), 3);
+    let array: UnionArray = array.into();
+
+    let expected = UnionArray::try_new(
+        data_type,
+        vec![0, 0, 1].into(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None, None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[None, None, Some("c")])) as Arc<dyn Array>,
+        ],
+        None,
+    )?;
+    assert_eq!(array, expected);
+    assert_eq!(format!("{:?}", array), "UnionArray[1, None, c]");
+
+    Ok(())
+}
+
+#[test]
+fn mutable_dense() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Dense);
+
+    let mut array = MutableUnionArray::try_new(data_type.clone(), mutable_fields())?;
+    array.try_push_variant::<MutableUtf8Array<i32>, _>(7, Some("a"))?;
+    array.try_push_variant::<MutablePrimitiveArray<i32>, _>(5, Some(1))?;
+    array.try_push_variant::<MutableUtf8Array<i32>, _>(7, None::<&str>)?;
+    array.push_null();
+    let array = array.as_box();
+
+    let expected = UnionArray::try_new(
+        data_type,
+        vec![7, 5, 7, 5].into(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), None])) as Arc<dyn Array>,
+        ],
+        Some(vec![0, 0, 1, 1].into()),
+    )?;
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+    Ok(())
+}
+
+#[test]
+fn mutable_errors() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+
+    // wrong number of fields
+    assert!(MutableUnionArray::try_new(data_type.clone(), vec![]).is_err());
+
+    let mut array = MutableUnionArray::try_new(data_type, mutable_fields())?;
+    // unknown type id
+    assert!(array
+        .try_push_variant::<MutablePrimitiveArray<i32>, _>(2, Some(1))
+        .is_err());
+    // wrong type of field
+    assert!(array
+        .try_push_variant::<MutablePrimitiveArray<i32>, _>(1, Some(1))
+        .is_err());
+    assert!(array.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::aggregate::{approx_distinct, can_approx_distinct, HyperLogLog};
+use arrow2::datatypes::DataType;
+
+fn assert_within(estimate: u64, expected: u64, relative_error: f64) {
+    let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+    assert!(
+        error < relative_error,
+        "estimate {} of {} is off by {}",
+        estimate,
+        expected,
+        error
+    );
+}
+
+#[test]
+fn small_cardinalities() {
+    let array = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None, Some("a"), Some("")]);
+    assert_eq!(approx_distinct(&array).unwrap(), 3);
+
+    let array = BooleanArray::from(&[Some(true), Some(true), None]);
+    assert_eq!(approx_distinct(&array).unwrap(), 1);
+
+    let array = NullArray::new(DataType::Null, 10);
+    assert_eq!(approx_distinct(&array).unwrap(), 0);
+}
+
+#[test]
+fn large_cardinality() {
+    let array = Int64Array::from_vec((0..200_000).map(|x| x % 100_000).collect());
+    assert_within(approx_distinct(&array).unwrap(), 100_000, 0.03);
+
+    let array = Float64Array::from_vec((0..50_000).map(|x| x as f64 / 7.0).collect());
+    assert_within(approx_distinct(&array).unwrap(), 50_000, 0.03);
+}
+
+#[test]
+fn merge() {
+    let lhs = UInt32Array::from_vec((0..30_000).collect());
+    let rhs = UInt32Array::from_vec((20_000..60_000).collect());
+
+    let mut sketch = HyperLogLog::try_new(12).unwrap();
+    sketch.update(&lhs).unwrap();
+    sketch.update(&rhs).unwrap();
+
+    let mut lhs_sketch = HyperLogLog::try_new(12).unwrap();
+    lhs_sketch.update(&lhs).unwrap();
+    let mut rhs_sketch = HyperLogLog::try_new(12).unwrap();
+    rhs_sketch.update(&rhs).unwrap();
+    lhs_sketch.merge(&rhs_sketch).unwrap();
+
+    assert_eq!(sketch, lhs_sketch);
+    assert_within(sketch.estimate(), 60_000, 0.06);
+
+    // round trip through the registers
+    let registers = sketch.registers().to_vec();
+    let sketch = HyperLogLog::try_from_registers(registers).unwrap();
+    assert_eq!(sketch, lhs_sketch);
+
+    let other = HyperLogLog::try_new(10).unwrap();
+    assert!(lhs_sketch.merge(&other).is_err());
+}
+
+#[test]
+fn portable_hash() {
+    use arrow2::compute::hash::{hash_with_options, HashAlgorithm, HashOptions};
+
+    // values are hashed with XXH64 and seed 0, so that registers are reproducible
+    let array = Utf8Array::<i32>::from_slice(&["a", "b", "c"]);
+    let options = HashOptions {
+        algorithm: HashAlgorithm::XxHash64,
+        seed: 0,
+    };
+    let precision = 4;
+    let mut registers = vec![0u8; 1 << precision];
+    hash_with_options(&array, &options)
+        .unwrap()
+        .values()
+        .iter()
+        .for_each(|hash| {
+            let rank = ((hash << precision) | (1 << (precision - 1))).leading_zeros() as u8 + 1;
+            let register = &mut registers[(hash >> (64 - precision)) as usize];
+            *register = (*register).max(rank);
+        });
+
+    let mut sketch = HyperLogLog::try_new(precision).unwrap();
+    sketch.update(&array).unwrap();
+    assert_eq!(sketch.registers(), registers.as_slice());
+}
+
+#[test]
+fn dictionary() {
+    let values = Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("a"), None]);
+    let keys = Int32Array::from(&[Some(0), Some(2), Some(1), None, Some(3)]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+    assert_eq!(approx_distinct(&array).unwrap(), 2);
+}
+
+#[test]
+fn errors() {
+    assert!(HyperLogLog::try_new(3).is_err());
+    assert!(HyperLogLog::try_new(19).is_err());
+    assert!(HyperLogLog::try_from_registers(vec![0; 100]).is_err());
+    assert!(HyperLogLog::try_from_registers(vec![0; 8]).is_err());
+
+    let data_type = DataType::List(Box::new(arrow2::datatypes::Field::new(
+        "item",
+        DataType::Int32,
+        true,
+    )));
+    assert!(!can_approx_distinct(&data_type));
+    let array = new_empty_array(data_type);
+    assert!(approx_distinct(array.as_ref()).is_err());
+}
@@ -0,0 +1,75 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::*;
+use arrow2::datatypes::{DataType, TimeUnit};
+
+fn options(nulls: NullPolicy, nans: NanPolicy) -> ArgMinMaxOptions {
+    ArgMinMaxOptions { nulls, nans }
+}
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from(&[Some(5), None, Some(-1), Some(7), Some(-1), Some(7)]);
+    let default = ArgMinMaxOptions::default();
+    assert_eq!(argmin_primitive(&array, default), Some(2));
+    assert_eq!(argmax_primitive(&array, default), Some(3));
+
+    let propagate = options(NullPolicy::Propagate, NanPolicy::Largest);
+    assert_eq!(argmin_primitive(&array, propagate), None);
+    assert_eq!(argmax_primitive(&array, propagate), None);
+
+    let array = Int32Array::from(&[None, None]);
+    assert_eq!(argmin_primitive(&array, default), None);
+
+    let array = Int32Array::from_slice(&[]);
+    assert_eq!(argmax_primitive(&array, default), None);
+}
+
+#[test]
+fn nans() {
+    let array = Float32Array::from(&[Some(1.0), Some(f32::NAN), None, Some(0.5), Some(f32::NAN)]);
+
+    let largest = options(NullPolicy::Ignore, NanPolicy::Largest);
+    assert_eq!(argmin_primitive(&array, largest), Some(3));
+    assert_eq!(argmax_primitive(&array, largest), Some(1));
+
+    let ignore = options(NullPolicy::Ignore, NanPolicy::Ignore);
+    assert_eq!(argmin_primitive(&array, ignore), Some(3));
+    assert_eq!(argmax_primitive(&array, ignore), Some(0));
+
+    let propagate = options(NullPolicy::Ignore, NanPolicy::Propagate);
+    assert_eq!(argmin_primitive(&array, propagate), Some(1));
+    assert_eq!(argmax_primitive(&array, propagate), Some(1));
+
+    let array = Float64Array::from_slice(&[f64::NAN, f64::NAN]);
+    assert_eq!(argmin_primitive(&array, largest), Some(0));
+    assert_eq!(argmin_primitive(&array, ignore), None);
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i64>::from(&[Some("b"), None, Some("a"), Some("c"), Some("a")]);
+    let default = ArgMinMaxOptions::default();
+    assert_eq!(argmin(&array, default).unwrap(), Some(2));
+    assert_eq!(argmax(&array, default).unwrap(), Some(3));
+
+    let propagate = options(NullPolicy::Propagate, NanPolicy::Largest);
+    assert_eq!(argmin(&array, propagate).unwrap(), None);
+}
+
+#[test]
+fn dynamic() {
+    let array = Int64Array::from_slice(&[3, 1, 2]).to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(
+        argmin(&array, ArgMinMaxOptions::default()).unwrap(),
+        Some(1)
+    );
+    assert_eq!(
+        argmax(&array, ArgMinMaxOptions::default()).unwrap(),
+        Some(0)
+    );
+
+    let array = BooleanArray::from_slice(&[true]);
+    assert!(!can_argmin(array.data_type()));
+    assert!(argmin(&array, ArgMinMaxOptions::default()).is_err());
+    assert!(argmax(&array, ArgMinMaxOptions::default()).is_err());
+}
@@ -0,0 +1,67 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::aggregate::{all, any};
+
+#[test]
+fn no_nulls() {
+    let array = BooleanArray::from_slice(&[false, false, true]);
+    assert!(any(&array));
+    assert!(!all(&array));
+
+    let array = BooleanArray::from_slice(&[true; 100]);
+    assert!(any(&array));
+    assert!(all(&array));
+
+    let array = BooleanArray::from_slice(&[false; 100]);
+    assert!(!any(&array));
+    assert!(!all(&array));
+}
+
+#[test]
+fn empty() {
+    let array = BooleanArray::from_slice(&[]);
+    assert!(!any(&array));
+    assert!(all(&array));
+}
+
+#[test]
+fn nulls_are_ignored() {
+    let array = BooleanArray::from(&[None, Some(true), None]);
+    assert!(any(&array));
+    assert!(all(&array));
+
+    let array = BooleanArray::from(&[None, None]);
+    assert!(!any(&array));
+    assert!(all(&array));
+}
+
+#[test]
+fn nulls_over_values() {
+    // values that are masked by the validity must be ignored, across words and the remainder
+    let values = Bitmap::from_iter((0..150).map(|i| i % 2 == 0));
+    let validity = Bitmap::from_iter((0..150).map(|i| i % 2 == 0));
+    let array =
+        BooleanArray::from_data(arrow2::datatypes::DataType::Boolean, values, Some(validity));
+    assert!(any(&array));
+    assert!(all(&array));
+
+    let values = Bitmap::from_iter((0..150).map(|i| i == 140));
+    let validity = Bitmap::from_iter((0..150).map(|i| i != 140));
+    let array =
+        BooleanArray::from_data(arrow2::datatypes::DataType::Boolean, values, Some(validity));
+    assert!(!any(&array));
+    assert!(!all(&array));
+}
+
+#[test]
+fn sliced() {
+    let array = BooleanArray::from(&[Some(true), Some(false), None, Some(true), Some(true)]);
+    let array = array.slice(2, 3);
+    assert!(any(&array));
+    assert!(all(&array));
+
+    let array = BooleanArray::from_slice(&[false, true, true, true]).slice(1, 2);
+    assert!(all(&array));
+    let array = BooleanArray::from_slice(&[true; 70]).slice(3, 60);
+    assert!(all(&array));
+}
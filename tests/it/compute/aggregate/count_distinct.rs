@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::aggregate::{can_count_distinct, count_distinct};
+use arrow2::datatypes::DataType;
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from(&[Some(1), None, Some(2), Some(1), None, Some(3)]);
+    assert_eq!(count_distinct(&array, false).unwrap(), 3);
+    assert_eq!(count_distinct(&array, true).unwrap(), 4);
+
+    let array = Float64Array::from_slice(&[1.0, f64::NAN, 1.0, f64::NAN, -0.0, 0.0]);
+    assert_eq!(count_distinct(&array, true).unwrap(), 4);
+
+    let array = Int32Array::from_slice(&[]);
+    assert_eq!(count_distinct(&array, true).unwrap(), 0);
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i64>::from(&[Some("a"), Some("b"), None, Some("a")]);
+    assert_eq!(count_distinct(&array, false).unwrap(), 2);
+    assert_eq!(count_distinct(&array, true).unwrap(), 3);
+}
+
+#[test]
+fn dictionary() {
+    // "c" is unused and "a" is repeated
+    let values = Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c"), Some("a"), None]);
+    let keys = Int32Array::from(&[Some(0), Some(1), Some(3), Some(0), Some(1)]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values.clone()));
+    assert_eq!(count_distinct(&array, true).unwrap(), 2);
+
+    let keys = Int32Array::from(&[Some(0), None, Some(1)]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values.clone()));
+    assert_eq!(count_distinct(&array, false).unwrap(), 2);
+    assert_eq!(count_distinct(&array, true).unwrap(), 3);
+
+    let keys = Int32Array::from_slice(&[4, 2, 1, 0, 3]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+    assert_eq!(count_distinct(&array, false).unwrap(), 3);
+    assert_eq!(count_distinct(&array, true).unwrap(), 4);
+}
+
+#[test]
+fn unsupported() {
+    let array = BooleanArray::from_slice(&[true]);
+    assert!(!can_count_distinct(array.data_type()));
+    assert!(count_distinct(&array, false).is_err());
+    assert!(can_count_distinct(&DataType::Dictionary(
+        arrow2::datatypes::IntegerType::Int8,
+        Box::new(DataType::Utf8),
+        false
+    )));
+}
@@ -2,7 +2,7 @@ use arrow2::compute::aggregate::{
     max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
     min_string,
 };
-use arrow2::{array::*, datatypes::DataType};
+use arrow2::{array::*, datatypes::DataType, types::f16};
 
 #[test]
 fn test_primitive_array_min_max() {
@@ -25,6 +25,20 @@ fn test_primitive_min_max_1() {
     assert_eq!(Some(5), max_primitive(&a));
 }
 
+#[test]
+fn test_primitive_array_min_max_f16() {
+    let a = (0..100)
+        .map(|x| Some(f16::from_f32(x as f32 - 50.0)))
+        .chain(std::iter::once(None))
+        .collect::<PrimitiveArray<f16>>();
+    assert_eq!(Some(f16::from_f32(-50.0)), min_primitive(&a));
+    assert_eq!(Some(f16::from_f32(49.0)), max_primitive(&a));
+
+    let a = PrimitiveArray::from_slice(&[f16::NAN, f16::from_f32(1.0), f16::NEG_INFINITY]);
+    assert_eq!(Some(f16::NEG_INFINITY), min_primitive(&a));
+    assert_eq!(Some(f16::from_f32(1.0)), max_primitive(&a));
+}
+
 #[test]
 fn decimal() {
     let a = Int128Array::from(&[None, None, Some(5), Some(2)]);
@@ -1,3 +1,9 @@
+mod approx_distinct;
+mod arg_min_max;
+mod boolean;
+mod count_distinct;
 mod memory;
 mod min_max;
+mod quantile;
 mod sum;
+mod variance;
@@ -0,0 +1,84 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{
+    can_quantile, median, median_primitive, quantile, quantile_primitive, QuantileInterpolation,
+};
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Scalar};
+
+#[test]
+fn interpolations() {
+    let a = Int32Array::from_slice(&[5, 1, 4, 2, 3]);
+    let check = |q: f64, interpolation, expected: f64| {
+        let result = quantile_primitive(&a, q, interpolation).unwrap().unwrap();
+        assert_eq!(result, expected, "q = {}, {:?}", q, interpolation);
+    };
+    use QuantileInterpolation::*;
+
+    // the position is 0.3 * 4 = 1.2, between the values 2 and 3
+    check(0.3, Linear, 2.2);
+    check(0.3, Lower, 2.0);
+    check(0.3, Higher, 3.0);
+    check(0.3, Nearest, 2.0);
+    check(0.3, Midpoint, 2.5);
+
+    // the position is 0.625 * 4 = 2.5, halfway between the values 3 and 4
+    check(0.625, Nearest, 3.0);
+    // the position is 0.875 * 4 = 3.5, halfway between the values 4 and 5
+    check(0.875, Nearest, 5.0);
+
+    for interpolation in [Linear, Lower, Higher, Nearest, Midpoint] {
+        check(0.0, interpolation, 1.0);
+        check(0.5, interpolation, 3.0);
+        check(1.0, interpolation, 5.0);
+    }
+}
+
+#[test]
+fn median_() {
+    let a = Float64Array::from(&[Some(3.0), None, Some(-1.0), Some(10.0), Some(2.0)]);
+    assert_eq!(median_primitive(&a), Some(2.5));
+
+    let a = Float64Array::from(&[Some(1.0), Some(f64::NAN), Some(0.0)]);
+    assert_eq!(median_primitive(&a), Some(1.0));
+
+    let a = UInt64Array::from(&[None, None]);
+    assert_eq!(median_primitive(&a), None);
+}
+
+#[test]
+fn repeated_values() {
+    let a = Int64Array::from_slice(&[7, 7, 7, 1, 7, 9, 7]);
+    let result = quantile_primitive(&a, 0.9, QuantileInterpolation::Linear).unwrap();
+    assert!((result.unwrap() - 7.8).abs() < 1e-12);
+}
+
+#[test]
+fn invalid_quantile() {
+    let a = Int32Array::from_slice(&[1]);
+    assert!(quantile_primitive(&a, 1.5, QuantileInterpolation::Linear).is_err());
+    assert!(quantile_primitive(&a, -0.1, QuantileInterpolation::Linear).is_err());
+    assert!(quantile_primitive(&a, f64::NAN, QuantileInterpolation::Linear).is_err());
+}
+
+#[test]
+fn dynamic() {
+    let a = UInt8Array::from_slice(&[1, 2, 3, 4]);
+    assert_eq!(
+        median(&a).unwrap().as_ref(),
+        &PrimitiveScalar::from(Some(2.5f64)) as &dyn Scalar
+    );
+    assert_eq!(
+        quantile(&a, 0.25, QuantileInterpolation::Higher)
+            .unwrap()
+            .as_ref(),
+        &PrimitiveScalar::from(Some(2.0f64)) as &dyn Scalar
+    );
+
+    let a = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(!can_quantile(a.data_type()));
+    assert!(median(&a).is_err());
+
+    let a = PrimitiveArray::<i128>::from_slice(&[1]).to(DataType::Decimal(5, 2));
+    assert!(!can_quantile(a.data_type()));
+    assert!(median(&a).is_err());
+}
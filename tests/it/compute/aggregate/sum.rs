@@ -3,6 +3,7 @@ use arrow2::compute::aggregate::{sum, sum_primitive};
 use arrow2::compute::arithmetics;
 use arrow2::datatypes::DataType;
 use arrow2::scalar::{PrimitiveScalar, Scalar};
+use arrow2::types::f16;
 
 #[test]
 fn test_primitive_array_sum() {
@@ -25,6 +26,23 @@ fn test_primitive_array_float_sum() {
     assert!((16.5 - sum_primitive(&a).unwrap()).abs() < f64::EPSILON);
 }
 
+#[test]
+fn test_primitive_array_f16_sum() {
+    let a = (0..100)
+        .map(|x| {
+            if x % 3 == 0 {
+                None
+            } else {
+                Some(f16::from_f32(0.5))
+            }
+        })
+        .collect::<PrimitiveArray<f16>>();
+    assert_eq!(
+        &PrimitiveScalar::<f16>::from(Some(f16::from_f32(33.0))) as &dyn Scalar,
+        sum(&a).unwrap().as_ref()
+    );
+}
+
 #[test]
 fn test_primitive_array_sum_with_nulls() {
     let a = Int32Array::from(&[None, Some(2), Some(3), None, Some(5)]);
@@ -0,0 +1,84 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{
+    can_variance, stddev, stddev_primitive, variance, variance_primitive,
+};
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Scalar};
+use arrow2::types::f16;
+
+fn assert_close(result: Option<f64>, expected: f64) {
+    let result = result.unwrap();
+    assert!(
+        (result - expected).abs() < 1e-9,
+        "{} != {}",
+        result,
+        expected
+    );
+}
+
+#[test]
+fn primitive() {
+    let a = Float64Array::from_slice(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+    assert_close(variance_primitive(&a, 0), 4.0);
+    assert_close(stddev_primitive(&a, 0), 2.0);
+    assert_close(variance_primitive(&a, 1), 32.0 / 7.0);
+
+    let a = UInt8Array::from(&[Some(1), None, Some(3), None]);
+    assert_close(variance_primitive(&a, 0), 1.0);
+    assert_close(variance_primitive(&a, 1), 2.0);
+}
+
+#[test]
+fn too_few_values() {
+    let a = Int32Array::from(&[Some(1), None]);
+    assert_close(variance_primitive(&a, 0), 0.0);
+    assert_eq!(variance_primitive(&a, 1), None);
+
+    let a = Int32Array::from_slice(&[]);
+    assert_eq!(variance_primitive(&a, 0), None);
+}
+
+#[test]
+fn numerically_stable() {
+    // the naive sum of squares loses all precision with such an offset
+    let offset = 1e9;
+    let a = Float64Array::from_slice(&[offset + 4.0, offset + 7.0, offset + 13.0, offset + 16.0]);
+    assert_close(variance_primitive(&a, 1), 30.0);
+}
+
+#[test]
+fn dynamic() {
+    let a = Int64Array::from_slice(&[1, 2, 3, 4]);
+    assert_eq!(
+        variance(&a, 0).unwrap().as_ref(),
+        &PrimitiveScalar::from(Some(1.25f64)) as &dyn Scalar
+    );
+    assert_eq!(
+        stddev(&a, 0).unwrap().as_ref(),
+        &PrimitiveScalar::from(Some(1.25f64.sqrt())) as &dyn Scalar
+    );
+    assert_eq!(
+        variance(&a, 4).unwrap().as_ref(),
+        &PrimitiveScalar::<f64>::from(None) as &dyn Scalar
+    );
+
+    let a = PrimitiveArray::<f16>::from_slice(&[f16::from_f32(1.0), f16::from_f32(3.0)]);
+    assert_eq!(
+        variance(&a, 0).unwrap().as_ref(),
+        &PrimitiveScalar::from(Some(1.0f64)) as &dyn Scalar
+    );
+}
+
+#[test]
+fn unsupported() {
+    let a = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(!can_variance(a.data_type()));
+    assert!(variance(&a, 0).is_err());
+
+    let a = PrimitiveArray::<i128>::from_slice(&[1]).to(DataType::Decimal(5, 2));
+    assert!(!can_variance(a.data_type()));
+    assert!(stddev(&a, 0).is_err());
+
+    assert!(can_variance(&DataType::Float16));
+    assert!(can_variance(&DataType::Date32));
+}
@@ -0,0 +1,75 @@
+use std::f64::consts::{E, FRAC_PI_2, FRAC_PI_4, PI};
+
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::*;
+
+fn assert_close(result: &Float64Array, expected: &[Option<f64>]) {
+    assert_eq!(result.len(), expected.len());
+    result.iter().zip(expected).for_each(|(r, e)| match (r, e) {
+        (Some(r), Some(e)) if e.is_nan() => assert!(r.is_nan()),
+        (Some(r), Some(e)) => assert!(r == e || (r - e).abs() < 1e-12, "{} != {}", r, e),
+        (r, e) => assert_eq!(r.copied(), *e),
+    })
+}
+
+#[test]
+fn trigonometric() {
+    let a = Float64Array::from(&[Some(0.0), None, Some(FRAC_PI_2)]);
+    assert_close(&sin(&a), &[Some(0.0), None, Some(1.0)]);
+    assert_close(&cos(&a), &[Some(1.0), None, Some(0.0)]);
+
+    let a = Float64Array::from(&[Some(FRAC_PI_4), None]);
+    assert_close(&tan(&a), &[Some(1.0), None]);
+}
+
+#[test]
+fn inverse_trigonometric() {
+    let a = Float64Array::from(&[Some(1.0), None, Some(2.0)]);
+    assert_close(&asin(&a), &[Some(FRAC_PI_2), None, Some(f64::NAN)]);
+    assert_close(&acos(&a), &[Some(0.0), None, Some(f64::NAN)]);
+    assert_close(&atan(&a), &[Some(FRAC_PI_4), None, Some(2.0f64.atan())]);
+
+    let y = Float64Array::from(&[Some(1.0), Some(0.0), None]);
+    let x = Float64Array::from(&[Some(-1.0), Some(-1.0), Some(1.0)]);
+    assert_close(&atan2(&y, &x), &[Some(3.0 * FRAC_PI_4), Some(PI), None]);
+}
+
+#[test]
+fn logarithms() {
+    let a = Float64Array::from(&[Some(1.0), None, Some(0.0), Some(-1.0), Some(1024.0)]);
+    assert_close(
+        &ln(&a),
+        &[
+            Some(0.0),
+            None,
+            Some(f64::NEG_INFINITY),
+            Some(f64::NAN),
+            Some(1024f64.ln()),
+        ],
+    );
+    assert_close(
+        &log2(&a),
+        &[
+            Some(0.0),
+            None,
+            Some(f64::NEG_INFINITY),
+            Some(f64::NAN),
+            Some(10.0),
+        ],
+    );
+    let a = Float64Array::from(&[Some(1000.0), None]);
+    assert_close(&log10(&a), &[Some(3.0), None]);
+}
+
+#[test]
+fn exp_sqrt() {
+    let a = Float64Array::from(&[Some(1.0), None, Some(4.0), Some(-4.0)]);
+    assert_close(
+        &exp(&a),
+        &[Some(E), None, Some(4f64.exp()), Some((-4f64).exp())],
+    );
+    assert_close(&sqrt(&a), &[Some(1.0), None, Some(2.0), Some(f64::NAN)]);
+
+    let a = Float32Array::from(&[Some(9.0), None]);
+    assert_eq!(sqrt(&a), Float32Array::from(&[Some(3.0), None]));
+}
@@ -1,6 +1,8 @@
 mod add;
 mod div;
+mod math;
 mod mul;
 mod pow;
 mod rem;
+mod round;
 mod sub;
@@ -16,3 +16,30 @@ fn test_raise_power_scalar_checked() {
     let expected = Int8Array::from(&[Some(1i8), None, None]);
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn test_raise_power_float() {
+    let a = Float32Array::from(&[Some(2.0), None, Some(9.0), Some(-1.0)]);
+    let b = Float32Array::from(&[Some(-1.0), Some(2.0), Some(0.5), Some(0.5)]);
+    let actual = powf(&a, &b);
+    assert_eq!(actual.value(0), 0.5);
+    assert!(actual.is_null(1));
+    assert_eq!(actual.value(2), 3.0);
+    assert!(actual.value(3).is_nan());
+}
+
+#[test]
+fn test_raise_power_checked() {
+    let a = UInt8Array::from(&[Some(2), None, Some(16), Some(0)]);
+    let b = UInt8Array::from(&[Some(7), Some(1), Some(2), Some(0)]);
+    let actual = checked_pow(&a, &b);
+    let expected = UInt8Array::from(&[Some(128), None, None, Some(1)]);
+    assert_eq!(expected, actual);
+
+    let a = Int64Array::from(&[Some(-3), None]);
+    assert_eq!(
+        checked_pow_scalar(&a, &3),
+        Int64Array::from(&[Some(-27), None])
+    );
+    assert_eq!(checked_pow_scalar(&a, &-1), Int64Array::from(&[None, None]));
+}
@@ -0,0 +1,66 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::*;
+use arrow2::compute::arithmetics::RoundMode;
+
+#[test]
+fn test_round() {
+    let a = Float64Array::from(&[Some(0.5), Some(1.5), Some(-2.5), None, Some(2.675)]);
+
+    let result = round(&a, 0, RoundMode::HalfEven);
+    let expected = Float64Array::from(&[Some(0.0), Some(2.0), Some(-2.0), None, Some(3.0)]);
+    assert_eq!(result, expected);
+
+    let result = round(&a, 0, RoundMode::HalfUp);
+    let expected = Float64Array::from(&[Some(1.0), Some(2.0), Some(-3.0), None, Some(3.0)]);
+    assert_eq!(result, expected);
+
+    let a = Float64Array::from(&[Some(1.125), Some(-0.375)]);
+    let result = round(&a, 2, RoundMode::HalfEven);
+    assert_eq!(result, Float64Array::from(&[Some(1.12), Some(-0.38)]));
+}
+
+#[test]
+fn test_round_negative_decimals() {
+    let a = Float32Array::from(&[Some(1250.0), Some(1350.0), Some(-49.0)]);
+
+    let result = round(&a, -2, RoundMode::HalfEven);
+    assert_eq!(
+        result,
+        Float32Array::from(&[Some(1200.0), Some(1400.0), Some(-0.0)])
+    );
+
+    assert_eq!(
+        floor(&a, -2),
+        Float32Array::from(&[Some(1200.0), Some(1300.0), Some(-100.0)])
+    );
+    assert_eq!(
+        ceil(&a, -3),
+        Float32Array::from(&[Some(2000.0), Some(2000.0), Some(-0.0)])
+    );
+}
+
+#[test]
+fn test_floor_ceil() {
+    let a = Float64Array::from(&[Some(1.234), None, Some(-1.234)]);
+    assert_eq!(
+        floor(&a, 1),
+        Float64Array::from(&[Some(1.2), None, Some(-1.3)])
+    );
+    assert_eq!(
+        ceil(&a, 1),
+        Float64Array::from(&[Some(1.3), None, Some(-1.2)])
+    );
+    assert_eq!(
+        floor(&a, 0),
+        Float64Array::from(&[Some(1.0), None, Some(-2.0)])
+    );
+}
+
+#[test]
+fn test_round_non_finite() {
+    let a = Float64Array::from_slice(&[f64::MAX, f64::INFINITY]);
+    assert_eq!(round(&a, 10, RoundMode::HalfUp), a);
+
+    let a = Float64Array::from_slice(&[f64::NAN]);
+    assert!(ceil(&a, 2).value(0).is_nan());
+}
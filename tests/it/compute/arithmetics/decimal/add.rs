@@ -22,6 +22,10 @@ fn test_add_normal() {
     // Testing trait
     let result = a.add(&b);
     assert_eq!(result, expected);
+
+    // Testing the dynamically typed kernel
+    let result = arrow2::compute::arithmetics::add(&a, &b);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
 }
 
 #[test]
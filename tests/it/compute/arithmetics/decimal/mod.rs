@@ -1,4 +1,6 @@
 mod add;
 mod div;
 mod mul;
+mod rescaled;
+mod round;
 mod sub;
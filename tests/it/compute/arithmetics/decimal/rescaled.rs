@@ -0,0 +1,71 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::decimal::{
+    rescaled_add, rescaled_div, rescaled_mul, rescaled_sub,
+};
+use arrow2::datatypes::DataType;
+
+fn decimal(values: &[Option<i128>], precision: usize, scale: usize) -> PrimitiveArray<i128> {
+    PrimitiveArray::from(values).to(DataType::Decimal(precision, scale))
+}
+
+#[test]
+fn test_add_sub() {
+    // 123.45, -0.5, null
+    let a = decimal(&[Some(12345), Some(-50), None], 5, 2);
+    // 1.001, 0.25, 1
+    let b = decimal(&[Some(1001), Some(250), Some(1000)], 4, 3);
+
+    let result = rescaled_add(&a, &b).unwrap();
+    let expected = decimal(&[Some(124451), Some(-250), None], 7, 3);
+    assert_eq!(result, expected);
+
+    let result = rescaled_sub(&a, &b).unwrap();
+    let expected = decimal(&[Some(122449), Some(-750), None], 7, 3);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_mul() {
+    // 1.5, -2.25
+    let a = decimal(&[Some(15), Some(-225)], 3, 1);
+    let b = decimal(&[Some(-3), Some(11)], 2, 0);
+    let result = rescaled_mul(&a, &b).unwrap();
+    let expected = decimal(&[Some(-45), Some(-2475)], 6, 1);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_mul_reduces_scale() {
+    // 0.5 * 0.5 with 19 digits of scale each results in 38 digits of scale
+    let half = 5 * 10i128.pow(18);
+    let a = decimal(&[Some(half)], 19, 19);
+    let result = rescaled_mul(&a, &a).unwrap();
+    // precision 39 -> 38, integral digits 1, scale 38 -> 37
+    let expected = decimal(&[Some(25 * 10i128.pow(35))], 38, 37);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_div() {
+    // 2 / 3, -1 / 8, null divided by zero
+    let a = decimal(&[Some(2), Some(-1), None], 3, 0);
+    let b = decimal(&[Some(3), Some(8), Some(0)], 2, 0);
+    let result = rescaled_div(&a, &b).unwrap();
+    // scale max(6, 0 + 2 + 1) = 6, precision 3 + 6
+    let expected = decimal(&[Some(666667), Some(-125000), None], 9, 6);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_errors() {
+    let a = decimal(&[Some(1)], 3, 0);
+    let zero = decimal(&[Some(0)], 3, 0);
+    assert!(rescaled_div(&a, &zero).is_err());
+
+    let max = decimal(&[Some(10i128.pow(38) - 1)], 38, 0);
+    assert!(rescaled_add(&max, &a).is_err());
+    assert!(rescaled_mul(&max, &max).is_err());
+
+    let a = decimal(&[Some(1), Some(2)], 3, 0);
+    assert!(rescaled_add(&a, &zero).is_err());
+}
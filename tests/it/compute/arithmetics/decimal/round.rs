@@ -0,0 +1,84 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::decimal::{ceil, floor, round};
+use arrow2::compute::arithmetics::RoundMode;
+use arrow2::datatypes::DataType;
+
+fn decimal(values: &[Option<i128>], precision: usize, scale: usize) -> PrimitiveArray<i128> {
+    PrimitiveArray::from(values).to(DataType::Decimal(precision, scale))
+}
+
+#[test]
+fn test_round() {
+    // 1.2345, 1.2350, 1.2250, -1.2250
+    let a = decimal(
+        &[Some(12345), Some(12350), Some(12250), None, Some(-12250)],
+        5,
+        4,
+    );
+
+    let result = round(&a, 2, RoundMode::HalfEven).unwrap();
+    let expected = decimal(&[Some(123), Some(124), Some(122), None, Some(-122)], 4, 2);
+    assert_eq!(result, expected);
+
+    let result = round(&a, 2, RoundMode::HalfUp).unwrap();
+    let expected = decimal(&[Some(123), Some(124), Some(123), None, Some(-123)], 4, 2);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_round_negative_decimals() {
+    // 1250.5, 1350.0, -49.9
+    let a = decimal(&[Some(12505), Some(13500), Some(-499)], 5, 1);
+
+    let result = round(&a, -2, RoundMode::HalfEven).unwrap();
+    let expected = decimal(&[Some(1300), Some(1400), Some(0)], 5, 0);
+    assert_eq!(result, expected);
+
+    let result = floor(&a, -2).unwrap();
+    let expected = decimal(&[Some(1200), Some(1300), Some(-100)], 5, 0);
+    assert_eq!(result, expected);
+
+    let result = ceil(&a, 0).unwrap();
+    let expected = decimal(&[Some(1251), Some(1350), Some(-49)], 5, 0);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_round_noop_and_overflow() {
+    let a = decimal(&[Some(123)], 3, 2);
+    assert_eq!(round(&a, 2, RoundMode::HalfUp).unwrap(), a);
+    assert_eq!(ceil(&a, 5).unwrap(), a);
+
+    let max = 10i128.pow(38) - 1;
+    let a = decimal(&[Some(max), Some(1)], 38, 0);
+    let result = round(&a, -1, RoundMode::HalfUp).unwrap();
+    assert_eq!(result, decimal(&[None, Some(0)], 38, 0));
+}
+
+#[test]
+fn test_round_errors() {
+    let a = decimal(&[Some(1)], 5, 2);
+    assert!(floor(&a, -37).is_err());
+}
+
+#[test]
+fn test_round_all_digits() {
+    // 0.6, 0.5 and -0.9 with 38 digits, whose remainders overflow when doubled
+    let a = decimal(
+        &[
+            Some(6 * 10i128.pow(37)),
+            Some(5 * 10i128.pow(37)),
+            Some(-9 * 10i128.pow(37)),
+        ],
+        38,
+        38,
+    );
+
+    let result = round(&a, 0, RoundMode::HalfEven).unwrap();
+    let expected = decimal(&[Some(1), Some(0), Some(-1)], 1, 0);
+    assert_eq!(result, expected);
+
+    let result = round(&a, 0, RoundMode::HalfUp).unwrap();
+    let expected = decimal(&[Some(1), Some(1), Some(-1)], 1, 0);
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,192 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::decimal256::*;
+use arrow2::compute::arithmetics::{self, ArrayAdd, ArrayCheckedMul, ArraySaturatingSub};
+use arrow2::datatypes::DataType;
+use arrow2::scalar::PrimitiveScalar;
+use arrow2::types::i256;
+
+fn decimal(values: &[Option<i128>], precision: usize, scale: usize) -> PrimitiveArray<i256> {
+    let values = values.iter().map(|x| x.map(i256::from)).collect::<Vec<_>>();
+    PrimitiveArray::from(values).to(DataType::Decimal256(precision, scale))
+}
+
+// 10^exponent as a i256
+fn pow10(exponent: u32) -> i256 {
+    i256::from(10).checked_pow(exponent).unwrap()
+}
+
+#[test]
+fn test_add_sub() {
+    let a = decimal(&[Some(11111), Some(-5), None], 50, 2);
+    let b = decimal(&[Some(22222), Some(10), Some(1)], 50, 2);
+
+    let expected = decimal(&[Some(33333), Some(5), None], 50, 2);
+    assert_eq!(add(&a, &b), expected);
+    assert_eq!(a.add(&b), expected);
+    assert_eq!(checked_add(&a, &b), expected);
+    assert_eq!(saturating_add(&a, &b), expected);
+    assert_eq!(arithmetics::add(&a, &b).as_ref(), &expected as &dyn Array);
+
+    let expected = decimal(&[Some(-11111), Some(-15), None], 50, 2);
+    assert_eq!(sub(&a, &b), expected);
+    assert_eq!(checked_sub(&a, &b), expected);
+    assert_eq!(a.saturating_sub(&b), expected);
+}
+
+#[test]
+fn test_add_beyond_i128() {
+    let value = i256::from(i128::MAX);
+    let a = PrimitiveArray::from([Some(value)]).to(DataType::Decimal256(76, 0));
+    let result = add(&a, &a);
+    assert_eq!(result.value(0), value * i256::from(2));
+}
+
+#[test]
+#[should_panic(expected = "Overflow in addition presented for precision 40")]
+fn test_add_panic() {
+    let max = pow10(40) - i256::ONE;
+    let a = PrimitiveArray::from([Some(max)]).to(DataType::Decimal256(40, 2));
+    let b = PrimitiveArray::from([Some(i256::ONE)]).to(DataType::Decimal256(40, 2));
+    let _ = add(&a, &b);
+}
+
+#[test]
+#[should_panic]
+fn test_add_wrong_precision() {
+    let a = decimal(&[None], 50, 2);
+    let b = decimal(&[None], 51, 2);
+    add(&a, &b);
+}
+
+#[test]
+fn test_overflow_checked_saturating() {
+    let max = pow10(40) - i256::ONE;
+    let a = PrimitiveArray::from([Some(max), Some(-max), Some(i256::ONE)])
+        .to(DataType::Decimal256(40, 0));
+    let b = PrimitiveArray::from([Some(i256::ONE), Some(i256::ONE), Some(i256::ONE)])
+        .to(DataType::Decimal256(40, 0));
+
+    let result = checked_add(&a, &b);
+    let expected = PrimitiveArray::from([None, Some(-max + i256::ONE), Some(i256::from(2))])
+        .to(DataType::Decimal256(40, 0));
+    assert_eq!(result, expected);
+
+    let result = saturating_sub(&a, &b);
+    let expected = PrimitiveArray::from([Some(max - i256::ONE), Some(-max), Some(i256::ZERO)])
+        .to(DataType::Decimal256(40, 0));
+    assert_eq!(result, expected);
+
+    // the products overflow 256 bits
+    let big = PrimitiveArray::from([Some(max), Some(-max)]).to(DataType::Decimal256(76, 0));
+    let result = saturating_mul(&big, &big.clone().with_validity(None));
+    let max76 = pow10(76) - i256::ONE;
+    let expected = PrimitiveArray::from([Some(max76), Some(max76)]).to(DataType::Decimal256(76, 0));
+    assert_eq!(result, expected);
+    let result = big.checked_mul(&big);
+    let expected = PrimitiveArray::<i256>::from([None, None]).to(DataType::Decimal256(76, 0));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_mul() {
+    // 1.50 * 2.25, -0.01 * 0.50
+    let a = decimal(&[Some(150), Some(-1), None], 50, 2);
+    let b = decimal(&[Some(225), Some(50), Some(1)], 50, 2);
+
+    let expected = decimal(&[Some(337), Some(0), None], 50, 2);
+    assert_eq!(mul(&a, &b), expected);
+    assert_eq!(checked_mul(&a, &b), expected);
+    assert_eq!(saturating_mul(&a, &b), expected);
+
+    // 10^30.00 * 10^30.00 does not fit in 128 bits
+    let a = PrimitiveArray::from([Some(pow10(32))]).to(DataType::Decimal256(70, 2));
+    let expected = PrimitiveArray::from([Some(pow10(62))]).to(DataType::Decimal256(70, 2));
+    assert_eq!(mul(&a, &a), expected);
+
+    let scalar = PrimitiveScalar::new(DataType::Decimal256(70, 2), Some(pow10(32)));
+    assert_eq!(mul_scalar(&a, &scalar), expected);
+}
+
+#[test]
+fn test_div() {
+    // 1.00 / 3.00, -2.00 / 0.50
+    let a = decimal(&[Some(100), Some(-200), None], 50, 2);
+    let b = decimal(&[Some(300), Some(50), Some(1)], 50, 2);
+
+    let expected = decimal(&[Some(33), Some(-400), None], 50, 2);
+    assert_eq!(div(&a, &b), expected);
+    assert_eq!(checked_div(&a, &b), expected);
+    assert_eq!(saturating_div(&a, &b), expected);
+    assert_eq!(arithmetics::div(&a, &b).as_ref(), &expected as &dyn Array);
+
+    let scalar = PrimitiveScalar::new(DataType::Decimal256(50, 2), Some(i256::from(-50)));
+    let expected = decimal(&[Some(-200), Some(400), None], 50, 2);
+    assert_eq!(div_scalar(&a, &scalar), expected);
+
+    let zero = decimal(&[Some(0), Some(0), Some(0)], 50, 2);
+    assert_eq!(checked_div(&a, &zero), decimal(&[None, None, None], 50, 2));
+    assert_eq!(
+        saturating_div(&a, &zero),
+        decimal(&[Some(0), Some(0), None], 50, 2)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Found division by zero")]
+fn test_div_by_zero() {
+    let a = decimal(&[Some(1)], 50, 2);
+    let b = decimal(&[Some(0)], 50, 2);
+    let _ = div(&a, &b);
+}
+
+#[test]
+fn test_rescaled() {
+    // 123.45 + 1.001, -0.5 + 0.25
+    let a = decimal(&[Some(12345), Some(-50), None], 45, 2);
+    let b = decimal(&[Some(1001), Some(250), Some(1000)], 4, 3);
+
+    let result = rescaled_add(&a, &b).unwrap();
+    assert_eq!(result, decimal(&[Some(124451), Some(-250), None], 47, 3));
+    let result = rescaled_sub(&a, &b).unwrap();
+    assert_eq!(result, decimal(&[Some(122449), Some(-750), None], 47, 3));
+
+    let result = rescaled_mul(&a, &b).unwrap();
+    assert_eq!(
+        result,
+        decimal(&[Some(12357345), Some(-12500), None], 50, 5)
+    );
+
+    // 2 / 3
+    let a = decimal(&[Some(2)], 1, 0);
+    let b = decimal(&[Some(3)], 1, 0);
+    let result = rescaled_div(&a, &b).unwrap();
+    assert_eq!(result, decimal(&[Some(666667)], 7, 6));
+
+    let zero = decimal(&[Some(0)], 1, 0);
+    assert!(rescaled_div(&a, &zero).is_err());
+}
+
+#[test]
+fn test_rescaled_reduces_scale() {
+    // 0.5 * 0.5 with 38 digits of scale each results in 76 digits of scale
+    let half = i256::from(5) * pow10(37);
+    let a = PrimitiveArray::from([Some(half)]).to(DataType::Decimal256(38, 38));
+    let result = rescaled_mul(&a, &a).unwrap();
+    // precision 77 -> 76, integral digits 1, scale 76 -> 75
+    let expected =
+        PrimitiveArray::from([Some(i256::from(25) * pow10(73))]).to(DataType::Decimal256(76, 75));
+    assert_eq!(result, expected);
+
+    let max = pow10(76) - i256::ONE;
+    let a = PrimitiveArray::from([Some(max)]).to(DataType::Decimal256(76, 0));
+    assert!(rescaled_add(&a, &a).is_err());
+}
+
+#[test]
+fn test_fmt() {
+    let a = decimal(&[Some(12345), Some(-5), Some(0), None], 50, 2);
+    assert_eq!(
+        format!("{:?}", a),
+        "Decimal256(50, 2)[123.45, -0.05, 0.00, None]"
+    );
+}
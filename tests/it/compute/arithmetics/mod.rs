@@ -1,5 +1,6 @@
 mod basic;
 mod decimal;
+mod decimal256;
 mod time;
 
 use arrow2::array::*;
@@ -7,6 +8,7 @@ use arrow2::compute::arithmetics::*;
 use arrow2::datatypes::DataType::*;
 use arrow2::datatypes::{IntervalUnit, TimeUnit};
 use arrow2::scalar::PrimitiveScalar;
+use arrow2::types::f16;
 
 #[test]
 fn test_add() {
@@ -26,6 +28,96 @@ fn test_add_scalar() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn test_add_f16() {
+    let a = PrimitiveArray::from(&[Some(f16::from_f32(1.5)), None, Some(f16::from_f32(2.0))]);
+    let b = PrimitiveArray::from(&[
+        Some(f16::from_f32(0.25)),
+        Some(f16::from_f32(1.0)),
+        Some(f16::from_f32(-2.0)),
+    ]);
+    let result = add(&a, &b);
+    let expected =
+        PrimitiveArray::from(&[Some(f16::from_f32(1.75)), None, Some(f16::from_f32(0.0))]);
+    assert_eq!(expected, result.as_ref());
+
+    let b: PrimitiveScalar<f16> = Some(f16::from_f32(2.0)).into();
+    let result = mul_scalar(&a, &b);
+    let expected =
+        PrimitiveArray::from(&[Some(f16::from_f32(3.0)), None, Some(f16::from_f32(4.0))]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_pow() {
+    let a = Int32Array::from(&[Some(2), None, Some(i32::MAX), Some(3)]);
+    let b = Int32Array::from(&[Some(10), Some(1), Some(2), Some(-1)]);
+    let result = pow(&a, &b).unwrap();
+    let expected = Int32Array::from(&[Some(1024), None, None, None]);
+    assert_eq!(expected, result.as_ref());
+
+    let a = Float64Array::from(&[Some(4.0), None]);
+    let b: PrimitiveScalar<f64> = Some(0.5).into();
+    let result = pow_scalar(&a, &b).unwrap();
+    let expected = Float64Array::from(&[Some(2.0), None]);
+    assert_eq!(expected, result.as_ref());
+
+    let b: PrimitiveScalar<f64> = None.into();
+    let result = pow_scalar(&a, &b).unwrap();
+    let expected = Float64Array::from(&[None, None]);
+    assert_eq!(expected, result.as_ref());
+
+    // unsupported types are an error
+    let a = Utf8Array::<i32>::from_slice(&["a"]);
+    let b = Int32Array::from_slice(&[1]);
+    assert!(pow(&a, &b).is_err());
+}
+
+#[test]
+fn test_dictionary_scalar() {
+    let a = DictionaryArray::<i32>::from_data(
+        Int32Array::from(&[Some(0), None, Some(1), Some(0)]),
+        std::sync::Arc::new(Int64Array::from(&[Some(10), None])),
+    );
+    let b: PrimitiveScalar<i64> = Some(5i64).into();
+
+    let result = add_scalar(&a, &b);
+    let expected = DictionaryArray::<i32>::from_data(
+        Int32Array::from(&[Some(0), None, Some(1), Some(0)]),
+        std::sync::Arc::new(Int64Array::from(&[Some(15), None])),
+    );
+    assert_eq!(expected, result.as_ref());
+
+    let result = mul_scalar(&a, &b);
+    let expected = DictionaryArray::<i32>::from_data(
+        Int32Array::from(&[Some(0), None, Some(1), Some(0)]),
+        std::sync::Arc::new(Int64Array::from(&[Some(50), None])),
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_dictionary_array() {
+    let a = DictionaryArray::<u8>::from_data(
+        UInt8Array::from(&[Some(1), Some(0), None]),
+        std::sync::Arc::new(Int32Array::from_slice(&[1, 2])),
+    );
+    let b = Int32Array::from_slice(&[10, 20, 30]);
+
+    let result = sub(&b, &a);
+    let expected = Int32Array::from(&[Some(8), Some(19), None]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = add(&a, &a);
+    let expected = Int32Array::from(&[Some(4), Some(2), None]);
+    assert_eq!(expected, result.as_ref());
+
+    let data_type = a.data_type();
+    assert!(can_add(data_type, &Int32));
+    assert!(can_rem(&Int32, data_type));
+    assert!(!can_mul(data_type, &Int64));
+}
+
 #[test]
 fn consistency() {
     let datatypes = vec![
@@ -39,6 +131,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Timestamp(TimeUnit::Second, None),
@@ -82,6 +175,9 @@ fn consistency() {
         if can_rem(&lhs, &rhs) {
             rem(lhs_a.as_ref(), rhs_a.as_ref());
         }
+        if can_pow(&lhs, &rhs) {
+            pow(lhs_a.as_ref(), rhs_a.as_ref()).unwrap();
+        }
     });
 }
 
@@ -1,7 +1,8 @@
 use arrow2::array::*;
 use arrow2::compute::arithmetics::time::*;
-use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::datatypes::{DataType, IntervalUnit, TimeUnit};
 use arrow2::scalar::*;
+use arrow2::types::{days_ms, months_days_ns};
 
 #[test]
 fn test_adding_timestamp() {
@@ -317,3 +318,232 @@ fn test_date64() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_date32_calendar_interval() {
+    // 2021-01-31, 2020-01-31, 2021-03-31
+    let date_32 =
+        Int32Array::from(&[Some(18_658), Some(18_292), Some(18_717), None]).to(DataType::Date32);
+    let months = Int32Array::from(&[Some(1), Some(1), Some(-1), Some(1)])
+        .to(DataType::Interval(IntervalUnit::YearMonth));
+
+    let result = add_calendar_interval(&date_32, &months).unwrap();
+    // 2021-02-28, 2020-02-29, 2021-02-28
+    let expected =
+        Int32Array::from(&[Some(18_686), Some(18_321), Some(18_686), None]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let result = sub_calendar_interval(&date_32, &months).unwrap();
+    // 2020-12-31, 2019-12-31, 2021-04-30
+    let expected =
+        Int32Array::from(&[Some(18_627), Some(18_261), Some(18_747), None]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    // the time of the interval is rounded towards the past day
+    let interval = PrimitiveScalar::new(
+        DataType::Interval(IntervalUnit::DayTime),
+        Some(days_ms::new(1, -1)),
+    );
+    let result = add_calendar_interval_scalar(&date_32, &interval).unwrap();
+    let expected =
+        Int32Array::from(&[Some(18_658), Some(18_292), Some(18_717), None]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_date64_calendar_interval() {
+    // 2021-01-31
+    let date_64 = Int64Array::from(&[Some(18_658 * 86_400_000), None]).to(DataType::Date64);
+    let interval = PrimitiveArray::from_slice(&[
+        months_days_ns::new(1, 1, 1_000_000),
+        months_days_ns::new(1, 1, 1_000_000),
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+    let result = add_calendar_interval(&date_64, &interval).unwrap();
+    // 2021-03-01 00:00:00.001
+    let expected = Int64Array::from(&[Some(18_687 * 86_400_000 + 1), None]).to(DataType::Date64);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_timestamp_calendar_interval_scalar() {
+    // 2021-03-30 23:30:00 UTC
+    let timestamp = Int64Array::from(&[Some(1_617_147_000), None]);
+    let interval = PrimitiveScalar::new(DataType::Interval(IntervalUnit::YearMonth), Some(1i32));
+
+    let naive = timestamp
+        .clone()
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let result = sub_calendar_interval_scalar(&naive, &interval).unwrap();
+    // 2021-02-28 23:30:00
+    let expected = Int64Array::from(&[Some(1_614_555_000), None])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(expected, result.as_ref());
+
+    // 2021-03-31 00:30:00 in +01:00
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+    let with_tz = timestamp.to(data_type.clone());
+    let result = sub_calendar_interval_scalar(&with_tz, &interval).unwrap();
+    // 2021-02-28 00:30:00 in +01:00
+    let expected = Int64Array::from(&[Some(1_614_468_600), None]).to(data_type.clone());
+    assert_eq!(expected, result.as_ref());
+
+    let interval = PrimitiveScalar::<months_days_ns>::new(
+        DataType::Interval(IntervalUnit::MonthDayNano),
+        None,
+    );
+    let result = add_calendar_interval_scalar(&with_tz, &interval).unwrap();
+    assert_eq!(Int64Array::new_null(data_type, 2), result.as_ref());
+}
+
+#[test]
+fn test_calendar_interval_overflow() {
+    let date_32 = Int32Array::from_slice(&[0, 0]).to(DataType::Date32);
+    let months =
+        Int32Array::from_slice(&[1, i32::MAX]).to(DataType::Interval(IntervalUnit::YearMonth));
+    let result = add_calendar_interval(&date_32, &months).unwrap();
+    let expected = Int32Array::from(&[Some(31), None]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_calendar_interval_errors() {
+    let date_32 = Int32Array::from_slice(&[1]).to(DataType::Date32);
+    let months = Int32Array::from_slice(&[1, 2]).to(DataType::Interval(IntervalUnit::YearMonth));
+    assert!(add_calendar_interval(&date_32, &months).is_err());
+    assert!(add_calendar_interval(&date_32, &date_32).is_err());
+
+    let time = Int32Array::from_slice(&[1]).to(DataType::Time32(TimeUnit::Second));
+    let months = Int32Array::from_slice(&[1]).to(DataType::Interval(IntervalUnit::YearMonth));
+    assert!(add_calendar_interval(&time, &months).is_err());
+
+    assert!(can_calendar_interval(
+        &DataType::Date64,
+        &DataType::Interval(IntervalUnit::DayTime)
+    ));
+    assert!(!can_calendar_interval(
+        &DataType::Time32(TimeUnit::Second),
+        &DataType::Interval(IntervalUnit::DayTime)
+    ));
+    assert!(!can_calendar_interval(
+        &DataType::Date64,
+        &DataType::Duration(TimeUnit::Second)
+    ));
+}
+
+#[test]
+fn test_date_diff() {
+    let lhs = Int64Array::from(&[Some(1_500), Some(-1_500), None, Some(0)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let rhs = Int64Array::from(&[Some(1), Some(0), Some(1), Some(1)])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let result = date_diff(&lhs, &rhs, TimeUnit::Second).unwrap();
+    let expected = Int64Array::from(&[Some(0), Some(-1), None, Some(-1)])
+        .to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(result, expected);
+
+    let result = date_diff(&lhs, &rhs, TimeUnit::Microsecond).unwrap();
+    let expected = Int64Array::from(&[Some(500_000), Some(-1_500_000), None, Some(-1_000_000)])
+        .to(DataType::Duration(TimeUnit::Microsecond));
+    assert_eq!(result, expected);
+
+    // 1970-01-02 and 1970-01-01 00:00:00.001
+    let date_32 = Int32Array::from_slice(&[1]).to(DataType::Date32);
+    let date_64 = Int64Array::from_slice(&[1]).to(DataType::Date64);
+    let result = date_diff(&date_32, &date_64, TimeUnit::Millisecond).unwrap();
+    let expected =
+        Int64Array::from_slice(&[86_399_999]).to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_date_diff_timezones() {
+    let lhs = Int64Array::from_slice(&[3_600]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+01:00".to_string()),
+    ));
+    let rhs = Int64Array::from_slice(&[0]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+05:00".to_string()),
+    ));
+
+    let result = date_diff(&lhs, &rhs, TimeUnit::Second).unwrap();
+    let expected = Int64Array::from_slice(&[3_600]).to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(result, expected);
+
+    let naive = Int64Array::from_slice(&[0]).to(DataType::Timestamp(TimeUnit::Second, None));
+    assert!(date_diff(&lhs, &naive, TimeUnit::Second).is_err());
+    assert!(!can_date_diff(lhs.data_type(), naive.data_type()));
+    assert!(can_date_diff(lhs.data_type(), rhs.data_type()));
+    assert!(can_date_diff(&DataType::Date32, naive.data_type()));
+}
+
+#[test]
+fn test_date_diff_overflow() {
+    let lhs = Int64Array::from_slice(&[i64::MAX, i64::MAX / 1_000])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let rhs = Int64Array::from_slice(&[-1, 0]).to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let result = date_diff(&lhs, &rhs, TimeUnit::Millisecond).unwrap();
+    let expected = Int64Array::from(&[None, Some(i64::MAX / 1_000 * 1_000)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(result, expected);
+
+    let time = Int64Array::from_slice(&[0, 0]).to(DataType::Time64(TimeUnit::Microsecond));
+    assert!(date_diff(&time, &rhs, TimeUnit::Second).is_err());
+    let date_32 = Int32Array::from_slice(&[0]).to(DataType::Date32);
+    assert!(date_diff(&date_32, &rhs, TimeUnit::Second).is_err());
+}
+
+#[test]
+fn test_month_day_nano_arithmetics() {
+    let data_type = DataType::Interval(IntervalUnit::MonthDayNano);
+    let lhs = PrimitiveArray::from(&[
+        Some(months_days_ns::new(1, 2, 3)),
+        Some(months_days_ns::new(i32::MAX, 0, 0)),
+        None,
+    ])
+    .to(data_type.clone());
+    let rhs = PrimitiveArray::from(&[
+        Some(months_days_ns::new(-1, 1, -3)),
+        Some(months_days_ns::new(1, 0, 0)),
+        Some(months_days_ns::new(1, 0, 0)),
+    ])
+    .to(data_type.clone());
+
+    let result = checked_add_intervals(&lhs, &rhs);
+    let expected = PrimitiveArray::from(&[Some(months_days_ns::new(0, 3, 0)), None, None])
+        .to(data_type.clone());
+    assert_eq!(result, expected);
+
+    let result = checked_sub_intervals(&lhs, &rhs);
+    let expected = PrimitiveArray::from(&[
+        Some(months_days_ns::new(2, 1, 6)),
+        Some(months_days_ns::new(i32::MAX - 1, 0, 0)),
+        None,
+    ])
+    .to(data_type.clone());
+    assert_eq!(result, expected);
+
+    let lhs = lhs.slice(0, 1);
+    let rhs = rhs.slice(0, 1);
+    let result = arrow2::compute::arithmetics::sub(&lhs, &rhs);
+    let expected =
+        PrimitiveArray::from_slice(&[months_days_ns::new(2, 1, 6)]).to(data_type.clone());
+    assert_eq!(expected, result.as_ref());
+    assert!(arrow2::compute::arithmetics::can_sub(
+        &data_type, &data_type
+    ));
+
+    let scalar = PrimitiveScalar::new(data_type.clone(), Some(months_days_ns::new(1, 1, 1)));
+    let result = arrow2::compute::arithmetics::add_scalar(&lhs, &scalar);
+    let expected =
+        PrimitiveArray::from_slice(&[months_days_ns::new(2, 3, 4)]).to(data_type.clone());
+    assert_eq!(expected, result.as_ref());
+
+    let result = arrow2::compute::arithmetics::neg(&lhs);
+    let expected = PrimitiveArray::from_slice(&[months_days_ns::new(-1, -2, -3)]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+}
@@ -39,3 +39,80 @@ fn test_not() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_scalar() {
+    let a = UInt8Array::from(&[None, Some(0b1100), Some(0b1010)]);
+    assert_eq!(
+        and_scalar(&a, &0b0110),
+        UInt8Array::from(&[None, Some(0b0100), Some(0b0010)])
+    );
+    assert_eq!(
+        or_scalar(&a, &0b0110),
+        UInt8Array::from(&[None, Some(0b1110), Some(0b1110)])
+    );
+    assert_eq!(
+        xor_scalar(&a, &0b0110),
+        UInt8Array::from(&[None, Some(0b1010), Some(0b1100)])
+    );
+}
+
+#[test]
+fn test_shift() {
+    let a = Int8Array::from(&[Some(1), Some(-8), None, Some(3)]);
+    let b = Int8Array::from(&[Some(3), Some(1), Some(1), Some(9)]);
+    assert_eq!(
+        shift_left(&a, &b),
+        Int8Array::from(&[Some(8), Some(-16), None, Some(6)])
+    );
+    assert_eq!(
+        shift_right(&a, &b),
+        Int8Array::from(&[Some(0), Some(-4), None, Some(1)])
+    );
+
+    let a = UInt64Array::from(&[Some(u64::MAX), Some(1), None]);
+    assert_eq!(
+        shift_right_scalar(&a, &63),
+        UInt64Array::from(&[Some(1), Some(0), None])
+    );
+    assert_eq!(
+        shift_left_scalar(&a, &1),
+        UInt64Array::from(&[Some(u64::MAX - 1), Some(2), None])
+    );
+}
+
+#[test]
+fn test_shift_wraps() {
+    // the amounts are taken modulo the number of bits, negative amounts as unsigned
+    let a = Int32Array::from_slice(&[1, 1, 1, -8]);
+    let b = Int32Array::from_slice(&[32, 33, -1, -31]);
+    assert_eq!(
+        shift_left(&a, &b),
+        Int32Array::from_slice(&[1, 2, i32::MIN, -16])
+    );
+    assert_eq!(shift_right(&a, &b), Int32Array::from_slice(&[1, 0, 0, -4]));
+}
+
+#[test]
+fn test_checked_shift() {
+    let a = Int32Array::from(&[Some(1), Some(-8), None, Some(1), Some(1)]);
+    let b = Int32Array::from(&[Some(31), Some(1), Some(1), Some(32), Some(-1)]);
+    assert_eq!(
+        checked_shift_left(&a, &b),
+        Int32Array::from(&[Some(i32::MIN), Some(-16), None, None, None])
+    );
+    assert_eq!(
+        checked_shift_right(&a, &b),
+        Int32Array::from(&[Some(0), Some(-4), None, None, None])
+    );
+
+    let a = UInt8Array::from(&[Some(0b1000_0001), None]);
+    assert_eq!(
+        checked_shift_left_scalar(&a, &7),
+        UInt8Array::from(&[Some(0b1000_0000), None])
+    );
+    assert_eq!(
+        checked_shift_right_scalar(&a, &8),
+        UInt8Array::from(&[None, None])
+    );
+}
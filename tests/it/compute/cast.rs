@@ -1,7 +1,9 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
 use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
 use arrow2::datatypes::*;
-use arrow2::types::{days_ms, months_days_ns, NativeType};
+use arrow2::types::{days_ms, f16, i256, months_days_ns, NativeType};
 
 #[test]
 fn i32_to_f64() {
@@ -109,6 +111,59 @@ fn i32_to_u8_sliced() {
     assert_eq!(c, &expected);
 }
 
+#[test]
+fn i32_to_u8_saturating() {
+    let array = Int32Array::from(&[Some(-5), Some(6), None, Some(100000000)]);
+    let options = CastOptions {
+        saturating: true,
+        ..Default::default()
+    };
+    let b = cast(&array, &DataType::UInt8, options).unwrap();
+    let expected = UInt8Array::from(&[Some(0), Some(6), None, Some(255)]);
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+
+    let array = Float64Array::from_slice(&[f64::NAN, -1e10, 1e10, 1.5]);
+    let b = cast(&array, &DataType::Int16, options).unwrap();
+    let expected = Int16Array::from(&[None, Some(i16::MIN), Some(i16::MAX), Some(1)]);
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn strict() {
+    let options = CastOptions {
+        strict: true,
+        ..Default::default()
+    };
+
+    let array = Int32Array::from(&[None, Some(6), Some(-7)]);
+    let error = cast(&array, &DataType::UInt8, options).unwrap_err();
+    assert!(error.to_string().contains("row 2"));
+    let b = cast(&array.slice(0, 2), &DataType::UInt8, options).unwrap();
+    assert_eq!(
+        b.as_ref(),
+        &UInt8Array::from(&[None, Some(6)]) as &dyn Array
+    );
+
+    let array = Utf8Array::<i32>::from(&[Some("1"), None, Some("a")]);
+    let error = cast(&array, &DataType::Int32, options).unwrap_err();
+    assert!(error.to_string().contains("row 2: the value a"));
+
+    // nested values are checked too
+    let to_type = ListArray::<i32>::default_datatype(DataType::Int64);
+    let list = cast(&Int64Array::from(&[Some(1), None]), &to_type, options).unwrap();
+    let to_type = ListArray::<i32>::default_datatype(DataType::UInt8);
+    assert!(cast(list.as_ref(), &to_type, options).is_ok());
+    let list = cast(&Int64Array::from_slice(&[-1]), list.data_type(), options).unwrap();
+    assert!(cast(list.as_ref(), &to_type, options).is_err());
+
+    let options = CastOptions {
+        strict: true,
+        saturating: true,
+        ..Default::default()
+    };
+    assert!(cast(&Int32Array::from_slice(&[-7]), &DataType::UInt8, options).is_ok());
+}
+
 #[test]
 fn i32_to_i32() {
     let array = Int32Array::from_slice(&[5, 6, 7, 8, 9]);
@@ -197,6 +252,23 @@ fn i32_to_binary() {
     assert_eq!(c, &expected);
 }
 
+#[test]
+fn binary_to_utf8() {
+    let array = BinaryArray::<i64>::from(&[Some(b"a".as_ref()), Some(b"b\xffc"), None]);
+
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("a"), None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let options = CastOptions {
+        lossy_utf8: true,
+        ..Default::default()
+    };
+    let result = cast(&array, &DataType::LargeUtf8, options).unwrap();
+    let expected = Utf8Array::<i64>::from(&[Some("a"), Some("b\u{FFFD}c"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn binary_to_i32() {
     let array = BinaryArray::<i32>::from_slice(&["5", "6", "seven", "8", "9.1"]);
@@ -360,6 +432,177 @@ fn decimal_to_integer() {
     assert_eq!(c, &expected)
 }
 
+fn decimal256(values: &[Option<i128>], precision: usize, scale: usize) -> PrimitiveArray<i256> {
+    values
+        .iter()
+        .map(|x| x.map(i256::from_i128))
+        .collect::<PrimitiveArray<i256>>()
+        .to(DataType::Decimal256(precision, scale))
+}
+
+#[test]
+fn int32_to_decimal256() {
+    // 10 and -10 can't be represented with precision 2 and scale 1
+    let array = Int32Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None]);
+
+    let b = cast(&array, &DataType::Decimal256(2, 1), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i256>>().unwrap();
+
+    let expected = decimal256(&[Some(20), None, Some(-20), None, None], 2, 1);
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn int64_to_decimal256_large_scale() {
+    let array = Int64Array::from(&[Some(i64::MAX), Some(-1)]);
+
+    let b = cast(
+        &array,
+        &DataType::Decimal256(76, 50),
+        CastOptions::default(),
+    )
+    .unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i256>>().unwrap();
+
+    let expected = (i64::MAX.to_string() + &"0".repeat(50)).parse().unwrap();
+    assert_eq!(c.value(0), expected);
+    assert_eq!(c.value(1), format!("-1{}", "0".repeat(50)).parse().unwrap());
+}
+
+#[test]
+fn float64_to_decimal256() {
+    let array = Float64Array::from(&[
+        Some(2.4),
+        Some(-10.0),
+        Some(-100.01), // can't be represented in (4, 2)
+        Some(f64::NAN),
+        None,
+    ]);
+
+    let b = cast(&array, &DataType::Decimal256(4, 2), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i256>>().unwrap();
+
+    let expected = decimal256(&[Some(240), Some(-1000), None, None, None], 4, 2);
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn decimal_to_decimal256() {
+    let array = Int128Array::from(&[Some(i128::MAX), Some(-25), None]).to(DataType::Decimal(38, 0));
+
+    let b = cast(&array, &DataType::Decimal256(76, 2), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i256>>().unwrap();
+
+    let expected = (i256::from_i128(i128::MAX) * i256::from_i128(100)).to_string();
+    assert_eq!(c.value(0).to_string(), expected);
+    assert_eq!(c.value(1), i256::from_i128(-2500));
+    assert!(c.is_null(2));
+    assert_eq!(c.data_type(), &DataType::Decimal256(76, 2));
+}
+
+#[test]
+fn decimal256_to_decimal() {
+    let large = i256::from_i128(i128::MAX) * i256::from_i128(10);
+    let array = PrimitiveArray::<i256>::from(&[Some(large), Some(i256::from_i128(-255)), None])
+        .to(DataType::Decimal256(76, 2));
+
+    let b = cast(&array, &DataType::Decimal(5, 1), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
+
+    let expected = Int128Array::from(&[None, Some(-25), None]).to(DataType::Decimal(5, 1));
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn decimal256_to_decimal256() {
+    let array = decimal256(&[Some(2), Some(10), Some(-2), Some(-10), None], 2, 0);
+
+    let b = cast(&array, &DataType::Decimal256(3, 1), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i256>>().unwrap();
+
+    let expected = decimal256(&[Some(20), Some(100), Some(-20), Some(-100), None], 3, 1);
+    assert_eq!(c, &expected);
+
+    // 10 and -10 can't be represented with precision 2 and scale 1
+    let b = cast(&array, &DataType::Decimal256(2, 1), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i256>>().unwrap();
+
+    let expected = decimal256(&[Some(20), None, Some(-20), None, None], 2, 1);
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn decimal256_to_float() {
+    let array = decimal256(&[Some(2), Some(10), Some(-2), Some(-10), None], 2, 1);
+
+    let b = cast(&array, &DataType::Float32, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<f32>>().unwrap();
+
+    let expected = Float32Array::from(&[Some(0.2), Some(1.0), Some(-0.2), Some(-1.0), None]);
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn decimal256_to_integer() {
+    let array = decimal256(
+        &[Some(2), Some(10), Some(-2), Some(-10), None, Some(2560)],
+        4,
+        1,
+    );
+
+    let b = cast(&array, &DataType::Int8, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i8>>().unwrap();
+
+    let expected = Int8Array::from(&[Some(0), Some(1), Some(0), Some(-1), None, None]);
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn utf8_to_decimal256() {
+    let array = Utf8Array::<i32>::from(&[
+        Some("1.23"),
+        Some("-0.5"),
+        Some("+7"),
+        Some(".25"),
+        Some("1.239"), // truncated
+        Some("123.4"), // can't be represented in (4, 2)
+        Some("1e3"),
+        Some("-"),
+        None,
+    ]);
+
+    let b = cast(&array, &DataType::Decimal256(4, 2), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i256>>().unwrap();
+
+    let expected = decimal256(
+        &[
+            Some(123),
+            Some(-50),
+            Some(700),
+            Some(25),
+            Some(123),
+            None,
+            None,
+            None,
+            None,
+        ],
+        4,
+        2,
+    );
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn decimal256_to_utf8() {
+    let array = decimal256(&[Some(123), Some(-5), Some(0), None], 10, 2);
+
+    let b = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+
+    let expected = Utf8Array::<i64>::from(&[Some("1.23"), Some("-0.05"), Some("0.00"), None]);
+    assert_eq!(c, &expected)
+}
+
 #[test]
 fn utf8_to_i32_partial() {
     let array = Utf8Array::<i32>::from_slice(&["5", "6", "seven", "8aa", "9.1aa"]);
@@ -446,6 +689,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Timestamp(TimeUnit::Second, None),
@@ -460,6 +704,8 @@ fn consistency() {
         Time32(TimeUnit::Millisecond),
         Decimal(1, 2),
         Decimal(2, 2),
+        Decimal256(1, 2),
+        Decimal256(40, 2),
         Date64,
         Utf8,
         LargeUtf8,
@@ -469,6 +715,7 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        Interval(IntervalUnit::DayTime),
         List(Box::new(Field::new("a", Utf8, true))),
         LargeList(Box::new(Field::new("a", Utf8, true))),
     ];
@@ -535,6 +782,46 @@ fn months_to_months_days_ns() {
     );
 }
 
+#[test]
+fn duration_to_duration() {
+    test_primitive_to_primitive(
+        &[1i64, -2],
+        DataType::Duration(TimeUnit::Second),
+        &[1_000_000i64, -2_000_000],
+        DataType::Duration(TimeUnit::Microsecond),
+    );
+    test_primitive_to_primitive(
+        &[1_999i64, -2_001],
+        DataType::Duration(TimeUnit::Millisecond),
+        &[1i64, -2],
+        DataType::Duration(TimeUnit::Second),
+    );
+
+    let array = Int64Array::from_slice(&[i64::MAX, 1]).to(DataType::Duration(TimeUnit::Second));
+    let to_type = DataType::Duration(TimeUnit::Nanosecond);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[None, Some(1_000_000_000)]).to(to_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn days_ms_to_duration() {
+    test_primitive_to_primitive(
+        &[days_ms::new(1, 1), days_ms::new(-1, 0)],
+        DataType::Interval(IntervalUnit::DayTime),
+        &[86_400_001_000i64, -86_400_000_000],
+        DataType::Duration(TimeUnit::Microsecond),
+    );
+
+    // lossy casts are null
+    let array = PrimitiveArray::from_slice(&[days_ms::new(1, 1), days_ms::new(0, 2000)])
+        .to(DataType::Interval(IntervalUnit::DayTime));
+    let to_type = DataType::Duration(TimeUnit::Second);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[None, Some(2)]).to(to_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn date64_to_date32() {
     test_primitive_to_primitive(
@@ -641,6 +928,31 @@ fn utf8_to_dict() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn large_utf8_to_dict() {
+    let array = Utf8Array::<i64>::from(&[Some("b"), None, Some("a"), Some("b"), Some("c")]);
+    let array = array.slice(1, 4);
+
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::Utf8), false);
+    let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+
+    // values are in the order of their first occurrence
+    let values = Utf8Array::<i32>::from_slice(&["a", "b", "c"]);
+    assert_eq!(result.values().as_ref(), &values as &dyn Array);
+    assert_eq!(
+        result.keys(),
+        &UInt8Array::from(&[None, Some(0), Some(1), Some(2)])
+    );
+
+    let array = Utf8Array::<i32>::from_iter_values((0..300).map(|x| x.to_string()));
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::LargeUtf8), false);
+    assert!(cast(&array, &cast_type, CastOptions::default()).is_err());
+}
+
 #[test]
 fn dict_to_utf8() {
     let mut array = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
@@ -696,6 +1008,69 @@ fn list_to_list() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn list_to_fixed_size_list() {
+    let data = vec![
+        Some(vec![Some(0i32), Some(0)]),
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![Some(3), None]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+    let array = array.slice(1, 3);
+
+    let to_type = FixedSizeListArray::default_datatype(DataType::Int64, 2);
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = FixedSizeListArray::from_data(
+        to_type.clone(),
+        Arc::new(Int64Array::from(&[
+            Some(1),
+            Some(2),
+            None,
+            None,
+            Some(3),
+            None,
+        ])),
+        Some([true, false, true].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // and back
+    let to_type = ListArray::<i64>::default_datatype(DataType::Int32);
+    let result = cast(result.as_ref(), &to_type, CastOptions::default()).unwrap();
+    let result = cast(result.as_ref(), array.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn list_to_fixed_size_list_resize() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        Some(vec![Some(4)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let to_type = FixedSizeListArray::default_datatype(DataType::Int32, 2);
+    assert!(cast(&array, &to_type, CastOptions::default()).is_err());
+
+    let options = CastOptions {
+        resize_lists: true,
+        ..Default::default()
+    };
+    let result = cast(&array, &to_type, options).unwrap();
+    let expected = FixedSizeListArray::from_data(
+        to_type.clone(),
+        Arc::new(Int32Array::from(&[Some(1), Some(2), Some(4), None])),
+        None,
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn timestamp_with_tz_to_utf8() {
     let tz = "-02:00".to_string();
@@ -803,3 +1178,67 @@ fn utf8_to_date64() {
 
     assert_eq!(&expected, c);
 }
+
+#[test]
+fn f16_to_f32() {
+    test_primitive_to_primitive(
+        &[f16::from_f32(1.5), f16::from_f32(-0.25), f16::INFINITY],
+        DataType::Float16,
+        &[1.5f32, -0.25, f32::INFINITY],
+        DataType::Float32,
+    );
+}
+
+#[test]
+fn f64_to_f16() {
+    test_primitive_to_primitive(
+        // rounds to nearest, overflows to infinity, underflows to (subnormals and) zero
+        &[1.0f64 / 3.0, 65520.0, -65520.0, 2.0f64.powi(-24), 1e-9],
+        DataType::Float64,
+        &[
+            f16::from_bits(0x3555),
+            f16::INFINITY,
+            f16::NEG_INFINITY,
+            f16::from_bits(0x0001),
+            f16::from_bits(0x0000),
+        ],
+        DataType::Float16,
+    );
+}
+
+#[test]
+fn i32_to_f16() {
+    test_primitive_to_primitive(
+        // 2049 is not representable: ties round to even
+        &[1i32, 2049, -7],
+        DataType::Int32,
+        &[
+            f16::from_f32(1.0),
+            f16::from_f32(2048.0),
+            f16::from_f32(-7.0),
+        ],
+        DataType::Float16,
+    );
+}
+
+#[test]
+fn f16_to_i32() {
+    test_primitive_to_primitive(
+        &[
+            f16::from_f32(1.0),
+            f16::from_f32(-2048.0),
+            f16::from_f32(65504.0),
+        ],
+        DataType::Float16,
+        &[1i32, -2048, 65504],
+        DataType::Int32,
+    );
+}
+
+#[test]
+fn f16_to_utf8() {
+    let array = PrimitiveArray::from(&[Some(f16::from_f32(1.5)), None]);
+    let b = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("1.5"), None]);
+    assert_eq!(expected, b.as_ref());
+}
@@ -0,0 +1,59 @@
+use arrow2::array::*;
+use arrow2::compute::clamp::clamp;
+use arrow2::datatypes::{DataType, TimeUnit};
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from(&[Some(-5), Some(0), None, Some(5), Some(10)]);
+
+    let result = clamp(&array, Some(0), Some(5)).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from(&[Some(0), Some(0), None, Some(5), Some(5)])
+    );
+
+    let result = clamp(&array, Some(1), None).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from(&[Some(1), Some(1), None, Some(5), Some(10)])
+    );
+
+    let result = clamp(&array, None, Some(-1)).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from(&[Some(-5), Some(-1), None, Some(-1), Some(-1)])
+    );
+
+    assert_eq!(clamp(&array, None, None).unwrap(), array);
+}
+
+#[test]
+fn float() {
+    let array = Float64Array::from_slice(&[f64::NEG_INFINITY, f64::NAN, 0.25]);
+    let result = clamp(&array, Some(0.0), Some(0.5)).unwrap();
+    assert_eq!(result.value(0), 0.0);
+    assert!(result.value(1).is_nan());
+    assert_eq!(result.value(2), 0.25);
+}
+
+#[test]
+fn temporal() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+    let array = Int64Array::from_slice(&[10, 100, 1000]).to(data_type.clone());
+
+    let result = clamp(&array, Some(50), Some(500)).unwrap();
+    assert_eq!(
+        result,
+        Int64Array::from_slice(&[50, 100, 500]).to(data_type)
+    );
+}
+
+#[test]
+fn errors() {
+    let array = Int32Array::from_slice(&[1]);
+    assert!(clamp(&array, Some(2), Some(1)).is_err());
+
+    let array = Float32Array::from_slice(&[1.0]);
+    assert!(clamp(&array, Some(f32::NAN), None).is_err());
+    assert!(clamp(&array, None, Some(f32::NAN)).is_err());
+}
@@ -0,0 +1,49 @@
+use arrow2::array::*;
+use arrow2::compute::coalesce::coalesce;
+
+#[test]
+fn primitive() {
+    let a = Int64Array::from(&[Some(1), None, None, None, Some(5), None]);
+    let b = Int64Array::from(&[None, Some(2), None, Some(4), Some(0), None]);
+    let c = Int64Array::from(&[None, Some(0), Some(3), Some(0), Some(0), None]);
+
+    let result = coalesce(&[&a, &b, &c]).unwrap();
+    let expected = Int64Array::from(&[Some(1), Some(2), Some(3), Some(4), Some(5), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8() {
+    let a = Utf8Array::<i32>::from(&[None, Some("a"), None]);
+    let b = Utf8Array::<i32>::from(&[Some("b"), Some("c"), Some("d")]);
+
+    let result = coalesce(&[&a, &b]).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(&["b", "a", "d"]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn single() {
+    let a = BooleanArray::from(&[Some(true), None]);
+    let result = coalesce(&[&a]).unwrap();
+    assert_eq!(a, result.as_ref());
+}
+
+#[test]
+fn empty() {
+    let a = Int32Array::from_slice(&[]);
+    let result = coalesce(&[&a, &a]).unwrap();
+    assert_eq!(result.len(), 0);
+}
+
+#[test]
+fn errors() {
+    assert!(coalesce(&[]).is_err());
+
+    let a = Int32Array::from_slice(&[1]);
+    let b = Int64Array::from_slice(&[1]);
+    assert!(coalesce(&[&a, &b]).is_err());
+
+    let b = Int32Array::from_slice(&[1, 2]);
+    assert!(coalesce(&[&a, &b]).is_err());
+}
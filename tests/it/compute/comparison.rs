@@ -3,6 +3,7 @@ use arrow2::compute::comparison::{self, boolean::*};
 use arrow2::datatypes::{DataType::*, IntervalUnit};
 use arrow2::datatypes::{IntegerType, TimeUnit};
 use arrow2::scalar::new_scalar;
+use arrow2::types::{f16, months_days_ns};
 
 #[test]
 fn consistency() {
@@ -18,6 +19,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Interval(IntervalUnit::YearMonth),
@@ -42,6 +44,8 @@ fn consistency() {
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
         Dictionary(IntegerType::Int32, Box::new(LargeBinary), false),
+        Dictionary(IntegerType::UInt8, Box::new(Decimal(10, 2)), false),
+        Dictionary(IntegerType::Int16, Box::new(Float16), false),
     ];
 
     // array <> array
@@ -68,6 +72,291 @@ fn consistency() {
     });
 }
 
+#[test]
+fn f16() {
+    let a = PrimitiveArray::from(&[
+        Some(f16::from_f32(1.0)),
+        Some(f16::NAN),
+        None,
+        Some(f16::from_f32(-0.0)),
+    ]);
+    let b = PrimitiveArray::from_slice(&[
+        f16::from_f32(2.0),
+        f16::NAN,
+        f16::from_f32(1.0),
+        f16::from_f32(0.0),
+    ]);
+    let expected = BooleanArray::from(&[Some(false), Some(false), None, Some(true)]);
+    assert_eq!(comparison::eq(&a, &b), expected);
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(false)]);
+    assert_eq!(comparison::lt(&a, &b), expected);
+}
+
+#[test]
+fn month_day_nano() {
+    let data_type = Interval(IntervalUnit::MonthDayNano);
+    let a = PrimitiveArray::from(&[
+        Some(months_days_ns::new(1, 0, 0)),
+        Some(months_days_ns::new(0, 1, 0)),
+        None,
+        Some(months_days_ns::new(0, 1, 1)),
+    ])
+    .to(data_type.clone());
+    let b = PrimitiveArray::from_slice(&[
+        // months are compared before days and days before nanoseconds
+        months_days_ns::new(0, 40, 0),
+        months_days_ns::new(0, 0, 25 * 3_600_000_000_000),
+        months_days_ns::new(0, 0, 0),
+        months_days_ns::new(0, 1, 1),
+    ])
+    .to(data_type.clone());
+    let expected = BooleanArray::from(&[Some(true), Some(true), None, Some(false)]);
+    assert_eq!(comparison::gt(&a, &b), expected);
+    let expected = BooleanArray::from(&[Some(false), Some(false), None, Some(true)]);
+    assert_eq!(comparison::lt_eq(&a, &b), expected);
+
+    let scalar = new_scalar(&b, 3);
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+    assert_eq!(comparison::gt_eq_scalar(&a, scalar.as_ref()), expected);
+}
+
+#[test]
+fn eq_neq_missing() {
+    use arrow2::bitmap::Bitmap;
+
+    // the values behind the nulls differ
+    let lhs = Int32Array::from_slice(&[1, 2, 3, 4, 5])
+        .with_validity(Some(Bitmap::from([true, false, false, true, false])));
+    let rhs = Int32Array::from_slice(&[1, 0, 3, 0, 5])
+        .with_validity(Some(Bitmap::from([true, false, true, true, true])));
+
+    let result = comparison::eq_missing(&lhs, &rhs);
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, true, false, false, false])
+    );
+
+    let result = comparison::neq_missing(&lhs, &rhs);
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[false, false, true, true, true])
+    );
+
+    let lhs = Utf8Array::<i32>::from(&[Some("a"), None, Some("b")]);
+    let rhs = Utf8Array::<i32>::from_slice(&["a", "a", "c"]);
+
+    let result = comparison::eq_missing(&lhs, &rhs);
+    assert_eq!(result, BooleanArray::from_slice(&[true, false, false]));
+
+    let result = comparison::neq_missing(&rhs, &lhs);
+    assert_eq!(result, BooleanArray::from_slice(&[false, true, true]));
+}
+
+#[test]
+fn dictionary() {
+    use std::sync::Arc;
+
+    // the dictionaries have different values, with duplicates and nulls
+    let lhs = DictionaryArray::<i32>::from_data(
+        Int32Array::from(&[Some(0), Some(1), Some(2), None, Some(3), Some(0)]),
+        Arc::new(Utf8Array::<i32>::from(&[
+            Some("b"),
+            Some("a"),
+            None,
+            Some("b"),
+        ])),
+    );
+    let rhs = DictionaryArray::<i32>::from_data(
+        Int32Array::from(&[Some(0), Some(0), Some(0), Some(0), Some(1), Some(2)]),
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])),
+    );
+
+    let expected =
+        BooleanArray::from(&[Some(false), Some(true), None, None, Some(true), Some(false)]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from(&[
+        Some(false),
+        Some(false),
+        None,
+        None,
+        Some(false),
+        Some(true),
+    ]);
+    assert_eq!(comparison::lt(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from(&[
+        Some(true),
+        Some(false),
+        None,
+        None,
+        Some(false),
+        Some(false),
+    ]);
+    assert_eq!(comparison::gt(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice(&[false, true, false, false, true, false]);
+    assert_eq!(comparison::eq_missing(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice(&[true, false, true, true, false, true]);
+    assert_eq!(comparison::neq_missing(&lhs, &rhs), expected);
+}
+
+#[test]
+fn dictionary_same_values() {
+    use std::sync::Arc;
+
+    let values: Arc<dyn Array> = Arc::new(Int64Array::from_slice(&[3, 1, 3, 2]));
+    let lhs =
+        DictionaryArray::<u8>::from_data(UInt8Array::from_slice(&[0, 1, 2, 3]), values.clone());
+    let rhs = DictionaryArray::<u8>::from_data(UInt8Array::from_slice(&[2, 3, 0, 1]), values);
+
+    let expected = BooleanArray::from_slice(&[true, false, true, false]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice(&[true, false, true, true]);
+    assert_eq!(comparison::gt_eq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice(&[true, true, true, false]);
+    assert_eq!(comparison::lt_eq(&lhs, &rhs), expected);
+}
+
+#[test]
+fn dictionary_floats() {
+    use std::sync::Arc;
+
+    let lhs_values = Float64Array::from(&[Some(f64::NAN), Some(0.0), Some(1.0), None]);
+    let rhs_values = Float64Array::from(&[Some(f64::NAN), Some(-0.0), Some(1.0), Some(2.0)]);
+    let keys = UInt8Array::from_slice(&[0, 1, 2, 3, 0]);
+    let rhs_keys = UInt8Array::from_slice(&[0, 1, 0, 3, 2]);
+    let lhs = DictionaryArray::<u8>::from_data(keys.clone(), Arc::new(lhs_values.clone()));
+    let rhs = DictionaryArray::<u8>::from_data(rhs_keys.clone(), Arc::new(rhs_values.clone()));
+
+    // the same as comparing the values of each slot
+    let lhs_values = arrow2::compute::take::take(&lhs_values, &keys).unwrap();
+    let rhs_values = arrow2::compute::take::take(&rhs_values, &rhs_keys).unwrap();
+    let lhs_values = lhs_values.as_ref();
+    let rhs_values = rhs_values.as_ref();
+    assert_eq!(
+        comparison::eq(&lhs, &rhs),
+        comparison::eq(lhs_values, rhs_values)
+    );
+    assert_eq!(
+        comparison::eq(&lhs, &rhs),
+        BooleanArray::from(&[Some(false), Some(true), Some(false), None, Some(false)])
+    );
+    assert_eq!(
+        comparison::neq(&lhs, &rhs),
+        comparison::neq(lhs_values, rhs_values)
+    );
+    assert_eq!(
+        comparison::lt(&lhs, &rhs),
+        comparison::lt(lhs_values, rhs_values)
+    );
+    assert_eq!(
+        comparison::lt_eq(&lhs, &rhs),
+        comparison::lt_eq(lhs_values, rhs_values)
+    );
+    assert_eq!(
+        comparison::gt(&lhs, &rhs),
+        comparison::gt(lhs_values, rhs_values)
+    );
+    assert_eq!(
+        comparison::gt_eq(&lhs, &rhs),
+        comparison::gt_eq(lhs_values, rhs_values)
+    );
+    assert_eq!(
+        comparison::eq_and_validity(&lhs, &rhs),
+        comparison::eq_and_validity(lhs_values, rhs_values)
+    );
+    assert_eq!(
+        comparison::neq_and_validity(&lhs, &rhs),
+        comparison::neq_and_validity(lhs_values, rhs_values)
+    );
+
+    // NaN is not equal to itself, even when both sides share the dictionary
+    let values: Arc<dyn Array> = Arc::new(Float32Array::from_slice(&[f32::NAN, 1.0]));
+    let array = DictionaryArray::<u8>::from_data(UInt8Array::from_slice(&[0, 1]), values);
+    assert_eq!(
+        comparison::eq(&array, &array),
+        BooleanArray::from_slice(&[false, true])
+    );
+}
+
+#[test]
+fn total_order() {
+    use arrow2::compute::comparison::*;
+    let lhs = Float64Array::from(&[Some(f64::NAN), Some(-0.0), Some(f64::NAN), Some(1.0), None]);
+    let rhs = Float64Array::from(&[
+        Some(f64::NAN),
+        Some(0.0),
+        Some(f64::INFINITY),
+        Some(1.0),
+        Some(1.0),
+    ]);
+
+    let expected = BooleanArray::from(&[Some(true), Some(false), Some(false), Some(true), None]);
+    assert_eq!(total_eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from(&[Some(false), Some(true), Some(true), Some(false), None]);
+    assert_eq!(total_neq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from(&[Some(false), Some(true), Some(false), Some(false), None]);
+    assert_eq!(total_lt(&lhs, &rhs), expected);
+    let expected = BooleanArray::from(&[Some(true), Some(true), Some(false), Some(true), None]);
+    assert_eq!(total_lt_eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from(&[Some(false), Some(false), Some(true), Some(false), None]);
+    assert_eq!(total_gt(&lhs, &rhs), expected);
+    let expected = BooleanArray::from(&[Some(true), Some(false), Some(true), Some(true), None]);
+    assert_eq!(total_gt_eq(&lhs, &rhs), expected);
+
+    let scalar = new_scalar(&Float64Array::from_slice(&[f64::NAN]), 0);
+    let expected = BooleanArray::from(&[Some(true), Some(false), Some(true), Some(false), None]);
+    assert_eq!(total_eq_scalar(&lhs, scalar.as_ref()), expected);
+    let expected = BooleanArray::from(&[Some(false), Some(true), Some(false), Some(true), None]);
+    assert_eq!(total_lt_scalar(&lhs, scalar.as_ref()), expected);
+
+    // other types are compared as usual
+    let lhs = Int32Array::from_slice(&[1, 2]);
+    let rhs = Int32Array::from_slice(&[2, 2]);
+    assert_eq!(total_lt(&lhs, &rhs), lt(&lhs, &rhs));
+}
+
+#[test]
+fn total_order_f16_f32() {
+    use arrow2::compute::comparison::total;
+    let lhs = Float32Array::from_slice(&[f32::NAN, -0.0, f32::NEG_INFINITY]);
+    let rhs = Float32Array::from_slice(&[f32::INFINITY, 0.0, -1.0]);
+    let expected = BooleanArray::from_slice(&[true, false, false]);
+    assert_eq!(total::gt(&lhs, &rhs), expected);
+    assert_eq!(
+        total::lt_scalar(&lhs, 0.0),
+        BooleanArray::from_slice(&[false, true, true])
+    );
+
+    let lhs = PrimitiveArray::<f16>::from_slice(&[f16::from_f32(f32::NAN), f16::from_f32(-0.0)]);
+    let expected = BooleanArray::from_slice(&[false, true]);
+    assert_eq!(total::lt_scalar(&lhs, f16::from_f32(0.0)), expected);
+}
+
+#[test]
+fn total_order_agrees_with_sort() {
+    use arrow2::compute::comparison::total_lt_eq;
+    use arrow2::compute::sort::sort;
+
+    let array = Float64Array::from_slice(&[
+        1.0,
+        f64::NAN,
+        -0.0,
+        f64::NEG_INFINITY,
+        0.0,
+        f64::INFINITY,
+        -1.0,
+    ]);
+    let sorted = sort(&array, &Default::default(), None).unwrap();
+    let lhs = sorted.slice(0, sorted.len() - 1);
+    let rhs = sorted.slice(1, sorted.len() - 1);
+    let result = total_lt_eq(lhs.as_ref(), rhs.as_ref());
+    assert_eq!(result, BooleanArray::from_slice(&[true; 6]));
+}
+
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
 #[cfg(test)]
@@ -115,3 +115,38 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn union_arrays() -> Result<()> {
+    use arrow2::datatypes::{DataType, Field, UnionMode};
+    use std::sync::Arc;
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let array = UnionArray::from_data(
+        data_type.clone(),
+        vec![0, 1].into(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a")])) as Arc<dyn Array>,
+        ],
+        Some(vec![0, 0].into()),
+    );
+
+    let result = concatenate(&[&array, &array.slice(1, 1)])?;
+
+    let expected = UnionArray::from_data(
+        data_type,
+        vec![0, 1, 1].into(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("a")])) as Arc<dyn Array>,
+        ],
+        Some(vec![0, 0, 1].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
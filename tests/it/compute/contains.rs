@@ -1,5 +1,11 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
-use arrow2::compute::contains::contains;
+use arrow2::buffer::Buffer;
+use arrow2::compute::contains::{contains, is_in, list_contains, list_contains_scalar};
+use arrow2::compute::take::take;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::scalar::{new_scalar, PrimitiveScalar, Utf8Scalar};
 
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
@@ -59,3 +65,155 @@ fn test_contains_binary() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn is_in_primitive() {
+    let values = Int64Array::from(&[Some(1), Some(2), None, Some(3)]);
+    let set = Int64Array::from_slice(&[3, 1, 1]);
+
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+    assert_eq!(is_in(&values, &set, false).unwrap(), expected);
+
+    let expected = BooleanArray::from_slice(&[true, false, false, true]);
+    assert_eq!(is_in(&values, &set, true).unwrap(), expected);
+}
+
+#[test]
+fn is_in_set_with_nulls() {
+    let values = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None]);
+    let set = Utf8Array::<i32>::from(&[None, Some("a")]);
+
+    let expected = BooleanArray::from(&[Some(true), None, None]);
+    assert_eq!(is_in(&values, &set, false).unwrap(), expected);
+
+    let expected = BooleanArray::from_slice(&[true, false, true]);
+    assert_eq!(is_in(&values, &set, true).unwrap(), expected);
+}
+
+#[test]
+fn is_in_other_types() {
+    let values = Float32Array::from_slice(&[f32::NAN, 1.0, 2.0]);
+    let set = Float32Array::from_slice(&[f32::NAN, 2.0]);
+    let expected = BooleanArray::from_slice(&[true, false, true]);
+    assert_eq!(is_in(&values, &set, false).unwrap(), expected);
+
+    let values = BinaryArray::<i64>::from_slice(&[b"a".as_ref(), b"bc"]);
+    let set = BinaryArray::<i64>::from_slice(&[b"bc"]);
+    let expected = BooleanArray::from_slice(&[false, true]);
+    assert_eq!(is_in(&values, &set, false).unwrap(), expected);
+
+    let values = BooleanArray::from_slice(&[true, false]);
+    let set = BooleanArray::from_slice(&[false]);
+    let expected = BooleanArray::from_slice(&[false, true]);
+    assert_eq!(is_in(&values, &set, false).unwrap(), expected);
+}
+
+#[test]
+fn is_in_errors() {
+    let values = Int32Array::from_slice(&[1]);
+    let set = Int64Array::from_slice(&[1]);
+    assert!(is_in(&values, &set, false).is_err());
+}
+
+fn struct_list() -> (ListArray<i64>, StructArray) {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let values = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(Int32Array::from(&[
+                Some(1),
+                Some(2),
+                None,
+                Some(1),
+                Some(1),
+            ])),
+            Arc::new(Utf8Array::<i32>::from(&[
+                Some("a"),
+                Some("b"),
+                Some("c"),
+                Some("a"),
+                None,
+            ])),
+        ],
+        Some([true, true, true, false, true].into()),
+    );
+    let list = ListArray::<i64>::from_data(
+        ListArray::<i64>::default_datatype(values.data_type().clone()),
+        Buffer::from(vec![0, 2, 4, 5, 5]),
+        Arc::new(values.clone()),
+        Some([true, true, true, false].into()),
+    );
+    (list, values)
+}
+
+#[test]
+fn list_contains_struct() {
+    // [{1, a}, {2, b}], [{null, c}, null], [{1, null}], null
+    let (list, values) = struct_list();
+
+    // {1, a}, {1, a}, {1, null}, {1, a}
+    let needles = take(&values, &Int32Array::from_slice(&[0, 0, 4, 0])).unwrap();
+    let result = list_contains(&list, needles.as_ref()).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), Some(true), None]);
+    assert_eq!(result, expected);
+
+    // {null, c}, a null element is not equal to a null value
+    let needles = take(
+        &values,
+        &Int32Array::from(&[Some(2), Some(2), None, Some(0)]),
+    )
+    .unwrap();
+    let result = list_contains(&list, needles.as_ref()).unwrap();
+    let expected = BooleanArray::from(&[Some(false), Some(true), None, None]);
+    assert_eq!(result, expected);
+
+    let scalar = new_scalar(&values, 1);
+    let result = list_contains_scalar(&list, scalar.as_ref()).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), Some(false), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn list_contains_primitive_and_utf8() {
+    let data = vec![
+        Some(vec![Some(1.0), Some(f64::NAN)]),
+        Some(vec![None]),
+        Some(vec![]),
+    ];
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<f64>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i32> = list.into();
+
+    let needles = Float64Array::from(&[Some(f64::NAN), None, Some(1.0)]);
+    let result = list_contains(&list, &needles).unwrap();
+    let expected = BooleanArray::from(&[Some(true), None, Some(false)]);
+    assert_eq!(result, expected);
+
+    let scalar = PrimitiveScalar::new(DataType::Float64, Some(1.0f64));
+    let result = list_contains_scalar(&list, &scalar).unwrap();
+    let expected = BooleanArray::from_slice(&[true, false, false]);
+    assert_eq!(result, expected);
+
+    let scalar = PrimitiveScalar::<f64>::new(DataType::Float64, None);
+    let result = list_contains_scalar(&list, &scalar).unwrap();
+    assert_eq!(result, BooleanArray::new_null(DataType::Boolean, 3));
+
+    let data = vec![Some(vec![Some("a"), Some("b")]), Some(vec![Some("c")])];
+    let mut list = MutableListArray::<i64, MutableUtf8Array<i32>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i64> = list.into();
+    let result = list_contains_scalar(&list, &Utf8Scalar::<i32>::new(Some("b"))).unwrap();
+    assert_eq!(result, BooleanArray::from_slice(&[true, false]));
+}
+
+#[test]
+fn list_contains_errors() {
+    let (list, values) = struct_list();
+    assert!(list_contains(&list, &values.slice(0, 3)).is_err());
+    assert!(list_contains(&list, &Int32Array::from_slice(&[1, 2, 3, 4])).is_err());
+    assert!(list_contains(&values, &values).is_err());
+    assert!(list_contains_scalar(&list, &Utf8Scalar::<i32>::new(Some("a"))).is_err());
+}
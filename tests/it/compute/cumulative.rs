@@ -0,0 +1,100 @@
+use arrow2::array::*;
+use arrow2::compute::cumulative::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn sum_and_product() {
+    let array = Int64Array::from(&[Some(1), Some(2), None, Some(3), Some(-4)]);
+
+    let result = cumsum(&array, true).unwrap();
+    let expected = Int64Array::from(&[Some(1), Some(3), None, Some(6), Some(2)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = cumprod(&array, true).unwrap();
+    let expected = Int64Array::from(&[Some(1), Some(2), None, Some(6), Some(-24)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn propagate_nulls() {
+    let array = UInt8Array::from(&[None, Some(1), Some(2)]);
+    let result = cumsum(&array, false).unwrap();
+    assert_eq!(UInt8Array::from(&[None, None, None]), result.as_ref());
+
+    let array = UInt8Array::from(&[Some(1), Some(2), None, Some(3)]);
+    let result = cummax(&array, false).unwrap();
+    assert_eq!(
+        UInt8Array::from(&[Some(1), Some(2), None, None]),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn no_nulls() {
+    let array = Float32Array::from_slice(&[1.5, 0.5, 2.0]);
+    let result = cumsum(&array, false).unwrap();
+    assert_eq!(Float32Array::from_slice(&[1.5, 2.0, 4.0]), result.as_ref());
+    assert!(result.validity().is_none());
+}
+
+#[test]
+fn min_max_nan() {
+    let array = Float64Array::from(&[Some(2.0), Some(f64::NAN), None, Some(1.0), Some(3.0)]);
+
+    let result = cummin(&array, true).unwrap();
+    let expected = Float64Array::from(&[Some(2.0), Some(2.0), None, Some(1.0), Some(1.0)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = cummax(&array, true).unwrap();
+    let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(result.value(0), 2.0);
+    assert!((1..5).all(|i| result.value(i).is_nan()));
+    assert!(result.is_null(2));
+}
+
+#[test]
+fn decimal() {
+    let array = PrimitiveArray::<i128>::from_slice(&[100, 250]).to(DataType::Decimal(10, 2));
+    let result = cumsum(&array, true).unwrap();
+    let expected = PrimitiveArray::<i128>::from_slice(&[100, 350]).to(DataType::Decimal(10, 2));
+    assert_eq!(expected, result.as_ref());
+
+    assert!(cumprod(&array, true).is_err());
+}
+
+#[test]
+fn errors() {
+    let array = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(cumsum(&array, true).is_err());
+    assert!(cumprod(&array, true).is_err());
+    assert!(cummin(&array, true).is_err());
+    assert!(cummax(&array, true).is_err());
+}
+
+#[test]
+fn overflow() {
+    let array = Int8Array::from_slice(&[100, 100]);
+
+    let result = cumsum(&array, true).unwrap();
+    assert_eq!(Int8Array::from_slice(&[100, -56]), result.as_ref());
+    assert!(checked_cumsum(&array, true).is_err());
+
+    let result = cumprod(&array, true).unwrap();
+    assert_eq!(Int8Array::from_slice(&[100, 16]), result.as_ref());
+    assert!(checked_cumprod(&array, true).is_err());
+
+    // nulls are not accumulated
+    let array = UInt8Array::from(&[Some(200), None, Some(55)]);
+    let result = checked_cumsum(&array, true).unwrap();
+    assert_eq!(
+        UInt8Array::from(&[Some(200), None, Some(255)]),
+        result.as_ref()
+    );
+
+    let array = Float64Array::from_slice(&[f64::MAX, f64::MAX]);
+    let result = checked_cumsum(&array, true).unwrap();
+    assert_eq!(
+        Float64Array::from_slice(&[f64::MAX, f64::INFINITY]),
+        result.as_ref()
+    );
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::distinct::*;
+
+#[test]
+fn distinct_order() {
+    let array = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("b"), None, Some("c")]);
+
+    let result = distinct(&array, DistinctOrder::FirstOccurrence).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("c")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = distinct(&array, DistinctOrder::Sorted).unwrap();
+    let expected = Utf8Array::<i32>::from(&[None, Some("a"), Some("b"), Some("c")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn distinct_dictionary_by_values() {
+    // the keys 0 and 2 point to equal values
+    let values = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b", "a"]));
+    let keys = Int32Array::from_slice(&[0, 1, 2, 1]);
+    let array = DictionaryArray::<i32>::from_data(keys, values);
+
+    let indices =
+        distinct_indices(&[&array as &dyn Array], DistinctOrder::FirstOccurrence).unwrap();
+    assert_eq!(indices, UInt64Array::from_slice(&[0, 1]));
+}
+
+#[test]
+fn distinct_multiple_columns() {
+    let a = Arc::new(Int32Array::from(&[
+        Some(2),
+        Some(1),
+        Some(2),
+        None,
+        Some(1),
+    ])) as Arc<dyn Array>;
+    let b = Arc::new(BooleanArray::from(&[
+        Some(true),
+        Some(false),
+        Some(true),
+        None,
+        Some(true),
+    ])) as Arc<dyn Array>;
+
+    let result = distinct_columns(&[a.clone(), b.clone()], DistinctOrder::Sorted).unwrap();
+    let expected_a = Int32Array::from(&[None, Some(1), Some(1), Some(2)]);
+    let expected_b = BooleanArray::from(&[None, Some(false), Some(true), Some(true)]);
+    assert_eq!(result[0].as_ref(), &expected_a as &dyn Array);
+    assert_eq!(result[1].as_ref(), &expected_b as &dyn Array);
+
+    let b = b.slice(0, 4);
+    assert!(distinct_columns(&[a.as_ref(), b.as_ref()], DistinctOrder::Sorted).is_err());
+}
@@ -0,0 +1,82 @@
+use arrow2::array::*;
+use arrow2::compute::edit_distance::*;
+
+#[test]
+fn levenshtein_distance() {
+    let lhs = Utf8Array::<i32>::from(&[
+        Some("kitten"),
+        Some(""),
+        Some("abc"),
+        Some("héllo"),
+        None,
+        Some("a"),
+    ]);
+    let rhs = Utf8Array::<i64>::from(&[
+        Some("sitting"),
+        Some("abc"),
+        Some(""),
+        Some("hello"),
+        Some("a"),
+        None,
+    ]);
+    let result = levenshtein(&lhs, &rhs).unwrap();
+    let expected = UInt32Array::from(&[Some(3), Some(3), Some(3), Some(1), None, None]);
+    assert_eq!(result, expected);
+
+    let result = levenshtein_scalar(&lhs, "abc").unwrap();
+    let expected = UInt32Array::from(&[Some(6), Some(3), Some(0), Some(5), None, Some(2)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn jaro_winkler_similarity() {
+    let lhs = Utf8Array::<i32>::from(&[
+        Some("martha"),
+        Some("dixon"),
+        Some("abc"),
+        Some(""),
+        Some("abc"),
+        None,
+    ]);
+    let rhs = Utf8Array::<i32>::from(&[
+        Some("marhta"),
+        Some("dicksonx"),
+        Some("xyz"),
+        Some(""),
+        Some(""),
+        Some("a"),
+    ]);
+    let result = jaro_winkler(&lhs, &rhs).unwrap();
+    let expected = [
+        Some(0.9611),
+        Some(0.8133),
+        Some(0.0),
+        Some(1.0),
+        Some(0.0),
+        None,
+    ];
+    assert_eq!(result.len(), expected.len());
+    result
+        .iter()
+        .zip(expected)
+        .for_each(|(result, expected)| match (result, expected) {
+            (Some(result), Some(expected)) => assert!((result - expected).abs() < 1e-4),
+            (result, expected) => assert_eq!(result.copied(), expected),
+        });
+
+    let result = jaro_winkler_scalar(&lhs, "martha").unwrap();
+    assert_eq!(result.value(0), jaro_winkler(&lhs, &lhs).unwrap().value(0));
+    assert!(result.is_null(5));
+}
+
+#[test]
+fn errors() {
+    let lhs = Utf8Array::<i32>::from_slice(&["a"]);
+    let rhs = Utf8Array::<i32>::from_slice(&["a", "b"]);
+    assert!(levenshtein(&lhs, &rhs).is_err());
+    assert!(jaro_winkler(&lhs, &rhs).is_err());
+
+    let array = Int32Array::from_slice(&[1]);
+    assert!(levenshtein_scalar(&array, "a").is_err());
+    assert!(jaro_winkler(&lhs, &array).is_err());
+}
@@ -193,3 +193,37 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+#[test]
+fn selection() {
+    let filter = BooleanArray::from(&[
+        Some(false),
+        Some(true),
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(true),
+    ]);
+    assert_eq!(selection_ranges(&filter, 0), vec![(1, 2), (5, 1), (7, 2)]);
+    assert_eq!(selection_ranges(&filter, 1), vec![(1, 2), (5, 1), (7, 2)]);
+    assert_eq!(selection_ranges(&filter, 2), vec![(1, 2), (5, 4)]);
+    assert_eq!(selection_ranges(&filter, 3), vec![(1, 8)]);
+
+    let indices = selection_indices::<u64>(&filter).unwrap();
+    assert_eq!(indices, UInt64Array::from_slice(&[1, 2, 5, 7, 8]));
+
+    // sliced
+    let filter = filter.slice(2, 4);
+    assert_eq!(selection_ranges(&filter, 0), vec![(0, 1), (3, 1)]);
+    let indices = selection_indices::<u32>(&filter).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice(&[0, 3]));
+
+    let filter = BooleanArray::from_slice(&[false; 3]);
+    assert!(selection_ranges(&filter, 0).is_empty());
+
+    let filter = BooleanArray::from_slice(&[true; 300]);
+    assert!(selection_indices::<u8>(&filter).is_err());
+}
@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::groupby::*;
+use arrow2::datatypes::DataType;
+
+fn aggregation(values: &dyn Array, function: AggregateFunction) -> Aggregation {
+    Aggregation { values, function }
+}
+
+#[test]
+fn primitive() {
+    let keys = Int32Array::from(&[Some(1), Some(2), None, Some(1), None, Some(2), Some(1)]);
+    let values = Int64Array::from(&[Some(1), Some(2), Some(3), None, Some(5), Some(6), Some(7)]);
+
+    let (keys, values) = groupby(
+        &[&keys as &dyn Array],
+        &[
+            aggregation(&values, AggregateFunction::Count),
+            aggregation(&values, AggregateFunction::Sum),
+            aggregation(&values, AggregateFunction::Min),
+            aggregation(&values, AggregateFunction::Max),
+            aggregation(&values, AggregateFunction::Mean),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(
+        keys[0].as_ref(),
+        &Int32Array::from(&[Some(1), Some(2), None]) as &dyn Array
+    );
+    let expected: Vec<Box<dyn Array>> = vec![
+        Box::new(UInt64Array::from_slice(&[2, 2, 2])),
+        Box::new(Int64Array::from_slice(&[8, 8, 8])),
+        Box::new(Int64Array::from_slice(&[1, 2, 3])),
+        Box::new(Int64Array::from_slice(&[7, 6, 5])),
+        Box::new(Float64Array::from_slice(&[4.0, 4.0, 4.0])),
+    ];
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn multiple_keys() {
+    let a = Utf8Array::<i32>::from(&[Some("a"), Some("a"), Some("b"), Some("a"), None, None]);
+    let b = BooleanArray::from(&[Some(true), Some(false), Some(true), Some(true), None, None]);
+    let values = Float32Array::from(&[Some(1.0), Some(2.0), Some(3.0), Some(4.0), None, None]);
+
+    let (keys, values) = groupby(
+        &[&a as &dyn Array, &b as &dyn Array],
+        &[
+            aggregation(&values, AggregateFunction::Sum),
+            aggregation(&values, AggregateFunction::Count),
+        ],
+    )
+    .unwrap();
+
+    let expected: Vec<Box<dyn Array>> = vec![
+        Box::new(Utf8Array::<i32>::from(&[
+            Some("a"),
+            Some("a"),
+            Some("b"),
+            None,
+        ])),
+        Box::new(BooleanArray::from(&[
+            Some(true),
+            Some(false),
+            Some(true),
+            None,
+        ])),
+    ];
+    assert_eq!(keys, expected);
+    let expected: Vec<Box<dyn Array>> = vec![
+        Box::new(Float32Array::from(&[Some(5.0), Some(2.0), Some(3.0), None])),
+        Box::new(UInt64Array::from_slice(&[2, 1, 1, 0])),
+    ];
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn min_max_non_primitive() {
+    let keys = DictionaryArray::<i32>::from_data(
+        Int32Array::from_slice(&[0, 1, 0, 1, 0]),
+        Arc::new(Utf8Array::<i32>::from_slice(&["x", "y"])),
+    );
+    let strings = Utf8Array::<i64>::from(&[Some("b"), None, Some("a"), None, Some("c")]);
+    let booleans = BooleanArray::from(&[Some(true), Some(true), Some(false), None, Some(true)]);
+
+    let (keys, values) = groupby(
+        &[&keys as &dyn Array],
+        &[
+            aggregation(&strings, AggregateFunction::Min),
+            aggregation(&strings, AggregateFunction::Max),
+            aggregation(&booleans, AggregateFunction::Min),
+            aggregation(&booleans, AggregateFunction::Max),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(keys[0].len(), 2);
+    assert_eq!(
+        keys[0].data_type(),
+        &DataType::Dictionary(
+            arrow2::datatypes::IntegerType::Int32,
+            Box::new(DataType::Utf8),
+            false,
+        )
+    );
+    let expected: Vec<Box<dyn Array>> = vec![
+        Box::new(Utf8Array::<i64>::from(&[Some("a"), None])),
+        Box::new(Utf8Array::<i64>::from(&[Some("c"), None])),
+        Box::new(BooleanArray::from(&[Some(false), Some(true)])),
+        Box::new(BooleanArray::from(&[Some(true), Some(true)])),
+    ];
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn floats_nan() {
+    let keys = Int32Array::from_slice(&[0, 0, 0, 1]);
+    let values = Float64Array::from_slice(&[1.0, f64::NAN, -1.0, 2.0]);
+
+    let (_, values) = groupby(
+        &[&keys as &dyn Array],
+        &[
+            aggregation(&values, AggregateFunction::Min),
+            aggregation(&values, AggregateFunction::Max),
+        ],
+    )
+    .unwrap();
+
+    let min = values[0].as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(min.values().as_slice(), &[-1.0, 2.0]);
+    let max = values[1].as_any().downcast_ref::<Float64Array>().unwrap();
+    assert!(max.value(0).is_nan());
+    assert_eq!(max.value(1), 2.0);
+}
+
+#[test]
+fn empty() {
+    let keys = Int32Array::from_slice(&[]);
+    let values = Int32Array::from_slice(&[]);
+    let (keys, values) = groupby(
+        &[&keys as &dyn Array],
+        &[aggregation(&values, AggregateFunction::Sum)],
+    )
+    .unwrap();
+    assert_eq!(keys[0].len(), 0);
+    assert_eq!(values[0].len(), 0);
+}
+
+#[test]
+fn errors() {
+    let keys = Int32Array::from_slice(&[1, 2]);
+    let values = Utf8Array::<i32>::from_slice(&["a", "b"]);
+
+    let no_keys: &[&dyn Array] = &[];
+    assert!(groupby(no_keys, &[]).is_err());
+    assert!(groupby(
+        &[&keys as &dyn Array],
+        &[aggregation(&values, AggregateFunction::Sum)]
+    )
+    .is_err());
+    assert!(groupby(
+        &[&keys as &dyn Array],
+        &[aggregation(&values.slice(0, 1), AggregateFunction::Count)]
+    )
+    .is_err());
+
+    assert!(can_aggregate(AggregateFunction::Count, &DataType::Utf8));
+    assert!(!can_aggregate(
+        AggregateFunction::Mean,
+        &DataType::Decimal(10, 2)
+    ));
+    assert!(!can_aggregate(
+        AggregateFunction::Sum,
+        &DataType::Decimal256(10, 2)
+    ));
+}
+
+#[test]
+fn sum_overflow() {
+    let keys = Int32Array::from_slice(&[1, 1, 2]);
+    let values = Int8Array::from_slice(&[100, 100, -128]);
+    let (_, values) = groupby(
+        &[&keys as &dyn Array],
+        &[aggregation(&values, AggregateFunction::Sum)],
+    )
+    .unwrap();
+    assert_eq!(
+        values[0].as_ref(),
+        &Int8Array::from_slice(&[-56, -128]) as &dyn Array
+    );
+}
+
+#[test]
+fn too_many_rows() {
+    let keys = NullArray::new_null(DataType::Null, u32::MAX as usize + 1);
+    assert!(groupby(&[&keys as &dyn Array], &[]).is_err());
+}
@@ -1,4 +1,4 @@
-use arrow2::array::new_null_array;
+use arrow2::array::*;
 use arrow2::compute::hash::*;
 use arrow2::datatypes::DataType::*;
 use arrow2::datatypes::TimeUnit;
@@ -47,3 +47,204 @@ fn consistency() {
         }
     });
 }
+
+fn hash_utf8(values: &[&str], algorithm: HashAlgorithm, seed: u64) -> Vec<u64> {
+    let array = Utf8Array::<i32>::from_slice(values);
+    let options = HashOptions { algorithm, seed };
+    let result = hash_with_options(&array, &options).unwrap();
+    result.values().to_vec()
+}
+
+#[test]
+fn xxhash64() {
+    let values = ["", "a", "abc", "Nobody inspects the spammish repetition"];
+    let result = hash_utf8(&values, HashAlgorithm::XxHash64, 0);
+    assert_eq!(
+        result,
+        vec![
+            0xef46db3751d8e999,
+            0xd24ec4f1a98c6e5b,
+            0x44bc2cf5ad770999,
+            0xfbcea83c8a378bf1,
+        ]
+    );
+}
+
+#[test]
+fn murmur3() {
+    let result = hash_utf8(&["", "test"], HashAlgorithm::Murmur3, 0);
+    assert_eq!(result, vec![0, 0xba6bd213]);
+
+    let result = hash_utf8(&[""], HashAlgorithm::Murmur3, 1);
+    assert_eq!(result, vec![0x514e28b7]);
+
+    let result = hash_utf8(&["Hello, world!"], HashAlgorithm::Murmur3, 1234);
+    assert_eq!(result, vec![0xfaf6cdb3]);
+
+    let result = hash_utf8(
+        &["The quick brown fox jumps over the lazy dog"],
+        HashAlgorithm::Murmur3,
+        0x9747b28c,
+    );
+    assert_eq!(result, vec![0x2fa826cd]);
+}
+
+#[test]
+fn portable_primitive() {
+    // Spark's `hash(1)`, i.e. MurmurHash3 of the 4 little-endian bytes of `1` with seed 42
+    let array = Int32Array::from(&[Some(1), None]);
+    let options = HashOptions {
+        algorithm: HashAlgorithm::Murmur3,
+        seed: 42,
+    };
+    let result = hash_with_options(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        UInt64Array::from(&[Some((-559580957i32) as u32 as u64), None])
+    );
+
+    // Spark's `xxhash64(1L)`
+    let array = Int64Array::from_slice(&[1]);
+    let options = HashOptions {
+        algorithm: HashAlgorithm::XxHash64,
+        seed: 42,
+    };
+    let result = hash_with_options(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        UInt64Array::from_slice(&[(-7001672635703045582i64) as u64])
+    );
+}
+
+#[test]
+fn seed() {
+    let array = Int32Array::from_slice(&[1, 2]);
+    for algorithm in [
+        HashAlgorithm::AHash,
+        HashAlgorithm::XxHash64,
+        HashAlgorithm::Murmur3,
+    ] {
+        let a = hash_with_options(&array, &HashOptions { algorithm, seed: 1 }).unwrap();
+        let b = hash_with_options(&array, &HashOptions { algorithm, seed: 2 }).unwrap();
+        assert_ne!(a, b);
+    }
+
+    // the default options are the ones of `hash`
+    let result = hash_with_options(&array, &HashOptions::default()).unwrap();
+    assert_eq!(result, hash(&array).unwrap());
+}
+
+fn chunk(arrays: Vec<Box<dyn Array>>) -> arrow2::chunk::Chunk<Box<dyn Array>> {
+    arrow2::chunk::Chunk::new(arrays)
+}
+
+#[test]
+fn chunk_primitive_utf8() {
+    let a = Int32Array::from(&[Some(1), None, Some(1), Some(1), None]);
+    let b = Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("a"), Some("b"), Some("b")]);
+    let chunk = chunk(vec![Box::new(a), Box::new(b)]);
+
+    for algorithm in [
+        HashAlgorithm::AHash,
+        HashAlgorithm::XxHash64,
+        HashAlgorithm::Murmur3,
+    ] {
+        let options = HashOptions { algorithm, seed: 7 };
+        let result = hash_chunk(&chunk, &options).unwrap();
+        assert_eq!(result.validity(), None);
+        assert_eq!(result.value(0), result.value(2));
+        assert_eq!(result.value(1), result.value(4));
+        assert_ne!(result.value(0), result.value(3));
+        assert_ne!(result.value(1), result.value(3));
+    }
+}
+
+#[test]
+fn chunk_column_order() {
+    let a = Int32Array::from_slice(&[1]);
+    let b = Int32Array::from_slice(&[2]);
+    let options = HashOptions::default();
+    let ab = hash_chunk(
+        &chunk(vec![Box::new(a.clone()), Box::new(b.clone())]),
+        &options,
+    );
+    let ba = hash_chunk(&chunk(vec![Box::new(b), Box::new(a)]), &options);
+    assert_ne!(ab.unwrap(), ba.unwrap());
+}
+
+#[test]
+fn chunk_dictionary() {
+    let values = Utf8Array::<i32>::from_slice(&["a", "b"]);
+    let keys = PrimitiveArray::<i32>::from(&[Some(1), Some(0), None, Some(1)]);
+    let dictionary = DictionaryArray::<i32>::from_data(keys, std::sync::Arc::new(values));
+    let plain = Utf8Array::<i32>::from(&[Some("b"), Some("a"), None, Some("b")]);
+
+    let options = HashOptions::default();
+    let result = hash_chunk(&chunk(vec![Box::new(dictionary)]), &options).unwrap();
+    let expected = hash_chunk(&chunk(vec![Box::new(plain)]), &options).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn chunk_nested() {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), None]),
+        Some(vec![Some(1)]),
+    ];
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i32> = list.into();
+
+    let options = HashOptions::default();
+    let result = hash_chunk(&chunk(vec![Box::new(list.clone())]), &options).unwrap();
+    assert_eq!(result.value(0), result.value(3));
+    let distinct = [0, 1, 2, 4, 5]
+        .iter()
+        .map(|i| result.value(*i))
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(distinct.len(), 5);
+
+    let fields = vec![
+        arrow2::datatypes::Field::new("a", list.data_type().clone(), true),
+        arrow2::datatypes::Field::new("b", Boolean, true),
+    ];
+    let b = BooleanArray::from_slice(&[true, true, true, true, false, true]);
+    let array = StructArray::from_data(
+        Struct(fields),
+        vec![std::sync::Arc::new(list), std::sync::Arc::new(b)],
+        None,
+    );
+    let result = hash_chunk(&chunk(vec![Box::new(array)]), &options).unwrap();
+    assert_eq!(result.value(0), result.value(3));
+    assert_ne!(result.value(0), result.value(4));
+}
+
+#[test]
+fn chunk_consistency() {
+    let datatypes = vec![
+        Null,
+        Boolean,
+        Int32,
+        Float32,
+        Utf8,
+        List(Box::new(arrow2::datatypes::Field::new("a", Int64, true))),
+        List(Box::new(arrow2::datatypes::Field::new("a", Float64, true))),
+        Struct(vec![arrow2::datatypes::Field::new("a", Binary, true)]),
+        Dictionary(
+            arrow2::datatypes::IntegerType::Int32,
+            Box::new(LargeUtf8),
+            false,
+        ),
+    ];
+
+    datatypes.into_iter().for_each(|d1| {
+        let array = new_null_array(d1.clone(), 10);
+        let chunk = chunk(vec![array]);
+        let result = hash_chunk(&chunk, &HashOptions::default());
+        assert_eq!(can_hash_chunk(&d1), result.is_ok(), "{:?}", d1);
+    });
+}
@@ -1,6 +1,8 @@
 use arrow2::array::*;
-use arrow2::compute::if_then_else::if_then_else;
+use arrow2::compute::if_then_else::*;
+use arrow2::datatypes::DataType;
 use arrow2::error::Result;
+use arrow2::scalar::{BooleanScalar, PrimitiveScalar, Utf8Scalar};
 
 #[test]
 fn basics() -> Result<()> {
@@ -40,3 +42,60 @@ fn basics_nulls_pred() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn array_scalar() -> Result<()> {
+    let lhs = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+    let rhs = PrimitiveScalar::<i32>::from(Some(0));
+    let predicate = BooleanArray::from_slice(&[true, true, false, false]);
+    let result = if_then_else_array_scalar(&predicate, &lhs, &rhs)?;
+    let expected = Int32Array::from(&[Some(1), None, Some(0), Some(0)]);
+    assert_eq!(expected, result.as_ref());
+
+    let predicate = BooleanArray::from(&[Some(false), None, Some(true), Some(false)]);
+    let result = if_then_else_array_scalar(&predicate, &lhs, &rhs)?;
+    let expected = Int32Array::from(&[Some(0), None, Some(3), Some(0)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn scalar_array() -> Result<()> {
+    let lhs = Utf8Scalar::<i32>::new(Some("a"));
+    let rhs = Utf8Array::<i32>::from(&[Some("b"), Some("c"), None]);
+    let predicate = BooleanArray::from_slice(&[true, false, false]);
+    let result = if_then_else_scalar_array(&predicate, &lhs, &rhs)?;
+    let expected = Utf8Array::<i32>::from(&[Some("a"), Some("c"), None]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn scalar_scalar() -> Result<()> {
+    let lhs = BooleanScalar::new(Some(true));
+    let rhs = BooleanScalar::new(None);
+    let predicate = BooleanArray::from(&[Some(false), Some(true), None, Some(true)]);
+    let result = if_then_else_scalar_scalar(&predicate, &lhs, &rhs)?;
+    let expected = BooleanArray::from(&[None, Some(true), None, Some(true)]);
+    assert_eq!(expected, result.as_ref());
+
+    let lhs = PrimitiveScalar::<i64>::from(Some(1)).to(DataType::Date64);
+    let rhs = PrimitiveScalar::<i64>::from(Some(2)).to(DataType::Date64);
+    let predicate = BooleanArray::from_slice(&[false, true]);
+    let result = if_then_else_scalar_scalar(&predicate, &lhs, &rhs)?;
+    let expected = Int64Array::from_slice(&[2, 1]).to(DataType::Date64);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn scalar_errors() {
+    let lhs = Int32Array::from_slice(&[1]);
+    let rhs = PrimitiveScalar::<i64>::from(Some(0));
+    let predicate = BooleanArray::from_slice(&[true]);
+    assert!(if_then_else_array_scalar(&predicate, &lhs, &rhs).is_err());
+
+    let rhs = PrimitiveScalar::<i32>::from(Some(0));
+    let predicate = BooleanArray::from_slice(&[true, false]);
+    assert!(if_then_else_array_scalar(&predicate, &lhs, &rhs).is_err());
+}
@@ -0,0 +1,66 @@
+use arrow2::array::*;
+use arrow2::compute::interleave::interleave;
+
+#[test]
+fn primitive() {
+    let a = Int32Array::from_slice(&[1, 2, 3]);
+    let b = Int32Array::from_slice(&[4, 5]);
+    let c = Int32Array::from(&[None, Some(6)]);
+
+    let indices = [(0, 0), (0, 1), (1, 1), (2, 1), (0, 2), (1, 0)];
+    let result = interleave(&[&a, &b, &c], &indices).unwrap();
+    let expected = Int32Array::from_slice(&[1, 2, 5, 6, 3, 4]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = interleave(&[&a, &b, &c], &[(2, 0), (2, 1), (0, 0)]).unwrap();
+    let expected = Int32Array::from(&[None, Some(6), Some(1)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8() {
+    let a = Utf8Array::<i32>::from_slice(&["a", "bb"]);
+    let b = Utf8Array::<i32>::from(&[Some("ccc"), None]);
+
+    let result = interleave(&[&a, &b], &[(1, 1), (0, 1), (1, 0), (0, 0), (0, 0)]).unwrap();
+    let expected = Utf8Array::<i32>::from(&[None, Some("bb"), Some("ccc"), Some("a"), Some("a")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn nested() {
+    let data = vec![Some(vec![Some(1), Some(2)]), None];
+    let mut a = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    a.try_extend(data).unwrap();
+    let a: ListArray<i32> = a.into();
+
+    let data = vec![Some(vec![Some(3)])];
+    let mut b = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    b.try_extend(data).unwrap();
+    let b: ListArray<i32> = b.into();
+
+    let result = interleave(&[&a, &b], &[(1, 0), (0, 1), (0, 0)]).unwrap();
+
+    let data = vec![Some(vec![Some(3)]), None, Some(vec![Some(1), Some(2)])];
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected.try_extend(data).unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn empty() {
+    let a = Int32Array::from_slice(&[1]);
+    let result = interleave(&[&a], &[]).unwrap();
+    assert_eq!(result.len(), 0);
+}
+
+#[test]
+fn errors() {
+    let a = Int32Array::from_slice(&[1]);
+    let b = Int64Array::from_slice(&[1]);
+    assert!(interleave(&[], &[]).is_err());
+    assert!(interleave(&[&a, &b], &[(0, 0)]).is_err());
+    assert!(interleave(&[&a], &[(0, 1)]).is_err());
+    assert!(interleave(&[&a], &[(1, 0)]).is_err());
+}
@@ -57,3 +57,100 @@ fn test_nlike_binary_scalar() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_like_utf8_with_escape() -> Result<()> {
+    let strings = Utf8Array::<i32>::from(&[
+        Some("10%"),
+        Some("100"),
+        Some("a.c"),
+        Some("abc"),
+        Some("a\nb"),
+        None,
+        Some("é"),
+    ]);
+    let patterns = Utf8Array::<i32>::from(&[
+        Some("10!%"),
+        Some("10!%"),
+        Some("a.c"),
+        Some("a.c"),
+        Some("a_b"),
+        Some("%"),
+        None,
+    ]);
+    let result = like_utf8_with_escape(&strings, &patterns, Some('!'))?;
+    let expected = BooleanArray::from(&[
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(true),
+        None,
+        None,
+    ]);
+    assert_eq!(result, expected);
+
+    let result = nlike_utf8_with_escape(&strings, &patterns, Some('!'))?;
+    let expected = BooleanArray::from(&[
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(false),
+        None,
+        None,
+    ]);
+    assert_eq!(result, expected);
+
+    let patterns = Utf8Array::<i32>::from_slice(&["a!"; 7]);
+    assert!(like_utf8_with_escape(&strings, &patterns, Some('!')).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_scalar_with_escape() -> Result<()> {
+    let array = Utf8Array::<i64>::from_slice(&["a_1", "ab1", "a_", "(a)", "é_"]);
+
+    let result = like_utf8_scalar_with_escape(&array, r"a\_%", Some('\\'))?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, false, true, false, false])
+    );
+
+    // without escape character, `\` matches itself
+    let result = like_utf8_scalar_with_escape(&array, r"a\_%", None)?;
+    assert_eq!(result, BooleanArray::from_slice(&[false; 5]));
+
+    // regex characters match themselves
+    let result = like_utf8_scalar_with_escape(&array, "(_)", None)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[false, false, false, true, false])
+    );
+
+    // `_` matches a character, not a byte
+    let result = nlike_utf8_scalar_with_escape(&array, "__", None)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, true, false, true, false])
+    );
+
+    assert!(like_utf8_scalar_with_escape(&array, "a%\\", Some('\\')).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_like_binary_with_escape() -> Result<()> {
+    let values = BinaryArray::<i32>::from_slice(&[&b"a%\xff"[..], b"ab\xff", b"a%\xff\xfe"]);
+
+    let patterns = BinaryArray::<i32>::from_slice(&[b"a#%_", b"a#%_", b"a#%_"]);
+    let result = like_binary_with_escape(&values, &patterns, Some(b'#'))?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, false, false]));
+
+    let result = like_binary_scalar_with_escape(&values, b"%\xff%", None)?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, true, true]));
+
+    let result = nlike_binary_scalar_with_escape(&values, b"a#%\xff", Some(b'#'))?;
+    assert_eq!(result, BooleanArray::from_slice(&[false, true, true]));
+    Ok(())
+}
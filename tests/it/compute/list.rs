@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::buffer::Buffer;
+use arrow2::compute::list::*;
+use arrow2::datatypes::DataType;
+
+fn list(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn explode_contiguous() {
+    let array = list(vec![
+        Some(vec![Some(0)]),
+        Some(vec![Some(1), None]),
+        Some(vec![Some(2), Some(3), Some(4)]),
+    ]);
+    let array = array.slice(1, 2);
+
+    let (values, indices) = explode(&array).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, Some(2), Some(3), Some(4)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(indices, UInt64Array::from_slice(&[0, 0, 1, 1, 1]));
+}
+
+#[test]
+fn explode_null_and_empty() {
+    // the null list covers values that must not be part of the result
+    let values = Arc::new(Int32Array::from_slice(&[1, 2, 3, 4]));
+    let array = ListArray::<i64>::from_data(
+        ListArray::<i64>::default_datatype(DataType::Int32),
+        Buffer::from(vec![0, 1, 3, 3, 4]),
+        values,
+        Some([true, false, true, true].into()),
+    );
+
+    let (values, indices) = explode(&array).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, None, Some(4)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(indices, UInt64Array::from_slice(&[0, 1, 2, 3]));
+
+    let array = list(vec![]);
+    let (values, indices) = explode(&array).unwrap();
+    assert!(values.is_empty());
+    assert!(indices.is_empty());
+}
+
+#[test]
+fn explode_unsupported() {
+    assert!(explode(&Int32Array::from_slice(&[1])).is_err());
+}
+
+fn nested(inner: ListArray<i64>, offsets: Vec<i32>, validity: Option<Vec<bool>>) -> ListArray<i32> {
+    ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(inner.data_type().clone()),
+        Buffer::from(offsets),
+        Arc::new(inner),
+        validity.map(|x| x.into()),
+    )
+}
+
+#[test]
+fn flatten_merges_offsets() {
+    let values = Arc::new(Int32Array::from_slice(&[1, 2, 3, 4, 5]));
+    // [[1, 2], [], null, [3], [4, 5]]
+    let inner = ListArray::<i64>::from_data(
+        ListArray::<i64>::default_datatype(DataType::Int32),
+        Buffer::from(vec![0, 2, 2, 2, 3, 5]),
+        values.clone(),
+        Some([true, true, false, true, true].into()),
+    );
+    // [[[1, 2], []], null, [null, [3]], [[4, 5]]]
+    let array = nested(
+        inner,
+        vec![0, 2, 2, 4, 5],
+        Some(vec![true, false, true, true]),
+    );
+
+    let result = flatten(&array, FlattenNulls::Skip).unwrap();
+    let expected = ListArray::<i64>::from_data(
+        ListArray::<i64>::default_datatype(DataType::Int32),
+        Buffer::from(vec![0, 2, 2, 3, 5]),
+        values,
+        Some([true, false, true, true].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = flatten(&array.slice(2, 2), FlattenNulls::Propagate).unwrap();
+    let expected = expected
+        .slice(2, 2)
+        .with_validity(Some([false, true].into()));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn flatten_removes_values_of_null_lists() {
+    let values = Arc::new(Int32Array::from_slice(&[1, 2, 3]));
+    // [[1], null (covering [2]), [3]]
+    let inner = ListArray::<i64>::from_data(
+        ListArray::<i64>::default_datatype(DataType::Int32),
+        Buffer::from(vec![0, 1, 2, 3]),
+        values,
+        Some([true, false, true].into()),
+    );
+    let array = nested(inner, vec![0, 2, 3], None);
+
+    let result = flatten(&array, FlattenNulls::Skip).unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 1, 2]);
+    let expected = Int32Array::from_slice(&[1, 3]);
+    assert_eq!(result.values().as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn flatten_unsupported() {
+    assert!(flatten(&Int32Array::from_slice(&[1]), FlattenNulls::Skip).is_err());
+    let array = list(vec![Some(vec![Some(1)])]);
+    assert!(flatten(&array, FlattenNulls::Skip).is_err());
+}
+
+#[test]
+fn list_slice_bounds() {
+    let array = list(vec![
+        Some(vec![Some(1), Some(2), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4), None]),
+    ]);
+
+    let result = list_slice(&array, 1, Some(1)).unwrap();
+    let expected = list(vec![
+        Some(vec![Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![None]),
+    ]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_slice(&array, -2, None).unwrap();
+    let expected = list(vec![
+        Some(vec![Some(2), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4), None]),
+    ]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_slice(&array, -5, Some(1)).unwrap();
+    let expected = list(vec![
+        Some(vec![Some(1)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4)]),
+    ]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_slice(&array, 5, None).unwrap();
+    let expected = list(vec![Some(vec![]), None, Some(vec![]), Some(vec![])]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn list_slice_reuses_values() {
+    let array = list(vec![
+        Some(vec![Some(0)]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(3)]),
+    ]);
+    let array = array.slice(1, 2);
+
+    let result = list_slice(&array, 0, Some(2)).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert!(Arc::ptr_eq(result.values(), array.values()));
+
+    let result = list_slice(&array, 0, Some(1)).unwrap();
+    let expected = list(vec![Some(vec![Some(1)]), Some(vec![Some(3)])]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert!(list_slice(&Int32Array::from_slice(&[1]), 0, None).is_err());
+}
@@ -0,0 +1,132 @@
+use arrow2::array::*;
+use arrow2::compute::merge_join::{merge_join, JoinType};
+use arrow2::compute::sort::SortOptions;
+
+fn join(
+    left: &[&dyn Array],
+    right: &[&dyn Array],
+    options: &[SortOptions],
+    join_type: JoinType,
+) -> (Vec<Option<u32>>, Vec<Option<u32>>) {
+    let (l, r) = merge_join::<u32>(left, right, options, join_type).unwrap();
+    (
+        l.iter().map(|x| x.copied()).collect(),
+        r.iter().map(|x| x.copied()).collect(),
+    )
+}
+
+#[test]
+fn join_types() {
+    let left = Int32Array::from(&[None, Some(1), Some(2), Some(2), Some(5)]);
+    let right = Int32Array::from(&[None, Some(2), Some(2), Some(3), Some(5)]);
+    let options = [SortOptions::default()];
+
+    let (l, r) = join(&[&left], &[&right], &options, JoinType::Inner);
+    assert_eq!(l, vec![Some(2), Some(2), Some(3), Some(3), Some(4)]);
+    assert_eq!(r, vec![Some(1), Some(2), Some(1), Some(2), Some(4)]);
+
+    let (l, r) = join(&[&left], &[&right], &options, JoinType::Left);
+    assert_eq!(
+        l,
+        vec![
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(2),
+            Some(3),
+            Some(3),
+            Some(4)
+        ]
+    );
+    assert_eq!(
+        r,
+        vec![None, None, Some(1), Some(2), Some(1), Some(2), Some(4)]
+    );
+
+    let (l, r) = join(&[&left], &[&right], &options, JoinType::Full);
+    assert_eq!(
+        l,
+        vec![
+            Some(0),
+            None,
+            Some(1),
+            Some(2),
+            Some(2),
+            Some(3),
+            Some(3),
+            None,
+            Some(4)
+        ]
+    );
+    assert_eq!(
+        r,
+        vec![
+            None,
+            Some(0),
+            None,
+            Some(1),
+            Some(2),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4)
+        ]
+    );
+}
+
+#[test]
+fn descending_nulls_last() {
+    let left = Utf8Array::<i32>::from(&[Some("c"), Some("a"), None]);
+    let right = Utf8Array::<i32>::from(&[Some("c"), Some("b"), None, None]);
+    let options = [SortOptions {
+        descending: true,
+        nulls_first: false,
+    }];
+
+    let (l, r) = join(&[&left], &[&right], &options, JoinType::Full);
+    assert_eq!(l, vec![Some(0), None, Some(1), Some(2), None, None]);
+    assert_eq!(r, vec![Some(0), Some(1), None, None, Some(2), Some(3)]);
+}
+
+#[test]
+fn multiple_keys() {
+    let left_a = Int32Array::from_slice(&[1, 1, 2]);
+    // nulls first within `a == 1`
+    let left_b = Utf8Array::<i32>::from(&[None, Some("a"), Some("a")]);
+    let right_a = Int32Array::from_slice(&[1, 1, 1, 2]);
+    let right_b = Utf8Array::<i32>::from(&[None, Some("a"), Some("a"), Some("b")]);
+    let options = [SortOptions::default(), SortOptions::default()];
+
+    let (l, r) = join(
+        &[&left_a, &left_b],
+        &[&right_a, &right_b],
+        &options,
+        JoinType::Inner,
+    );
+    assert_eq!(l, vec![Some(1), Some(1)]);
+    assert_eq!(r, vec![Some(1), Some(2)]);
+}
+
+#[test]
+fn empty() {
+    let left = Int32Array::from_slice(&[1, 2]);
+    let right = Int32Array::from_slice(&[]);
+    let options = [SortOptions::default()];
+
+    let (l, r) = join(&[&left], &[&right], &options, JoinType::Inner);
+    assert!(l.is_empty() && r.is_empty());
+
+    let (l, r) = join(&[&left], &[&right], &options, JoinType::Left);
+    assert_eq!(l, vec![Some(0), Some(1)]);
+    assert_eq!(r, vec![None, None]);
+}
+
+#[test]
+fn errors() {
+    let left = Int32Array::from_slice(&[1]);
+    let right = Int64Array::from_slice(&[1]);
+    let options = [SortOptions::default()];
+    assert!(merge_join::<u32>(&[&left], &[&right], &options, JoinType::Inner).is_err());
+    assert!(merge_join::<u32>(&[&left], &[&left], &[], JoinType::Inner).is_err());
+    assert!(merge_join::<u32>(&[], &[], &[], JoinType::Inner).is_err());
+}
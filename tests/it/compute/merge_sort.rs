@@ -228,3 +228,146 @@ fn test_sort() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[cfg(feature = "io_ipc")]
+#[test]
+fn external_sort_spills() -> Result<()> {
+    use std::sync::Arc;
+
+    use arrow2::chunk::Chunk;
+    use arrow2::compute::merge_sort::external::*;
+    use arrow2::compute::sort::{lexsort, SortColumn};
+    use arrow2::datatypes::{DataType, Field, Schema};
+
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let a = (0..100)
+        .map(|x| {
+            if x % 7 == 0 {
+                None
+            } else {
+                Some((x * 37) % 11)
+            }
+        })
+        .collect::<Int32Array>();
+    let b = (0..100)
+        .map(|x| Some(format!("{}", (x * 13) % 17)))
+        .collect::<Utf8Array<i32>>();
+    let chunks = (0..10)
+        .map(|i| {
+            Ok(Chunk::new(vec![
+                Arc::new(a.slice(i * 10, 10)) as Arc<dyn Array>,
+                Arc::new(b.slice(i * 10, 10)),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    let directory =
+        std::env::temp_dir().join(format!("arrow2-external-sort-{}", std::process::id()));
+    std::fs::create_dir_all(&directory)?;
+    let options = ExternalSortOptions {
+        spill_directory: directory.clone(),
+        max_rows_in_memory: 25,
+        batch_size: 16,
+    };
+    let a_options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let columns = [(0, a_options), (1, SortOptions::default())];
+
+    let mut sorted = external_sort(chunks, &schema, &columns, &options)?;
+    let first = sorted.next().unwrap()?;
+    assert_eq!(first.len(), 16);
+    // the first three runs were spilled
+    assert_eq!(std::fs::read_dir(&directory)?.count(), 3);
+    let mut result = vec![first];
+    for chunk in sorted.by_ref() {
+        result.push(chunk?);
+    }
+    assert_eq!(result.len(), 7);
+    drop(sorted);
+    assert_eq!(std::fs::read_dir(&directory)?.count(), 0);
+    std::fs::remove_dir(&directory)?;
+
+    let expected = lexsort::<i32>(
+        &[
+            SortColumn {
+                values: &a,
+                options: Some(a_options),
+            },
+            SortColumn {
+                values: &b,
+                options: None,
+            },
+        ],
+        None,
+    )?;
+    for (column, expected) in expected.iter().enumerate() {
+        let arrays = result
+            .iter()
+            .map(|chunk| chunk.arrays()[column].as_ref())
+            .collect::<Vec<_>>();
+        let array = arrow2::compute::concatenate::concatenate(&arrays)?;
+        assert_eq!(array.as_ref(), expected.as_ref());
+    }
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc")]
+#[test]
+fn external_sort_keeps_existing_files() -> Result<()> {
+    use std::sync::Arc;
+
+    use arrow2::chunk::Chunk;
+    use arrow2::compute::merge_sort::external::*;
+    use arrow2::datatypes::{DataType, Field, Schema};
+
+    let directory = std::env::temp_dir().join(format!(
+        "arrow2-external-sort-existing-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&directory)?;
+    // files with the names that the spills of the first sorts of this process would have
+    let existing = (0..16)
+        .flat_map(|id| (0..4).map(move |file| (id, file)))
+        .map(|(id, file)| {
+            directory.join(format!(
+                "arrow2-external-sort-{}-{}-{}.arrow",
+                std::process::id(),
+                id,
+                file
+            ))
+        })
+        .collect::<Vec<_>>();
+    for path in &existing {
+        std::fs::write(path, b"existing")?;
+    }
+
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let chunks = [vec![3, 1], vec![2, 0], vec![5, 4]]
+        .into_iter()
+        .map(|values| {
+            Ok(Chunk::new(vec![
+                Arc::new(Int32Array::from_vec(values)) as Arc<dyn Array>
+            ]))
+        });
+    let options = ExternalSortOptions::new(2, 6).with_spill_directory(&directory);
+
+    let sorted = external_sort(chunks, &schema, &[(0, SortOptions::default())], &options)?
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        sorted[0].arrays()[0].as_ref(),
+        &Int32Array::from_slice(&[0, 1, 2, 3, 4, 5]) as &dyn Array
+    );
+
+    // the spills were removed, but not the existing files
+    assert_eq!(std::fs::read_dir(&directory)?.count(), existing.len());
+    for path in &existing {
+        assert_eq!(std::fs::read(path)?, b"existing");
+    }
+    std::fs::remove_dir_all(&directory)?;
+    Ok(())
+}
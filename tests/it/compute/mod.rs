@@ -10,32 +10,58 @@ mod boolean;
 mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 mod cast;
+#[cfg(feature = "compute_clamp")]
+mod clamp;
+#[cfg(feature = "compute_coalesce")]
+mod coalesce;
 #[cfg(feature = "compute_comparison")]
 mod comparison;
 #[cfg(feature = "compute_concatenate")]
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(feature = "compute_cumulative")]
+mod cumulative;
+#[cfg(feature = "compute_distinct")]
+mod distinct;
+#[cfg(feature = "compute_edit_distance")]
+mod edit_distance;
 #[cfg(feature = "compute_filter")]
 mod filter;
+#[cfg(feature = "compute_groupby")]
+mod groupby;
 #[cfg(feature = "compute_hash")]
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
 mod if_then_else;
+#[cfg(feature = "compute_interleave")]
+mod interleave;
 #[cfg(feature = "compute_length")]
 mod length;
 #[cfg(feature = "compute_like")]
 mod like;
 #[cfg(feature = "compute_limit")]
 mod limit;
+#[cfg(feature = "compute_list")]
+mod list;
+#[cfg(feature = "compute_merge_join")]
+mod merge_join;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
+#[cfg(feature = "compute_parse")]
+mod parse;
 #[cfg(feature = "compute_partition")]
 mod partition;
+#[cfg(feature = "compute_rank")]
+mod rank;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
+#[cfg(feature = "compute_search_sorted")]
+mod search_sorted;
 #[cfg(feature = "compute_sort")]
 mod sort;
+#[cfg(feature = "compute_struct")]
+mod struct_;
 #[cfg(feature = "compute_substring")]
 mod substring;
 #[cfg(feature = "compute_take")]
@@ -44,5 +70,7 @@ mod take;
 mod temporal;
 #[cfg(feature = "compute_utf8")]
 mod utf8;
+#[cfg(feature = "compute_value_counts")]
+mod value_counts;
 #[cfg(feature = "compute_window")]
 mod window;
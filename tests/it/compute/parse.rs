@@ -0,0 +1,131 @@
+use arrow2::array::*;
+use arrow2::compute::parse::*;
+use arrow2::datatypes::{DataType, TimeUnit};
+
+#[test]
+fn error_policies() {
+    let array = Utf8Array::<i32>::from(&[Some("1"), Some("a"), None, Some("300"), Some("-1")]);
+
+    let options = ParseOptions::default();
+    let (result, failures) = parse(&array, &DataType::UInt8, &options).unwrap();
+    let expected = UInt8Array::from(&[Some(1), None, None, None, None]);
+    assert_eq!(expected, result.as_ref());
+    assert!(failures.is_empty());
+
+    let options = ParseOptions {
+        on_error: ParseErrorPolicy::Collect,
+        ..Default::default()
+    };
+    let (result, failures) = parse(&array, &DataType::UInt8, &options).unwrap();
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(failures, vec![1, 3, 4]);
+
+    let options = ParseOptions {
+        on_error: ParseErrorPolicy::Error,
+        ..Default::default()
+    };
+    assert!(parse(&array, &DataType::UInt8, &options).is_err());
+    let array = Utf8Array::<i32>::from(&[Some("1"), None]);
+    let (result, _) = parse(&array, &DataType::UInt8, &options).unwrap();
+    assert_eq!(UInt8Array::from(&[Some(1), None]), result.as_ref());
+}
+
+#[test]
+fn separators_and_whitespace() {
+    let array = Utf8Array::<i64>::from_slice(&["1,234.5", " 2 ", "\t-3,000\n", "4"]);
+
+    let options = ParseOptions::default();
+    let (result, _) = parse(&array, &DataType::Float64, &options).unwrap();
+    let expected = Float64Array::from(&[None, None, None, Some(4.0)]);
+    assert_eq!(expected, result.as_ref());
+
+    let options = ParseOptions {
+        thousands_separator: Some(','),
+        trim: true,
+        ..Default::default()
+    };
+    let (result, _) = parse(&array, &DataType::Float64, &options).unwrap();
+    let expected = Float64Array::from_slice(&[1234.5, 2.0, -3000.0, 4.0]);
+    assert_eq!(expected, result.as_ref());
+
+    let (result, _) = parse(&array, &DataType::Int32, &options).unwrap();
+    let expected = Int32Array::from(&[None, Some(2), Some(-3000), Some(4)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn logical_types() {
+    let array = Utf8Array::<i32>::from_slice(&["1"]);
+    let (result, _) = parse(&array, &DataType::Date32, &ParseOptions::default()).unwrap();
+    assert_eq!(result.data_type(), &DataType::Date32);
+}
+
+#[test]
+fn unsupported() {
+    assert!(can_parse(&DataType::LargeUtf8, &DataType::Int8));
+    assert!(!can_parse(&DataType::Utf8, &DataType::Utf8));
+    assert!(!can_parse(&DataType::Binary, &DataType::Int8));
+
+    let array = Int32Array::from_slice(&[1]);
+    assert!(parse(&array, &DataType::Int32, &ParseOptions::default()).is_err());
+}
+
+#[test]
+fn timestamp_fallback_formats() {
+    let array = Utf8Array::<i64>::from(&[
+        Some("2021-01-02 03:04:05"),
+        Some("2021/01/02"),
+        Some("02.01.2021 03:04"),
+        None,
+        Some("2021-01-02 03:04:05 trailing"),
+        Some("2021-01-02"),
+    ]);
+    let formats = [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y/%m/%d",
+        "%d.%m.%Y %H:%M",
+        "%Y-%m-%d",
+    ];
+
+    let (result, failures) = parse_timestamp(&array, &formats, None).unwrap();
+    let second = 1_000_000_000;
+    let day = 1_609_545_600 * second;
+    let expected = Int64Array::from(&[
+        Some(day + (3 * 3600 + 4 * 60 + 5) * second),
+        None,
+        Some(day + (3 * 3600 + 4 * 60) * second),
+        None,
+        None,
+        None,
+    ])
+    .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(expected, result.as_ref());
+    // dates without time are not timestamps
+    assert_eq!(failures, vec![1, 4, 5]);
+}
+
+#[test]
+fn timestamp_timezone() {
+    let array =
+        Utf8Array::<i32>::from_slice(&["1970-01-01 01:00:00 +01:00", "1970-01-01 01:00:00"]);
+    let formats = ["%Y-%m-%d %H:%M:%S %z", "%Y-%m-%d %H:%M:%S"];
+
+    let (result, failures) = parse_timestamp(&array, &formats, Some("+02:00".to_string())).unwrap();
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, Some("+02:00".to_string()));
+    let expected = Int64Array::from_slice(&[0, -3_600_000_000_000]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+    assert!(failures.is_empty());
+
+    let (result, _) = parse_timestamp(&array, &formats, None).unwrap();
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let expected = Int64Array::from_slice(&[3_600_000_000_000, 3_600_000_000_000]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn timestamp_errors() {
+    let array = Utf8Array::<i32>::from_slice(&["1970"]);
+    assert!(parse_timestamp(&array, &["%Q"], None).is_err());
+    assert!(parse_timestamp(&array, &["%Y"], Some("invalid".to_string())).is_err());
+    assert!(parse_timestamp(&Int32Array::from_slice(&[1]), &["%Y"], None).is_err());
+}
@@ -189,3 +189,27 @@ fn lexicographical_partition_non_unique_column_1() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn lexicographical_partition_lengths() -> Result<()> {
+    // partitions of lengths 1, 2, ..., 20
+    let values = (1..=20)
+        .flat_map(|length| vec![length; length as usize])
+        .collect::<Vec<i32>>();
+    let values = Int32Array::from_vec(values);
+    let input = vec![SortColumn {
+        values: &values,
+        options: None,
+    }];
+
+    let mut start = 0;
+    let expected = (1..=20)
+        .map(|length| {
+            start += length;
+            start - length..start
+        })
+        .collect::<Vec<_>>();
+    let results = lexicographical_partition_ranges(&input)?;
+    assert_eq!(expected, results.collect::<Vec<_>>());
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use arrow2::array::*;
+use arrow2::compute::rank::{rank, RankMethod};
+use arrow2::compute::sort::SortOptions;
+
+#[test]
+fn methods() {
+    let array = Int32Array::from(&[Some(2), Some(1), Some(2), Some(5), Some(2), Some(1)]);
+
+    let result = rank(&array, RankMethod::Min, None).unwrap();
+    let expected = UInt64Array::from_slice(&[3, 1, 3, 6, 3, 1]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = rank(&array, RankMethod::Max, None).unwrap();
+    let expected = UInt64Array::from_slice(&[5, 2, 5, 6, 5, 2]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = rank(&array, RankMethod::Dense, None).unwrap();
+    let expected = UInt64Array::from_slice(&[2, 1, 2, 3, 2, 1]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = rank(&array, RankMethod::Average, None).unwrap();
+    let expected = Float64Array::from_slice(&[4.0, 1.5, 4.0, 6.0, 4.0, 1.5]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn options() {
+    let array = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), None, Some("c")]);
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let result = rank(&array, RankMethod::Min, Some(options)).unwrap();
+    let expected = UInt64Array::from_slice(&[2, 4, 1, 4, 3]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let result = rank(&array, RankMethod::Dense, Some(options)).unwrap();
+    let expected = UInt64Array::from_slice(&[3, 1, 4, 1, 2]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn empty() {
+    let array = Int32Array::from_slice(&[]);
+    let result = rank(&array, RankMethod::Average, None).unwrap();
+    assert_eq!(result.len(), 0);
+}
@@ -55,3 +55,89 @@ fn test_like_scalar() {
         vec![true, false, false, false],
     )
 }
+
+#[test]
+fn extract_group() {
+    let values = Utf8Array::<i32>::from(&[
+        Some("2021-01-27"),
+        None,
+        Some("not a date"),
+        Some("1999-12"),
+    ]);
+    let pattern = r"(\d{4})-(\d{2})(?:-(\d{2}))?";
+
+    let result = extract(&values, pattern, 0).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("2021-01-27"), None, None, Some("1999-12")]);
+    assert_eq!(result, expected);
+
+    let result = extract(&values, pattern, 3).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("27"), None, None, None]);
+    assert_eq!(result, expected);
+
+    assert!(extract(&values, pattern, 4).is_err());
+    assert!(extract(&values, "(", 0).is_err());
+}
+
+#[test]
+fn extract_precompiled() {
+    let regex = Regex::new(r"(\w+)@").unwrap();
+    let values = Utf8Array::<i64>::from_slice(&["a@b", "cd@e"]);
+    let result = extract_regex(&values, &regex, 1).unwrap();
+    assert_eq!(result, Utf8Array::<i64>::from_slice(&["a", "cd"]));
+
+    let values = Utf8Array::<i64>::from_slice(&["no"]);
+    let result = extract_regex(&values, &regex, 1).unwrap();
+    assert_eq!(result, Utf8Array::<i64>::from(&[None::<&str>]));
+}
+
+#[test]
+fn extract_all_groups() {
+    let values = Utf8Array::<i32>::from(&[Some("x=1"), Some("y"), None, Some("z=")]);
+    let result = extract_groups(&values, r"(?P<key>\w)(=(\d)?)?").unwrap();
+
+    let names = result
+        .fields()
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["key", "2", "3"]);
+
+    let expected = Utf8Array::<i32>::from(&[Some("x"), Some("y"), None, Some("z")]);
+    assert_eq!(expected, result.values()[0].as_ref());
+    let expected = Utf8Array::<i32>::from(&[Some("=1"), None, None, Some("=")]);
+    assert_eq!(expected, result.values()[1].as_ref());
+    let expected = Utf8Array::<i32>::from(&[Some("1"), None, None, None]);
+    assert_eq!(expected, result.values()[2].as_ref());
+    assert_eq!(result.validity(), Some(&[true, true, false, true].into()));
+}
+
+#[test]
+fn extract_groups_without_groups() {
+    let values = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(extract_groups(&values, r"\w").is_err());
+}
+
+#[test]
+fn replace_first_and_all() {
+    let values = Utf8Array::<i32>::from(&[Some("aaa"), None, Some("bab"), Some("")]);
+
+    let result = replace(&values, "a", "x", false).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("xaa"), None, Some("bxb"), Some("")]);
+    assert_eq!(result, expected);
+
+    let result = replace(&values, "a", "x", true).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("xxx"), None, Some("bxb"), Some("")]);
+    assert_eq!(result, expected);
+
+    assert!(replace(&values, "(", "x", true).is_err());
+}
+
+#[test]
+fn replace_groups() {
+    let values = Utf8Array::<i64>::from_slice(&["john smith", "jane doe", "single"]);
+    let regex = Regex::new(r"(?P<first>\w+) (\w+)").unwrap();
+
+    let result = replace_regex(&values, &regex, "$2, ${first} $$", false).unwrap();
+    let expected = Utf8Array::<i64>::from_slice(&["smith, john $", "doe, jane $", "single"]);
+    assert_eq!(result, expected);
+}
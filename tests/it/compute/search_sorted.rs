@@ -0,0 +1,60 @@
+use arrow2::array::*;
+use arrow2::compute::search_sorted::{search_sorted, SearchSide};
+use arrow2::compute::sort::SortOptions;
+
+#[test]
+fn primitive() {
+    let haystack = Int64Array::from_slice(&[-3, 0, 0, 0, 7, 10]);
+    let needles = Int64Array::from_slice(&[-5, -3, 0, 1, 10, 11]);
+
+    let result = search_sorted::<u32>(&haystack, &needles, SearchSide::Left, None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice(&[0, 0, 1, 4, 5, 6]));
+
+    let result = search_sorted::<u32>(&haystack, &needles, SearchSide::Right, None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice(&[0, 1, 4, 4, 6, 6]));
+}
+
+#[test]
+fn utf8() {
+    let haystack = Utf8Array::<i32>::from_slice(&["a", "bb", "bb", "d"]);
+    let needles = Utf8Array::<i32>::from_slice(&["", "b", "bb", "c", "e"]);
+
+    let result = search_sorted::<u64>(&haystack, &needles, SearchSide::Left, None).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[0, 1, 1, 3, 4]));
+
+    let result = search_sorted::<u64>(&haystack, &needles, SearchSide::Right, None).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[0, 1, 3, 3, 4]));
+}
+
+#[test]
+fn options() {
+    let haystack = Int32Array::from(&[Some(5), Some(3), Some(3), None, None]);
+    let needles = Int32Array::from(&[Some(6), Some(3), Some(1), None]);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+
+    let result =
+        search_sorted::<u32>(&haystack, &needles, SearchSide::Left, Some(options)).unwrap();
+    assert_eq!(result, UInt32Array::from_slice(&[0, 1, 3, 3]));
+
+    let result =
+        search_sorted::<u32>(&haystack, &needles, SearchSide::Right, Some(options)).unwrap();
+    assert_eq!(result, UInt32Array::from_slice(&[0, 3, 3, 5]));
+}
+
+#[test]
+fn empty() {
+    let haystack = Int32Array::from_slice(&[]);
+    let needles = Int32Array::from_slice(&[1, 2]);
+    let result = search_sorted::<u32>(&haystack, &needles, SearchSide::Left, None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice(&[0, 0]));
+}
+
+#[test]
+fn different_types() {
+    let haystack = Int32Array::from_slice(&[1]);
+    let needles = Int64Array::from_slice(&[1]);
+    assert!(search_sorted::<u32>(&haystack, &needles, SearchSide::Left, None).is_err());
+}
@@ -1,7 +1,12 @@
 mod lex_sort;
+mod row;
+mod select;
+
+use std::sync::Arc;
 
 use arrow2::array::*;
 use arrow2::compute::sort::*;
+use arrow2::compute::take::take;
 use arrow2::datatypes::*;
 use arrow2::types::NativeType;
 
@@ -551,6 +556,90 @@ fn test_lex_sort_unaligned_rows() {
 }
 */
 
+#[test]
+fn primitive_dicts() {
+    // the value at index 3 is null; 2.0 appears twice in the dictionary
+    let values = Float64Array::from(&[Some(2.0), Some(-1.0), Some(2.0), None, Some(0.5)]);
+    let keys = Int8Array::from(&[Some(0), Some(1), None, Some(2), Some(3), Some(4), Some(1)]);
+    let array = DictionaryArray::<i8>::from_data(keys, Arc::new(values));
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let result = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    let sorted = result.values().as_slice();
+    assert_eq!(&sorted[..3], &[1, 6, 5]);
+    // 2.0 through both keys ties
+    assert!(sorted[3..5] == [0, 3] || sorted[3..5] == [3, 0]);
+    assert!(sorted[5..] == [2, 4] || sorted[5..] == [4, 2]);
+
+    // the result remains dictionary-encoded
+    let result = sort(&array, &options, Some(3)).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert_eq!(result.keys(), &Int8Array::from_slice(&[1, 1, 4]));
+}
+
+#[test]
+fn nested_lists() {
+    let mut array = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![
+            Some(vec![Some("b")]),
+            Some(vec![Some("a"), Some("b")]),
+            None,
+            Some(vec![Some("a")]),
+            Some(vec![]),
+            Some(vec![Some("a"), None]),
+        ])
+        .unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let options = SortOptions::default();
+    let result = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    assert_eq!(result, Int32Array::from_slice(&[2, 4, 3, 5, 1, 0]));
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let result = sort_to_indices::<i32>(&array, &options, Some(3)).unwrap();
+    assert_eq!(result, Int32Array::from_slice(&[0, 1, 5]));
+}
+
+#[test]
+fn nested_structs() {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let array = StructArray::from_data(
+        data_type,
+        vec![
+            Arc::new(Int32Array::from_slice(&[1, 0, 1, 0, 1])),
+            Arc::new(Utf8Array::<i32>::from(&[
+                Some("b"),
+                Some("z"),
+                Some("a"),
+                Some("c"),
+                None,
+            ])),
+        ],
+        Some([true, true, true, false, true].into()),
+    );
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let result = sort(&array, &options, None).unwrap();
+    let expected = take(&array, &Int32Array::from_slice(&[0, 2, 4, 1, 3])).unwrap();
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn consistency() {
     use arrow2::array::new_null_array;
@@ -588,6 +677,14 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        List(Box::new(Field::new("item", Utf8, true))),
+        FixedSizeList(Box::new(Field::new("item", Int32, true)), 2),
+        Struct(vec![Field::new("a", Boolean, true)]),
+        List(Box::new(Field::new(
+            "item",
+            Interval(IntervalUnit::DayTime),
+            true,
+        ))),
     ];
 
     datatypes.into_iter().for_each(|d1| {
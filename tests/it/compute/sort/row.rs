@@ -0,0 +1,485 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use arrow2::array::*;
+use arrow2::compute::sort::row::{RowConverter, Rows, SortField};
+use arrow2::compute::sort::{lexsort_to_indices, SortColumn, SortOptions};
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::types::{days_ms, f16, months_days_ns};
+
+const ALL_OPTIONS: [SortOptions; 4] = [
+    SortOptions {
+        descending: false,
+        nulls_first: true,
+    },
+    SortOptions {
+        descending: false,
+        nulls_first: false,
+    },
+    SortOptions {
+        descending: true,
+        nulls_first: true,
+    },
+    SortOptions {
+        descending: true,
+        nulls_first: false,
+    },
+];
+
+fn convert(
+    columns: &[&(dyn Array + 'static)],
+    options: &[SortOptions],
+) -> Result<(RowConverter, Rows)> {
+    let fields = columns
+        .iter()
+        .zip(options.iter())
+        .map(|(column, options)| SortField::new_with_options(column.data_type().clone(), *options))
+        .collect();
+    let converter = RowConverter::new(fields)?;
+    let rows = converter.convert_columns(columns)?;
+    Ok((converter, rows))
+}
+
+/// Asserts that the order of the rows equals the order of `lexsort_to_indices`, and that
+/// the rows round trip.
+fn test_columns(columns: &[&(dyn Array + 'static)], options: &[SortOptions]) {
+    let (converter, rows) = convert(columns, options).unwrap();
+    assert_eq!(rows.len(), columns[0].len());
+
+    let mut indices = (0..rows.len() as i32).collect::<Vec<_>>();
+    indices.sort_by_key(|i| rows.row(*i as usize));
+
+    let sort_columns = columns
+        .iter()
+        .zip(options.iter())
+        .map(|(column, options)| SortColumn {
+            values: *column,
+            options: Some(*options),
+        })
+        .collect::<Vec<_>>();
+    let expected = lexsort_to_indices::<i32>(&sort_columns, None).unwrap();
+    assert_eq!(Int32Array::from_vec(indices), expected);
+
+    let decoded = converter.convert_rows(rows.iter()).unwrap();
+    decoded
+        .iter()
+        .zip(columns.iter())
+        .for_each(|(decoded, column)| assert_eq!(decoded.as_ref(), *column));
+}
+
+/// Asserts that `array` round trips and that its rows are strictly increasing
+fn test_increasing(array: &(dyn Array + 'static), options: SortOptions) {
+    let (converter, rows) = convert(&[array], &[options]).unwrap();
+    rows.iter()
+        .zip(rows.iter().skip(1))
+        .for_each(|(a, b)| assert!(a < b, "{:?} < {:?}", a, b));
+
+    let decoded = converter.convert_rows(rows.iter()).unwrap();
+    assert_eq!(decoded[0].as_ref(), array);
+}
+
+fn asc_nulls_first() -> SortOptions {
+    ALL_OPTIONS[0]
+}
+
+fn desc_nulls_last() -> SortOptions {
+    ALL_OPTIONS[3]
+}
+
+#[test]
+fn primitives() {
+    let a = Int32Array::from(&[
+        Some(1),
+        None,
+        Some(-2),
+        Some(i32::MAX),
+        Some(i32::MIN),
+        Some(0),
+    ]);
+    let b = UInt64Array::from(&[Some(2), Some(1), None, Some(u64::MAX), Some(0), Some(1)]);
+    let c = Int8Array::from(&[Some(1), Some(1), Some(1), None, None, Some(-1)]);
+    for options in ALL_OPTIONS {
+        test_columns(&[&a], &[options]);
+        test_columns(&[&c, &a], &[options, options]);
+        test_columns(&[&c, &b], &[options, asc_nulls_first()]);
+    }
+}
+
+#[test]
+fn floats() {
+    let array = Float64Array::from(&[
+        None,
+        Some(f64::NEG_INFINITY),
+        Some(-1.5),
+        Some(-0.0),
+        Some(0.0),
+        Some(f64::MIN_POSITIVE),
+        Some(1.5),
+        Some(f64::INFINITY),
+    ]);
+    test_increasing(&array, asc_nulls_first());
+
+    let array = Float32Array::from(&[None, Some(-1.5), Some(-0.0), Some(0.0)]);
+    test_increasing(&array, asc_nulls_first());
+
+    // NaNs are ordered according to totalOrder
+    let array = Float64Array::from_slice(&[-f64::NAN, f64::NEG_INFINITY, f64::INFINITY, f64::NAN]);
+    let (_, rows) = convert(&[&array], &[asc_nulls_first()]).unwrap();
+    assert!(rows.row(0) < rows.row(1));
+    assert!(rows.row(1) < rows.row(2));
+    assert!(rows.row(2) < rows.row(3));
+
+    let array = PrimitiveArray::<f16>::from(&[
+        None,
+        Some(f16::NEG_INFINITY),
+        Some(f16::from_f32(-1.0)),
+        Some(f16::from_f32(2.0)),
+        Some(f16::INFINITY),
+    ]);
+    test_increasing(&array, asc_nulls_first());
+
+    let array = Float64Array::from(&[Some(1.5), Some(-1.5), None]);
+    test_increasing(&array, desc_nulls_last());
+}
+
+#[test]
+fn other_primitives() {
+    let array = Int128Array::from(&[None, Some(i128::MIN), Some(-1), Some(1), Some(i128::MAX)])
+        .to(DataType::Decimal(38, 2));
+    test_increasing(&array, asc_nulls_first());
+
+    let array = DaysMsArray::from(&[
+        None,
+        Some(days_ms::new(-1, 2)),
+        Some(days_ms::new(1, -2)),
+        Some(days_ms::new(1, 2)),
+    ]);
+    test_increasing(&array, asc_nulls_first());
+
+    let array = MonthsDaysNsArray::from(&[
+        Some(months_days_ns::new(1, 2, 3)),
+        Some(months_days_ns::new(1, 2, -3)),
+        Some(months_days_ns::new(-1, 2, 3)),
+        None,
+    ]);
+    test_increasing(&array, desc_nulls_last());
+
+    let array = Int64Array::from(&[None, Some(-1), Some(1)]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+01:00".to_string()),
+    ));
+    test_increasing(&array, asc_nulls_first());
+}
+
+#[test]
+fn boolean() {
+    let a = BooleanArray::from(&[Some(true), None, Some(false), Some(true), Some(false)]);
+    let b = Int32Array::from(&[Some(1), Some(2), Some(3), Some(0), None]);
+    for options in ALL_OPTIONS {
+        test_columns(&[&a], &[options]);
+        test_columns(&[&a, &b], &[options, desc_nulls_last()]);
+    }
+}
+
+#[test]
+fn utf8() {
+    let long = "a".repeat(32);
+    let longer = "a".repeat(33);
+    let longest = format!("{}b", "a".repeat(64));
+    let a = Utf8Array::<i32>::from(&[
+        Some("a"),
+        None,
+        Some(""),
+        Some("ab"),
+        Some(long.as_str()),
+        Some(longer.as_str()),
+        Some("b"),
+        Some(longest.as_str()),
+        Some(""),
+        Some("aa"),
+    ]);
+    let b = Int32Array::from(&[
+        Some(1),
+        Some(2),
+        Some(3),
+        Some(0),
+        None,
+        None,
+        Some(5),
+        Some(1),
+        None,
+        Some(1),
+    ]);
+    for options in ALL_OPTIONS {
+        test_columns(&[&a], &[options]);
+        test_columns(&[&a, &b], &[options, options]);
+        test_columns(&[&b, &a], &[options, options]);
+    }
+
+    let c: Utf8Array<i64> = a.iter().collect();
+    let (converter, rows) = convert(&[&c], &[asc_nulls_first()]).unwrap();
+    let decoded = converter.convert_rows(rows.iter()).unwrap();
+    assert_eq!(decoded[0].as_ref(), &c as &dyn Array);
+
+    let d = a.slice(2, 5);
+    test_columns(&[&d], &[desc_nulls_last()]);
+}
+
+#[test]
+fn binary() {
+    let a = BinaryArray::<i64>::from(&[
+        Some(b"\x00".as_ref()),
+        None,
+        Some(b""),
+        Some(b"\x00\x00"),
+        Some(&[0xFF; 32]),
+        Some(&[0xFF; 40]),
+    ]);
+    test_increasing(
+        &a.clone().slice(2, 4).with_validity(None),
+        asc_nulls_first(),
+    );
+
+    let (converter, rows) = convert(&[&a], &[desc_nulls_last()]).unwrap();
+    assert!(rows.row(0) > rows.row(3));
+    assert!(rows.row(5) < rows.row(4));
+    assert!(rows.row(2) > rows.row(0));
+    assert!(rows.row(1) > rows.row(2));
+    let decoded = converter.convert_rows(rows.iter()).unwrap();
+    assert_eq!(decoded[0].as_ref(), &a as &dyn Array);
+
+    let b = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(2),
+        vec![0, 1, 0, 0, 1, 0, 0, 0].into(),
+        Some([true, true, true, false].into()),
+    );
+    let (converter, rows) = convert(&[&b], &[asc_nulls_first()]).unwrap();
+    assert!(rows.row(3) < rows.row(1));
+    assert!(rows.row(1) < rows.row(0));
+    assert!(rows.row(0) < rows.row(2));
+    let decoded = converter.convert_rows(rows.iter()).unwrap();
+    assert_eq!(decoded[0].as_ref(), &b as &dyn Array);
+}
+
+#[test]
+fn views() {
+    let long = "c".repeat(40);
+    let a = Utf8ViewArray::from([None, Some(""), Some("a"), Some(long.as_str())]);
+    test_increasing(&a, asc_nulls_first());
+
+    let b = BinaryViewArray::from([Some(b"b".as_ref()), Some(b"a"), None]);
+    test_increasing(&b, desc_nulls_last());
+}
+
+#[test]
+fn null() {
+    let a = NullArray::new(DataType::Null, 3);
+    let b = Int32Array::from(&[Some(2), None, Some(1)]);
+    let (converter, rows) = convert(&[&a, &b], &[asc_nulls_first(), asc_nulls_first()]).unwrap();
+    assert!(rows.row(1) < rows.row(2));
+    assert!(rows.row(2) < rows.row(0));
+    let decoded = converter.convert_rows(rows.iter()).unwrap();
+    assert_eq!(decoded[0].as_ref(), &a as &dyn Array);
+}
+
+#[test]
+fn dictionary() {
+    let values = Utf8Array::<i32>::from(&[Some("c"), Some("a"), None, Some("b")]);
+    let keys = PrimitiveArray::<i32>::from(&[Some(0), Some(1), None, Some(2), Some(3), Some(1)]);
+    let a = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+    for options in ALL_OPTIONS {
+        let (converter, rows) = convert(&[&a], &[options]).unwrap();
+        // null keys and null values are equal
+        assert_eq!(rows.row(2), rows.row(3));
+        assert_eq!(rows.row(1), rows.row(5));
+        assert_eq!(rows.row(1) < rows.row(4), !options.descending);
+        assert_eq!(rows.row(4) < rows.row(0), !options.descending);
+        assert_eq!(rows.row(2) < rows.row(1), options.nulls_first);
+
+        let decoded = converter.convert_rows(rows.iter()).unwrap();
+        let decoded = decoded[0]
+            .as_any()
+            .downcast_ref::<DictionaryArray<i32>>()
+            .unwrap();
+        let values = decoded
+            .values()
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap();
+        let decoded = decoded
+            .keys()
+            .iter()
+            .map(|key| {
+                key.map(|key| *key as usize)
+                    .filter(|key| values.is_valid(*key))
+                    .map(|key| values.value(key))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            decoded,
+            vec![Some("c"), Some("a"), None, None, Some("b"), Some("a")]
+        );
+    }
+}
+
+#[test]
+fn struct_() {
+    let a = Int32Array::from(&[Some(1), Some(1), Some(2), None, Some(1)]);
+    let b = Utf8Array::<i32>::from(&[Some("b"), Some("a"), Some("a"), None, Some("c")]);
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let array = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![Arc::new(a), Arc::new(b)],
+        Some([true, true, true, true, false].into()),
+    );
+
+    for options in ALL_OPTIONS {
+        let (converter, rows) = convert(&[&array], &[options]).unwrap();
+        assert_eq!(rows.row(1) < rows.row(0), !options.descending);
+        assert_eq!(rows.row(0) < rows.row(2), !options.descending);
+        assert_eq!(rows.row(4) < rows.row(3), options.nulls_first);
+        assert_eq!(rows.row(4) < rows.row(1), options.nulls_first);
+
+        let decoded = converter.convert_rows(rows.iter()).unwrap();
+        let decoded = decoded[0].as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(decoded.validity(), array.validity());
+        // the children of null slots are not preserved
+        assert_eq!(decoded.slice(0, 4), array.slice(0, 4));
+    }
+}
+
+fn cmp_nulls<T: ?Sized, F: FnOnce(&T, &T) -> Ordering>(
+    a: Option<&T>,
+    b: Option<&T>,
+    options: SortOptions,
+    cmp: F,
+) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, _) if options.nulls_first => Ordering::Less,
+        (None, _) => Ordering::Greater,
+        (_, None) if options.nulls_first => Ordering::Greater,
+        (_, None) => Ordering::Less,
+        (Some(a), Some(b)) if options.descending => cmp(a, b).reverse(),
+        (Some(a), Some(b)) => cmp(a, b),
+    }
+}
+
+/// Nulls inside lists are ordered according to `nulls_first`, values and lengths according to
+/// `descending`.
+fn cmp_lists(
+    a: Option<&[Option<i32>]>,
+    b: Option<&[Option<i32>]>,
+    options: SortOptions,
+) -> Ordering {
+    cmp_nulls(a, b, options, |a, b| {
+        let items = a
+            .iter()
+            .zip(b.iter())
+            .map(|(a, b)| {
+                let ascending = SortOptions {
+                    descending: false,
+                    nulls_first: options.nulls_first,
+                };
+                let ordering = cmp_nulls(a.as_ref(), b.as_ref(), ascending, |a, b| a.cmp(b));
+                // the outer comparison reverses the items when descending, which must not
+                // affect the order of nulls
+                if options.descending && (a.is_none() || b.is_none()) {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            })
+            .find(|x| *x != Ordering::Equal);
+        items.unwrap_or_else(|| a.len().cmp(&b.len()))
+    })
+}
+
+#[test]
+fn list() {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+        None,
+        Some(vec![Some(1)]),
+        Some(vec![Some(1), None]),
+        Some(vec![None]),
+        Some(vec![Some(1), Some(2), Some(0)]),
+        Some(vec![Some(0), Some(3)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data.clone()).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    for options in ALL_OPTIONS {
+        let (converter, rows) = convert(&[&array], &[options]).unwrap();
+
+        for i in 0..data.len() {
+            for j in 0..data.len() {
+                let expected = cmp_lists(data[i].as_deref(), data[j].as_deref(), options);
+                assert_eq!(rows.row(i).cmp(&rows.row(j)), expected);
+            }
+        }
+
+        let decoded = converter.convert_rows(rows.iter()).unwrap();
+        assert_eq!(decoded[0].as_ref(), &array as &dyn Array);
+    }
+
+    let sliced = array.slice(3, 4);
+    let (converter, rows) = convert(&[&sliced], &[asc_nulls_first()]).unwrap();
+    let decoded = converter.convert_rows(rows.iter()).unwrap();
+    assert_eq!(decoded[0].as_ref(), &sliced as &dyn Array);
+}
+
+#[test]
+fn any_rows() {
+    // rows can be decoded in any order and in any number
+    let a = Int32Array::from(&[Some(1), None, Some(3)]);
+    let b = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None]);
+    let (converter, rows) = convert(&[&a, &b], &[asc_nulls_first(), desc_nulls_last()]).unwrap();
+
+    let decoded = converter
+        .convert_rows([rows.row(2), rows.row(0), rows.row(2)])
+        .unwrap();
+    assert_eq!(
+        decoded[0].as_ref(),
+        &Int32Array::from(&[Some(3), Some(1), Some(3)]) as &dyn Array
+    );
+    assert_eq!(
+        decoded[1].as_ref(),
+        &Utf8Array::<i32>::from(&[None, Some("a"), None]) as &dyn Array
+    );
+}
+
+#[test]
+fn errors() {
+    let a = Int32Array::from(&[Some(1), None]);
+    let b = Int64Array::from(&[Some(1)]);
+
+    let converter = RowConverter::new(vec![SortField::new(DataType::Int32)]).unwrap();
+    assert!(converter.convert_columns(&[&b as &dyn Array]).is_err());
+    assert!(converter
+        .convert_columns(&[&a as &dyn Array, &a as &dyn Array])
+        .is_err());
+
+    let converter = RowConverter::new(vec![
+        SortField::new(DataType::Int32),
+        SortField::new(DataType::Int64),
+    ])
+    .unwrap();
+    assert!(converter
+        .convert_columns(&[&a as &dyn Array, &b as &dyn Array])
+        .is_err());
+
+    // rows of a different converter
+    let other = RowConverter::new(vec![SortField::new(DataType::Int32)]).unwrap();
+    let rows = other.convert_columns(&[&a as &dyn Array]).unwrap();
+    assert!(converter.convert_rows(rows.iter()).is_err());
+
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 2);
+    assert!(RowConverter::new(vec![SortField::new(data_type)]).is_err());
+}
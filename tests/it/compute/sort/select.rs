@@ -0,0 +1,92 @@
+use arrow2::array::*;
+use arrow2::compute::sort::{lexsort_to_indices, select_k_indices, SortColumn, SortOptions};
+
+#[test]
+fn single_column() {
+    let array = Int32Array::from(&[Some(5), None, Some(1), Some(3), None, Some(1), Some(-2)]);
+    let columns = [SortColumn {
+        values: &array,
+        options: None,
+    }];
+
+    let indices = select_k_indices::<u32>(&columns, 4).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice(&[1, 4, 6, 2]));
+
+    let indices = select_k_indices::<u32>(&columns, 0).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice(&[]));
+
+    let indices = select_k_indices::<u64>(&columns, 100).unwrap();
+    assert_eq!(indices, UInt64Array::from_slice(&[1, 4, 6, 2, 5, 3, 0]));
+}
+
+#[test]
+fn multiple_columns() {
+    let a = Utf8Array::<i32>::from(&[
+        Some("b"),
+        Some("a"),
+        None,
+        Some("b"),
+        Some("a"),
+        Some("c"),
+        Some("a"),
+    ]);
+    let b = Float64Array::from(&[
+        Some(1.0),
+        Some(2.0),
+        Some(3.0),
+        None,
+        Some(f64::NAN),
+        Some(0.0),
+        Some(2.0),
+    ]);
+    let options = [
+        SortOptions {
+            descending: false,
+            nulls_first: false,
+        },
+        SortOptions {
+            descending: true,
+            nulls_first: true,
+        },
+    ];
+    let columns = [
+        SortColumn {
+            values: &a,
+            options: Some(options[0]),
+        },
+        SortColumn {
+            values: &b,
+            options: Some(options[1]),
+        },
+    ];
+
+    for k in 0..=a.len() {
+        let indices = select_k_indices::<i32>(&columns, k).unwrap();
+        // equal rows (1 and 6) are ordered by index
+        let expected = Int32Array::from_slice(&[4, 1, 6, 3, 0, 5, 2]).slice(0, k);
+        assert_eq!(indices, expected);
+    }
+
+    let expected = lexsort_to_indices::<i32>(&columns, Some(3)).unwrap();
+    let indices = select_k_indices::<i32>(&columns, 3).unwrap();
+    assert_eq!(indices, expected);
+}
+
+#[test]
+fn errors() {
+    assert!(select_k_indices::<u32>(&[], 1).is_err());
+
+    let a = Int32Array::from_slice(&[1, 2]);
+    let b = Int32Array::from_slice(&[1]);
+    let columns = [
+        SortColumn {
+            values: &a,
+            options: None,
+        },
+        SortColumn {
+            values: &b,
+            options: None,
+        },
+    ];
+    assert!(select_k_indices::<u32>(&columns, 1).is_err());
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::struct_::get_field;
+use arrow2::datatypes::{DataType, Field, UnionMode};
+
+fn nested() -> StructArray {
+    let inner = StructArray::from_data(
+        DataType::Struct(vec![
+            Field::new("b", DataType::Int32, true),
+            Field::new("c", DataType::Null, true),
+        ]),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(3), Some(4)])),
+            Arc::new(NullArray::new(DataType::Null, 4)),
+        ],
+        Some([true, true, false, true].into()),
+    );
+    StructArray::from_data(
+        DataType::Struct(vec![Field::new("a", inner.data_type().clone(), true)]),
+        vec![Arc::new(inner)],
+        Some([false, true, true, true].into()),
+    )
+}
+
+#[test]
+fn merges_validity() {
+    let array = nested();
+
+    let result = get_field(&array, &["a", "b"]).unwrap();
+    let expected = Int32Array::from(&[None, None, None, Some(4)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get_field(&array.slice(2, 2), &["a", "b"]).unwrap();
+    assert_eq!(result.as_ref(), &expected.slice(2, 2) as &dyn Array);
+
+    let result = get_field(&array, &["a"]).unwrap();
+    assert_eq!(result.validity(), Some(&[false, true, false, true].into()));
+
+    let result = get_field(&array, &["a", "c"]).unwrap();
+    assert_eq!(result.data_type(), &DataType::Null);
+
+    let result = get_field(&array, &[]).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn map_entries() {
+    let entries = StructArray::from_data(
+        DataType::Struct(vec![
+            Field::new("keys", DataType::Utf8, false),
+            Field::new("values", DataType::Int32, true),
+        ]),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+            Arc::new(Int32Array::from(&[Some(1), None, Some(3)])),
+        ],
+        None,
+    );
+    let array = MapArray::from_data(
+        MapArray::default_datatype(DataType::Utf8, DataType::Int32),
+        vec![0, 2, 2, 3].into(),
+        Arc::new(entries),
+        Some([true, false, true].into()),
+    );
+
+    let result = get_field(&array, &["values"]).unwrap();
+    let expected = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 2, 2, 3].into(),
+        Arc::new(Int32Array::from(&[Some(1), None, Some(3)])),
+        Some([true, false, true].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn errors() {
+    let array = nested();
+    assert!(get_field(&array, &["b"]).is_err());
+    assert!(get_field(&array, &["a", "b", "c"]).is_err());
+    assert!(get_field(&Int32Array::from_slice(&[1]), &["a"]).is_err());
+}
+
+#[test]
+fn union_field() {
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let union = UnionArray::from_data(
+        DataType::Union(fields, None, UnionMode::Sparse),
+        vec![0, 0].into(),
+        vec![Arc::new(Int32Array::from_slice(&[1, 2])) as Arc<dyn Array>],
+        None,
+    );
+    let data_type = DataType::Struct(vec![Field::new("u", union.data_type().clone(), true)]);
+
+    let array = StructArray::from_data(data_type.clone(), vec![Arc::new(union.clone())], None);
+    let result = get_field(&array, &["u"]).unwrap();
+    assert_eq!(result.as_ref(), &union as &dyn Array);
+
+    let array =
+        StructArray::from_data(data_type, vec![Arc::new(union)], Some([true, false].into()));
+    assert!(get_field(&array, &["u"]).is_err());
+}
@@ -342,3 +342,77 @@ fn consistency() {
         }
     });
 }
+
+fn by_char<O: Offset>() -> Result<()> {
+    let array = Utf8Array::<O>::from(&[Some("héllo"), None, Some("ab"), Some(""), Some("日本語")]);
+    let cases = vec![
+        (
+            0,
+            None,
+            vec![Some("héllo"), None, Some("ab"), Some(""), Some("日本語")],
+        ),
+        (
+            1,
+            Some(3),
+            vec![Some("éll"), None, Some("b"), Some(""), Some("本語")],
+        ),
+        (
+            1,
+            Some(0),
+            vec![Some(""), None, Some(""), Some(""), Some("")],
+        ),
+        (
+            -1,
+            None,
+            vec![Some("o"), None, Some("b"), Some(""), Some("語")],
+        ),
+        (
+            -4,
+            Some(2),
+            vec![Some("él"), None, Some("ab"), Some(""), Some("日本")],
+        ),
+        (
+            -10,
+            Some(1),
+            vec![Some("h"), None, Some("a"), Some(""), Some("日")],
+        ),
+        (
+            3,
+            None,
+            vec![Some("lo"), None, Some(""), Some(""), Some("")],
+        ),
+        (
+            10,
+            Some(1),
+            vec![Some(""), None, Some(""), Some(""), Some("")],
+        ),
+    ];
+    for (start, length, expected) in cases {
+        let result = substring_by_char(&array, start, length)?;
+        let expected = Utf8Array::<O>::from(&expected);
+        assert_eq!(
+            result.as_ref(),
+            &expected as &dyn Array,
+            "{} {:?}",
+            start,
+            length
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn by_char_utf8() -> Result<()> {
+    by_char::<i32>()
+}
+
+#[test]
+fn by_char_large_utf8() -> Result<()> {
+    by_char::<i64>()
+}
+
+#[test]
+fn by_char_errors() {
+    let array = BinaryArray::<i32>::from_slice(&[b"a"]);
+    assert!(substring_by_char(&array, 0, None).is_err());
+}
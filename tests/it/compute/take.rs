@@ -313,3 +313,164 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn union() {
+    use arrow2::datatypes::UnionMode;
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(fields.clone(), None, mode);
+        let types = vec![0, 1, 0].into();
+        let (a, b, offsets) = if mode.is_sparse() {
+            let a = Int32Array::from(&[Some(1), None, Some(3)]);
+            let b = Utf8Array::<i32>::from(&[None, Some("b"), None]);
+            (a, b, None)
+        } else {
+            let a = Int32Array::from_slice(&[1, 3]);
+            let b = Utf8Array::<i32>::from_slice(&["b"]);
+            (a, b, Some(vec![0, 0, 1].into()))
+        };
+        let array =
+            UnionArray::from_data(data_type, types, vec![Arc::new(a), Arc::new(b)], offsets);
+
+        let indices = Int32Array::from_slice(&[2, 1, 1, 0]);
+        let result = take(&array, &indices).unwrap();
+        let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(result.len(), 4);
+        assert_eq!(result.types().as_slice(), &[0, 1, 1, 0]);
+        let values = (0..4)
+            .map(|i| format!("{:?}", result.value(i)))
+            .collect::<Vec<_>>();
+        let expected = [2, 1, 1, 0]
+            .iter()
+            .map(|i| format!("{:?}", array.value(*i)))
+            .collect::<Vec<_>>();
+        assert_eq!(values, expected);
+    }
+}
+
+#[test]
+fn union_null_indices() {
+    use arrow2::datatypes::UnionMode;
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(fields.clone(), None, mode);
+        let types = vec![0, 1, 0].into();
+        let (a, b, offsets) = if mode.is_sparse() {
+            let a = Int32Array::from_slice(&[1, 2, 3]);
+            let b = Utf8Array::<i32>::from_slice(&["a", "b", "c"]);
+            (a, b, None)
+        } else {
+            let a = Int32Array::from_slice(&[1, 3]);
+            let b = Utf8Array::<i32>::from_slice(&["b"]);
+            (a, b, Some(vec![0, 0, 1].into()))
+        };
+        let array =
+            UnionArray::from_data(data_type, types, vec![Arc::new(a), Arc::new(b)], offsets);
+
+        let indices = Int32Array::from(&[Some(2), None, Some(1)]);
+        let result = take(&array, &indices).unwrap();
+        let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.types().as_slice(), &[0, 0, 1]);
+        assert_eq!(
+            format!("{:?}", result.value(0)),
+            format!("{:?}", array.value(2))
+        );
+        assert!(!result.value(1).is_valid());
+        assert_eq!(
+            format!("{:?}", result.value(2)),
+            format!("{:?}", array.value(1))
+        );
+    }
+}
+
+#[test]
+fn map() {
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", entries.clone(), false)),
+        false,
+    );
+    let field = StructArray::from_data(
+        entries,
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(&["a", "b", "c"])),
+            Arc::new(Int32Array::from_slice(&[1, 2, 3])),
+        ],
+        None,
+    );
+    let array = MapArray::from_data(
+        data_type,
+        vec![0, 2, 2, 3].into(),
+        Arc::new(field),
+        Some([true, false, true].into()),
+    );
+
+    let indices = Int32Array::from(&[Some(2), None, Some(0), Some(1)]);
+    let result = take(&array, &indices).unwrap();
+    let result = result.as_any().downcast_ref::<MapArray>().unwrap();
+    assert_eq!(result.len(), 4);
+    assert_eq!(result.offsets().as_slice(), &[0, 1, 1, 3, 3]);
+    assert_eq!(result.validity(), Some(&[true, false, true, false].into()));
+    assert_eq!(result.value(0).as_ref(), array.value(2).as_ref());
+    assert_eq!(result.value(2).as_ref(), array.value(0).as_ref());
+}
+
+#[test]
+fn map_null_indices() {
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, false),
+    ]);
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", entries.clone(), false)),
+        false,
+    );
+    let field = StructArray::from_data(
+        entries,
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(&["a", "b", "c"])),
+            Arc::new(Int32Array::from_slice(&[1, 2, 3])),
+        ],
+        None,
+    );
+    let array = MapArray::from_data(data_type, vec![0, 2, 3].into(), Arc::new(field), None);
+
+    let indices = Int32Array::from(&[Some(1), None, Some(0)]);
+    let result = take(&array, &indices).unwrap();
+    let result = result.as_any().downcast_ref::<MapArray>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 1, 1, 3]);
+    assert_eq!(result.validity(), Some(&[true, false, true].into()));
+    assert_eq!(result.value(0).as_ref(), array.value(1).as_ref());
+    assert_eq!(result.value(2).as_ref(), array.value(0).as_ref());
+}
+
+#[test]
+fn fixed_size() {
+    let array = FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), None, Some(b"cd")], 2);
+    let indices = Int32Array::from(&[Some(2), None, Some(1), Some(0)]);
+    let result = take(&array, &indices).unwrap();
+    let expected = FixedSizeBinaryArray::from_iter(vec![Some(b"cd"), None, None, Some(b"ab")], 2);
+    assert_eq!(expected, result.as_ref());
+
+    let values = Int32Array::from_slice(&[1, 2, 3, 4, 5, 6]);
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), 2);
+    let array = FixedSizeListArray::from_data(data_type.clone(), Arc::new(values), None);
+    let indices = Int32Array::from_slice(&[2, 0]);
+    let result = take(&array, &indices).unwrap();
+    let values = Int32Array::from_slice(&[5, 6, 1, 2]);
+    let expected = FixedSizeListArray::from_data(data_type, Arc::new(values), None);
+    assert_eq!(expected, result.as_ref());
+}
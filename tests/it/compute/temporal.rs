@@ -323,6 +323,28 @@ fn consistency_iso_week() {
     consistency_check(can_iso_week, iso_week);
 }
 
+#[test]
+fn consistency_quarter() {
+    consistency_check(can_quarter, quarter);
+}
+
+#[test]
+fn consistency_day_of_year() {
+    consistency_check(can_day_of_year, day_of_year);
+}
+
+#[test]
+fn consistency_day_of_week() {
+    consistency_check(can_day_of_week, |array: &dyn Array| {
+        day_of_week(array, chrono::Weekday::Sun)
+    });
+}
+
+#[test]
+fn consistency_epoch() {
+    consistency_check(can_epoch, epoch);
+}
+
 fn consistency_check<O: arrow2::types::NativeType>(
     can_extract: fn(&DataType) -> bool,
     extract: fn(&dyn Array) -> arrow2::error::Result<PrimitiveArray<O>>,
@@ -372,3 +394,294 @@ fn consistency_check<O: arrow2::types::NativeType>(
         }
     });
 }
+
+#[test]
+fn strftime_dates_and_times() {
+    let array = Int32Array::from(&[Some(0), None, Some(365)]).to(DataType::Date32);
+    let result = strftime(&array, "%Y/%m/%d (%a)", None).unwrap();
+    let expected =
+        Utf8Array::<i32>::from(&[Some("1970/01/01 (Thu)"), None, Some("1971/01/01 (Fri)")]);
+    assert_eq!(result, expected);
+    // dates have no time
+    assert!(strftime(&array, "%H", None).is_err());
+
+    let array = Int64Array::from_slice(&[86_400_000 + 3_600_000]).to(DataType::Date64);
+    let result = strftime(&array, "%F %T", None).unwrap();
+    assert_eq!(
+        result,
+        Utf8Array::<i32>::from_slice(&["1970-01-02 01:00:00"])
+    );
+
+    let array =
+        Int64Array::from_slice(&[3_723_000_000_001]).to(DataType::Time64(TimeUnit::Nanosecond));
+    let result = strftime(&array, "%H:%M:%S%.9f", None).unwrap();
+    assert_eq!(
+        result,
+        Utf8Array::<i32>::from_slice(&["01:02:03.000000001"])
+    );
+
+    let array = Int32Array::from_slice(&[61]).to(DataType::Time32(TimeUnit::Second));
+    let result = strftime(&array, "%M:%S", None).unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(&["01:01"]));
+}
+
+#[test]
+fn strftime_timestamps() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("-01:00".to_string()));
+    let array = Int64Array::from(&[Some(3600), None]).to(data_type);
+
+    let result = strftime(&array, "%Y-%m-%dT%H:%M:%S%:z", None).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("1970-01-01T00:00:00-01:00"), None]);
+    assert_eq!(result, expected);
+
+    let result = strftime(&array, "%H:%M%:z", Some("+03:30")).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("04:30+03:30"), None]);
+    assert_eq!(result, expected);
+
+    #[cfg(feature = "chrono-tz")]
+    {
+        let result = strftime(&array, "%H:%M %Z", Some("Europe/Lisbon")).unwrap();
+        let expected = Utf8Array::<i32>::from(&[Some("02:00 CET"), None]);
+        assert_eq!(result, expected);
+    }
+
+    assert!(strftime(&array, "%Y", Some("invalid")).is_err());
+    assert!(strftime(&array, "%Q", None).is_err());
+    assert!(strftime(&Int32Array::from_slice(&[1]), "%Y", None).is_err());
+    assert!(can_strftime(&DataType::Date64));
+    assert!(!can_strftime(&DataType::Int64));
+}
+
+fn timestamp(date: (i32, u32, u32), time: (u32, u32, u32)) -> i64 {
+    chrono::NaiveDate::from_ymd_opt(date.0, date.1, date.2)
+        .unwrap()
+        .and_hms_opt(time.0, time.1, time.2)
+        .unwrap()
+        .signed_duration_since(
+            chrono::NaiveDate::from_ymd_opt(1970, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        )
+        .num_seconds()
+}
+
+#[test]
+fn date_trunc_naive() {
+    let value = timestamp((2021, 8, 19), (13, 14, 15));
+    let array = Int64Array::from(&[Some(value * 1_000 + 678), None])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let cases = [
+        (TruncUnit::Year, timestamp((2021, 1, 1), (0, 0, 0))),
+        (TruncUnit::Quarter, timestamp((2021, 7, 1), (0, 0, 0))),
+        (TruncUnit::Month, timestamp((2021, 8, 1), (0, 0, 0))),
+        (TruncUnit::Week, timestamp((2021, 8, 16), (0, 0, 0))),
+        (TruncUnit::Day, timestamp((2021, 8, 19), (0, 0, 0))),
+        (TruncUnit::Hour, timestamp((2021, 8, 19), (13, 0, 0))),
+        (TruncUnit::Minute, timestamp((2021, 8, 19), (13, 14, 0))),
+        (TruncUnit::Second, value),
+    ];
+    for (unit, expected) in cases {
+        let result = date_trunc(&array, unit).unwrap();
+        let expected = Int64Array::from(&[Some(expected * 1_000), None])
+            .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+        assert_eq!(expected, result.as_ref(), "{:?}", unit);
+    }
+}
+
+#[test]
+fn date_trunc_dates() {
+    let days = (timestamp((2021, 8, 19), (0, 0, 0)) / 86_400) as i32;
+    let array = Int32Array::from_slice(&[days]).to(DataType::Date32);
+    let result = date_trunc(&array, TruncUnit::Quarter).unwrap();
+    let expected = (timestamp((2021, 7, 1), (0, 0, 0)) / 86_400) as i32;
+    assert_eq!(
+        Int32Array::from_slice(&[expected]).to(DataType::Date32),
+        result.as_ref()
+    );
+    let result = date_trunc(&array, TruncUnit::Hour).unwrap();
+    assert_eq!(array, result.as_ref());
+
+    let array =
+        Int64Array::from_slice(&[timestamp((2021, 8, 19), (1, 2, 3)) * 1_000]).to(DataType::Date64);
+    let result = date_trunc(&array, TruncUnit::Week).unwrap();
+    let expected =
+        Int64Array::from_slice(&[timestamp((2021, 8, 16), (0, 0, 0)) * 1_000]).to(DataType::Date64);
+    assert_eq!(expected, result.as_ref());
+
+    assert!(date_trunc(&Int32Array::from_slice(&[1]), TruncUnit::Day).is_err());
+}
+
+#[test]
+fn date_trunc_fixed_offset() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+05:30".to_string()));
+    // 2021-08-19 03:00:00 UTC is 2021-08-19 08:30:00 local
+    let array =
+        Int64Array::from_slice(&[timestamp((2021, 8, 19), (3, 0, 0))]).to(data_type.clone());
+
+    let result = date_trunc(&array, TruncUnit::Day).unwrap();
+    let expected = timestamp((2021, 8, 18), (18, 30, 0));
+    assert_eq!(
+        Int64Array::from_slice(&[expected]).to(data_type.clone()),
+        result.as_ref()
+    );
+
+    let result = date_trunc(&array, TruncUnit::Hour).unwrap();
+    let expected = timestamp((2021, 8, 19), (2, 30, 0));
+    assert_eq!(
+        Int64Array::from_slice(&[expected]).to(data_type),
+        result.as_ref()
+    );
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn date_trunc_dst() {
+    // DST ends at 03:00 CEST (01:00 UTC) and 02:00-03:00 happens twice
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("Europe/Berlin".to_string()));
+    let array = Int64Array::from_slice(&[
+        timestamp((2021, 10, 31), (0, 30, 0)),
+        timestamp((2021, 10, 31), (1, 30, 0)),
+    ])
+    .to(data_type.clone());
+
+    let result = date_trunc(&array, TruncUnit::Hour).unwrap();
+    let expected = Int64Array::from_slice(&[
+        timestamp((2021, 10, 31), (0, 0, 0)),
+        timestamp((2021, 10, 31), (1, 0, 0)),
+    ])
+    .to(data_type.clone());
+    assert_eq!(expected, result.as_ref());
+
+    let result = date_trunc(&array, TruncUnit::Day).unwrap();
+    let midnight = timestamp((2021, 10, 30), (22, 0, 0));
+    let expected = Int64Array::from_slice(&[midnight, midnight]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+
+    // DST starts at midnight (03:00 UTC), so that the day starts at 01:00 local
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("America/Sao_Paulo".to_string()));
+    let array =
+        Int64Array::from_slice(&[timestamp((2018, 11, 4), (12, 0, 0))]).to(data_type.clone());
+    let result = date_trunc(&array, TruncUnit::Day).unwrap();
+    let expected = Int64Array::from_slice(&[timestamp((2018, 11, 4), (3, 0, 0))]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn quarter_and_day_of_year() {
+    let array =
+        Int32Array::from(&[Some(0), Some(59), Some(365 + 59), None, Some(-1)]).to(DataType::Date32);
+    assert_eq!(
+        quarter(&array).unwrap(),
+        UInt32Array::from(&[Some(1), Some(1), Some(1), None, Some(4)])
+    );
+    // 1972 is a leap year
+    let array = Int64Array::from_slice(&[
+        timestamp((1972, 3, 1), (0, 0, 0)),
+        timestamp((1972, 12, 31), (23, 0, 0)),
+        timestamp((1971, 7, 1), (0, 0, 0)),
+    ])
+    .to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(
+        quarter(&array).unwrap(),
+        UInt32Array::from_slice(&[1, 4, 3])
+    );
+    assert_eq!(
+        day_of_year(&array).unwrap(),
+        UInt32Array::from_slice(&[61, 366, 182])
+    );
+}
+
+#[test]
+fn day_of_week_first_day() {
+    // 1970-01-01 was a Thursday, 1970-01-04 a Sunday
+    let array = Int32Array::from(&[Some(0), Some(3), Some(4), None]).to(DataType::Date32);
+    assert_eq!(
+        day_of_week(&array, chrono::Weekday::Mon).unwrap(),
+        weekday(&array).unwrap()
+    );
+    assert_eq!(
+        day_of_week(&array, chrono::Weekday::Sun).unwrap(),
+        UInt32Array::from(&[Some(5), Some(1), Some(2), None])
+    );
+    assert_eq!(
+        day_of_week(&array, chrono::Weekday::Thu).unwrap(),
+        UInt32Array::from(&[Some(1), Some(4), Some(5), None])
+    );
+}
+
+#[test]
+fn epoch_seconds() {
+    let array = Int32Array::from(&[Some(1), Some(-1), None]).to(DataType::Date32);
+    assert_eq!(
+        epoch(&array).unwrap(),
+        Int64Array::from(&[Some(86_400), Some(-86_400), None])
+    );
+
+    let array = Int64Array::from_slice(&[1_999, -1]).to(DataType::Date64);
+    assert_eq!(epoch(&array).unwrap(), Int64Array::from_slice(&[1, -1]));
+
+    let array = Int64Array::from_slice(&[1_500_000_000, -1]).to(DataType::Timestamp(
+        TimeUnit::Nanosecond,
+        Some("+01:00".to_string()),
+    ));
+    assert_eq!(epoch(&array).unwrap(), Int64Array::from_slice(&[1, -1]));
+
+    let array = Int64Array::from_slice(&[7]).to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(epoch(&array).unwrap(), Int64Array::from_slice(&[7]));
+}
+
+#[test]
+fn convert_timezone_relabels() {
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string()));
+    let array = Int64Array::from(&[Some(1), None, Some(-1)]).to(data_type);
+
+    let result = convert_timezone(&array, "+05:30".to_string()).unwrap();
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+05:30".to_string()));
+    assert_eq!(result, array.clone().to(data_type));
+    // the values are not copied
+    assert_eq!(result.values().as_ptr(), array.values().as_ptr());
+
+    assert!(convert_timezone(&array, "invalid".to_string()).is_err());
+    assert!(convert_timezone(&Int64Array::from_slice(&[1]), "+01:00".to_string()).is_err());
+}
+
+#[test]
+fn convert_timezone_naive() {
+    let array = Int64Array::from(&[Some(timestamp((2021, 3, 1), (6, 0, 0))), None])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let result = convert_timezone(&array, "+05:30".to_string()).unwrap();
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+05:30".to_string()));
+    let expected =
+        Int64Array::from(&[Some(timestamp((2021, 3, 1), (0, 30, 0))), None]).to(data_type);
+    assert_eq!(result, expected);
+
+    assert!(convert_timezone(&array, "invalid".to_string()).is_err());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn convert_timezone_dst() {
+    let array = Int64Array::from_slice(&[
+        // DST ends: 02:30 happens in +02:00 and in +01:00
+        timestamp((2021, 10, 31), (2, 30, 0)),
+        // DST starts: 02:30 does not exist
+        timestamp((2021, 3, 28), (2, 30, 0)),
+        timestamp((2021, 7, 1), (12, 0, 0)),
+    ])
+    .to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let result = convert_timezone(&array, "Europe/Berlin".to_string()).unwrap();
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("Europe/Berlin".to_string()));
+    let expected = Int64Array::from(&[
+        Some(timestamp((2021, 10, 31), (0, 30, 0))),
+        None,
+        Some(timestamp((2021, 7, 1), (10, 0, 0))),
+    ])
+    .to(data_type);
+    assert_eq!(result, expected);
+
+    let result = convert_timezone(&result, "America/Sao_Paulo".to_string()).unwrap();
+    assert_eq!(result.values(), expected.values());
+}
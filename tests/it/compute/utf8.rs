@@ -369,3 +369,232 @@ fn consistency_upper() {
         }
     });
 }
+
+fn pad<O: Offset>() -> Result<()> {
+    let array = Utf8Array::<O>::from(&[Some("ab"), None, Some(""), Some("héllo"), Some("日本")]);
+
+    let result = lpad(&array, 4, "xy")?;
+    let expected = Utf8Array::<O>::from(&[
+        Some("xyab"),
+        None,
+        Some("xyxy"),
+        Some("héll"),
+        Some("xy日本"),
+    ]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rpad(&array, 3, "ö")?;
+    let expected =
+        Utf8Array::<O>::from(&[Some("abö"), None, Some("ööö"), Some("hél"), Some("日本ö")]);
+    assert_eq!(expected, result.as_ref());
+
+    // empty pad
+    let result = lpad(&array, 3, "")?;
+    let expected = Utf8Array::<O>::from(&[Some("ab"), None, Some(""), Some("hél"), Some("日本")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rpad(&array, 0, "a")?;
+    let expected = Utf8Array::<O>::from(&[Some(""), None, Some(""), Some(""), Some("")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn pad_utf8() -> Result<()> {
+    pad::<i32>()
+}
+
+#[test]
+fn pad_large_utf8() -> Result<()> {
+    pad::<i64>()
+}
+
+#[test]
+fn pad_sliced() -> Result<()> {
+    let array = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None, Some("c")]).slice(1, 3);
+    let result = lpad(&array, 2, "-")?;
+    let expected = Utf8Array::<i32>::from(&[Some("-b"), None, Some("-c")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn pad_errors() {
+    let array = Int32Array::from_slice(&[1]);
+    assert!(lpad(&array, 2, "0").is_err());
+    assert!(!can_pad(array.data_type()));
+}
+
+fn split_cases<O: Offset>() -> Result<()> {
+    let array =
+        Utf8Array::<O>::from(&[Some("a--b--c"), None, Some(""), Some("--"), Some("日--本")]);
+
+    let result = split(&array, "--", None)?;
+    let result = result.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let offsets = result
+        .offsets()
+        .iter()
+        .map(|x| x.to_usize())
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec![0, 3, 3, 4, 6, 8]);
+    assert_eq!(
+        result.validity(),
+        Some(&[true, false, true, true, true].into())
+    );
+    let expected = Utf8Array::<O>::from_slice(&["a", "b", "c", "", "", "", "日", "本"]);
+    assert_eq!(expected, result.values().as_ref());
+
+    let result = split(&array, "--", Some(1))?;
+    let result = result.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let expected = Utf8Array::<O>::from_slice(&["a", "b--c", "", "", "", "日", "本"]);
+    assert_eq!(expected, result.values().as_ref());
+
+    let result = split(&array, "--", Some(0))?;
+    let result = result.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let expected = Utf8Array::<O>::from_slice(&["a--b--c", "", "--", "日--本"]);
+    assert_eq!(expected, result.values().as_ref());
+    Ok(())
+}
+
+#[test]
+fn split_utf8() -> Result<()> {
+    split_cases::<i32>()
+}
+
+#[test]
+fn split_large_utf8() -> Result<()> {
+    split_cases::<i64>()
+}
+
+#[test]
+fn split_errors() {
+    let array = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(split(&array, "", None).is_err());
+    let array = Int32Array::from_slice(&[1]);
+    assert!(split(&array, ",", None).is_err());
+}
+
+fn repeat_cases<O: Offset>() -> Result<()> {
+    let array = Utf8Array::<O>::from(&[Some("ab"), None, Some(""), Some("é")]);
+
+    let result = repeat(&array, 2)?;
+    let expected = Utf8Array::<O>::from(&[Some("abab"), None, Some(""), Some("éé")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = repeat(&array, 0)?;
+    let expected = Utf8Array::<O>::from(&[Some(""), None, Some(""), Some("")]);
+    assert_eq!(expected, result.as_ref());
+
+    let times = Int64Array::from(&[Some(1), Some(3), Some(usize::MAX as i64), None]);
+    let result = repeat_by(&array, &times)?;
+    let expected = Utf8Array::<O>::from(&[Some("ab"), None, Some(""), None]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn repeat_utf8() -> Result<()> {
+    repeat_cases::<i32>()
+}
+
+#[test]
+fn repeat_large_utf8() -> Result<()> {
+    repeat_cases::<i64>()
+}
+
+#[test]
+fn repeat_errors() {
+    let array = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(repeat_by(&array, &Int64Array::from_slice(&[1, 2])).is_err());
+    assert!(repeat_by(&array, &Int64Array::from_slice(&[i64::MAX])).is_err());
+    let array = Int32Array::from_slice(&[1]);
+    assert!(repeat(&array, 1).is_err());
+}
+
+fn trim_cases<O: Offset>() -> Result<()> {
+    let array = Utf8Array::<O>::from(&[Some("xyaxyb yx"), None, Some("xx"), Some(""), Some("éaé")]);
+
+    let result = trim(&array, "xyé")?;
+    let expected = Utf8Array::<O>::from(&[Some("axyb "), None, Some(""), Some(""), Some("a")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = ltrim(&array, "xyé")?;
+    let expected = Utf8Array::<O>::from(&[Some("axyb yx"), None, Some(""), Some(""), Some("aé")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rtrim(&array, "xyé")?;
+    let expected = Utf8Array::<O>::from(&[Some("xyaxyb "), None, Some(""), Some(""), Some("éa")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = trim(&array, "")?;
+    assert_eq!(array, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn trim_utf8() -> Result<()> {
+    trim_cases::<i32>()
+}
+
+#[test]
+fn trim_large_utf8() -> Result<()> {
+    trim_cases::<i64>()
+}
+
+#[test]
+fn trim_errors() {
+    let array = Int32Array::from_slice(&[1]);
+    assert!(trim(&array, " ").is_err());
+}
+
+fn concat_ws_cases<O: Offset>() -> Result<()> {
+    let a = Utf8Array::<O>::from(&[Some("a"), None, None, Some("")]);
+    let b = Utf8Array::<O>::from(&[Some("b"), Some("c"), None, Some("d")]);
+
+    let args = [ConcatArg::Array(&a), ConcatArg::Array(&b)];
+    let result = concat_ws(&args, ", ", true)?;
+    let expected = Utf8Array::<O>::from(&[Some("a, b"), Some("c"), Some(""), Some(", d")]);
+    assert_eq!(expected, result.as_ref());
+
+    let args = [
+        ConcatArg::Scalar(Some("x")),
+        ConcatArg::Array(&a),
+        ConcatArg::Scalar(None),
+        ConcatArg::Array(&b),
+    ];
+    let result = concat_ws(&args, "", true)?;
+    let expected = Utf8Array::<O>::from(&[Some("xab"), Some("xc"), Some("x"), Some("xd")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = concat_ws(&args, "", false)?;
+    let expected = Utf8Array::<O>::from(&[None::<&str>, None, None, None]);
+    assert_eq!(expected, result.as_ref());
+
+    let args = [ConcatArg::Array(&a), ConcatArg::Array(&b)];
+    let result = concat_ws(&args, "-", false)?;
+    let expected = Utf8Array::<O>::from(&[Some("a-b"), None, None, Some("-d")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn concat_ws_utf8() -> Result<()> {
+    concat_ws_cases::<i32>()
+}
+
+#[test]
+fn concat_ws_large_utf8() -> Result<()> {
+    concat_ws_cases::<i64>()
+}
+
+#[test]
+fn concat_ws_errors() {
+    let a = Utf8Array::<i32>::from_slice(&["a"]);
+    let b = Utf8Array::<i32>::from_slice(&["a", "b"]);
+    let c = Utf8Array::<i64>::from_slice(&["a"]);
+    let d = Int32Array::from_slice(&[1]);
+    assert!(concat_ws(&[ConcatArg::Scalar(Some("a"))], "", true).is_err());
+    assert!(concat_ws(&[ConcatArg::Array(&a), ConcatArg::Array(&b)], "", true).is_err());
+    assert!(concat_ws(&[ConcatArg::Array(&a), ConcatArg::Array(&c)], "", true).is_err());
+    assert!(concat_ws(&[ConcatArg::Array(&d)], "", true).is_err());
+}
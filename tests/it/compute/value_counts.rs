@@ -0,0 +1,95 @@
+use arrow2::array::*;
+use arrow2::compute::value_counts::{can_value_counts, value_counts};
+use arrow2::datatypes::DataType;
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from(&[Some(2), None, Some(1), Some(2), None, Some(2)]);
+    let (values, counts) = value_counts(&array).unwrap();
+
+    let expected = Int32Array::from(&[Some(2), None, Some(1)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(counts, UInt64Array::from_slice(&[3, 2, 1]));
+}
+
+#[test]
+fn float() {
+    let array = Float64Array::from_slice(&[f64::NAN, 1.0, f64::NAN, 1.0, 2.0]);
+    let (values, counts) = value_counts(&array).unwrap();
+
+    let values = values.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(values.len(), 3);
+    assert!(values.value(0).is_nan());
+    assert_eq!(values.values().as_slice()[1..], [1.0, 2.0]);
+    assert_eq!(counts, UInt64Array::from_slice(&[2, 2, 1]));
+}
+
+#[test]
+fn binary() {
+    let array = BinaryArray::<i64>::from(&[Some(b"a".as_ref()), Some(b""), Some(b"a"), None]);
+    let (values, counts) = value_counts(&array).unwrap();
+
+    let expected = BinaryArray::<i64>::from(&[Some(b"a".as_ref()), Some(b""), None]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(counts, UInt64Array::from_slice(&[2, 1, 1]));
+}
+
+#[test]
+fn boolean() {
+    let array = BooleanArray::from(&[Some(true), Some(false), Some(true), None]);
+    let (values, counts) = value_counts(&array).unwrap();
+
+    let expected = BooleanArray::from(&[Some(true), Some(false), None]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(counts, UInt64Array::from_slice(&[2, 1, 1]));
+}
+
+#[test]
+fn dictionary() {
+    // "b" is repeated in the values and index 3 is a null value
+    let values = Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("b"), None]);
+    let keys = Int32Array::from(&[Some(1), Some(0), Some(2), None, Some(3), Some(0)]);
+    let array = DictionaryArray::<i32>::from_data(keys, std::sync::Arc::new(values));
+
+    let (values, counts) = value_counts(&array).unwrap();
+
+    assert_eq!(values.data_type(), array.data_type());
+    let values = values
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(values.keys(), &Int32Array::from(&[Some(1), Some(0), None]));
+    assert_eq!(counts, UInt64Array::from_slice(&[2, 2, 2]));
+}
+
+#[test]
+fn null() {
+    let array = NullArray::new(DataType::Null, 3);
+    let (values, counts) = value_counts(&array).unwrap();
+
+    assert_eq!(
+        values.as_ref(),
+        &NullArray::new(DataType::Null, 1) as &dyn Array
+    );
+    assert_eq!(counts, UInt64Array::from_slice(&[3]));
+}
+
+#[test]
+fn empty() {
+    let array = Utf8Array::<i32>::from_slice::<&str, _>(&[]);
+    let (values, counts) = value_counts(&array).unwrap();
+    assert_eq!(values.len(), 0);
+    assert_eq!(counts.len(), 0);
+}
+
+#[test]
+fn unsupported() {
+    let data_type = DataType::List(Box::new(arrow2::datatypes::Field::new(
+        "item",
+        DataType::Int32,
+        true,
+    )));
+    assert!(!can_value_counts(&data_type));
+    let array = new_empty_array(data_type);
+    assert!(value_counts(array.as_ref()).is_err());
+}
@@ -1,6 +1,10 @@
-use arrow2::array::{new_null_array, Int32Array};
+use arrow2::array::{
+    new_null_array, BooleanArray, Float64Array, Int32Array, Int64Array, PrimitiveArray, UInt8Array,
+    Utf8Array,
+};
 use arrow2::compute::window::*;
 use arrow2::datatypes::DataType;
+use arrow2::scalar::{BooleanScalar, PrimitiveScalar, Utf8Scalar};
 
 #[test]
 fn shift_pos() {
@@ -27,3 +31,253 @@ fn shift_max() {
 
     assert_eq!(expected.as_ref(), result.as_ref());
 }
+
+#[test]
+fn lead_primitive() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]).to(DataType::Date32);
+
+    let result = lead(&array, 1, None).unwrap();
+    let expected = Int32Array::from(&[None, Some(3), Some(4), None]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let default = PrimitiveScalar::new(DataType::Date32, Some(0i32));
+    let result = lead(&array, 2, Some(&default)).unwrap();
+    let expected = Int32Array::from(&[Some(3), Some(4), Some(0), Some(0)]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let result = lead(&array, 0, Some(&default)).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn lag_primitive() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+
+    let result = lag(&array, 1, None).unwrap();
+    let expected = Int32Array::from(&[None, Some(1), None, Some(3)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = lag(&array, 3, Some(&PrimitiveScalar::from(Some(-1i32)))).unwrap();
+    let expected = Int32Array::from(&[Some(-1), Some(-1), Some(-1), Some(1)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn lead_lag_large_offset() {
+    let array = Utf8Array::<i32>::from(&[Some("a"), None]);
+    let default = Utf8Scalar::<i32>::new(Some("z"));
+
+    let expected = Utf8Array::<i32>::from(&[Some("z"), Some("z")]);
+    assert_eq!(expected, lead(&array, 10, Some(&default)).unwrap().as_ref());
+    assert_eq!(expected, lag(&array, 10, Some(&default)).unwrap().as_ref());
+
+    let expected = new_null_array(DataType::Utf8, 2);
+    assert_eq!(expected.as_ref(), lead(&array, 3, None).unwrap().as_ref());
+}
+
+#[test]
+fn lead_lag_boolean() {
+    let array = BooleanArray::from(&[Some(true), Some(false), None]);
+    let default = BooleanScalar::new(Some(true));
+
+    let result = lead(&array, 1, Some(&default)).unwrap();
+    let expected = BooleanArray::from(&[Some(false), None, Some(true)]);
+    assert_eq!(expected, result.as_ref());
+
+    // a null default is equivalent to no default
+    let result = lag(&array, 1, Some(&BooleanScalar::new(None))).unwrap();
+    let expected = BooleanArray::from(&[None, Some(true), Some(false)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn lead_lag_wrong_default() {
+    let array = Int32Array::from(&[Some(1), None]);
+    let default = PrimitiveScalar::from(Some(1i64));
+    assert!(lead(&array, 1, Some(&default)).is_err());
+    assert!(lag(&array, 1, Some(&default)).is_err());
+}
+
+#[test]
+fn rolling_sum_basics() {
+    let array = Int64Array::from(&[Some(1), None, Some(3), Some(4), None, None, Some(7)]);
+
+    let result = rolling_sum(&array, 3, 1).unwrap();
+    let expected = Int64Array::from(&[
+        Some(1),
+        Some(1),
+        Some(4),
+        Some(7),
+        Some(7),
+        Some(4),
+        Some(7),
+    ]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rolling_sum(&array, 3, 2).unwrap();
+    let expected = Int64Array::from(&[None, None, Some(4), Some(7), Some(7), None, None]);
+    assert_eq!(expected, result.as_ref());
+
+    // a window of 1 is the identity
+    let result = rolling_sum(&array, 1, 1).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+/// naive, O(N * window) implementation of the rolling aggregates
+fn naive<F: Fn(&[f64]) -> f64>(
+    values: &[Option<f64>],
+    window: usize,
+    min_periods: usize,
+    f: F,
+) -> Float64Array {
+    (0..values.len())
+        .map(|i| {
+            let start = (i + 1).saturating_sub(window);
+            let valid = values[start..=i]
+                .iter()
+                .flatten()
+                .copied()
+                .collect::<Vec<_>>();
+            (valid.len() >= min_periods.max(1)).then(|| f(&valid))
+        })
+        .collect()
+}
+
+#[test]
+fn rolling_consistent_with_naive() {
+    let values = (0..50)
+        .map(|i: i32| (i % 7 != 3 && i % 11 != 0).then(|| ((i * 37) % 23 - 11) as f64))
+        .collect::<Vec<_>>();
+    let array = Float64Array::from(&values);
+
+    for window in [1, 2, 3, 5, 8, 60] {
+        for min_periods in [0, 1, 2] {
+            if min_periods > window {
+                continue;
+            }
+            let result = rolling_mean(&array, window, min_periods).unwrap();
+            let expected = naive(&values, window, min_periods, |x| {
+                x.iter().sum::<f64>() / x.len() as f64
+            });
+            let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+            assert_eq!(result.validity(), expected.validity());
+            result
+                .iter()
+                .zip(expected.iter())
+                .for_each(|(a, b)| match (a, b) {
+                    (Some(a), Some(b)) => assert!((a - b).abs() < 1e-9),
+                    (a, b) => assert_eq!(a, b),
+                });
+
+            let result = rolling_min(&array, window, min_periods).unwrap();
+            let expected = naive(&values, window, min_periods, |x| {
+                x.iter().copied().fold(f64::INFINITY, f64::min)
+            });
+            assert_eq!(expected, result.as_ref());
+
+            let result = rolling_max(&array, window, min_periods).unwrap();
+            let expected = naive(&values, window, min_periods, |x| {
+                x.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+            });
+            assert_eq!(expected, result.as_ref());
+        }
+    }
+}
+
+#[test]
+fn rolling_min_max_nan() {
+    let array = Float64Array::from_slice(&[1.0, f64::NAN, 0.0, 2.0]);
+
+    let result = rolling_min(&array, 2, 1).unwrap();
+    let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(result.values().as_slice(), &[1.0, 1.0, 0.0, 0.0]);
+
+    let result = rolling_max(&array, 2, 1).unwrap();
+    let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(result.value(0), 1.0);
+    assert!(result.value(1).is_nan());
+    assert!(result.value(2).is_nan());
+    assert_eq!(result.value(3), 2.0);
+}
+
+#[test]
+fn rolling_sum_mean_non_finite() {
+    let array = Float64Array::from_slice(&[1.0, f64::INFINITY, 1.0, 1.0, f64::NAN, 1.0, 1.0]);
+
+    let result = rolling_sum(&array, 2, 1).unwrap();
+    let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(
+        &result.values().as_slice()[..4],
+        &[1.0, f64::INFINITY, f64::INFINITY, 2.0]
+    );
+    assert!(result.value(4).is_nan());
+    assert!(result.value(5).is_nan());
+    assert_eq!(result.value(6), 2.0);
+
+    let result = rolling_mean(&array, 2, 1).unwrap();
+    let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(result.value(3), 1.0);
+    assert!(result.value(5).is_nan());
+    assert_eq!(result.value(6), 1.0);
+
+    let array = Float64Array::from_slice(&[f64::INFINITY, f64::NEG_INFINITY, 1.0]);
+    let result = rolling_sum(&array, 2, 1).unwrap();
+    let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(result.value(0), f64::INFINITY);
+    assert!(result.value(1).is_nan());
+    assert_eq!(result.value(2), f64::NEG_INFINITY);
+}
+
+#[test]
+fn rolling_sum_mean_precision() {
+    let array = Float64Array::from_slice(&[1e17, 1.0, 1.0, 1.0]);
+
+    let result = rolling_sum(&array, 1, 1).unwrap();
+    assert_eq!(array, result.as_ref());
+
+    let result = rolling_sum(&array, 2, 1).unwrap();
+    let expected = Float64Array::from_slice(&[1e17, 1e17, 2.0, 2.0]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rolling_mean(&array, 1, 1).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn rolling_sum_overflow() {
+    let array = UInt8Array::from_slice(&[200, 100, 1]);
+    let result = rolling_sum(&array, 2, 1).unwrap();
+    // wraps around
+    let expected = UInt8Array::from_slice(&[200, 44, 101]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = Int64Array::from_slice(&[i64::MAX, i64::MAX, 1]);
+    let result = rolling_mean(&array, 2, 1).unwrap();
+    let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(result.value(1), i64::MAX as f64);
+}
+
+#[test]
+fn rolling_unsigned() {
+    let array = UInt8Array::from(&[Some(1), Some(2), None, Some(4)]);
+    let result = rolling_max(&array, 2, 1).unwrap();
+    let expected = UInt8Array::from(&[Some(1), Some(2), Some(2), Some(4)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rolling_sum(&array, 2, 1).unwrap();
+    let expected = UInt8Array::from(&[Some(1), Some(3), Some(2), Some(4)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn rolling_errors() {
+    let array = Int32Array::from_slice(&[1, 2]);
+    assert!(rolling_sum(&array, 0, 0).is_err());
+    assert!(rolling_sum(&array, 2, 3).is_err());
+
+    let array = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(rolling_max(&array, 1, 1).is_err());
+
+    let array = PrimitiveArray::<i128>::from_slice(&[1]).to(DataType::Decimal(5, 2));
+    assert!(rolling_mean(&array, 1, 1).is_err());
+}
@@ -81,6 +81,14 @@ fn u32() -> Result<()> {
     test_round_trip(data)
 }
 
+#[test]
+fn decimal256() -> Result<()> {
+    use arrow2::types::i256;
+    let data = PrimitiveArray::from([Some(i256::MAX), None, Some(i256::from(-2))])
+        .to(DataType::Decimal256(76, 2));
+    test_round_trip(data)
+}
+
 #[test]
 fn timestamp_tz() -> Result<()> {
     let data = Int64Array::from(&vec![Some(2), None, None]).to(DataType::Timestamp(
@@ -128,6 +136,24 @@ fn large_binary() -> Result<()> {
     test_round_trip(data)
 }
 
+#[test]
+fn utf8_view() -> Result<()> {
+    let data = vec![Some("a"), None, Some("a string longer than 12 bytes")];
+    let array = Utf8ViewArray::from(&data);
+    test_round_trip(array)
+}
+
+#[test]
+fn binary_view() -> Result<()> {
+    let data = vec![
+        Some(b"a string longer than 12 bytes".as_ref()),
+        None,
+        Some(b"a"),
+    ];
+    let array = BinaryViewArray::from(&data);
+    test_round_trip(array)
+}
+
 #[test]
 fn list() -> Result<()> {
     let data = vec![
@@ -204,6 +230,14 @@ fn dict() -> Result<()> {
     test_round_trip(array)
 }
 
+#[test]
+fn run_end_encoded() -> Result<()> {
+    let values = Arc::new(Utf8Array::<i32>::from(&[Some("a"), None, Some("b")]));
+    let array = RunEndEncodedArray::<i32>::from_data(vec![2, 3, 5].into(), values);
+
+    test_round_trip(array)
+}
+
 #[test]
 fn schema() -> Result<()> {
     let field = Field::new(
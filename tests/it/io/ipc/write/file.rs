@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::{Field, Schema};
+use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::{read_file_metadata, FileReader};
 use arrow2::io::ipc::{write::*, IpcField};
@@ -349,6 +349,16 @@ fn write_sliced_utf8() -> Result<()> {
     round_trip(columns, schema, None, Some(Compression::ZSTD))
 }
 
+#[test]
+fn write_decimal256() -> Result<()> {
+    use arrow2::types::i256;
+    let array = PrimitiveArray::from([Some(i256::MIN), None, Some(i256::from(1))])
+        .to(DataType::Decimal256(76, 10));
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![Arc::new(array) as Arc<dyn Array>])?;
+    round_trip(columns, schema, None, None)
+}
+
 #[test]
 fn write_sliced_list() -> Result<()> {
     let data = vec![
@@ -365,3 +375,108 @@ fn write_sliced_list() -> Result<()> {
     let columns = Chunk::try_new(vec![array])?;
     round_trip(columns, schema, None, None)
 }
+
+#[test]
+fn write_run_end_encoded() -> Result<()> {
+    let mut array = MutableRunEndEncodedArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(vec![Some(1), Some(1), None, Some(2), Some(2), Some(2)])?;
+    let array = array.into_arc();
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    round_trip(columns, schema, None, None)
+}
+
+#[test]
+fn write_sliced_run_end_encoded() -> Result<()> {
+    let mut array = MutableRunEndEncodedArray::<i16, MutableUtf8Array<i32>>::new();
+    array.try_extend(vec![
+        Some("a"),
+        Some("a"),
+        None,
+        Some("b"),
+        Some("b"),
+        Some("c"),
+    ])?;
+    let array: Arc<dyn Array> = array.into_arc().slice(1, 4).into();
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    round_trip(columns, schema, None, None)
+}
+
+#[test]
+fn write_run_end_encoded_dictionary() -> Result<()> {
+    let mut array =
+        MutableRunEndEncodedArray::<i64, MutableDictionaryArray<u8, MutableUtf8Array<i32>>>::new();
+    array.try_extend(vec![Some("a"), Some("a"), None, Some("b")])?;
+    let array = array.into_arc();
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    round_trip(columns, schema, None, None)
+}
+
+#[test]
+fn write_utf8_view() -> Result<()> {
+    let array = Arc::new(Utf8ViewArray::from([
+        Some("a"),
+        None,
+        Some("a long string that is not inlined"),
+        Some("another string that is too long to be inlined"),
+    ])) as Arc<dyn Array>;
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    round_trip(columns, schema, None, None)
+}
+
+#[test]
+fn write_sliced_binary_view() -> Result<()> {
+    let array = BinaryViewArray::from([
+        Some(b"a long binary value that is not inlined".as_ref()),
+        None,
+        Some(b"b".as_ref()),
+        Some(b"another long binary value that is not inlined".as_ref()),
+    ]);
+    let array = Arc::new(array.slice(1, 3)) as Arc<dyn Array>;
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    round_trip(columns, schema, None, None)
+}
+
+#[test]
+fn write_compressed_utf8_view() -> Result<()> {
+    let array = Arc::new(Utf8ViewArray::from([
+        Some("a"),
+        None,
+        Some("a long string that is not inlined"),
+    ])) as Arc<dyn Array>;
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    round_trip(columns, schema, None, Some(Compression::ZSTD))
+}
+
+#[test]
+fn write_nested_utf8_view() -> Result<()> {
+    let values = Arc::new(Utf8ViewArray::from([
+        Some("a long string that is not inlined"),
+        None,
+        Some("b"),
+    ])) as Arc<dyn Array>;
+    let fields = vec![Field::new("a", values.data_type().clone(), true)];
+    let array = Arc::new(StructArray::new(
+        DataType::Struct(fields),
+        vec![values.clone()],
+        None,
+    )) as Arc<dyn Array>;
+
+    let schema = Schema::from(vec![
+        Field::new("a", array.data_type().clone(), true),
+        Field::new("b", values.data_type().clone(), true),
+    ]);
+    let columns = Chunk::try_new(vec![array, values])?;
+    round_trip(columns, schema, None, None)
+}
@@ -1,9 +1,9 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
-use arrow2::array::Array;
+use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::Schema;
+use arrow2::datatypes::{Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::read_stream_metadata;
 use arrow2::io::ipc::read::StreamReader;
@@ -30,6 +30,25 @@ fn write_(
     result
 }
 
+fn round_trip(columns: Chunk<Arc<dyn Array>>, schema: Schema) {
+    let expected_batches = vec![columns];
+
+    let result = write_(&schema, None, &expected_batches);
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_stream_metadata(&mut reader).unwrap();
+    let reader = StreamReader::new(reader, metadata);
+
+    assert_eq!(reader.metadata().schema, schema);
+
+    let batches = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(batches, expected_batches);
+}
+
 fn test_file(version: &str, file_name: &str) {
     let (schema, ipc_fields, batches) = read_arrow_stream(version, file_name);
 
@@ -147,3 +166,24 @@ fn write_100_custom_metadata() {
 fn write_100_decimal() {
     test_file("1.0.0-littleendian", "generated_decimal");
 }
+
+#[test]
+fn write_run_end_encoded() {
+    let mut array = MutableRunEndEncodedArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![Some("a"), Some("a"), None, Some("b"), Some("b")])
+        .unwrap();
+    let array: Arc<dyn Array> = array.into_arc().slice(1, 4).into();
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    round_trip(Chunk::new(vec![array]), schema);
+}
+
+#[test]
+fn write_utf8_view() {
+    let array = Utf8ViewArray::from([Some("a"), None, Some("a long string that is not inlined")]);
+    let array: Arc<dyn Array> = Arc::new(array.slice(1, 2));
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    round_trip(Chunk::new(vec![array]), schema);
+}
@@ -0,0 +1,165 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::parquet::read::bloom_filter::*;
+use arrow2::io::parquet::read::read_metadata;
+
+use super::integration_write;
+
+#[test]
+fn contains_physical_values() -> Result<()> {
+    let mut bitset = vec![0; 64];
+    insert(&mut bitset, hash_native(-1i32));
+    insert(&mut bitset, hash_native(10i64));
+    insert(&mut bitset, hash_byte("a"));
+
+    // u32 is written as parquet's INT32
+    let result = contains(&bitset, &UInt32Array::from_slice(&[u32::MAX, 1]))?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, false]));
+
+    let result = contains(&bitset, &Int64Array::from(&[Some(10), None, Some(11)]))?;
+    assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(false)]));
+
+    let values = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"]));
+    let array = DictionaryArray::<u8>::from_data(UInt8Array::from_slice(&[1, 0]), values);
+    let result = contains(&bitset, &array)?;
+    assert_eq!(result, BooleanArray::from_slice(&[false, true]));
+
+    assert!(contains(&bitset, &BooleanArray::from_slice(&[true])).is_err());
+    assert!(contains(&bitset[..40], &Int64Array::from_slice(&[1])).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_without_bloom_filter() -> Result<()> {
+    let array = Int64Array::from_slice(&[1, 2, 3]);
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, false)]);
+    let chunk = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+    let data = integration_write(&schema, &[chunk])?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let column = &metadata.row_groups[0].columns()[0];
+    assert_eq!(read(column, &mut reader)?, None);
+    Ok(())
+}
+
+#[test]
+fn write_bloom_filter() -> Result<()> {
+    use std::collections::HashMap;
+
+    use arrow2::io::parquet::read::{infer_schema, FileReader};
+    use arrow2::io::parquet::write::*;
+
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, false),
+        Field::new("c", DataType::Boolean, false),
+    ]);
+    let chunks = vec![
+        Chunk::new(vec![
+            Arc::new(Int64Array::from(&[Some(1), None, Some(3)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(&["a", "b", "c"])),
+            Arc::new(BooleanArray::from_slice(&[true, false, true])),
+        ]),
+        Chunk::new(vec![
+            Arc::new(Int64Array::from_slice(&[10, 20])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(&["d", "e"])),
+            Arc::new(BooleanArray::from_slice(&[false, false])),
+        ]),
+    ];
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+    };
+
+    // booleans have no bloom filters and are written without
+    let bloom_filter = ColumnOptions {
+        bloom_filter: Some(BloomFilterOptions::from_ndv_fpp(100, 0.01)),
+        ..Default::default()
+    };
+    let columns = HashMap::from([
+        (vec!["a".to_string()], bloom_filter),
+        (vec!["c".to_string()], bloom_filter),
+    ]);
+    let mut row_groups = RowGroupIterator::try_new(
+        chunks.clone().into_iter().map(Ok),
+        &schema,
+        options,
+        vec![Encoding::Plain; 3],
+    )?
+    .with_column_options(columns)?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    while let Some(group) = row_groups.next_with_bloom_filters() {
+        let (group, bloom_filters) = group?;
+        writer.write_with_bloom_filters(group, bloom_filters)?;
+    }
+    let size = writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+    assert_eq!(size, data.len() as u64);
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    assert_eq!(infer_schema(&metadata)?, schema);
+    for (group, chunk) in metadata.row_groups.iter().zip(&chunks) {
+        let bitset = read(&group.columns()[0], &mut reader)?.unwrap();
+        // the size of the bitset is rounded up to a power of two
+        assert_eq!(bitset.len(), 128);
+        let result = contains(&bitset, chunk.arrays()[0].as_ref())?;
+        assert_eq!(result.iter().flatten().filter(|x| !x).count(), 0);
+        assert_eq!(read(&group.columns()[1], &mut reader)?, None);
+        assert_eq!(read(&group.columns()[2], &mut reader)?, None);
+    }
+    let result = contains(
+        &read(&metadata.row_groups[0].columns()[0], &mut reader)?.unwrap(),
+        &Int64Array::from_slice(&[10, 20]),
+    )?;
+    assert_eq!(result, BooleanArray::from_slice(&[false, false]));
+
+    let reader = FileReader::try_new(reader, None, None, None, None)?;
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, chunks);
+    Ok(())
+}
+
+#[test]
+fn bloom_filters_are_not_dropped() -> Result<()> {
+    use std::collections::HashMap;
+
+    use arrow2::io::parquet::write::*;
+
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, false)]);
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from_slice(&[1, 2, 3])) as Arc<dyn Array>
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+    };
+    let columns = HashMap::from([(
+        vec!["a".to_string()],
+        ColumnOptions {
+            bloom_filter: Some(BloomFilterOptions::from_ndv_fpp(100, 0.01)),
+            ..Default::default()
+        },
+    )]);
+    let mut row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk)].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain],
+    )?
+    .with_column_options(columns)?;
+
+    // iterating would write the row groups without their bloom filters
+    assert!(row_groups.next().unwrap().is_err());
+    Ok(())
+}
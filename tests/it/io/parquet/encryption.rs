@@ -0,0 +1,442 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::*;
+use arrow2::error::{ArrowError, Result};
+use arrow2::io::parquet::encryption::*;
+use arrow2::io::parquet::read::*;
+use arrow2::io::parquet::write::*;
+
+fn schema() -> Schema {
+    Schema::from(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, false),
+        Field::new(
+            "c",
+            DataType::Dictionary(IntegerType::UInt32, Box::new(DataType::Utf8), false),
+            false,
+        ),
+    ])
+}
+
+fn chunks() -> Vec<Chunk<Arc<dyn Array>>> {
+    let dictionary = |keys: &[u32]| {
+        let values = Arc::new(Utf8Array::<i32>::from_slice(&["x", "y"]));
+        Arc::new(DictionaryArray::<u32>::from_data(
+            UInt32Array::from_slice(keys),
+            values,
+        )) as Arc<dyn Array>
+    };
+    vec![
+        Chunk::new(vec![
+            Arc::new(Int64Array::from(&[Some(1), None, Some(3)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(&[
+                "secret-alpha",
+                "secret-beta",
+                "secret-gamma",
+            ])),
+            dictionary(&[0, 1, 0]),
+        ]),
+        Chunk::new(vec![
+            Arc::new(Int64Array::from_slice(&[4, 5])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(&[
+                "secret-delta",
+                "secret-epsilon",
+            ])),
+            dictionary(&[1, 1]),
+        ]),
+    ]
+}
+
+fn write(properties: FileEncryptionProperties) -> Result<Vec<u8>> {
+    let schema = schema();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        chunks().into_iter().map(Ok),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::Plain, Encoding::RleDictionary],
+    )?;
+
+    let mut writer =
+        FileWriter::try_new_with_encryption(Cursor::new(vec![]), schema, options, properties)?;
+    writer.start()?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    let size = writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+    assert_eq!(size, data.len() as u64);
+    Ok(data)
+}
+
+fn read(data: &[u8], properties: &FileDecryptionProperties) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let reader = Cursor::new(data);
+    FileReader::try_new_with_decryption(reader, properties, None, None, None, None)?.collect()
+}
+
+fn contains(data: &[u8], value: &[u8]) -> bool {
+    data.windows(value.len()).any(|window| window == value)
+}
+
+fn decryption(footer_key: &[u8]) -> FileDecryptionProperties {
+    FileDecryptionProperties {
+        footer_key: Some(footer_key.to_vec()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn footer_key() -> Result<()> {
+    let key = vec![1; 16];
+    let data = write(FileEncryptionProperties::new(EncryptionKey::new(
+        key.clone(),
+    )))?;
+    assert_eq!(&data[..4], b"PARE");
+    assert_eq!(&data[data.len() - 4..], b"PARE");
+    assert!(!contains(&data, b"secret"));
+
+    let error = read_metadata(&mut Cursor::new(&data)).unwrap_err();
+    assert!(matches!(error, ArrowError::InvalidArgumentError(_)));
+
+    assert_eq!(read(&data, &decryption(&key))?, chunks());
+
+    let (metadata, _) = read_metadata_with_decryption(&mut Cursor::new(&data), &decryption(&key))?;
+    assert_eq!(infer_schema(&metadata)?, schema());
+    assert_eq!(metadata.num_rows, 5);
+    // other readers need the offset of the dictionary page to decrypt it
+    let column = &metadata.row_groups[0].columns()[2];
+    assert!(column.dictionary_page_offset().is_some());
+    // the indexes of encrypted columns can't be read
+    let error = read_columns_indexes(
+        &mut Cursor::new(&data),
+        metadata.row_groups[0].columns(),
+        &schema().fields,
+    )
+    .unwrap_err();
+    assert!(matches!(error, ArrowError::NotYetImplemented(_)));
+    Ok(())
+}
+
+#[test]
+fn wrong_key() -> Result<()> {
+    let data = write(FileEncryptionProperties::new(EncryptionKey::new(vec![
+        1;
+        32
+    ])))?;
+
+    assert!(read(&data, &decryption(&[2; 32])).is_err());
+    assert!(read(&data, &FileDecryptionProperties::default()).is_err());
+    Ok(())
+}
+
+#[test]
+fn modified_page() -> Result<()> {
+    let key = vec![1; 24];
+    let mut data = write(FileEncryptionProperties::new(EncryptionKey::new(
+        key.clone(),
+    )))?;
+
+    // a byte of the first page of the first column chunk
+    data[100] ^= 1;
+    assert!(read(&data, &decryption(&key)).is_err());
+    Ok(())
+}
+
+struct Keys(HashMap<Vec<u8>, Vec<u8>>);
+
+impl KeyRetriever for Keys {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>> {
+        self.0
+            .get(key_metadata)
+            .cloned()
+            .ok_or_else(|| ArrowError::InvalidArgumentError("The key does not exist".to_string()))
+    }
+}
+
+#[test]
+fn column_keys() -> Result<()> {
+    let mut properties = FileEncryptionProperties::new(EncryptionKey {
+        key: vec![1; 16],
+        key_metadata: Some(b"footer".to_vec()),
+    });
+    properties.column_keys.insert(
+        "a".to_string(),
+        EncryptionKey {
+            key: vec![2; 16],
+            key_metadata: Some(b"a".to_vec()),
+        },
+    );
+    properties.plaintext_columns.push("b".to_string());
+    let data = write(properties)?;
+    // `b` is not encrypted
+    assert!(contains(&data, b"secret"));
+
+    let keys = Keys(HashMap::from([
+        (b"footer".to_vec(), vec![1; 16]),
+        (b"a".to_vec(), vec![2; 16]),
+    ]));
+    let properties = FileDecryptionProperties::with_key_retriever(Arc::new(keys));
+    assert_eq!(read(&data, &properties)?, chunks());
+
+    // keys can also be declared
+    let mut properties = decryption(&[1; 16]);
+    properties.column_keys.insert("a".to_string(), vec![2; 16]);
+    assert_eq!(read(&data, &properties)?, chunks());
+
+    // the key of `a` is missing
+    assert!(read(&data, &decryption(&[1; 16])).is_err());
+    Ok(())
+}
+
+#[test]
+fn invalid_properties() {
+    let mut properties = FileEncryptionProperties::new(EncryptionKey::new(vec![1; 16]));
+    properties
+        .column_keys
+        .insert("d".to_string(), EncryptionKey::new(vec![2; 16]));
+    assert!(write(properties).is_err());
+
+    assert!(write(FileEncryptionProperties::new(EncryptionKey::new(vec![
+        1;
+        10
+    ])))
+    .is_err());
+}
+
+#[test]
+fn plaintext_footer() -> Result<()> {
+    let mut properties = FileEncryptionProperties::new(EncryptionKey::new(vec![1; 16]));
+    properties.plaintext_footer = true;
+    properties.plaintext_columns.push("b".to_string());
+    let mut data = write(properties)?;
+    assert_eq!(&data[..4], b"PAR1");
+    assert_eq!(&data[data.len() - 4..], b"PAR1");
+
+    // readers without keys can read the schema and the plaintext columns
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(infer_schema(&metadata)?, schema());
+    let reader = FileReader::try_new(Cursor::new(&data), Some(&[1]), None, None, None)?;
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    let expected = chunks()
+        .into_iter()
+        .map(|chunk| Chunk::new(vec![chunk.arrays()[1].clone()]))
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+
+    assert_eq!(read(&data, &decryption(&[1; 16]))?, chunks());
+    // the signature of the footer is verified
+    assert!(read(&data, &decryption(&[2; 16])).is_err());
+    let signature = data.len() - 8 - 28;
+    data[signature] ^= 1;
+    assert!(read(&data, &decryption(&[1; 16])).is_err());
+    Ok(())
+}
+
+#[test]
+fn aad_prefix() -> Result<()> {
+    let mut properties = FileEncryptionProperties::new(EncryptionKey::new(vec![1; 16]));
+    properties.aad_prefix = Some(b"file.parquet".to_vec());
+    properties.store_aad_prefix = false;
+    let data = write(properties)?;
+
+    // the prefix must be supplied
+    assert!(read(&data, &decryption(&[1; 16])).is_err());
+    let mut properties = decryption(&[1; 16]);
+    properties.aad_prefix = Some(b"other.parquet".to_vec());
+    assert!(read(&data, &properties).is_err());
+    properties.aad_prefix = Some(b"file.parquet".to_vec());
+    assert_eq!(read(&data, &properties)?, chunks());
+    Ok(())
+}
+
+#[test]
+fn row_filter() -> Result<()> {
+    let key = vec![1; 16];
+    let data = write(FileEncryptionProperties::new(EncryptionKey::new(
+        key.clone(),
+    )))?;
+
+    let mut reader = FileReader::try_new_with_decryption(
+        Cursor::new(data),
+        &decryption(&key),
+        None,
+        None,
+        None,
+        None,
+    )?;
+    reader.set_row_filter(RowFilter::new(vec![0], |chunk| {
+        let array = chunk.arrays()[0]
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        Ok(array.iter().map(|x| x.map(|x| *x > 2)).collect())
+    }));
+    let result = reader
+        .map(|chunk| chunk.map(|chunk| chunk.arrays()[..2].to_vec()))
+        .collect::<Result<Vec<_>>>()?;
+
+    let expected = vec![
+        vec![
+            Arc::new(Int64Array::from_slice(&[3])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(&["secret-gamma"])),
+        ],
+        chunks()[1].arrays()[..2].to_vec(),
+    ];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn decrypt_column_chunks() -> Result<()> {
+    let key = vec![1; 16];
+    let data = write(FileEncryptionProperties::new(EncryptionKey::new(
+        key.clone(),
+    )))?;
+
+    let mut reader = Cursor::new(data);
+    let (metadata, decryptor) = read_metadata_with_decryption(&mut reader, &decryption(&key))?;
+    let schema = infer_schema(&metadata)?;
+    let row_group = &metadata.row_groups[0];
+    let columns = read_columns(&mut reader, row_group.columns(), "b")?
+        .into_iter()
+        .map(|(column, chunk)| Ok((column, decryptor.decrypt_column_chunk(column, chunk)?)))
+        .collect::<Result<Vec<_>>>()?;
+    let mut arrays = to_deserializer(
+        columns,
+        schema.fields[1].clone(),
+        row_group.num_rows(),
+        None,
+    )?;
+    assert_eq!(
+        arrays.next().unwrap()?.as_ref(),
+        chunks()[0].arrays()[1].as_ref()
+    );
+    Ok(())
+}
+
+// files written by parquet-mr and parquet-cpp, from the parquet-testing repository
+const FOOTER_KEY: &[u8] = b"0123456789012345";
+const COLUMN_1_KEY: &[u8] = b"1234567890123450";
+const COLUMN_2_KEY: &[u8] = b"1234567890123451";
+
+fn interop_decryption(aad_prefix: Option<&[u8]>) -> FileDecryptionProperties {
+    let mut properties = decryption(FOOTER_KEY);
+    properties
+        .column_keys
+        .insert("double_field".to_string(), COLUMN_1_KEY.to_vec());
+    properties
+        .column_keys
+        .insert("float_field".to_string(), COLUMN_2_KEY.to_vec());
+    properties.aad_prefix = aad_prefix.map(|prefix| prefix.to_vec());
+    properties
+}
+
+fn read_interop(file: &str, properties: &FileDecryptionProperties) -> Result<()> {
+    let path = format!("testing/parquet-testing/data/{}", file);
+    let reader = std::fs::File::open(path)?;
+    let reader = FileReader::try_new_with_decryption(reader, properties, None, None, None, None)?;
+    assert_eq!(reader.metadata().num_rows, 50);
+    assert_eq!(reader.schema().fields.len(), 8);
+
+    let mut i = 0;
+    for chunk in reader {
+        let chunk = chunk?;
+        let arrays = chunk.arrays();
+        let booleans = arrays[0].as_any().downcast_ref::<BooleanArray>().unwrap();
+        let times = arrays[1].as_any().downcast_ref::<Int32Array>().unwrap();
+        let lists = arrays[2].as_any().downcast_ref::<ListArray<i32>>().unwrap();
+        let timestamps = arrays[3].as_any().downcast_ref::<Int64Array>().unwrap();
+        let floats = arrays[4].as_any().downcast_ref::<Float32Array>().unwrap();
+        let doubles = arrays[5].as_any().downcast_ref::<Float64Array>().unwrap();
+        let binaries = arrays[6]
+            .as_any()
+            .downcast_ref::<BinaryArray<i32>>()
+            .unwrap();
+        let fixed = arrays[7]
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        for row in 0..chunk.len() {
+            assert_eq!(booleans.value(row), i % 2 == 0);
+            assert_eq!(times.value(row), i as i32);
+            let list = lists.value(row);
+            let list = list.as_any().downcast_ref::<Int64Array>().unwrap();
+            let expected = [(i * 2) as i64, (i * 2 + 1) as i64].map(|x| x * 1000000000000);
+            assert_eq!(list.values().as_slice(), expected);
+            assert!(timestamps.is_valid(row));
+            assert_eq!(floats.value(row), i as f32 * 1.1);
+            assert_eq!(doubles.value(row), i as f64 * 1.1111111);
+            assert_eq!(binaries.is_valid(row), i % 2 == 0);
+            if binaries.is_valid(row) {
+                assert_eq!(&binaries.value(row)[..7], b"parquet");
+            }
+            assert_eq!(fixed.value(row), [i as u8; 10]);
+            i += 1;
+        }
+    }
+    assert_eq!(i, 50);
+    Ok(())
+}
+
+#[test]
+fn interop_uniform_encryption() -> Result<()> {
+    let file = "uniform_encryption.parquet.encrypted";
+    read_interop(file, &decryption(FOOTER_KEY))?;
+    assert!(read_interop(file, &FileDecryptionProperties::default()).is_err());
+    Ok(())
+}
+
+#[test]
+fn interop_columns_and_footer() -> Result<()> {
+    let file = "encrypt_columns_and_footer.parquet.encrypted";
+    read_interop(file, &interop_decryption(None))?;
+    assert!(read_interop(file, &decryption(FOOTER_KEY)).is_err());
+    Ok(())
+}
+
+#[test]
+fn interop_plaintext_footer() -> Result<()> {
+    let file = "encrypt_columns_plaintext_footer.parquet.encrypted";
+    read_interop(file, &interop_decryption(None))?;
+
+    // the plaintext columns can be read without keys
+    let path = format!("testing/parquet-testing/data/{}", file);
+    let metadata = read_metadata(&mut std::fs::File::open(&path)?)?;
+    assert_eq!(infer_schema(&metadata)?.fields.len(), 8);
+    let reader = FileReader::try_new(std::fs::File::open(&path)?, Some(&[0]), None, None, None)?;
+    let rows = reader
+        .map(|chunk| chunk.map(|chunk| chunk.len()))
+        .sum::<Result<usize>>()?;
+    assert_eq!(rows, 50);
+    Ok(())
+}
+
+#[test]
+fn interop_aad_prefix() -> Result<()> {
+    read_interop(
+        "encrypt_columns_and_footer_aad.parquet.encrypted",
+        &interop_decryption(None),
+    )?;
+
+    let file = "encrypt_columns_and_footer_disable_aad_storage.parquet.encrypted";
+    read_interop(file, &interop_decryption(Some(b"tester")))?;
+    assert!(read_interop(file, &interop_decryption(None)).is_err());
+    assert!(read_interop(file, &interop_decryption(Some(b"wrong_aad_prefix"))).is_err());
+    Ok(())
+}
+
+#[test]
+fn interop_ctr() -> Result<()> {
+    read_interop(
+        "encrypt_columns_and_footer_ctr.parquet.encrypted",
+        &interop_decryption(None),
+    )
+}
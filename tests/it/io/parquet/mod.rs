@@ -6,10 +6,16 @@ use arrow2::{
     io::parquet::read::statistics::*, io::parquet::read::*, io::parquet::write::*,
 };
 
+#[cfg(feature = "io_parquet_bloom_filter")]
+mod bloom_filter;
+#[cfg(feature = "io_parquet_encryption")]
+mod encryption;
 #[cfg(feature = "io_json_integration")]
 mod integration;
 mod read;
 mod read_indexes;
+mod read_ranged;
+mod row_filter;
 mod write;
 mod write_async;
 
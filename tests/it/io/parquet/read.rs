@@ -573,3 +573,552 @@ fn invalid_utf8() {
         error
     );
 }
+
+fn limit_data() -> Result<Vec<u8>> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+    let chunks = [0i64, 4]
+        .iter()
+        .map(|start| {
+            let array = Int64Array::from_vec((*start..*start + 4).collect());
+            Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])
+        })
+        .collect::<Vec<_>>();
+    integration_write(&schema, &chunks)
+}
+
+#[test]
+fn limit() -> Result<()> {
+    let data = limit_data()?;
+
+    let reader = FileReader::try_new(Cursor::new(&data), None, None, Some(3), None)?;
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Int64Array::from_slice([0, 1, 2]) as &dyn Array
+    );
+
+    // the limit ends in the middle of the second row group
+    let reader = FileReader::try_new(Cursor::new(&data), None, Some(3), Some(6), None)?;
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    let lengths = chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![3, 1, 2]);
+    assert_eq!(
+        chunks[2].arrays()[0].as_ref(),
+        &Int64Array::from_slice([4, 5]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn read_columns_many_limit() -> Result<()> {
+    let mut reader = Cursor::new(limit_data()?);
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+
+    let mut columns = read_columns_many(
+        &mut reader,
+        &metadata.row_groups[0],
+        schema.fields,
+        Some(2),
+        Some(3),
+    )?;
+    let arrays = columns.pop().unwrap().collect::<Result<Vec<_>>>()?;
+    let lengths = arrays.iter().map(|array| array.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![2, 1]);
+    Ok(())
+}
+
+#[test]
+fn row_group_tasks() -> Result<()> {
+    let data = limit_data()?;
+    let expected = FileReader::try_new(Cursor::new(&data), None, Some(3), None, None)?
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+    let tasks = metadata
+        .row_groups
+        .iter()
+        .map(|row_group| read_row_group_task(&mut reader, row_group, schema.fields.clone(), None))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].num_rows(), 4);
+
+    let handles = tasks
+        .into_iter()
+        .map(|task| {
+            std::thread::spawn(move || task.deserialize(Some(3))?.collect::<Result<Vec<_>>>())
+        })
+        .collect::<Vec<_>>();
+    let chunks = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    assert_eq!(chunks, expected);
+    Ok(())
+}
+
+#[test]
+fn column_tasks() -> Result<()> {
+    let mut reader = Cursor::new(limit_data()?);
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+
+    let task = read_row_group_task(&mut reader, &metadata.row_groups[1], schema.fields, Some(3))?;
+    let mut columns = task.into_columns();
+    assert_eq!(columns.len(), 1);
+    let column = columns.pop().unwrap();
+    assert_eq!(column.field().name, "a");
+
+    let arrays = column.deserialize(None)?.collect::<Result<Vec<_>>>()?;
+    assert_eq!(arrays.len(), 1);
+    assert_eq!(
+        arrays[0].as_ref(),
+        &Int64Array::from_slice([4, 5, 6]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn statistics_many() -> Result<()> {
+    let mut reader = Cursor::new(limit_data()?);
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+
+    let mut statistics = statistics::deserialize_many(&schema.fields, &metadata.row_groups)?;
+    assert_eq!(statistics.len(), 1);
+    let statistics = statistics.pop().unwrap();
+    assert_eq!(
+        statistics.min_value.as_ref(),
+        &Int64Array::from_slice([0, 4]) as &dyn Array
+    );
+    assert_eq!(
+        statistics.max_value.as_ref(),
+        &Int64Array::from_slice([3, 7]) as &dyn Array
+    );
+    assert_eq!(
+        statistics.null_count,
+        statistics::Count::Single(UInt64Array::from_slice([0, 0]))
+    );
+    assert_eq!(
+        statistics::num_rows(&metadata.row_groups),
+        UInt64Array::from_slice([4, 4])
+    );
+    Ok(())
+}
+
+// rewrites the (uncompressed) parquet file `data` with its pages compressed by the legacy LZ4
+// codec, in the Hadoop framing (`hadoop`) or as raw LZ4 blocks
+#[cfg(feature = "io_parquet_compression")]
+fn to_legacy_lz4(data: &[u8], hadoop: bool) -> Vec<u8> {
+    use parquet_format_async_temp::thrift::protocol::{
+        TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+    };
+    use parquet_format_async_temp::{CompressionCodec, FileMetaData, PageHeader, PageType};
+
+    let length = data.len();
+    let metadata_length = u32::from_le_bytes(data[length - 8..length - 4].try_into().unwrap());
+    let mut protocol = TCompactInputProtocol::new(&data[length - 8 - metadata_length as usize..]);
+    let mut metadata = FileMetaData::read_from_in_protocol(&mut protocol).unwrap();
+
+    let mut result = b"PAR1".to_vec();
+    for row_group in metadata.row_groups.iter_mut() {
+        for column in row_group.columns.iter_mut() {
+            let meta = column.meta_data.as_mut().unwrap();
+            let start = meta.dictionary_page_offset.unwrap_or(meta.data_page_offset) as usize;
+            let mut reader = Cursor::new(&data[start..start + meta.total_compressed_size as usize]);
+
+            let chunk_start = result.len() as i64;
+            while (reader.position() as usize) < reader.get_ref().len() {
+                let mut header =
+                    PageHeader::read_from_in_protocol(&mut TCompactInputProtocol::new(&mut reader))
+                        .unwrap();
+                if header.type_ == PageType::DICTIONARY_PAGE {
+                    meta.dictionary_page_offset = Some(result.len() as i64);
+                } else if meta.data_page_offset < chunk_start {
+                    meta.data_page_offset = result.len() as i64;
+                }
+                let position = reader.position() as usize;
+                let page =
+                    &reader.get_ref()[position..position + header.compressed_page_size as usize];
+                reader.set_position((position + page.len()) as u64);
+
+                let levels = header.data_page_header_v2.as_ref().map_or(0, |v2| {
+                    (v2.definition_levels_byte_length + v2.repetition_levels_byte_length) as usize
+                });
+                let block = lz4::block::compress(&page[levels..], None, false).unwrap();
+                let mut compressed = page[..levels].to_vec();
+                if hadoop {
+                    compressed.extend_from_slice(&((page.len() - levels) as u32).to_be_bytes());
+                    compressed.extend_from_slice(&(block.len() as u32).to_be_bytes());
+                }
+                compressed.extend_from_slice(&block);
+
+                header.compressed_page_size = compressed.len() as i32;
+                if let Some(v2) = header.data_page_header_v2.as_mut() {
+                    v2.is_compressed = Some(true);
+                }
+                let mut protocol = TCompactOutputProtocol::new(&mut result);
+                header.write_to_out_protocol(&mut protocol).unwrap();
+                protocol.flush().unwrap();
+                result.extend_from_slice(&compressed);
+            }
+            meta.codec = CompressionCodec::LZ4;
+            meta.total_compressed_size = result.len() as i64 - chunk_start;
+            column.file_offset = chunk_start;
+            column.column_index_offset = None;
+            column.column_index_length = None;
+            column.offset_index_offset = None;
+            column.offset_index_length = None;
+        }
+    }
+
+    let mut protocol = TCompactOutputProtocol::new(&mut result);
+    let metadata_length = metadata.write_to_out_protocol(&mut protocol).unwrap();
+    protocol.flush().unwrap();
+    result.extend_from_slice(&(metadata_length as u32).to_le_bytes());
+    result.extend_from_slice(b"PAR1");
+    result
+}
+
+#[cfg(feature = "io_parquet_compression")]
+#[test]
+fn legacy_lz4() -> Result<()> {
+    use arrow2::io::parquet::write::*;
+
+    let values = Utf8Array::<i32>::from(&[Some("a"), None, Some("bb"), Some("a")]);
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary.try_extend(values.iter())?;
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from(&[Some(1), None, Some(3), Some(4)])) as Arc<dyn Array>,
+        Arc::new(dictionary) as Arc<dyn Array>,
+    ]);
+    let schema = Schema::from(vec![
+        Field::new("a", chunk.arrays()[0].data_type().clone(), true),
+        Field::new("b", chunk.arrays()[1].data_type().clone(), true),
+    ]);
+
+    for version in [Version::V1, Version::V2] {
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionOptions::Uncompressed,
+            version,
+        };
+        let row_groups = RowGroupIterator::try_new(
+            vec![Ok(chunk.clone())].into_iter(),
+            &schema,
+            options,
+            vec![Encoding::Plain, Encoding::RleDictionary],
+        )?;
+        let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+        writer.start()?;
+        for group in row_groups {
+            writer.write(group?)?;
+        }
+        writer.end(None)?;
+        let data = writer.into_inner().into_inner();
+
+        for hadoop in [true, false] {
+            let data = to_legacy_lz4(&data, hadoop);
+            let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+            assert_eq!(
+                reader.metadata().row_groups[0].columns()[0].compression(),
+                parquet2::compression::Compression::Lz4
+            );
+            let chunks = reader.collect::<Result<Vec<_>>>()?;
+            assert_eq!(chunks, vec![chunk.clone()]);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn nested_projection() -> Result<()> {
+    let city = Field::new("city", DataType::Utf8, true);
+    let address_type = DataType::Struct(vec![
+        city.clone(),
+        Field::new("zip", DataType::Int32, false),
+    ]);
+    let user_type = DataType::Struct(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("address", address_type, false),
+    ]);
+    let schema = Schema::from(vec![
+        Field::new("user", user_type, false),
+        Field::new("name", DataType::Utf8, false),
+    ]);
+
+    // the structs are required, so that every leaf is written as a non-nested column
+    let cities = Utf8Array::<i32>::from([Some("Lisbon"), None, Some("Porto")]);
+    let leaves: [&dyn Array; 4] = [
+        &Int64Array::from_slice([10, 20, 30]),
+        &cities,
+        &Int32Array::from_slice([1, 2, 3]),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]),
+    ];
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let columns = leaves
+        .iter()
+        .zip(parquet_schema.columns())
+        .map(|(array, column)| {
+            let type_ = ParquetType::PrimitiveType(column.descriptor.primitive_type.clone());
+            let page = array_to_page(*array, type_, options, Encoding::Plain)?;
+            let pages = DynIter::new(std::iter::once(Ok(page)));
+            let pages =
+                Compressor::new(pages, options.compression, vec![]).map_err(ArrowError::from);
+            Ok(DynStreamingIterator::new(pages))
+        })
+        .collect::<Vec<_>>();
+
+    let mut writer = FileWriter::try_new(vec![], schema, options)?;
+    writer.start()?;
+    writer.write(DynIter::new(columns.into_iter()))?;
+    writer.end(None)?;
+    let mut reader = Cursor::new(writer.into_inner());
+
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+    let fields = project_fields(&schema.fields, &[&["user", "address", "city"]])?;
+
+    let address_type = DataType::Struct(vec![city]);
+    let user_type = DataType::Struct(vec![Field::new("address", address_type.clone(), false)]);
+    assert_eq!(fields, vec![Field::new("user", user_type.clone(), false)]);
+
+    let columns = read_columns_many(&mut reader, &metadata.row_groups[0], fields, None, None)?;
+    let chunks = RowGroupDeserializer::new(columns, 3, None).collect::<Result<Vec<_>>>()?;
+
+    let address = StructArray::from_data(address_type, vec![Arc::new(cities)], None);
+    let expected = StructArray::from_data(user_type, vec![Arc::new(address)], None);
+    assert_eq!(chunks[0].arrays()[0].as_ref(), &expected as &dyn Array);
+
+    assert!(project_fields(&schema.fields, &[&["name", "a"]]).is_err());
+    assert!(project_fields(&schema.fields, &[&["user", "a"]]).is_err());
+    Ok(())
+}
+
+#[test]
+fn encrypted_footer() {
+    // the magic of parquet modular encryption, an (encrypted) footer and its length
+    let mut data = b"PARE".to_vec();
+    data.extend_from_slice(&[0; 16]);
+    data.extend_from_slice(&16u32.to_le_bytes());
+    data.extend_from_slice(b"PARE");
+
+    let error = read_metadata(&mut Cursor::new(data)).unwrap_err();
+    assert!(
+        matches!(error, ArrowError::InvalidArgumentError(_)),
+        "unexpected error: {}",
+        error
+    );
+}
+
+// a parquet file with a single column of schema `message` and a single (plain) page of `buffer`,
+// i.e. its repetition levels, definition levels and values
+fn single_page_file(message: &str, buffer: Vec<u8>, num_values: usize, num_rows: usize) -> Vec<u8> {
+    use parquet2::compression::CompressionOptions;
+    use parquet2::encoding::Encoding;
+    use parquet2::metadata::SchemaDescriptor;
+    use parquet2::page::{DataPage, DataPageHeader, DataPageHeaderV1, EncodedPage};
+    use parquet2::write::{Compressor, DynIter, DynStreamingIterator, FileWriter, Version};
+
+    let schema = SchemaDescriptor::try_from_message(message).unwrap();
+    let header = DataPageHeaderV1 {
+        num_values: num_values as i32,
+        encoding: Encoding::Plain.into(),
+        definition_level_encoding: Encoding::Rle.into(),
+        repetition_level_encoding: Encoding::Rle.into(),
+        statistics: None,
+    };
+    let page = DataPage::new(
+        DataPageHeader::V1(header),
+        buffer,
+        None,
+        schema.columns()[0].descriptor.clone(),
+        Some(num_rows),
+    );
+    let pages = DynIter::new(std::iter::once(Ok(EncodedPage::Data(page))));
+    let pages = Compressor::new(pages, CompressionOptions::Uncompressed, vec![]);
+    let columns = std::iter::once(Ok(DynStreamingIterator::new(pages)));
+
+    let options = parquet2::write::WriteOptions {
+        write_statistics: false,
+        version: Version::V1,
+    };
+    let mut writer = FileWriter::new(vec![], schema, options, None);
+    writer.start().unwrap();
+    writer.write(DynIter::new(columns)).unwrap();
+    writer.end(None).unwrap();
+    writer.into_inner()
+}
+
+// a parquet file with a required INT96 column "a" of `values` (nanoseconds of the day, julian day)
+fn int96_file(values: &[(u64, u32)]) -> Vec<u8> {
+    let buffer = values
+        .iter()
+        .flat_map(|(nanos, day)| {
+            let mut value = nanos.to_le_bytes().to_vec();
+            value.extend_from_slice(&day.to_le_bytes());
+            value
+        })
+        .collect::<Vec<_>>();
+    single_page_file(
+        "message schema { REQUIRED INT96 a; }",
+        buffer,
+        values.len(),
+        values.len(),
+    )
+}
+
+#[test]
+fn int96_timestamps() -> Result<()> {
+    // the julian day of the unix epoch
+    let epoch = 2_440_588;
+    let data = int96_file(&[
+        (1_500_000, epoch),
+        (0, epoch + 1),
+        // 3000-01-01, beyond the range of timestamps in nanoseconds
+        (0, epoch + 376_200),
+        // 1.5 milliseconds before the epoch
+        (86_400_000_000_000 - 1_500_000, epoch - 1),
+    ]);
+    let mut reader = Cursor::new(data.clone());
+    let metadata = read_metadata(&mut reader)?;
+
+    let schema = infer_schema(&metadata)?;
+    assert_eq!(
+        schema.fields[0].data_type(),
+        &DataType::Timestamp(TimeUnit::Nanosecond, None)
+    );
+
+    let options = SchemaInferenceOptions {
+        int96_coerce_to_timeunit: TimeUnit::Millisecond,
+    };
+    let schema = infer_schema_with_options(&metadata, &Some(options))?;
+    assert_eq!(
+        schema.fields[0].data_type(),
+        &DataType::Timestamp(TimeUnit::Millisecond, None)
+    );
+    let columns = read_columns_many(
+        &mut reader,
+        &metadata.row_groups[0],
+        schema.fields,
+        None,
+        None,
+    )?;
+    let chunks = RowGroupDeserializer::new(columns, 4, None).collect::<Result<Vec<_>>>()?;
+    // values before the epoch round towards negative infinity
+    let expected = Int64Array::from_slice([1, 86_400_000, 376_200 * 86_400_000, -2])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    assert_eq!(chunks[0].arrays()[0].as_ref(), &expected as &dyn Array);
+
+    // in nanoseconds, the last value errors by default...
+    let mut reader = FileReader::try_new(reader, None, None, None, None)?;
+    assert!(matches!(
+        reader.next(),
+        Some(Err(ArrowError::InvalidArgumentError(_)))
+    ));
+
+    // ...but can saturate
+    let mut reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    reader.set_int96_overflow(Int96Overflow::Saturate);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    let expected = Int64Array::from_slice([1_500_000, 86_400_000_000_000, i64::MAX, -1_500_000])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(chunks[0].arrays()[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn legacy_two_level_list() -> Result<()> {
+    // the list `[[1, 2], [], None, [3]]` as written by e.g. parquet-mr's avro writer
+    let message = "
+    message schema {
+      OPTIONAL GROUP a (LIST) {
+        REPEATED INT32 array;
+      }
+    }";
+    // the repetition levels `[0, 1, 0, 0, 0]`, bit-packed with a bit width of 1
+    let mut buffer = vec![2, 0, 0, 0, 0b11, 0b0000_0010];
+    // the definition levels `[2, 2, 1, 0, 2]`, bit-packed with a bit width of 2
+    buffer.extend_from_slice(&[3, 0, 0, 0, 0b11, 0b0001_1010, 0b0000_0010]);
+    buffer.extend([1i32, 2, 3].iter().flat_map(|x| x.to_le_bytes()));
+    let data = single_page_file(message, buffer, 5, 4);
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+    let data_type = DataType::List(Box::new(Field::new("array", DataType::Int32, false)));
+    assert_eq!(
+        schema.fields,
+        vec![Field::new("a", data_type.clone(), true)]
+    );
+
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new_from(
+        Default::default(),
+        data_type,
+        0,
+    );
+    expected.try_extend(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+        None,
+        Some(vec![Some(3)]),
+    ])?;
+    let expected: ListArray<i32> = expected.into();
+
+    let chunks =
+        FileReader::try_new(reader.clone(), None, None, None, None)?.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks[0].arrays()[0].as_ref(), &expected as &dyn Array);
+
+    // the elements can also be read as nullable
+    let data_type = DataType::List(Box::new(Field::new("array", DataType::Int32, true)));
+    let fields = vec![Field::new("a", data_type.clone(), true)];
+    let columns = read_columns_many(&mut reader, &metadata.row_groups[0], fields, None, None)?;
+    let chunks = RowGroupDeserializer::new(columns, 4, None).collect::<Result<Vec<_>>>()?;
+    let array = chunks[0].arrays()[0].as_ref();
+    assert_eq!(array.data_type(), &data_type);
+    assert_eq!(array.validity(), expected.validity());
+    let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(array.offsets(), expected.offsets());
+    assert_eq!(array.values().as_ref(), expected.values().as_ref());
+    Ok(())
+}
+
+#[test]
+fn repeated_field() -> Result<()> {
+    // the (required) list `[[1, 2], [], [3]]`, whose elements are the values of a repeated field
+    let message = "message schema { REPEATED INT32 a; }";
+    // the repetition levels `[0, 1, 0, 0]` and definition levels `[1, 1, 0, 1]`
+    let mut buffer = vec![2, 0, 0, 0, 0b11, 0b0000_0010];
+    buffer.extend_from_slice(&[2, 0, 0, 0, 0b11, 0b0000_1011]);
+    buffer.extend([1i32, 2, 3].iter().flat_map(|x| x.to_le_bytes()));
+    let data = single_page_file(message, buffer, 4, 3);
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let data_type = DataType::List(Box::new(Field::new("a", DataType::Int32, false)));
+    assert_eq!(
+        reader.schema().fields,
+        vec![Field::new("a", data_type.clone(), false)]
+    );
+
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    let expected = ListArray::<i32>::from_data(
+        data_type,
+        vec![0, 2, 2, 3].into(),
+        Arc::new(Int32Array::from_slice([1, 2, 3])),
+        None,
+    );
+    assert_eq!(chunks[0].arrays()[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
+use arrow2::chunk::Chunk;
 use arrow2::error::ArrowError;
 use arrow2::{array::*, datatypes::*, error::Result, io::parquet::read::*, io::parquet::write::*};
 use parquet2::indexes::{compute_rows, select_pages};
@@ -63,11 +64,10 @@ fn pages(
     Ok((pages1, pages2, schema))
 }
 
-/// Tests reading pages while skipping indexes
-fn read_with_indexes(
+/// Writes a file with a single row group of two columns with `pages1` and `pages2`
+fn write(
     (pages1, pages2, schema): (Vec<EncodedPage>, Vec<EncodedPage>, Schema),
-    expected: Arc<dyn Array>,
-) -> Result<()> {
+) -> Result<Vec<u8>> {
     let options = WriteOptions {
         write_statistics: true,
         compression: CompressionOptions::Uncompressed,
@@ -89,9 +89,15 @@ fn read_with_indexes(
     writer.start()?;
     writer.write(row_group)?;
     writer.end(None)?;
-    let data = writer.into_inner();
+    Ok(writer.into_inner())
+}
 
-    let mut reader = Cursor::new(data);
+/// Tests reading pages while skipping indexes
+fn read_with_indexes(
+    pages: (Vec<EncodedPage>, Vec<EncodedPage>, Schema),
+    expected: Arc<dyn Array>,
+) -> Result<()> {
+    let mut reader = Cursor::new(write(pages)?);
 
     let metadata = read_metadata(&mut reader)?;
 
@@ -213,3 +219,124 @@ fn indexed_dict() -> Result<()> {
 
     read_with_indexes(pages(&[&array], Encoding::RleDictionary)?, expected)
 }
+
+#[test]
+fn read_columns_many_pruned() -> Result<()> {
+    let array21 = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+    let array22 = Utf8Array::<i32>::from([None, Some("e"), Some("f")]);
+    let mut reader = Cursor::new(write(pages(&[&array21, &array22], Encoding::Plain)?)?);
+
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+    let row_group = &metadata.row_groups[0];
+
+    // the pages of the first column are [1, 2, 3, 4], [5] and [6]
+    let indexes = read_columns_indexes(&mut reader, row_group.columns(), &schema.fields)?;
+    let lower = Int64Array::from_slice([5]);
+    let selected = select_pages_in_range(&indexes[0], Some(&lower), None)?;
+    assert_eq!(selected, vec![false, true, true]);
+    let upper = Int64Array::from_slice([5]);
+    let selected = select_pages_in_range(&indexes[0], Some(&lower), Some(&upper))?;
+    assert_eq!(selected, vec![false, true, false]);
+
+    let locations = read_pages_locations(&mut reader, row_group.columns())?;
+    let rows = compute_rows(&selected, &locations[0], row_group.num_rows())?;
+
+    let columns =
+        read_columns_many_indexed(&mut reader, row_group, schema.fields.clone(), None, &rows)?;
+    let chunks = RowGroupDeserializer::new(columns, 1, None).collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Int64Array::from_slice([5]) as &dyn Array
+    );
+    assert_eq!(
+        chunks[0].arrays()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["e"]) as &dyn Array
+    );
+    Ok(())
+}
+
+/// Returns the lists `[[1, 2], None, [3]]` and `[[4], [5, 6], []]`, one per page
+fn lists() -> (ListArray<i32>, ListArray<i32>) {
+    let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let list21 = ListArray::<i32>::from_data(
+        data_type.clone(),
+        vec![0, 2, 2, 3].into(),
+        Arc::new(Int32Array::from_slice([1, 2, 3])),
+        Some([true, false, true].into()),
+    );
+    let list22 = ListArray::<i32>::from_data(
+        data_type,
+        vec![0, 1, 3, 3].into(),
+        Arc::new(Int32Array::from_slice([4, 5, 6])),
+        None,
+    );
+    (list21, list22)
+}
+
+fn read_page_filtered(
+    data: Vec<u8>,
+    page_filter: PageIndexFilter,
+) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let mut reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    reader.set_page_filter(page_filter);
+    reader.collect()
+}
+
+#[test]
+fn page_filter() -> Result<()> {
+    let (list21, list22) = lists();
+    let data = write(pages(&[&list21, &list22], Encoding::Plain)?)?;
+
+    // the pages of the first column are [1, 2, 3, 4], [5] and [6]
+    let value = Arc::new(Int64Array::from_slice([5])) as Arc<dyn Array>;
+    let page_filter = PageIndexFilter::new(0, Some(value.clone()), Some(value));
+    let chunks = read_page_filtered(data, page_filter)?;
+
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Int64Array::from_slice([5]) as &dyn Array
+    );
+    // the whole second page of the list is decoded, but only its second row is returned
+    let expected = list22.slice(1, 1);
+    assert_eq!(chunks[0].arrays()[1].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn page_filter_nested() -> Result<()> {
+    let (list21, list22) = lists();
+    let data = write(pages(&[&list21, &list22], Encoding::Plain)?)?;
+
+    // the values of the pages of the list are within [1, 3] and [4, 6]
+    let lower = Arc::new(Int32Array::from_slice([5])) as Arc<dyn Array>;
+    let page_filter = PageIndexFilter::new(1, Some(lower), None);
+    let chunks = read_page_filtered(data, page_filter)?;
+
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Int64Array::from_slice([4, 5, 6]) as &dyn Array
+    );
+    assert_eq!(chunks[0].arrays()[1].as_ref(), &list22 as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn page_filter_with_row_filter() -> Result<()> {
+    let data = write(pages(
+        &[&Int32Array::from_slice([1, 2, 3, 4, 5, 6])],
+        Encoding::Plain,
+    )?)?;
+
+    let mut reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    reader.set_page_filter(PageIndexFilter::new(0, None, None));
+    reader.set_row_filter(RowFilter::new(vec![0], |chunk| {
+        Ok(BooleanArray::from_slice(vec![true; chunk.len()]))
+    }));
+    assert!(reader.next().unwrap().is_err());
+    Ok(())
+}
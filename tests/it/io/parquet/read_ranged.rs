@@ -0,0 +1,148 @@
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::parquet::read::ranged::*;
+use arrow2::io::parquet::read::{infer_schema, RowGroupDeserializer};
+use futures::future::BoxFuture;
+use futures::TryStreamExt;
+
+use super::integration_write;
+
+// an in-memory file that records the ranges requested from it
+struct Recorder {
+    data: Vec<u8>,
+    requests: Mutex<Vec<Range<u64>>>,
+}
+
+impl RangeRead for Recorder {
+    fn get_range(&self, range: Range<u64>) -> BoxFuture<'_, std::io::Result<Vec<u8>>> {
+        self.requests.lock().unwrap().push(range.clone());
+        let result = self.data[range.start as usize..range.end as usize].to_vec();
+        Box::pin(async move { Ok(result) })
+    }
+}
+
+fn data() -> Result<(Schema, Vec<Chunk<Arc<dyn Array>>>, Vec<u8>)> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Boolean, true),
+    ]);
+    let chunks = (0..3)
+        .map(|i| {
+            Chunk::new(vec![
+                Arc::new(Int64Array::from(&[Some(i), None, Some(i + 1)])) as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("bb"), None])),
+                Arc::new(BooleanArray::from(&[Some(true), None, Some(false)])),
+            ])
+        })
+        .collect::<Vec<_>>();
+    let data = integration_write(&schema, &chunks)?;
+    Ok((schema, chunks, data))
+}
+
+fn recorder(data: Vec<u8>) -> Recorder {
+    Recorder {
+        data,
+        requests: Mutex::new(vec![]),
+    }
+}
+
+#[tokio::test]
+async fn read_coalesced() -> Result<()> {
+    let (schema, chunks, data) = data()?;
+    let file_size = data.len() as u64;
+    let reader = recorder(data);
+
+    let metadata = read_metadata_ranged(&reader, file_size).await?;
+    assert_eq!(reader.requests.lock().unwrap().len(), 1);
+    assert_eq!(infer_schema(&metadata)?, schema);
+
+    // the column chunks "a" and "c" of a row group are read in a single request
+    let fields = vec![schema.fields[0].clone(), schema.fields[2].clone()];
+    let options = RangedReadOptions::default();
+    let row_group = &metadata.row_groups[1];
+    let columns =
+        read_columns_many_ranged(&reader, row_group, fields, None, None, &options).await?;
+    assert_eq!(reader.requests.lock().unwrap().len(), 2);
+
+    let result = RowGroupDeserializer::new(columns, row_group.num_rows(), None)
+        .collect::<Result<Vec<_>>>()?;
+    let expected = Chunk::new(vec![
+        chunks[1].arrays()[0].clone(),
+        chunks[1].arrays()[2].clone(),
+    ]);
+    assert_eq!(result, vec![expected]);
+
+    // without gaps, every column chunk is read in its own request
+    let options = RangedReadOptions {
+        max_gap: 0,
+        ..Default::default()
+    };
+    let fields = vec![schema.fields[0].clone(), schema.fields[2].clone()];
+    read_columns_many_ranged(&reader, row_group, fields, None, None, &options).await?;
+    assert_eq!(reader.requests.lock().unwrap().len(), 4);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_tasks() -> Result<()> {
+    let (schema, chunks, data) = data()?;
+    let file_size = data.len() as u64;
+    let reader = recorder(data);
+    let metadata = read_metadata_ranged(&reader, file_size).await?;
+
+    // 7 rows: all rows of the first two row groups and one of the last
+    let tasks = read_row_group_tasks_ranged(
+        &reader,
+        &metadata.row_groups,
+        schema.fields.clone(),
+        Some(7),
+        RangedReadOptions::default(),
+    )
+    .try_collect::<Vec<_>>()
+    .await?;
+    // one request for the metadata and one per row group
+    assert_eq!(reader.requests.lock().unwrap().len(), 4);
+
+    let result = tasks
+        .into_iter()
+        .map(|task| task.deserialize(None)?.collect::<Result<Vec<_>>>())
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    let last = Chunk::new(
+        chunks[2]
+            .arrays()
+            .iter()
+            .map(|x| x.slice(0, 1).into())
+            .collect(),
+    );
+    assert_eq!(result, vec![chunks[0].clone(), chunks[1].clone(), last]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_ranges() -> Result<()> {
+    let reader = recorder((0..100).collect());
+    let options = RangedReadOptions {
+        max_gap: 10,
+        max_request_size: 50,
+        prefetch: 1,
+    };
+
+    let ranges = [60..70, 0..10, 15..20, 75..80, 5..12, 30..90];
+    let result = get_ranges_coalesced(&reader, &ranges, &options).await?;
+    let expected = ranges
+        .iter()
+        .map(|range| (range.start as u8..range.end as u8).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+    assert_eq!(reader.requests.lock().unwrap().as_slice(), &[0..20, 30..90]);
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::parquet::read::{FileReader, RowFilter};
+
+use super::integration_write;
+
+fn greater_than(value: i64) -> RowFilter {
+    RowFilter::new(vec![1], move |chunk| {
+        let array = chunk.arrays()[0]
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        Ok(array.iter().map(|x| x.map(|x| *x > value)).collect())
+    })
+}
+
+fn read(
+    data: &[u8],
+    chunk_size: Option<usize>,
+    row_filter: RowFilter,
+) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let mut reader = FileReader::try_new(Cursor::new(data), None, chunk_size, None, None)?;
+    reader.set_row_filter(row_filter);
+    reader.collect()
+}
+
+fn data() -> Result<Vec<u8>> {
+    let list = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 1, 1, 3, 4].into(),
+        Arc::new(Int32Array::from_slice([1, 2, 3, 4])),
+        Some([true, false, true, true].into()),
+    );
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Utf8, true),
+        Field::new("b", DataType::Int64, true),
+        Field::new("c", list.data_type().clone(), true),
+    ]);
+    let chunk1 = Chunk::new(vec![
+        Arc::new(Utf8Array::<i32>::from([
+            Some("a"),
+            None,
+            Some("c"),
+            Some("d"),
+        ])) as Arc<dyn Array>,
+        Arc::new(Int64Array::from([Some(1), Some(5), None, Some(3)])),
+        Arc::new(list.clone()),
+    ]);
+    let chunk2 = Chunk::new(vec![
+        Arc::new(Utf8Array::<i32>::from_slice(&["e", "f", "g", "h"])) as Arc<dyn Array>,
+        Arc::new(Int64Array::from_slice(&[0, 1, 2, 0])),
+        Arc::new(list),
+    ]);
+    integration_write(&schema, &[chunk1, chunk2])
+}
+
+#[test]
+fn late_materialization() -> Result<()> {
+    let chunks = read(&data()?, None, greater_than(2))?;
+
+    // the second row group has no selected rows
+    assert_eq!(chunks.len(), 1);
+    let arrays = chunks[0].arrays();
+    assert_eq!(
+        arrays[0].as_ref(),
+        &Utf8Array::<i32>::from([None, Some("d")]) as &dyn Array
+    );
+    assert_eq!(
+        arrays[1].as_ref(),
+        &Int64Array::from_slice([5, 3]) as &dyn Array
+    );
+    let expected = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 0, 1].into(),
+        Arc::new(Int32Array::from_slice([4])),
+        Some([false, true].into()),
+    );
+    assert_eq!(arrays[2].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn chunk_size() -> Result<()> {
+    let chunks = read(&data()?, Some(2), greater_than(0))?;
+
+    let lengths = chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![2, 1, 2]);
+    assert_eq!(
+        chunks[2].arrays()[0].as_ref(),
+        &Utf8Array::<i32>::from_slice(["f", "g"]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_projection() -> Result<()> {
+    let row_filter = RowFilter::new(vec![3], |chunk| {
+        Ok(BooleanArray::from_slice(vec![true; chunk.len()]))
+    });
+    assert!(read(&data()?, None, row_filter).is_err());
+    Ok(())
+}
@@ -366,7 +366,6 @@ fn list_large_binary_optional_v1() -> Result<()> {
 }
 
 #[test]
-#[ignore]
 fn utf8_optional_v2_delta() -> Result<()> {
     round_trip(
         "string",
@@ -378,6 +377,66 @@ fn utf8_optional_v2_delta() -> Result<()> {
     )
 }
 
+#[test]
+fn utf8_required_v1_delta() -> Result<()> {
+    round_trip(
+        "string",
+        false,
+        false,
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        Encoding::DeltaLengthByteArray,
+    )
+}
+
+#[test]
+fn utf8_optional_v2_delta_byte_array() -> Result<()> {
+    round_trip(
+        "string",
+        true,
+        false,
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        Encoding::DeltaByteArray,
+    )
+}
+
+#[test]
+fn utf8_required_v1_delta_byte_array() -> Result<()> {
+    round_trip(
+        "string",
+        false,
+        false,
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        Encoding::DeltaByteArray,
+    )
+}
+
+#[test]
+fn f64_optional_v1_byte_stream_split() -> Result<()> {
+    round_trip(
+        "float64",
+        true,
+        false,
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        Encoding::ByteStreamSplit,
+    )
+}
+
+#[test]
+fn f64_optional_v2_byte_stream_split() -> Result<()> {
+    round_trip(
+        "float64",
+        true,
+        false,
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        Encoding::ByteStreamSplit,
+    )
+}
+
 #[test]
 fn i32_optional_v2_dict() -> Result<()> {
     round_trip(
@@ -403,6 +462,32 @@ fn i32_optional_v2_dict_compressed() -> Result<()> {
     )
 }
 
+#[cfg(feature = "io_parquet_compression")]
+#[test]
+fn i32_optional_v1_dict_lz4_raw() -> Result<()> {
+    round_trip(
+        "int32_dict",
+        true,
+        false,
+        Version::V1,
+        CompressionOptions::Lz4Raw,
+        Encoding::RleDictionary,
+    )
+}
+
+#[cfg(feature = "io_parquet_compression")]
+#[test]
+fn utf8_optional_v2_lz4_raw() -> Result<()> {
+    round_trip(
+        "string",
+        true,
+        false,
+        Version::V2,
+        CompressionOptions::Lz4Raw,
+        Encoding::Plain,
+    )
+}
+
 // Decimal Testing
 #[test]
 fn decimal_9_optional_v1() -> Result<()> {
@@ -547,3 +632,823 @@ fn decimal_26_required_v2() -> Result<()> {
         Encoding::Plain,
     )
 }
+
+fn write_sorted(encoding: Encoding) -> Result<(Chunk<Arc<dyn Array>>, Vec<u8>)> {
+    let values = (0..1000)
+        .map(|i| format!("user-{:06}", i))
+        .collect::<Vec<_>>();
+    let utf8 = Utf8Array::<i32>::from(
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, x)| if i % 7 == 0 { None } else { Some(x) })
+            .collect::<Vec<_>>(),
+    );
+    let binary = BinaryArray::<i64>::from_slice(&values);
+    let chunk = Chunk::new(vec![
+        Arc::new(utf8) as Arc<dyn Array>,
+        Arc::new(binary) as Arc<dyn Array>,
+    ]);
+    let schema = Schema::from(vec![
+        Field::new("a", chunk.arrays()[0].data_type().clone(), true),
+        Field::new("b", chunk.arrays()[1].data_type().clone(), false),
+    ]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![encoding, encoding],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok((chunk, writer.into_inner().into_inner()))
+}
+
+#[test]
+fn delta_byte_array_sorted() -> Result<()> {
+    let (chunk, data) = write_sorted(Encoding::DeltaByteArray)?;
+    let (_, plain) = write_sorted(Encoding::Plain)?;
+    assert!(data.len() * 3 < plain.len());
+
+    let (_, result) = integration_read(&data)?;
+    assert_eq!(result, vec![chunk.clone()]);
+
+    // only the selected rows of the pages are deserialized
+    let mut reader = FileReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    reader.set_row_filter(RowFilter::new(vec![1], |chunk| {
+        let array = chunk.arrays()[0]
+            .as_any()
+            .downcast_ref::<BinaryArray<i64>>()
+            .unwrap();
+        Ok(array.iter().map(|x| x.map(|x| x[9] == b'5')).collect())
+    }));
+    let result = reader.collect::<Result<Vec<_>>>()?;
+
+    let mask = BooleanArray::from_slice((0..1000).map(|i| i / 10 % 10 == 5).collect::<Vec<_>>());
+    let expected = arrow2::compute::filter::filter_chunk(&chunk, &mask)?;
+    let expected = Chunk::new(expected.into_arrays().into_iter().map(Arc::from).collect());
+    assert_eq!(result, vec![expected]);
+    Ok(())
+}
+
+#[test]
+fn byte_stream_split_filtered() -> Result<()> {
+    let f32 = Float32Array::from_values((0..1000).map(|x| x as f32 / 3.0));
+    let f64 = Float64Array::from(
+        (0..1000)
+            .map(|x| {
+                if x % 3 == 0 {
+                    None
+                } else {
+                    Some(x as f64 * 1.5)
+                }
+            })
+            .collect::<Vec<_>>(),
+    );
+    let chunk = Chunk::new(vec![
+        Arc::new(f32) as Arc<dyn Array>,
+        Arc::new(f64) as Arc<dyn Array>,
+    ]);
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Float32, false),
+        Field::new("b", DataType::Float64, true),
+    ]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::ByteStreamSplit, Encoding::ByteStreamSplit],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let (_, result) = integration_read(&data)?;
+    assert_eq!(result, vec![chunk.clone()]);
+
+    // only the selected rows of the pages are deserialized
+    let mut reader = FileReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    reader.set_row_filter(RowFilter::new(vec![0], |chunk| {
+        let array = chunk.arrays()[0]
+            .as_any()
+            .downcast_ref::<Float32Array>()
+            .unwrap();
+        Ok(array.iter().map(|x| x.map(|x| *x > 200.0)).collect())
+    }));
+    let result = reader.collect::<Result<Vec<_>>>()?;
+
+    let mask = BooleanArray::from_slice((0..1000).map(|x| x > 600).collect::<Vec<_>>());
+    let expected = arrow2::compute::filter::filter_chunk(&chunk, &mask)?;
+    let expected = Chunk::new(expected.into_arrays().into_iter().map(Arc::from).collect());
+    assert_eq!(result, vec![expected]);
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet_compression")]
+#[test]
+fn column_options() -> Result<()> {
+    use parquet2::compression::Compression;
+    use std::collections::HashMap;
+
+    let chunk = Chunk::new(vec![
+        Arc::new(Utf8Array::<i32>::from_slice(&["aa", "ab", "abc"])) as Arc<dyn Array>,
+        Arc::new(BinaryArray::<i32>::from_slice(&[
+            b"\x1f\x8b",
+            b"\x1f\x8b",
+            b"\x00\x00",
+        ])),
+        Arc::new(Int64Array::from_slice(&[1, 2, 3])),
+    ]);
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Utf8, false),
+        Field::new("b", DataType::Binary, false),
+        Field::new("c", DataType::Int64, false),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Snappy,
+        version: Version::V2,
+    };
+
+    let columns = HashMap::from([
+        (
+            vec!["a".to_string()],
+            ColumnOptions {
+                compression: Some(CompressionOptions::Zstd(None)),
+                encoding: Some(Encoding::DeltaByteArray),
+                ..Default::default()
+            },
+        ),
+        (
+            vec!["b".to_string()],
+            ColumnOptions {
+                compression: Some(CompressionOptions::Uncompressed),
+                encoding: None,
+                ..Default::default()
+            },
+        ),
+    ]);
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain; 3],
+    )?
+    .with_column_options(columns)?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let columns = metadata.row_groups[0].columns();
+    let compressions = columns.iter().map(|x| x.compression()).collect::<Vec<_>>();
+    assert_eq!(
+        compressions,
+        vec![
+            Compression::Zstd,
+            Compression::Uncompressed,
+            Compression::Snappy,
+        ]
+    );
+
+    let (_, result) = integration_read(&data)?;
+    assert_eq!(result, vec![chunk.clone()]);
+
+    // unknown fields are an error
+    let columns = HashMap::from([(vec!["d".to_string()], ColumnOptions::default())]);
+    assert!(RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain; 3],
+    )?
+    .with_column_options(columns)
+    .is_err());
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet_compression")]
+#[test]
+fn column_options_nested() -> Result<()> {
+    use parquet2::compression::Compression;
+    use std::collections::HashMap;
+
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    list.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![])])?;
+    let list: ListArray<i32> = list.into();
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", list.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from_slice(&[1, 2, 3])) as Arc<dyn Array>,
+        Arc::new(list),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Snappy,
+        version: Version::V1,
+    };
+    let row_groups = |columns| {
+        RowGroupIterator::try_new(
+            vec![Ok(chunk.clone())].into_iter(),
+            &schema,
+            options,
+            vec![Encoding::Plain; 2],
+        )?
+        .with_column_options(columns)
+    };
+
+    let path = ["b", "list", "item"].map(|x| x.to_string()).to_vec();
+    let columns = HashMap::from([(
+        path,
+        ColumnOptions {
+            compression: Some(CompressionOptions::Uncompressed),
+            ..Default::default()
+        },
+    )]);
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups(columns)? {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let columns = metadata.row_groups[0].columns();
+    let compressions = columns.iter().map(|x| x.compression()).collect::<Vec<_>>();
+    assert_eq!(
+        compressions,
+        vec![Compression::Snappy, Compression::Uncompressed]
+    );
+    let (_, result) = integration_read(&data)?;
+    assert_eq!(result, vec![chunk.clone()]);
+
+    // a nested column is not identified by the name of its field
+    let columns = HashMap::from([(vec!["b".to_string()], ColumnOptions::default())]);
+    assert!(row_groups(columns).is_err());
+    Ok(())
+}
+
+#[test]
+fn column_options_struct() -> Result<()> {
+    use std::collections::HashMap;
+
+    let fields = vec![
+        Field::new("x", DataType::Int64, true),
+        Field::new("y", DataType::Int64, true),
+    ];
+    let schema = Schema::from(vec![Field::new("a", DataType::Struct(fields), true)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+    };
+
+    // the options of a leaf would also apply to the other leaves of the struct
+    let path = ["a", "x"].map(|x| x.to_string()).to_vec();
+    let columns = HashMap::from([(
+        path,
+        ColumnOptions {
+            encoding: Some(Encoding::DeltaBinaryPacked),
+            ..Default::default()
+        },
+    )]);
+    let row_groups = RowGroupIterator::<Arc<dyn Array>, _>::try_new(
+        std::iter::empty(),
+        &schema,
+        options,
+        vec![Encoding::Plain],
+    )?;
+    assert!(row_groups.with_column_options(columns).is_err());
+    Ok(())
+}
+
+#[test]
+fn dictionary_fallback() -> Result<()> {
+    use parquet_format_async_temp::Encoding as ParquetEncoding;
+    use std::collections::HashMap;
+
+    // a row group with 2 distinct values and one with 10
+    let chunks = [2, 10]
+        .into_iter()
+        .map(|cardinality| {
+            let mut array = MutableDictionaryArray::<u32, MutableUtf8Array<i32>>::new();
+            array
+                .try_extend((0..10).map(|x| Some(format!("value_{}", x % cardinality))))
+                .unwrap();
+            let array: DictionaryArray<u32> = array.into();
+            Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])
+        })
+        .collect::<Vec<_>>();
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunks[0].arrays()[0].data_type().clone(),
+        true,
+    )]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+    };
+
+    let columns = HashMap::from([(
+        vec!["a".to_string()],
+        ColumnOptions {
+            max_dictionary_cardinality: Some(5),
+            ..Default::default()
+        },
+    )]);
+    let row_groups = RowGroupIterator::try_new(
+        chunks.clone().into_iter().map(Ok),
+        &schema,
+        options,
+        vec![Encoding::RleDictionary],
+    )?
+    .with_column_options(columns)?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    // only the row group whose dictionary exceeds the cardinality is plain-encoded
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let is_dictionary = metadata
+        .row_groups
+        .iter()
+        .map(|row_group| {
+            row_group.columns()[0]
+                .column_encoding()
+                .contains(&ParquetEncoding::RLE_DICTIONARY)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(is_dictionary, vec![true, false]);
+
+    // both row groups are read as dictionary arrays of the same values
+    let (_, result) = integration_read(&data)?;
+    assert_eq!(result.len(), 2);
+    for (result, expected) in result.iter().zip(chunks.iter()) {
+        let result = result.arrays()[0].as_ref();
+        let expected = expected.arrays()[0].as_ref();
+        assert_eq!(result.data_type(), expected.data_type());
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<u32>>()
+            .unwrap();
+        let expected = expected
+            .as_any()
+            .downcast_ref::<DictionaryArray<u32>>()
+            .unwrap();
+        let result = arrow2::compute::take::take(result.values().as_ref(), result.keys())?;
+        let expected = arrow2::compute::take::take(expected.values().as_ref(), expected.keys())?;
+        assert_eq!(result, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn field_ids_and_key_value_metadata() -> Result<()> {
+    use arrow2::io::parquet::read::schema::ParquetType;
+    use arrow2::io::parquet::PARQUET_FIELD_ID_META_KEY;
+
+    let with_id = |field: Field, id: &str| {
+        field.with_metadata(
+            [(PARQUET_FIELD_ID_META_KEY.to_string(), id.to_string())]
+                .into_iter()
+                .collect(),
+        )
+    };
+    let schema = Schema::from(vec![
+        with_id(Field::new("a", DataType::Int32, false), "1"),
+        with_id(
+            Field::new(
+                "b",
+                DataType::List(Box::new(with_id(
+                    Field::new("item", DataType::Utf8, true),
+                    "3",
+                ))),
+                true,
+            ),
+            "2",
+        ),
+        Field::new("d", DataType::Int64, true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice(&[1, 2])) as Arc<dyn Array>,
+        Arc::new(ListArray::<i32>::from_data(
+            schema.fields[1].data_type().clone(),
+            vec![0, 2, 3].into(),
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), None, Some("b")])),
+            None,
+        )),
+        Arc::new(Int64Array::from(&[None, Some(1)])),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain; 3],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    let key_value = KeyValue {
+        key: "key".to_string(),
+        value: Some("value".to_string()),
+    };
+    writer.end(Some(vec![key_value.clone()]))?;
+    let data = writer.into_inner().into_inner();
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let ids = metadata
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| {
+            // the id of the element of the list
+            let children = match field {
+                ParquetType::GroupType { fields, .. } => match &fields[0] {
+                    ParquetType::GroupType { fields, .. } => {
+                        fields.iter().map(|x| x.get_field_info().id).collect()
+                    }
+                    _ => vec![],
+                },
+                _ => vec![],
+            };
+            (field.get_field_info().id, children)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        vec![(Some(1), vec![]), (Some(2), vec![Some(3)]), (None, vec![])]
+    );
+    assert!(metadata
+        .key_value_metadata()
+        .as_ref()
+        .unwrap()
+        .contains(&key_value));
+
+    // the ids are also part of the schema inferred from the parquet schema
+    let mut parquet_only = metadata.clone();
+    parquet_only.key_value_metadata = None;
+    assert_eq!(infer_schema(&parquet_only)?.fields, schema.fields);
+
+    let (read_schema, result) = integration_read(&data)?;
+    assert_eq!(read_schema.fields, schema.fields);
+    assert_eq!(result, vec![chunk]);
+
+    // ids must be 32-bit integers
+    let schema = Schema::from(vec![with_id(Field::new("a", DataType::Int32, false), "a")]);
+    assert!(to_parquet_schema(&schema).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet_compression")]
+#[test]
+fn data_page_v2() -> Result<()> {
+    use arrow2::io::parquet::read::get_page_iterator;
+    use parquet2::page::DataPageHeader;
+
+    // a null list, an empty list and a null element
+    let array = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 2, 2, 2, 3].into(),
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])),
+        Some([true, false, true, true].into()),
+    );
+    let chunk = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunk.arrays()[0].data_type().clone(),
+        true,
+    )]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Snappy,
+        version: Version::V2,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let column = &metadata.row_groups[0].columns()[0];
+    let pages = get_page_iterator(column, Cursor::new(&data), None, vec![])?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    assert_eq!(pages.len(), 1);
+    match pages[0].header() {
+        DataPageHeader::V2(header) => {
+            assert_eq!(header.num_values, 5);
+            assert_eq!(header.num_rows, 4);
+            // the null list, the empty list and the null element
+            assert_eq!(header.num_nulls, 3);
+            assert_eq!(header.is_compressed, Some(true));
+        }
+        DataPageHeader::V1(_) => panic!("a data page v1 was written"),
+    }
+
+    let (_, result) = integration_read(&data)?;
+    assert_eq!(result, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn sliced_list() -> Result<()> {
+    use arrow2::io::parquet::read::get_page_iterator;
+    use parquet2::page::DataPageHeader;
+
+    // [[1, 2], [None, 3], None, [], [4]] sliced to [[None, 3], None, []]
+    let offsets = vec![0, 2, 4, 4, 4, 5];
+    let validity = Some([true, true, false, true, true].into());
+    let values: Vec<Arc<dyn Array>> = vec![
+        Arc::new(Int32Array::from(&[
+            Some(1),
+            Some(2),
+            None,
+            Some(3),
+            Some(4),
+        ])),
+        Arc::new(Utf8Array::<i32>::from(&[
+            Some("a"),
+            Some("b"),
+            None,
+            Some("c"),
+            Some("d"),
+        ])),
+        Arc::new(BinaryArray::<i64>::from(&[
+            Some(b"a".as_ref()),
+            Some(b"b"),
+            None,
+            Some(b"c"),
+            Some(b"d"),
+        ])),
+        Arc::new(BooleanArray::from(&[
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+            Some(false),
+        ])),
+    ];
+    let arrays = values
+        .into_iter()
+        .map(|values| {
+            let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+            ListArray::<i32>::from_data(data_type, offsets.clone().into(), values, validity.clone())
+                .slice(1, 3)
+        })
+        .map(|array| Arc::new(array) as Arc<dyn Array>)
+        .collect::<Vec<_>>();
+    let chunk = Chunk::new(arrays);
+    let schema = Schema::from(
+        chunk
+            .arrays()
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(i.to_string(), array.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain; 4],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    for column in metadata.row_groups[0].columns() {
+        let pages = get_page_iterator(column, Cursor::new(&data), None, vec![])?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        match pages[0].header() {
+            DataPageHeader::V2(header) => {
+                assert_eq!(header.num_values, 4);
+                assert_eq!(header.num_rows, 3);
+                // the null element, the null list and the empty list
+                assert_eq!(header.num_nulls, 3);
+            }
+            DataPageHeader::V1(_) => panic!("a data page v1 was written"),
+        }
+    }
+
+    let (_, result) = integration_read(&data)?;
+    assert_eq!(result, vec![chunk]);
+    Ok(())
+}
+
+fn write_sized(chunks: Vec<Chunk<Arc<dyn Array>>>, target_size: usize) -> Result<Vec<u8>> {
+    let data_type = chunks[0].arrays()[0].data_type().clone();
+    let schema = Schema::from(vec![Field::new("a", data_type, false)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        chunks.into_iter().map(Ok),
+        &schema,
+        options,
+        vec![Encoding::Plain],
+    )?
+    .with_target_row_group_size(target_size);
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok(writer.into_inner().into_inner())
+}
+
+#[test]
+fn target_row_group_size() -> Result<()> {
+    let array = Int64Array::from_values(0..1000);
+    let expected = Chunk::new(vec![Arc::new(array.clone()) as Arc<dyn Array>]);
+
+    // 100 rows of 8 bytes per chunk
+    let chunks = (0..10)
+        .map(|i| Chunk::new(vec![Arc::new(array.slice(i * 100, 100)) as Arc<dyn Array>]))
+        .collect::<Vec<_>>();
+    // a single chunk
+    let chunk = vec![expected.clone()];
+
+    for chunks in [chunks, chunk] {
+        let data = write_sized(chunks, 2000)?;
+
+        let metadata = read_metadata(&mut Cursor::new(&data))?;
+        let rows = metadata
+            .row_groups
+            .iter()
+            .map(|group| group.num_rows())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec![250, 250, 250, 250]);
+
+        let reader = FileReader::try_new(Cursor::new(&data), None, Some(1000), None, None)?;
+        let arrays = reader
+            .map(|chunk| chunk.map(|chunk| chunk.into_arrays().pop().unwrap()))
+            .collect::<Result<Vec<_>>>()?;
+        let arrays = arrays.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+        let result = arrow2::compute::concatenate::concatenate(&arrays)?;
+        assert_eq!(result.as_ref(), expected.arrays()[0].as_ref());
+    }
+    Ok(())
+}
+
+#[test]
+fn target_row_group_size_utf8() -> Result<()> {
+    // 1000 rows of 1KB
+    let value = "a".repeat(1000);
+    let array = Utf8Array::<i32>::from_iter_values(std::iter::repeat(value).take(1000));
+    let chunks = vec![Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])];
+
+    let data = write_sized(chunks, 100_000)?;
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let rows = metadata
+        .row_groups
+        .iter()
+        .map(|group| group.num_rows())
+        .collect::<Vec<_>>();
+    // each row also has 4 bytes of offsets
+    let mut expected = vec![99; 10];
+    expected.push(10);
+    assert_eq!(rows, expected);
+    Ok(())
+}
+
+#[test]
+fn sorting_columns() -> Result<()> {
+    use parquet_format_async_temp::thrift::protocol::TCompactInputProtocol;
+
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from_slice(&[1, 2, 3])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("c"), None, Some("a")])),
+    ]);
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+    };
+
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone()), Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain; 2],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    let columns = vec![
+        SortingColumn {
+            column: 0,
+            descending: false,
+            nulls_first: true,
+        },
+        SortingColumn {
+            column: 1,
+            descending: true,
+            nulls_first: false,
+        },
+    ];
+    writer.set_sorting_columns(columns)?;
+    assert!(writer
+        .set_sorting_columns(vec![SortingColumn {
+            column: 2,
+            descending: false,
+            nulls_first: false,
+        }])
+        .is_err());
+
+    writer.start()?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    let size = writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+    assert_eq!(size, data.len() as u64);
+
+    let length = data.len();
+    let metadata_length = u32::from_le_bytes(data[length - 8..length - 4].try_into().unwrap());
+    let mut protocol =
+        TCompactInputProtocol::new(&data[length - 8 - metadata_length as usize..length - 8]);
+    let metadata =
+        parquet_format_async_temp::FileMetaData::read_from_in_protocol(&mut protocol).unwrap();
+    let expected = vec![
+        parquet_format_async_temp::SortingColumn::new(0, false, true),
+        parquet_format_async_temp::SortingColumn::new(1, true, false),
+    ];
+    assert_eq!(metadata.row_groups.len(), 2);
+    for group in metadata.row_groups {
+        assert_eq!(group.sorting_columns, Some(expected.clone()));
+    }
+
+    let (_, result) = integration_read(&data)?;
+    assert_eq!(result, vec![chunk.clone(), chunk]);
+    Ok(())
+}
@@ -6,8 +6,14 @@ use arrow2::{
     datatypes::{DataType, Field, Schema},
     error::Result,
     io::parquet::{
-        read::{infer_schema, read_columns_many_async, read_metadata_async, RowGroupDeserializer},
-        write::{CompressionOptions, Encoding, Version, WriteOptions},
+        read::{
+            infer_schema, read_columns_indexes, read_columns_many_async, read_metadata,
+            read_metadata_async, RowGroupDeserializer,
+        },
+        write::{
+            CompressionOptions, Encoding, FileStreamer, FileWriter, RowGroupIterator,
+            SortingColumn, Version, WriteOptions,
+        },
     },
 };
 use futures::{future::BoxFuture, io::Cursor, SinkExt};
@@ -64,9 +70,10 @@ async fn test_parquet_async_roundtrip() {
 
     let mut out = vec![];
     for group in &metadata.row_groups {
-        let column_chunks = read_columns_many_async(factory, group, schema.fields.clone(), None)
-            .await
-            .unwrap();
+        let column_chunks =
+            read_columns_many_async(factory, group, schema.fields.clone(), None, None)
+                .await
+                .unwrap();
         let chunks = RowGroupDeserializer::new(column_chunks, group.num_rows() as usize, None);
         let mut chunks = chunks.collect::<Result<Vec<_>>>().unwrap();
         out.append(&mut chunks);
@@ -76,3 +83,208 @@ async fn test_parquet_async_roundtrip() {
         assert_eq!(data[i], out[i]);
     }
 }
+
+#[tokio::test]
+async fn test_parquet_async_file_streamer() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a1", DataType::Int32, true)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+    };
+    let data = (0..3)
+        .map(|i| {
+            let array = Int32Array::from(&[Some(i), None, Some(i + 1)]);
+            Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])
+        })
+        .collect::<Vec<_>>();
+
+    let mut writer = FileStreamer::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start().await?;
+    let row_groups = RowGroupIterator::try_new(
+        data.clone().into_iter().map(Ok),
+        &schema,
+        options,
+        vec![Encoding::Plain],
+    )?;
+    for group in row_groups {
+        writer.write(group?).await?;
+    }
+    let (size, mut buffer) = writer.end(None).await?;
+    assert_eq!(size as usize, buffer.get_ref().len());
+
+    buffer.set_position(0);
+    let metadata = read_metadata_async(&mut buffer).await?;
+    assert_eq!(infer_schema(&metadata)?, schema);
+    let factory = || Box::pin(futures::future::ready(Ok(buffer.clone()))) as BoxFuture<_>;
+
+    let mut out = vec![];
+    for group in &metadata.row_groups {
+        let column_chunks =
+            read_columns_many_async(factory, group, schema.fields.clone(), None, None).await?;
+        let chunks = RowGroupDeserializer::new(column_chunks, group.num_rows(), None);
+        out.extend(chunks.collect::<Result<Vec<_>>>()?);
+    }
+    assert_eq!(out, data);
+    Ok(())
+}
+
+fn streamer_data() -> (Schema, WriteOptions, Vec<Chunk<Arc<dyn Array>>>) {
+    let schema = Schema::from(vec![Field::new("a1", DataType::Int32, true)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+    };
+    let data = (0..3)
+        .map(|i| {
+            let array = Int32Array::from(&[Some(i), None, Some(i + 1)]);
+            Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])
+        })
+        .collect::<Vec<_>>();
+    (schema, options, data)
+}
+
+#[tokio::test]
+async fn test_parquet_async_file_streamer_is_file_writer() -> Result<()> {
+    let (schema, options, data) = streamer_data();
+    let sorting_columns = vec![SortingColumn {
+        column: 0,
+        descending: false,
+        nulls_first: true,
+    }];
+    let row_groups = || {
+        RowGroupIterator::try_new(
+            data.clone().into_iter().map(Ok),
+            &schema,
+            options,
+            vec![Encoding::Plain],
+        )
+    };
+
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), options)?;
+    writer.set_sorting_columns(sorting_columns.clone())?;
+    writer.start()?;
+    for group in row_groups()? {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let expected = writer.into_inner();
+
+    let mut writer = FileStreamer::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.set_sorting_columns(sorting_columns)?;
+    writer.start().await?;
+    for group in row_groups()? {
+        writer.write(group?).await?;
+    }
+    let (size, buffer) = writer.end(None).await?;
+    let result = buffer.into_inner();
+    assert_eq!(size as usize, result.len());
+    assert_eq!(result, expected);
+
+    // the indexes are written
+    let mut reader = std::io::Cursor::new(result);
+    let metadata = read_metadata(&mut reader)?;
+    let indexes = read_columns_indexes(
+        &mut reader,
+        metadata.row_groups[0].columns(),
+        &schema.fields,
+    )?;
+    assert_eq!(indexes.len(), 1);
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet_bloom_filter")]
+#[tokio::test]
+async fn test_parquet_async_file_streamer_bloom_filters() -> Result<()> {
+    use arrow2::io::parquet::write::{BloomFilterOptions, ColumnOptions};
+
+    let (schema, options, data) = streamer_data();
+    let row_groups = || {
+        RowGroupIterator::try_new(
+            data.clone().into_iter().map(Ok),
+            &schema,
+            options,
+            vec![Encoding::Plain],
+        )?
+        .with_column_options(HashMap::from([(
+            vec!["a1".to_string()],
+            ColumnOptions {
+                bloom_filter: Some(BloomFilterOptions::from_ndv_fpp(100, 0.01)),
+                ..Default::default()
+            },
+        )]))
+    };
+
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), options)?;
+    writer.start()?;
+    let mut groups = row_groups()?;
+    while let Some(group) = groups.next_with_bloom_filters() {
+        let (group, bloom_filters) = group?;
+        writer.write_with_bloom_filters(group, bloom_filters)?;
+    }
+    writer.end(None)?;
+    let expected = writer.into_inner();
+
+    let mut writer = FileStreamer::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start().await?;
+    let mut groups = row_groups()?;
+    while let Some(group) = groups.next_with_bloom_filters() {
+        let (group, bloom_filters) = group?;
+        writer
+            .write_with_bloom_filters(group, bloom_filters)
+            .await?;
+    }
+    let (_, buffer) = writer.end(None).await?;
+    let result = buffer.into_inner();
+    assert_eq!(result, expected);
+
+    let metadata = read_metadata(&mut std::io::Cursor::new(&result))?;
+    let column = &metadata.row_groups[0].columns()[0];
+    assert!(column.metadata().bloom_filter_offset.is_some());
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet_encryption")]
+#[tokio::test]
+async fn test_parquet_async_file_streamer_encryption() -> Result<()> {
+    use arrow2::io::parquet::encryption::{
+        EncryptionKey, FileDecryptionProperties, FileEncryptionProperties,
+    };
+    use arrow2::io::parquet::read::FileReader;
+
+    let (schema, options, data) = streamer_data();
+    let key = vec![1; 16];
+    let properties = FileEncryptionProperties::new(EncryptionKey::new(key.clone()));
+
+    let mut writer = FileStreamer::try_new_with_encryption(
+        Cursor::new(vec![]),
+        schema.clone(),
+        options,
+        properties,
+    )?;
+    writer.start().await?;
+    let row_groups = RowGroupIterator::try_new(
+        data.clone().into_iter().map(Ok),
+        &schema,
+        options,
+        vec![Encoding::Plain],
+    )?;
+    for group in row_groups {
+        writer.write(group?).await?;
+    }
+    let (size, buffer) = writer.end(None).await?;
+    let result = buffer.into_inner();
+    assert_eq!(size as usize, result.len());
+    assert_eq!(&result[..4], b"PARE");
+
+    let properties = FileDecryptionProperties {
+        footer_key: Some(key),
+        ..Default::default()
+    };
+    let reader = std::io::Cursor::new(result);
+    let out = FileReader::try_new_with_decryption(reader, &properties, None, None, None, None)?
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(out, data);
+    Ok(())
+}
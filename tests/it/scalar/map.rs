@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{Array, BooleanArray, StructArray, Utf8Array},
+    datatypes::{DataType, Field},
+    scalar::{MapScalar, Scalar},
+};
+
+fn entries(values: [bool; 2]) -> (DataType, Arc<dyn Array>) {
+    let dt = DataType::Struct(vec![
+        Field::new("keys", DataType::Utf8, false),
+        Field::new("values", DataType::Boolean, true),
+    ]);
+    let entries = StructArray::new(
+        dt.clone(),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])) as Arc<dyn Array>,
+            Arc::new(BooleanArray::from_slice(values)),
+        ],
+        None,
+    );
+    let dt = DataType::Map(Box::new(Field::new("entries", dt, false)), false);
+    (dt, Arc::new(entries))
+}
+
+#[allow(clippy::eq_op)]
+#[test]
+fn equal() {
+    let (dt, values) = entries([true, false]);
+    let a = MapScalar::new(dt.clone(), Some(values));
+    let b = MapScalar::new(dt.clone(), None);
+    assert_eq!(a, a);
+    assert_eq!(b, b);
+    assert!(a != b);
+    let (_, values) = entries([true, true]);
+    let b = MapScalar::new(dt, Some(values));
+    assert!(a != b);
+    assert_eq!(b, b);
+}
+
+#[test]
+fn basics() {
+    let (dt, values) = entries([true, false]);
+    let a = MapScalar::new(dt.clone(), Some(values.clone()));
+
+    assert_eq!(values.as_ref(), a.values().as_ref());
+    assert_eq!(a.data_type(), &dt);
+    assert!(a.is_valid());
+
+    let _: &dyn std::any::Any = a.as_any();
+}
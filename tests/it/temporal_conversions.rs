@@ -79,7 +79,8 @@ fn add_interval_fixed_offset() {
         timeunit,
         months_days_ns::new(0, 1, 60_000_000_000),
         &timezone,
-    );
+    )
+    .unwrap();
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("1972-02-29 02:01:00 +01:00", format!("{}", r));
 
@@ -88,7 +89,8 @@ fn add_interval_fixed_offset() {
         timeunit,
         months_days_ns::new(1, 1, 60_000_000_000),
         &timezone,
-    );
+    )
+    .unwrap();
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("1972-03-29 02:01:00 +01:00", format!("{}", r));
 
@@ -97,7 +99,8 @@ fn add_interval_fixed_offset() {
         timeunit,
         months_days_ns::new(24, 1, 60_000_000_000),
         &timezone,
-    );
+    )
+    .unwrap();
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("1974-03-01 02:01:00 +01:00", format!("{}", r));
 
@@ -106,7 +109,8 @@ fn add_interval_fixed_offset() {
         timeunit,
         months_days_ns::new(-1, 1, 60_000_000_000),
         &timezone,
-    );
+    )
+    .unwrap();
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("1972-01-29 02:01:00 +01:00", format!("{}", r));
 }
@@ -125,7 +129,8 @@ fn add_interval_timezone() {
         timeunit,
         months_days_ns::new(0, 0, 60 * 60 * 1_000_000_000),
         &timezone,
-    );
+    )
+    .unwrap();
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("2020-03-29 02:00:00 WEST", format!("{}", r));
 
@@ -135,7 +140,79 @@ fn add_interval_timezone() {
         timeunit,
         months_days_ns::new(7, 0, 60 * 60 * 1_000_000_000),
         &timezone,
-    );
+    )
+    .unwrap();
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("2020-10-29 01:00:00 WET", format!("{}", r));
 }
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn add_interval_across_dst() {
+    // Sat Mar 13 2021 12:00:00 EST; summer time starts the next day at 02:00
+    let timestamp = 1615654800;
+    let timeunit = TimeUnit::Second;
+    let timezone = temporal_conversions::parse_offset_tz("America/New_York").unwrap();
+
+    // one day later is 12:00 EDT, 23 hours later
+    let r = temporal_conversions::add_interval(
+        timestamp,
+        timeunit,
+        months_days_ns::new(0, 1, 0),
+        &timezone,
+    );
+    assert_eq!(r, Some(1615737600));
+
+    // 24 hours later is 13:00 EDT
+    let r = temporal_conversions::add_interval(
+        timestamp,
+        timeunit,
+        months_days_ns::new(0, 0, 24 * 60 * 60 * 1_000_000_000),
+        &timezone,
+    );
+    assert_eq!(r, Some(1615741200));
+
+    // Sat Mar 13 2021 02:30:00 EST + 1 day does not exist locally and is resolved with EST
+    let r = temporal_conversions::add_interval(
+        1615620600,
+        timeunit,
+        months_days_ns::new(0, 1, 0),
+        &timezone,
+    )
+    .unwrap();
+    let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
+    assert_eq!("2021-03-14 03:30:00 EDT", format!("{}", r));
+}
+
+#[test]
+fn add_interval_overflow() {
+    let timezone = temporal_conversions::parse_offset("+01:00").unwrap();
+    let interval = months_days_ns::new(0, i32::MAX, 0);
+    for timeunit in [TimeUnit::Second, TimeUnit::Nanosecond] {
+        assert_eq!(
+            temporal_conversions::add_interval(0, timeunit, interval, &timezone),
+            None
+        );
+        assert_eq!(
+            temporal_conversions::add_naive_interval(0, timeunit, interval),
+            None
+        );
+    }
+    assert_eq!(
+        temporal_conversions::add_naive_interval(
+            i64::MAX,
+            TimeUnit::Nanosecond,
+            months_days_ns::new(0, 0, 1)
+        ),
+        None
+    );
+    // before the epoch
+    assert_eq!(
+        temporal_conversions::add_naive_interval(
+            -1,
+            TimeUnit::Nanosecond,
+            months_days_ns::new(0, 1, 0)
+        ),
+        Some(86_400_000_000_000 - 1)
+    );
+}
@@ -0,0 +1,104 @@
+use arrow2::types::{i256, NativeType};
+
+fn i(value: i128) -> i256 {
+    i256::from(value)
+}
+
+#[test]
+fn i256_add_sub() {
+    assert_eq!(i(1) + i(-3), i(-2));
+    assert_eq!(i(i128::MAX) + i(1), i256::from_parts(1 << 127, 0));
+    assert_eq!(i256::from_parts(1 << 127, 0) - i(1), i(i128::MAX));
+    assert_eq!(i(i128::MIN) - i(1), i256::from_parts(u128::MAX >> 1, -1));
+
+    assert_eq!(i256::MAX.checked_add(i(1)), None);
+    assert_eq!(i256::MIN.checked_sub(i(1)), None);
+    assert_eq!(i256::MIN.checked_add(i(-1)), None);
+    assert_eq!(i256::MAX.checked_sub(i(-1)), None);
+    assert_eq!(i256::MIN.checked_add(i256::MAX), Some(i(-1)));
+    assert_eq!(i256::MAX.saturating_add(i(1)), i256::MAX);
+    assert_eq!(i256::MIN.saturating_sub(i(1)), i256::MIN);
+    assert_eq!(i256::MIN.checked_neg(), None);
+    assert_eq!(-i256::MAX, i256::MIN + i(1));
+}
+
+#[test]
+fn i256_mul_div() {
+    let large = i(i128::MAX);
+    let square = large * large;
+    assert_eq!(square / large, large);
+    assert_eq!((square + i(5)) % large, i(5));
+    assert_eq!((-square) / large, -large);
+    assert_eq!((-square - i(5)) % large, i(-5));
+    assert_eq!(i(-7) / i(2), i(-3));
+    assert_eq!(i(-7) % i(2), i(-1));
+
+    assert_eq!(square.checked_mul(i(4)), None);
+    assert_eq!(i256::MIN.checked_mul(i(-1)), None);
+    assert_eq!(i256::MIN.checked_div(i(-1)), None);
+    assert_eq!(i(1).checked_div(i(0)), None);
+    assert_eq!((i256::MIN / i(2)) * i(2), i256::MIN);
+    assert_eq!(i256::MAX.saturating_mul(i(-2)), i256::MIN);
+
+    let ten = i(10);
+    assert_eq!(
+        ten.checked_pow(76).unwrap() / ten.checked_pow(75).unwrap(),
+        ten
+    );
+    assert_eq!(ten.checked_pow(77), None);
+}
+
+#[test]
+fn i256_cmp() {
+    let mut values = vec![i256::MAX, i(1), i256::MIN, i(-1), i(i128::MIN), i(0)];
+    values.sort();
+    assert_eq!(
+        values,
+        vec![i256::MIN, i(i128::MIN), i(-1), i(0), i(1), i256::MAX]
+    );
+}
+
+#[test]
+fn i256_display_parse() {
+    let max = "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+    assert_eq!(i256::MAX.to_string(), max);
+    assert_eq!(
+        i256::MIN.to_string(),
+        format!("-{}8", &max[..max.len() - 1])
+    );
+    assert_eq!(i(-10).to_string(), "-10");
+    assert_eq!(
+        (i(10).checked_pow(40).unwrap()).to_string(),
+        format!("1{}", "0".repeat(40))
+    );
+
+    for value in [
+        i256::MAX,
+        i256::MIN,
+        i(0),
+        i(-10),
+        i(10).checked_pow(40).unwrap(),
+    ] {
+        assert_eq!(value.to_string().parse::<i256>().unwrap(), value);
+    }
+    assert_eq!("+1".parse::<i256>().unwrap(), i(1));
+    assert!(format!("{}0", max).parse::<i256>().is_err());
+    assert!("1.0".parse::<i256>().is_err());
+    assert!("-".parse::<i256>().is_err());
+}
+
+#[test]
+fn i256_conversions() {
+    assert_eq!(i(-1).to_i128(), Some(-1));
+    assert_eq!(i256::MAX.to_i128(), None);
+    assert_eq!(i(-3).to_f64(), -3.0);
+    assert_eq!(i256::from_f64(-2.5e40).unwrap().to_f64(), -2.5e40);
+    assert_eq!(i256::from_f64(-3.9), Some(i(-3)));
+    assert_eq!(i256::from_f64(1e77), None);
+    assert_eq!(i256::from_f64(f64::NAN), None);
+
+    let value = i(-2) * i(i128::MAX);
+    assert_eq!(i256::from_le_bytes(value.to_le_bytes()), value);
+    assert_eq!(i256::from_be_bytes(value.to_be_bytes()), value);
+    assert_eq!(i(1).to_le_bytes()[0], 1);
+}